@@ -34,6 +34,8 @@ pub const ROOCH_KEYSTORE_FILENAME: &str = "rooch.keystore";
 
 const DEFAULT_BTC_REORG_AWARE_BLOCK_STORE_DIR: &str = "btc-reorg-aware-block-store";
 const DEFAULT_BTC_REORG_AWARE_HEIGHT: usize = 16; // much larger than bitcoin_reorg_block_count, no need to be too large
+const DEFAULT_ETH_CONFIRMATIONS: u64 = 12;
+const DEFAULT_ETH_REORG_AWARE_HEIGHT: usize = 64; // larger than eth_confirmations, reorg rarely goes deeper than it
 
 pub static R_DEFAULT_BASE_DATA_DIR: Lazy<PathBuf> = Lazy::new(|| {
     dirs_next::home_dir()
@@ -107,6 +109,19 @@ pub struct RoochOpt {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[clap(long, env = "ETH_RPC_URL")]
     pub eth_rpc_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, env = "ETH_CONFIRMATIONS")]
+    /// The number of confirmations an Ethereum block needs before relaying, default is 12.
+    pub eth_confirmations: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, env = "ETH_START_BLOCK_NUMBER")]
+    /// The Ethereum block number to start relaying from if no block has been relayed yet,
+    /// default is the latest confirmed block.
+    pub eth_start_block_number: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    /// The number of recent Ethereum blocks kept for reorg detection, default is 64.
+    pub eth_reorg_aware_height: Option<usize>,
 
     /// The Bitcoin RPC URL to connect to for relay L1 block and transaction to L2.
     /// If not set, the relayer service will not start.
@@ -155,6 +170,12 @@ pub struct RoochOpt {
     #[clap(long, default_value_t, value_enum)]
     pub service_status: ServiceStatus,
 
+    /// The RPC URL of the upstream node which proposes the blocks.
+    /// The follower checks the derived state roots against the proposed blocks, it is required in follower mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub upstream_rpc_url: Option<String>,
+
     /// Set quota size that defines how many requests can occur
     /// before the governor middleware starts blocking requests from an IP address and
     /// clients have to wait until the elements of the quota are replenished.
@@ -196,6 +217,9 @@ impl RoochOpt {
             store: StoreConfig::default(),
             port: None,
             eth_rpc_url: None,
+            eth_confirmations: None,
+            eth_start_block_number: None,
+            eth_reorg_aware_height: None,
             btc_rpc_url: None,
             btc_rpc_username: None,
             btc_rpc_password: None,
@@ -208,6 +232,7 @@ impl RoochOpt {
             da: DAConfig::default(),
             proposer: ProposerConfig::default(),
             service_status: ServiceStatus::default(),
+            upstream_rpc_url: None,
             traffic_per_second: None,
             traffic_burst_size: None,
             base: None,
@@ -249,6 +274,11 @@ impl RoochOpt {
             .as_ref()
             .map(|eth_rpc_url| EthereumRelayerConfig {
                 eth_rpc_url: eth_rpc_url.clone(),
+                eth_confirmations: self.eth_confirmations.unwrap_or(DEFAULT_ETH_CONFIRMATIONS),
+                eth_start_block_number: self.eth_start_block_number,
+                eth_reorg_aware_height: self
+                    .eth_reorg_aware_height
+                    .unwrap_or(DEFAULT_ETH_REORG_AWARE_HEIGHT),
             })
     }
 
@@ -340,6 +370,9 @@ impl RoochOpt {
 #[derive(Debug, Clone)]
 pub struct EthereumRelayerConfig {
     pub eth_rpc_url: String,
+    pub eth_confirmations: u64,
+    pub eth_start_block_number: Option<u64>,
+    pub eth_reorg_aware_height: usize,
 }
 
 #[derive(Debug, Clone)]
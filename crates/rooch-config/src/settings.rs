@@ -7,3 +7,7 @@
 pub const ROOCH_BATCH_INTERVAL: u64 = 1000 * 60 * 15;
 // 5 seconds, check avail block to propose interval
 pub const PROPOSER_CHECK_INTERVAL: u64 = 5;
+// 5 seconds, check DA for new blocks to derive interval in follower mode
+pub const DERIVATION_CHECK_INTERVAL: u64 = 5;
+// 60 seconds, prune the stale state nodes interval
+pub const STATE_PRUNE_INTERVAL: u64 = 60;
@@ -6,6 +6,7 @@ use anyhow::anyhow;
 use async_trait::async_trait;
 use rooch_config::da_config::{DABackendConfig, DABackendConfigType};
 use rooch_types::da::batch::DABatch;
+use rooch_types::da::segment::SegmentID;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -25,6 +26,11 @@ pub const UNKNOWN_BACKEND_PRIORITY: usize = usize::MAX;
 #[async_trait]
 pub trait DABackend: Sync + Send {
     async fn submit_batch(&self, batch: Arc<DABatch>) -> anyhow::Result<()>;
+    /// Get the batch of the block from backend, return None if it is not found.
+    /// The batch is reassembled from its segments and verified before returning.
+    async fn get_batch(&self, block_number: u128) -> anyhow::Result<Option<DABatch>>;
+    /// List the segment_ids of the chunk that could be found in backend, sorted by segment_number.
+    async fn list_segments(&self, chunk_id: u128) -> anyhow::Result<Vec<SegmentID>>;
     fn get_identifier(&self) -> String;
     fn get_adapter_stats(&self) -> AdapterSubmitStat;
 }
@@ -95,6 +101,31 @@ impl DABackends {
         });
     }
 
+    /// Get the batch of the block from backends in priority order.
+    /// Backends that miss the batch or fail are skipped, the first verified batch is returned.
+    pub async fn get_batch(&self, block_number: u128) -> anyhow::Result<Option<DABatch>> {
+        let mut last_error = None;
+        for backend in &self.backends {
+            match backend.get_batch(block_number).await {
+                Ok(Some(batch)) => return Ok(Some(batch)),
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::warn!(
+                        "failed to get batch from {:?}, block_number: {}, error: {:?}",
+                        backend.get_identifier(),
+                        block_number,
+                        e
+                    );
+                    last_error = Some(e);
+                }
+            }
+        }
+        match last_error {
+            Some(e) => Err(e),
+            None => Ok(None),
+        }
+    }
+
     async fn load_backends_from_configs(
         backend_configs: &[DABackendConfigType],
         genesis_namespace: String,
@@ -30,6 +30,22 @@ pub(crate) trait OpenDAAdapter: Sync + Send {
         segment_bytes: &[u8],
         is_last_segment: bool,
     ) -> anyhow::Result<()>;
+
+    /// Get segment bytes by segment_id, return None if the segment is not found.
+    async fn get_segment(&self, segment_id: SegmentID) -> anyhow::Result<Option<Vec<u8>>> {
+        Err(anyhow!(
+            "get_segment is not supported by this adapter, segment_id: {}",
+            segment_id
+        ))
+    }
+
+    /// List all segment_ids of the chunk which could be found in the backend, sorted by segment_number.
+    async fn list_segments(&self, chunk_id: u128) -> anyhow::Result<Vec<SegmentID>> {
+        Err(anyhow!(
+            "list_segments is not supported by this adapter, chunk_id: {}",
+            chunk_id
+        ))
+    }
 }
 
 #[derive(Clone)]
@@ -4,10 +4,12 @@
 use crate::backend::openda::adapter::{AdapterSubmitStat, OpenDAAdapter, OpenDAAdapterConfig};
 use crate::backend::openda::derive_identifier;
 use crate::backend::DABackend;
+use anyhow::anyhow;
 use async_trait::async_trait;
 use rooch_config::da_config::DABackendOpenDAConfig;
 use rooch_types::da::batch::DABatch;
-use rooch_types::da::chunk::{Chunk, ChunkV0};
+use rooch_types::da::chunk::{chunk_from_segments, Chunk, ChunkV0};
+use rooch_types::da::segment::{segment_from_bytes, SegmentID};
 use std::sync::Arc;
 
 /// manage OpenDA backends while integrating specific adapter logic
@@ -70,6 +72,58 @@ impl DABackend for OpenDABackendManager {
         Ok(())
     }
 
+    async fn get_batch(&self, block_number: u128) -> anyhow::Result<Option<DABatch>> {
+        // each chunk maps to a batch, block_number is chunk_id
+        let chunk_id = block_number;
+        let mut segments = Vec::new();
+        for segment_number in 0.. {
+            let segment_id = SegmentID {
+                chunk_id,
+                segment_number,
+            };
+            let segment_bytes = match self.adapter.get_segment(segment_id).await? {
+                Some(bytes) => bytes,
+                None => {
+                    if segment_number == 0 {
+                        return Ok(None);
+                    }
+                    return Err(anyhow!(
+                        "missing segment in {:?}, segment_id: {}, last segment not found",
+                        self.get_identifier(),
+                        segment_id
+                    ));
+                }
+            };
+            let segment = segment_from_bytes(&segment_bytes)?;
+            let is_last_segment = segment.is_last();
+            segments.push(segment);
+            if is_last_segment {
+                break;
+            }
+        }
+
+        let chunk = chunk_from_segments(segments)?;
+        let batch = chunk.get_batches().into_iter().next().ok_or(anyhow!(
+            "no batch found in chunk: {} from {:?}",
+            chunk_id,
+            self.get_identifier()
+        ))?;
+        if batch.meta.block_range.block_number != block_number {
+            return Err(anyhow!(
+                "block number mismatch in {:?}, exp: {}, act: {}",
+                self.get_identifier(),
+                block_number,
+                batch.meta.block_range.block_number
+            ));
+        }
+        batch.verify(true)?;
+        Ok(Some(batch))
+    }
+
+    async fn list_segments(&self, chunk_id: u128) -> anyhow::Result<Vec<SegmentID>> {
+        self.adapter.list_segments(chunk_id).await
+    }
+
     fn get_identifier(&self) -> String {
         self.identifier.clone()
     }
@@ -78,3 +132,50 @@ impl DABackend for OpenDABackendManager {
         self.adapter_stats.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rooch_config::da_config::OpenDAScheme;
+    use rooch_types::crypto::RoochKeyPair;
+    use rooch_types::test_utils::random_ledger_transaction_with_order;
+    use std::collections::HashMap;
+
+    #[tokio::test]
+    async fn test_fs_backend_submit_and_get_batch() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let mut config = HashMap::new();
+        config.insert(
+            "root".to_string(),
+            tmp_dir.path().to_str().unwrap().to_string(),
+        );
+        let open_da_config = DABackendOpenDAConfig {
+            scheme: OpenDAScheme::Fs,
+            config,
+            namespace: Some("test_namespace".to_string()),
+            max_segment_size: Some(1024),
+            max_retries: None,
+        };
+        let backend = OpenDABackendManager::new(&open_da_config).await.unwrap();
+
+        let keypair = RoochKeyPair::generate_secp256k1();
+        let tx_list = (0..64)
+            .map(|i| random_ledger_transaction_with_order(i as u64 + 1, &keypair))
+            .collect::<Vec<_>>();
+        let batch = DABatch::new(7, 1, 64, &tx_list, &keypair).unwrap();
+        backend.submit_batch(Arc::new(batch.clone())).await.unwrap();
+
+        let segment_ids = backend.list_segments(7).await.unwrap();
+        assert!(segment_ids.len() > 1);
+        for (i, segment_id) in segment_ids.iter().enumerate() {
+            assert_eq!(segment_id.chunk_id, 7);
+            assert_eq!(segment_id.segment_number, i as u64);
+        }
+
+        let act_batch = backend.get_batch(7).await.unwrap().unwrap();
+        assert_eq!(act_batch, batch);
+
+        assert!(backend.get_batch(8).await.unwrap().is_none());
+        assert!(backend.list_segments(8).await.unwrap().is_empty());
+    }
+}
@@ -4,7 +4,7 @@
 use crate::backend::openda::adapter::{AdapterSubmitStat, OpenDAAdapter};
 use async_trait::async_trait;
 use opendal::layers::{LoggingLayer, RetryLayer};
-use opendal::{Operator, Scheme};
+use opendal::{ErrorKind, Operator, Scheme};
 use rooch_config::da_config::OpenDAScheme;
 use rooch_types::da::segment::SegmentID;
 use std::collections::HashMap;
@@ -46,6 +46,29 @@ impl OpenDalAdapter {
         w.close().await?;
         Ok(())
     }
+
+    async fn get(&self, segment_id: SegmentID) -> anyhow::Result<Option<Vec<u8>>> {
+        let path = segment_id.to_string();
+        match self.operator.read(&path).await {
+            Ok(buf) => Ok(Some(buf.to_vec())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn list(&self, chunk_id: u128) -> anyhow::Result<Vec<SegmentID>> {
+        // The segments of a chunk share the `{chunk_id}_` prefix, list them only instead of the whole bucket
+        let prefix = format!("{}_", chunk_id);
+        let entries = self.operator.list(&prefix).await?;
+        let mut segment_ids = entries
+            .iter()
+            .filter(|entry| entry.metadata().is_file())
+            .filter_map(|entry| entry.name().parse::<SegmentID>().ok())
+            .filter(|segment_id| segment_id.chunk_id == chunk_id)
+            .collect::<Vec<_>>();
+        segment_ids.sort_by_key(|segment_id| segment_id.segment_number);
+        Ok(segment_ids)
+    }
 }
 
 #[async_trait]
@@ -66,4 +89,12 @@ impl OpenDAAdapter for OpenDalAdapter {
             Err(error) => Err(error),
         }
     }
+
+    async fn get_segment(&self, segment_id: SegmentID) -> anyhow::Result<Option<Vec<u8>>> {
+        self.get(segment_id).await
+    }
+
+    async fn list_segments(&self, chunk_id: u128) -> anyhow::Result<Vec<SegmentID>> {
+        self.list(chunk_id).await
+    }
 }
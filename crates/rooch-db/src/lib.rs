@@ -9,7 +9,7 @@ use moveos_common::utils::to_bytes;
 use moveos_store::config_store::STARTUP_INFO_KEY;
 use moveos_store::transaction_store::TransactionStore as TxExecutionInfoStore;
 use moveos_store::{
-    MoveOSStore, CONFIG_STARTUP_INFO_COLUMN_FAMILY_NAME,
+    MoveOSStore, CONFIG_STARTUP_INFO_COLUMN_FAMILY_NAME, STATE_NODE_STALE_INDEX_COLUMN_FAMILY_NAME,
     TRANSACTION_EXECUTION_INFO_COLUMN_FAMILY_NAME,
 };
 use moveos_types::access_path::AccessPath;
@@ -43,6 +43,10 @@ use rooch_types::indexer::state::{
 use rooch_types::sequencer::SequencerInfo;
 use tracing::error;
 
+pub mod metrics;
+pub mod pruner;
+pub mod snapshot;
+
 #[derive(Clone)]
 pub struct RoochDB {
     pub moveos_store: MoveOSStore,
@@ -65,8 +69,16 @@ impl RoochDB {
         let indexer_dir = config.get_indexer_dir();
         let moveos_store = MoveOSStore::new_with_instance(instance.clone(), registry)?;
         let rooch_store = RoochStore::new_with_instance(instance.clone(), registry)?;
-        let indexer_store = IndexerStore::new(indexer_dir.clone(), registry)?;
-        let indexer_reader = IndexerReader::new(indexer_dir, registry)?;
+        let (indexer_store, indexer_reader) = match config.get_indexer_db_url() {
+            Some(indexer_db_url) => (
+                IndexerStore::new_postgres(indexer_db_url, registry)?,
+                IndexerReader::new_postgres(indexer_db_url, registry)?,
+            ),
+            None => (
+                IndexerStore::new(indexer_dir.clone(), registry)?,
+                IndexerReader::new(indexer_dir, registry)?,
+            ),
+        };
 
         Ok(Self {
             moveos_store,
@@ -113,6 +125,11 @@ impl RoochDB {
         Ok(startup_info.map(|s| s.into_root_metadata()))
     }
 
+    /// Ensure the state after the tx order has not been pruned
+    pub fn ensure_state_not_pruned(&self, tx_order: u64) -> Result<()> {
+        self.moveos_store.ensure_state_not_pruned(tx_order)
+    }
+
     /// revert tx with these operations:
     /// 1. check preconditions
     /// 2. remove the tx + save previous tx as startup (atomic)
@@ -193,6 +210,7 @@ impl RoochDB {
 
         // ensure previous tx existed
         let previous_tx_order = tx_order - 1;
+        self.ensure_state_not_pruned(previous_tx_order)?;
         let previous_tx_hash_opt = self
             .rooch_store
             .transaction_store
@@ -254,11 +272,13 @@ impl RoochDB {
         write_batch.delete(to_bytes(&tx_order)?)?; // tx_order:tx_hash
         write_batch.delete(to_bytes(&tx_hash)?)?; // tx_hash:tx_execution_info
         write_batch.delete(to_bytes(&tx_order)?)?; // tx_order:tx_state_change_set
+        write_batch.delete(to_bytes(&tx_hash)?)?; // tx_hash:stale_state_nodes
         let mut cf_names = vec![
             TRANSACTION_COLUMN_FAMILY_NAME,
             TX_SEQUENCE_INFO_MAPPING_COLUMN_FAMILY_NAME,
             TRANSACTION_EXECUTION_INFO_COLUMN_FAMILY_NAME,
             STATE_CHANGE_SET_COLUMN_FAMILY_NAME,
+            STATE_NODE_STALE_INDEX_COLUMN_FAMILY_NAME,
         ];
 
         // save sequencer info and startup info for setup with previous tx values
@@ -0,0 +1,48 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use metrics::metrics_util::LATENCY_SEC_BUCKETS;
+use prometheus::{
+    register_histogram_with_registry, register_int_counter_with_registry,
+    register_int_gauge_with_registry, Histogram, IntCounter, IntGauge, Registry,
+};
+
+#[derive(Debug)]
+pub struct StatePrunerMetrics {
+    pub state_pruner_pruned_tx_order: IntGauge,
+    pub state_pruner_pruned_txs: IntCounter,
+    pub state_pruner_deleted_nodes: IntCounter,
+    pub state_pruner_batch_latency_seconds: Histogram,
+}
+
+impl StatePrunerMetrics {
+    pub(crate) fn new(registry: &Registry) -> Self {
+        StatePrunerMetrics {
+            state_pruner_pruned_tx_order: register_int_gauge_with_registry!(
+                "state_pruner_pruned_tx_order",
+                "The states before this tx order have been pruned",
+                registry,
+            )
+            .unwrap(),
+            state_pruner_pruned_txs: register_int_counter_with_registry!(
+                "state_pruner_pruned_txs",
+                "Total number of transactions whose stale state nodes have been pruned",
+                registry,
+            )
+            .unwrap(),
+            state_pruner_deleted_nodes: register_int_counter_with_registry!(
+                "state_pruner_deleted_nodes",
+                "Total number of stale state nodes deleted by the pruner",
+                registry,
+            )
+            .unwrap(),
+            state_pruner_batch_latency_seconds: register_histogram_with_registry!(
+                "state_pruner_batch_latency_seconds",
+                "State pruner batch latency in seconds",
+                LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
+        }
+    }
+}
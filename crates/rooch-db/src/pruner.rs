@@ -0,0 +1,151 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::metrics::StatePrunerMetrics;
+use crate::RoochDB;
+use anyhow::Result;
+use moveos_store::transaction_store::TransactionStore as TxExecutionInfoStore;
+use prometheus::Registry;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::transaction_store::TransactionStore;
+use std::sync::Arc;
+
+pub const DEFAULT_PRUNE_BATCH_SIZE: u64 = 1000;
+
+/// StatePruner deletes the state nodes which are not reachable from the recent state roots.
+/// The JMT records the stale nodes of every transaction, so the pruner only needs to walk the stale
+/// node indexes of the transactions before the last `keep_recent` transactions.
+///
+/// Only the state written after the stale node indexes and the node reference counts were introduced
+/// is pruned, the nodes written by an older version are kept forever. An existing database has to be
+/// re-synced or restored from a snapshot to reclaim the space of its historical states.
+#[derive(Clone)]
+pub struct StatePruner {
+    rooch_db: RoochDB,
+    keep_recent: u64,
+    batch_size: u64,
+    metrics: Arc<StatePrunerMetrics>,
+}
+
+impl StatePruner {
+    pub fn new(rooch_db: RoochDB, keep_recent: u64, registry: &Registry) -> Self {
+        Self {
+            rooch_db,
+            keep_recent,
+            batch_size: DEFAULT_PRUNE_BATCH_SIZE,
+            metrics: Arc::new(StatePrunerMetrics::new(registry)),
+        }
+    }
+
+    pub fn with_batch_size(mut self, batch_size: u64) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// The states before the returned tx order have been pruned.
+    pub fn pruned_tx_order(&self) -> Result<u64> {
+        Ok(self
+            .rooch_db
+            .moveos_store
+            .get_prune_store()
+            .get_pruned_tx_order()?
+            .unwrap_or(0))
+    }
+
+    /// Prune the stale state nodes, keep the states of the last `keep_recent` transactions.
+    /// Returns the pruned tx order and the number of deleted nodes.
+    pub fn prune(&self) -> Result<(u64, u64)> {
+        let last_order = match self.last_executed_tx_order()? {
+            Some(last_order) => last_order,
+            None => return Ok((0, 0)),
+        };
+        // Pruning the tx at `order` deletes the state at `order - 1`,
+        // so the states from `last_order + 1 - keep_recent` to `last_order` are kept.
+        let target_order = (last_order + 1).saturating_sub(self.keep_recent);
+        let mut pruned_order = self.pruned_tx_order()?;
+        let mut deleted_nodes = 0;
+        while pruned_order < target_order {
+            let end_order = target_order.min(pruned_order + self.batch_size);
+            deleted_nodes += self.prune_batch(pruned_order + 1, end_order)?;
+            pruned_order = end_order;
+        }
+        Ok((pruned_order, deleted_nodes))
+    }
+
+    /// The tx order of the last executed transaction. The sequencer may be ahead of the executor,
+    /// the states the executor will build on must be kept.
+    fn last_executed_tx_order(&self) -> Result<Option<u64>> {
+        let last_order = match self.rooch_db.rooch_store.get_sequencer_info()? {
+            Some(sequencer_info) => sequencer_info.last_order,
+            None => return Ok(None),
+        };
+        if !self.is_executed(0)? {
+            return Ok(None);
+        }
+        // The transactions are executed in order, so the executed transactions are a prefix
+        let (mut low, mut high) = (0, last_order);
+        while low < high {
+            let mid = low + (high - low + 1) / 2;
+            if self.is_executed(mid)? {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        Ok(Some(low))
+    }
+
+    fn is_executed(&self, tx_order: u64) -> Result<bool> {
+        let tx_hash = self
+            .rooch_db
+            .rooch_store
+            .get_tx_hashes(vec![tx_order])?
+            .pop()
+            .flatten();
+        match tx_hash {
+            Some(tx_hash) => Ok(self
+                .rooch_db
+                .moveos_store
+                .get_tx_execution_info(tx_hash)?
+                .is_some()),
+            None => Ok(false),
+        }
+    }
+
+    fn prune_batch(&self, start_order: u64, end_order: u64) -> Result<u64> {
+        let _timer = self
+            .metrics
+            .state_pruner_batch_latency_seconds
+            .start_timer();
+        let tx_orders = (start_order..=end_order).collect::<Vec<_>>();
+        let tx_hashes = self
+            .rooch_db
+            .rooch_store
+            .get_tx_hashes(tx_orders)?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        let moveos_store = &self.rooch_db.moveos_store;
+        let deleted_nodes = moveos_store.prune_stale_nodes(tx_hashes)?;
+        moveos_store
+            .get_prune_store()
+            .save_pruned_tx_order(end_order)?;
+
+        self.metrics
+            .state_pruner_pruned_tx_order
+            .set(end_order as i64);
+        self.metrics
+            .state_pruner_pruned_txs
+            .inc_by(end_order + 1 - start_order);
+        self.metrics
+            .state_pruner_deleted_nodes
+            .inc_by(deleted_nodes);
+        tracing::debug!(
+            "Pruned the stale state nodes of tx order [{}, {}], deleted nodes: {}",
+            start_order,
+            end_order,
+            deleted_nodes
+        );
+        Ok(deleted_nodes)
+    }
+}
@@ -0,0 +1,361 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::RoochDB;
+use accumulator::inmemory::InMemoryAccumulator;
+use accumulator::AccumulatorNode;
+use anyhow::{anyhow, ensure, Result};
+use moveos_common::utils::{from_bytes, to_bytes};
+use moveos_store::state_store::statedb::StateDBStore;
+use moveos_store::transaction_store::TransactionStore as TxExecutionInfoStore;
+use moveos_types::genesis_info::GenesisInfo;
+use moveos_types::h256::H256;
+use moveos_types::startup_info::StartupInfo;
+use moveos_types::transaction::TransactionExecutionInfo;
+use rooch_store::da_store::DAMetaStore;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::proposer_store::ProposerStore;
+use rooch_types::block::Block;
+use rooch_types::da::batch::BlockRange;
+use rooch_types::sequencer::SequencerInfo;
+use rooch_types::transaction::LedgerTransaction;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::{ErrorKind, Read, Write};
+
+/// The number of state nodes written to the store in one batch when importing a snapshot
+pub const SNAPSHOT_IMPORT_BATCH_SIZE: usize = 10000;
+
+/// The manifest of a state snapshot, it contains everything except the state tree nodes
+/// for a node to start from the snapshot.
+/// The snapshot file is a sequence of length-prefixed bcs records,
+/// the manifest is the first record and the (node_hash, node) records follow it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SnapshotManifest {
+    /// The tx order of the last transaction in the snapshot
+    pub tx_order: u64,
+    /// The last transaction, the node continues to sync the transactions after it
+    pub ledger_tx: LedgerTransaction,
+    pub execution_info: TransactionExecutionInfo,
+    pub genesis_info: GenesisInfo,
+    pub startup_info: StartupInfo,
+    pub sequencer_info: SequencerInfo,
+    /// The frozen subtree roots of the tx accumulator, it is enough to append the following transactions
+    pub accumulator_frontier: Vec<AccumulatorNode>,
+    /// The frozen subtree roots of the tx accumulator before the last transaction,
+    /// appending the last transaction to them must result in the tx accumulator of the snapshot.
+    pub pre_accumulator_frozen_subtree_roots: Vec<H256>,
+    /// The block ends with the last transaction, None if the snapshot is not taken at the end of a block
+    pub block: Option<Block>,
+}
+
+impl SnapshotManifest {
+    /// Verify the manifest is consistent and matches the `trusted_block`,
+    /// the state nodes are verified against the state root when importing.
+    pub fn verify(&self, trusted_block: &Block) -> Result<()> {
+        let mut ledger_tx = self.ledger_tx.clone();
+        ensure!(
+            ledger_tx.sequence_info.tx_order == self.tx_order
+                && self.sequencer_info.last_order == self.tx_order,
+            "Snapshot tx order mismatch, exp: {}, tx: {}, sequencer info: {}",
+            self.tx_order,
+            ledger_tx.sequence_info.tx_order,
+            self.sequencer_info.last_order
+        );
+        ensure!(
+            ledger_tx.tx_hash() == self.execution_info.tx_hash,
+            "Snapshot execution info does not belong to the tx: {}",
+            self.tx_order
+        );
+        ensure!(
+            self.startup_info.state_root == self.execution_info.state_root
+                && self.startup_info.size == self.execution_info.size,
+            "Snapshot startup info mismatch with the execution info of tx: {}",
+            self.tx_order
+        );
+
+        let accumulator_info = &self.sequencer_info.last_accumulator_info;
+        ensure!(
+            *accumulator_info == ledger_tx.sequence_info.tx_accumulator_info(),
+            "Snapshot accumulator info mismatch with the sequence info of tx: {}",
+            self.tx_order
+        );
+        ensure!(
+            accumulator_info.num_leaves == self.tx_order + 1,
+            "Snapshot accumulator leaves mismatch, exp: {}, act: {}",
+            self.tx_order + 1,
+            accumulator_info.num_leaves
+        );
+        ensure!(
+            self.accumulator_frontier
+                .iter()
+                .map(|node| node.hash())
+                .eq(accumulator_info.frozen_subtree_roots.iter().copied()),
+            "Snapshot accumulator frontier mismatch with the frozen subtree roots"
+        );
+        let accumulator = InMemoryAccumulator::new(
+            accumulator_info.frozen_subtree_roots.clone(),
+            accumulator_info.num_leaves,
+        )?;
+        ensure!(
+            accumulator.root_hash() == accumulator_info.accumulator_root,
+            "Snapshot accumulator root mismatch with the frozen subtree roots"
+        );
+        let pre_accumulator = InMemoryAccumulator::new(
+            self.pre_accumulator_frozen_subtree_roots.clone(),
+            self.tx_order,
+        )?;
+        let accumulator = pre_accumulator.append(&[ledger_tx.tx_hash()]);
+        ensure!(
+            accumulator.root_hash() == accumulator_info.accumulator_root
+                && *accumulator.frozen_subtree_roots() == accumulator_info.frozen_subtree_roots,
+            "Snapshot tx {} is not the last leaf of the tx accumulator",
+            self.tx_order
+        );
+
+        if let Some(block) = &self.block {
+            ensure!(
+                block == trusted_block,
+                "Snapshot block mismatch, exp block: {}, act block: {}",
+                trusted_block.block_number,
+                block.block_number
+            );
+        }
+        ensure!(
+            trusted_block.batch_size > 0 && trusted_block.batch_size <= self.tx_order,
+            "Invalid batch size of the trusted block: {}",
+            trusted_block.batch_size
+        );
+        ensure!(
+            self.startup_info.state_root == trusted_block.state_root,
+            "State root mismatch with the trusted block: {}, exp: {:?}, act: {:?}",
+            trusted_block.block_number,
+            trusted_block.state_root,
+            self.startup_info.state_root
+        );
+        ensure!(
+            accumulator_info.accumulator_root == trusted_block.tx_accumulator_root,
+            "Tx accumulator root mismatch with the trusted block: {}, exp: {:?}, act: {:?}",
+            trusted_block.block_number,
+            trusted_block.tx_accumulator_root,
+            accumulator_info.accumulator_root
+        );
+        Ok(())
+    }
+}
+
+fn write_record<W: Write, T: Serialize>(writer: &mut W, record: &T) -> Result<()> {
+    let bytes = to_bytes(record)?;
+    writer.write_all(&(bytes.len() as u32).to_le_bytes())?;
+    writer.write_all(&bytes)?;
+    Ok(())
+}
+
+// returns None at the end of the snapshot
+fn read_record<R: Read, T: DeserializeOwned>(reader: &mut R) -> Result<Option<T>> {
+    let mut len_bytes = [0u8; 4];
+    match reader.read_exact(&mut len_bytes) {
+        Ok(()) => {}
+        Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e.into()),
+    }
+    let mut bytes = vec![0u8; u32::from_le_bytes(len_bytes) as usize];
+    reader.read_exact(&mut bytes)?;
+    Ok(Some(from_bytes(&bytes)?))
+}
+
+impl RoochDB {
+    /// Export the state snapshot after the tx order, the default tx order is the end of the last proposed block,
+    /// or the last sequenced transaction if no block has been proposed.
+    /// Returns the manifest and the number of exported state nodes.
+    pub fn export_snapshot<W: Write>(
+        &self,
+        tx_order: Option<u64>,
+        writer: &mut W,
+    ) -> Result<(SnapshotManifest, u64)> {
+        let tx_order = match tx_order {
+            Some(tx_order) => tx_order,
+            None => self.default_snapshot_tx_order()?,
+        };
+        self.ensure_state_not_pruned(tx_order)?;
+        let manifest = self.snapshot_manifest(tx_order)?;
+        write_record(writer, &manifest)?;
+
+        let mut node_count = 0u64;
+        self.moveos_store.state_store.walk_nodes(
+            manifest.startup_info.state_root,
+            |hash, node| {
+                write_record(writer, &(hash, node))?;
+                node_count += 1;
+                Ok(())
+            },
+        )?;
+        writer.flush()?;
+        Ok((manifest, node_count))
+    }
+
+    /// Import the state snapshot into an empty db, the manifest is verified against the `trusted_block`
+    /// and every state node is checked by its hash, so the imported state is the one committed by the block.
+    /// After importing, the node continues to sync the transactions after the snapshot from DA or the sequencer.
+    /// Returns the manifest and the number of imported state nodes.
+    pub fn import_snapshot<R: Read>(
+        &self,
+        reader: &mut R,
+        trusted_block: &Block,
+    ) -> Result<(SnapshotManifest, u64)> {
+        let config_store = &self.moveos_store.config_store;
+        ensure!(
+            config_store.get_genesis()?.is_none()
+                && config_store.get_startup_info()?.is_none()
+                && self.rooch_store.get_sequencer_info()?.is_none(),
+            "The snapshot can only be imported into an empty db"
+        );
+        let manifest: SnapshotManifest =
+            read_record(reader)?.ok_or_else(|| anyhow!("Snapshot manifest not found"))?;
+        manifest.verify(trusted_block)?;
+
+        let node_store = &self.moveos_store.state_store.node_store;
+        let mut nodes = BTreeMap::new();
+        let mut node_count = 0u64;
+        while let Some((hash, node)) = read_record::<_, (H256, Vec<u8>)>(reader)? {
+            StateDBStore::check_node(hash, &node)?;
+            nodes.insert(hash, node);
+            node_count += 1;
+            if nodes.len() >= SNAPSHOT_IMPORT_BATCH_SIZE {
+                node_store.write_nodes(std::mem::take(&mut nodes))?;
+            }
+        }
+        node_store.write_nodes(nodes)?;
+        // every node matches its hash, so the state is the trusted one if the state tree is complete
+        self.moveos_store
+            .state_store
+            .walk_nodes(manifest.startup_info.state_root, |_, _| Ok(()))?;
+
+        self.moveos_store
+            .save_tx_execution_info(manifest.execution_info.clone())?;
+        // the states before the snapshot are not available
+        self.moveos_store
+            .get_prune_store()
+            .save_pruned_tx_order(manifest.tx_order)?;
+
+        // the blocks after the trusted block are derived from DA or proposed by the node itself
+        let block_range = BlockRange {
+            block_number: trusted_block.block_number,
+            tx_order_start: manifest.tx_order + 1 - trusted_block.batch_size,
+            tx_order_end: manifest.tx_order,
+        };
+        let da_meta_store = self.rooch_store.get_da_meta_store();
+        match da_meta_store.get_last_block_number()? {
+            None => {
+                da_meta_store.init_with_submitted_block(block_range, trusted_block.batch_hash)?
+            }
+            // initialized by a failed import of the same snapshot
+            Some(block_number) => ensure!(
+                block_number == trusted_block.block_number,
+                "DA meta has been initialized, last block number: {}",
+                block_number
+            ),
+        }
+        self.rooch_store
+            .get_proposer_store()
+            .save_proposed_block(trusted_block.clone())?;
+
+        // save the sequencer info, the genesis and the startup info at last,
+        // roll them back if the import fails so that the import can be retried on the same db
+        let mut ledger_tx = manifest.ledger_tx.clone();
+        let tx_hash = ledger_tx.tx_hash();
+        self.rooch_store.save_sequenced_tx(
+            tx_hash,
+            ledger_tx,
+            manifest.sequencer_info.clone(),
+            Some(manifest.accumulator_frontier.clone()),
+            true,
+        )?;
+        let result = config_store
+            .save_genesis(manifest.genesis_info.clone())
+            .and_then(|_| config_store.save_startup_info(manifest.startup_info.clone()));
+        if let Err(e) = result {
+            self.rooch_store.remove_sequencer_info()?;
+            config_store.remove_genesis()?;
+            return Err(e);
+        }
+        Ok((manifest, node_count))
+    }
+
+    fn default_snapshot_tx_order(&self) -> Result<u64> {
+        if let Some(last_proposed) = self.rooch_store.get_last_proposed()? {
+            let block_state = self.rooch_store.get_block_state(last_proposed)?;
+            return Ok(block_state.block_range.tx_order_end);
+        }
+        let sequencer_info = self
+            .rooch_store
+            .get_sequencer_info()?
+            .ok_or_else(|| anyhow!("Sequencer info not found"))?;
+        Ok(sequencer_info.last_order)
+    }
+
+    fn snapshot_manifest(&self, tx_order: u64) -> Result<SnapshotManifest> {
+        let mut ledger_tx = self
+            .rooch_store
+            .get_transaction_store()
+            .get_tx_by_order(tx_order)?
+            .ok_or_else(|| anyhow!("LedgerTransaction not found for order: {}", tx_order))?;
+        let tx_hash = ledger_tx.tx_hash();
+        let execution_info = self
+            .moveos_store
+            .get_tx_execution_info(tx_hash)?
+            .ok_or_else(|| anyhow!("TransactionExecutionInfo not found for order: {}", tx_order))?;
+        let genesis_info = self
+            .moveos_store
+            .config_store
+            .get_genesis()?
+            .ok_or_else(|| anyhow!("Genesis info not found"))?;
+        let startup_info = StartupInfo::new(execution_info.state_root, execution_info.size);
+
+        let accumulator_info = ledger_tx.sequence_info.tx_accumulator_info();
+        let accumulator_store = self.rooch_store.get_transaction_accumulator_store();
+        let accumulator_frontier = accumulator_info
+            .frozen_subtree_roots
+            .iter()
+            .map(|hash| {
+                accumulator_store
+                    .get_node(*hash)?
+                    .ok_or_else(|| anyhow!("Tx accumulator node not found: {:?}", hash))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let sequencer_info = SequencerInfo::new(tx_order, accumulator_info);
+        let pre_accumulator_frozen_subtree_roots = match tx_order.checked_sub(1) {
+            Some(pre_tx_order) => {
+                self.rooch_store
+                    .get_transaction_store()
+                    .get_tx_by_order(pre_tx_order)?
+                    .ok_or_else(|| {
+                        anyhow!("LedgerTransaction not found for order: {}", pre_tx_order)
+                    })?
+                    .sequence_info
+                    .tx_accumulator_frozen_subtree_roots
+            }
+            None => vec![],
+        };
+
+        let block = match self.rooch_store.find_block_state_by_tx_order(tx_order)? {
+            Some(block_state) if block_state.block_range.tx_order_end == tx_order => self
+                .rooch_store
+                .get_proposed_block(block_state.block_range.block_number)?,
+            _ => None,
+        };
+
+        Ok(SnapshotManifest {
+            tx_order,
+            ledger_tx,
+            execution_info,
+            genesis_info,
+            startup_info,
+            sequencer_info,
+            accumulator_frontier,
+            pre_accumulator_frozen_subtree_roots,
+            block,
+        })
+    }
+}
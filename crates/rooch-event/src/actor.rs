@@ -1,7 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::event::{GasUpgradeEvent, ServiceStatusEvent};
+use crate::event::{GasUpgradeEvent, ServiceStatusEvent, TransactionExecutedEvent};
 use async_trait::async_trait;
 use coerce::actor::context::ActorContext;
 use coerce::actor::message::{Handler, Message};
@@ -68,6 +68,28 @@ impl Handler<UpdateServiceStatusMessage> for EventActor {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct TransactionExecutedMessage {
+    pub event: TransactionExecutedEvent,
+}
+
+impl Message for TransactionExecutedMessage {
+    type Result = anyhow::Result<()>;
+}
+
+#[async_trait]
+impl Handler<TransactionExecutedMessage> for EventActor {
+    async fn handle(
+        &mut self,
+        message: TransactionExecutedMessage,
+        _ctx: &mut ActorContext,
+    ) -> anyhow::Result<()> {
+        self.event_bus
+            .notify::<TransactionExecutedEvent>(message.event)?;
+        Ok(())
+    }
+}
+
 pub struct EventActorSubscribeMessage<T: Send + Sync + 'static> {
     event_type: T,
     subscriber: String,
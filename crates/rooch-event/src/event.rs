@@ -1,7 +1,11 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use moveos_types::moveos_std::event::Event;
+use moveos_types::moveos_std::tx_context::TxContext;
+use moveos_types::transaction::{MoveAction, TransactionExecutionInfo};
 use rooch_types::service_status::ServiceStatus;
+use rooch_types::transaction::LedgerTransaction;
 
 #[derive(Default, Clone, Debug)]
 pub struct GasUpgradeEvent {}
@@ -10,3 +14,14 @@ pub struct GasUpgradeEvent {}
 pub struct ServiceStatusEvent {
     pub status: ServiceStatus,
 }
+
+/// The event is emitted after a transaction is executed,
+/// it carries the data required to build the indexer transaction and events.
+#[derive(Clone, Debug)]
+pub struct TransactionExecutedEvent {
+    pub ledger_transaction: LedgerTransaction,
+    pub execution_info: TransactionExecutionInfo,
+    pub move_action: MoveAction,
+    pub tx_context: TxContext,
+    pub events: Vec<Event>,
+}
@@ -3,49 +3,56 @@
 
 use super::messages::{
     ConvertL2TransactionData, DryRunTransactionMessage, DryRunTransactionResult,
-    ExecuteTransactionMessage, ExecuteTransactionResult, GetRootMessage, SaveStateChangeSetMessage,
-    ValidateL1BlockMessage, ValidateL1TxMessage, ValidateL2TxMessage,
+    EstimateGasMessage, ExecuteTransactionBatchMessage, ExecuteTransactionMessage,
+    ExecuteTransactionResult, GasEstimateResult, GetRootMessage, SaveStateChangeSetMessage,
+    TraceTransactionMessage, ValidateL1BlockMessage, ValidateL1TxMessage, ValidateL2TxMessage,
 };
 use crate::metrics::ExecutorMetrics;
 use anyhow::Result;
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor, LocalActorRef};
 use function_name::named;
+use move_core_types::account_address::AccountAddress;
 use move_core_types::vm_status::VMStatus;
 use moveos::moveos::{MoveOS, MoveOSConfig};
+use moveos::trace::TransactionTrace;
 use moveos::vm::vm_status_explainer::explain_vm_status;
-use moveos_eventbus::bus::EventData;
-use moveos_store::MoveOSStore;
+use moveos_store::{load_feature_store_object, MoveOSStore};
 use moveos_types::function_return_value::FunctionResult;
 use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::move_std::option::MoveOption;
+use moveos_types::moveos_std::gas_payment_account::GasPaymentAccount;
+use moveos_types::moveos_std::gas_schedule::GasScheduleConfig;
 use moveos_types::moveos_std::object::ObjectMeta;
 use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::moveos_std::tx_meta::TxMeta;
 use moveos_types::state::{ObjectState, StateChangeSetExt};
+use moveos_types::state_override::ObjectOverride;
 use moveos_types::state_resolver::RootObjectResolver;
 use moveos_types::transaction::{FunctionCall, MoveOSTransaction, VerifiedMoveAction};
 use moveos_types::transaction::{MoveAction, VerifiedMoveOSTransaction};
 use prometheus::Registry;
-use rooch_event::actor::{EventActor, EventActorSubscribeMessage, GasUpgradeMessage};
-use rooch_event::event::GasUpgradeEvent;
+use rooch_event::actor::{EventActor, GasUpgradeMessage};
 use rooch_genesis::FrameworksGasParameters;
 use rooch_store::state_store::StateStore;
 use rooch_store::RoochStore;
 use rooch_types::address::{BitcoinAddress, MultiChainAddress};
 // use rooch_types::bitcoin::transaction_validator::TransactionValidator as L1TransactionValidator;
 use rooch_types::bitcoin::BitcoinModule;
+use rooch_types::framework::account_coin_store::AccountCoinStoreModule;
 use rooch_types::framework::auth_validator::{
     AuthValidatorCaller, BuiltinAuthValidator, TxValidateResult,
 };
 use rooch_types::framework::ethereum::EthereumModule;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::framework::transaction_fee::TransactionFeeModule;
 use rooch_types::framework::transaction_validator::TransactionValidator;
 use rooch_types::framework::{system_post_execute_functions, system_pre_execute_functions};
 use rooch_types::multichain_id::RoochMultiChainID;
 use rooch_types::transaction::authenticator::AUTH_PAYLOAD_SIZE;
 use rooch_types::transaction::{
-    AuthenticatorInfo, L1Block, L1BlockWithBody, L1Transaction, RoochTransaction,
-    RoochTransactionData,
+    AuthenticatorInfo, L1Block, L1BlockWithBody, L1Transaction, LedgerTransaction, LedgerTxData,
+    RoochTransaction, RoochTransactionData,
 };
 use std::str::FromStr;
 use std::sync::Arc;
@@ -61,6 +68,26 @@ pub struct ExecutorActor {
 
 type ValidateAuthenticatorResult = Result<TxValidateResult, VMStatus>;
 
+/// Create the MoveOS with the gas parameters in effect at the root, and the gas parameters of the pending gas schedule,
+/// so the natives switch to the pending gas schedule with the cost table at the activation time.
+pub(crate) fn new_moveos(moveos_store: &MoveOSStore, root: &ObjectMeta) -> Result<MoveOS> {
+    let resolver = RootObjectResolver::new(root.clone(), moveos_store);
+    let gas_parameters = FrameworksGasParameters::load_from_chain(&resolver)?;
+    let mut moveos = MoveOS::new(
+        moveos_store.clone(),
+        gas_parameters.all_natives(),
+        MoveOSConfig::default(),
+        system_pre_execute_functions(),
+        system_post_execute_functions(),
+    )?;
+    if let Some((activation_time, pending_gas_parameters)) =
+        FrameworksGasParameters::load_pending_from_chain(&resolver)?
+    {
+        moveos.set_pending_natives(activation_time, pending_gas_parameters.all_natives())?;
+    }
+    Ok(moveos)
+}
+
 impl ExecutorActor {
     pub fn new(
         root: ObjectMeta,
@@ -69,16 +96,7 @@ impl ExecutorActor {
         registry: &Registry,
         event_actor: Option<LocalActorRef<EventActor>>,
     ) -> Result<Self> {
-        let resolver = RootObjectResolver::new(root.clone(), &moveos_store);
-        let gas_parameters = FrameworksGasParameters::load_from_chain(&resolver)?;
-
-        let moveos = MoveOS::new(
-            moveos_store.clone(),
-            gas_parameters.all_natives(),
-            MoveOSConfig::default(),
-            system_pre_execute_functions(),
-            system_post_execute_functions(),
-        )?;
+        let moveos = new_moveos(&moveos_store, &root)?;
 
         Ok(Self {
             root,
@@ -90,20 +108,6 @@ impl ExecutorActor {
         })
     }
 
-    pub async fn subscribe_event(
-        &self,
-        event_actor_ref: LocalActorRef<EventActor>,
-        executor_actor_ref: LocalActorRef<ExecutorActor>,
-    ) {
-        let gas_upgrade_event = GasUpgradeEvent::default();
-        let actor_subscribe_message = EventActorSubscribeMessage::new(
-            gas_upgrade_event,
-            "executor".to_string(),
-            Box::new(executor_actor_ref),
-        );
-        let _ = event_actor_ref.send(actor_subscribe_message).await;
-    }
-
     pub fn get_rooch_store(&self) -> RoochStore {
         self.rooch_store.clone()
     }
@@ -126,10 +130,13 @@ impl ExecutorActor {
             .start_timer();
         let tx_hash = tx.ctx.tx_hash();
         let size = tx.ctx.tx_size;
+        let gas_payer = tx.ctx.gas_payment_account()?;
         let (raw_output, _) = self.moveos.execute_only(tx)?;
         let is_gas_upgrade = raw_output.is_gas_upgrade;
 
-        let (output, execution_info) = self.moveos_store.handle_tx_output(tx_hash, raw_output)?;
+        let (output, execution_info) = self
+            .moveos_store
+            .handle_tx_output(tx_hash, gas_payer, raw_output)?;
 
         self.root = execution_info.root_metadata();
         self.metrics
@@ -138,9 +145,7 @@ impl ExecutorActor {
             .observe(size as f64);
 
         if is_gas_upgrade {
-            if let Some(event_actor) = self.event_actor.clone() {
-                let _ = event_actor.notify(GasUpgradeMessage {});
-            }
+            self.reload_moveos()?;
         }
 
         Ok(ExecuteTransactionResult {
@@ -149,21 +154,183 @@ impl ExecutorActor {
         })
     }
 
+    /// Execute the sequenced transactions optimistically in parallel, the result is the same as executing them one by one.
+    /// The transactions should be validated at the current root.
+    #[named]
+    pub fn execute_batch(
+        &mut self,
+        txs: Vec<VerifiedMoveOSTransaction>,
+    ) -> Result<Vec<ExecuteTransactionResult>> {
+        let fn_name = function_name!();
+        let _timer = self
+            .metrics
+            .executor_execute_tx_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let tx_infos = txs
+            .iter()
+            .map(|tx| {
+                Ok((
+                    tx.ctx.tx_hash(),
+                    tx.ctx.tx_size,
+                    tx.ctx.gas_payment_account()?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut results = Vec::with_capacity(txs.len());
+        let mut reexecuted = 0;
+        // The parallel execution stops after a gas schedule update, the MoveOS is reloaded before the remaining transactions,
+        // so the natives use the gas parameters in effect.
+        let execute_result = loop {
+            let offset = results.len();
+            let mut is_gas_upgrade = false;
+            let moveos_store = &self.moveos_store;
+            let metrics = &self.metrics;
+            let result = self.moveos.execute_parallel(
+                self.root.clone(),
+                &txs[offset..],
+                |index, raw_output| {
+                    let (tx_hash, size, gas_payer) = tx_infos[offset + index];
+                    is_gas_upgrade |= raw_output.is_gas_upgrade;
+                    let (output, execution_info) =
+                        moveos_store.handle_tx_output(tx_hash, gas_payer, raw_output)?;
+                    let root = execution_info.root_metadata();
+                    metrics
+                        .executor_execute_tx_bytes
+                        .with_label_values(&[fn_name])
+                        .observe(size as f64);
+                    results.push(ExecuteTransactionResult {
+                        output,
+                        transaction_info: execution_info,
+                    });
+                    Ok(root)
+                },
+            );
+
+            // The committed transactions are kept even if the batch failed.
+            if let Some(result) = results.last() {
+                self.root = result.transaction_info.root_metadata();
+            }
+            if is_gas_upgrade {
+                self.reload_moveos()?;
+            }
+            match result {
+                Ok(count) => reexecuted += count,
+                Err(e) => break Err(e),
+            }
+            if !is_gas_upgrade || results.len() == txs.len() {
+                break Ok(());
+            }
+        };
+        self.metrics
+            .executor_parallel_reexecute_tx_count
+            .inc_by(reexecuted as u64);
+        execute_result?;
+        Ok(results)
+    }
+
     #[named]
-    pub fn dry_run(&mut self, tx: VerifiedMoveOSTransaction) -> Result<DryRunTransactionResult> {
+    pub fn dry_run(
+        &mut self,
+        tx: VerifiedMoveOSTransaction,
+        state_overrides: Vec<ObjectOverride>,
+    ) -> Result<DryRunTransactionResult> {
         let fn_name = function_name!();
         let _timer = self
             .metrics
             .executor_execute_tx_latency_seconds
             .with_label_values(&[fn_name])
             .start_timer();
-        let (raw_output, vm_error_info) = self.moveos.execute_only(tx)?;
+        let root = tx.root.clone();
+        let tx_size = tx.ctx.tx_size;
+        let (raw_output, vm_error_info) = if state_overrides.is_empty() {
+            self.moveos.execute_only(tx)?
+        } else {
+            self.moveos
+                .execute_only_with_overrides(tx, state_overrides)?
+        };
+        let storage_gas = self.moveos.storage_gas(&root, tx_size, &raw_output)?;
         Ok(DryRunTransactionResult {
             raw_output,
             vm_error_info,
+            storage_gas,
+        })
+    }
+
+    /// Estimate the gas of the transaction data, the transaction does not need to be signed.
+    /// The max gas amount is capped by the RGas balance of the gas payer at the gas price,
+    /// the gas payer is the sender if the transaction is not sponsored.
+    pub fn estimate_gas(
+        &mut self,
+        mut tx_data: RoochTransactionData,
+        gas_payer: Option<AccountAddress>,
+    ) -> Result<GasEstimateResult> {
+        let payer = gas_payer.unwrap_or_else(|| tx_data.sender.into());
+        let gas_price = self
+            .as_module_binding::<TransactionFeeModule>()
+            .gas_price()?;
+        let balance = self
+            .as_module_binding::<AccountCoinStoreModule>()
+            .balance(payer, RGas::struct_tag())?;
+        let max_gas_amount = GasScheduleConfig::max_gas_amount_for_balance(balance, gas_price);
+        tx_data.max_gas_amount = max_gas_amount;
+        let tx = self.convert_to_verified_tx_for_dry_run_with_gas_payer(tx_data, gas_payer)?;
+        let dry_run_result = self.dry_run(tx, vec![])?;
+        Ok(GasEstimateResult {
+            dry_run_result,
+            gas_price,
+            max_gas_amount,
         })
     }
 
+    /// Re-execute the sequenced transaction at the state root before it is executed, and trace the calls.
+    #[named]
+    pub fn trace_transaction(
+        &mut self,
+        root: ObjectMeta,
+        tx: LedgerTransaction,
+        l1_block: Option<L1BlockWithBody>,
+    ) -> Result<TransactionTrace> {
+        let fn_name = function_name!();
+        let _timer = self
+            .metrics
+            .executor_execute_tx_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let LedgerTransaction {
+            data,
+            sequence_info,
+        } = tx;
+        // The pre-state of the transaction is required to re-execute it
+        self.moveos_store
+            .ensure_state_not_pruned(sequence_info.tx_order.saturating_sub(1))?;
+        self.moveos_store
+            .ensure_state_root_not_pruned(root.state_root())?;
+        // The transaction is validated at the pre-state root, then switch back to the latest root.
+        let latest_root = std::mem::replace(&mut self.root, root);
+        let result = match data {
+            LedgerTxData::L1Block(block) => l1_block
+                .filter(|l1_block| l1_block.block == block)
+                .ok_or_else(|| anyhow::anyhow!("The body of the L1 block {:?} is required", block))
+                .and_then(|l1_block| self.validate_l1_block(l1_block)),
+            LedgerTxData::L1Tx(l1_tx) => self.validate_l1_tx(l1_tx),
+            LedgerTxData::L2Tx(l2_tx) => self.validate_l2_tx(l2_tx),
+        };
+        self.root = latest_root;
+        let mut moveos_tx = result?;
+        moveos_tx.ctx.add(sequence_info)?;
+        self.moveos.trace(moveos_tx)
+    }
+
+    /// Whether the ethereum receipts feature is enabled at the current root
+    pub fn ethereum_receipts_enabled(&self) -> bool {
+        let resolver = RootObjectResolver::new(self.root.clone(), &self.moveos_store);
+        load_feature_store_object(&resolver)
+            .map(|feature_store| feature_store.has_ethereum_receipts_feature())
+            .unwrap_or(false)
+    }
+
     #[named]
     pub fn validate_l1_block(
         &self,
@@ -206,8 +373,15 @@ impl ExecutorActor {
                 ))
             }
             RoochMultiChainID::Ether => {
+                // The blocks sequenced before the ethereum receipts feature is enabled only carry the block header,
+                // so they are replayed in the same way as they were executed.
+                let call = if self.ethereum_receipts_enabled() {
+                    EthereumModule::create_execute_l1_block_with_receipts_call_bytes(block_body)
+                } else {
+                    EthereumModule::create_execute_l1_block_call_bytes(block_body)
+                };
                 let action = VerifiedMoveAction::Function {
-                    call: EthereumModule::create_execute_l1_block_call_bytes(block_body),
+                    call,
                     bypass_visibility: true,
                 };
                 Ok(VerifiedMoveOSTransaction::new(
@@ -284,6 +458,7 @@ impl ExecutorActor {
         tracing::debug!("executor validate_l2_tx: {:?}, sender: {}", tx_hash, sender);
 
         let authenticator = tx.authenticator_info();
+        let gas_payer = authenticator.authenticator.gas_payer()?;
         let mut moveos_tx: MoveOSTransaction = tx.into_moveos_transaction(self.root.clone());
         let tx_size = moveos_tx.ctx.tx_size;
         let tx_result = self.validate_authenticator(&moveos_tx.ctx, authenticator);
@@ -295,6 +470,13 @@ impl ExecutorActor {
                         .ctx
                         .add(tx_validate_result)
                         .expect("add tx_validate_result failed");
+                    // The gas payer of the sponsored transaction is validated by the sponsor validator
+                    if let Some(gas_payer) = gas_payer {
+                        moveos_tx
+                            .ctx
+                            .add(GasPaymentAccount::new(gas_payer))
+                            .expect("add gas_payment_account failed");
+                    }
 
                     let verify_result = self.moveos.verify(moveos_tx);
                     match verify_result {
@@ -360,9 +542,12 @@ impl ExecutorActor {
                 let auth_validator_option = tx_validate_result.auth_validator();
                 match auth_validator_option {
                     Some(auth_validator) => {
+                        // The third-party auth validator only validates the sender's authenticator
+                        let sender_authenticator =
+                            authenticator.authenticator.sender_authenticator()?;
                         let auth_validator_caller = AuthValidatorCaller::new(self, auth_validator);
                         let auth_validator_function_result = auth_validator_caller
-                            .validate(ctx, authenticator.authenticator.payload)?
+                            .validate(ctx, sender_authenticator.payload)?
                             .into_result();
                         match auth_validator_function_result {
                             Ok(_) => Ok(tx_validate_result),
@@ -381,6 +566,15 @@ impl ExecutorActor {
     pub fn convert_to_verified_tx_for_dry_run(
         &self,
         tx_data: RoochTransactionData,
+    ) -> Result<VerifiedMoveOSTransaction> {
+        self.convert_to_verified_tx_for_dry_run_with_gas_payer(tx_data, None)
+    }
+
+    /// Convert the transaction data for the dry run, the gas is paid by the gas payer if it is the sponsored transaction.
+    pub fn convert_to_verified_tx_for_dry_run_with_gas_payer(
+        &self,
+        tx_data: RoochTransactionData,
+        gas_payer: Option<AccountAddress>,
     ) -> Result<VerifiedMoveOSTransaction> {
         let root = self.root.clone();
 
@@ -414,6 +608,9 @@ impl ExecutorActor {
         };
 
         tx_ctx.add(dummy_result)?;
+        if let Some(gas_payer) = gas_payer {
+            tx_ctx.add(GasPaymentAccount::new(gas_payer))?;
+        }
 
         let verified_action = match tx_data.action {
             MoveAction::Script(script_call) => VerifiedMoveAction::Script { call: script_call },
@@ -425,6 +622,7 @@ impl ExecutorActor {
                 module_bundle,
                 init_function_modules: vec![],
             },
+            MoveAction::Batch(calls) => VerifiedMoveAction::Batch { calls },
         };
 
         Ok(VerifiedMoveOSTransaction::new(
@@ -439,6 +637,17 @@ impl ExecutorActor {
         self.moveos.flush_module_cache(is_upgrade)
     }
 
+    /// Reload the MoveOS with the gas schedule at the current root before executing the next transaction,
+    /// and notify the reader executor to reload.
+    fn reload_moveos(&mut self) -> Result<()> {
+        tracing::info!("ExecutorActor: Reload the MoveOS instance...");
+        self.moveos = new_moveos(&self.moveos_store, &self.root)?;
+        if let Some(event_actor) = self.event_actor.clone() {
+            let _ = event_actor.notify(GasUpgradeMessage {});
+        }
+        Ok(())
+    }
+
     pub fn save_state_change_set(
         &mut self,
         tx_order: u64,
@@ -449,15 +658,7 @@ impl ExecutorActor {
     }
 }
 
-#[async_trait]
-impl Actor for ExecutorActor {
-    async fn started(&mut self, ctx: &mut ActorContext) {
-        let local_actor_ref: LocalActorRef<Self> = ctx.actor_ref();
-        if let Some(event_actor) = self.event_actor.clone() {
-            let _ = self.subscribe_event(event_actor, local_actor_ref).await;
-        }
-    }
-}
+impl Actor for ExecutorActor {}
 
 #[async_trait]
 impl Handler<ValidateL2TxMessage> for ExecutorActor {
@@ -503,6 +704,17 @@ impl Handler<ExecuteTransactionMessage> for ExecutorActor {
     }
 }
 
+#[async_trait]
+impl Handler<ExecuteTransactionBatchMessage> for ExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: ExecuteTransactionBatchMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<ExecuteTransactionResult>> {
+        self.execute_batch(msg.txs)
+    }
+}
+
 #[async_trait]
 impl Handler<GetRootMessage> for ExecutorActor {
     async fn handle(
@@ -534,34 +746,24 @@ impl MoveFunctionCaller for ExecutorActor {
 }
 
 #[async_trait]
-impl Handler<EventData> for ExecutorActor {
-    async fn handle(&mut self, message: EventData, _ctx: &mut ActorContext) -> Result<()> {
-        if let Ok(_gas_upgrade_msg) = message.data.downcast::<GasUpgradeEvent>() {
-            tracing::info!("ExecutorActor: Reload the MoveOS instance...");
-
-            let resolver = RootObjectResolver::new(self.root.clone(), &self.moveos_store);
-            let gas_parameters = FrameworksGasParameters::load_from_chain(&resolver)?;
-
-            self.moveos = MoveOS::new(
-                self.moveos_store.clone(),
-                gas_parameters.all_natives(),
-                MoveOSConfig::default(),
-                system_pre_execute_functions(),
-                system_post_execute_functions(),
-            )?;
-        }
-        Ok(())
+impl Handler<ConvertL2TransactionData> for ExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: ConvertL2TransactionData,
+        _ctx: &mut ActorContext,
+    ) -> Result<VerifiedMoveOSTransaction> {
+        self.convert_to_verified_tx_for_dry_run_with_gas_payer(msg.tx_data, msg.gas_payer)
     }
 }
 
 #[async_trait]
-impl Handler<ConvertL2TransactionData> for ExecutorActor {
+impl Handler<EstimateGasMessage> for ExecutorActor {
     async fn handle(
         &mut self,
-        msg: ConvertL2TransactionData,
+        msg: EstimateGasMessage,
         _ctx: &mut ActorContext,
-    ) -> Result<VerifiedMoveOSTransaction> {
-        self.convert_to_verified_tx_for_dry_run(msg.tx_data)
+    ) -> Result<GasEstimateResult> {
+        self.estimate_gas(msg.tx_data, msg.gas_payer)
     }
 }
 
@@ -572,6 +774,17 @@ impl Handler<DryRunTransactionMessage> for ExecutorActor {
         msg: DryRunTransactionMessage,
         _ctx: &mut ActorContext,
     ) -> Result<DryRunTransactionResult> {
-        self.dry_run(msg.tx)
+        self.dry_run(msg.tx, msg.state_overrides)
+    }
+}
+
+#[async_trait]
+impl Handler<TraceTransactionMessage> for ExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: TraceTransactionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<TransactionTrace> {
+        self.trace_transaction(msg.root, msg.tx, msg.l1_block)
     }
 }
@@ -5,12 +5,16 @@ use anyhow::Result;
 use coerce::actor::message::Message;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::StructTag;
+use moveos::gas::table::StorageGasStatement;
+use moveos::trace::TransactionTrace;
 use moveos_types::access_path::AccessPath;
 use moveos_types::function_return_value::AnnotatedFunctionResult;
 use moveos_types::h256::H256;
 use moveos_types::moveos_std::event::{AnnotatedEvent, Event, EventID};
 use moveos_types::moveos_std::object::ObjectMeta;
 use moveos_types::state::{AnnotatedState, FieldKey, ObjectState, StateChangeSetExt};
+use moveos_types::state_override::ObjectOverride;
+use moveos_types::state_proof::StateProof;
 use moveos_types::state_resolver::{AnnotatedStateKV, StateKV};
 use moveos_types::transaction::TransactionExecutionInfo;
 use moveos_types::transaction::TransactionOutput;
@@ -18,7 +22,7 @@ use moveos_types::transaction::VerifiedMoveOSTransaction;
 use moveos_types::transaction::{FunctionCall, RawTransactionOutput, VMErrorInfo};
 use rooch_types::address::MultiChainAddress;
 use rooch_types::transaction::{
-    L1BlockWithBody, L1Transaction, RoochTransaction, RoochTransactionData,
+    L1BlockWithBody, L1Transaction, LedgerTransaction, RoochTransaction, RoochTransactionData,
 };
 use serde::{Deserialize, Serialize};
 
@@ -64,8 +68,21 @@ impl Message for ExecuteTransactionMessage {
     type Result = Result<ExecuteTransactionResult>;
 }
 
+/// Execute a batch of sequenced transactions in parallel, the transactions are committed in order.
+#[derive(Debug)]
+pub struct ExecuteTransactionBatchMessage {
+    pub txs: Vec<VerifiedMoveOSTransaction>,
+}
+
+impl Message for ExecuteTransactionBatchMessage {
+    type Result = Result<Vec<ExecuteTransactionResult>>;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ExecuteViewFunctionMessage {
+    /// Execute the function at the historical root, the latest root is used if it is None.
+    /// The root carries the real object count of the state, it is loaded from the execution info.
+    pub root: Option<ObjectMeta>,
     pub call: FunctionCall,
 }
 
@@ -83,6 +100,17 @@ impl Message for StatesMessage {
     type Result = Result<Vec<Option<ObjectState>>>;
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StateProofsMessage {
+    pub state_root: Option<H256>,
+    pub access_path: AccessPath,
+}
+
+impl Message for StateProofsMessage {
+    /// The state root which the proofs are against, and the proof of each state
+    type Result = Result<(H256, Vec<StateProof>)>;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ResolveMessage {
     pub address: MultiChainAddress,
@@ -225,6 +253,8 @@ impl Message for CheckStateChangeSetsMessage {
 #[derive(Debug)]
 pub struct ConvertL2TransactionData {
     pub tx_data: RoochTransactionData,
+    /// The gas payer of the sponsored transaction, the sender pays the gas if None
+    pub gas_payer: Option<AccountAddress>,
 }
 impl Message for ConvertL2TransactionData {
     type Result = Result<VerifiedMoveOSTransaction>;
@@ -233,6 +263,8 @@ impl Message for ConvertL2TransactionData {
 #[derive(Debug)]
 pub struct DryRunTransactionMessage {
     pub tx: VerifiedMoveOSTransaction,
+    /// The object overrides applied on top of the state, only for the dry run
+    pub state_overrides: Vec<ObjectOverride>,
 }
 impl Message for DryRunTransactionMessage {
     type Result = Result<DryRunTransactionResult>;
@@ -242,4 +274,39 @@ impl Message for DryRunTransactionMessage {
 pub struct DryRunTransactionResult {
     pub raw_output: RawTransactionOutput,
     pub vm_error_info: Option<VMErrorInfo>,
+    /// The storage gas of the transaction, only the storage gas of the transaction size is included in the `raw_output.gas_used`
+    pub storage_gas: StorageGasStatement,
+}
+
+#[derive(Debug)]
+pub struct EstimateGasMessage {
+    pub tx_data: RoochTransactionData,
+    /// The gas payer of the sponsored transaction, the sender pays the gas if None
+    pub gas_payer: Option<AccountAddress>,
+}
+
+impl Message for EstimateGasMessage {
+    type Result = Result<GasEstimateResult>;
+}
+
+#[derive(Debug)]
+pub struct GasEstimateResult {
+    pub dry_run_result: DryRunTransactionResult,
+    /// The gas price, the transaction fee is the gas used multiplied by it
+    pub gas_price: u64,
+    /// The max gas amount of the dry run, it is capped by the balance of the gas payer
+    pub max_gas_amount: u64,
+}
+
+#[derive(Debug)]
+pub struct TraceTransactionMessage {
+    /// The state root before the transaction is executed
+    pub root: ObjectMeta,
+    pub tx: LedgerTransaction,
+    /// The L1 block with body, it is required if the transaction is a L1 block
+    pub l1_block: Option<L1BlockWithBody>,
+}
+
+impl Message for TraceTransactionMessage {
+    type Result = Result<TransactionTrace>;
 }
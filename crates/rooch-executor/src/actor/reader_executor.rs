@@ -4,8 +4,10 @@
 use super::messages::{
     AnnotatedStatesMessage, CheckStateChangeSetsMessage, ExecuteViewFunctionMessage,
     GetAnnotatedEventsByEventHandleMessage, GetAnnotatedEventsByEventIDsMessage,
-    GetEventsByEventHandleMessage, GetStateChangeSetsMessage, RefreshStateMessage, StatesMessage,
+    GetEventsByEventHandleMessage, GetStateChangeSetsMessage, RefreshStateMessage,
+    StateProofsMessage, StatesMessage,
 };
+use crate::actor::executor::new_moveos;
 use crate::actor::messages::{
     GetEventsByEventIDsMessage, GetTxExecutionInfosByHashMessage, ListAnnotatedStatesMessage,
     ListStatesMessage,
@@ -15,24 +17,23 @@ use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor, LocalActorRef};
 use move_resource_viewer::MoveValueAnnotator;
 use moveos::moveos::MoveOS;
-use moveos::moveos::MoveOSConfig;
 use moveos_eventbus::bus::EventData;
 use moveos_store::transaction_store::TransactionStore;
 use moveos_store::MoveOSStore;
 use moveos_types::function_return_value::AnnotatedFunctionResult;
 use moveos_types::function_return_value::AnnotatedFunctionReturnValue;
+use moveos_types::h256::H256;
 use moveos_types::moveos_std::event::EventHandle;
 use moveos_types::moveos_std::event::{AnnotatedEvent, Event};
 use moveos_types::moveos_std::object::ObjectMeta;
 use moveos_types::state::{AnnotatedState, ObjectState, StateChangeSetExt};
+use moveos_types::state_proof::StateProof;
 use moveos_types::state_resolver::RootObjectResolver;
 use moveos_types::state_resolver::{AnnotatedStateKV, AnnotatedStateReader, StateKV, StateReader};
 use moveos_types::transaction::TransactionExecutionInfo;
 use rooch_event::actor::{EventActor, EventActorSubscribeMessage};
 use rooch_event::event::GasUpgradeEvent;
-use rooch_genesis::FrameworksGasParameters;
 use rooch_store::RoochStore;
-use rooch_types::framework::{system_post_execute_functions, system_pre_execute_functions};
 
 pub struct ReaderExecutorActor {
     root: ObjectMeta,
@@ -49,15 +50,7 @@ impl ReaderExecutorActor {
         rooch_store: RoochStore,
         event_actor: Option<LocalActorRef<EventActor>>,
     ) -> Result<Self> {
-        let resolver = RootObjectResolver::new(root.clone(), &moveos_store);
-        let gas_parameters = FrameworksGasParameters::load_from_chain(&resolver)?;
-        let moveos = MoveOS::new(
-            moveos_store.clone(),
-            gas_parameters.all_natives(),
-            MoveOSConfig::default(),
-            system_pre_execute_functions(),
-            system_post_execute_functions(),
-        )?;
+        let moveos = new_moveos(&moveos_store, &root)?;
 
         Ok(Self {
             root,
@@ -94,6 +87,19 @@ impl ReaderExecutorActor {
         self.root = root;
         self.moveos.flush_module_cache(is_upgrade)
     }
+
+    /// Resolve the root to read the states, the historical state root must not be pruned.
+    /// The object count of a historical state root is unknown, so the root is only used to read the fields,
+    /// the view functions are executed at the root loaded from the execution info.
+    fn resolve_root(&self, state_root: Option<H256>) -> Result<ObjectMeta> {
+        match state_root {
+            Some(state_root) if state_root != self.root.state_root() => {
+                self.moveos_store.ensure_state_root_not_pruned(state_root)?;
+                Ok(ObjectMeta::root_metadata(state_root, 0))
+            }
+            _ => Ok(self.root.clone()),
+        }
+    }
 }
 
 #[async_trait]
@@ -113,10 +119,22 @@ impl Handler<ExecuteViewFunctionMessage> for ReaderExecutorActor {
         msg: ExecuteViewFunctionMessage,
         _ctx: &mut ActorContext,
     ) -> Result<AnnotatedFunctionResult, anyhow::Error> {
-        let resolver = RootObjectResolver::new(self.root.clone(), &self.moveos_store);
-        let function_result = self
-            .moveos()
-            .execute_view_function(self.root.clone(), msg.call);
+        let (root, function_result) = match msg.root {
+            Some(root) => {
+                self.moveos_store
+                    .ensure_state_root_not_pruned(root.state_root())?;
+                let function_result = self
+                    .moveos()
+                    .execute_historical_view_function(root.clone(), msg.call);
+                (root, function_result)
+            }
+            None => {
+                let root = self.root.clone();
+                let function_result = self.moveos().execute_view_function(root.clone(), msg.call);
+                (root, function_result)
+            }
+        };
+        let resolver = RootObjectResolver::new(root, &self.moveos_store);
 
         Ok(AnnotatedFunctionResult {
             vm_status: function_result.vm_status,
@@ -146,16 +164,34 @@ impl Handler<StatesMessage> for ReaderExecutorActor {
         msg: StatesMessage,
         _ctx: &mut ActorContext,
     ) -> Result<Vec<Option<ObjectState>>, anyhow::Error> {
-        let resolver = if let Some(state_root) = msg.state_root {
-            let root_object_meta = ObjectMeta::root_metadata(state_root, 0);
-            RootObjectResolver::new(root_object_meta, &self.moveos_store)
-        } else {
-            RootObjectResolver::new(self.root.clone(), &self.moveos_store)
-        };
+        let resolver =
+            RootObjectResolver::new(self.resolve_root(msg.state_root)?, &self.moveos_store);
         resolver.get_states(msg.access_path)
     }
 }
 
+#[async_trait]
+impl Handler<StateProofsMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: StateProofsMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<(H256, Vec<StateProof>), anyhow::Error> {
+        let state_root = self.resolve_root(msg.state_root)?.state_root();
+        let state_store = self.moveos_store.get_state_store();
+        let proofs = msg
+            .access_path
+            .into_state_query()
+            .into_fields_query()?
+            .into_iter()
+            .map(|(object_id, field_key)| {
+                state_store.get_state_proof(state_root, &object_id, field_key)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok((state_root, proofs))
+    }
+}
+
 #[async_trait]
 impl Handler<AnnotatedStatesMessage> for ReaderExecutorActor {
     async fn handle(
@@ -163,12 +199,8 @@ impl Handler<AnnotatedStatesMessage> for ReaderExecutorActor {
         msg: AnnotatedStatesMessage,
         _ctx: &mut ActorContext,
     ) -> Result<Vec<Option<AnnotatedState>>, anyhow::Error> {
-        let resolver = if let Some(state_root) = msg.state_root {
-            let root_object_meta = ObjectMeta::root_metadata(state_root, 0);
-            RootObjectResolver::new(root_object_meta, &self.moveos_store)
-        } else {
-            RootObjectResolver::new(self.root.clone(), &self.moveos_store)
-        };
+        let resolver =
+            RootObjectResolver::new(self.resolve_root(msg.state_root)?, &self.moveos_store);
         resolver.get_annotated_states(msg.access_path)
     }
 }
@@ -180,12 +212,8 @@ impl Handler<ListStatesMessage> for ReaderExecutorActor {
         msg: ListStatesMessage,
         _ctx: &mut ActorContext,
     ) -> Result<Vec<StateKV>, anyhow::Error> {
-        let resolver = if let Some(state_root) = msg.state_root {
-            let root_object_meta = ObjectMeta::root_metadata(state_root, 0);
-            RootObjectResolver::new(root_object_meta, &self.moveos_store)
-        } else {
-            RootObjectResolver::new(self.root.clone(), &self.moveos_store)
-        };
+        let resolver =
+            RootObjectResolver::new(self.resolve_root(msg.state_root)?, &self.moveos_store);
         resolver.list_states(msg.access_path, msg.cursor, msg.limit)
     }
 }
@@ -197,12 +225,8 @@ impl Handler<ListAnnotatedStatesMessage> for ReaderExecutorActor {
         msg: ListAnnotatedStatesMessage,
         _ctx: &mut ActorContext,
     ) -> Result<Vec<AnnotatedStateKV>, anyhow::Error> {
-        let resolver = if let Some(state_root) = msg.state_root {
-            let root_object_meta = ObjectMeta::root_metadata(state_root, 0);
-            RootObjectResolver::new(root_object_meta, &self.moveos_store)
-        } else {
-            RootObjectResolver::new(self.root.clone(), &self.moveos_store)
-        };
+        let resolver =
+            RootObjectResolver::new(self.resolve_root(msg.state_root)?, &self.moveos_store);
         resolver.list_annotated_states(msg.access_path, msg.cursor, msg.limit)
     }
 }
@@ -329,16 +353,7 @@ impl Handler<EventData> for ReaderExecutorActor {
         if let Ok(_gas_upgrade_msg) = message.data.downcast::<GasUpgradeEvent>() {
             tracing::info!("ReadExecutorActor: Reload the MoveOS instance...");
 
-            let resolver = RootObjectResolver::new(self.root.clone(), &self.moveos_store);
-            let gas_parameters = FrameworksGasParameters::load_from_chain(&resolver)?;
-
-            self.moveos = MoveOS::new(
-                self.moveos_store.clone(),
-                gas_parameters.all_natives(),
-                MoveOSConfig::default(),
-                system_pre_execute_functions(),
-                system_post_execute_functions(),
-            )?;
+            self.moveos = new_moveos(&self.moveos_store, &self.root)?;
         }
         Ok(())
     }
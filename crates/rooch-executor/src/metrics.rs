@@ -2,7 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use metrics::metrics_util::LATENCY_SEC_BUCKETS;
-use prometheus::{register_histogram_vec_with_registry, HistogramVec, Registry};
+use prometheus::{
+    register_histogram_vec_with_registry, register_int_counter_with_registry, HistogramVec,
+    IntCounter, Registry,
+};
 
 #[derive(Debug)]
 pub struct ExecutorMetrics {
@@ -10,6 +13,7 @@ pub struct ExecutorMetrics {
     pub executor_execute_tx_bytes: HistogramVec,
     pub executor_validate_tx_latency_seconds: HistogramVec,
     pub executor_validate_tx_bytes: HistogramVec,
+    pub executor_parallel_reexecute_tx_count: IntCounter,
 }
 
 impl ExecutorMetrics {
@@ -51,6 +55,12 @@ impl ExecutorMetrics {
                 registry,
             )
             .unwrap(),
+            executor_parallel_reexecute_tx_count: register_int_counter_with_registry!(
+                "executor_parallel_reexecute_tx_count",
+                "Executor re-executed tx count in the parallel execution",
+                registry,
+            )
+            .unwrap(),
         }
     }
 }
@@ -3,10 +3,11 @@
 
 use crate::actor::messages::{
     CheckStateChangeSetsMessage, ConvertL2TransactionData, DryRunTransactionResult,
-    GetAnnotatedEventsByEventIDsMessage, GetEventsByEventHandleMessage, GetEventsByEventIDsMessage,
-    GetStateChangeSetsMessage, GetTxExecutionInfosByHashMessage, ListAnnotatedStatesMessage,
-    ListStatesMessage, RefreshStateMessage, SaveStateChangeSetMessage, ValidateL1BlockMessage,
-    ValidateL1TxMessage,
+    EstimateGasMessage, GasEstimateResult, GetAnnotatedEventsByEventIDsMessage,
+    GetEventsByEventHandleMessage, GetEventsByEventIDsMessage, GetStateChangeSetsMessage,
+    GetTxExecutionInfosByHashMessage, ListAnnotatedStatesMessage, ListStatesMessage,
+    RefreshStateMessage, SaveStateChangeSetMessage, StateProofsMessage, TraceTransactionMessage,
+    ValidateL1BlockMessage, ValidateL1TxMessage,
 };
 use crate::actor::reader_executor::ReaderExecutorActor;
 use crate::actor::{
@@ -20,6 +21,7 @@ use anyhow::{anyhow, Result};
 use coerce::actor::ActorRef;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::StructTag;
+use moveos::trace::TransactionTrace;
 use moveos_types::function_return_value::{AnnotatedFunctionResult, FunctionResult};
 use moveos_types::h256::H256;
 use moveos_types::module_binding::MoveFunctionCaller;
@@ -28,6 +30,8 @@ use moveos_types::moveos_std::event::{Event, EventID};
 use moveos_types::moveos_std::object::ObjectMeta;
 use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::state::{FieldKey, StateChangeSetExt};
+use moveos_types::state_override::ObjectOverride;
+use moveos_types::state_proof::StateProof;
 use moveos_types::state_resolver::{AnnotatedStateKV, StateKV};
 use moveos_types::transaction::FunctionCall;
 use moveos_types::transaction::TransactionExecutionInfo;
@@ -40,7 +44,7 @@ use moveos_types::{
 use rooch_types::bitcoin::network::BitcoinNetwork;
 use rooch_types::framework::chain_id::ChainID;
 use rooch_types::transaction::{
-    L1BlockWithBody, L1Transaction, RoochTransaction, RoochTransactionData,
+    L1BlockWithBody, L1Transaction, LedgerTransaction, RoochTransaction, RoochTransactionData,
 };
 use tokio::runtime::Handle;
 
@@ -81,7 +85,21 @@ impl ExecutorProxy {
         tx_data: RoochTransactionData,
     ) -> Result<VerifiedMoveOSTransaction> {
         self.actor
-            .send(ConvertL2TransactionData { tx_data })
+            .send(ConvertL2TransactionData {
+                tx_data,
+                gas_payer: None,
+            })
+            .await?
+    }
+
+    /// Estimate the gas of the transaction data, the gas is paid by the gas payer if it is the sponsored transaction.
+    pub async fn estimate_gas(
+        &self,
+        tx_data: RoochTransactionData,
+        gas_payer: Option<AccountAddress>,
+    ) -> Result<GasEstimateResult> {
+        self.actor
+            .send(EstimateGasMessage { tx_data, gas_payer })
             .await?
     }
 
@@ -97,23 +115,63 @@ impl ExecutorProxy {
         Ok((result.output, result.transaction_info))
     }
 
+    /// Execute the sequenced transactions in parallel, the transactions are committed in order.
+    pub async fn execute_transaction_batch(
+        &self,
+        txs: Vec<VerifiedMoveOSTransaction>,
+    ) -> Result<Vec<(TransactionOutput, TransactionExecutionInfo)>> {
+        let results = self
+            .actor
+            .send(crate::actor::messages::ExecuteTransactionBatchMessage { txs })
+            .await??;
+        Ok(results
+            .into_iter()
+            .map(|result| (result.output, result.transaction_info))
+            .collect())
+    }
+
     pub async fn dry_run_transaction(
         &self,
         tx: VerifiedMoveOSTransaction,
+        state_overrides: Vec<ObjectOverride>,
     ) -> Result<DryRunTransactionResult> {
         let result = self
             .actor
-            .send(crate::actor::messages::DryRunTransactionMessage { tx })
+            .send(crate::actor::messages::DryRunTransactionMessage {
+                tx,
+                state_overrides,
+            })
             .await??;
         Ok(result)
     }
 
+    /// Trace the sequenced transaction at the state root before it is executed
+    pub async fn trace_transaction(
+        &self,
+        root: ObjectMeta,
+        tx: LedgerTransaction,
+        l1_block: Option<L1BlockWithBody>,
+    ) -> Result<TransactionTrace> {
+        self.actor
+            .send(TraceTransactionMessage { root, tx, l1_block })
+            .await?
+    }
+
     pub async fn execute_view_function(
         &self,
         call: FunctionCall,
+    ) -> Result<AnnotatedFunctionResult> {
+        self.execute_view_function_at(call, None).await
+    }
+
+    /// Execute the view function at the historical root
+    pub async fn execute_view_function_at(
+        &self,
+        call: FunctionCall,
+        root: Option<ObjectMeta>,
     ) -> Result<AnnotatedFunctionResult> {
         self.reader_actor
-            .send(ExecuteViewFunctionMessage { call })
+            .send(ExecuteViewFunctionMessage { root, call })
             .await?
     }
 
@@ -130,6 +188,20 @@ impl ExecutorProxy {
             .await?
     }
 
+    /// Get the proofs of the states against the `state_root`, use the latest state root if it is None.
+    pub async fn get_state_proofs(
+        &self,
+        access_path: AccessPath,
+        state_root: Option<H256>,
+    ) -> Result<(H256, Vec<StateProof>)> {
+        self.reader_actor
+            .send(StateProofsMessage {
+                state_root,
+                access_path,
+            })
+            .await?
+    }
+
     pub async fn get_annotated_states(
         &self,
         access_path: AccessPath,
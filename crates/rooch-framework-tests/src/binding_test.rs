@@ -1,9 +1,16 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::{bail, Result};
+use anyhow::{bail, format_err, Result};
 use metrics::RegistryService;
+use move_binary_format::access::ModuleAccess;
+use move_binary_format::file_format::Bytecode;
+use move_binary_format::CompiledModule;
 use move_core_types::account_address::AccountAddress;
+use move_core_types::effects::Op;
+use move_core_types::identifier::IdentStr;
+use move_core_types::language_storage::ModuleId;
+use move_core_types::resolver::ModuleResolver;
 use move_core_types::u256::U256;
 use move_core_types::vm_status::KeptVMStatus;
 use moveos_config::DataDirPath;
@@ -13,9 +20,11 @@ use moveos_types::h256::H256;
 use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::moveos_std::event::Event;
 use moveos_types::moveos_std::gas_schedule::GasScheduleConfig;
-use moveos_types::moveos_std::object::ObjectMeta;
+use moveos_types::moveos_std::module_store::ModuleStore;
+use moveos_types::moveos_std::object::{ObjectID, ObjectMeta};
 use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::state::{FieldKey, MoveStructType, ObjectChange, ObjectState, StateChangeSet};
+use moveos_types::state_override::ObjectOverride;
 use moveos_types::state_resolver::{
     RootObjectResolver, StateKV, StateReaderExt, StateResolver, StatelessResolver,
 };
@@ -152,6 +161,21 @@ impl RustBindingTest {
         // transfer RGas from rooch dao account to addr
         let function_call =
             TransferModule::create_transfer_coin_action(RGas::struct_tag(), addr, amount);
+        self.execute_via_rooch_dao(function_call)?;
+        Ok(())
+    }
+
+    /// Execute the action with the rooch dao account, it is the admin of the chain.
+    pub fn execute_via_rooch_dao(
+        &mut self,
+        action: MoveAction,
+    ) -> Result<ExecuteTransactionResult> {
+        let tx = self.sign_via_rooch_dao(action)?;
+        self.execute(tx)
+    }
+
+    /// Sign the action with the rooch dao account, the transaction is not executed.
+    pub fn sign_via_rooch_dao(&self, action: MoveAction) -> Result<RoochTransaction> {
         let sender = self
             .network
             .genesis_config
@@ -164,15 +188,13 @@ impl RustBindingTest {
             sequence_number,
             self.network.chain_id.id,
             GasScheduleConfig::CLI_DEFAULT_MAX_GAS_AMOUNT,
-            function_call,
+            action,
         );
         //RoochDao is a multisign account, so we need to sign the tx with the multisign account
         //In test env, it is a 1-of-1 multisign account, so we can sign with the only key
         let first_signature = BitcoinAuthenticator::sign(&self.kp, &tx_data);
         let authenticator = Authenticator::bitcoin_multisign(vec![first_signature])?;
-        let tx = RoochTransaction::new(tx_data, authenticator);
-        self.execute(tx)?;
-        Ok(())
+        Ok(RoochTransaction::new(tx_data, authenticator))
     }
 
     //TODO let the module bundle to execute the function
@@ -307,6 +329,69 @@ impl RustBindingTest {
         Ok(())
     }
 
+    /// Replace the code of the function in the module of the current state, return the new module bytes.
+    /// It is used to change the behavior of a module in the tests, the function signature is kept.
+    pub fn replace_function_code(
+        &self,
+        module_id: &ModuleId,
+        function_name: &IdentStr,
+        code: Vec<Bytecode>,
+    ) -> Result<Vec<u8>> {
+        let module_bytes = self
+            .resolver()
+            .get_module(module_id)?
+            .ok_or_else(|| format_err!("Module {} not found", module_id))?;
+        let mut module = CompiledModule::deserialize(&module_bytes)?;
+        let function_index = module
+            .function_defs
+            .iter()
+            .position(|def| {
+                module.identifier_at(module.function_handle_at(def.function).name) == function_name
+            })
+            .ok_or_else(|| format_err!("Function {}::{} not found", module_id, function_name))?;
+        let code_unit = module.function_defs[function_index]
+            .code
+            .as_mut()
+            .ok_or_else(|| format_err!("Function {}::{} is native", module_id, function_name))?;
+        code_unit.code = code;
+        let mut binary = vec![];
+        module.serialize(&mut binary)?;
+        Ok(binary)
+    }
+
+    /// Directly replace the module in the state and flush the module cache, the module must exist.
+    pub fn replace_module(&mut self, module_bytes: Vec<u8>) -> Result<()> {
+        let module_override = ObjectOverride::module(module_bytes)?;
+        let package_id = module_override
+            .id
+            .parent()
+            .expect("The module object should have a parent");
+        let module_store_change = {
+            let resolver = self.resolver();
+            let get_object = |id: &ObjectID| {
+                resolver
+                    .get_object(id)?
+                    .ok_or_else(|| format_err!("Object {} not found", id))
+            };
+            let module_state = get_object(&module_override.id)?;
+            let package_state = get_object(&package_id)?;
+            let module_store_state = get_object(&ModuleStore::object_id())?;
+
+            let mut package_change = ObjectChange::meta(package_state.metadata);
+            package_change.add_field_change(ObjectChange::new(
+                module_state.metadata,
+                Op::Modify(module_override.value),
+            ))?;
+            let mut module_store_change = ObjectChange::meta(module_store_state.metadata);
+            module_store_change.add_field_change(package_change)?;
+            module_store_change
+        };
+        self.apply_changes(vec![module_store_change])?;
+        self.reader_executor
+            .refresh_state(self.root.clone(), true)?;
+        self.executor.refresh_state(self.root.clone(), true)
+    }
+
     pub fn get_account_sequence_number(&self, address: AccountAddress) -> Result<u64> {
         Ok(self
             .resolver()
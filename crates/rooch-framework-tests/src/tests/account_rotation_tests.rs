@@ -0,0 +1,194 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::vm_status::{AbortLocation, KeptVMStatus, VMStatus};
+use moveos_types::module_binding::{ModuleBinding, MoveFunctionCaller};
+use moveos_types::move_std::string::MoveString;
+use moveos_types::transaction::MoveAction;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::account_authentication::AccountAuthenticationModule;
+use rooch_types::framework::address_mapping::RoochToBitcoinAddressMapping;
+use rooch_types::framework::auth_validator::BuiltinAuthValidator;
+use rooch_types::framework::empty::Empty;
+use rooch_types::framework::session_key::{SessionKeyModule, SessionScope};
+use rooch_types::rooch_network::BuiltinChainID;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use std::str::FromStr;
+
+fn empty_action() -> MoveAction {
+    MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![])
+}
+
+#[tokio::test]
+async fn test_rotate_bitcoin_auth_key() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let mut keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses().unwrap()[0];
+    let old_kp = keystore.get_key_pair(&sender, None).unwrap();
+    let bitcoin_address = old_kp.public().bitcoin_address().unwrap();
+    let new_kp = RoochKeyPair::generate_secp256k1();
+    let auth_validator_id: u64 = BuiltinAuthValidator::Bitcoin.flag().into();
+    let chain_id = BuiltinChainID::Local.chain_id().id();
+
+    // The rotation message signed for another sequence number or another chain is rejected
+    for (message_chain_id, sequence_number_offset) in
+        [(chain_id, 1), (BuiltinChainID::Main.chain_id().id(), 0)]
+    {
+        let sequence_number = binding_test
+            .get_account_sequence_number(sender.into())
+            .unwrap();
+        let action = AccountAuthenticationModule::rotate_auth_key_action_with_key_pair(
+            message_chain_id,
+            sender.into(),
+            auth_validator_id,
+            sequence_number + sequence_number_offset,
+            &new_kp,
+        )
+        .unwrap();
+        let tx = RoochTransactionData::new_for_test(sender, sequence_number, action).sign(&old_kp);
+        let execute_result = binding_test.execute_as_result(tx).unwrap();
+        match execute_result.transaction_info.status {
+            KeptVMStatus::MoveAbort(l, code) => {
+                match l {
+                    AbortLocation::Module(module_id) => {
+                        assert_eq!(
+                            module_id,
+                            AccountAuthenticationModule::module_id(),
+                            "expect account authentication module"
+                        );
+                    }
+                    _ => panic!("expect move abort in module"),
+                }
+                // ErrorInvalidRotationSignature = 4
+                assert_eq!(code, 4, "expect ErrorInvalidRotationSignature");
+            }
+            _ => panic!("expect move abort"),
+        }
+    }
+
+    // Rotate the key, the new key signs the rotation message and the old key signs the transaction
+    let sequence_number = binding_test
+        .get_account_sequence_number(sender.into())
+        .unwrap();
+    let action = AccountAuthenticationModule::rotate_auth_key_action_with_key_pair(
+        chain_id,
+        sender.into(),
+        auth_validator_id,
+        sequence_number,
+        &new_kp,
+    )
+    .unwrap();
+    let tx = RoochTransactionData::new_for_test(sender, sequence_number, action).sign(&old_kp);
+    binding_test.execute(tx).unwrap();
+
+    let module = binding_test.as_module_binding::<AccountAuthenticationModule>();
+    assert_eq!(
+        module
+            .rotated_auth_key(sender.into(), auth_validator_id)
+            .unwrap(),
+        Some(new_kp.public().as_ref().to_vec())
+    );
+    keystore.rotate_key(&sender, new_kp.copy(), None).unwrap();
+
+    // The old key can not sign the transaction anymore
+    let sequence_number = sequence_number + 1;
+    let tx_data = RoochTransactionData::new_for_test(sender, sequence_number, empty_action());
+    let error = binding_test
+        .execute_as_result(tx_data.clone().sign(&old_kp))
+        .unwrap_err();
+    match error.downcast_ref() {
+        Some(VMStatus::MoveAbort(_, code)) => {
+            // ErrorValidateInvalidAuthenticator = 1010
+            assert_eq!(*code, 1010, "expect ErrorValidateInvalidAuthenticator");
+        }
+        _ => {
+            panic!("Expect move abort")
+        }
+    }
+
+    // The new key signs the transaction for the same address
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+
+    // The account keeps its original Bitcoin address
+    let account = keystore.get_account(&sender, None).unwrap().unwrap();
+    assert_eq!(account.address, sender);
+    assert_eq!(account.bitcoin_address, bitcoin_address);
+    let resolved_bitcoin_address = RoochToBitcoinAddressMapping::resolve_bitcoin_address(
+        &binding_test.resolver(),
+        sender.into(),
+    )
+    .unwrap();
+    assert_eq!(resolved_bitcoin_address, Some(bitcoin_address));
+}
+
+#[tokio::test]
+async fn test_rotate_auth_key_removes_session_keys() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let mut keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses().unwrap()[0];
+    let old_kp = keystore.get_key_pair(&sender, None).unwrap();
+    let new_kp = RoochKeyPair::generate_secp256k1();
+    let auth_validator_id: u64 = BuiltinAuthValidator::Bitcoin.flag().into();
+    let chain_id = BuiltinChainID::Local.chain_id().id();
+
+    // Create a session key with the old key
+    let session_auth_key = keystore.generate_session_key(&sender, None).unwrap();
+    let action = SessionKeyModule::create_session_key_action(
+        MoveString::from_str("test").unwrap(),
+        MoveString::from_str("https:://test.rooch.network").unwrap(),
+        session_auth_key.as_ref().to_vec(),
+        SessionScope::new(ROOCH_FRAMEWORK_ADDRESS, "*", "*").unwrap(),
+        0,
+    );
+    let tx = RoochTransactionData::new_for_test(sender, 0, action).sign(&old_kp);
+    binding_test.execute(tx).unwrap();
+    let session_key = binding_test
+        .as_module_binding::<SessionKeyModule>()
+        .get_session_key(sender.into(), &session_auth_key)
+        .unwrap()
+        .expect("Session key not found");
+    keystore.binding_session_key(sender, session_key).unwrap();
+
+    // Rotate the key
+    let action = AccountAuthenticationModule::rotate_auth_key_action_with_key_pair(
+        chain_id,
+        sender.into(),
+        auth_validator_id,
+        1,
+        &new_kp,
+    )
+    .unwrap();
+    let tx = RoochTransactionData::new_for_test(sender, 1, action).sign(&old_kp);
+    binding_test.execute(tx).unwrap();
+
+    // The session key authorized by the old key is removed
+    let session_key = binding_test
+        .as_module_binding::<SessionKeyModule>()
+        .get_session_key(sender.into(), &session_auth_key)
+        .unwrap();
+    assert!(session_key.is_none(), "Session key should be removed");
+
+    let tx_data = RoochTransactionData::new_for_test(sender, 2, empty_action());
+    let tx = keystore
+        .sign_transaction_via_session_key(&sender, tx_data, &session_auth_key, None)
+        .unwrap();
+    let error = binding_test.execute_as_result(tx).unwrap_err();
+    match error.downcast_ref() {
+        Some(VMStatus::MoveAbort(_, code)) => {
+            // ErrorValidateInvalidAccountAuthKey = 1009
+            assert_eq!(*code, 1009, "expect ErrorValidateInvalidAccountAuthKey");
+        }
+        _ => {
+            panic!("Expect move abort")
+        }
+    }
+}
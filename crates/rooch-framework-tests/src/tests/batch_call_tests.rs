@@ -0,0 +1,93 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::language_storage::TypeTag;
+use move_core_types::u256::U256;
+use move_core_types::value::MoveValue;
+use moveos_types::module_binding::ModuleBinding;
+use moveos_types::move_types::FunctionId;
+use moveos_types::state::MoveStructType;
+use moveos_types::transaction::{BatchCall, CallResultArg, FunctionCall, MoveAction};
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::framework::empty::Empty;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::framework::transfer::TransferModule;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use std::str::FromStr;
+
+fn empty_call() -> BatchCall {
+    FunctionCall::new(Empty::empty_function_id(), vec![], vec![]).into()
+}
+
+#[tokio::test]
+async fn test_batch_call() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses().unwrap()[0];
+
+    let action = MoveAction::new_batch_call(vec![empty_call(), empty_call()]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+}
+
+#[tokio::test]
+async fn test_batch_call_with_result_args() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses().unwrap()[0];
+
+    // transfer the coin to the address returned by `tx_context::sender`
+    let sender_call = FunctionCall::new(
+        FunctionId::from_str("0x2::tx_context::sender").unwrap(),
+        vec![],
+        vec![],
+    );
+    let transfer_call = FunctionCall::new(
+        FunctionId::new(
+            TransferModule::module_id(),
+            TransferModule::TRANSFER_COIN_FUNCTION_NAME.to_owned(),
+        ),
+        vec![TypeTag::Struct(Box::new(RGas::struct_tag()))],
+        vec![MoveValue::U256(U256::one()).simple_serialize().unwrap()],
+    );
+    let action = MoveAction::new_batch_call(vec![
+        sender_call.into(),
+        BatchCall::new(transfer_call, vec![CallResultArg::new(0, 0, 0)]),
+    ]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+}
+
+#[tokio::test]
+async fn test_batch_call_with_invalid_result_args() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses().unwrap()[0];
+
+    // the result of a later call can not be used
+    let transfer_call = FunctionCall::new(
+        FunctionId::new(
+            TransferModule::module_id(),
+            TransferModule::TRANSFER_COIN_FUNCTION_NAME.to_owned(),
+        ),
+        vec![TypeTag::Struct(Box::new(RGas::struct_tag()))],
+        vec![MoveValue::U256(U256::one()).simple_serialize().unwrap()],
+    );
+    let action = MoveAction::new_batch_call(vec![
+        BatchCall::new(transfer_call, vec![CallResultArg::new(0, 1, 0)]),
+        empty_call(),
+    ]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    assert!(binding_test.execute(tx).is_err());
+}
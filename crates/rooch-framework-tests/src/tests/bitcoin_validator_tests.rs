@@ -20,7 +20,7 @@ async fn test_validate() {
     let validator_caller = AuthValidatorCaller::new(&binding_test, auth_validator);
 
     let keystore = InMemKeystore::new_insecure_for_tests(1);
-    let sender = keystore.addresses()[0];
+    let sender = keystore.addresses().unwrap()[0];
     let sequence_number = 0;
     let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
     let tx_data = RoochTransactionData::new_for_test(sender, sequence_number, action);
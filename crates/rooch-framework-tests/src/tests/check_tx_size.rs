@@ -31,7 +31,7 @@ async fn check_tx_size() {
     let action = MoveAction::new_function_call(function_id, vec![], arg_bytes);
 
     let keystore = InMemKeystore::new_insecure_for_tests(1);
-    let sender = keystore.addresses()[0];
+    let sender = keystore.addresses().unwrap()[0];
     let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
     let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
 
@@ -0,0 +1,77 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_binary_format::file_format::Bytecode;
+use move_core_types::u256::U256;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_types::module_binding::{ModuleBinding, MoveFunctionCaller};
+use moveos_types::moveos_std::gas_schedule::GasScheduleConfig;
+use moveos_types::state::MoveStructType;
+use moveos_types::transaction::MoveAction;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::framework::account_coin_store::AccountCoinStoreModule;
+use rooch_types::framework::empty::Empty;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::framework::transaction_fee::TransactionFeeModule;
+use rooch_types::transaction::rooch::RoochTransactionData;
+
+#[tokio::test]
+async fn test_estimate_gas_of_sponsored_transaction() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(2);
+    let sender = keystore.addresses().unwrap()[0];
+    let gas_payer = keystore.addresses().unwrap()[1];
+    binding_test
+        .get_rgas(gas_payer.into(), U256::from(1_000_000_000u64))
+        .unwrap();
+    let gas_payer_balance = binding_test
+        .executor
+        .as_module_binding::<AccountCoinStoreModule>()
+        .balance(gas_payer.into(), RGas::struct_tag())
+        .unwrap();
+    assert_eq!(gas_payer_balance, U256::from(1_000_000_000u64));
+
+    // Raise the gas price to 2
+    let module_bytes = binding_test
+        .replace_function_code(
+            &TransactionFeeModule::module_id(),
+            TransactionFeeModule::GET_GAS_FACTOR_FUNCTION_NAME,
+            vec![Bytecode::LdU64(2), Bytecode::Ret],
+        )
+        .unwrap();
+    binding_test.replace_module(module_bytes).unwrap();
+    let gas_price = binding_test
+        .executor
+        .as_module_binding::<TransactionFeeModule>()
+        .gas_price()
+        .unwrap();
+    assert_eq!(gas_price, 2);
+
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+
+    // The max gas amount is capped by the balance of the gas payer at the gas price
+    let result = binding_test
+        .executor
+        .estimate_gas(tx_data.clone(), Some(gas_payer.into()))
+        .unwrap();
+    assert_eq!(result.gas_price, 2);
+    assert_eq!(result.max_gas_amount, 500_000_000);
+    let dry_run_result = result.dry_run_result;
+    assert_eq!(dry_run_result.raw_output.status, KeptVMStatus::Executed);
+    assert!(dry_run_result.raw_output.gas_used > 0);
+    assert!(dry_run_result.storage_gas.io_write_gas > 0);
+    assert!(dry_run_result.storage_gas.change_set_gas > 0);
+    assert!(dry_run_result.storage_gas.total() > dry_run_result.storage_gas.io_write_gas);
+
+    // The sender pays the gas if the transaction is not sponsored, the new sender has no balance
+    let result = binding_test.executor.estimate_gas(tx_data, None).unwrap();
+    assert_eq!(
+        result.max_gas_amount,
+        GasScheduleConfig::READONLY_MAX_GAS_AMOUNT
+    );
+}
@@ -4,11 +4,16 @@
 use crate::binding_test;
 use ethers::prelude::*;
 use moveos_types::module_binding::MoveFunctionCaller;
-use moveos_types::transaction::MoveAction;
+use moveos_types::move_types::FunctionId;
+use moveos_types::moveos_std::onchain_features::ETHEREUM_RECEIPTS_FEATURE;
+use moveos_types::transaction::{FunctionCall, MoveAction};
 use rooch_key::keystore::account_keystore::AccountKeystore;
 use rooch_key::keystore::memory_keystore::InMemKeystore;
-use rooch_types::framework::ethereum::BlockHeader;
+use rooch_types::framework::ethereum::{BlockHeader, BlockWithReceipts, EthereumModule};
+use rooch_types::multichain_id::RoochMultiChainID;
 use rooch_types::transaction::rooch::RoochTransactionData;
+use rooch_types::transaction::{L1Block, L1BlockWithBody};
+use std::str::FromStr;
 
 #[tokio::test]
 async fn test_submit_block() {
@@ -16,7 +21,7 @@ async fn test_submit_block() {
     let mut binding_test = binding_test::RustBindingTest::new().unwrap();
 
     let keystore = InMemKeystore::new_insecure_for_tests(1);
-    let sender = keystore.addresses()[0];
+    let sender = keystore.addresses().unwrap()[0];
     let sequence_number = 0;
 
     let json = serde_json::json!(
@@ -73,3 +78,154 @@ async fn test_submit_block() {
     );
     assert_eq!(now_milliseconds, duration.as_millis() as u64);
 }
+
+fn new_block_with_receipts(number: u64, hash: H256, parent_hash: H256) -> BlockWithReceipts {
+    let tx_hash = H256::repeat_byte(number as u8);
+    let ethereum_block = Block::<H256> {
+        hash: Some(hash),
+        parent_hash,
+        number: Some(number.into()),
+        author: Some(Address::zero()),
+        timestamp: (1700000000 + number).into(),
+        transactions: vec![tx_hash],
+        ..Default::default()
+    };
+    let receipt = TransactionReceipt {
+        transaction_hash: tx_hash,
+        block_hash: Some(hash),
+        block_number: Some(number.into()),
+        status: Some(1.into()),
+        gas_used: Some(21000.into()),
+        cumulative_gas_used: 21000.into(),
+        logs: vec![Log {
+            address: Address::repeat_byte(0xcc),
+            topics: vec![H256::repeat_byte(0xbb)],
+            data: vec![1u8, 2, 3].into(),
+            log_index: Some(0.into()),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    BlockWithReceipts::new(
+        BlockHeader::try_from(&ethereum_block).unwrap(),
+        vec![rooch_types::framework::ethereum::TransactionReceipt::try_from(&receipt).unwrap()],
+    )
+}
+
+#[tokio::test]
+async fn test_submit_block_with_receipts_and_reorg() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses().unwrap()[0];
+
+    let blocks = vec![
+        new_block_with_receipts(1, H256::repeat_byte(1), H256::repeat_byte(0)),
+        new_block_with_receipts(2, H256::repeat_byte(2), H256::repeat_byte(1)),
+        // block 2 is reorganized
+        new_block_with_receipts(2, H256::repeat_byte(22), H256::repeat_byte(1)),
+    ];
+    for (sequence_number, block) in blocks.into_iter().enumerate() {
+        let action = MoveAction::Function(
+            EthereumModule::create_execute_l1_block_with_receipts_call_bytes(block.encode()),
+        );
+        let tx_data = RoochTransactionData::new_for_test(sender, sequence_number as u64, action);
+        let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+        binding_test.execute(tx).unwrap();
+    }
+
+    let ethereum_module = binding_test.as_module_binding::<EthereumModule>();
+    assert_eq!(ethereum_module.get_latest_block_number().unwrap(), Some(2));
+    let block_header = ethereum_module.get_block(2).unwrap();
+    assert_eq!(block_header.hash, H256::repeat_byte(22).as_bytes().to_vec());
+    let receipts = ethereum_module.get_receipts(2).unwrap();
+    assert_eq!(receipts.len(), 1);
+    assert_eq!(receipts[0].logs[0].data, vec![1u8, 2, 3]);
+
+    // the block which is not continuous should be rejected
+    let block = new_block_with_receipts(4, H256::repeat_byte(4), H256::repeat_byte(3));
+    let action = MoveAction::Function(
+        EthereumModule::create_execute_l1_block_with_receipts_call_bytes(block.encode()),
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, 3, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    assert!(binding_test.execute(tx).is_err());
+    let ethereum_module = binding_test.as_module_binding::<EthereumModule>();
+    assert_eq!(ethereum_module.get_latest_block_number().unwrap(), Some(2));
+}
+
+fn change_ethereum_receipts_feature(
+    binding_test: &mut binding_test::RustBindingTest,
+    enable: bool,
+) {
+    let features = vec![ETHEREUM_RECEIPTS_FEATURE];
+    let empty: Vec<u64> = vec![];
+    let (enable, disable) = if enable {
+        (features, empty)
+    } else {
+        (empty, features)
+    };
+    let action = MoveAction::Function(FunctionCall::new(
+        FunctionId::from_str("0x3::onchain_config::change_feature_flags").unwrap(),
+        vec![],
+        vec![
+            bcs::to_bytes(&enable).unwrap(),
+            bcs::to_bytes(&disable).unwrap(),
+        ],
+    ));
+    binding_test.execute_via_rooch_dao(action).unwrap();
+}
+
+fn ethereum_l1_block(number: u64, hash: H256, block_body: Vec<u8>) -> L1BlockWithBody {
+    L1BlockWithBody {
+        block: L1Block {
+            chain_id: RoochMultiChainID::Ether.multichain_id(),
+            block_height: number,
+            block_hash: hash.as_bytes().to_vec(),
+        },
+        block_body,
+    }
+}
+
+#[tokio::test]
+async fn test_relay_block_before_receipts_feature() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    // The blocks relayed before the feature is enabled only carry the block header
+    change_ethereum_receipts_feature(&mut binding_test, false);
+    assert!(!binding_test.executor().ethereum_receipts_enabled());
+    let block = new_block_with_receipts(1, H256::repeat_byte(1), H256::repeat_byte(0));
+    binding_test
+        .execute_l1_block(ethereum_l1_block(
+            1,
+            H256::repeat_byte(1),
+            block.header.encode(),
+        ))
+        .unwrap();
+    let ethereum_module = binding_test.as_module_binding::<EthereumModule>();
+    assert_eq!(ethereum_module.get_latest_block_number().unwrap(), Some(1));
+    assert!(ethereum_module.get_receipts(1).unwrap().is_empty());
+
+    // The receipts can not be relayed before the feature is enabled
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses().unwrap()[0];
+    let block = new_block_with_receipts(2, H256::repeat_byte(2), H256::repeat_byte(1));
+    let action = MoveAction::Function(
+        EthereumModule::create_execute_l1_block_with_receipts_call_bytes(block.encode()),
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    assert!(binding_test.execute(tx).is_err());
+
+    // The blocks relayed after the feature is enabled carry the receipts
+    change_ethereum_receipts_feature(&mut binding_test, true);
+    assert!(binding_test.executor().ethereum_receipts_enabled());
+    binding_test
+        .execute_l1_block(ethereum_l1_block(2, H256::repeat_byte(2), block.encode()))
+        .unwrap();
+    let ethereum_module = binding_test.as_module_binding::<EthereumModule>();
+    assert_eq!(ethereum_module.get_latest_block_number().unwrap(), Some(2));
+    assert_eq!(ethereum_module.get_receipts(2).unwrap().len(), 1);
+}
@@ -0,0 +1,180 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use metrics::RegistryService;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::effects::Op;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::ModuleId;
+use move_core_types::u256::U256;
+use moveos::gas::table::get_gas_schedule_config;
+use moveos_types::move_types::FunctionId;
+use moveos_types::moveos_std::gas_schedule::{
+    GasSchedule, GasScheduleUpdateScheduledEvent, GasScheduleUpdatedEvent, PendingGasSchedule,
+};
+use moveos_types::moveos_std::timestamp::Timestamp;
+use moveos_types::state::{MoveStructType, ObjectChange};
+use moveos_types::state_resolver::StateResolver;
+use moveos_types::transaction::MoveAction;
+use rooch_executor::actor::executor::ExecutorActor;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::framework::transfer::TransferModule;
+
+fn set_timestamp(binding_test: &mut binding_test::RustBindingTest, milliseconds: u64) {
+    let timestamp_state = binding_test
+        .resolver()
+        .get_object(&Timestamp::object_id())
+        .unwrap()
+        .unwrap();
+    let value = bcs::to_bytes(&Timestamp { milliseconds }).unwrap();
+    binding_test
+        .apply_changes(vec![ObjectChange::new(
+            timestamp_state.metadata,
+            Op::Modify(value),
+        )])
+        .unwrap();
+}
+
+fn transfer_rgas_action() -> MoveAction {
+    TransferModule::create_transfer_coin_action(
+        RGas::struct_tag(),
+        AccountAddress::random(),
+        U256::from(100u64),
+    )
+}
+
+fn get_gas_schedule(binding_test: &binding_test::RustBindingTest) -> GasSchedule {
+    binding_test
+        .resolver()
+        .get_object(&GasSchedule::gas_schedule_object_id())
+        .unwrap()
+        .unwrap()
+        .into_object::<GasSchedule>()
+        .unwrap()
+        .value
+}
+
+#[tokio::test]
+async fn test_scheduled_gas_schedule_upgrade() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let (gas_schedule_config, activation_time) = get_gas_schedule_config(&binding_test.resolver())
+        .unwrap()
+        .unwrap();
+    assert!(activation_time.is_none());
+
+    let schedule_version = get_gas_schedule(&binding_test).schedule_version;
+    let now = binding_test
+        .resolver()
+        .get_object(&Timestamp::object_id())
+        .unwrap()
+        .unwrap()
+        .into_object::<Timestamp>()
+        .unwrap()
+        .value
+        .milliseconds;
+    let activation_time = now + 10_000;
+    // Raise the gas of the object natives, they are charged by every coin transfer
+    let mut new_gas_schedule_config = gas_schedule_config.clone();
+    for entry in new_gas_schedule_config.entries.iter_mut() {
+        if entry.key.as_str().starts_with("object.") {
+            entry.val += 1_000;
+        }
+    }
+
+    let action = MoveAction::new_function_call(
+        FunctionId::new(
+            ModuleId::new(ROOCH_FRAMEWORK_ADDRESS, Identifier::new("upgrade").unwrap()),
+            Identifier::new("schedule_gas_schedule_upgrade").unwrap(),
+        ),
+        vec![],
+        vec![
+            bcs::to_bytes(&bcs::to_bytes(&new_gas_schedule_config).unwrap()).unwrap(),
+            bcs::to_bytes(&activation_time).unwrap(),
+        ],
+    );
+    let result = binding_test.execute_via_rooch_dao(action).unwrap();
+    assert!(result
+        .output
+        .events
+        .iter()
+        .any(|event| event.event_type == GasScheduleUpdateScheduledEvent::struct_tag()));
+
+    // The pending gas schedule does not take effect before the activation time
+    let (effective_config, pending_activation_time) =
+        get_gas_schedule_config(&binding_test.resolver())
+            .unwrap()
+            .unwrap();
+    assert_eq!(effective_config, gas_schedule_config);
+    assert_eq!(pending_activation_time, Some(activation_time));
+    let gas_used_before_activation = binding_test
+        .execute_via_rooch_dao(transfer_rgas_action())
+        .unwrap()
+        .output
+        .gas_used;
+
+    // The pending gas schedule takes effect when the on-chain time reaches the activation time
+    set_timestamp(&mut binding_test, activation_time);
+    let (effective_config, pending_activation_time) =
+        get_gas_schedule_config(&binding_test.resolver())
+            .unwrap()
+            .unwrap();
+    assert_eq!(effective_config, new_gas_schedule_config);
+    assert!(pending_activation_time.is_none());
+
+    // The first transaction after the activation time is executed with the natives of the pending gas schedule,
+    // the running executor, the restarted executor and the dry run charge the same gas.
+    let tx = binding_test
+        .sign_via_rooch_dao(transfer_rgas_action())
+        .unwrap();
+    let verified_tx = binding_test.executor.validate_l2_tx(tx).unwrap();
+    let restarted_executor = ExecutorActor::new(
+        binding_test.root().clone(),
+        binding_test.rooch_db().moveos_store.clone(),
+        binding_test.rooch_db().rooch_store.clone(),
+        &RegistryService::default().default_registry(),
+        None,
+    )
+    .unwrap();
+    let (restarted_output, _) = restarted_executor
+        .moveos()
+        .execute_only(verified_tx.clone())
+        .unwrap();
+    let dry_run_result = binding_test
+        .executor
+        .dry_run(verified_tx.clone(), vec![])
+        .unwrap();
+    let result = binding_test.execute_verified_tx(verified_tx).unwrap();
+    assert!(result.output.gas_used > gas_used_before_activation);
+    assert_eq!(result.output.gas_used, restarted_output.gas_used);
+    assert_eq!(result.output.gas_used, dry_run_result.raw_output.gas_used);
+
+    // The transaction applies the pending gas schedule to the gas schedule
+    assert!(result
+        .output
+        .events
+        .iter()
+        .any(|event| event.event_type == GasScheduleUpdatedEvent::struct_tag()));
+    assert!(binding_test
+        .resolver()
+        .get_object(&PendingGasSchedule::pending_gas_schedule_object_id())
+        .unwrap()
+        .is_none());
+    let gas_schedule = get_gas_schedule(&binding_test);
+    assert_eq!(gas_schedule.schedule_version, schedule_version + 1);
+    assert_eq!(gas_schedule.entries, new_gas_schedule_config.entries);
+
+    // The executor keeps the new natives after the gas schedule is applied
+    let result = binding_test
+        .execute_via_rooch_dao(transfer_rgas_action())
+        .unwrap();
+    assert!(result.output.gas_used > gas_used_before_activation);
+    assert!(!result
+        .output
+        .events
+        .iter()
+        .any(|event| event.event_type == GasScheduleUpdatedEvent::struct_tag()));
+}
@@ -1,6 +1,8 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+mod account_rotation_tests;
+mod batch_call_tests;
 mod bbn_test;
 mod bitcoin_data;
 mod bitcoin_multisign_validator_tests;
@@ -11,8 +13,17 @@ mod brc20_test;
 mod chain_id_test;
 mod check_tx_size;
 mod empty_tests;
+mod estimate_gas_tests;
 mod ethereum_test;
+mod gas_schedule_tests;
 mod multisign_account_tests;
 mod ord_test;
+mod parallel_tests;
 mod session_validator_tests;
+mod snapshot_tests;
+mod sponsor_validator_tests;
+mod state_override_tests;
+mod trace_tests;
 mod view_function_gas;
+mod webauthn_validator_tests;
+mod weighted_multisign_validator_tests;
@@ -0,0 +1,181 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::ident_str;
+use move_core_types::u256::U256;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos::moveos::{MoveOS, MoveOSConfig};
+use moveos_types::h256::H256;
+use moveos_types::module_binding::ModuleBinding;
+use moveos_types::moveos_std::gas_schedule::GasScheduleConfig;
+use moveos_types::moveos_std::tx_context::TxContext;
+use moveos_types::state::{MoveStructType, MoveType};
+use moveos_types::state_resolver::RootObjectResolver;
+use moveos_types::transaction::{MoveAction, MoveOSTransaction};
+use rooch_genesis::FrameworksGasParameters;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::framework::account_coin_store::AccountCoinStoreModule;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::framework::transfer::TransferModule;
+use rooch_types::transaction::rooch::RoochTransactionData;
+
+#[tokio::test]
+async fn test_parallel_execution_same_as_serial() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(3);
+    let senders = keystore.addresses().unwrap();
+    for sender in &senders {
+        binding_test
+            .get_rgas((*sender).into(), U256::from(100_0000_0000u64))
+            .unwrap();
+    }
+
+    // Some transactions transfer to the same recipient, and the first sender sends two transactions,
+    // so the parallel execution has conflicts to re-execute.
+    let recipient = AccountAddress::random();
+    let transfers = vec![
+        (senders[0], 0, recipient, 100u64),
+        (senders[1], 0, recipient, 200u64),
+        (senders[2], 0, AccountAddress::random(), 300u64),
+        (senders[0], 1, AccountAddress::random(), 400u64),
+        // Insufficient balance, the transaction is aborted
+        (
+            senders[1],
+            1,
+            AccountAddress::random(),
+            100_0000_0000_0000u64,
+        ),
+        (senders[2], 1, recipient, 500u64),
+    ];
+
+    let pre_root = binding_test.root().clone();
+    let mut verified_txs = vec![];
+    let mut serial_results = vec![];
+    for (sender, sequence_number, to, amount) in transfers {
+        let action =
+            TransferModule::create_transfer_coin_action(RGas::struct_tag(), to, U256::from(amount));
+        let tx_data = RoochTransactionData::new_for_test(sender, sequence_number, action);
+        let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+        let verified_tx = binding_test.executor.validate_l2_tx(tx).unwrap();
+        verified_txs.push(verified_tx.clone());
+        let result = binding_test
+            .execute_verified_tx_as_result(verified_tx)
+            .unwrap();
+        serial_results.push(result);
+    }
+    assert!(matches!(
+        serial_results[4].transaction_info.status,
+        KeptVMStatus::MoveAbort(_, _)
+    ));
+
+    // Execute the same transactions in parallel from the same state root
+    binding_test
+        .executor
+        .refresh_state(pre_root, false)
+        .unwrap();
+    let results = binding_test.executor.execute_batch(verified_txs).unwrap();
+
+    assert_eq!(results.len(), serial_results.len());
+    for (result, serial_result) in results.iter().zip(serial_results.iter()) {
+        // The event ids are allocated by the event store, so the event root is not compared.
+        let info = &result.transaction_info;
+        let serial_info = &serial_result.transaction_info;
+        assert_eq!(info.tx_hash, serial_info.tx_hash);
+        assert_eq!(info.state_root, serial_info.state_root);
+        assert_eq!(info.size, serial_info.size);
+        assert_eq!(info.gas_used, serial_info.gas_used);
+        assert_eq!(info.status, serial_info.status);
+        assert_eq!(result.output.changeset, serial_result.output.changeset);
+        assert_eq!(
+            result.output.events.len(),
+            serial_result.output.events.len()
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_parallel_execution_rebases_disjoint_transactions() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let moveos_store = binding_test.rooch_db().moveos_store.clone();
+    let pre_root = binding_test.root().clone();
+
+    // Without the system pre and post execute functions, the transactions do not pay the gas
+    // to the shared transaction fee pool, so they only conflict on the state they access.
+    let resolver = RootObjectResolver::new(pre_root.clone(), &moveos_store);
+    let gas_parameters = FrameworksGasParameters::load_from_chain(&resolver).unwrap();
+    let moveos = MoveOS::new(
+        moveos_store.clone(),
+        gas_parameters.all_natives(),
+        MoveOSConfig::default(),
+        vec![],
+        vec![],
+    )
+    .unwrap();
+
+    let accept_coin = |sender: AccountAddress, sequence_number: u64| {
+        let action = MoveAction::new_function_call(
+            AccountCoinStoreModule::function_id(ident_str!("accept_coin_entry")),
+            vec![RGas::type_tag()],
+            vec![],
+        );
+        let ctx = TxContext::new(
+            sender,
+            sequence_number,
+            GasScheduleConfig::INITIAL_MAX_GAS_AMOUNT,
+            H256::random(),
+            1,
+        );
+        moveos
+            .verify(MoveOSTransaction::new(pre_root.clone(), ctx, action))
+            .unwrap()
+    };
+    let senders = (0..3).map(|_| AccountAddress::random()).collect::<Vec<_>>();
+    // Every transaction creates the coin store of its sender, only the last one reads
+    // the coin store created by the first one.
+    let txs = vec![
+        accept_coin(senders[0], 0),
+        accept_coin(senders[1], 0),
+        accept_coin(senders[2], 0),
+        accept_coin(senders[0], 1),
+    ];
+
+    let mut serial_roots = vec![];
+    let mut root = pre_root.clone();
+    for tx in &txs {
+        let mut tx = tx.clone();
+        tx.root = root.clone();
+        let tx_hash = tx.ctx.tx_hash();
+        let (raw_output, _) = moveos.execute(tx).unwrap();
+        assert_eq!(raw_output.status, KeptVMStatus::Executed);
+        let (_, execution_info) = moveos_store
+            .handle_tx_output(tx_hash, AccountAddress::ZERO, raw_output)
+            .unwrap();
+        root = execution_info.root_metadata();
+        serial_roots.push(execution_info.state_root);
+    }
+
+    let mut parallel_roots = vec![];
+    let reexecuted = moveos
+        .execute_parallel(pre_root, &txs, |index, raw_output| {
+            assert_eq!(raw_output.status, KeptVMStatus::Executed);
+            let (_, execution_info) = moveos_store.handle_tx_output(
+                txs[index].ctx.tx_hash(),
+                AccountAddress::ZERO,
+                raw_output,
+            )?;
+            parallel_roots.push(execution_info.state_root);
+            Ok(execution_info.root_metadata())
+        })
+        .unwrap();
+
+    // The speculative outputs of the disjoint transactions are rebased and committed,
+    // only the conflicting transaction is re-executed.
+    assert_eq!(reexecuted, 1);
+    assert_eq!(parallel_roots, serial_roots);
+}
@@ -4,18 +4,22 @@
 use crate::binding_test;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::ident_str;
-use move_core_types::language_storage::ModuleId;
+use move_core_types::language_storage::{ModuleId, TypeTag};
+use move_core_types::u256::U256;
 use move_core_types::value::MoveValue;
 use move_core_types::vm_status::{AbortLocation, KeptVMStatus, VMStatus};
 use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::move_std::string::MoveString;
 use moveos_types::move_types::FunctionId;
+use moveos_types::state::MoveStructType;
 use moveos_types::{module_binding::ModuleBinding, transaction::MoveAction};
 use rooch_key::keystore::account_keystore::AccountKeystore;
 use rooch_key::keystore::memory_keystore::InMemKeystore;
-use rooch_types::framework::session_key::SessionKeyModule;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::framework::session_key::{SessionKey, SessionKeyModule};
 use rooch_types::framework::session_validator::SessionValidatorModule;
 use rooch_types::framework::timestamp::TimestampModule;
+use rooch_types::framework::transfer::TransferModule;
 use rooch_types::{addresses::ROOCH_FRAMEWORK_ADDRESS, framework::empty::Empty};
 use rooch_types::{framework::session_key::SessionScope, transaction::rooch::RoochTransactionData};
 use std::str::FromStr;
@@ -26,7 +30,7 @@ async fn test_session_key_rooch() {
     let mut binding_test = binding_test::RustBindingTest::new().unwrap();
 
     let mut keystore = InMemKeystore::new_insecure_for_tests(1);
-    let sender = keystore.addresses()[0];
+    let sender = keystore.addresses().unwrap()[0];
     let sequence_number = 0;
 
     let session_auth_key = keystore.generate_session_key(&sender, None).unwrap();
@@ -184,3 +188,142 @@ async fn test_session_key_rooch() {
         }
     }
 }
+
+#[tokio::test]
+async fn test_session_key_quota() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let mut keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses().unwrap()[0];
+    let sequence_number = 0;
+
+    let session_auth_key = keystore.generate_session_key(&sender, None).unwrap();
+
+    let session_scope = SessionScope::new(ROOCH_FRAMEWORK_ADDRESS, "*", "*").unwrap();
+    let max_coin_amount = U256::from(10u64);
+    let action = SessionKeyModule::create_session_key_with_quota_action(
+        MoveString::from_str("test").unwrap(),
+        MoveString::from_str("https:://test.rooch.network").unwrap(),
+        session_auth_key.as_ref().to_vec(),
+        vec![session_scope],
+        100,
+        None,
+        Some(2),
+        vec![(RGas::struct_tag(), max_coin_amount)],
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, sequence_number, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+
+    let session_key_module = binding_test.as_module_binding::<SessionKeyModule>();
+    let session_key = session_key_module
+        .get_session_key(sender.into(), &session_auth_key)
+        .unwrap()
+        .expect("Session key not found");
+    let quota = session_key_module
+        .get_session_key_quota(sender.into(), &session_auth_key)
+        .unwrap()
+        .expect("Session key quota not found");
+    assert_eq!(
+        quota.max_coin_amount(&RGas::struct_tag()),
+        Some(max_coin_amount)
+    );
+    // The coin quota is keyed by the coin type, other coin types are not limited.
+    assert_eq!(quota.max_coin_amount(&SessionKey::struct_tag()), None);
+    assert_eq!(Option::<u64>::from(quota.max_gas_amount), None);
+    assert_eq!(Option::<u64>::from(quota.max_tx_count), Some(2));
+    assert_eq!(quota.tx_count, 0);
+    keystore.binding_session_key(sender, session_key).unwrap();
+
+    // transfer the coin within the coin quota, it should success.
+    let transfer_action = |amount: u64| {
+        MoveAction::new_function_call(
+            FunctionId::new(
+                TransferModule::module_id(),
+                TransferModule::TRANSFER_COIN_FUNCTION_NAME.to_owned(),
+            ),
+            vec![TypeTag::Struct(Box::new(RGas::struct_tag()))],
+            vec![
+                MoveValue::Address(AccountAddress::random())
+                    .simple_serialize()
+                    .unwrap(),
+                MoveValue::U256(U256::from(amount))
+                    .simple_serialize()
+                    .unwrap(),
+            ],
+        )
+    };
+    let tx_data =
+        RoochTransactionData::new_for_test(sender, sequence_number + 1, transfer_action(6));
+    let tx = keystore
+        .sign_transaction_via_session_key(&sender, tx_data, &session_auth_key, None)
+        .unwrap();
+    binding_test.execute(tx).unwrap();
+
+    let quota = binding_test
+        .as_module_binding::<SessionKeyModule>()
+        .get_session_key_quota(sender.into(), &session_auth_key)
+        .unwrap()
+        .expect("Session key quota not found");
+    assert_eq!(
+        quota.spent_coin_amount(&RGas::struct_tag()),
+        U256::from(6u64)
+    );
+    assert_eq!(quota.tx_count, 1);
+    assert!(quota.used_gas_amount > 0);
+
+    // transfer the coin beyond the coin quota, the transaction should abort.
+    let tx_data =
+        RoochTransactionData::new_for_test(sender, sequence_number + 2, transfer_action(6));
+    let tx = keystore
+        .sign_transaction_via_session_key(&sender, tx_data, &session_auth_key, None)
+        .unwrap();
+    let execute_result = binding_test.execute_as_result(tx).unwrap();
+    match execute_result.output.status {
+        KeptVMStatus::MoveAbort(l, code) => {
+            match l {
+                AbortLocation::Module(module_id) => {
+                    assert_eq!(
+                        module_id,
+                        SessionKeyModule::module_id(),
+                        "expect session key module"
+                    );
+                }
+                _ => panic!("expect move abort in module"),
+            }
+            // ErrorSessionKeyCoinQuotaExceeded = 6
+            assert_eq!(code, 6, "expect ErrorSessionKeyCoinQuotaExceeded");
+        }
+        _ => {
+            panic!("Expect move abort")
+        }
+    }
+
+    // the aborted transaction is counted, so the tx count quota is exhausted.
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, sequence_number + 3, action);
+    let tx = keystore
+        .sign_transaction_via_session_key(&sender, tx_data, &session_auth_key, None)
+        .unwrap();
+    let error = binding_test.execute_as_result(tx).unwrap_err();
+    match error.downcast_ref() {
+        Some(VMStatus::MoveAbort(l, code)) => {
+            match l {
+                AbortLocation::Module(module_id) => {
+                    assert_eq!(
+                        module_id,
+                        &SessionValidatorModule::module_id(),
+                        "expect session validator module"
+                    );
+                }
+                _ => panic!("expect move abort in module"),
+            }
+            // ErrorValidateSessionKeyQuotaExceeded = 1014
+            assert_eq!(*code, 1014, "expect ErrorValidateSessionKeyQuotaExceeded");
+        }
+        _ => {
+            panic!("Expect move abort")
+        }
+    }
+}
@@ -0,0 +1,185 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use accumulator::{Accumulator, MerkleAccumulator};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
+use moveos_store::transaction_store::TransactionStore as TxExecutionInfoStore;
+use moveos_types::h256::H256;
+use moveos_types::state::MoveStructType;
+use moveos_types::state_resolver::{RootObjectResolver, StateReaderExt};
+use rooch_config::RoochOpt;
+use rooch_db::snapshot::SnapshotManifest;
+use rooch_db::RoochDB;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_store::da_store::DAMetaStore;
+use rooch_store::meta_store::MetaStore;
+use rooch_types::block::Block;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::framework::transfer::TransferModule;
+use rooch_types::sequencer::SequencerInfo;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use rooch_types::transaction::{LedgerTransaction, TransactionSequenceInfo};
+
+#[tokio::test]
+async fn test_snapshot_export_and_import() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses().unwrap()[0];
+    binding_test
+        .get_rgas(sender.into(), U256::from(100_0000_0000u64))
+        .unwrap();
+    let action = TransferModule::create_transfer_coin_action(
+        RGas::struct_tag(),
+        AccountAddress::random(),
+        U256::from(100u64),
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    let result = binding_test.execute(tx.clone()).unwrap();
+    let tx_hash = result.transaction_info.tx_hash;
+    let state_root = result.transaction_info.state_root;
+
+    // Sequence the tx after the genesis tx, and make a block of it
+    let rooch_db = binding_test.rooch_db().clone();
+    let rooch_store = &rooch_db.rooch_store;
+    let sequencer_info = rooch_store.get_sequencer_info().unwrap().unwrap();
+    let tx_accumulator = MerkleAccumulator::new_with_info(
+        sequencer_info.last_accumulator_info,
+        rooch_store.get_transaction_accumulator_store(),
+    );
+    tx_accumulator.append(&[tx_hash]).unwrap();
+    let accumulator_info = tx_accumulator.get_info();
+    let tx_order = sequencer_info.last_order + 1;
+    let ledger_tx = LedgerTransaction::new_l2_tx(
+        tx,
+        TransactionSequenceInfo::new(tx_order, vec![], accumulator_info.clone(), 0),
+    );
+    rooch_store
+        .save_sequenced_tx(
+            tx_hash,
+            ledger_tx,
+            SequencerInfo::new(tx_order, accumulator_info.clone()),
+            tx_accumulator.pop_unsaved_nodes(),
+            false,
+        )
+        .unwrap();
+    let block = Block::new(
+        0,
+        tx_order,
+        H256::random(),
+        H256::zero(),
+        accumulator_info.accumulator_root,
+        state_root,
+    );
+
+    let mut snapshot = vec![];
+    let (manifest, node_count) = rooch_db.export_snapshot(None, &mut snapshot).unwrap();
+    assert_eq!(manifest.tx_order, tx_order);
+    assert_eq!(manifest.startup_info.state_root, state_root);
+    assert!(manifest.block.is_none());
+    assert!(node_count > 0);
+
+    let opt = RoochOpt::new_with_temp_store().unwrap();
+    let registry_service = metrics::RegistryService::default();
+    let new_db = RoochDB::init(opt.store_config(), &registry_service.default_registry()).unwrap();
+
+    // The snapshot is rejected if it does not match the trusted block
+    let mut untrusted_block = block.clone();
+    untrusted_block.state_root = H256::random();
+    assert!(new_db
+        .import_snapshot(&mut snapshot.as_slice(), &untrusted_block)
+        .is_err());
+    // The snapshot is rejected if any state node is tampered
+    let mut tampered_snapshot = snapshot.clone();
+    let last = tampered_snapshot.len() - 1;
+    tampered_snapshot[last] ^= 0xff;
+    assert!(new_db
+        .import_snapshot(&mut tampered_snapshot.as_slice(), &block)
+        .is_err());
+
+    // The snapshot is rejected if the last transaction is replaced
+    let mut tampered_manifest = manifest.clone();
+    let other_tx_data = RoochTransactionData::new_for_test(
+        sender,
+        1,
+        TransferModule::create_transfer_coin_action(
+            RGas::struct_tag(),
+            AccountAddress::random(),
+            U256::from(100u64),
+        ),
+    );
+    let other_tx = keystore
+        .sign_transaction(&sender, other_tx_data, None)
+        .unwrap();
+    tampered_manifest.ledger_tx =
+        LedgerTransaction::new_l2_tx(other_tx, manifest.ledger_tx.sequence_info.clone());
+    tampered_manifest.execution_info.tx_hash = tampered_manifest.ledger_tx.tx_hash();
+    assert!(tampered_manifest.verify(&block).is_err());
+    let mut tampered_snapshot = snapshot_records(&snapshot);
+    tampered_snapshot[0] = snapshot_record(&tampered_manifest);
+    assert!(new_db
+        .import_snapshot(&mut tampered_snapshot.concat().as_slice(), &block)
+        .is_err());
+    // A partially imported snapshot leaves the db empty, the import can be retried
+    let mut truncated_snapshot = snapshot_records(&snapshot);
+    truncated_snapshot.pop();
+    assert!(new_db
+        .import_snapshot(&mut truncated_snapshot.concat().as_slice(), &block)
+        .is_err());
+    assert!(new_db.rooch_store.get_sequencer_info().unwrap().is_none());
+    assert!(new_db.latest_root().unwrap().is_none());
+
+    let (imported, imported_node_count) = new_db
+        .import_snapshot(&mut snapshot.as_slice(), &block)
+        .unwrap();
+    assert_eq!(imported.tx_order, tx_order);
+    assert_eq!(imported_node_count, node_count);
+
+    let root = new_db.latest_root().unwrap().unwrap();
+    assert_eq!(root.state_root(), state_root);
+    let resolver = RootObjectResolver::new(root, &new_db.moveos_store);
+    let account = resolver.get_account(sender.into()).unwrap().unwrap();
+    assert_eq!(account.value.sequence_number, 1);
+
+    // The node continues from the snapshot
+    assert!(
+        new_db.rooch_store.get_sequencer_info().unwrap().unwrap()
+            == SequencerInfo::new(tx_order, accumulator_info)
+    );
+    assert!(new_db
+        .moveos_store
+        .get_tx_execution_info(tx_hash)
+        .unwrap()
+        .is_some());
+    assert_eq!(new_db.rooch_store.get_last_block_number().unwrap(), Some(0));
+    assert!(new_db.ensure_state_not_pruned(tx_order - 1).is_err());
+    // The snapshot can only be imported into an empty db
+    assert!(new_db
+        .import_snapshot(&mut snapshot.as_slice(), &block)
+        .is_err());
+}
+
+// Split the snapshot into the length-prefixed records
+fn snapshot_records(snapshot: &[u8]) -> Vec<Vec<u8>> {
+    let mut records = vec![];
+    let mut offset = 0;
+    while offset < snapshot.len() {
+        let len_bytes: [u8; 4] = snapshot[offset..offset + 4].try_into().unwrap();
+        let end = offset + 4 + u32::from_le_bytes(len_bytes) as usize;
+        records.push(snapshot[offset..end].to_vec());
+        offset = end;
+    }
+    records
+}
+
+fn snapshot_record(manifest: &SnapshotManifest) -> Vec<u8> {
+    let bytes = bcs::to_bytes(manifest).unwrap();
+    let mut record = (bytes.len() as u32).to_le_bytes().to_vec();
+    record.extend(bytes);
+    record
+}
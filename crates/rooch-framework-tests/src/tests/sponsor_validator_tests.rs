@@ -0,0 +1,90 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
+use move_core_types::vm_status::VMStatus;
+use moveos_types::state::MoveStructType;
+use moveos_types::state_resolver::StateResolver;
+use moveos_types::transaction::MoveAction;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::framework::account_coin_store::AccountCoinStoreModule;
+use rooch_types::framework::coin_store::CoinStoreInfo;
+use rooch_types::framework::empty::Empty;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::transaction::rooch::RoochTransactionData;
+
+fn gas_balance(binding_test: &binding_test::RustBindingTest, addr: AccountAddress) -> U256 {
+    let coin_store_id = AccountCoinStoreModule::account_coin_store_id(addr, RGas::struct_tag());
+    binding_test
+        .resolver()
+        .get_object(&coin_store_id)
+        .unwrap()
+        .map(|state| CoinStoreInfo::try_from(state).unwrap().balance())
+        .unwrap_or_default()
+}
+
+fn empty_action() -> MoveAction {
+    MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![])
+}
+
+#[tokio::test]
+async fn test_sponsor_transaction() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(2);
+    let sender = keystore.addresses().unwrap()[0];
+    let gas_payer = keystore.addresses().unwrap()[1];
+
+    // the gas payer account is created and funded by its first transaction
+    let tx_data = RoochTransactionData::new_for_test(gas_payer, 0, empty_action());
+    let tx = keystore
+        .sign_transaction(&gas_payer, tx_data, None)
+        .unwrap();
+    binding_test.execute(tx).unwrap();
+
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, empty_action());
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    let tx = keystore.sponsor_transaction(&gas_payer, tx, None).unwrap();
+    let result = binding_test.execute(tx).unwrap();
+    assert_eq!(result.transaction_info.gas_payer, gas_payer.into());
+
+    // the sender does not pay the gas of the sponsored transaction
+    let sender_balance = gas_balance(&binding_test, sender.into());
+    let gas_payer_balance = gas_balance(&binding_test, gas_payer.into());
+
+    let tx_data = RoochTransactionData::new_for_test(sender, 1, empty_action());
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    let tx = keystore.sponsor_transaction(&gas_payer, tx, None).unwrap();
+    let result = binding_test.execute(tx).unwrap();
+    assert!(result.transaction_info.gas_used > 0);
+    assert_eq!(gas_balance(&binding_test, sender.into()), sender_balance);
+    assert!(gas_balance(&binding_test, gas_payer.into()) < gas_payer_balance);
+}
+
+#[tokio::test]
+async fn test_sponsor_transaction_with_nonexistent_gas_payer() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(2);
+    let sender = keystore.addresses().unwrap()[0];
+    let gas_payer = keystore.addresses().unwrap()[1];
+
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, empty_action());
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    let tx = keystore.sponsor_transaction(&gas_payer, tx, None).unwrap();
+    let error = binding_test.execute_as_result(tx).unwrap_err();
+    match error.downcast_ref() {
+        Some(VMStatus::MoveAbort(_, code)) => {
+            // ErrorValidateAccountDoesNotExist = 1003
+            assert_eq!(*code, 1003, "expect ErrorValidateAccountDoesNotExist");
+        }
+        _ => {
+            panic!("Expect move abort")
+        }
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_binary_format::file_format::Bytecode;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
+use move_core_types::vm_status::{AbortLocation, KeptVMStatus};
+use moveos_types::module_binding::ModuleBinding;
+use moveos_types::moveos_std::module_store::Package;
+use moveos_types::moveos_std::move_module::MoveModuleDynamicField;
+use moveos_types::state::MoveStructType;
+use moveos_types::state_override::ObjectOverride;
+use moveos_types::state_resolver::StateResolver;
+use moveos_types::transaction::MoveAction;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::framework::account_coin_store::AccountCoinStoreModule;
+use rooch_types::framework::empty::Empty;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::framework::transfer::TransferModule;
+use rooch_types::transaction::rooch::RoochTransactionData;
+
+#[tokio::test]
+async fn test_dry_run_with_balance_override() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses().unwrap()[0];
+    let receiver = AccountAddress::random();
+    // The sender only has enough RGas to pay the gas
+    binding_test
+        .get_rgas(sender.into(), U256::from(100_0000_0000u64))
+        .unwrap();
+
+    let coin_store_id =
+        AccountCoinStoreModule::account_coin_store_id(sender.into(), RGas::struct_tag());
+    let coin_store_before = binding_test
+        .resolver()
+        .get_object(&coin_store_id)
+        .unwrap()
+        .unwrap();
+    let root_before = binding_test.root().clone();
+
+    let amount = U256::from(1_000_000_0000_0000u64);
+    let action = TransferModule::create_transfer_coin_action(RGas::struct_tag(), receiver, amount);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+
+    // Without the override, the transfer fails because of the insufficient balance
+    let tx = binding_test
+        .executor
+        .convert_to_verified_tx_for_dry_run(tx_data.clone())
+        .unwrap();
+    let result = binding_test.executor.dry_run(tx, vec![]).unwrap();
+    assert!(
+        matches!(result.raw_output.status, KeptVMStatus::MoveAbort(_, _)),
+        "expect move abort, got {:?}",
+        result.raw_output.status
+    );
+
+    // Pretend the sender holds enough RGas
+    let state_override = AccountCoinStoreModule::balance_override(
+        sender.into(),
+        RGas::struct_tag(),
+        amount * U256::from(2u64),
+    )
+    .unwrap();
+    let tx = binding_test
+        .executor
+        .convert_to_verified_tx_for_dry_run(tx_data)
+        .unwrap();
+    let result = binding_test
+        .executor
+        .dry_run(tx, vec![state_override])
+        .unwrap();
+    assert_eq!(result.raw_output.status, KeptVMStatus::Executed);
+
+    // The override does not change the state
+    assert_eq!(binding_test.root(), &root_before);
+    let coin_store_after = binding_test
+        .resolver()
+        .get_object(&coin_store_id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(coin_store_after, coin_store_before);
+}
+
+#[tokio::test]
+async fn test_dry_run_with_module_override() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses().unwrap()[0];
+    // Change the behavior of the empty function, it aborts with the code 42
+    let module_bytes = binding_test
+        .replace_function_code(
+            &Empty::module_id(),
+            Empty::EMPTY_FUNCTION_NAME,
+            vec![Bytecode::LdU64(42), Bytecode::Abort],
+        )
+        .unwrap();
+    let state_override = ObjectOverride::module(module_bytes).unwrap();
+    assert!(state_override.is_module());
+    assert!(binding_test
+        .resolver()
+        .get_object(&state_override.id)
+        .unwrap()
+        .is_some());
+
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = binding_test
+        .executor
+        .convert_to_verified_tx_for_dry_run(tx_data.clone())
+        .unwrap();
+    let result = binding_test
+        .executor
+        .dry_run(tx, vec![state_override.clone()])
+        .unwrap();
+    assert_eq!(
+        result.raw_output.status,
+        KeptVMStatus::MoveAbort(AbortLocation::Module(Empty::module_id()), 42)
+    );
+
+    // The overridden module does not leak into the following executions
+    let tx = binding_test
+        .executor
+        .convert_to_verified_tx_for_dry_run(tx_data)
+        .unwrap();
+    let result = binding_test.executor.dry_run(tx, vec![]).unwrap();
+    assert_eq!(result.raw_output.status, KeptVMStatus::Executed);
+
+    // The override is rejected if the package of the module does not exist
+    let module_field_key = state_override.id.field_key();
+    let state_override = ObjectOverride::new(
+        Package::package_id(&AccountAddress::from_hex_literal("0x42").unwrap())
+            .child_id(module_field_key),
+        None,
+        Some(MoveModuleDynamicField::type_tag()),
+        state_override.value,
+    );
+    let tx_data = RoochTransactionData::new_for_test(
+        sender,
+        0,
+        MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]),
+    );
+    let tx = binding_test
+        .executor
+        .convert_to_verified_tx_for_dry_run(tx_data)
+        .unwrap();
+    assert!(binding_test
+        .executor
+        .dry_run(tx, vec![state_override])
+        .is_err());
+}
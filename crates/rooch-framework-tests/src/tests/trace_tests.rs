@@ -0,0 +1,115 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
+use move_core_types::value::MoveValue;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_gas_profiling::log::FrameName;
+use moveos_types::move_types::FunctionId;
+use moveos_types::state::MoveStructType;
+use moveos_types::transaction::{FunctionCall, MoveAction};
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::framework::transfer::TransferModule;
+use rooch_types::test_utils::random_accumulator_info;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use rooch_types::transaction::{LedgerTransaction, TransactionSequenceInfo};
+use std::str::FromStr;
+
+#[tokio::test]
+async fn test_trace_transaction() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses().unwrap()[0];
+    binding_test
+        .get_rgas(sender.into(), U256::from(100_0000_0000u64))
+        .unwrap();
+
+    let pre_root = binding_test.root().clone();
+    let action = TransferModule::create_transfer_coin_action(
+        RGas::struct_tag(),
+        AccountAddress::random(),
+        U256::from(100u64),
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    let result = binding_test.execute(tx.clone()).unwrap();
+    let latest_root = binding_test.root().clone();
+
+    let sequence_info = TransactionSequenceInfo::new(1, vec![], random_accumulator_info(), 0);
+    let ledger_tx = LedgerTransaction::new_l2_tx(tx, sequence_info);
+    let trace = binding_test
+        .executor
+        .trace_transaction(pre_root, ledger_tx, None)
+        .unwrap();
+
+    assert_eq!(trace.raw_output.status, KeptVMStatus::Executed);
+    assert_eq!(trace.raw_output.gas_used, result.output.gas_used);
+    assert_eq!(
+        u64::from(trace.gas_log.exec_io.total),
+        result.output.gas_used
+    );
+    match &trace.gas_log.exec_io.call_graph.name {
+        FrameName::Function { name, .. } => assert_eq!(name.as_str(), "transfer_coin"),
+        name => panic!("expect function frame, got {:?}", name),
+    }
+    assert!(!trace.read_set.is_empty());
+
+    // Tracing does not change the state
+    assert_eq!(binding_test.root(), &latest_root);
+}
+
+#[tokio::test]
+async fn test_trace_batch_transaction() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses().unwrap()[0];
+    binding_test
+        .get_rgas(sender.into(), U256::from(100_0000_0000u64))
+        .unwrap();
+
+    let pre_root = binding_test.root().clone();
+    // The native function is called directly by the batch without a call frame
+    let hash_call = FunctionCall::new(
+        FunctionId::from_str("0x1::hash::sha3_256").unwrap(),
+        vec![],
+        vec![MoveValue::vector_u8(b"rooch".to_vec())
+            .simple_serialize()
+            .unwrap()],
+    );
+    let transfer_call = TransferModule::create_transfer_coin_action(
+        RGas::struct_tag(),
+        AccountAddress::random(),
+        U256::from(100u64),
+    );
+    let MoveAction::Function(transfer_call) = transfer_call else {
+        panic!("expect function call action");
+    };
+    let action = MoveAction::new_batch_call(vec![hash_call.into(), transfer_call.into()]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    let result = binding_test.execute(tx.clone()).unwrap();
+
+    let sequence_info = TransactionSequenceInfo::new(1, vec![], random_accumulator_info(), 0);
+    let ledger_tx = LedgerTransaction::new_l2_tx(tx, sequence_info);
+    let trace = binding_test
+        .executor
+        .trace_transaction(pre_root, ledger_tx, None)
+        .unwrap();
+
+    assert_eq!(trace.raw_output.status, KeptVMStatus::Executed);
+    assert_eq!(trace.raw_output.gas_used, result.output.gas_used);
+    // The calls of the batch are recorded in the script frame
+    assert!(matches!(
+        trace.gas_log.exec_io.call_graph.name,
+        FrameName::Script
+    ));
+    assert!(!trace.gas_log.exec_io.call_graph.events.is_empty());
+}
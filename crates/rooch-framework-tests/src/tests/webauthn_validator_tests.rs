@@ -0,0 +1,111 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::vm_status::{AbortLocation, VMStatus};
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::move_std::string::MoveString;
+use moveos_types::{module_binding::ModuleBinding, transaction::MoveAction};
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::framework::session_key::SessionKeyModule;
+use rooch_types::framework::webauthn_validator::WebauthnValidatorModule;
+use rooch_types::transaction::RoochTransaction;
+use rooch_types::{addresses::ROOCH_FRAMEWORK_ADDRESS, framework::empty::Empty};
+use rooch_types::{framework::session_key::SessionScope, transaction::rooch::RoochTransactionData};
+use std::str::FromStr;
+
+#[tokio::test]
+async fn test_webauthn_passkey() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let mut keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses().unwrap()[0];
+    let sequence_number = 0;
+
+    // register the passkey as a session key of the account
+    let passkey_auth_key = keystore
+        .generate_webauthn_session_key(&sender, None)
+        .unwrap();
+
+    let session_scope = SessionScope::new(ROOCH_FRAMEWORK_ADDRESS, "*", "*").unwrap();
+    let max_inactive_interval = 100;
+    let action = SessionKeyModule::create_session_key_action(
+        MoveString::from_str("passkey").unwrap(),
+        MoveString::from_str("https://rooch.network").unwrap(),
+        passkey_auth_key.as_ref().to_vec(),
+        session_scope.clone(),
+        max_inactive_interval,
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, sequence_number, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+
+    let session_key = binding_test
+        .as_module_binding::<SessionKeyModule>()
+        .get_session_key(sender.into(), &passkey_auth_key)
+        .unwrap()
+        .expect("Session key not found");
+    keystore.binding_session_key(sender, session_key).unwrap();
+
+    // send transaction via the passkey
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, sequence_number + 1, action);
+    let tx = keystore
+        .sign_transaction_via_session_key(&sender, tx_data, &passkey_auth_key, None)
+        .unwrap();
+    binding_test.execute(tx).unwrap();
+
+    // replay the passkey assertion with another transaction, the challenge does not match the tx hash
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let signed_tx_data = RoochTransactionData::new_for_test(sender, sequence_number + 2, action);
+    let signed_tx = keystore
+        .sign_transaction_via_session_key(&sender, signed_tx_data, &passkey_auth_key, None)
+        .unwrap();
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let mut other_tx_data = RoochTransactionData::new_for_test(sender, sequence_number + 2, action);
+    other_tx_data.max_gas_amount += 1;
+    let tx = RoochTransaction::new(other_tx_data, signed_tx.authenticator().clone());
+
+    let error = binding_test.execute_as_result(tx).unwrap_err();
+    match error.downcast_ref() {
+        Some(VMStatus::MoveAbort(l, code)) => {
+            match l {
+                AbortLocation::Module(module_id) => {
+                    assert_eq!(
+                        module_id,
+                        &WebauthnValidatorModule::module_id(),
+                        "expect webauthn validator module"
+                    );
+                }
+                _ => panic!("expect move abort in module"),
+            }
+            // ErrorValidateInvalidAuthenticator = 1010
+            assert_eq!(*code, 1010, "expect ErrorValidateInvalidAuthenticator");
+        }
+        _ => {
+            panic!("Expect move abort")
+        }
+    }
+
+    // a passkey which is not registered as session key should be rejected
+    let unregistered_auth_key = keystore
+        .generate_webauthn_session_key(&sender, None)
+        .unwrap();
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, sequence_number + 2, action);
+    let tx = keystore
+        .sign_transaction_via_session_key(&sender, tx_data, &unregistered_auth_key, None)
+        .unwrap();
+    let error = binding_test.execute_as_result(tx).unwrap_err();
+    match error.downcast_ref() {
+        Some(VMStatus::MoveAbort(_, code)) => {
+            // ErrorValidateInvalidAccountAuthKey = 1009
+            assert_eq!(*code, 1009, "expect ErrorValidateInvalidAccountAuthKey");
+        }
+        _ => {
+            panic!("Expect move abort")
+        }
+    }
+}
@@ -0,0 +1,322 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::u256::U256;
+use move_core_types::vm_status::VMStatus;
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::state::MoveStructType;
+use moveos_types::transaction::MoveAction;
+use rooch_types::address::RoochAddress;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::empty::Empty;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::framework::transfer::TransferModule;
+use rooch_types::framework::weighted_multisign_account::{
+    self, Participant, ParticipantScheme, WeightedMultisignAccountModule,
+};
+use rooch_types::transaction::rooch::{
+    PartiallySignedWeightedMultisignTransaction, RoochTransactionData,
+};
+use rooch_types::transaction::{Authenticator, RoochTransaction};
+
+fn empty_action() -> MoveAction {
+    MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![])
+}
+
+fn assert_invalid_authenticator(
+    binding_test: &mut binding_test::RustBindingTest,
+    tx: RoochTransaction,
+) {
+    let error = binding_test.execute_as_result(tx).unwrap_err();
+    match error.downcast_ref() {
+        Some(VMStatus::MoveAbort(_, code)) => {
+            // ErrorValidateInvalidAuthenticator = 1010
+            assert_eq!(*code, 1010, "expect ErrorValidateInvalidAuthenticator");
+        }
+        _ => {
+            panic!("Expect move abort")
+        }
+    }
+}
+
+struct TestMultisignAccount {
+    address: RoochAddress,
+    ed25519_kp: RoochKeyPair,
+    secp256k1_kp: RoochKeyPair,
+    ethereum_kp: RoochKeyPair,
+}
+
+/// Create a 3-of-4 weighted multisign account with an Ed25519(1), a Secp256k1(1) and an Ethereum(2) participant
+fn create_multisign_account(
+    binding_test: &mut binding_test::RustBindingTest,
+) -> TestMultisignAccount {
+    let creator_kp = RoochKeyPair::generate_secp256k1();
+    let creator = creator_kp.public().rooch_address().unwrap();
+
+    let ed25519_kp = RoochKeyPair::generate_ed25519();
+    let secp256k1_kp = RoochKeyPair::generate_secp256k1();
+    let ethereum_kp = RoochKeyPair::generate_secp256k1();
+
+    let participants = vec![
+        Participant::from_public_key(ParticipantScheme::Ed25519, &ed25519_kp.public(), 1).unwrap(),
+        Participant::from_public_key(ParticipantScheme::Secp256k1, &secp256k1_kp.public(), 1)
+            .unwrap(),
+        Participant::from_public_key(ParticipantScheme::Ethereum, &ethereum_kp.public(), 2)
+            .unwrap(),
+    ];
+    let address = weighted_multisign_account::generate_multisign_address(3, &participants).unwrap();
+
+    let action =
+        WeightedMultisignAccountModule::create_multisign_account_action(3, participants.clone());
+    let tx = RoochTransactionData::new_for_test(creator, 0, action).sign(&creator_kp);
+    binding_test.execute(tx).unwrap();
+
+    let module = binding_test.as_module_binding::<WeightedMultisignAccountModule>();
+    assert!(module.is_multisign_account(address.into()).unwrap());
+    assert_eq!(module.threshold(address.into()).unwrap(), 3);
+    assert_eq!(module.participants(address.into()).unwrap(), participants);
+
+    //transfer gas coin to the multisign account
+    let gas_action = TransferModule::create_transfer_coin_action(
+        RGas::struct_tag(),
+        address.into(),
+        U256::from(100000000000u128),
+    );
+    let tx = RoochTransactionData::new_for_test(creator, 1, gas_action).sign(&creator_kp);
+    binding_test.execute(tx).unwrap();
+
+    TestMultisignAccount {
+        address,
+        ed25519_kp,
+        secp256k1_kp,
+        ethereum_kp,
+    }
+}
+
+#[tokio::test]
+async fn test_weighted_multisign_validate() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+    let account = create_multisign_account(&mut binding_test);
+
+    // Ed25519(1) + Ethereum(2) reach the threshold
+    let tx_data = RoochTransactionData::new_for_test(account.address, 0, empty_action());
+    let authenticator = Authenticator::weighted_multisign(
+        &[
+            (ParticipantScheme::Ed25519, &account.ed25519_kp),
+            (ParticipantScheme::Ethereum, &account.ethereum_kp),
+        ],
+        &tx_data,
+    )
+    .unwrap();
+    binding_test
+        .execute(RoochTransaction::new(tx_data, authenticator))
+        .unwrap();
+
+    // Ed25519(1) + Secp256k1(1) do not reach the threshold
+    let tx_data = RoochTransactionData::new_for_test(account.address, 1, empty_action());
+    let authenticator = Authenticator::weighted_multisign(
+        &[
+            (ParticipantScheme::Ed25519, &account.ed25519_kp),
+            (ParticipantScheme::Secp256k1, &account.secp256k1_kp),
+        ],
+        &tx_data,
+    )
+    .unwrap();
+    assert_invalid_authenticator(
+        &mut binding_test,
+        RoochTransaction::new(tx_data.clone(), authenticator),
+    );
+
+    // The same signer can not be counted twice
+    let authenticator = Authenticator::weighted_multisign(
+        &[(ParticipantScheme::Ethereum, &account.ethereum_kp)],
+        &tx_data,
+    )
+    .unwrap();
+    let mut payload = bcs::from_bytes::<
+        rooch_types::framework::weighted_multisign_validator::WeightedMultisignAuthPayload,
+    >(&authenticator.payload)
+    .unwrap();
+    payload.public_keys.push(payload.public_keys[0].clone());
+    payload.signatures.push(payload.signatures[0].clone());
+    let authenticator = Authenticator::new(
+        authenticator.auth_validator_id,
+        bcs::to_bytes(&payload).unwrap(),
+    );
+    assert_invalid_authenticator(
+        &mut binding_test,
+        RoochTransaction::new(tx_data.clone(), authenticator),
+    );
+
+    // A key which is not a participant is rejected
+    let outsider_kp = RoochKeyPair::generate_ed25519();
+    let authenticator = Authenticator::weighted_multisign(
+        &[
+            (ParticipantScheme::Ed25519, &outsider_kp),
+            (ParticipantScheme::Ethereum, &account.ethereum_kp),
+        ],
+        &tx_data,
+    )
+    .unwrap();
+    assert_invalid_authenticator(
+        &mut binding_test,
+        RoochTransaction::new(tx_data, authenticator),
+    );
+}
+
+#[tokio::test]
+async fn test_weighted_multisign_partially_signed_transaction() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+    let account = create_multisign_account(&mut binding_test);
+
+    let module = binding_test.as_module_binding::<WeightedMultisignAccountModule>();
+    let threshold = module.threshold(account.address.into()).unwrap();
+    let participants = module.participants(account.address.into()).unwrap();
+
+    let tx_data = RoochTransactionData::new_for_test(account.address, 0, empty_action());
+
+    // Each signer signs the transaction separately, then the partially signed transactions are merged
+    let mut pswrt1 = PartiallySignedWeightedMultisignTransaction::new(
+        tx_data.clone(),
+        threshold,
+        participants.clone(),
+    );
+    pswrt1.sign(&account.ed25519_kp).unwrap();
+    pswrt1.sign(&account.secp256k1_kp).unwrap();
+    assert!(pswrt1.sign(&account.ed25519_kp).is_err());
+    assert_eq!(pswrt1.signed_weight(), 2);
+    assert!(!pswrt1.is_fully_signed());
+
+    let mut pswrt2 = PartiallySignedWeightedMultisignTransaction::new(
+        tx_data.clone(),
+        threshold,
+        participants.clone(),
+    );
+    pswrt2.sign(&account.ethereum_kp).unwrap();
+    assert!(pswrt2.sign(&RoochKeyPair::generate_secp256k1()).is_err());
+
+    // The partially signed transactions of different threshold or participants can not be merged
+    let mut other_threshold = PartiallySignedWeightedMultisignTransaction::new(
+        tx_data.clone(),
+        threshold - 1,
+        participants.clone(),
+    );
+    other_threshold.sign(&account.ethereum_kp).unwrap();
+    assert!(pswrt1.clone().merge(other_threshold).is_err());
+    let mut other_participants = participants.clone();
+    other_participants[2].weight = threshold;
+    let mut other_participants =
+        PartiallySignedWeightedMultisignTransaction::new(tx_data, threshold, other_participants);
+    other_participants.sign(&account.ethereum_kp).unwrap();
+    assert!(pswrt1.clone().merge(other_participants).is_err());
+
+    pswrt1.merge(pswrt2).unwrap();
+    assert_eq!(pswrt1.signatories(), 3);
+    assert!(pswrt1.is_fully_signed());
+
+    let tx = pswrt1.try_into_rooch_transaction().unwrap();
+    binding_test.execute(tx).unwrap();
+}
+
+#[tokio::test]
+async fn test_weighted_multisign_rotation() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+    let account = create_multisign_account(&mut binding_test);
+    let new_kp = RoochKeyPair::generate_ed25519();
+
+    // Replace the Secp256k1 participant with a new Ed25519 participant and lower the threshold
+    let new_participants = vec![
+        Participant::from_public_key(ParticipantScheme::Ed25519, &account.ed25519_kp.public(), 1)
+            .unwrap(),
+        Participant::from_public_key(ParticipantScheme::Ed25519, &new_kp.public(), 1).unwrap(),
+        Participant::from_public_key(
+            ParticipantScheme::Ethereum,
+            &account.ethereum_kp.public(),
+            2,
+        )
+        .unwrap(),
+    ];
+    let action =
+        WeightedMultisignAccountModule::rotate_participants_action(2, new_participants.clone());
+    let tx_data = RoochTransactionData::new_for_test(account.address, 0, action);
+    let authenticator = Authenticator::weighted_multisign(
+        &[
+            (ParticipantScheme::Ed25519, &account.ed25519_kp),
+            (ParticipantScheme::Ethereum, &account.ethereum_kp),
+        ],
+        &tx_data,
+    )
+    .unwrap();
+    binding_test
+        .execute(RoochTransaction::new(tx_data, authenticator))
+        .unwrap();
+
+    // The address of the multisign account does not change after rotation
+    let module = binding_test.as_module_binding::<WeightedMultisignAccountModule>();
+    assert!(module.is_multisign_account(account.address.into()).unwrap());
+    assert_eq!(module.threshold(account.address.into()).unwrap(), 2);
+    assert_eq!(
+        module.participants(account.address.into()).unwrap(),
+        new_participants
+    );
+
+    // The removed participant can not sign anymore
+    let tx_data = RoochTransactionData::new_for_test(account.address, 1, empty_action());
+    let authenticator = Authenticator::weighted_multisign(
+        &[
+            (ParticipantScheme::Ed25519, &account.ed25519_kp),
+            (ParticipantScheme::Secp256k1, &account.secp256k1_kp),
+        ],
+        &tx_data,
+    )
+    .unwrap();
+    assert_invalid_authenticator(
+        &mut binding_test,
+        RoochTransaction::new(tx_data.clone(), authenticator),
+    );
+
+    // The new participants reach the new threshold
+    let authenticator = Authenticator::weighted_multisign(
+        &[
+            (ParticipantScheme::Ed25519, &account.ed25519_kp),
+            (ParticipantScheme::Ed25519, &new_kp),
+        ],
+        &tx_data,
+    )
+    .unwrap();
+    binding_test
+        .execute(RoochTransaction::new(tx_data, authenticator))
+        .unwrap();
+
+    // Update the threshold, then the two Ed25519 participants are not enough
+    let action = WeightedMultisignAccountModule::update_threshold_action(4);
+    let tx_data = RoochTransactionData::new_for_test(account.address, 2, action);
+    let authenticator = Authenticator::weighted_multisign(
+        &[(ParticipantScheme::Ethereum, &account.ethereum_kp)],
+        &tx_data,
+    )
+    .unwrap();
+    binding_test
+        .execute(RoochTransaction::new(tx_data, authenticator))
+        .unwrap();
+    let module = binding_test.as_module_binding::<WeightedMultisignAccountModule>();
+    assert_eq!(module.threshold(account.address.into()).unwrap(), 4);
+
+    let tx_data = RoochTransactionData::new_for_test(account.address, 3, empty_action());
+    let authenticator = Authenticator::weighted_multisign(
+        &[
+            (ParticipantScheme::Ed25519, &account.ed25519_kp),
+            (ParticipantScheme::Ed25519, &new_kp),
+        ],
+        &tx_data,
+    )
+    .unwrap();
+    assert_invalid_authenticator(
+        &mut binding_test,
+        RoochTransaction::new(tx_data, authenticator),
+    );
+}
@@ -11,7 +11,7 @@ use move_core_types::gas_algebra::{InternalGas, InternalGasPerArg};
 use move_core_types::value::MoveTypeLayout;
 use move_core_types::{account_address::AccountAddress, identifier::Identifier};
 use move_vm_runtime::native_functions::NativeFunction;
-use moveos::gas::table::VMGasParameters;
+use moveos::gas::table::{get_gas_schedule_config, get_pending_gas_schedule, VMGasParameters};
 use moveos::moveos::{MoveOS, MoveOSConfig};
 use moveos_stdlib::natives::moveos_stdlib::base64::EncodeDecodeGasParametersOption;
 use moveos_stdlib::natives::moveos_stdlib::object::ListFieldsGasParametersOption;
@@ -19,7 +19,7 @@ use moveos_store::MoveOSStore;
 use moveos_types::genesis_info::GenesisInfo;
 use moveos_types::h256::H256;
 use moveos_types::move_std::string::MoveString;
-use moveos_types::moveos_std::gas_schedule::{GasEntry, GasSchedule, GasScheduleConfig};
+use moveos_types::moveos_std::gas_schedule::{GasEntry, GasScheduleConfig};
 use moveos_types::moveos_std::object::ObjectMeta;
 use moveos_types::state::{ObjectState, StateChangeSetExt};
 use moveos_types::transaction::{MoveAction, MoveOSTransaction, RawTransactionOutput};
@@ -205,14 +205,24 @@ impl FrameworksGasParameters {
     }
 
     pub fn load_from_chain(state_resolver: &dyn state_resolver::StateResolver) -> Result<Self> {
-        let gas_schedule_state = state_resolver
-            .get_object(&GasSchedule::gas_schedule_object_id())?
+        let (gas_schedule_config, _) = get_gas_schedule_config(state_resolver)?
             .ok_or_else(|| anyhow::anyhow!("Gas schedule object not found"))?;
-        let gas_schedule = gas_schedule_state.into_object::<GasSchedule>()?;
-        Self::load_from_gas_entries(
-            gas_schedule.value.max_gas_amount,
-            gas_schedule.value.entries,
-        )
+        Self::load_from_gas_config(&gas_schedule_config)
+    }
+
+    /// Load the gas parameters of the pending gas schedule with its activation time,
+    /// they take effect when the on-chain time reaches the activation time.
+    pub fn load_pending_from_chain(
+        state_resolver: &dyn state_resolver::StateResolver,
+    ) -> Result<Option<(u64, Self)>> {
+        get_pending_gas_schedule(state_resolver)?
+            .map(|pending| {
+                Ok((
+                    pending.activation_time,
+                    Self::load_from_gas_config(&pending.gas_schedule_config)?,
+                ))
+            })
+            .transpose()
     }
 
     pub fn load_from_gas_config(gas_config: &GasScheduleConfig) -> Result<Self> {
@@ -477,9 +487,11 @@ impl RoochGenesis {
         genesis_tx_accumulator.clear_after_save();
 
         let tx_hash = self.genesis_tx().tx_hash();
-        let (output, genesis_execution_info) = rooch_db
-            .moveos_store
-            .handle_tx_output(tx_hash, genesis_raw_output.clone())?;
+        let (output, genesis_execution_info) = rooch_db.moveos_store.handle_tx_output(
+            tx_hash,
+            self.genesis_moveos_tx().ctx.sender(),
+            genesis_raw_output.clone(),
+        )?;
 
         // Save genesis tx state change set
         let state_change_set_ext = StateChangeSetExt::new(
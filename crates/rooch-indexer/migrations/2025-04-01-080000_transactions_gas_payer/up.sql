@@ -0,0 +1,2 @@
+-- The account which paid the gas, it is null for the transactions indexed before the sponsored transaction support, which were paid by the sender.
+ALTER TABLE transactions ADD COLUMN gas_payer VARCHAR;
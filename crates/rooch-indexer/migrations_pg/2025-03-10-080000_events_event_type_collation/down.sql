@@ -0,0 +1 @@
+ALTER TABLE events ALTER COLUMN event_type TYPE VARCHAR COLLATE "default";
@@ -0,0 +1,2 @@
+-- The event module filter queries the event type by prefix range, the "C" collation keeps the byte order as SQLite.
+ALTER TABLE events ALTER COLUMN event_type TYPE VARCHAR COLLATE "C";
@@ -40,6 +40,23 @@ pub enum IndexerError {
     #[error(transparent)]
     SQLiteError(#[from] diesel::result::Error),
 
+    #[error("Indexer failed to build Postgres connection pool with error: `{0}`")]
+    PostgresConnectionPoolInitError(String),
+
+    #[error(
+        "Indexer failed to get a pool connection from Postgres connection pool with error: `{0}`"
+    )]
+    PostgresPoolConnectionError(String),
+
+    #[error("Indexer failed to read PostgresDB with error: `{0}`")]
+    PostgresReadError(String),
+
+    #[error("Indexer async read PostgresDB error: `{0}`")]
+    PostgresAsyncReadError(String),
+
+    #[error("Indexer failed to write PostgresDB with error: `{0}`")]
+    PostgresWriteError(String),
+
     #[error("Indexer failed to serialize/deserialize with error: `{0}`")]
     SerdeError(String),
 
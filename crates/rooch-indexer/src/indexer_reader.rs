@@ -7,8 +7,9 @@ use crate::models::events::StoredEvent;
 use crate::models::fields::StoredField;
 use crate::models::states::{StoredObjectStateInfo, StoredStateID};
 use crate::models::transactions::StoredTransaction;
-use crate::schema::{events, transactions};
 use crate::utils::escape_sql_string;
+#[cfg(not(feature = "postgres"))]
+use crate::POSTGRES_FEATURE_DISABLED_ERROR;
 use crate::{
     IndexerResult, IndexerStoreMeta, IndexerTableName, SqliteConnectionConfig,
     SqliteConnectionPoolConfig, SqlitePoolConnection, DEFAULT_BUSY_TIMEOUT,
@@ -16,10 +17,13 @@ use crate::{
     INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME, INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME,
     INDEXER_TRANSACTIONS_TABLE_NAME,
 };
+#[cfg(feature = "postgres")]
+use crate::{PostgresConnectionPool, PostgresConnectionPoolConfig, PostgresPoolConnection};
 use anyhow::{anyhow, Result};
-use diesel::{
-    r2d2::ConnectionManager, Connection, ExpressionMethods, QueryDsl, RunQueryDsl, SqliteConnection,
-};
+#[cfg(feature = "postgres")]
+use diesel::pg::{Pg, PgConnection};
+use diesel::sqlite::Sqlite;
+use diesel::{r2d2::ConnectionManager, Connection, QueryableByName, RunQueryDsl, SqliteConnection};
 use function_name::named;
 use move_core_types::language_storage::StructTag;
 use moveos_types::moveos_std::object::ObjectID;
@@ -136,12 +140,104 @@ impl InnerIndexerReader {
     }
 }
 
+/// The rows loaded by the raw SQL queries of the indexer reader, they can be loaded from every backend.
+#[cfg(feature = "postgres")]
+pub trait IndexerQueryableRow:
+    QueryableByName<Sqlite> + QueryableByName<Pg> + Send + 'static
+{
+}
+#[cfg(feature = "postgres")]
+impl<T> IndexerQueryableRow for T where
+    T: QueryableByName<Sqlite> + QueryableByName<Pg> + Send + 'static
+{
+}
+
+/// The rows loaded by the raw SQL queries of the indexer reader, they can be loaded from every backend.
+#[cfg(not(feature = "postgres"))]
+pub trait IndexerQueryableRow: QueryableByName<Sqlite> + Send + 'static {}
+#[cfg(not(feature = "postgres"))]
+impl<T> IndexerQueryableRow for T where T: QueryableByName<Sqlite> + Send + 'static {}
+
+/// The reader of the Postgres indexer db, all the indexer tables are in one database.
+#[cfg(feature = "postgres")]
+#[derive(Clone)]
+pub struct PostgresIndexerReader {
+    pub(crate) pool: PostgresConnectionPool,
+}
+
+#[cfg(feature = "postgres")]
+impl PostgresIndexerReader {
+    pub fn new_with_config<T: Into<String>>(
+        db_url: T,
+        config: PostgresConnectionPoolConfig,
+    ) -> Result<Self> {
+        let manager = ConnectionManager::<PgConnection>::new(db_url);
+
+        let pool = diesel::r2d2::Pool::builder()
+            .max_size(config.pool_size)
+            .connection_timeout(config.connection_timeout)
+            .connection_customizer(Box::new(config.connection_config()))
+            .build(manager)
+            .map_err(|e| anyhow!("Failed to initialize Postgres connection pool. Error: {:?}. If Error is None, please check whether the configured pool size (currently {}) exceeds the maximum number of connections allowed by the database.", e, config.pool_size))?;
+
+        Ok(Self { pool })
+    }
+
+    pub fn get_connection(&self) -> Result<PostgresPoolConnection, IndexerError> {
+        self.pool.get().map_err(|e| {
+            IndexerError::PostgresPoolConnectionError(format!(
+                "Failed to get connection from Postgres connection pool with error: {:?}",
+                e
+            ))
+        })
+    }
+
+    pub fn run_query_with_timeout<T, E, F>(&self, query: F) -> Result<T, IndexerError>
+    where
+        F: FnOnce(&mut PgConnection) -> Result<T, E> + Send + 'static,
+        E: From<diesel::result::Error> + std::error::Error + Send,
+        T: Send + 'static,
+    {
+        // default query time out in second
+        let timeout_duration = Duration::from_secs(DEFAULT_QUERY_TIMEOUT);
+        let mut connection = self.get_connection()?;
+
+        tokio::task::block_in_place(|| {
+            Handle::current().block_on(async move {
+                timeout(
+                    timeout_duration,
+                    tokio::task::spawn_blocking(move || {
+                        connection
+                            .deref_mut()
+                            .build_transaction()
+                            .read_only()
+                            .run(query)
+                            .map_err(|e| IndexerError::PostgresReadError(e.to_string()))
+                    }),
+                )
+                .await
+                .map_err(|e| IndexerError::PostgresAsyncReadError(e.to_string()))??
+            })
+        })
+    }
+}
+
+/// The indexer reader reads the SQLite db of each table under the indexer dir by default,
+/// or the Postgres db if rooch is built with the `postgres` feature and the indexer db url is set.
+/// The raw SQL queries only use the syntax supported by both SQLite and Postgres.
 #[derive(Clone)]
 pub struct IndexerReader {
-    pub(crate) inner_indexer_reader_mapping: HashMap<String, InnerIndexerReader>,
+    backend: IndexerReaderBackend,
     metrics: Arc<IndexerReaderMetrics>,
 }
 
+#[derive(Clone)]
+enum IndexerReaderBackend {
+    Sqlite(HashMap<String, InnerIndexerReader>),
+    #[cfg(feature = "postgres")]
+    Postgres(PostgresIndexerReader),
+}
+
 impl IndexerReader {
     pub fn new(db_path: PathBuf, registry: &Registry) -> Result<Self> {
         let config = SqliteConnectionPoolConfig::pool_config(true);
@@ -169,19 +265,55 @@ impl IndexerReader {
         }
 
         Ok(IndexerReader {
-            inner_indexer_reader_mapping,
+            backend: IndexerReaderBackend::Sqlite(inner_indexer_reader_mapping),
             metrics: Arc::new(IndexerReaderMetrics::new(registry)),
         })
     }
 
+    #[cfg(feature = "postgres")]
+    pub fn new_postgres(db_url: &str, registry: &Registry) -> Result<Self> {
+        let config = PostgresConnectionPoolConfig::pool_config(true);
+        let postgres_reader = PostgresIndexerReader::new_with_config(db_url, config)?;
+
+        Ok(IndexerReader {
+            backend: IndexerReaderBackend::Postgres(postgres_reader),
+            metrics: Arc::new(IndexerReaderMetrics::new(registry)),
+        })
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn new_postgres(_db_url: &str, _registry: &Registry) -> Result<Self> {
+        Err(anyhow!(POSTGRES_FEATURE_DISABLED_ERROR))
+    }
+
     pub fn get_inner_indexer_reader(&self, table_name: &str) -> Result<InnerIndexerReader> {
-        Ok(self
-            .inner_indexer_reader_mapping
-            .get(table_name)
+        let inner_indexer_reader = match &self.backend {
+            IndexerReaderBackend::Sqlite(inner_indexer_reader_mapping) => {
+                inner_indexer_reader_mapping.get(table_name)
+            }
+            #[cfg(feature = "postgres")]
+            IndexerReaderBackend::Postgres(_) => None,
+        };
+        Ok(inner_indexer_reader
             .ok_or(anyhow::anyhow!("Inner indexer reader not exist"))?
             .clone())
     }
 
+    /// Load the rows of the raw SQL query from the table on SQLite or Postgres.
+    fn load_by_sql<T>(&self, table_name: &str, query: String) -> IndexerResult<Vec<T>>
+    where
+        T: IndexerQueryableRow,
+    {
+        match &self.backend {
+            IndexerReaderBackend::Sqlite(_) => self
+                .get_inner_indexer_reader(table_name)?
+                .run_query_with_timeout(move |conn| diesel::sql_query(query).load::<T>(conn)),
+            #[cfg(feature = "postgres")]
+            IndexerReaderBackend::Postgres(postgres_reader) => postgres_reader
+                .run_query_with_timeout(move |conn| diesel::sql_query(query).load::<T>(conn)),
+        }
+    }
+
     #[named]
     pub fn query_transactions_with_filter(
         &self,
@@ -200,14 +332,15 @@ impl IndexerReader {
         let tx_order = if let Some(cursor) = cursor {
             cursor as i64
         } else if descending_order {
-            let max_tx_order: i64 = self
-                .get_inner_indexer_reader(INDEXER_TRANSACTIONS_TABLE_NAME)?
-                .run_query_with_timeout(|conn| {
-                    transactions::dsl::transactions
-                        .select(transactions::tx_order)
-                        .order_by(transactions::tx_order.desc())
-                        .first::<i64>(conn)
-                })?;
+            let last_transactions = self.load_by_sql::<StoredTransaction>(
+                INDEXER_TRANSACTIONS_TABLE_NAME,
+                format!("SELECT * FROM transactions ORDER BY {TX_ORDER_STR} DESC LIMIT 1"),
+            )?;
+            // Query nothing if the table is empty
+            let max_tx_order = last_transactions
+                .first()
+                .map(|transaction| transaction.tx_order)
+                .unwrap_or(-1);
             max_tx_order + 1
         } else {
             -1
@@ -215,12 +348,12 @@ impl IndexerReader {
 
         let main_where_clause = match filter {
             TransactionFilter::Sender(sender) => {
-                format!("{TX_SENDER_STR} = \"{}\"", sender.to_hex_literal())
+                format!("{TX_SENDER_STR} = '{}'", sender.to_hex_literal())
             }
             TransactionFilter::TxHashes(tx_hashes) => {
                 let in_tx_hash_str: String = tx_hashes
                     .iter()
-                    .map(|tx_hash| format!("\"{:?}\"", tx_hash))
+                    .map(|tx_hash| format!("'{:?}'", tx_hash))
                     .collect::<Vec<String>>()
                     .join(",");
                 format!("{TX_HASH_STR} in ({})", in_tx_hash_str)
@@ -267,11 +400,8 @@ impl IndexerReader {
         );
 
         tracing::debug!("Query transactions: {}", query);
-        let stored_transactions = self
-            .get_inner_indexer_reader(INDEXER_TRANSACTIONS_TABLE_NAME)?
-            .run_query_with_timeout(|conn| {
-                diesel::sql_query(query).load::<StoredTransaction>(conn)
-            })?;
+        let stored_transactions =
+            self.load_by_sql::<StoredTransaction>(INDEXER_TRANSACTIONS_TABLE_NAME, query)?;
 
         let result = stored_transactions
             .into_iter()
@@ -307,56 +437,23 @@ impl IndexerReader {
             } = cursor;
             (tx_order as i64, event_index as i64)
         } else if descending_order {
-            let (max_tx_order, event_index): (i64, i64) = self
-                .get_inner_indexer_reader(INDEXER_EVENTS_TABLE_NAME)?
-                .run_query_with_timeout(|conn| {
-                    events::dsl::events
-                        .select((events::tx_order, events::event_index))
-                        .order_by((events::tx_order.desc(), events::event_index.desc()))
-                        .first::<(i64, i64)>(conn)
-                })?;
+            let last_events = self.load_by_sql::<StoredEvent>(
+                INDEXER_EVENTS_TABLE_NAME,
+                format!(
+                    "SELECT * FROM events ORDER BY {TX_ORDER_STR} DESC, {EVENT_INDEX_STR} DESC LIMIT 1"
+                ),
+            )?;
+            // Query nothing if the table is empty
+            let (max_tx_order, event_index) = last_events
+                .first()
+                .map(|event| (event.tx_order, event.event_index))
+                .unwrap_or((-1, -1));
             (max_tx_order, event_index + 1)
         } else {
             (-1, 0)
         };
 
-        let main_where_clause = match filter {
-            EventFilter::EventTypeWithSender { event_type, sender } => {
-                format!(
-                    "{TX_SENDER_STR} = \"{}\" AND {EVENT_TYPE_STR} = \"{}\"",
-                    sender.to_hex_literal(),
-                    event_type
-                )
-            }
-            EventFilter::EventType(event_type) => {
-                format!("{EVENT_TYPE_STR} = \"{}\"", event_type)
-            }
-            EventFilter::Sender(sender) => {
-                format!("{TX_SENDER_STR} = \"{}\"", sender.to_hex_literal())
-            }
-            EventFilter::TxHash(tx_hash) => {
-                let tx_hash_str = format!("{:?}", tx_hash);
-                format!("{TX_HASH_STR} = \"{}\"", tx_hash_str)
-            }
-            EventFilter::TimeRange {
-                start_time,
-                end_time,
-            } => {
-                format!(
-                    "({CREATED_AT_STR} >= {} AND {CREATED_AT_STR} < {})",
-                    start_time, end_time
-                )
-            }
-            EventFilter::TxOrderRange {
-                from_order,
-                to_order,
-            } => {
-                format!(
-                    "({TX_ORDER_STR} >= {} AND {TX_ORDER_STR} < {})",
-                    from_order, to_order
-                )
-            }
-        };
+        let main_where_clause = event_filter_query(filter)?;
 
         let cursor_clause = if descending_order {
             format!(
@@ -386,9 +483,7 @@ impl IndexerReader {
         );
 
         tracing::debug!("Query events: {}", query);
-        let stored_events = self
-            .get_inner_indexer_reader(INDEXER_EVENTS_TABLE_NAME)?
-            .run_query_with_timeout(|conn| diesel::sql_query(query).load::<StoredEvent>(conn))?;
+        let stored_events = self.load_by_sql::<StoredEvent>(INDEXER_EVENTS_TABLE_NAME, query)?;
 
         let result = stored_events
             .into_iter()
@@ -448,14 +543,14 @@ impl IndexerReader {
                             object_type_query(&object_type)
                         };
                         format!(
-                            "{STATE_OWNER_STR} = \"{}\" AND {}",
+                            "{STATE_OWNER_STR} = '{}' AND {}",
                             owner.to_hex_literal(),
                             object_query
                         )
                     }
                     _ => {
                         // Ignore object_type param for utxo and inscription query
-                        format!("{STATE_OWNER_STR} = \"{}\"", owner.to_hex_literal(),)
+                        format!("{STATE_OWNER_STR} = '{}'", owner.to_hex_literal(),)
                     }
                 }
             }
@@ -468,12 +563,12 @@ impl IndexerReader {
             }
 
             ObjectStateFilter::Owner(owner) => {
-                format!("{STATE_OWNER_STR} = \"{}\"", owner.to_hex_literal())
+                format!("{STATE_OWNER_STR} = '{}'", owner.to_hex_literal())
             }
             ObjectStateFilter::ObjectId(object_ids) => {
                 let object_ids_str = object_ids
                     .into_iter()
-                    .map(|obj_id| format!("\"{}\"", obj_id))
+                    .map(|obj_id| format!("'{}'", obj_id))
                     .collect::<Vec<_>>()
                     .join(",");
                 format!("{OBJECT_ID_STR} IN ({object_ids_str})")
@@ -510,11 +605,8 @@ impl IndexerReader {
         );
 
         tracing::debug!("Query object states: {}", query);
-        let stored_object_state_infos = self
-            .get_inner_indexer_reader(table_name)?
-            .run_query_with_timeout(|conn| {
-                diesel::sql_query(query).load::<StoredObjectStateInfo>(conn)
-            })?;
+        let stored_object_state_infos =
+            self.load_by_sql::<StoredObjectStateInfo>(table_name, query)?;
 
         tracing::debug!("Query object states time elapsed: {:?}", start.elapsed());
         Ok(stored_object_state_infos)
@@ -569,9 +661,7 @@ impl IndexerReader {
         );
 
         tracing::debug!("query last indexer state id: {}", query);
-        let stored_state_ids = self
-            .get_inner_indexer_reader(table_name)?
-            .run_query_with_timeout(|conn| diesel::sql_query(query).load::<StoredStateID>(conn))?;
+        let stored_state_ids = self.load_by_sql::<StoredStateID>(table_name, query)?;
 
         let last_state_id = if stored_state_ids.is_empty() {
             None
@@ -590,7 +680,7 @@ impl IndexerReader {
         state_type: ObjectStateType,
     ) -> IndexerResult<Option<u64>> {
         let table_name = get_table_name_by_state_type(state_type);
-        let where_clause = format!("{TX_ORDER_STR} = \"{}\"", tx_order as i64);
+        let where_clause = format!("{TX_ORDER_STR} = {}", tx_order as i64);
         let order_clause = format!("{TX_ORDER_STR} DESC, {STATE_INDEX_STR} DESC");
         let query = format!(
             "
@@ -603,9 +693,7 @@ impl IndexerReader {
         );
 
         tracing::debug!("query last state index by tx order: {}", query);
-        let stored_state_ids = self
-            .get_inner_indexer_reader(table_name)?
-            .run_query_with_timeout(|conn| diesel::sql_query(query).load::<StoredStateID>(conn))?;
+        let stored_state_ids = self.load_by_sql::<StoredStateID>(table_name, query)?;
         let last_state_index = if stored_state_ids.is_empty() {
             None
         } else {
@@ -635,7 +723,7 @@ impl IndexerReader {
             FieldFilter::ObjectId(object_ids) => {
                 let object_ids_str = object_ids
                     .into_iter()
-                    .map(|obj_id| format!("\"{}\"", obj_id))
+                    .map(|obj_id| format!("'{}'", obj_id))
                     .collect::<Vec<_>>()
                     .join(",");
                 format!("{PARENT_OBJECT_ID_STR} IN ({object_ids_str})")
@@ -649,7 +737,7 @@ impl IndexerReader {
         };
         let mut start_limit = (page_of - 1) * (limit as u64);
         start_limit = start_limit.saturating_sub(1);
-        let page_clause = format!("{}, {}", start_limit, limit);
+        let page_clause = format!("{} OFFSET {}", limit, start_limit);
         let query = format!(
             "
                 SELECT * FROM fields \
@@ -661,9 +749,7 @@ impl IndexerReader {
         );
 
         tracing::debug!("Query fields: {}", query);
-        let stored_fields = self
-            .get_inner_indexer_reader(INDEXER_FIELDS_TABLE_NAME)?
-            .run_query_with_timeout(|conn| diesel::sql_query(query).load::<StoredField>(conn))?;
+        let stored_fields = self.load_by_sql::<StoredField>(INDEXER_FIELDS_TABLE_NAME, query)?;
 
         let result = stored_fields
             .into_iter()
@@ -685,6 +771,99 @@ fn get_table_name_by_state_type(state_type: ObjectStateType) -> IndexerTableName
         ObjectStateType::Inscription => INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME,
     }
 }
+fn event_filter_query(filter: EventFilter) -> IndexerResult<String> {
+    let query = match filter {
+        EventFilter::EventTypeWithSender { event_type, sender } => {
+            format!(
+                "{TX_SENDER_STR} = '{}' AND {EVENT_TYPE_STR} = '{}'",
+                sender.to_hex_literal(),
+                escape_sql_string(event_type.to_string())
+            )
+        }
+        EventFilter::EventType(event_type) => {
+            format!(
+                "{EVENT_TYPE_STR} = '{}'",
+                escape_sql_string(event_type.to_string())
+            )
+        }
+        EventFilter::Sender(sender) => {
+            format!("{TX_SENDER_STR} = '{}'", sender.to_hex_literal())
+        }
+        EventFilter::TxHash(tx_hash) => {
+            let tx_hash_str = format!("{:?}", tx_hash);
+            format!("{TX_HASH_STR} = '{}'", tx_hash_str)
+        }
+        EventFilter::TimeRange {
+            start_time,
+            end_time,
+        } => {
+            format!(
+                "({CREATED_AT_STR} >= {} AND {CREATED_AT_STR} < {})",
+                start_time, end_time
+            )
+        }
+        EventFilter::TxOrderRange {
+            from_order,
+            to_order,
+        } => {
+            format!(
+                "({TX_ORDER_STR} >= {} AND {TX_ORDER_STR} < {})",
+                from_order, to_order
+            )
+        }
+        EventFilter::EventModule { address, module } => {
+            // The event types of the module share the prefix `address::module::`,
+            // query by the prefix range to use the event type index.
+            let prefix = match module {
+                Some(module) => format!("{}::{}::", address.to_hex_literal(), module),
+                None => format!("{}::", address.to_hex_literal()),
+            };
+            let upper_bound = increment_query_string(prefix.as_str());
+            format!(
+                "({EVENT_TYPE_STR} >= '{}' AND {EVENT_TYPE_STR} < '{}')",
+                escape_sql_string(prefix),
+                escape_sql_string(upper_bound)
+            )
+        }
+        EventFilter::EventTypes(event_types) => {
+            if event_types.is_empty() {
+                return Err(IndexerError::InvalidArgumentError(
+                    "The event types of the filter should not be empty".to_string(),
+                ));
+            }
+            let event_types_str = event_types
+                .into_iter()
+                .map(|event_type| format!("'{}'", escape_sql_string(event_type.to_string())))
+                .collect::<Vec<_>>()
+                .join(",");
+            format!("{EVENT_TYPE_STR} IN ({event_types_str})")
+        }
+        EventFilter::EventHandle(event_handle_id) => {
+            format!("{EVENT_HANDLE_ID_STR} = '{}'", event_handle_id)
+        }
+        EventFilter::And(filters) => combine_event_filter_queries(filters, "AND")?,
+        EventFilter::Or(filters) => combine_event_filter_queries(filters, "OR")?,
+    };
+    Ok(query)
+}
+
+fn combine_event_filter_queries(
+    filters: Vec<EventFilter>,
+    operator: &str,
+) -> IndexerResult<String> {
+    if filters.is_empty() {
+        return Err(IndexerError::InvalidArgumentError(format!(
+            "The filters combined by {} should not be empty",
+            operator
+        )));
+    }
+    let queries = filters
+        .into_iter()
+        .map(|filter| event_filter_query(filter).map(|query| format!("({})", query)))
+        .collect::<IndexerResult<Vec<_>>>()?;
+    Ok(format!("({})", queries.join(&format!(" {} ", operator))))
+}
+
 fn object_type_query(object_type: &StructTag) -> String {
     let object_type_str = object_type.to_string();
     // if the caller does not specify the type parameters, we will use the prefix match
@@ -692,11 +871,17 @@ fn object_type_query(object_type: &StructTag) -> String {
         let (first_bound, second_bound, upper_bound) =
             optimize_object_type_like_query(object_type_str.as_str());
         format!(
-            "({STATE_OBJECT_TYPE_STR} = \"{}\" OR ({STATE_OBJECT_TYPE_STR} >= \"{}\" AND {STATE_OBJECT_TYPE_STR} < \"{}\" AND {STATE_OBJECT_TYPE_STR} < \"{}\"))",
-            object_type_str, first_bound, second_bound, upper_bound
+            "({STATE_OBJECT_TYPE_STR} = '{}' OR ({STATE_OBJECT_TYPE_STR} >= '{}' AND {STATE_OBJECT_TYPE_STR} < '{}' AND {STATE_OBJECT_TYPE_STR} < '{}'))",
+            escape_sql_string(object_type_str),
+            escape_sql_string(first_bound),
+            escape_sql_string(second_bound),
+            escape_sql_string(upper_bound)
         )
     } else {
-        format!("{STATE_OBJECT_TYPE_STR} = \"{}\"", object_type_str)
+        format!(
+            "{STATE_OBJECT_TYPE_STR} = '{}'",
+            escape_sql_string(object_type_str)
+        )
     }
 }
 
@@ -707,11 +892,17 @@ fn not_object_type_query(object_type: &StructTag) -> String {
         let (first_bound, second_bound, upper_bound) =
             optimize_object_type_like_query(object_type_str.as_str());
         format!(
-            "({STATE_OBJECT_TYPE_STR} != \"{}\" AND ({STATE_OBJECT_TYPE_STR} < \"{}\" OR {STATE_OBJECT_TYPE_STR} >= \"{}\" OR {STATE_OBJECT_TYPE_STR} >= \"{}\"))",
-            object_type_str, first_bound, second_bound, upper_bound
+            "({STATE_OBJECT_TYPE_STR} != '{}' AND ({STATE_OBJECT_TYPE_STR} < '{}' OR {STATE_OBJECT_TYPE_STR} >= '{}' OR {STATE_OBJECT_TYPE_STR} >= '{}'))",
+            escape_sql_string(object_type_str),
+            escape_sql_string(first_bound),
+            escape_sql_string(second_bound),
+            escape_sql_string(upper_bound)
         )
     } else {
-        format!("{STATE_OBJECT_TYPE_STR} != \"{}\"", object_type_str)
+        format!(
+            "{STATE_OBJECT_TYPE_STR} != '{}'",
+            escape_sql_string(object_type_str)
+        )
     }
 }
 
@@ -724,12 +915,8 @@ fn optimize_object_type_like_query(query: &str) -> (String, String, String) {
     let second_bound = format!("{}{}", query, "=");
     // Calculate the upper bound for BETWEEN AND or OR
     let upper_bound = increment_query_string(query);
-    // Avoid potential SQL injection risks
-    (
-        escape_sql_string(first_bound),
-        escape_sql_string(second_bound),
-        escape_sql_string(upper_bound),
-    )
+    // The bounds are raw values, escape them when building the SQL to avoid potential SQL injection risks
+    (first_bound, second_bound, upper_bound)
 }
 
 pub fn increment_query_string(s: &str) -> String {
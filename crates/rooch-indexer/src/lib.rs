@@ -1,12 +1,18 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "postgres")]
 use crate::store::metrics::IndexerDBMetrics;
-use crate::store::sqlite_store::SqliteIndexerStore;
+#[cfg(feature = "postgres")]
+use crate::store::postgres_store::PostgresIndexerStore;
+use crate::store::sqlite_store::{SqliteIndexerStore, SqliteIndexerStores};
 use crate::store::traits::IndexerStoreTrait;
-use crate::utils::create_all_tables_if_not_exists;
+#[cfg(feature = "postgres")]
+use crate::utils::create_all_postgres_tables_if_not_exists;
 use anyhow::Result;
 use diesel::connection::SimpleConnection;
+#[cfg(feature = "postgres")]
+use diesel::pg::PgConnection;
 use diesel::r2d2::ConnectionManager;
 use diesel::sqlite::SqliteConnection;
 use diesel::ConnectionError::BadConnection;
@@ -20,11 +26,8 @@ use prometheus::Registry;
 use rooch_types::framework::indexer::{FieldIndexerData, IndexerModule};
 use rooch_types::indexer::event::IndexerEvent;
 use rooch_types::indexer::field::{IndexerField, IndexerFieldChanges};
-use rooch_types::indexer::state::{
-    IndexerObjectState, IndexerObjectStateChangeSet, IndexerObjectStateChanges, ObjectStateType,
-};
+use rooch_types::indexer::state::{IndexerObjectState, IndexerObjectStateChangeSet};
 use rooch_types::indexer::transaction::IndexerTransaction;
-use std::collections::HashMap;
 use std::fmt::{Debug, Display, Formatter};
 use std::path::PathBuf;
 use std::string::ToString;
@@ -54,6 +57,9 @@ pub const INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME: IndexerTableName = "utxos";
 pub const INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME: IndexerTableName = "inscriptions";
 pub const INDEXER_TRANSACTIONS_TABLE_NAME: IndexerTableName = "transactions";
 pub const INDEXER_FIELDS_TABLE_NAME: IndexerTableName = "fields";
+#[cfg(not(feature = "postgres"))]
+pub(crate) const POSTGRES_FEATURE_DISABLED_ERROR: &str =
+    "The Postgres indexer is not supported, please build rooch with the `postgres` feature";
 
 /// Please note that adding new indexer table needs to be added in vec simultaneously.
 static INDEXER_VEC_TABLE_NAME: Lazy<Vec<IndexerTableName>> = Lazy::new(|| {
@@ -78,56 +84,69 @@ impl IndexerStoreMeta {
 
 pub type SqliteConnectionPool = diesel::r2d2::Pool<ConnectionManager<SqliteConnection>>;
 pub type SqlitePoolConnection = diesel::r2d2::PooledConnection<ConnectionManager<SqliteConnection>>;
+#[cfg(feature = "postgres")]
+pub type PostgresConnectionPool = diesel::r2d2::Pool<ConnectionManager<PgConnection>>;
+#[cfg(feature = "postgres")]
+pub type PostgresPoolConnection = diesel::r2d2::PooledConnection<ConnectionManager<PgConnection>>;
 
+/// The indexer store writes to one SQLite db per table under the indexer dir by default,
+/// or to a Postgres db if rooch is built with the `postgres` feature and the indexer db url is set.
 #[derive(Clone)]
 pub struct IndexerStore {
-    pub sqlite_store_mapping: HashMap<String, SqliteIndexerStore>,
+    backend: IndexerStoreBackend,
+}
+
+#[derive(Clone)]
+enum IndexerStoreBackend {
+    Sqlite(SqliteIndexerStores),
+    #[cfg(feature = "postgres")]
+    Postgres(PostgresIndexerStore),
 }
 
 impl IndexerStore {
     pub fn new(db_path: PathBuf, registry: &Registry) -> Result<Self> {
-        if !db_path.exists() {
-            std::fs::create_dir_all(&db_path)?;
-        }
-        let tables = IndexerStoreMeta::get_indexer_table_names().to_vec();
+        let sqlite_stores = SqliteIndexerStores::new(db_path, registry)?;
+        Ok(Self {
+            backend: IndexerStoreBackend::Sqlite(sqlite_stores),
+        })
+    }
 
+    #[cfg(feature = "postgres")]
+    pub fn new_postgres(db_url: &str, registry: &Registry) -> Result<Self> {
         let db_metrics = IndexerDBMetrics::get_or_init(registry).clone();
-        let mut sqlite_store_mapping = HashMap::<String, SqliteIndexerStore>::new();
-        for table in tables {
-            let indexer_db_path = db_path.as_path().join(table);
-            if !indexer_db_path.exists() {
-                std::fs::File::create(indexer_db_path.clone())?;
-            };
-            let indexer_db_url = indexer_db_path
-                .to_str()
-                .ok_or(anyhow::anyhow!("Invalid indexer db path"))?
-                .to_string();
-            let sqlite_cp = new_sqlite_connection_pool(indexer_db_url.as_str())?;
-            let sqlite_store = SqliteIndexerStore::new(sqlite_cp, db_metrics.clone());
-            sqlite_store_mapping.insert(table.to_string(), sqlite_store);
-        }
+        let postgres_cp = new_postgres_connection_pool(db_url)?;
+        let mut connection = get_postgres_pool_connection(&postgres_cp)?;
+        create_all_postgres_tables_if_not_exists(&mut connection)?;
+
+        Ok(Self {
+            backend: IndexerStoreBackend::Postgres(PostgresIndexerStore::new(
+                postgres_cp,
+                db_metrics,
+            )),
+        })
+    }
 
-        let store = Self {
-            sqlite_store_mapping,
-        };
-        store.create_all_tables_if_not_exists()?;
-        Ok(store)
+    #[cfg(not(feature = "postgres"))]
+    pub fn new_postgres(_db_url: &str, _registry: &Registry) -> Result<Self> {
+        Err(anyhow::anyhow!(POSTGRES_FEATURE_DISABLED_ERROR))
     }
 
     pub fn get_sqlite_store(&self, table_name: &str) -> Result<SqliteIndexerStore> {
-        Ok(self
-            .sqlite_store_mapping
-            .get(table_name)
-            .ok_or(anyhow::anyhow!("Sqlite store not exist"))?
-            .clone())
+        match &self.backend {
+            IndexerStoreBackend::Sqlite(sqlite_stores) => {
+                sqlite_stores.get_sqlite_store(table_name)
+            }
+            #[cfg(feature = "postgres")]
+            IndexerStoreBackend::Postgres(_) => Err(anyhow::anyhow!("Sqlite store not exist")),
+        }
     }
 
-    fn create_all_tables_if_not_exists(&self) -> Result<()> {
-        for (k, v) in &self.sqlite_store_mapping {
-            let mut connection = get_sqlite_pool_connection(&v.connection_pool)?;
-            create_all_tables_if_not_exists(&mut connection, k.clone())?;
+    fn store(&self) -> &dyn IndexerStoreTrait {
+        match &self.backend {
+            IndexerStoreBackend::Sqlite(sqlite_stores) => sqlite_stores,
+            #[cfg(feature = "postgres")]
+            IndexerStoreBackend::Postgres(postgres_store) => postgres_store,
         }
-        Ok(())
     }
 }
 
@@ -146,145 +165,89 @@ pub fn new_sqlite_connection_pool(db_url: &str) -> Result<SqliteConnectionPool,
     new_sqlite_connection_pool_impl(db_url, None)
 }
 
+#[cfg(feature = "postgres")]
+pub fn new_postgres_connection_pool(db_url: &str) -> Result<PostgresConnectionPool, IndexerError> {
+    new_postgres_connection_pool_impl(db_url, None)
+}
+
 impl IndexerStoreTrait for IndexerStore {
     fn apply_object_states(
         &self,
         object_state_change_set: IndexerObjectStateChangeSet,
     ) -> Result<(), IndexerError> {
-        self.update_object_states_by_state_type(
-            object_state_change_set.object_states,
-            INDEXER_OBJECT_STATES_TABLE_NAME,
-            ObjectStateType::ObjectState,
-        )?;
-        self.update_object_states_by_state_type(
-            object_state_change_set.object_state_utxos,
-            INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME,
-            ObjectStateType::UTXO,
-        )?;
-        self.update_object_states_by_state_type(
-            object_state_change_set.object_state_inscriptions,
-            INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME,
-            ObjectStateType::Inscription,
-        )
+        self.store().apply_object_states(object_state_change_set)
     }
 
     fn persist_or_update_object_states(
         &self,
         states: Vec<IndexerObjectState>,
     ) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_OBJECT_STATES_TABLE_NAME)?
-            .persist_or_update_object_states(states)
+        self.store().persist_or_update_object_states(states)
     }
 
     fn delete_object_states(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_OBJECT_STATES_TABLE_NAME)?
-            .delete_object_states(state_pks)
+        self.store().delete_object_states(state_pks)
     }
 
     fn persist_or_update_object_state_utxos(
         &self,
         states: Vec<IndexerObjectState>,
     ) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME)?
-            .persist_or_update_object_state_utxos(states)
+        self.store().persist_or_update_object_state_utxos(states)
     }
 
     fn delete_object_state_utxos(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME)?
-            .delete_object_state_utxos(state_pks)
+        self.store().delete_object_state_utxos(state_pks)
     }
 
     fn persist_or_update_object_state_inscriptions(
         &self,
         states: Vec<IndexerObjectState>,
     ) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME)?
+        self.store()
             .persist_or_update_object_state_inscriptions(states)
     }
 
     fn delete_object_state_inscriptions(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME)?
-            .delete_object_state_inscriptions(state_pks)
+        self.store().delete_object_state_inscriptions(state_pks)
     }
 
     fn persist_transactions(
         &self,
         transactions: Vec<IndexerTransaction>,
     ) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_TRANSACTIONS_TABLE_NAME)?
-            .persist_transactions(transactions)
+        self.store().persist_transactions(transactions)
     }
 
     fn persist_events(&self, events: Vec<IndexerEvent>) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_EVENTS_TABLE_NAME)?
-            .persist_events(events)
+        self.store().persist_events(events)
     }
 
     fn delete_transactions(&self, tx_orders: Vec<u64>) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_TRANSACTIONS_TABLE_NAME)?
-            .delete_transactions(tx_orders)
+        self.store().delete_transactions(tx_orders)
     }
 
     fn delete_events(&self, tx_orders: Vec<u64>) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_EVENTS_TABLE_NAME)?
-            .delete_events(tx_orders)
+        self.store().delete_events(tx_orders)
     }
 
     fn persist_or_update_fields(&self, fields: Vec<IndexerField>) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_FIELDS_TABLE_NAME)?
-            .persist_or_update_fields(fields)
+        self.store().persist_or_update_fields(fields)
     }
 
     fn delete_fields(&self, table_pks: Vec<String>) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_FIELDS_TABLE_NAME)?
-            .delete_fields(table_pks)
+        self.store().delete_fields(table_pks)
     }
 
     fn delete_fields_by_parent_id(&self, ids: Vec<String>) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_FIELDS_TABLE_NAME)?
-            .delete_fields_by_parent_id(ids)
+        self.store().delete_fields_by_parent_id(ids)
     }
 
-    fn apply_fields(&self, mut field_changes: IndexerFieldChanges) -> Result<(), IndexerError> {
-        let mut fields_new_and_update = field_changes.new_fields;
-        fields_new_and_update.append(&mut field_changes.update_fields);
-        self.persist_or_update_fields(fields_new_and_update)?;
-        self.delete_fields(field_changes.remove_fields)?;
-        self.delete_fields_by_parent_id(field_changes.remove_fields_by_parent_id)
+    fn apply_fields(&self, field_changes: IndexerFieldChanges) -> Result<(), IndexerError> {
+        self.store().apply_fields(field_changes)
     }
 }
 
-impl IndexerStore {
-    fn update_object_states_by_state_type(
-        &self,
-        mut object_state_change: IndexerObjectStateChanges,
-        table_name: &str,
-        state_type: ObjectStateType,
-    ) -> Result<(), IndexerError> {
-        let mut object_states_new_and_update = object_state_change.new_object_states;
-        object_states_new_and_update.append(&mut object_state_change.update_object_states);
-        match state_type {
-            ObjectStateType::ObjectState => {
-                self.get_sqlite_store(table_name)?
-                    .persist_or_update_object_states(object_states_new_and_update)?;
-                self.get_sqlite_store(table_name)?
-                    .delete_object_states(object_state_change.remove_object_states)
-            }
-            ObjectStateType::UTXO => {
-                self.get_sqlite_store(table_name)?
-                    .persist_or_update_object_state_utxos(object_states_new_and_update)?;
-                self.get_sqlite_store(table_name)?
-                    .delete_object_state_utxos(object_state_change.remove_object_states)
-            }
-            ObjectStateType::Inscription => {
-                self.get_sqlite_store(table_name)?
-                    .persist_or_update_object_state_inscriptions(object_states_new_and_update)?;
-                self.get_sqlite_store(table_name)?
-                    .delete_object_state_inscriptions(object_state_change.remove_object_states)
-            }
-        }
-    }
-}
 pub fn new_sqlite_connection_pool_impl(
     db_url: &str,
     pool_size: Option<u32>,
@@ -306,6 +269,28 @@ pub fn new_sqlite_connection_pool_impl(
         })
 }
 
+#[cfg(feature = "postgres")]
+pub fn new_postgres_connection_pool_impl(
+    db_url: &str,
+    pool_size: Option<u32>,
+) -> Result<PostgresConnectionPool, IndexerError> {
+    let pool_config = PostgresConnectionPoolConfig::default();
+    let manager = ConnectionManager::<PgConnection>::new(db_url);
+
+    let pool_size = pool_size.unwrap_or(pool_config.pool_size);
+    diesel::r2d2::Pool::builder()
+        .max_size(pool_size)
+        .connection_timeout(pool_config.connection_timeout)
+        .connection_customizer(Box::new(pool_config.connection_config()))
+        .build(manager)
+        .map_err(|e| {
+            IndexerError::PostgresConnectionPoolInitError(format!(
+                "Failed to initialize connection pool with error: {:?}",
+                e
+            ))
+        })
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct SqliteConnectionPoolConfig {
     pool_size: u32,
@@ -363,6 +348,101 @@ impl Default for SqliteConnectionPoolConfig {
     }
 }
 
+#[cfg(feature = "postgres")]
+#[derive(Debug, Clone, Copy)]
+pub struct PostgresConnectionPoolConfig {
+    pool_size: u32,
+    connection_timeout: Duration,
+    statement_timeout: Duration,
+    read_only: bool,
+}
+
+#[cfg(feature = "postgres")]
+impl PostgresConnectionPoolConfig {
+    const DEFAULT_POOL_SIZE: u32 = 16;
+    const DEFAULT_READER_POOL_SIZE: u32 = 32;
+    const DEFAULT_CONNECTION_TIMEOUT: u64 = 30; // second
+    const DEFAULT_STATEMENT_TIMEOUT: u64 = 60; // second
+
+    fn connection_config(&self) -> PostgresConnectionConfig {
+        PostgresConnectionConfig {
+            statement_timeout: self.statement_timeout,
+            read_only: self.read_only,
+        }
+    }
+
+    pub fn set_pool_size(&mut self, size: u32) {
+        self.pool_size = size;
+    }
+
+    pub fn set_connection_timeout(&mut self, timeout: Duration) {
+        self.connection_timeout = timeout;
+    }
+
+    pub fn set_statement_timeout(&mut self, timeout: Duration) {
+        self.statement_timeout = timeout;
+    }
+
+    pub fn pool_config(read_only: bool) -> Self {
+        let default_pool_size = if read_only {
+            Self::DEFAULT_READER_POOL_SIZE
+        } else {
+            Self::DEFAULT_POOL_SIZE
+        };
+        let db_pool_size = std::env::var("POSTGRES_POOL_SIZE")
+            .ok()
+            .and_then(|s| s.parse::<u32>().ok())
+            .unwrap_or(default_pool_size);
+        let conn_timeout_secs = std::env::var("POSTGRES_CONNECTION_TIMEOUT")
+            .ok()
+            .and_then(|s| s.parse::<u64>().ok())
+            .unwrap_or(Self::DEFAULT_CONNECTION_TIMEOUT);
+        let statement_timeout_secs = std::env::var("POSTGRES_STATEMENT_TIMEOUT")
+            .ok()
+            .and_then(|s| s.parse::<u64>().ok())
+            .unwrap_or(Self::DEFAULT_STATEMENT_TIMEOUT);
+
+        Self {
+            pool_size: db_pool_size,
+            connection_timeout: Duration::from_secs(conn_timeout_secs),
+            statement_timeout: Duration::from_secs(statement_timeout_secs),
+            read_only,
+        }
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl Default for PostgresConnectionPoolConfig {
+    fn default() -> Self {
+        Self::pool_config(false)
+    }
+}
+
+#[cfg(feature = "postgres")]
+#[derive(Debug, Clone)]
+struct PostgresConnectionConfig {
+    statement_timeout: Duration,
+    read_only: bool,
+}
+
+#[cfg(feature = "postgres")]
+impl diesel::r2d2::CustomizeConnection<PgConnection, diesel::r2d2::Error>
+    for PostgresConnectionConfig
+{
+    fn on_acquire(&self, conn: &mut PgConnection) -> std::result::Result<(), diesel::r2d2::Error> {
+        let mut settings = format!(
+            "SET statement_timeout = {};",
+            self.statement_timeout.as_millis()
+        );
+        if self.read_only {
+            settings.push_str("SET default_transaction_read_only = on;");
+        }
+        conn.batch_execute(&settings)
+            .map_err(diesel::r2d2::Error::QueryError)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 struct SqliteConnectionConfig {
     // SQLite does not support the statement_timeout parameter
@@ -432,6 +512,18 @@ pub fn get_sqlite_pool_connection(
     })
 }
 
+#[cfg(feature = "postgres")]
+pub fn get_postgres_pool_connection(
+    pool: &PostgresConnectionPool,
+) -> Result<PostgresPoolConnection, IndexerError> {
+    pool.get().map_err(|e| {
+        IndexerError::PostgresPoolConnectionError(format!(
+            "Failed to get connection from Postgres connection pool with error: {:?}",
+            e
+        ))
+    })
+}
+
 pub fn list_field_indexer_keys(
     resolver: &RootObjectResolver<MoveOSStore>,
 ) -> Result<Vec<ObjectID>> {
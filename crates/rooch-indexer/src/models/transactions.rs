@@ -36,6 +36,9 @@ pub struct StoredTransaction {
     pub status: String,
     #[diesel(sql_type = diesel::sql_types::BigInt)]
     pub created_at: i64,
+    /// The rooch address of the account which paid the gas, null means the sender paid the gas.
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Text>)]
+    pub gas_payer: Option<String>,
 }
 
 impl From<IndexerTransaction> for StoredTransaction {
@@ -50,6 +53,7 @@ impl From<IndexerTransaction> for StoredTransaction {
             gas_used: transaction.gas_used as i64,
             status: transaction.status,
             created_at: transaction.created_at as i64,
+            gas_payer: Some(transaction.gas_payer.to_hex_literal()),
         }
     }
 }
@@ -60,6 +64,10 @@ impl TryFrom<StoredTransaction> for IndexerTransaction {
     fn try_from(transaction: StoredTransaction) -> Result<Self, Self::Error> {
         let sender = AccountAddress::from_str(transaction.sender.as_str())?;
         let tx_hash = H256::from_str(transaction.tx_hash.as_str())?;
+        let gas_payer = match transaction.gas_payer {
+            Some(gas_payer) => AccountAddress::from_str(gas_payer.as_str())?,
+            None => sender,
+        };
 
         let indexer_transaction = IndexerTransaction {
             tx_hash,
@@ -71,6 +79,7 @@ impl TryFrom<StoredTransaction> for IndexerTransaction {
             gas_used: transaction.gas_used as u64,
             status: transaction.status,
             created_at: transaction.created_at as u64,
+            gas_payer,
         };
         Ok(indexer_transaction)
     }
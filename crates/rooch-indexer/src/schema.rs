@@ -61,6 +61,7 @@ diesel::table! {
         gas_used -> BigInt,
         status -> Text,
         created_at -> BigInt,
+        gas_payer -> Nullable<Text>,
     }
 }
 
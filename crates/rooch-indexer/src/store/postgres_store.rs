@@ -0,0 +1,491 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::errors::{Context, IndexerError};
+use crate::models::events::StoredEvent;
+use crate::models::fields::StoredField;
+use crate::models::inscriptions::StoredInscription;
+use crate::models::states::StoredObjectState;
+use crate::models::transactions::StoredTransaction;
+use crate::models::utxos::StoredUTXO;
+use crate::schema::{events, fields, inscriptions, object_states, transactions, utxos};
+use crate::store::metrics::IndexerDBMetrics;
+use crate::store::traits::IndexerStoreTrait;
+use crate::{get_postgres_pool_connection, PostgresConnectionPool};
+use anyhow::Result;
+use diesel::upsert::excluded;
+use diesel::QueryDsl;
+use diesel::{ExpressionMethods, RunQueryDsl};
+use function_name::named;
+use rooch_types::indexer::event::IndexerEvent;
+use rooch_types::indexer::field::{IndexerField, IndexerFieldChanges};
+use rooch_types::indexer::state::{
+    IndexerObjectState, IndexerObjectStateChangeSet, IndexerObjectStateChanges,
+};
+use rooch_types::indexer::transaction::IndexerTransaction;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+// Postgres limits the bind parameters of one statement to 65535,
+// the widest indexer table has 9 columns.
+const POSTGRES_INSERT_CHUNK_SIZE: usize = 5000;
+
+/// The indexer store on Postgres, all the indexer tables are in one database.
+/// Unlike SQLite, Postgres supports batch upsert, so the writes are built by the diesel DSL instead of raw SQL.
+#[derive(Clone)]
+pub struct PostgresIndexerStore {
+    pub(crate) connection_pool: PostgresConnectionPool,
+    db_metrics: Arc<IndexerDBMetrics>,
+}
+
+impl PostgresIndexerStore {
+    pub fn new(connection_pool: PostgresConnectionPool, db_metrics: Arc<IndexerDBMetrics>) -> Self {
+        Self {
+            connection_pool,
+            db_metrics,
+        }
+    }
+
+    fn apply_object_state_changes(
+        &self,
+        mut object_state_change: IndexerObjectStateChanges,
+        persist_or_update: impl Fn(&Self, Vec<IndexerObjectState>) -> Result<(), IndexerError>,
+        delete: impl Fn(&Self, Vec<String>) -> Result<(), IndexerError>,
+    ) -> Result<(), IndexerError> {
+        let mut object_states_new_and_update = object_state_change.new_object_states;
+        object_states_new_and_update.append(&mut object_state_change.update_object_states);
+        persist_or_update(self, object_states_new_and_update)?;
+        delete(self, object_state_change.remove_object_states)
+    }
+}
+
+impl IndexerStoreTrait for PostgresIndexerStore {
+    fn apply_object_states(
+        &self,
+        object_state_change_set: IndexerObjectStateChangeSet,
+    ) -> Result<(), IndexerError> {
+        self.apply_object_state_changes(
+            object_state_change_set.object_states,
+            Self::persist_or_update_object_states,
+            Self::delete_object_states,
+        )?;
+        self.apply_object_state_changes(
+            object_state_change_set.object_state_utxos,
+            Self::persist_or_update_object_state_utxos,
+            Self::delete_object_state_utxos,
+        )?;
+        self.apply_object_state_changes(
+            object_state_change_set.object_state_inscriptions,
+            Self::persist_or_update_object_state_inscriptions,
+            Self::delete_object_state_inscriptions,
+        )
+    }
+
+    #[named]
+    fn persist_or_update_object_states(
+        &self,
+        states: Vec<IndexerObjectState>,
+    ) -> Result<(), IndexerError> {
+        if states.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_postgres_pool_connection(&self.connection_pool)?;
+        let states = dedup_by_id(
+            states.into_iter().map(StoredObjectState::from).collect(),
+            |state| &state.id,
+        );
+
+        for chunk in states.chunks(POSTGRES_INSERT_CHUNK_SIZE) {
+            diesel::insert_into(object_states::table)
+                .values(chunk)
+                .on_conflict(object_states::id)
+                .do_update()
+                .set((
+                    object_states::owner.eq(excluded(object_states::owner)),
+                    object_states::tx_order.eq(excluded(object_states::tx_order)),
+                    object_states::state_index.eq(excluded(object_states::state_index)),
+                    object_states::updated_at.eq(excluded(object_states::updated_at)),
+                ))
+                .execute(&mut connection)
+                .map_err(|e| IndexerError::PostgresWriteError(e.to_string()))
+                .context("Failed to write or update object states to PostgresDB")?;
+        }
+
+        Ok(())
+    }
+
+    #[named]
+    fn delete_object_states(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
+        if state_pks.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_postgres_pool_connection(&self.connection_pool)?;
+
+        diesel::delete(object_states::table.filter(object_states::id.eq_any(state_pks)))
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::PostgresWriteError(e.to_string()))
+            .context("Failed to delete object states to PostgresDB")?;
+
+        Ok(())
+    }
+
+    #[named]
+    fn persist_or_update_object_state_utxos(
+        &self,
+        states: Vec<IndexerObjectState>,
+    ) -> Result<(), IndexerError> {
+        if states.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_postgres_pool_connection(&self.connection_pool)?;
+        let states = dedup_by_id(
+            states.into_iter().map(StoredUTXO::from).collect(),
+            |state| &state.id,
+        );
+
+        for chunk in states.chunks(POSTGRES_INSERT_CHUNK_SIZE) {
+            diesel::insert_into(utxos::table)
+                .values(chunk)
+                .on_conflict(utxos::id)
+                .do_update()
+                .set((
+                    utxos::owner.eq(excluded(utxos::owner)),
+                    utxos::tx_order.eq(excluded(utxos::tx_order)),
+                    utxos::state_index.eq(excluded(utxos::state_index)),
+                    utxos::updated_at.eq(excluded(utxos::updated_at)),
+                ))
+                .execute(&mut connection)
+                .map_err(|e| IndexerError::PostgresWriteError(e.to_string()))
+                .context("Failed to write or update object state utxos to PostgresDB")?;
+        }
+
+        Ok(())
+    }
+
+    #[named]
+    fn delete_object_state_utxos(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
+        if state_pks.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_postgres_pool_connection(&self.connection_pool)?;
+
+        diesel::delete(utxos::table.filter(utxos::id.eq_any(state_pks)))
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::PostgresWriteError(e.to_string()))
+            .context("Failed to delete object state utxos to PostgresDB")?;
+
+        Ok(())
+    }
+
+    #[named]
+    fn persist_or_update_object_state_inscriptions(
+        &self,
+        states: Vec<IndexerObjectState>,
+    ) -> Result<(), IndexerError> {
+        if states.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_postgres_pool_connection(&self.connection_pool)?;
+        let states = dedup_by_id(
+            states.into_iter().map(StoredInscription::from).collect(),
+            |state| &state.id,
+        );
+
+        for chunk in states.chunks(POSTGRES_INSERT_CHUNK_SIZE) {
+            diesel::insert_into(inscriptions::table)
+                .values(chunk)
+                .on_conflict(inscriptions::id)
+                .do_update()
+                .set((
+                    inscriptions::owner.eq(excluded(inscriptions::owner)),
+                    inscriptions::tx_order.eq(excluded(inscriptions::tx_order)),
+                    inscriptions::state_index.eq(excluded(inscriptions::state_index)),
+                    inscriptions::updated_at.eq(excluded(inscriptions::updated_at)),
+                ))
+                .execute(&mut connection)
+                .map_err(|e| IndexerError::PostgresWriteError(e.to_string()))
+                .context("Failed to write or update object state inscriptions to PostgresDB")?;
+        }
+
+        Ok(())
+    }
+
+    #[named]
+    fn delete_object_state_inscriptions(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
+        if state_pks.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_postgres_pool_connection(&self.connection_pool)?;
+
+        diesel::delete(inscriptions::table.filter(inscriptions::id.eq_any(state_pks)))
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::PostgresWriteError(e.to_string()))
+            .context("Failed to delete object state inscriptions to PostgresDB")?;
+
+        Ok(())
+    }
+
+    #[named]
+    fn persist_transactions(
+        &self,
+        transactions: Vec<IndexerTransaction>,
+    ) -> Result<(), IndexerError> {
+        if transactions.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_postgres_pool_connection(&self.connection_pool)?;
+        let transactions = transactions
+            .into_iter()
+            .map(StoredTransaction::from)
+            .collect::<Vec<_>>();
+
+        for chunk in transactions.chunks(POSTGRES_INSERT_CHUNK_SIZE) {
+            diesel::insert_into(transactions::table)
+                .values(chunk)
+                .execute(&mut connection)
+                .map_err(|e| IndexerError::PostgresWriteError(e.to_string()))
+                .context("Failed to write transactions to PostgresDB")?;
+        }
+
+        Ok(())
+    }
+
+    #[named]
+    fn persist_events(&self, events: Vec<IndexerEvent>) -> Result<(), IndexerError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_postgres_pool_connection(&self.connection_pool)?;
+        let events = events
+            .into_iter()
+            .map(StoredEvent::from)
+            .collect::<Vec<_>>();
+
+        for chunk in events.chunks(POSTGRES_INSERT_CHUNK_SIZE) {
+            diesel::insert_into(events::table)
+                .values(chunk)
+                .execute(&mut connection)
+                .map_err(|e| IndexerError::PostgresWriteError(e.to_string()))
+                .context("Failed to write events to PostgresDB")?;
+        }
+
+        Ok(())
+    }
+
+    #[named]
+    fn delete_transactions(&self, tx_orders: Vec<u64>) -> Result<(), IndexerError> {
+        if tx_orders.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_postgres_pool_connection(&self.connection_pool)?;
+
+        let tx_orders: Vec<_> = tx_orders.into_iter().map(|v| v as i64).collect();
+        diesel::delete(transactions::table.filter(transactions::tx_order.eq_any(tx_orders)))
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::PostgresWriteError(e.to_string()))
+            .context("Failed to delete transactions to PostgresDB")?;
+
+        Ok(())
+    }
+
+    #[named]
+    fn delete_events(&self, tx_orders: Vec<u64>) -> Result<(), IndexerError> {
+        if tx_orders.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_postgres_pool_connection(&self.connection_pool)?;
+
+        let tx_orders: Vec<_> = tx_orders.into_iter().map(|v| v as i64).collect();
+        diesel::delete(events::table.filter(events::tx_order.eq_any(tx_orders)))
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::PostgresWriteError(e.to_string()))
+            .context("Failed to delete events to PostgresDB")?;
+
+        Ok(())
+    }
+
+    #[named]
+    fn persist_or_update_fields(&self, fields: Vec<IndexerField>) -> Result<(), IndexerError> {
+        if fields.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_postgres_pool_connection(&self.connection_pool)?;
+        let fields = dedup_by_id(
+            fields.into_iter().map(StoredField::from).collect(),
+            |field| &field.id,
+        );
+
+        for chunk in fields.chunks(POSTGRES_INSERT_CHUNK_SIZE) {
+            diesel::insert_into(fields::table)
+                .values(chunk)
+                .on_conflict(fields::id)
+                .do_update()
+                .set((
+                    fields::parent_id.eq(excluded(fields::parent_id)),
+                    fields::field_key.eq(excluded(fields::field_key)),
+                    fields::sort_key.eq(excluded(fields::sort_key)),
+                    fields::updated_at.eq(excluded(fields::updated_at)),
+                ))
+                .execute(&mut connection)
+                .map_err(|e| IndexerError::PostgresWriteError(e.to_string()))
+                .context("Failed to write or update fields to PostgresDB")?;
+        }
+
+        Ok(())
+    }
+
+    #[named]
+    fn delete_fields(&self, field_pks: Vec<String>) -> Result<(), IndexerError> {
+        if field_pks.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_postgres_pool_connection(&self.connection_pool)?;
+
+        diesel::delete(fields::table.filter(fields::id.eq_any(field_pks)))
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::PostgresWriteError(e.to_string()))
+            .context("Failed to delete fields to PostgresDB")?;
+
+        Ok(())
+    }
+
+    #[named]
+    fn delete_fields_by_parent_id(&self, ids: Vec<String>) -> Result<(), IndexerError> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_postgres_pool_connection(&self.connection_pool)?;
+
+        diesel::delete(fields::table.filter(fields::parent_id.eq_any(ids)))
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::PostgresWriteError(e.to_string()))
+            .context("Failed to delete fields to PostgresDB")?;
+
+        Ok(())
+    }
+
+    fn apply_fields(&self, mut field_changes: IndexerFieldChanges) -> Result<(), IndexerError> {
+        let mut fields_new_and_update = field_changes.new_fields;
+        fields_new_and_update.append(&mut field_changes.update_fields);
+        self.persist_or_update_fields(fields_new_and_update)?;
+        self.delete_fields(field_changes.remove_fields)?;
+        self.delete_fields_by_parent_id(field_changes.remove_fields_by_parent_id)
+    }
+}
+
+/// Postgres rejects updating the same row twice in one upsert statement,
+/// keep the last change of each row in the batch.
+fn dedup_by_id<T>(rows: Vec<T>, id: impl Fn(&T) -> &String) -> Vec<T> {
+    let mut ids = HashSet::with_capacity(rows.len());
+    let mut rows = rows
+        .into_iter()
+        .rev()
+        .filter(|row| ids.insert(id(row).clone()))
+        .collect::<Vec<_>>();
+    rows.reverse();
+    rows
+}
@@ -6,10 +6,15 @@ use anyhow::Result;
 use diesel::QueryDsl;
 use diesel::{ExpressionMethods, RunQueryDsl};
 use function_name::named;
+use prometheus::Registry;
 use rooch_types::indexer::event::IndexerEvent;
-use rooch_types::indexer::field::IndexerField;
-use rooch_types::indexer::state::IndexerObjectState;
+use rooch_types::indexer::field::{IndexerField, IndexerFieldChanges};
+use rooch_types::indexer::state::{
+    IndexerObjectState, IndexerObjectStateChangeSet, IndexerObjectStateChanges, ObjectStateType,
+};
 use rooch_types::indexer::transaction::IndexerTransaction;
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use crate::models::events::StoredEvent;
@@ -20,8 +25,14 @@ use crate::models::transactions::{escape_transaction, StoredTransaction};
 use crate::models::utxos::StoredUTXO;
 use crate::schema::{events, fields, inscriptions, object_states, transactions, utxos};
 use crate::store::metrics::IndexerDBMetrics;
-use crate::utils::escape_sql_string;
-use crate::{get_sqlite_pool_connection, SqliteConnectionPool};
+use crate::store::traits::IndexerStoreTrait;
+use crate::utils::{create_all_tables_if_not_exists, escape_sql_string};
+use crate::{
+    get_sqlite_pool_connection, new_sqlite_connection_pool, IndexerStoreMeta, SqliteConnectionPool,
+    INDEXER_EVENTS_TABLE_NAME, INDEXER_FIELDS_TABLE_NAME, INDEXER_OBJECT_STATES_TABLE_NAME,
+    INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME, INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME,
+    INDEXER_TRANSACTIONS_TABLE_NAME,
+};
 
 #[derive(Clone)]
 pub struct SqliteIndexerStore {
@@ -540,3 +551,194 @@ impl SqliteIndexerStore {
         Ok(())
     }
 }
+
+/// The SQLite indexer stores, one SQLite db per table under the indexer dir.
+#[derive(Clone)]
+pub struct SqliteIndexerStores {
+    sqlite_store_mapping: HashMap<String, SqliteIndexerStore>,
+}
+
+impl SqliteIndexerStores {
+    pub fn new(db_path: PathBuf, registry: &Registry) -> Result<Self> {
+        if !db_path.exists() {
+            std::fs::create_dir_all(&db_path)?;
+        }
+        let tables = IndexerStoreMeta::get_indexer_table_names().to_vec();
+
+        let db_metrics = IndexerDBMetrics::get_or_init(registry).clone();
+        let mut sqlite_store_mapping = HashMap::<String, SqliteIndexerStore>::new();
+        for table in tables {
+            let indexer_db_path = db_path.as_path().join(table);
+            if !indexer_db_path.exists() {
+                std::fs::File::create(indexer_db_path.clone())?;
+            };
+            let indexer_db_url = indexer_db_path
+                .to_str()
+                .ok_or(anyhow::anyhow!("Invalid indexer db path"))?
+                .to_string();
+            let sqlite_cp = new_sqlite_connection_pool(indexer_db_url.as_str())?;
+            let sqlite_store = SqliteIndexerStore::new(sqlite_cp, db_metrics.clone());
+            sqlite_store_mapping.insert(table.to_string(), sqlite_store);
+        }
+
+        let stores = Self {
+            sqlite_store_mapping,
+        };
+        stores.create_all_tables_if_not_exists()?;
+        Ok(stores)
+    }
+
+    pub fn get_sqlite_store(&self, table_name: &str) -> Result<SqliteIndexerStore> {
+        Ok(self
+            .sqlite_store_mapping
+            .get(table_name)
+            .ok_or(anyhow::anyhow!("Sqlite store not exist"))?
+            .clone())
+    }
+
+    fn create_all_tables_if_not_exists(&self) -> Result<()> {
+        for (k, v) in &self.sqlite_store_mapping {
+            let mut connection = get_sqlite_pool_connection(&v.connection_pool)?;
+            create_all_tables_if_not_exists(&mut connection, k.clone())?;
+        }
+        Ok(())
+    }
+
+    fn update_object_states_by_state_type(
+        &self,
+        mut object_state_change: IndexerObjectStateChanges,
+        table_name: &str,
+        state_type: ObjectStateType,
+    ) -> Result<(), IndexerError> {
+        let mut object_states_new_and_update = object_state_change.new_object_states;
+        object_states_new_and_update.append(&mut object_state_change.update_object_states);
+        match state_type {
+            ObjectStateType::ObjectState => {
+                self.get_sqlite_store(table_name)?
+                    .persist_or_update_object_states(object_states_new_and_update)?;
+                self.get_sqlite_store(table_name)?
+                    .delete_object_states(object_state_change.remove_object_states)
+            }
+            ObjectStateType::UTXO => {
+                self.get_sqlite_store(table_name)?
+                    .persist_or_update_object_state_utxos(object_states_new_and_update)?;
+                self.get_sqlite_store(table_name)?
+                    .delete_object_state_utxos(object_state_change.remove_object_states)
+            }
+            ObjectStateType::Inscription => {
+                self.get_sqlite_store(table_name)?
+                    .persist_or_update_object_state_inscriptions(object_states_new_and_update)?;
+                self.get_sqlite_store(table_name)?
+                    .delete_object_state_inscriptions(object_state_change.remove_object_states)
+            }
+        }
+    }
+}
+
+impl IndexerStoreTrait for SqliteIndexerStores {
+    fn apply_object_states(
+        &self,
+        object_state_change_set: IndexerObjectStateChangeSet,
+    ) -> Result<(), IndexerError> {
+        self.update_object_states_by_state_type(
+            object_state_change_set.object_states,
+            INDEXER_OBJECT_STATES_TABLE_NAME,
+            ObjectStateType::ObjectState,
+        )?;
+        self.update_object_states_by_state_type(
+            object_state_change_set.object_state_utxos,
+            INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME,
+            ObjectStateType::UTXO,
+        )?;
+        self.update_object_states_by_state_type(
+            object_state_change_set.object_state_inscriptions,
+            INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME,
+            ObjectStateType::Inscription,
+        )
+    }
+
+    fn persist_or_update_object_states(
+        &self,
+        states: Vec<IndexerObjectState>,
+    ) -> Result<(), IndexerError> {
+        self.get_sqlite_store(INDEXER_OBJECT_STATES_TABLE_NAME)?
+            .persist_or_update_object_states(states)
+    }
+
+    fn delete_object_states(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
+        self.get_sqlite_store(INDEXER_OBJECT_STATES_TABLE_NAME)?
+            .delete_object_states(state_pks)
+    }
+
+    fn persist_or_update_object_state_utxos(
+        &self,
+        states: Vec<IndexerObjectState>,
+    ) -> Result<(), IndexerError> {
+        self.get_sqlite_store(INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME)?
+            .persist_or_update_object_state_utxos(states)
+    }
+
+    fn delete_object_state_utxos(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
+        self.get_sqlite_store(INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME)?
+            .delete_object_state_utxos(state_pks)
+    }
+
+    fn persist_or_update_object_state_inscriptions(
+        &self,
+        states: Vec<IndexerObjectState>,
+    ) -> Result<(), IndexerError> {
+        self.get_sqlite_store(INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME)?
+            .persist_or_update_object_state_inscriptions(states)
+    }
+
+    fn delete_object_state_inscriptions(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
+        self.get_sqlite_store(INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME)?
+            .delete_object_state_inscriptions(state_pks)
+    }
+
+    fn persist_transactions(
+        &self,
+        transactions: Vec<IndexerTransaction>,
+    ) -> Result<(), IndexerError> {
+        self.get_sqlite_store(INDEXER_TRANSACTIONS_TABLE_NAME)?
+            .persist_transactions(transactions)
+    }
+
+    fn persist_events(&self, events: Vec<IndexerEvent>) -> Result<(), IndexerError> {
+        self.get_sqlite_store(INDEXER_EVENTS_TABLE_NAME)?
+            .persist_events(events)
+    }
+
+    fn delete_transactions(&self, tx_orders: Vec<u64>) -> Result<(), IndexerError> {
+        self.get_sqlite_store(INDEXER_TRANSACTIONS_TABLE_NAME)?
+            .delete_transactions(tx_orders)
+    }
+
+    fn delete_events(&self, tx_orders: Vec<u64>) -> Result<(), IndexerError> {
+        self.get_sqlite_store(INDEXER_EVENTS_TABLE_NAME)?
+            .delete_events(tx_orders)
+    }
+
+    fn persist_or_update_fields(&self, fields: Vec<IndexerField>) -> Result<(), IndexerError> {
+        self.get_sqlite_store(INDEXER_FIELDS_TABLE_NAME)?
+            .persist_or_update_fields(fields)
+    }
+
+    fn delete_fields(&self, table_pks: Vec<String>) -> Result<(), IndexerError> {
+        self.get_sqlite_store(INDEXER_FIELDS_TABLE_NAME)?
+            .delete_fields(table_pks)
+    }
+
+    fn delete_fields_by_parent_id(&self, ids: Vec<String>) -> Result<(), IndexerError> {
+        self.get_sqlite_store(INDEXER_FIELDS_TABLE_NAME)?
+            .delete_fields_by_parent_id(ids)
+    }
+
+    fn apply_fields(&self, mut field_changes: IndexerFieldChanges) -> Result<(), IndexerError> {
+        let mut fields_new_and_update = field_changes.new_fields;
+        fields_new_and_update.append(&mut field_changes.update_fields);
+        self.persist_or_update_fields(fields_new_and_update)?;
+        self.delete_fields(field_changes.remove_fields)?;
+        self.delete_fields_by_parent_id(field_changes.remove_fields_by_parent_id)
+    }
+}
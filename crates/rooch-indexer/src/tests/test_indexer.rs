@@ -7,8 +7,11 @@ use crate::IndexerStore;
 use anyhow::Result;
 use metrics::RegistryService;
 use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::StructTag;
 use move_core_types::vm_status::KeptVMStatus;
 use moveos_types::h256::H256;
+use moveos_types::moveos_std::event::{Event, EventID};
 use moveos_types::moveos_std::object::{ObjectEntity, ObjectID, ObjectMeta};
 use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::state::MoveStructType;
@@ -21,6 +24,7 @@ use rooch_types::indexer::event::{EventFilter, IndexerEvent};
 use rooch_types::indexer::field::FieldFilter;
 use rooch_types::indexer::state::{IndexerObjectState, ObjectStateFilter, ObjectStateType};
 use rooch_types::indexer::transaction::{IndexerTransaction, TransactionFilter};
+use rooch_types::indexer::Filter;
 use rooch_types::test_utils::{
     random_event, random_ledger_transaction, random_new_fields, random_new_object_states,
     random_remove_fields, random_remove_fields_by_parent_id, random_remove_object_states,
@@ -38,6 +42,7 @@ async fn test_transaction_store() -> Result<()> {
 
     let random_transaction = random_ledger_transaction();
 
+    let gas_payer = AccountAddress::random();
     let random_execution_info = TransactionExecutionInfo::new(
         H256::random(),
         H256::random(),
@@ -45,6 +50,7 @@ async fn test_transaction_store() -> Result<()> {
         H256::random(),
         rand::random(),
         KeptVMStatus::Executed,
+        gas_payer,
     );
 
     let tx_context = TxContext::new_readonly_ctx(AccountAddress::random());
@@ -68,6 +74,7 @@ async fn test_transaction_store() -> Result<()> {
     let query_transactions =
         indexer_reader.query_transactions_with_filter(filter, None, 1, true)?;
     assert_eq!(query_transactions.len(), 1);
+    assert_eq!(query_transactions[0].gas_payer, gas_payer);
     Ok(())
 }
 
@@ -105,6 +112,116 @@ async fn test_event_store() -> Result<()> {
     Ok(())
 }
 
+fn new_indexer_event(
+    event_type: &str,
+    event_handle_id: ObjectID,
+    sender: AccountAddress,
+    tx_order: u64,
+) -> Result<IndexerEvent> {
+    let event = Event {
+        event_id: EventID::new(event_handle_id, 0),
+        event_type: event_type.parse::<StructTag>()?,
+        event_data: vec![],
+        event_index: 0,
+    };
+    let mut ledger_transaction = random_ledger_transaction();
+    ledger_transaction.sequence_info.tx_order = tx_order;
+    Ok(IndexerEvent::new(
+        event,
+        ledger_transaction,
+        TxContext::new_readonly_ctx(sender),
+    ))
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_event_filters() -> Result<()> {
+    let registry_service = RegistryService::default();
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(DEFAULT_DB_INDEXER_SUBDIR);
+    let indexer_store =
+        IndexerStore::new(indexer_db.clone(), &registry_service.default_registry())?;
+    let indexer_reader = IndexerReader::new(indexer_db, &registry_service.default_registry())?;
+
+    let sender = AccountAddress::random();
+    let handles = (0..3).map(|_| ObjectID::random()).collect::<Vec<_>>();
+    let events = vec![
+        new_indexer_event("0x3::coin::MintEvent", handles[0].clone(), sender, 1)?,
+        new_indexer_event(
+            "0x3::coin_store::DepositEvent",
+            handles[1].clone(),
+            sender,
+            2,
+        )?,
+        new_indexer_event("0x4::ord::InscriptionEvent", handles[2].clone(), sender, 3)?,
+    ];
+    indexer_store.persist_events(events.clone())?;
+
+    let query_tx_orders = |filter: EventFilter| -> Result<Vec<u64>> {
+        let query_events =
+            indexer_reader.query_events_with_filter(filter.clone(), None, 10, false)?;
+        // The indexer query should agree with the filter matching
+        let matched_events = events
+            .iter()
+            .filter(|event| filter.matches(event))
+            .map(|event| event.indexer_event_id.tx_order)
+            .collect::<Vec<_>>();
+        let tx_orders = query_events
+            .iter()
+            .map(|event| event.indexer_event_id.tx_order)
+            .collect::<Vec<_>>();
+        assert_eq!(tx_orders, matched_events);
+        Ok(tx_orders)
+    };
+
+    // `0x3::coin` does not include `0x3::coin_store`
+    let filter = EventFilter::EventModule {
+        address: AccountAddress::from_hex_literal("0x3")?,
+        module: Some(Identifier::new("coin")?),
+    };
+    assert_eq!(query_tx_orders(filter)?, vec![1]);
+    let filter = EventFilter::EventModule {
+        address: AccountAddress::from_hex_literal("0x3")?,
+        module: None,
+    };
+    assert_eq!(query_tx_orders(filter)?, vec![1, 2]);
+
+    let filter = EventFilter::EventTypes(vec![
+        events[0].event_type.clone(),
+        events[2].event_type.clone(),
+    ]);
+    assert_eq!(query_tx_orders(filter)?, vec![1, 3]);
+
+    let filter = EventFilter::EventHandle(handles[1].clone());
+    assert_eq!(query_tx_orders(filter)?, vec![2]);
+
+    let filter = EventFilter::And(vec![
+        EventFilter::Sender(sender),
+        EventFilter::Or(vec![
+            EventFilter::EventHandle(handles[0].clone()),
+            EventFilter::EventModule {
+                address: AccountAddress::from_hex_literal("0x4")?,
+                module: None,
+            },
+        ]),
+    ]);
+    assert_eq!(query_tx_orders(filter)?, vec![1, 3]);
+
+    let filter = EventFilter::And(vec![
+        EventFilter::EventTypes(vec![events[1].event_type.clone()]),
+        EventFilter::TxOrderRange {
+            from_order: 3,
+            to_order: 4,
+        },
+    ]);
+    assert!(query_tx_orders(filter)?.is_empty());
+
+    // The empty combined filter is invalid
+    assert!(indexer_reader
+        .query_events_with_filter(EventFilter::Or(vec![]), None, 10, false)
+        .is_err());
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_state_store() -> Result<()> {
     let registry_service = RegistryService::default();
@@ -231,6 +348,7 @@ async fn test_escape_transaction() -> Result<()> {
         H256::random(),
         rand::random(),
         KeptVMStatus::Executed,
+        AccountAddress::random(),
     );
 
     let tx_context = TxContext::new_readonly_ctx(AccountAddress::random());
@@ -1,6 +1,8 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "postgres")]
+use crate::PostgresPoolConnection;
 use crate::SqlitePoolConnection;
 use anyhow::anyhow;
 use diesel::{RunQueryDsl, SqliteConnection};
@@ -8,6 +10,8 @@ use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
 use tracing::{debug, info};
 
 const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
+#[cfg(feature = "postgres")]
+const POSTGRES_MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations_pg");
 
 /// creates all the tables by applying all migrations.
 pub fn create_all_tables_if_not_exists(
@@ -50,6 +54,19 @@ pub fn create_all_tables_if_not_exists(
     Ok(())
 }
 
+/// creates all the tables in the Postgres db by applying all Postgres migrations.
+#[cfg(feature = "postgres")]
+pub fn create_all_postgres_tables_if_not_exists(
+    conn: &mut PostgresPoolConnection,
+) -> Result<(), anyhow::Error> {
+    debug!("Indexer creates all tables in the Postgres db ...");
+    // The __diesel_schema_migrations table is created by the migration harness on Postgres
+    conn.run_pending_migrations(POSTGRES_MIGRATIONS)
+        .map_err(|e| anyhow!("Failed to run Postgres migrations {e}"))?;
+    debug!("Indexer creates all tables in the Postgres db complete.");
+    Ok(())
+}
+
 /// Resets the db by reverting all migrations and reapplying them.
 ///
 /// If `drop_all` is set to `true`, the function will drop all tables in the db before
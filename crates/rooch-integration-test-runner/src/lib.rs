@@ -130,7 +130,10 @@ impl<'a> MoveOSTestAdapter<'a> for MoveOSTestRunner<'a> {
             .init_genesis(genesis.genesis_moveos_tx(), genesis.genesis_objects.clone())
             .unwrap();
         let tx_hash = genesis.genesis_tx().tx_hash();
-        let (_output, exe_info) = moveos_store.handle_tx_output(tx_hash, raw_output).unwrap();
+        let gas_payer = genesis.genesis_moveos_tx().ctx.sender();
+        let (_output, exe_info) = moveos_store
+            .handle_tx_output(tx_hash, gas_payer, raw_output)
+            .unwrap();
 
         let mut named_address_mapping = rooch_framework::rooch_framework_named_addresses()
             .into_iter()
@@ -193,9 +196,12 @@ impl<'a> MoveOSTestAdapter<'a> for MoveOSTestRunner<'a> {
 
         let tx = MoveOSTransaction::new_for_test(self.root.clone(), sender, action);
         let tx_hash = tx.ctx.tx_hash();
+        let gas_payer = tx.ctx.sender();
         let verified_tx = self.validate_tx(tx)?;
         let (raw_output, _) = self.moveos.execute_only(verified_tx)?;
-        let (output, _exe_info) = self.moveos_store.handle_tx_output(tx_hash, raw_output)?;
+        let (output, _exe_info) = self
+            .moveos_store
+            .handle_tx_output(tx_hash, gas_payer, raw_output)?;
         self.root = output.changeset.root_metadata();
         Ok((Some(tx_output_to_str(output)), module))
     }
@@ -233,10 +239,13 @@ impl<'a> MoveOSTestAdapter<'a> for MoveOSTestRunner<'a> {
             MoveAction::new_script_call(script_bytes, type_args, args),
         );
         let tx_hash = tx.ctx.tx_hash();
+        let gas_payer = tx.ctx.sender();
         let verified_tx = self.validate_tx(tx)?;
         let (raw_output, _) = self.moveos.execute_only(verified_tx)?;
 
-        let (output, _exe_info) = self.moveos_store.handle_tx_output(tx_hash, raw_output)?;
+        let (output, _exe_info) = self
+            .moveos_store
+            .handle_tx_output(tx_hash, gas_payer, raw_output)?;
         self.root = output.changeset.root_metadata();
         //TODO return values
         let value = SerializedReturnValues {
@@ -277,10 +286,13 @@ impl<'a> MoveOSTestAdapter<'a> for MoveOSTestRunner<'a> {
             MoveAction::new_function_call(function_id, type_args, args),
         );
         let tx_hash = tx.ctx.tx_hash();
+        let gas_payer = tx.ctx.sender();
         let verified_tx = self.validate_tx(tx)?;
         let (raw_output, _) = self.moveos.execute_only(verified_tx)?;
 
-        let (output, _exe_info) = self.moveos_store.handle_tx_output(tx_hash, raw_output)?;
+        let (output, _exe_info) = self
+            .moveos_store
+            .handle_tx_output(tx_hash, gas_payer, raw_output)?;
         self.root = output.changeset.root_metadata();
         debug_assert!(
             output.status == move_core_types::vm_status::KeptVMStatus::Executed,
@@ -3,6 +3,7 @@
 
 use super::types::LocalAccount;
 use crate::key_derive::{generate_derivation_path, generate_new_key_pair};
+use rooch_types::framework::auth_payload::{SignData, MESSAGE_INFO_PREFIX};
 use rooch_types::framework::session_key::SessionKey;
 use rooch_types::key_struct::{MnemonicData, MnemonicResult};
 use rooch_types::{
@@ -10,7 +11,10 @@ use rooch_types::{
     authentication_key::AuthenticationKey,
     crypto::{RoochKeyPair, Signature},
     key_struct::{EncryptionData, GeneratedKeyPair},
-    transaction::rooch::{RoochTransaction, RoochTransactionData},
+    transaction::{
+        rooch::{RoochTransaction, RoochTransactionData},
+        Authenticator,
+    },
 };
 use serde::Serialize;
 
@@ -105,7 +109,7 @@ pub trait AccountKeystore {
         Ok(account.cloned())
     }
 
-    fn contains_address(&self, address: &RoochAddress) -> bool;
+    fn contains_address(&self, address: &RoochAddress) -> Result<bool, anyhow::Error>;
 
     fn add_address_encryption_data_to_keys(
         &mut self,
@@ -129,6 +133,14 @@ pub trait AccountKeystore {
         is_password_empty: bool,
     ) -> Result<(), anyhow::Error>;
 
+    /// Replace the key of the address with the rotated key, the address does not change
+    fn rotate_key(
+        &mut self,
+        address: &RoochAddress,
+        kp: RoochKeyPair,
+        password: Option<String>,
+    ) -> Result<(), anyhow::Error>;
+
     fn nullify(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error>;
 
     fn sign_hashed(
@@ -138,6 +150,17 @@ pub trait AccountKeystore {
         password: Option<String>,
     ) -> Result<Signature, anyhow::Error>;
 
+    /// Sign the message in the Bitcoin signed message format
+    fn sign_message(
+        &self,
+        address: &RoochAddress,
+        msg: &[u8],
+        password: Option<String>,
+    ) -> Result<Signature, anyhow::Error> {
+        let sign_data = SignData::new_without_tx_hash(MESSAGE_INFO_PREFIX.to_vec(), msg.to_vec());
+        self.sign_hashed(address, &sign_data.encode(), password)
+    }
+
     fn sign_transaction(
         &self,
         address: &RoochAddress,
@@ -154,7 +177,19 @@ pub trait AccountKeystore {
     where
         T: Serialize;
 
-    fn addresses(&self) -> Vec<RoochAddress>;
+    /// Sponsor the transaction signed by the sender, the gas of the transaction is paid by the `gas_payer`
+    fn sponsor_transaction(
+        &self,
+        gas_payer: &RoochAddress,
+        tx: RoochTransaction,
+        password: Option<String>,
+    ) -> Result<RoochTransaction, anyhow::Error> {
+        let kp = self.get_key_pair(gas_payer, password)?;
+        let authenticator = Authenticator::sponsor(tx.authenticator.clone(), &kp, &tx.data);
+        Ok(RoochTransaction::new(tx.data, authenticator))
+    }
+
+    fn addresses(&self) -> Result<Vec<RoochAddress>, anyhow::Error>;
 
     fn nullify_address(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
         self.nullify(address)?;
@@ -167,6 +202,13 @@ pub trait AccountKeystore {
         password: Option<String>,
     ) -> Result<AuthenticationKey, anyhow::Error>;
 
+    /// Generate a secp256r1 session key, which simulates a WebAuthn passkey of the account
+    fn generate_webauthn_session_key(
+        &mut self,
+        address: &RoochAddress,
+        password: Option<String>,
+    ) -> Result<AuthenticationKey, anyhow::Error>;
+
     /// Binding on-chain SessionKey to LocalSessionKey
     fn binding_session_key(
         &mut self,
@@ -8,9 +8,9 @@ use rooch_types::framework::session_key::SessionKey;
 use rooch_types::key_struct::{MnemonicData, MnemonicResult};
 use rooch_types::to_bech32::ToBech32;
 use rooch_types::{
-    address::RoochAddress,
+    address::{BitcoinAddress, RoochAddress},
     authentication_key::AuthenticationKey,
-    crypto::{RoochKeyPair, Signature},
+    crypto::{RoochKeyPair, Signature, SignatureScheme},
     error::RoochError,
     key_struct::EncryptionData,
     transaction::{
@@ -36,6 +36,9 @@ pub(crate) struct BaseKeyStore {
     pub(crate) password_hash: Option<String>,
     #[serde(default)]
     pub(crate) is_password_empty: bool,
+    /// The original bitcoin address of the accounts whose auth key has been rotated
+    #[serde(default)]
+    pub(crate) rotated_accounts: BTreeMap<RoochAddress, BitcoinAddress>,
 }
 
 impl BaseKeyStore {
@@ -46,8 +49,26 @@ impl BaseKeyStore {
             session_keys: BTreeMap::new(),
             password_hash: None,
             is_password_empty: true,
+            rotated_accounts: BTreeMap::new(),
         }
     }
+
+    fn add_session_key(
+        &mut self,
+        address: &RoochAddress,
+        kp: RoochKeyPair,
+        password: Option<String>,
+    ) -> Result<AuthenticationKey, anyhow::Error> {
+        let authentication_key = kp.public().authentication_key();
+        let inner_map = self.session_keys.entry(*address).or_default();
+        let private_key_encryption = EncryptionData::encrypt_with_type(&kp, password)?;
+        let local_session_key = LocalSessionKey {
+            session_key: None,
+            private_key: private_key_encryption,
+        };
+        inner_map.insert(authentication_key.clone(), local_session_key);
+        Ok(authentication_key)
+    }
 }
 
 impl AccountKeystore for BaseKeyStore {
@@ -74,7 +95,11 @@ impl AccountKeystore for BaseKeyStore {
         for (address, encryption) in &self.keys {
             let keypair: RoochKeyPair = encryption.decrypt_with_type(password.clone())?;
             let public_key = keypair.public();
-            let bitcoin_address = public_key.bitcoin_address()?;
+            // The bitcoin address of a rotated account is derived from the original key
+            let bitcoin_address = match self.rotated_accounts.get(address) {
+                Some(bitcoin_address) => bitcoin_address.clone(),
+                None => public_key.bitcoin_address()?,
+            };
             let nostr_bech32_public_key = public_key.xonly_public_key()?.to_bech32()?;
             let has_session_key = self.session_keys.contains_key(address);
             let local_account = LocalAccount {
@@ -89,8 +114,8 @@ impl AccountKeystore for BaseKeyStore {
         Ok(accounts.into_values().collect())
     }
 
-    fn contains_address(&self, address: &RoochAddress) -> bool {
-        self.keys.contains_key(address)
+    fn contains_address(&self, address: &RoochAddress) -> Result<bool, anyhow::Error> {
+        Ok(self.keys.contains_key(address))
     }
 
     // TODO: deal with the Rooch and Nostr's get_key_pair() function. Consider Nostr scenario
@@ -155,8 +180,28 @@ impl AccountKeystore for BaseKeyStore {
         Ok(())
     }
 
+    fn rotate_key(
+        &mut self,
+        address: &RoochAddress,
+        kp: RoochKeyPair,
+        password: Option<String>,
+    ) -> Result<(), anyhow::Error> {
+        let encryption = self.keys.get(address).ok_or_else(|| {
+            RoochError::CommandArgumentError(format!("Cannot find key for address: [{address}]"))
+        })?;
+        if !self.rotated_accounts.contains_key(address) {
+            let old_kp: RoochKeyPair = encryption.decrypt_with_type(password.clone())?;
+            let bitcoin_address = old_kp.public().bitcoin_address()?;
+            self.rotated_accounts.insert(*address, bitcoin_address);
+        }
+        let private_key_encryption = EncryptionData::encrypt_with_type(&kp, password)?;
+        self.keys.insert(*address, private_key_encryption);
+        Ok(())
+    }
+
     fn nullify(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
         self.keys.remove(address);
+        self.rotated_accounts.remove(address);
         let mnemonic_data = match &self.mnemonic {
             Some(mnemonic) => mnemonic,
             // For None, this could be indicating that there's no internal account address in the mnemonic addresses
@@ -180,15 +225,16 @@ impl AccountKeystore for BaseKeyStore {
         password: Option<String>,
     ) -> Result<AuthenticationKey, anyhow::Error> {
         let kp: RoochKeyPair = RoochKeyPair::generate_ed25519();
-        let authentication_key = kp.public().authentication_key();
-        let inner_map = self.session_keys.entry(*address).or_default();
-        let private_key_encryption = EncryptionData::encrypt_with_type(&kp, password)?;
-        let local_session_key = LocalSessionKey {
-            session_key: None,
-            private_key: private_key_encryption,
-        };
-        inner_map.insert(authentication_key.clone(), local_session_key);
-        Ok(authentication_key)
+        self.add_session_key(address, kp, password)
+    }
+
+    fn generate_webauthn_session_key(
+        &mut self,
+        address: &RoochAddress,
+        password: Option<String>,
+    ) -> Result<AuthenticationKey, anyhow::Error> {
+        let kp: RoochKeyPair = RoochKeyPair::generate_secp256r1();
+        self.add_session_key(address, kp, password)
     }
 
     fn binding_session_key(
@@ -233,11 +279,14 @@ impl AccountKeystore for BaseKeyStore {
             .decrypt_with_type(password)
             .map_err(signature::Error::from_source)?;
 
-        let auth = authenticator::Authenticator::session(&kp, &msg);
+        let auth = match kp.public().scheme() {
+            SignatureScheme::Secp256r1 => authenticator::Authenticator::webauthn(&kp, &msg),
+            _ => authenticator::Authenticator::session(&kp, &msg),
+        };
         Ok(RoochTransaction::new(msg, auth))
     }
 
-    fn addresses(&self) -> Vec<RoochAddress> {
+    fn addresses(&self) -> Result<Vec<RoochAddress>, anyhow::Error> {
         // Create an empty Vec to store the addresses.
         let mut addresses = Vec::with_capacity(self.keys.len() + self.session_keys.len());
 
@@ -250,7 +299,7 @@ impl AccountKeystore for BaseKeyStore {
             addresses.push(*key);
         }
 
-        addresses
+        Ok(addresses)
     }
 
     fn set_password_hash_with_indicator(
@@ -45,7 +45,7 @@ impl AccountKeystore for FileBasedKeystore {
         self.keystore.get_accounts(password)
     }
 
-    fn contains_address(&self, address: &RoochAddress) -> bool {
+    fn contains_address(&self, address: &RoochAddress) -> Result<bool, anyhow::Error> {
         self.keystore.contains_address(address)
     }
 
@@ -68,6 +68,17 @@ impl AccountKeystore for FileBasedKeystore {
         self.keystore.get_key_pair(address, password)
     }
 
+    fn rotate_key(
+        &mut self,
+        address: &RoochAddress,
+        kp: RoochKeyPair,
+        password: Option<String>,
+    ) -> Result<(), anyhow::Error> {
+        self.keystore.rotate_key(address, kp, password)?;
+        self.save()?;
+        Ok(())
+    }
+
     fn nullify(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
         self.keystore.nullify(address)?;
         self.save()?;
@@ -104,7 +115,7 @@ impl AccountKeystore for FileBasedKeystore {
         self.keystore.sign_secure(address, msg, password)
     }
 
-    fn addresses(&self) -> Vec<RoochAddress> {
+    fn addresses(&self) -> Result<Vec<RoochAddress>, anyhow::Error> {
         // Create an empty Vec to store the addresses.
         let mut addresses =
             Vec::with_capacity(self.keystore.keys.len() + self.keystore.session_keys.len());
@@ -118,7 +129,7 @@ impl AccountKeystore for FileBasedKeystore {
             addresses.push(*key);
         }
 
-        addresses
+        Ok(addresses)
     }
 
     fn generate_session_key(
@@ -131,6 +142,18 @@ impl AccountKeystore for FileBasedKeystore {
         Ok(auth_key)
     }
 
+    fn generate_webauthn_session_key(
+        &mut self,
+        address: &RoochAddress,
+        password: Option<String>,
+    ) -> Result<AuthenticationKey, anyhow::Error> {
+        let auth_key = self
+            .keystore
+            .generate_webauthn_session_key(address, password)?;
+        self.save()?;
+        Ok(auth_key)
+    }
+
     fn binding_session_key(
         &mut self,
         address: RoochAddress,
@@ -24,7 +24,7 @@ impl AccountKeystore for InMemKeystore {
         self.keystore.get_accounts(password)
     }
 
-    fn contains_address(&self, address: &RoochAddress) -> bool {
+    fn contains_address(&self, address: &RoochAddress) -> Result<bool, anyhow::Error> {
         self.keystore.contains_address(address)
     }
 
@@ -45,6 +45,15 @@ impl AccountKeystore for InMemKeystore {
         self.keystore.get_key_pair(address, password)
     }
 
+    fn rotate_key(
+        &mut self,
+        address: &RoochAddress,
+        kp: RoochKeyPair,
+        password: Option<String>,
+    ) -> Result<(), anyhow::Error> {
+        self.keystore.rotate_key(address, kp, password)
+    }
+
     fn nullify(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
         self.keystore.nullify(address)
     }
@@ -79,7 +88,7 @@ impl AccountKeystore for InMemKeystore {
         self.keystore.sign_secure(address, msg, password)
     }
 
-    fn addresses(&self) -> Vec<RoochAddress> {
+    fn addresses(&self) -> Result<Vec<RoochAddress>, anyhow::Error> {
         // Create an empty Vec to store the addresses.
         let mut addresses =
             Vec::with_capacity(self.keystore.keys.len() + self.keystore.session_keys.len());
@@ -93,7 +102,7 @@ impl AccountKeystore for InMemKeystore {
             addresses.push(*key);
         }
 
-        addresses
+        Ok(addresses)
     }
 
     fn generate_session_key(
@@ -104,6 +113,15 @@ impl AccountKeystore for InMemKeystore {
         self.keystore.generate_session_key(address, password)
     }
 
+    fn generate_webauthn_session_key(
+        &mut self,
+        address: &RoochAddress,
+        password: Option<String>,
+    ) -> Result<AuthenticationKey, anyhow::Error> {
+        self.keystore
+            .generate_webauthn_session_key(address, password)
+    }
+
     fn binding_session_key(
         &mut self,
         address: RoochAddress,
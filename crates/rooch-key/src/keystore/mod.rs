@@ -5,6 +5,7 @@ use crate::keystore::account_keystore::AccountKeystore;
 use crate::keystore::file_keystore::FileBasedKeystore;
 use enum_dispatch::enum_dispatch;
 use memory_keystore::InMemKeystore;
+use remote_keystore::RemoteKeystore;
 use rooch_types::key_struct::{GeneratedKeyPair, MnemonicData, MnemonicResult};
 use rooch_types::{
     address::RoochAddress,
@@ -21,6 +22,7 @@ pub mod account_keystore;
 pub mod base_keystore;
 pub mod file_keystore;
 pub mod memory_keystore;
+pub mod remote_keystore;
 pub mod types;
 
 pub struct ImportedMnemonic {
@@ -33,6 +35,7 @@ pub struct ImportedMnemonic {
 pub enum Keystore {
     File(FileBasedKeystore),
     InMem(InMemKeystore),
+    Remote(RemoteKeystore),
 }
 
 impl AccountKeystore for Keystore {
@@ -49,6 +52,9 @@ impl AccountKeystore for Keystore {
             Keystore::InMem(inmem_keystore) => {
                 inmem_keystore.init_keystore(mnemonic_phrase, word_length, password)
             }
+            Keystore::Remote(remote_keystore) => {
+                remote_keystore.init_keystore(mnemonic_phrase, word_length, password)
+            }
         }
     }
 
@@ -56,6 +62,7 @@ impl AccountKeystore for Keystore {
         match self {
             Keystore::File(file_keystore) => file_keystore.init_mnemonic_data(mnemonic_data),
             Keystore::InMem(inmem_keystore) => inmem_keystore.init_mnemonic_data(mnemonic_data),
+            Keystore::Remote(remote_keystore) => remote_keystore.init_mnemonic_data(mnemonic_data),
         }
     }
 
@@ -68,13 +75,17 @@ impl AccountKeystore for Keystore {
             Keystore::InMem(inmem_keystore) => {
                 inmem_keystore.add_addresses_to_mnemonic_data(address)
             }
+            Keystore::Remote(remote_keystore) => {
+                remote_keystore.add_addresses_to_mnemonic_data(address)
+            }
         }
     }
 
-    fn contains_address(&self, address: &RoochAddress) -> bool {
+    fn contains_address(&self, address: &RoochAddress) -> Result<bool, anyhow::Error> {
         match self {
             Keystore::File(file_keystore) => file_keystore.contains_address(address),
             Keystore::InMem(inmem_keystore) => inmem_keystore.contains_address(address),
+            Keystore::Remote(remote_keystore) => remote_keystore.contains_address(address),
         }
     }
 
@@ -85,6 +96,7 @@ impl AccountKeystore for Keystore {
         match self {
             Keystore::File(file_keystore) => file_keystore.get_accounts(password),
             Keystore::InMem(inmem_keystore) => inmem_keystore.get_accounts(password),
+            Keystore::Remote(remote_keystore) => remote_keystore.get_accounts(password),
         }
     }
 
@@ -95,7 +107,7 @@ impl AccountKeystore for Keystore {
         authentication_key: &AuthenticationKey,
         password: Option<String>,
     ) -> Result<RoochTransaction, anyhow::Error> {
-        // Implement this method by delegating the call to the appropriate variant (File or InMem)
+        // Implement this method by delegating the call to the appropriate variant (File, InMem or Remote)
         match self {
             Keystore::File(file_keystore) => file_keystore.sign_transaction_via_session_key(
                 address,
@@ -109,6 +121,12 @@ impl AccountKeystore for Keystore {
                 authentication_key,
                 password,
             ),
+            Keystore::Remote(remote_keystore) => remote_keystore.sign_transaction_via_session_key(
+                address,
+                msg,
+                authentication_key,
+                password,
+            ),
         }
     }
 
@@ -117,7 +135,7 @@ impl AccountKeystore for Keystore {
         address: RoochAddress,
         encryption: EncryptionData,
     ) -> Result<(), anyhow::Error> {
-        // Implement this method to add a key pair to the appropriate variant (File or InMem)
+        // Implement this method to add a key pair to the appropriate variant (File, InMem or Remote)
         match self {
             Keystore::File(file_keystore) => {
                 file_keystore.add_address_encryption_data_to_keys(address, encryption)
@@ -125,6 +143,9 @@ impl AccountKeystore for Keystore {
             Keystore::InMem(inmem_keystore) => {
                 inmem_keystore.add_address_encryption_data_to_keys(address, encryption)
             }
+            Keystore::Remote(remote_keystore) => {
+                remote_keystore.add_address_encryption_data_to_keys(address, encryption)
+            }
         }
     }
 
@@ -133,18 +154,33 @@ impl AccountKeystore for Keystore {
         address: &RoochAddress,
         password: Option<String>,
     ) -> Result<RoochKeyPair, anyhow::Error> {
-        // Implement this method to get the key pair by coin ID from the appropriate variant (File or InMem)
+        // Implement this method to get the key pair by coin ID from the appropriate variant (File, InMem or Remote)
         match self {
             Keystore::File(file_keystore) => file_keystore.get_key_pair(address, password),
             Keystore::InMem(inmem_keystore) => inmem_keystore.get_key_pair(address, password),
+            Keystore::Remote(remote_keystore) => remote_keystore.get_key_pair(address, password),
+        }
+    }
+
+    fn rotate_key(
+        &mut self,
+        address: &RoochAddress,
+        kp: RoochKeyPair,
+        password: Option<String>,
+    ) -> Result<(), anyhow::Error> {
+        match self {
+            Keystore::File(file_keystore) => file_keystore.rotate_key(address, kp, password),
+            Keystore::InMem(inmem_keystore) => inmem_keystore.rotate_key(address, kp, password),
+            Keystore::Remote(remote_keystore) => remote_keystore.rotate_key(address, kp, password),
         }
     }
 
     fn nullify(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
-        // Implement this method to nullify the key pair by coin ID for the appropriate variant (File or InMem)
+        // Implement this method to nullify the key pair by coin ID for the appropriate variant (File, InMem or Remote)
         match self {
             Keystore::File(file_keystore) => file_keystore.nullify(address),
             Keystore::InMem(inmem_keystore) => inmem_keystore.nullify(address),
+            Keystore::Remote(remote_keystore) => remote_keystore.nullify(address),
         }
     }
 
@@ -154,10 +190,28 @@ impl AccountKeystore for Keystore {
         msg: &[u8],
         password: Option<String>,
     ) -> Result<Signature, anyhow::Error> {
-        // Implement this method to sign a hashed message for the appropriate variant (File or InMem)
+        // Implement this method to sign a hashed message for the appropriate variant (File, InMem or Remote)
         match self {
             Keystore::File(file_keystore) => file_keystore.sign_hashed(address, msg, password),
             Keystore::InMem(inmem_keystore) => inmem_keystore.sign_hashed(address, msg, password),
+            Keystore::Remote(remote_keystore) => {
+                remote_keystore.sign_hashed(address, msg, password)
+            }
+        }
+    }
+
+    fn sign_message(
+        &self,
+        address: &RoochAddress,
+        msg: &[u8],
+        password: Option<String>,
+    ) -> Result<Signature, anyhow::Error> {
+        match self {
+            Keystore::File(file_keystore) => file_keystore.sign_message(address, msg, password),
+            Keystore::InMem(inmem_keystore) => inmem_keystore.sign_message(address, msg, password),
+            Keystore::Remote(remote_keystore) => {
+                remote_keystore.sign_message(address, msg, password)
+            }
         }
     }
 
@@ -167,12 +221,15 @@ impl AccountKeystore for Keystore {
         msg: RoochTransactionData,
         password: Option<String>,
     ) -> Result<RoochTransaction, anyhow::Error> {
-        // Implement this method to sign a transaction for the appropriate variant (File or InMem)
+        // Implement this method to sign a transaction for the appropriate variant (File, InMem or Remote)
         match self {
             Keystore::File(file_keystore) => file_keystore.sign_transaction(address, msg, password),
             Keystore::InMem(inmem_keystore) => {
                 inmem_keystore.sign_transaction(address, msg, password)
             }
+            Keystore::Remote(remote_keystore) => {
+                remote_keystore.sign_transaction(address, msg, password)
+            }
         }
     }
 
@@ -185,10 +242,32 @@ impl AccountKeystore for Keystore {
     where
         T: Serialize,
     {
-        // Implement this method to sign a secure message for the appropriate variant (File or InMem)
+        // Implement this method to sign a secure message for the appropriate variant (File, InMem or Remote)
         match self {
             Keystore::File(file_keystore) => file_keystore.sign_secure(address, msg, password),
             Keystore::InMem(inmem_keystore) => inmem_keystore.sign_secure(address, msg, password),
+            Keystore::Remote(remote_keystore) => {
+                remote_keystore.sign_secure(address, msg, password)
+            }
+        }
+    }
+
+    fn sponsor_transaction(
+        &self,
+        gas_payer: &RoochAddress,
+        tx: RoochTransaction,
+        password: Option<String>,
+    ) -> Result<RoochTransaction, anyhow::Error> {
+        match self {
+            Keystore::File(file_keystore) => {
+                file_keystore.sponsor_transaction(gas_payer, tx, password)
+            }
+            Keystore::InMem(inmem_keystore) => {
+                inmem_keystore.sponsor_transaction(gas_payer, tx, password)
+            }
+            Keystore::Remote(remote_keystore) => {
+                remote_keystore.sponsor_transaction(gas_payer, tx, password)
+            }
         }
     }
 
@@ -197,12 +276,33 @@ impl AccountKeystore for Keystore {
         address: &RoochAddress,
         password: Option<String>,
     ) -> Result<AuthenticationKey, anyhow::Error> {
-        // Implement this method to generate a session key for the appropriate variant (File or InMem)
+        // Implement this method to generate a session key for the appropriate variant (File, InMem or Remote)
         match self {
             Keystore::File(file_keystore) => file_keystore.generate_session_key(address, password),
             Keystore::InMem(inmem_keystore) => {
                 inmem_keystore.generate_session_key(address, password)
             }
+            Keystore::Remote(remote_keystore) => {
+                remote_keystore.generate_session_key(address, password)
+            }
+        }
+    }
+
+    fn generate_webauthn_session_key(
+        &mut self,
+        address: &RoochAddress,
+        password: Option<String>,
+    ) -> Result<AuthenticationKey, anyhow::Error> {
+        match self {
+            Keystore::File(file_keystore) => {
+                file_keystore.generate_webauthn_session_key(address, password)
+            }
+            Keystore::InMem(inmem_keystore) => {
+                inmem_keystore.generate_webauthn_session_key(address, password)
+            }
+            Keystore::Remote(remote_keystore) => {
+                remote_keystore.generate_webauthn_session_key(address, password)
+            }
         }
     }
 
@@ -218,13 +318,17 @@ impl AccountKeystore for Keystore {
             Keystore::InMem(inmem_keystore) => {
                 inmem_keystore.binding_session_key(address, session_key)
             }
+            Keystore::Remote(remote_keystore) => {
+                remote_keystore.binding_session_key(address, session_key)
+            }
         }
     }
 
-    fn addresses(&self) -> Vec<RoochAddress> {
+    fn addresses(&self) -> Result<Vec<RoochAddress>, anyhow::Error> {
         match self {
             Keystore::File(file_keystore) => file_keystore.addresses(),
             Keystore::InMem(inmem_keystore) => inmem_keystore.addresses(),
+            Keystore::Remote(remote_keystore) => remote_keystore.addresses(),
         }
     }
 
@@ -240,6 +344,9 @@ impl AccountKeystore for Keystore {
             Keystore::InMem(inmem_keystore) => {
                 inmem_keystore.set_password_hash_with_indicator(password_hash, is_password_empty)
             }
+            Keystore::Remote(remote_keystore) => {
+                remote_keystore.set_password_hash_with_indicator(password_hash, is_password_empty)
+            }
         }
     }
 
@@ -247,6 +354,7 @@ impl AccountKeystore for Keystore {
         match self {
             Keystore::File(file_keystore) => file_keystore.get_password_hash(),
             Keystore::InMem(inmem_keystore) => inmem_keystore.get_password_hash(),
+            Keystore::Remote(remote_keystore) => remote_keystore.get_password_hash(),
         }
     }
 
@@ -254,6 +362,7 @@ impl AccountKeystore for Keystore {
         match self {
             Keystore::File(file_keystore) => file_keystore.get_if_password_is_empty(),
             Keystore::InMem(inmem_keystore) => inmem_keystore.get_if_password_is_empty(),
+            Keystore::Remote(remote_keystore) => remote_keystore.get_if_password_is_empty(),
         }
     }
 
@@ -261,6 +370,7 @@ impl AccountKeystore for Keystore {
         match self {
             Keystore::File(file_keystore) => file_keystore.get_mnemonic(password),
             Keystore::InMem(inmem_keystore) => inmem_keystore.get_mnemonic(password),
+            Keystore::Remote(remote_keystore) => remote_keystore.get_mnemonic(password),
         }
     }
 }
@@ -276,6 +386,10 @@ impl Display for Keystore {
             Keystore::InMem(_) => {
                 writeln!(writer, "Keystore Type : Rooch InMem")?;
             }
+            Keystore::Remote(remote) => {
+                writeln!(writer, "Keystore Type : Rooch Remote")?;
+                write!(writer, "Signer Endpoint : {}", remote.endpoint)?;
+            }
         }
         write!(f, "{}", writer)
     }
@@ -0,0 +1,981 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! The remote keystore signs the transactions via an external signer, so the private keys are isolated from the process which uses the keystore.
+//!
+//! The signer speaks JSON-RPC 2.0 over HTTP, or over a UNIX socket or a TCP connection with one request or response per line.
+//! The binary types such as transaction data are hex encoded BCS bytes.
+
+use super::types::LocalAccount;
+use crate::keystore::account_keystore::AccountKeystore;
+use anyhow::{anyhow, bail, ensure};
+use fastcrypto::encoding::{Encoding, Hex};
+use fastcrypto::traits::ToFromBytes;
+use rooch_types::framework::session_key::SessionKey;
+use rooch_types::key_struct::{MnemonicData, MnemonicResult};
+use rooch_types::{
+    address::RoochAddress,
+    authentication_key::AuthenticationKey,
+    crypto::{RoochKeyPair, Signature},
+    key_struct::EncryptionData,
+    transaction::rooch::{RoochTransaction, RoochTransactionData},
+};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt::{Display, Formatter};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+#[cfg(unix)]
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+pub const JSONRPC_VERSION: &str = "2.0";
+
+/// Get the accounts managed by the signer, no params
+pub const METHOD_ACCOUNTS: &str = "signer_accounts";
+/// Sign the transaction data, params: `[address, hex_bcs_transaction_data]`
+pub const METHOD_SIGN_TRANSACTION: &str = "signer_signTransaction";
+/// Sponsor the signed transaction, params: `[gas_payer, hex_bcs_transaction]`
+pub const METHOD_SPONSOR_TRANSACTION: &str = "signer_sponsorTransaction";
+/// Sign the message in the Bitcoin signed message format, params: `[address, hex_message]`
+pub const METHOD_SIGN_MESSAGE: &str = "signer_signMessage";
+
+/// The standard JSON-RPC error codes used by the signer
+pub const ERROR_CODE_INVALID_REQUEST: i64 = -32600;
+pub const ERROR_CODE_METHOD_NOT_FOUND: i64 = -32601;
+pub const ERROR_CODE_INVALID_PARAMS: i64 = -32602;
+pub const ERROR_CODE_INTERNAL_ERROR: i64 = -32603;
+
+const REMOTE_SIGNER_TIMEOUT: Duration = Duration::from_secs(30);
+/// The max size of the HTTP request or response body
+const REMOTE_SIGNER_MAX_HTTP_BODY_SIZE: usize = 8 * 1024 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RemoteSignerEndpoint {
+    #[cfg(unix)]
+    Unix(PathBuf),
+    Tcp(String),
+    Http(String),
+}
+
+impl FromStr for RemoteSignerEndpoint {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        #[cfg(unix)]
+        {
+            if let Some(path) = s.strip_prefix("unix://") {
+                ensure!(!path.is_empty(), "Invalid remote signer endpoint: {}", s);
+                return Ok(RemoteSignerEndpoint::Unix(PathBuf::from(path)));
+            }
+        }
+        if let Some(addr) = s.strip_prefix("tcp://") {
+            ensure!(!addr.is_empty(), "Invalid remote signer endpoint: {}", s);
+            return Ok(RemoteSignerEndpoint::Tcp(addr.to_owned()));
+        }
+        if let Some(addr) = s.strip_prefix("http://") {
+            // The signer serves the JSON-RPC requests on the root path
+            let addr = addr.strip_suffix('/').unwrap_or(addr);
+            ensure!(
+                !addr.is_empty() && !addr.contains('/'),
+                "Invalid remote signer endpoint: {}",
+                s
+            );
+            return Ok(RemoteSignerEndpoint::Http(addr.to_owned()));
+        }
+        bail!(
+            "Invalid remote signer endpoint: {}, expect `unix://<path>`, `tcp://<host>:<port>` or `http://<host>:<port>`",
+            s
+        )
+    }
+}
+
+impl RemoteSignerEndpoint {
+    /// The signer does not authenticate the clients, so the TCP and HTTP endpoints must be loopback addresses,
+    /// use a UNIX socket or a tunnel to access the signer from another host.
+    pub fn ensure_local(&self) -> Result<(), anyhow::Error> {
+        match self {
+            #[cfg(unix)]
+            RemoteSignerEndpoint::Unix(_) => Ok(()),
+            RemoteSignerEndpoint::Tcp(addr) | RemoteSignerEndpoint::Http(addr) => {
+                let socket_addrs = addr
+                    .to_socket_addrs()
+                    .map_err(|e| anyhow!("Invalid remote signer endpoint {}: {}", self, e))?
+                    .collect::<Vec<_>>();
+                ensure!(
+                    !socket_addrs.is_empty(),
+                    "Invalid remote signer endpoint {}: no address resolved",
+                    self
+                );
+                ensure!(
+                    socket_addrs.iter().all(|addr| addr.ip().is_loopback()),
+                    "The remote signer endpoint {} is not a loopback address",
+                    self
+                );
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Display for RemoteSignerEndpoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            #[cfg(unix)]
+            RemoteSignerEndpoint::Unix(path) => write!(f, "unix://{}", path.display()),
+            RemoteSignerEndpoint::Tcp(addr) => write!(f, "tcp://{}", addr),
+            RemoteSignerEndpoint::Http(addr) => write!(f, "http://{}", addr),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteSignerRequest {
+    pub jsonrpc: String,
+    pub id: u64,
+    pub method: String,
+    #[serde(default)]
+    pub params: Vec<Value>,
+}
+
+impl RemoteSignerRequest {
+    pub fn new(id: u64, method: &str, params: Vec<Value>) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_owned(),
+            id,
+            method: method.to_owned(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteSignerErrorObject {
+    pub code: i64,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteSignerResponse {
+    pub jsonrpc: String,
+    pub id: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<RemoteSignerErrorObject>,
+}
+
+impl RemoteSignerResponse {
+    pub fn result(id: u64, result: Value) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_owned(),
+            id,
+            result: Some(result),
+            error: None,
+        }
+    }
+
+    pub fn error(id: u64, code: i64, message: String) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_owned(),
+            id,
+            result: None,
+            error: Some(RemoteSignerErrorObject { code, message }),
+        }
+    }
+}
+
+/// The keystore which delegates the signing to a remote signer.
+/// The remote keystore does not hold any key, so the key management methods are not supported.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoteKeystore {
+    pub endpoint: RemoteSignerEndpoint,
+    /// The addresses of the signer, fetched on the first use
+    #[serde(skip)]
+    addresses: OnceLock<Vec<RoochAddress>>,
+}
+
+impl RemoteKeystore {
+    /// Create the keystore of the remote signer, the signer is not dialed until the keystore is used,
+    /// so the commands which do not sign work without the signer.
+    pub fn new(endpoint: RemoteSignerEndpoint) -> Self {
+        Self {
+            endpoint,
+            addresses: OnceLock::new(),
+        }
+    }
+
+    fn load_addresses(&self) -> Result<&Vec<RoochAddress>, anyhow::Error> {
+        if let Some(addresses) = self.addresses.get() {
+            return Ok(addresses);
+        }
+        let addresses = self
+            .get_accounts(None)?
+            .into_iter()
+            .map(|account| account.address)
+            .collect();
+        Ok(self.addresses.get_or_init(|| addresses))
+    }
+
+    fn call<R: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Vec<Value>,
+    ) -> Result<R, anyhow::Error> {
+        let request = RemoteSignerRequest::new(0, method, params);
+        let response = match &self.endpoint {
+            #[cfg(unix)]
+            RemoteSignerEndpoint::Unix(path) => {
+                let stream = UnixStream::connect(path).map_err(|e| {
+                    anyhow!("Connect to remote signer {} failed: {}", self.endpoint, e)
+                })?;
+                stream.set_read_timeout(Some(REMOTE_SIGNER_TIMEOUT))?;
+                stream.set_write_timeout(Some(REMOTE_SIGNER_TIMEOUT))?;
+                send_request(stream, &request)?
+            }
+            RemoteSignerEndpoint::Tcp(addr) => {
+                let stream = TcpStream::connect(addr).map_err(|e| {
+                    anyhow!("Connect to remote signer {} failed: {}", self.endpoint, e)
+                })?;
+                stream.set_read_timeout(Some(REMOTE_SIGNER_TIMEOUT))?;
+                stream.set_write_timeout(Some(REMOTE_SIGNER_TIMEOUT))?;
+                send_request(stream, &request)?
+            }
+            RemoteSignerEndpoint::Http(addr) => {
+                let stream = TcpStream::connect(addr).map_err(|e| {
+                    anyhow!("Connect to remote signer {} failed: {}", self.endpoint, e)
+                })?;
+                stream.set_read_timeout(Some(REMOTE_SIGNER_TIMEOUT))?;
+                stream.set_write_timeout(Some(REMOTE_SIGNER_TIMEOUT))?;
+                send_http_request(stream, addr, &request)?
+            }
+        };
+        if let Some(error) = response.error {
+            bail!(
+                "Remote signer {} error, code: {}, message: {}",
+                self.endpoint,
+                error.code,
+                error.message
+            );
+        }
+        let result = response
+            .result
+            .ok_or_else(|| anyhow!("Remote signer {} returns an empty result", self.endpoint))?;
+        Ok(serde_json::from_value(result)?)
+    }
+
+    fn unsupported<T>(&self, operation: &str) -> Result<T, anyhow::Error> {
+        bail!("The remote keystore does not support {}", operation)
+    }
+}
+
+fn send_request<S: Read + Write>(
+    mut stream: S,
+    request: &RemoteSignerRequest,
+) -> Result<RemoteSignerResponse, anyhow::Error> {
+    let mut line = serde_json::to_vec(request)?;
+    line.push(b'\n');
+    stream.write_all(&line)?;
+    stream.flush()?;
+
+    let mut reader = BufReader::new(stream);
+    let mut response = String::new();
+    reader.read_line(&mut response)?;
+    ensure!(
+        !response.is_empty(),
+        "Remote signer closed the connection without response"
+    );
+    let response: RemoteSignerResponse = serde_json::from_str(&response)?;
+    ensure!(
+        response.id == request.id,
+        "Remote signer response id mismatch, expect: {}, actual: {}",
+        request.id,
+        response.id
+    );
+    Ok(response)
+}
+
+/// Send the request as an HTTP POST to the root path, the connection is closed after the response
+fn send_http_request<S: Read + Write>(
+    mut stream: S,
+    host: &str,
+    request: &RemoteSignerRequest,
+) -> Result<RemoteSignerResponse, anyhow::Error> {
+    let body = serde_json::to_vec(request)?;
+    let header = format!(
+        "POST / HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        host,
+        body.len()
+    );
+    stream.write_all(header.as_bytes())?;
+    stream.write_all(&body)?;
+    stream.flush()?;
+
+    let mut reader = BufReader::new(stream);
+    let mut status_line = String::new();
+    reader.read_line(&mut status_line)?;
+    ensure!(
+        !status_line.is_empty(),
+        "Remote signer closed the connection without response"
+    );
+    let status = status_line.split_whitespace().nth(1).unwrap_or_default();
+    let (content_length, _) = read_http_headers(&mut reader)?;
+    let body = read_http_body(&mut reader, content_length)?;
+    ensure!(
+        status == "200",
+        "Remote signer HTTP error, status: {}, body: {}",
+        status_line.trim(),
+        String::from_utf8_lossy(&body)
+    );
+    let response: RemoteSignerResponse = serde_json::from_slice(&body)?;
+    ensure!(
+        response.id == request.id,
+        "Remote signer response id mismatch, expect: {}, actual: {}",
+        request.id,
+        response.id
+    );
+    Ok(response)
+}
+
+/// The HTTP headers which the signer and the client care about
+#[derive(Debug, Default)]
+struct HttpHeaders {
+    content_type: Option<String>,
+    origin: Option<String>,
+}
+
+/// Read the headers until the empty line, return the content length and the other headers
+fn read_http_headers<R: BufRead>(
+    reader: &mut R,
+) -> Result<(Option<usize>, HttpHeaders), anyhow::Error> {
+    let mut content_length = None;
+    let mut headers = HttpHeaders::default();
+    loop {
+        let mut line = String::new();
+        ensure!(
+            reader.read_line(&mut line)? != 0,
+            "Unexpected end of the HTTP headers"
+        );
+        let line = line.trim_end();
+        if line.is_empty() {
+            return Ok((content_length, headers));
+        }
+        let (name, value) = line
+            .split_once(':')
+            .ok_or_else(|| anyhow!("Invalid HTTP header: {}", line))?;
+        let value = value.trim();
+        match name.trim().to_ascii_lowercase().as_str() {
+            "content-length" => content_length = Some(value.parse()?),
+            "content-type" => headers.content_type = Some(value.to_owned()),
+            "origin" => headers.origin = Some(value.to_owned()),
+            "transfer-encoding" => bail!("Unsupported HTTP transfer encoding: {}", value),
+            _ => {}
+        }
+    }
+}
+
+/// Read the body of the content length, or until the connection is closed if the length is absent
+fn read_http_body<R: BufRead>(
+    reader: &mut R,
+    content_length: Option<usize>,
+) -> Result<Vec<u8>, anyhow::Error> {
+    let mut body = Vec::new();
+    match content_length {
+        Some(length) => {
+            ensure!(
+                length <= REMOTE_SIGNER_MAX_HTTP_BODY_SIZE,
+                "The HTTP body is too large: {}",
+                length
+            );
+            body.resize(length, 0);
+            reader.read_exact(&mut body)?;
+        }
+        None => {
+            reader
+                .take(REMOTE_SIGNER_MAX_HTTP_BODY_SIZE as u64 + 1)
+                .read_to_end(&mut body)?;
+            ensure!(
+                body.len() <= REMOTE_SIGNER_MAX_HTTP_BODY_SIZE,
+                "The HTTP body is too large"
+            );
+        }
+    }
+    Ok(body)
+}
+
+fn write_http_response<W: Write>(
+    stream: &mut W,
+    status: &str,
+    content_type: &str,
+    body: &[u8],
+) -> Result<(), anyhow::Error> {
+    let header = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        content_type,
+        body.len()
+    );
+    stream.write_all(header.as_bytes())?;
+    stream.write_all(body)?;
+    stream.flush()?;
+    Ok(())
+}
+
+impl AccountKeystore for RemoteKeystore {
+    fn init_mnemonic_data(&mut self, _mnemonic_data: MnemonicData) -> Result<(), anyhow::Error> {
+        self.unsupported("mnemonic")
+    }
+
+    fn add_addresses_to_mnemonic_data(
+        &mut self,
+        _address: RoochAddress,
+    ) -> Result<(), anyhow::Error> {
+        self.unsupported("mnemonic")
+    }
+
+    fn get_mnemonic(&self, _password: Option<String>) -> Result<MnemonicResult, anyhow::Error> {
+        self.unsupported("mnemonic")
+    }
+
+    fn get_accounts(&self, _password: Option<String>) -> Result<Vec<LocalAccount>, anyhow::Error> {
+        self.call(METHOD_ACCOUNTS, vec![])
+    }
+
+    fn contains_address(&self, address: &RoochAddress) -> Result<bool, anyhow::Error> {
+        Ok(self.load_addresses()?.contains(address))
+    }
+
+    fn add_address_encryption_data_to_keys(
+        &mut self,
+        _address: RoochAddress,
+        _encryption: EncryptionData,
+    ) -> Result<(), anyhow::Error> {
+        self.unsupported("adding keys")
+    }
+
+    fn get_key_pair(
+        &self,
+        _address: &RoochAddress,
+        _password: Option<String>,
+    ) -> Result<RoochKeyPair, anyhow::Error> {
+        self.unsupported("exporting key pairs")
+    }
+
+    fn get_password_hash(&self) -> String {
+        // The password of the keys is managed by the signer
+        String::new()
+    }
+
+    fn get_if_password_is_empty(&self) -> bool {
+        true
+    }
+
+    fn set_password_hash_with_indicator(
+        &mut self,
+        _password_hash: String,
+        _is_password_empty: bool,
+    ) -> Result<(), anyhow::Error> {
+        self.unsupported("password")
+    }
+
+    fn rotate_key(
+        &mut self,
+        _address: &RoochAddress,
+        _kp: RoochKeyPair,
+        _password: Option<String>,
+    ) -> Result<(), anyhow::Error> {
+        self.unsupported("rotating keys")
+    }
+
+    fn nullify(&mut self, _address: &RoochAddress) -> Result<(), anyhow::Error> {
+        self.unsupported("removing keys")
+    }
+
+    fn sign_hashed(
+        &self,
+        _address: &RoochAddress,
+        _msg: &[u8],
+        _password: Option<String>,
+    ) -> Result<Signature, anyhow::Error> {
+        // The signer only signs the decoded transactions and messages, it never signs an opaque hash
+        self.unsupported("signing hashed messages")
+    }
+
+    fn sign_message(
+        &self,
+        address: &RoochAddress,
+        msg: &[u8],
+        _password: Option<String>,
+    ) -> Result<Signature, anyhow::Error> {
+        let signature: String = self.call(
+            METHOD_SIGN_MESSAGE,
+            vec![
+                serde_json::to_value(address)?,
+                Value::String(Hex::encode(msg)),
+            ],
+        )?;
+        Signature::from_bytes(&Hex::decode(&signature)?)
+            .map_err(|e| anyhow!("Remote signer returns an invalid signature: {}", e))
+    }
+
+    fn sign_transaction(
+        &self,
+        address: &RoochAddress,
+        msg: RoochTransactionData,
+        _password: Option<String>,
+    ) -> Result<RoochTransaction, anyhow::Error> {
+        let tx: String = self.call(
+            METHOD_SIGN_TRANSACTION,
+            vec![
+                serde_json::to_value(address)?,
+                Value::String(Hex::encode(bcs::to_bytes(&msg)?)),
+            ],
+        )?;
+        let tx: RoochTransaction = bcs::from_bytes(&Hex::decode(&tx)?)?;
+        ensure!(
+            tx.data == msg,
+            "Remote signer returns a transaction with different data"
+        );
+        Ok(tx)
+    }
+
+    fn sign_secure<T>(
+        &self,
+        _address: &RoochAddress,
+        _msg: &T,
+        _password: Option<String>,
+    ) -> Result<Signature, anyhow::Error>
+    where
+        T: Serialize,
+    {
+        self.unsupported("signing messages")
+    }
+
+    fn sponsor_transaction(
+        &self,
+        gas_payer: &RoochAddress,
+        tx: RoochTransaction,
+        _password: Option<String>,
+    ) -> Result<RoochTransaction, anyhow::Error> {
+        let sponsored_tx: String = self.call(
+            METHOD_SPONSOR_TRANSACTION,
+            vec![
+                serde_json::to_value(gas_payer)?,
+                Value::String(Hex::encode(bcs::to_bytes(&tx)?)),
+            ],
+        )?;
+        let sponsored_tx: RoochTransaction = bcs::from_bytes(&Hex::decode(&sponsored_tx)?)?;
+        ensure!(
+            sponsored_tx.data == tx.data,
+            "Remote signer returns a transaction with different data"
+        );
+        Ok(sponsored_tx)
+    }
+
+    fn addresses(&self) -> Result<Vec<RoochAddress>, anyhow::Error> {
+        self.load_addresses().cloned()
+    }
+
+    fn generate_session_key(
+        &mut self,
+        _address: &RoochAddress,
+        _password: Option<String>,
+    ) -> Result<AuthenticationKey, anyhow::Error> {
+        self.unsupported("session keys")
+    }
+
+    fn generate_webauthn_session_key(
+        &mut self,
+        _address: &RoochAddress,
+        _password: Option<String>,
+    ) -> Result<AuthenticationKey, anyhow::Error> {
+        self.unsupported("session keys")
+    }
+
+    fn binding_session_key(
+        &mut self,
+        _address: RoochAddress,
+        _session_key: SessionKey,
+    ) -> Result<(), anyhow::Error> {
+        self.unsupported("session keys")
+    }
+
+    fn sign_transaction_via_session_key(
+        &self,
+        _address: &RoochAddress,
+        _msg: RoochTransactionData,
+        _authentication_key: &AuthenticationKey,
+        _password: Option<String>,
+    ) -> Result<RoochTransaction, anyhow::Error> {
+        self.unsupported("session keys")
+    }
+}
+
+/// The signer side of the remote keystore protocol, handle the requests with the wrapped keystore.
+pub struct RemoteSigner<K> {
+    keystore: K,
+    password: Option<String>,
+}
+
+impl<K: AccountKeystore> RemoteSigner<K> {
+    pub fn new(keystore: K, password: Option<String>) -> Self {
+        Self { keystore, password }
+    }
+
+    /// Serve the requests of a connection until the client closes it
+    pub fn serve<S: Read + Write>(&self, stream: S) -> Result<(), anyhow::Error> {
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 {
+                return Ok(());
+            }
+            if line.trim().is_empty() {
+                continue;
+            }
+            let response = match serde_json::from_str::<RemoteSignerRequest>(&line) {
+                Ok(request) => self.handle_request(request),
+                Err(e) => RemoteSignerResponse::error(
+                    0,
+                    ERROR_CODE_INVALID_REQUEST,
+                    format!("Invalid request: {}", e),
+                ),
+            };
+            let mut response = serde_json::to_vec(&response)?;
+            response.push(b'\n');
+            let stream = reader.get_mut();
+            stream.write_all(&response)?;
+            stream.flush()?;
+        }
+    }
+
+    /// Serve one HTTP request of a connection, the connection is closed after the response.
+    /// The requests from the browsers are rejected, so a web page can not sign via a local signer.
+    pub fn serve_http<S: Read + Write>(&self, stream: S) -> Result<(), anyhow::Error> {
+        let mut reader = BufReader::new(stream);
+        let mut request_line = String::new();
+        if reader.read_line(&mut request_line)? == 0 {
+            return Ok(());
+        }
+        let (content_length, headers) = read_http_headers(&mut reader)?;
+        // Read the body before responding, so the rejected client receives the response instead of a reset
+        let body = match content_length {
+            Some(_) => read_http_body(&mut reader, content_length)?,
+            None => vec![],
+        };
+        let method = request_line.split_whitespace().next().unwrap_or_default();
+        let rejection = if method != "POST" {
+            Some(("405 Method Not Allowed", "Only POST is allowed"))
+        } else if headers.origin.is_some() {
+            Some(("403 Forbidden", "The cross origin request is not allowed"))
+        } else if !headers
+            .content_type
+            .as_deref()
+            .is_some_and(|content_type| content_type.starts_with("application/json"))
+        {
+            Some((
+                "415 Unsupported Media Type",
+                "The content type must be application/json",
+            ))
+        } else if content_length.is_none() {
+            Some(("411 Length Required", "The content length is required"))
+        } else {
+            None
+        };
+        if let Some((status, message)) = rejection {
+            return write_http_response(reader.get_mut(), status, "text/plain", message.as_bytes());
+        }
+        let response = match serde_json::from_slice::<RemoteSignerRequest>(&body) {
+            Ok(request) => self.handle_request(request),
+            Err(e) => RemoteSignerResponse::error(
+                0,
+                ERROR_CODE_INVALID_REQUEST,
+                format!("Invalid request: {}", e),
+            ),
+        };
+        let response = serde_json::to_vec(&response)?;
+        write_http_response(reader.get_mut(), "200 OK", "application/json", &response)
+    }
+
+    pub fn handle_request(&self, request: RemoteSignerRequest) -> RemoteSignerResponse {
+        let id = request.id;
+        if request.jsonrpc != JSONRPC_VERSION {
+            return RemoteSignerResponse::error(
+                id,
+                ERROR_CODE_INVALID_REQUEST,
+                format!("Unsupported jsonrpc version: {}", request.jsonrpc),
+            );
+        }
+        let result = match request.method.as_str() {
+            METHOD_ACCOUNTS => self.accounts(),
+            METHOD_SIGN_TRANSACTION => self.sign_transaction(request.params),
+            METHOD_SPONSOR_TRANSACTION => self.sponsor_transaction(request.params),
+            METHOD_SIGN_MESSAGE => self.sign_message(request.params),
+            method => {
+                return RemoteSignerResponse::error(
+                    id,
+                    ERROR_CODE_METHOD_NOT_FOUND,
+                    format!("Method not found: {}", method),
+                )
+            }
+        };
+        match result {
+            Ok(result) => RemoteSignerResponse::result(id, result),
+            Err(e) => {
+                let code = if e.is::<serde_json::Error>() || e.is::<bcs::Error>() {
+                    ERROR_CODE_INVALID_PARAMS
+                } else {
+                    ERROR_CODE_INTERNAL_ERROR
+                };
+                RemoteSignerResponse::error(id, code, e.to_string())
+            }
+        }
+    }
+
+    fn accounts(&self) -> Result<Value, anyhow::Error> {
+        let accounts = self.keystore.get_accounts(self.password.clone())?;
+        Ok(serde_json::to_value(accounts)?)
+    }
+
+    fn sign_transaction(&self, params: Vec<Value>) -> Result<Value, anyhow::Error> {
+        let (address, tx_data) = parse_params(params)?;
+        let tx_data: RoochTransactionData = bcs::from_bytes(&Hex::decode(&tx_data)?)?;
+        ensure!(
+            tx_data.sender == address,
+            "The transaction sender {} is not the signing address {}",
+            tx_data.sender,
+            address
+        );
+        let tx = self
+            .keystore
+            .sign_transaction(&address, tx_data, self.password.clone())?;
+        Ok(Value::String(Hex::encode(bcs::to_bytes(&tx)?)))
+    }
+
+    fn sponsor_transaction(&self, params: Vec<Value>) -> Result<Value, anyhow::Error> {
+        let (gas_payer, tx) = parse_params(params)?;
+        let tx: RoochTransaction = bcs::from_bytes(&Hex::decode(&tx)?)?;
+        let tx = self
+            .keystore
+            .sponsor_transaction(&gas_payer, tx, self.password.clone())?;
+        Ok(Value::String(Hex::encode(bcs::to_bytes(&tx)?)))
+    }
+
+    fn sign_message(&self, params: Vec<Value>) -> Result<Value, anyhow::Error> {
+        let (address, msg) = parse_params(params)?;
+        let signature =
+            self.keystore
+                .sign_message(&address, &Hex::decode(&msg)?, self.password.clone())?;
+        Ok(Value::String(Hex::encode(signature.as_ref())))
+    }
+}
+
+/// Parse the `[address, hex_bytes]` params
+fn parse_params(params: Vec<Value>) -> Result<(RoochAddress, String), anyhow::Error> {
+    ensure!(
+        params.len() == 2,
+        "Invalid params length, expect: 2, actual: {}",
+        params.len()
+    );
+    let mut params = params.into_iter();
+    let address: RoochAddress = serde_json::from_value(params.next().unwrap())?;
+    let bytes: String = serde_json::from_value(params.next().unwrap())?;
+    Ok((address, bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keystore::memory_keystore::InMemKeystore;
+    use rooch_types::address::RoochSupportedAddress;
+    use rooch_types::test_utils::random_rooch_transaction;
+    use rooch_types::transaction::Authenticator;
+    use std::net::TcpListener;
+
+    fn start_signer(keystore: InMemKeystore) -> RemoteSignerEndpoint {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let signer = RemoteSigner::new(keystore, None);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                signer.serve(stream.unwrap()).unwrap();
+            }
+        });
+        RemoteSignerEndpoint::Tcp(addr.to_string())
+    }
+
+    fn start_http_signer(keystore: InMemKeystore) -> RemoteSignerEndpoint {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let signer = RemoteSigner::new(keystore, None);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                signer.serve_http(stream.unwrap()).unwrap();
+            }
+        });
+        RemoteSignerEndpoint::Http(addr.to_string())
+    }
+
+    #[test]
+    fn test_remote_keystore_sign() {
+        let keystore = InMemKeystore::new_insecure_for_tests(1);
+        let address = keystore.addresses().unwrap()[0];
+        let kp = keystore.get_key_pair(&address, None).unwrap();
+        let message_signature = keystore.sign_message(&address, b"hello", None).unwrap();
+        let remote_keystore = RemoteKeystore::new(start_signer(keystore));
+        assert_eq!(remote_keystore.addresses().unwrap(), vec![address]);
+        assert!(remote_keystore.contains_address(&address).unwrap());
+        assert_eq!(
+            remote_keystore
+                .sign_message(&address, b"hello", None)
+                .unwrap(),
+            message_signature
+        );
+
+        // The signer never signs an opaque hash
+        let msg = [1u8; 32];
+        assert!(remote_keystore.sign_hashed(&address, &msg, None).is_err());
+        let response = RemoteSigner::new(InMemKeystore::new_insecure_for_tests(1), None)
+            .handle_request(RemoteSignerRequest::new(
+                1,
+                "signer_signHashed",
+                vec![
+                    serde_json::to_value(address).unwrap(),
+                    Value::String(Hex::encode(msg)),
+                ],
+            ));
+        assert_eq!(response.error.unwrap().code, ERROR_CODE_METHOD_NOT_FOUND);
+
+        let mut tx_data = random_rooch_transaction().data;
+        tx_data.sender = address;
+        let tx = remote_keystore
+            .sign_transaction(&address, tx_data.clone(), None)
+            .unwrap();
+        assert_eq!(tx.authenticator, Authenticator::bitcoin(&kp, &tx_data));
+    }
+
+    #[test]
+    fn test_remote_keystore_sign_with_unknown_address() {
+        let keystore = InMemKeystore::new_insecure_for_tests(1);
+        let remote_keystore = RemoteKeystore::new(start_signer(keystore));
+
+        let sender = RoochAddress::random();
+        let mut tx_data = random_rooch_transaction().data;
+        tx_data.sender = sender;
+        assert!(remote_keystore
+            .sign_transaction(&sender, tx_data, None)
+            .is_err());
+    }
+
+    #[test]
+    fn test_parse_remote_signer_endpoint() {
+        assert_eq!(
+            RemoteSignerEndpoint::from_str("tcp://127.0.0.1:6780").unwrap(),
+            RemoteSignerEndpoint::Tcp("127.0.0.1:6780".to_owned())
+        );
+        #[cfg(unix)]
+        assert_eq!(
+            RemoteSignerEndpoint::from_str("unix:///tmp/rooch-signer.sock").unwrap(),
+            RemoteSignerEndpoint::Unix(PathBuf::from("/tmp/rooch-signer.sock"))
+        );
+        assert_eq!(
+            RemoteSignerEndpoint::from_str("http://127.0.0.1:6780/").unwrap(),
+            RemoteSignerEndpoint::Http("127.0.0.1:6780".to_owned())
+        );
+        assert!(RemoteSignerEndpoint::from_str("http://127.0.0.1:6780/sign").is_err());
+        assert!(RemoteSignerEndpoint::from_str("https://127.0.0.1:6780").is_err());
+        assert!(RemoteSignerEndpoint::from_str("tcp://").is_err());
+    }
+
+    #[test]
+    fn test_remote_keystore_connect_lazily() {
+        // The unreachable signer is not dialed until the keystore is used
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = RemoteSignerEndpoint::Tcp(listener.local_addr().unwrap().to_string());
+        drop(listener);
+        let remote_keystore = RemoteKeystore::new(endpoint);
+        // The unreachable signer is reported instead of an empty keystore
+        assert!(remote_keystore.addresses().is_err());
+        assert!(remote_keystore
+            .contains_address(&RoochAddress::random())
+            .is_err());
+        assert!(remote_keystore.get_accounts(None).is_err());
+    }
+
+    #[test]
+    fn test_remote_keystore_sign_via_http() {
+        let keystore = InMemKeystore::new_insecure_for_tests(1);
+        let address = keystore.addresses().unwrap()[0];
+        let kp = keystore.get_key_pair(&address, None).unwrap();
+        let endpoint = start_http_signer(keystore);
+        let remote_keystore = RemoteKeystore::new(endpoint.clone());
+        assert_eq!(remote_keystore.addresses().unwrap(), vec![address]);
+
+        let mut tx_data = random_rooch_transaction().data;
+        tx_data.sender = address;
+        let tx = remote_keystore
+            .sign_transaction(&address, tx_data.clone(), None)
+            .unwrap();
+        assert_eq!(tx.authenticator, Authenticator::bitcoin(&kp, &tx_data));
+
+        // The requests from the browsers are rejected
+        let RemoteSignerEndpoint::Http(addr) = endpoint else {
+            unreachable!()
+        };
+        let body =
+            serde_json::to_vec(&RemoteSignerRequest::new(0, METHOD_ACCOUNTS, vec![])).unwrap();
+        for content_type_and_origin in [
+            "Content-Type: application/json\r\nOrigin: http://example.com\r\n",
+            "Content-Type: text/plain\r\n",
+        ] {
+            let mut stream = TcpStream::connect(&addr).unwrap();
+            write!(
+                stream,
+                "POST / HTTP/1.1\r\nHost: {}\r\n{}Content-Length: {}\r\n\r\n",
+                addr,
+                content_type_and_origin,
+                body.len()
+            )
+            .unwrap();
+            stream.write_all(&body).unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            assert!(!response.starts_with("HTTP/1.1 200"), "{}", response);
+        }
+    }
+
+    #[test]
+    fn test_remote_signer_endpoint_ensure_local() {
+        for endpoint in [
+            "tcp://127.0.0.1:6780",
+            "tcp://[::1]:6780",
+            "tcp://localhost:6780",
+        ] {
+            RemoteSignerEndpoint::from_str(endpoint)
+                .unwrap()
+                .ensure_local()
+                .unwrap();
+        }
+        for endpoint in [
+            "tcp://0.0.0.0:6780",
+            "tcp://192.168.1.1:6780",
+            "tcp://[::]:6780",
+        ] {
+            assert!(RemoteSignerEndpoint::from_str(endpoint)
+                .unwrap()
+                .ensure_local()
+                .is_err());
+        }
+        #[cfg(unix)]
+        RemoteSignerEndpoint::from_str("unix:///tmp/rooch-signer.sock")
+            .unwrap()
+            .ensure_local()
+            .unwrap();
+    }
+}
@@ -0,0 +1,266 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::messages::DeriveTick;
+use crate::proxy::PipelineProcessorProxy;
+use anyhow::Result;
+use async_trait::async_trait;
+use coerce::actor::{context::ActorContext, message::Handler, Actor};
+use fastcrypto::traits::ToFromBytes;
+use moveos_types::h256::H256;
+use rooch_da::backend::DABackends;
+use rooch_executor::proxy::ExecutorProxy;
+use rooch_sequencer::proxy::SequencerProxy;
+use rooch_store::da_store::DAMetaStore;
+use rooch_store::RoochStore;
+use rooch_types::address::BitcoinAddress;
+use rooch_types::block::Block;
+use rooch_types::crypto::Signature;
+use rooch_types::da::batch::DABatch;
+use std::sync::Arc;
+use tracing::{debug, info};
+
+// avoid blocking the actor too long in one tick when catching up with DA
+const MAX_BLOCKS_PER_TICK: usize = 16;
+
+/// The source of the blocks proposed by the upstream proposer.
+#[async_trait]
+pub trait ProposedBlockSource: Send + Sync {
+    /// Get the proposed block which the transaction is included in,
+    /// return None if the block has not been proposed yet.
+    async fn get_proposed_block(&self, tx_hash: H256) -> Result<Option<Block>>;
+}
+
+/// DerivationActor derives the ledger from DA in follower mode.
+/// It polls batches from DA backends block by block, verifies them with the sequencer's signature,
+/// then applies and executes the transactions through the pipeline processor.
+/// A block is derived after it is proposed, the derived state root must match the proposed one,
+/// otherwise the derivation halts.
+pub struct DerivationActor {
+    da_backends: DABackends,
+    processor: PipelineProcessorProxy,
+    sequencer: SequencerProxy,
+    executor: ExecutorProxy,
+    rooch_store: RoochStore,
+    sequencer_address: BitcoinAddress,
+    block_source: Arc<dyn ProposedBlockSource>,
+    next_block_number: u128,
+    halted: bool,
+}
+
+impl DerivationActor {
+    pub fn new(
+        da_backends: DABackends,
+        processor: PipelineProcessorProxy,
+        sequencer: SequencerProxy,
+        executor: ExecutorProxy,
+        rooch_store: RoochStore,
+        sequencer_address: BitcoinAddress,
+        block_source: Arc<dyn ProposedBlockSource>,
+    ) -> Result<Self> {
+        if da_backends.backends.is_empty() {
+            return Err(anyhow::anyhow!(
+                "At least one DA backend is required in follower mode"
+            ));
+        }
+        // blocks derived from DA are recorded as done blocks,
+        // so the next block to derive is the one after the last block
+        let next_block_number = match rooch_store.get_last_block_number()? {
+            Some(last_block_number) => {
+                let last_block_state = rooch_store.get_block_state(last_block_number)?;
+                if last_block_state.done {
+                    last_block_number + 1
+                } else {
+                    last_block_number
+                }
+            }
+            None => 0,
+        };
+        info!(
+            "Derivation starts from block: {}, sequencer: {}",
+            next_block_number, sequencer_address
+        );
+
+        Ok(Self {
+            da_backends,
+            processor,
+            sequencer,
+            executor,
+            rooch_store,
+            sequencer_address,
+            block_source,
+            next_block_number,
+            halted: false,
+        })
+    }
+
+    fn verify_batch_signer(&self, batch: &DABatch) -> Result<()> {
+        let signature = Signature::from_bytes(&batch.meta_signature)?;
+        let signer = signature.to_public_key()?.bitcoin_address()?;
+        if signer != self.sequencer_address {
+            return Err(anyhow::anyhow!(
+                "Batch of block {} is not signed by sequencer, exp: {}, act: {}",
+                batch.meta.block_range.block_number,
+                self.sequencer_address,
+                signer
+            ));
+        }
+        Ok(())
+    }
+
+    fn verify_proposed_block(&self, proposed_block: &Block, batch: &DABatch) -> Result<()> {
+        let block_number = batch.meta.block_range.block_number;
+        let block_range = &batch.meta.block_range;
+        if proposed_block.block_number != block_number
+            || proposed_block.batch_size
+                != block_range.tx_order_end - block_range.tx_order_start + 1
+            || proposed_block.batch_hash != batch.get_hash()
+        {
+            return Err(anyhow::anyhow!(
+                "Proposed block {} does not match the batch of block {} in DA",
+                proposed_block.block_number,
+                block_number
+            ));
+        }
+        Ok(())
+    }
+
+    /// Derive the next block from DA, return false if the block is not available yet.
+    async fn derive_block(&mut self) -> Result<bool> {
+        let block_number = self.next_block_number;
+        let batch = match self.da_backends.get_batch(block_number).await? {
+            Some(batch) => batch,
+            None => return Ok(false),
+        };
+        self.verify_batch_signer(&batch)?;
+
+        let mut tx_list = batch.get_tx_list()?;
+        let (last_tx_hash, last_tx_accumulator_root) = match tx_list.last_mut() {
+            Some(last_tx) => (last_tx.tx_hash(), last_tx.sequence_info.tx_accumulator_root),
+            None => {
+                return Err(anyhow::anyhow!(
+                    "Batch of block {} has no transaction",
+                    block_number
+                ))
+            }
+        };
+        let proposed_block = match self.block_source.get_proposed_block(last_tx_hash).await? {
+            Some(proposed_block) => proposed_block,
+            None => {
+                debug!("Block {} has not been proposed yet", block_number);
+                return Ok(false);
+            }
+        };
+        self.verify_proposed_block(&proposed_block, &batch)?;
+        if proposed_block.tx_accumulator_root != last_tx_accumulator_root {
+            return Err(anyhow::anyhow!(
+                "Tx accumulator root mismatch of block {}, proposed: {:?}, derived: {:?}",
+                block_number,
+                proposed_block.tx_accumulator_root,
+                last_tx_accumulator_root
+            ));
+        }
+
+        let last_order = self.sequencer.get_sequencer_order().await?;
+        for mut ledger_tx in tx_list {
+            let tx_order = ledger_tx.sequence_info.tx_order;
+            if tx_order <= last_order {
+                let tx_hash = ledger_tx.tx_hash();
+                let execution_info = self
+                    .executor
+                    .get_transaction_execution_infos_by_hash(vec![tx_hash])
+                    .await?
+                    .pop()
+                    .flatten();
+                if execution_info.is_some() {
+                    debug!("Derived tx has been executed, tx_order: {}", tx_order);
+                    continue;
+                }
+            }
+            self.processor.execute_ledger_tx(ledger_tx).await?;
+        }
+
+        let state_root = self
+            .executor
+            .get_transaction_execution_infos_by_hash(vec![last_tx_hash])
+            .await?
+            .pop()
+            .flatten()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Execution info of the last tx of block {} not found",
+                    block_number
+                )
+            })?
+            .state_root;
+        if state_root != proposed_block.state_root {
+            // The local state diverges from the proposer, stop deriving the following blocks
+            self.halted = true;
+            return Err(anyhow::anyhow!(
+                "State root mismatch of block {}, proposed: {:?}, derived: {:?}, derivation halted",
+                block_number,
+                proposed_block.state_root,
+                state_root
+            ));
+        }
+
+        let block_range = batch.meta.block_range.clone();
+        if self
+            .rooch_store
+            .try_get_block_state(block_number)?
+            .is_none()
+        {
+            let appended_block_number = self
+                .rooch_store
+                .append_submitting_block(block_range.tx_order_start, block_range.tx_order_end)?;
+            if appended_block_number != block_number {
+                return Err(anyhow::anyhow!(
+                    "Derived block number mismatch, exp: {}, act: {}",
+                    block_number,
+                    appended_block_number
+                ));
+            }
+        }
+        self.rooch_store.set_submitting_block_done(
+            block_number,
+            block_range.tx_order_start,
+            block_range.tx_order_end,
+            batch.get_hash(),
+        )?;
+        info!(
+            "Derived block: {} from DA, tx_order: [{}, {}]",
+            block_number, block_range.tx_order_start, block_range.tx_order_end
+        );
+        self.next_block_number += 1;
+        Ok(true)
+    }
+}
+
+impl Actor for DerivationActor {}
+
+#[async_trait]
+impl Handler<DeriveTick> for DerivationActor {
+    async fn handle(&mut self, _message: DeriveTick, _ctx: &mut ActorContext) {
+        if self.halted {
+            tracing::error!(
+                "Derivation is halted at block {} by the state root mismatch",
+                self.next_block_number
+            );
+            return;
+        }
+        for _ in 0..MAX_BLOCKS_PER_TICK {
+            match self.derive_block().await {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(e) => {
+                    tracing::error!(
+                        "Derive block {} from DA failed, error: {:?}",
+                        self.next_block_number,
+                        e
+                    );
+                    break;
+                }
+            }
+        }
+    }
+}
@@ -2,10 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::Result;
-use coerce::actor::message::Message;
+use coerce::actor::{message::Message, scheduler::timer::TimerTick};
 use rooch_types::{
     service_status::ServiceStatus,
-    transaction::{ExecuteTransactionResponse, L1BlockWithBody, L1Transaction, RoochTransaction},
+    transaction::{
+        ExecuteTransactionResponse, L1BlockWithBody, L1Transaction, LedgerTransaction,
+        RoochTransaction,
+    },
 };
 
 #[derive(Clone)]
@@ -35,9 +38,28 @@ impl Message for ExecuteL1TxMessage {
     type Result = Result<ExecuteTransactionResponse>;
 }
 
+/// Execute a LedgerTransaction which has been sequenced by upstream sequencer
+#[derive(Clone)]
+pub struct ExecuteLedgerTxMessage {
+    pub tx: LedgerTransaction,
+}
+
+impl Message for ExecuteLedgerTxMessage {
+    type Result = Result<ExecuteTransactionResponse>;
+}
+
 #[derive(Clone)]
 pub struct GetServiceStatusMessage {}
 
 impl Message for GetServiceStatusMessage {
     type Result = Result<ServiceStatus>;
 }
+
+#[derive(Clone)]
+pub struct DeriveTick {}
+
+impl Message for DeriveTick {
+    type Result = ();
+}
+
+impl TimerTick for DeriveTick {}
@@ -9,6 +9,7 @@ use std::fs::File;
 use std::io::Write;
 use std::path::Path;
 
+pub mod derivation;
 pub mod messages;
 pub mod processor;
 
@@ -2,7 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::messages::{
-    ExecuteL1BlockMessage, ExecuteL1TxMessage, ExecuteL2TxMessage, GetServiceStatusMessage,
+    ExecuteL1BlockMessage, ExecuteL1TxMessage, ExecuteL2TxMessage, ExecuteLedgerTxMessage,
+    GetServiceStatusMessage,
 };
 use crate::metrics::PipelineProcessorMetrics;
 use anyhow::{Error, Result};
@@ -14,12 +15,13 @@ use function_name::named;
 use moveos::moveos::VMPanicError;
 use moveos_types::h256::H256;
 use moveos_types::state::StateChangeSetExt;
-use moveos_types::transaction::VerifiedMoveOSTransaction;
+use moveos_types::transaction::{MoveAction, VerifiedMoveOSTransaction};
 use prometheus::Registry;
 use rooch_da::actor::messages::{AppendTransactionMessage, RevertTransactionMessage};
 use rooch_da::proxy::DAServerProxy;
 use rooch_db::RoochDB;
-use rooch_event::actor::{EventActor, UpdateServiceStatusMessage};
+use rooch_event::actor::{EventActor, TransactionExecutedMessage, UpdateServiceStatusMessage};
+use rooch_event::event::TransactionExecutedEvent;
 use rooch_executor::proxy::ExecutorProxy;
 use rooch_indexer::proxy::IndexerProxy;
 use rooch_sequencer::proxy::SequencerProxy;
@@ -27,7 +29,7 @@ use rooch_types::bitcoin::types::Block as BitcoinBlock;
 use rooch_types::{
     service_status::ServiceStatus,
     transaction::{
-        ExecuteTransactionResponse, L1BlockWithBody, L1Transaction, LedgerTransaction,
+        ExecuteTransactionResponse, L1Block, L1BlockWithBody, L1Transaction, LedgerTransaction,
         LedgerTxData, RoochTransaction,
     },
 };
@@ -120,25 +122,8 @@ impl PipelineProcessorActor {
             match &ledger_tx.data {
                 LedgerTxData::L1Block(block) => {
                     debug!("process_sequenced_tx_on_startup l1_block_tx: {:?}", block);
-                    match &self.bitcoin_client_proxy {
-                        Some(bitcoin_client_proxy) => {
-                            let block_hash_vec = block.block_hash.clone();
-                            let block_hash =
-                                bitcoin::block::BlockHash::from_slice(&block_hash_vec)?;
-                            let btc_block = bitcoin_client_proxy.get_block(block_hash).await?;
-                            let block_body = BitcoinBlock::from(btc_block);
-                            self.execute_l1_block(L1BlockWithBody::new(
-                                block.clone(),
-                                block_body.encode(),
-                            ))
-                            .await?;
-                        }
-                        None => {
-                            return Err(anyhow::anyhow!(
-                                "The bitcoin client proxy should be initialized before processing the sequenced l1_block_tx(block: {:?}) on startup", block
-                            ));
-                        }
-                    }
+                    let l1_block_with_body = self.get_l1_block_with_body(block).await?;
+                    self.execute_l1_block(l1_block_with_body).await?;
                 }
                 LedgerTxData::L1Tx(l1_tx) => {
                     debug!("process_sequenced_tx_on_startup l1_tx: {:?}", l1_tx);
@@ -163,6 +148,22 @@ impl PipelineProcessorActor {
         Ok(())
     }
 
+    async fn get_l1_block_with_body(&self, block: &L1Block) -> Result<L1BlockWithBody> {
+        match &self.bitcoin_client_proxy {
+            Some(bitcoin_client_proxy) => {
+                let block_hash_vec = block.block_hash.clone();
+                let block_hash = bitcoin::block::BlockHash::from_slice(&block_hash_vec)?;
+                let btc_block = bitcoin_client_proxy.get_block(block_hash).await?;
+                let block_body = BitcoinBlock::from(btc_block);
+                Ok(L1BlockWithBody::new(block.clone(), block_body.encode()))
+            }
+            None => Err(anyhow::anyhow!(
+                "The bitcoin client proxy should be initialized before processing the sequenced l1_block_tx(block: {:?})",
+                block
+            )),
+        }
+    }
+
     // sequence tx and public tx to DA
     async fn sequence_and_public_tx(&mut self, tx_data: LedgerTxData) -> Result<LedgerTransaction> {
         let ledger_tx_ret = self.sequencer.sequence_transaction(tx_data).await;
@@ -395,6 +396,85 @@ impl PipelineProcessorActor {
         Ok(result)
     }
 
+    /// Execute a LedgerTransaction which has been sequenced by upstream sequencer (e.g., derived from DA in follower mode).
+    /// The tx is applied to local ledger instead of being sequenced and published to DA again.
+    /// If the tx has been applied but not executed (e.g., crashed before execution), only execute it.
+    #[named]
+    pub async fn execute_ledger_tx(
+        &mut self,
+        mut ledger_tx: LedgerTransaction,
+    ) -> Result<ExecuteTransactionResponse> {
+        let fn_name = function_name!();
+        let _timer = self
+            .metrics
+            .pipeline_processor_execution_tx_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+
+        let tx_order = ledger_tx.sequence_info.tx_order;
+        let tx_hash = ledger_tx.tx_hash();
+        let (moveos_tx, tx_type) = match &ledger_tx.data {
+            LedgerTxData::L1Block(block) => {
+                let l1_block_with_body = self.get_l1_block_with_body(block).await?;
+                (
+                    self.executor.validate_l1_block(l1_block_with_body).await?,
+                    "L1Block",
+                )
+            }
+            LedgerTxData::L1Tx(l1_tx) => {
+                (self.executor.validate_l1_tx(l1_tx.clone()).await?, "L1Tx")
+            }
+            LedgerTxData::L2Tx(l2_tx) => {
+                (self.executor.validate_l2_tx(l2_tx.clone()).await?, "L2Tx")
+            }
+        };
+
+        let sequenced_tx_hash = self
+            .sequencer
+            .get_tx_hashes(vec![tx_order])
+            .await?
+            .pop()
+            .flatten();
+        let ledger_tx = match sequenced_tx_hash {
+            Some(sequenced_tx_hash) if sequenced_tx_hash == tx_hash => ledger_tx,
+            Some(sequenced_tx_hash) => {
+                return Err(anyhow::anyhow!(
+                    "Ledger diverged at tx_order: {}, local tx_hash: {:?}, derived tx_hash: {:?}",
+                    tx_order,
+                    sequenced_tx_hash,
+                    tx_hash
+                ));
+            }
+            None => self.sequencer.apply_ledger_transaction(ledger_tx).await?,
+        };
+
+        let size = moveos_tx.ctx.tx_size;
+        let result = match self.execute_tx(ledger_tx, moveos_tx).await {
+            Ok(v) => v,
+            Err(err) => {
+                // the tx has been executed by upstream, any error means the local state is inconsistent with upstream
+                tracing::error!(
+                    "Execute derived {} failed, set service to Maintenance mode. error: {:?}, tx_order: {}, tx_hash: {:?}",
+                    tx_type,
+                    err,
+                    tx_order,
+                    tx_hash
+                );
+                if let Err(e) = self.rooch_db.revert_tx(tx_hash) {
+                    tracing::error!("Revert derived tx failed, error: {:?}", e);
+                }
+                self.update_service_status(ServiceStatus::Maintenance).await;
+                return Err(err);
+            }
+        };
+
+        self.metrics
+            .pipeline_processor_execution_tx_bytes
+            .with_label_values(&[fn_name])
+            .observe(size as f64);
+        Ok(result)
+    }
+
     #[named]
     pub async fn execute_tx(
         &mut self,
@@ -430,6 +510,19 @@ impl PipelineProcessorActor {
         let indexer = self.indexer.clone();
         let sequence_info = tx.sequence_info.clone();
 
+        // Notify the executed tx to the subscribers, it is a notify call, do not block the current task
+        if let Some(event_actor) = &self.event_actor {
+            let _ = event_actor.notify(TransactionExecutedMessage {
+                event: TransactionExecutedEvent {
+                    ledger_transaction: tx.clone(),
+                    execution_info: execution_info.clone(),
+                    move_action: MoveAction::from(moveos_tx.action.clone()),
+                    tx_context: moveos_tx.ctx.clone(),
+                    events: output.events.clone(),
+                },
+            });
+        }
+
         // If bitcoin block data import, don't write indexer
         if !self.service_status.is_date_import_mode() {
             //The update_indexer is a notification call, do not block the current task
@@ -506,6 +599,17 @@ impl Handler<ExecuteL1TxMessage> for PipelineProcessorActor {
     }
 }
 
+#[async_trait]
+impl Handler<ExecuteLedgerTxMessage> for PipelineProcessorActor {
+    async fn handle(
+        &mut self,
+        msg: ExecuteLedgerTxMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<ExecuteTransactionResponse> {
+        self.execute_ledger_tx(msg.tx).await
+    }
+}
+
 #[async_trait]
 impl Handler<GetServiceStatusMessage> for PipelineProcessorActor {
     async fn handle(
@@ -3,7 +3,8 @@
 
 use crate::actor::{
     messages::{
-        ExecuteL1BlockMessage, ExecuteL1TxMessage, ExecuteL2TxMessage, GetServiceStatusMessage,
+        ExecuteL1BlockMessage, ExecuteL1TxMessage, ExecuteL2TxMessage, ExecuteLedgerTxMessage,
+        GetServiceStatusMessage,
     },
     processor::PipelineProcessorActor,
 };
@@ -13,6 +14,7 @@ use rooch_types::{
     service_status::ServiceStatus,
     transaction::{
         rooch::RoochTransaction, ExecuteTransactionResponse, L1BlockWithBody, L1Transaction,
+        LedgerTransaction,
     },
 };
 
@@ -41,6 +43,13 @@ impl PipelineProcessorProxy {
         self.actor.send(ExecuteL1TxMessage { tx }).await?
     }
 
+    pub async fn execute_ledger_tx(
+        &self,
+        tx: LedgerTransaction,
+    ) -> Result<ExecuteTransactionResponse> {
+        self.actor.send(ExecuteLedgerTxMessage { tx }).await?
+    }
+
     pub async fn get_service_status(&self) -> Result<ServiceStatus> {
         self.actor.send(GetServiceStatusMessage {}).await?
     }
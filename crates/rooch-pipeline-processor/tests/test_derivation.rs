@@ -0,0 +1,400 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use async_trait::async_trait;
+use coerce::actor::{system::ActorSystem, IntoActor};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::gas_schedule::GasScheduleConfig;
+use moveos_types::state::MoveStructType;
+use prometheus::Registry;
+use rooch_config::da_config::DAConfig;
+use rooch_config::RoochOpt;
+use rooch_da::actor::server::DAServerActor;
+use rooch_da::backend::openda::AdapterSubmitStat;
+use rooch_da::backend::{DABackend, DABackends};
+use rooch_da::proxy::DAServerProxy;
+use rooch_db::RoochDB;
+use rooch_executor::actor::executor::ExecutorActor;
+use rooch_executor::actor::reader_executor::ReaderExecutorActor;
+use rooch_executor::proxy::ExecutorProxy;
+use rooch_genesis::RoochGenesis;
+use rooch_indexer::actor::indexer::IndexerActor;
+use rooch_indexer::actor::reader_indexer::IndexerReaderActor;
+use rooch_indexer::proxy::IndexerProxy;
+use rooch_pipeline_processor::actor::derivation::{DerivationActor, ProposedBlockSource};
+use rooch_pipeline_processor::actor::messages::DeriveTick;
+use rooch_pipeline_processor::actor::processor::PipelineProcessorActor;
+use rooch_pipeline_processor::proxy::PipelineProcessorProxy;
+use rooch_sequencer::actor::sequencer::SequencerActor;
+use rooch_sequencer::proxy::SequencerProxy;
+use rooch_store::da_store::DAMetaStore;
+use rooch_types::block::Block;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::da::batch::DABatch;
+use rooch_types::da::segment::SegmentID;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::framework::transfer::TransferModule;
+use rooch_types::rooch_network::{BuiltinChainID, RoochNetwork};
+use rooch_types::service_status::ServiceStatus;
+use rooch_types::transaction::authenticator::BitcoinAuthenticator;
+use rooch_types::transaction::{
+    Authenticator, LedgerTransaction, LedgerTxData, RoochTransaction, RoochTransactionData,
+};
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+use tokio::sync::broadcast;
+
+/// The DA backend serving the batches submitted by the upstream sequencer from memory
+#[derive(Default)]
+struct MemoryDABackend {
+    batches: RwLock<BTreeMap<u128, DABatch>>,
+}
+
+#[async_trait]
+impl DABackend for MemoryDABackend {
+    async fn submit_batch(&self, batch: Arc<DABatch>) -> Result<()> {
+        self.batches
+            .write()
+            .unwrap()
+            .insert(batch.meta.block_range.block_number, batch.as_ref().clone());
+        Ok(())
+    }
+
+    async fn get_batch(&self, block_number: u128) -> Result<Option<DABatch>> {
+        Ok(self.batches.read().unwrap().get(&block_number).cloned())
+    }
+
+    async fn list_segments(&self, _chunk_id: u128) -> Result<Vec<SegmentID>> {
+        Ok(vec![])
+    }
+
+    fn get_identifier(&self) -> String {
+        "memory".to_string()
+    }
+
+    fn get_adapter_stats(&self) -> AdapterSubmitStat {
+        AdapterSubmitStat::new()
+    }
+}
+
+/// The blocks proposed by the upstream proposer, keyed by the hash of the last transaction
+#[derive(Default)]
+struct MemoryBlockSource {
+    blocks: RwLock<BTreeMap<H256, Block>>,
+}
+
+impl MemoryBlockSource {
+    fn propose(&self, last_tx_hash: H256, block: Block) {
+        self.blocks.write().unwrap().insert(last_tx_hash, block);
+    }
+}
+
+#[async_trait]
+impl ProposedBlockSource for MemoryBlockSource {
+    async fn get_proposed_block(&self, tx_hash: H256) -> Result<Option<Block>> {
+        Ok(self.blocks.read().unwrap().get(&tx_hash).cloned())
+    }
+}
+
+/// Execute the ledger txs on the upstream, return the state root after the last tx
+fn execute_upstream(
+    executor: &mut ExecutorActor,
+    ledger_txs: &[LedgerTransaction],
+) -> Result<H256> {
+    let mut state_root = H256::zero();
+    for ledger_tx in ledger_txs {
+        let tx = match &ledger_tx.data {
+            LedgerTxData::L2Tx(tx) => tx.clone(),
+            _ => unreachable!("only l2 txs are sequenced in the test"),
+        };
+        let mut moveos_tx = executor.validate_l2_tx(tx)?;
+        moveos_tx.ctx.add(ledger_tx.sequence_info.clone())?;
+        state_root = executor.execute(moveos_tx)?.transaction_info.state_root;
+    }
+    Ok(state_root)
+}
+
+fn init_rooch_db(network: RoochNetwork, registry: &Registry) -> Result<(RoochOpt, RoochDB)> {
+    let opt = RoochOpt::new_with_temp_store()?;
+    let rooch_db = RoochDB::init(opt.store_config(), registry)?;
+    RoochGenesis::build(network)?.init_genesis(&rooch_db)?;
+    Ok((opt, rooch_db))
+}
+
+fn transfer_tx(
+    network: &RoochNetwork,
+    kp: &RoochKeyPair,
+    sequence_number: u64,
+    to: AccountAddress,
+) -> Result<RoochTransaction> {
+    let sender = network
+        .genesis_config
+        .rooch_dao
+        .multisign_bitcoin_address
+        .to_rooch_address();
+    let tx_data = RoochTransactionData::new(
+        sender,
+        sequence_number,
+        network.chain_id.id,
+        GasScheduleConfig::CLI_DEFAULT_MAX_GAS_AMOUNT,
+        TransferModule::create_transfer_coin_action(RGas::struct_tag(), to, U256::from(100u64)),
+    );
+    // The rooch dao is a 1-of-1 multisign account of the sequencer key in test
+    let signature = BitcoinAuthenticator::sign(kp, &tx_data);
+    let authenticator = Authenticator::bitcoin_multisign(vec![signature])?;
+    Ok(RoochTransaction::new(tx_data, authenticator))
+}
+
+#[tokio::test]
+async fn test_derive_ledger_from_da() -> Result<()> {
+    let sequencer_key = RoochKeyPair::generate_secp256k1();
+    let mut network: RoochNetwork = BuiltinChainID::Local.into();
+    network.mock_genesis_account(&sequencer_key)?;
+
+    // The upstream sequencer sequences the txs and submits them to DA as block 0
+    let upstream_registry = Registry::new();
+    let (_upstream_opt, upstream_db) = init_rooch_db(network.clone(), &upstream_registry)?;
+    let mut upstream_executor = ExecutorActor::new(
+        upstream_db.latest_root()?.unwrap(),
+        upstream_db.moveos_store.clone(),
+        upstream_db.rooch_store.clone(),
+        &upstream_registry,
+        None,
+    )?;
+    let mut upstream = SequencerActor::new(
+        sequencer_key.copy(),
+        upstream_db.rooch_store.clone(),
+        ServiceStatus::Active,
+        &upstream_registry,
+        None,
+    )?;
+    let mut ledger_txs = vec![];
+    for sequence_number in 0..3 {
+        let tx = transfer_tx(
+            &network,
+            &sequencer_key,
+            sequence_number,
+            AccountAddress::random(),
+        )?;
+        ledger_txs.push(upstream.sequence(LedgerTxData::L2Tx(tx))?);
+    }
+    let tx_order_start = ledger_txs.first().unwrap().sequence_info.tx_order;
+    let tx_order_end = ledger_txs.last().unwrap().sequence_info.tx_order;
+    let da_backend = Arc::new(MemoryDABackend::default());
+
+    // The batch is not signed by the sequencer, it should not be derived
+    let other_key = RoochKeyPair::generate_secp256k1();
+    let forged_batch = DABatch::new(0, tx_order_start, tx_order_end, &ledger_txs, &other_key)?;
+    da_backend.submit_batch(Arc::new(forged_batch)).await?;
+
+    // The follower derives the ledger from DA
+    let registry = Registry::new();
+    let (_opt, rooch_db) = init_rooch_db(network.clone(), &registry)?;
+    let root = rooch_db.latest_root()?.unwrap();
+    let actor_system = ActorSystem::global_system();
+
+    let executor = ExecutorActor::new(
+        root.clone(),
+        rooch_db.moveos_store.clone(),
+        rooch_db.rooch_store.clone(),
+        &registry,
+        None,
+    )?
+    .into_actor(Some("Executor"), &actor_system)
+    .await?;
+    let reader_executor = ReaderExecutorActor::new(
+        root.clone(),
+        rooch_db.moveos_store.clone(),
+        rooch_db.rooch_store.clone(),
+        None,
+    )?
+    .into_actor(Some("ReadExecutor"), &actor_system)
+    .await?;
+    let executor_proxy = ExecutorProxy::new(executor.into(), reader_executor.into());
+
+    let sequencer = SequencerActor::new(
+        sequencer_key.copy(),
+        rooch_db.rooch_store.clone(),
+        ServiceStatus::FollowerMode,
+        &registry,
+        None,
+    )?
+    .into_actor(Some("Sequencer"), &actor_system)
+    .await?;
+    let sequencer_proxy = SequencerProxy::new(sequencer.into());
+
+    let (_shutdown_tx, shutdown_rx) = broadcast::channel(1);
+    let da_proxy = DAServerProxy::new(
+        DAServerActor::new(
+            DAConfig::default(),
+            sequencer_key.copy(),
+            rooch_db.rooch_store.clone(),
+            "test".to_string(),
+            shutdown_rx,
+        )
+        .await?
+        .into_actor(Some("DAServer"), &actor_system)
+        .await?
+        .into(),
+    );
+
+    let indexer = IndexerActor::new(
+        root,
+        rooch_db.indexer_store.clone(),
+        rooch_db.moveos_store.clone(),
+        None,
+    )?
+    .into_actor(Some("Indexer"), &actor_system)
+    .await?;
+    let indexer_reader = IndexerReaderActor::new(rooch_db.indexer_reader.clone())?
+        .into_actor(Some("IndexerReader"), &actor_system)
+        .await?;
+    let indexer_proxy = IndexerProxy::new(indexer.into(), indexer_reader.into());
+
+    let processor = PipelineProcessorActor::new(
+        executor_proxy.clone(),
+        sequencer_proxy.clone(),
+        da_proxy,
+        indexer_proxy,
+        ServiceStatus::FollowerMode,
+        &registry,
+        None,
+        rooch_db.clone(),
+        None,
+    )
+    .into_actor(Some("PipelineProcessor"), &actor_system)
+    .await?;
+    let processor_proxy = PipelineProcessorProxy::new(processor.into());
+
+    let da_backends = DABackends {
+        backends: vec![da_backend.clone()],
+        submit_threshold: 1,
+    };
+    let block_source = Arc::new(MemoryBlockSource::default());
+    let derivation = DerivationActor::new(
+        da_backends,
+        processor_proxy,
+        sequencer_proxy.clone(),
+        executor_proxy.clone(),
+        rooch_db.rooch_store.clone(),
+        network.genesis_config.sequencer_account.clone(),
+        block_source.clone(),
+    )?
+    .into_actor(Some("Derivation"), &actor_system)
+    .await?;
+
+    derivation.send(DeriveTick {}).await?;
+    assert!(rooch_db.rooch_store.try_get_block_state(0)?.is_none());
+    assert_eq!(
+        sequencer_proxy.get_sequencer_order().await?,
+        tx_order_start - 1
+    );
+
+    // The batch signed by the sequencer is not derived before the block is proposed
+    let batch = DABatch::new(0, tx_order_start, tx_order_end, &ledger_txs, &sequencer_key)?;
+    let batch_hash = batch.get_hash();
+    da_backend.submit_batch(Arc::new(batch)).await?;
+    derivation.send(DeriveTick {}).await?;
+    assert!(rooch_db.rooch_store.try_get_block_state(0)?.is_none());
+    assert_eq!(
+        sequencer_proxy.get_sequencer_order().await?,
+        tx_order_start - 1
+    );
+
+    // The proposed block is derived and persisted
+    let mut last_tx = ledger_txs.last().unwrap().clone();
+    let state_root = execute_upstream(&mut upstream_executor, &ledger_txs)?;
+    block_source.propose(
+        last_tx.tx_hash(),
+        Block::new(
+            0,
+            ledger_txs.len() as u64,
+            batch_hash,
+            H256::zero(),
+            last_tx.sequence_info.tx_accumulator_root,
+            state_root,
+        ),
+    );
+    derivation.send(DeriveTick {}).await?;
+
+    let block_state = rooch_db.rooch_store.try_get_block_state(0)?.unwrap();
+    assert!(block_state.done);
+    assert_eq!(block_state.batch_hash, batch_hash);
+    assert_eq!(block_state.block_range.tx_order_start, tx_order_start);
+    assert_eq!(block_state.block_range.tx_order_end, tx_order_end);
+    assert_eq!(sequencer_proxy.get_sequencer_order().await?, tx_order_end);
+
+    // The derived txs can be queried with the same results as upstream
+    let tx_hashes = ledger_txs
+        .iter()
+        .map(|ledger_tx| ledger_tx.tx_hash())
+        .collect::<Vec<_>>();
+    let derived_txs = sequencer_proxy
+        .get_transactions_by_hash(tx_hashes.clone())
+        .await?;
+    for (derived_tx, ledger_tx) in derived_txs.into_iter().zip(ledger_txs.iter()) {
+        assert_eq!(derived_tx.unwrap().sequence_info, ledger_tx.sequence_info);
+    }
+    let execution_infos = executor_proxy
+        .get_transaction_execution_infos_by_hash(tx_hashes)
+        .await?;
+    for execution_info in execution_infos {
+        assert_eq!(execution_info.unwrap().status, KeptVMStatus::Executed);
+    }
+
+    // No more blocks in DA, the derivation waits for the next block
+    derivation.send(DeriveTick {}).await?;
+    assert!(rooch_db.rooch_store.try_get_block_state(1)?.is_none());
+
+    // The derived state root of block 1 diverges from the proposed one, the derivation halts
+    let mut next_ledger_txs = vec![];
+    for sequence_number in 3..5 {
+        let tx = transfer_tx(
+            &network,
+            &sequencer_key,
+            sequence_number,
+            AccountAddress::random(),
+        )?;
+        next_ledger_txs.push(upstream.sequence(LedgerTxData::L2Tx(tx))?);
+    }
+    let next_tx_order_start = next_ledger_txs.first().unwrap().sequence_info.tx_order;
+    let next_tx_order_end = next_ledger_txs.last().unwrap().sequence_info.tx_order;
+    let next_batch = DABatch::new(
+        1,
+        next_tx_order_start,
+        next_tx_order_end,
+        &next_ledger_txs,
+        &sequencer_key,
+    )?;
+    let next_batch_hash = next_batch.get_hash();
+    da_backend.submit_batch(Arc::new(next_batch)).await?;
+    let mut next_last_tx = next_ledger_txs.last().unwrap().clone();
+    let next_state_root = execute_upstream(&mut upstream_executor, &next_ledger_txs)?;
+    let next_block = Block::new(
+        1,
+        next_ledger_txs.len() as u64,
+        next_batch_hash,
+        last_tx.sequence_info.tx_accumulator_root,
+        next_last_tx.sequence_info.tx_accumulator_root,
+        next_state_root,
+    );
+    block_source.propose(
+        next_last_tx.tx_hash(),
+        Block {
+            state_root: H256::random(),
+            ..next_block.clone()
+        },
+    );
+    derivation.send(DeriveTick {}).await?;
+    assert!(rooch_db.rooch_store.try_get_block_state(1)?.is_none());
+
+    // The halted derivation does not continue even if the proposed block is corrected
+    block_source.propose(next_last_tx.tx_hash(), next_block);
+    derivation.send(DeriveTick {}).await?;
+    assert!(rooch_db.rooch_store.try_get_block_state(1)?.is_none());
+    Ok(())
+}
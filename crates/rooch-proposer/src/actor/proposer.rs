@@ -71,7 +71,7 @@ impl Handler<ProposeBlock> for ProposerActor {
                     Some(block) => {
                         // TODO submit to the on-chain SCC contract use the proposer key
                         let _proposer_key = &self.proposer_key;
-                        let ret = self.scc.set_last_proposed(block.block_number);
+                        let ret = self.scc.save_proposed_block(block.clone());
                         match ret {
                             Ok(_) => {
                                 tracing::info!(
@@ -80,7 +80,10 @@ impl Handler<ProposeBlock> for ProposerActor {
                                 );
                             }
                             Err(e) => {
-                                tracing::error!("[ProposeBlock] set last proposed error: {:?}", e);
+                                tracing::error!(
+                                    "[ProposeBlock] save proposed block error: {:?}",
+                                    e
+                                );
                             }
                         }
 
@@ -170,8 +170,9 @@ impl StateCommitmentChain {
         Ok(block)
     }
 
-    pub fn set_last_proposed(&self, block_number: u128) -> anyhow::Result<()> {
-        self.rooch_store.set_last_proposed(block_number)
+    /// Save the proposed block and set it as the last proposed
+    pub fn save_proposed_block(&self, block: Block) -> anyhow::Result<()> {
+        self.rooch_store.save_proposed_block(block)
     }
 
     /// Trigger the proposer to propose a new block
@@ -1,19 +1,22 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::actor::bitcoin_reorg::{find_fork_point, BitcoinBlockSource, BitcoinReorg};
 use crate::actor::messages::{GetReadyL1BlockMessage, GetReadyL1TxsMessage, SyncTick};
 use anyhow::Result;
 use async_trait::async_trait;
 use bitcoin::{Block, BlockHash};
 use bitcoin_client::proxy::BitcoinClientProxy;
 use bitcoincore_rpc::bitcoincore_rpc_json::GetBlockHeaderResult;
-use coerce::actor::{context::ActorContext, message::Handler, Actor};
+use coerce::actor::{context::ActorContext, message::Handler, Actor, LocalActorRef};
 use indexmap::IndexMap;
 use moveos_types::module_binding::MoveFunctionCaller;
 use rooch_config::BitcoinRelayerConfig;
+use rooch_event::actor::{EventActor, UpdateServiceStatusMessage};
 use rooch_executor::proxy::ExecutorProxy;
 use rooch_types::bitcoin::types::BlockHeightHash;
 use rooch_types::into_address::{FromAddress, IntoAddress};
+use rooch_types::service_status::ServiceStatus;
 use rooch_types::{
     bitcoin::{pending_block::PendingBlockModule, BitcoinModule},
     multichain_id::RoochMultiChainID,
@@ -21,13 +24,14 @@ use rooch_types::{
 };
 use std::io::Write;
 use std::path::PathBuf;
-use tracing::{debug, error, info};
+use std::sync::Arc;
+use tracing::{debug, error, info, warn};
 
 pub struct BitcoinRelayer {
     genesis_block: BlockHeightHash,
     // only for data import
     end_block_height: Option<u64>,
-    rpc_client: BitcoinClientProxy,
+    rpc_client: Arc<dyn BitcoinBlockSource>,
     move_caller: ExecutorProxy,
     buffer: Vec<BlockResult>,
     sync_block_interval: u64,
@@ -35,6 +39,9 @@ pub struct BitcoinRelayer {
     sync_to_latest: bool,
     batch_size: usize,
     reorg_aware_store: BitcoinReorgAwareStore,
+    event_actor: Option<LocalActorRef<EventActor>>,
+    // the reorg deeper than the pending window, the relayer stops until it is recovered
+    deep_reorg: Option<BitcoinReorg>,
 }
 
 #[derive(Debug, Clone)]
@@ -48,6 +55,17 @@ impl BitcoinRelayer {
         config: BitcoinRelayerConfig,
         rpc_client: BitcoinClientProxy,
         executor: ExecutorProxy,
+        event_actor: Option<LocalActorRef<EventActor>>,
+    ) -> Result<Self> {
+        Self::new_with_source(config, Arc::new(rpc_client), executor, event_actor)
+    }
+
+    /// Create the relayer which follows the blocks from the block source.
+    pub fn new_with_source(
+        config: BitcoinRelayerConfig,
+        rpc_client: Arc<dyn BitcoinBlockSource>,
+        executor: ExecutorProxy,
+        event_actor: Option<LocalActorRef<EventActor>>,
     ) -> Result<Self> {
         let bitcoin_module = executor.as_module_binding::<BitcoinModule>();
         let genesis_block = bitcoin_module.get_genesis_block()?;
@@ -68,10 +86,19 @@ impl BitcoinRelayer {
                 config.btc_reorg_aware_height,
                 rpc_client,
             ),
+            event_actor,
+            deep_reorg: None,
         })
     }
 
     async fn sync_block(&mut self) -> Result<()> {
+        if let Some(deep_reorg) = &self.deep_reorg {
+            debug!(
+                "BitcoinRelayer is stopped by the deep reorg {:?}, skip sync",
+                deep_reorg
+            );
+            return Ok(());
+        }
         if self.buffer.len() > self.batch_size {
             return Ok(());
         }
@@ -106,26 +133,36 @@ impl BitcoinRelayer {
                     return Ok(());
                 }
                 //We need to find the next block of the best block in rooch
-                let mut best_block_header_info = self
-                    .rpc_client
-                    .get_block_header_info(BlockHash::from_address(best_block_in_rooch.block_hash))
-                    .await?;
-
                 // if the best block in rooch is not in the main chain, we need to find the common ancestor
-                while best_block_header_info.confirmations < 0 {
-                    let previous_block_hash =
-                        best_block_header_info.previous_block_hash.ok_or_else(|| {
-                            anyhow::anyhow!(
-                                "The previous block of {:?} should exist",
-                                best_block_header_info.hash
-                            )
-                        })?;
-                    best_block_header_info = self
-                        .rpc_client
-                        .get_block_header_info(previous_block_hash)
-                        .await?;
+                let (fork_block_header_info, reorg) = find_fork_point(
+                    self.rpc_client.as_ref(),
+                    BlockHash::from_address(best_block_in_rooch.block_hash),
+                )
+                .await?;
+                if let Some(reorg) = reorg {
+                    if !self.buffer.is_empty() {
+                        // the buffered blocks are orphaned, sync again from the best block in rooch
+                        warn!(
+                            "BitcoinRelayer drop {} buffered blocks orphaned by reorg {:?}",
+                            self.buffer.len(),
+                            reorg
+                        );
+                        self.buffer.clear();
+                        self.sync_to_latest = false;
+                        return Ok(());
+                    }
+                    let reorg_block_count = pending_block_module.get_reorg_block_count()?;
+                    if reorg.is_deep(reorg_block_count) {
+                        self.raise_deep_reorg_alert(reorg, reorg_block_count).await;
+                        return Ok(());
+                    }
+                    warn!(
+                        "BitcoinRelayer detected reorg {:?}, depth: {}",
+                        reorg,
+                        reorg.depth()
+                    );
                 }
-                best_block_header_info.next_block_hash
+                fork_block_header_info.next_block_hash
             }
             None => {
                 // if the latest block in rooch is None, we start from the genesis block
@@ -177,6 +214,36 @@ impl BitcoinRelayer {
         Ok(())
     }
 
+    /// The transactions of the orphaned blocks have been executed, it needs a manual rollback to the fork point,
+    /// so stop relaying and switch the service to maintenance mode.
+    async fn raise_deep_reorg_alert(&mut self, reorg: BitcoinReorg, reorg_block_count: u64) {
+        error!(
+            "BitcoinRelayer detected reorg deeper than the pending window, depth: {}, reorg_block_count: {}, orphaned block: {} {}, fork point: {} {}. \
+            Please stop the service and rollback to the fork point via `rooch db reorg-rollback`.",
+            reorg.depth(),
+            reorg_block_count,
+            reorg.orphaned_block_height,
+            reorg.orphaned_block_hash,
+            reorg.fork_block_height,
+            reorg.fork_block_hash
+        );
+        self.buffer.clear();
+        self.deep_reorg = Some(reorg);
+        if let Some(event_actor) = &self.event_actor {
+            let message = UpdateServiceStatusMessage {
+                status: ServiceStatus::Maintenance,
+            };
+            let result = event_actor
+                .send(message)
+                .await
+                .map_err(Into::into)
+                .and_then(|result| result);
+            if let Err(e) = result {
+                error!("BitcoinRelayer update service status error: {:?}", e);
+            }
+        }
+    }
+
     fn pop_buffer(&mut self) -> Result<Option<L1BlockWithBody>> {
         if self.buffer.is_empty() {
             Ok(None)
@@ -269,14 +336,14 @@ pub struct BitcoinReorgAwareStore {
     block_store_dir: PathBuf,
     recent_blocks_map: IndexMap<u64, BlockHash>,
     aware_height: usize,
-    rpc_client: BitcoinClientProxy,
+    rpc_client: Arc<dyn BitcoinBlockSource>,
 }
 
 impl BitcoinReorgAwareStore {
     pub fn new(
         block_store_dir: PathBuf,
         aware_height: usize,
-        rpc_client: BitcoinClientProxy,
+        rpc_client: Arc<dyn BitcoinBlockSource>,
     ) -> Self {
         Self {
             block_store_dir,
@@ -0,0 +1,95 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use async_trait::async_trait;
+use bitcoin::{Block, BlockHash};
+use bitcoin_client::proxy::BitcoinClientProxy;
+use bitcoincore_rpc::bitcoincore_rpc_json::GetBlockHeaderResult;
+
+/// The Bitcoin block header source used to detect reorgs,
+/// it is the Bitcoin RPC client in the relayer, and can be replaced by a mock chain in tests.
+#[async_trait]
+pub trait BitcoinHeaderReader: Send + Sync {
+    async fn get_block_header_info(&self, hash: BlockHash) -> Result<GetBlockHeaderResult>;
+}
+
+#[async_trait]
+impl BitcoinHeaderReader for BitcoinClientProxy {
+    async fn get_block_header_info(&self, hash: BlockHash) -> Result<GetBlockHeaderResult> {
+        BitcoinClientProxy::get_block_header_info(self, hash).await
+    }
+}
+
+/// The Bitcoin block source followed by the relayer,
+/// it is the Bitcoin RPC client in the relayer, and can be replaced by a mock chain in tests.
+#[async_trait]
+pub trait BitcoinBlockSource: BitcoinHeaderReader {
+    async fn get_best_block_hash(&self) -> Result<BlockHash>;
+    async fn get_block(&self, hash: BlockHash) -> Result<Block>;
+}
+
+#[async_trait]
+impl BitcoinBlockSource for BitcoinClientProxy {
+    async fn get_best_block_hash(&self) -> Result<BlockHash> {
+        BitcoinClientProxy::get_best_block_hash(self).await
+    }
+
+    async fn get_block(&self, hash: BlockHash) -> Result<Block> {
+        BitcoinClientProxy::get_block(self, hash).await
+    }
+}
+
+/// A reorg of the Bitcoin blocks relayed to Rooch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitcoinReorg {
+    /// The relayed block which is not in the main chain anymore
+    pub orphaned_block_height: u64,
+    pub orphaned_block_hash: BlockHash,
+    /// The common ancestor of the relayed blocks and the main chain
+    pub fork_block_height: u64,
+    pub fork_block_hash: BlockHash,
+}
+
+impl BitcoinReorg {
+    pub fn depth(&self) -> u64 {
+        self.orphaned_block_height - self.fork_block_height
+    }
+
+    /// The blocks deeper than `reorg_block_count` have left the pending window and their transactions have been processed,
+    /// so the reorg can not be handled by the pending block store.
+    pub fn is_deep(&self, reorg_block_count: u64) -> bool {
+        self.depth() > reorg_block_count
+    }
+}
+
+/// Walk back from the block to the common ancestor with the main chain.
+/// Return the header of the common ancestor, and the reorg if the block is not in the main chain.
+pub async fn find_fork_point<R: BitcoinHeaderReader + ?Sized>(
+    reader: &R,
+    block_hash: BlockHash,
+) -> Result<(GetBlockHeaderResult, Option<BitcoinReorg>)> {
+    let block_header_info = reader.get_block_header_info(block_hash).await?;
+    if block_header_info.confirmations >= 0 {
+        return Ok((block_header_info, None));
+    }
+    let orphaned_block_height = block_header_info.height as u64;
+    let mut fork_block_header_info = block_header_info;
+    // the block is not in the main chain if the confirmations is negative
+    while fork_block_header_info.confirmations < 0 {
+        let previous_block_hash = fork_block_header_info.previous_block_hash.ok_or_else(|| {
+            anyhow::anyhow!(
+                "The previous block of {:?} should exist",
+                fork_block_header_info.hash
+            )
+        })?;
+        fork_block_header_info = reader.get_block_header_info(previous_block_hash).await?;
+    }
+    let reorg = BitcoinReorg {
+        orphaned_block_height,
+        orphaned_block_hash: block_hash,
+        fork_block_height: fork_block_header_info.height as u64,
+        fork_block_hash: fork_block_header_info.hash,
+    };
+    Ok((fork_block_header_info, Some(reorg)))
+}
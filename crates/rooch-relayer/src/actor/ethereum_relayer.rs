@@ -6,66 +6,134 @@ use anyhow::Result;
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
 use ethers::prelude::*;
+use indexmap::IndexMap;
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::moveos_std::onchain_features::FeaturesModule;
 use rooch_config::EthereumRelayerConfig;
+use rooch_executor::proxy::ExecutorProxy;
 use rooch_types::{
-    framework::ethereum::BlockHeader,
+    framework::ethereum::{
+        BlockHeader, BlockWithReceipts, EthereumModule,
+        TransactionReceipt as EthereumTransactionReceipt,
+    },
     multichain_id::RoochMultiChainID,
     transaction::{L1Block, L1BlockWithBody, L1Transaction},
 };
-use std::collections::HashSet;
-use tracing::info;
+use std::collections::VecDeque;
+use tracing::{debug, error, info, warn};
+
+// avoid fetching too many blocks in one tick, the buffered blocks wait for relaying
+const MAX_BUFFERED_BLOCKS: usize = 16;
 
 pub struct EthereumRelayer {
-    rpc_client: Provider<Http>,
-    processed_blocks: HashSet<H256>,
+    move_caller: ExecutorProxy,
+    syncer: EthereumBlockSyncer<Http>,
 }
 
 impl EthereumRelayer {
-    pub fn new(config: EthereumRelayerConfig) -> Result<Self> {
-        let rpc_client = Provider::<Http>::try_from(config.eth_rpc_url)?;
-        Ok(Self {
+    pub fn new(config: EthereumRelayerConfig, executor: ExecutorProxy) -> Result<Self> {
+        let rpc_client = Provider::<Http>::try_from(config.eth_rpc_url.as_str())?;
+        let syncer = EthereumBlockSyncer::new(
             rpc_client,
-            //TODO load processed block from Move state
-            processed_blocks: HashSet::new(),
-        })
-    }
-
-    async fn relay_ethereum(&mut self) -> Result<Option<L1BlockWithBody>> {
-        let block = self
-            .rpc_client
-            .get_block(BlockId::Number(BlockNumber::Latest))
-            .await?;
-        match block {
-            Some(block) => {
-                let block_hash = block
-                    .hash
-                    .ok_or_else(|| anyhow::format_err!("The block is a pending block"))?;
-                if self.processed_blocks.contains(&block_hash) {
-                    info!("The block {} has already been processed", block_hash);
-                    return Ok(None);
+            config.eth_confirmations,
+            config.eth_start_block_number,
+            config.eth_reorg_aware_height,
+        );
+        let mut relayer = Self {
+            move_caller: executor,
+            syncer,
+        };
+        relayer.load_relayed_blocks()?;
+        Ok(relayer)
+    }
+
+    /// Load the recent relayed blocks from Move state, so the relayer follows the blocks from the persisted height.
+    fn load_relayed_blocks(&mut self) -> Result<()> {
+        let ethereum_module = self.move_caller.as_module_binding::<EthereumModule>();
+        let mut relayed_blocks = vec![];
+        if let Some(latest_block_number) = ethereum_module.get_latest_block_number()? {
+            let mut block_number = latest_block_number;
+            while relayed_blocks.len() < self.syncer.reorg_aware_height() {
+                // the relayed blocks are continuous, stop at the first relayed block
+                if !ethereum_module.exists_block(block_number)? {
+                    break;
                 }
-                let block_header = BlockHeader::try_from(&block)?;
-                info!(
-                    "EthereumRelayer process block, hash: {:?}, number: {}, timestamp: {}",
-                    block_hash, block_header.number, block_header.timestamp
-                );
-                let l1_block = L1BlockWithBody {
-                    block: L1Block {
-                        chain_id: RoochMultiChainID::Ether.multichain_id(),
-                        block_height: block_header.number,
-                        block_hash: block_hash.as_bytes().to_vec(),
-                    },
-                    block_body: block_header.encode(),
-                };
-                self.processed_blocks.insert(block_hash);
-                Ok(Some(l1_block))
+                let block_header = ethereum_module.get_block(block_number)?;
+                relayed_blocks.push((block_number, H256::from_slice(&block_header.hash)));
+                if block_number == 0 {
+                    break;
+                }
+                block_number -= 1;
+            }
+        }
+        relayed_blocks.reverse();
+        info!(
+            "EthereumRelayer load relayed blocks, latest: {:?}",
+            relayed_blocks.last()
+        );
+        self.syncer.reset_recent_blocks(relayed_blocks);
+        Ok(())
+    }
+
+    fn latest_relayed_block(&self) -> Result<Option<(u64, H256)>> {
+        let ethereum_module = self.move_caller.as_module_binding::<EthereumModule>();
+        match ethereum_module.get_latest_block_number()? {
+            Some(block_number) => {
+                let block_header = ethereum_module.get_block(block_number)?;
+                Ok(Some((block_number, H256::from_slice(&block_header.hash))))
             }
-            None => {
-                info!("The RPC returned no block");
-                Ok(None)
+            None => Ok(None),
+        }
+    }
+
+    async fn sync_block(&mut self) -> Result<()> {
+        if self.syncer.is_buffer_empty() {
+            // All the buffered blocks have been relayed, reload from Move state
+            // if the relayed blocks diverge, such as the block execution failed.
+            let latest_relayed_block = self.latest_relayed_block()?;
+            if latest_relayed_block != self.syncer.latest_block() {
+                warn!(
+                    "EthereumRelayer latest block {:?} diverges from the relayed block {:?}, reload",
+                    self.syncer.latest_block(),
+                    latest_relayed_block
+                );
+                self.load_relayed_blocks()?;
             }
         }
-        //TODO clean up processed block
+        self.syncer.sync().await
+    }
+
+    /// Pop the next buffered block, the receipts are only relayed after the ethereum receipts feature is enabled,
+    /// otherwise only the block header is relayed.
+    fn pop_buffer(&mut self) -> Result<Option<L1BlockWithBody>> {
+        let receipts_enabled = self
+            .move_caller
+            .as_module_binding::<FeaturesModule>()
+            .ethereum_receipts_enabled()?;
+        let block = match self.syncer.pop_block() {
+            Some(block) => block,
+            None => return Ok(None),
+        };
+        info!(
+            "EthereumRelayer process block, hash: {}, number: {}, receipts: {}, timestamp: {}",
+            hex::encode(&block.header.hash),
+            block.header.number,
+            block.receipts.len(),
+            block.header.timestamp
+        );
+        let block_body = if receipts_enabled {
+            block.encode()
+        } else {
+            block.header.encode()
+        };
+        Ok(Some(L1BlockWithBody {
+            block: L1Block {
+                chain_id: RoochMultiChainID::Ether.multichain_id(),
+                block_height: block.header.number,
+                block_hash: block.header.hash.clone(),
+            },
+            block_body,
+        }))
     }
 }
 
@@ -77,7 +145,9 @@ impl Actor for EthereumRelayer {
 #[async_trait]
 impl Handler<SyncTick> for EthereumRelayer {
     async fn handle(&mut self, _message: SyncTick, _ctx: &mut ActorContext) {
-        //TODO support buffer block
+        if let Err(e) = self.sync_block().await {
+            error!("EthereumRelayer sync block error: {:?}", e);
+        }
     }
 }
 
@@ -88,7 +158,7 @@ impl Handler<GetReadyL1BlockMessage> for EthereumRelayer {
         _message: GetReadyL1BlockMessage,
         _ctx: &mut ActorContext,
     ) -> Result<Option<L1BlockWithBody>> {
-        self.relay_ethereum().await
+        self.pop_buffer()
     }
 }
 
@@ -99,7 +169,184 @@ impl Handler<GetReadyL1TxsMessage> for EthereumRelayer {
         _message: GetReadyL1TxsMessage,
         _ctx: &mut ActorContext,
     ) -> Result<Vec<L1Transaction>> {
-        //TODO
+        // The receipts are relayed with the block
         Ok(vec![])
     }
 }
+
+/// EthereumBlockSyncer follows the Ethereum blocks sequentially and buffers the confirmed blocks with their receipts.
+/// It keeps the recent blocks to detect the reorg by the parent hash,
+/// the buffered blocks after the common ancestor are dropped when the chain is reorganized.
+pub struct EthereumBlockSyncer<P: JsonRpcClient> {
+    rpc_client: Provider<P>,
+    confirmations: u64,
+    start_block_number: Option<u64>,
+    buffer: VecDeque<BlockWithReceipts>,
+    recent_blocks: IndexMap<u64, H256>,
+    reorg_aware_height: usize,
+}
+
+impl<P: JsonRpcClient> EthereumBlockSyncer<P> {
+    pub fn new(
+        rpc_client: Provider<P>,
+        confirmations: u64,
+        start_block_number: Option<u64>,
+        reorg_aware_height: usize,
+    ) -> Self {
+        Self {
+            rpc_client,
+            confirmations,
+            start_block_number,
+            buffer: VecDeque::new(),
+            recent_blocks: IndexMap::with_capacity(reorg_aware_height),
+            reorg_aware_height,
+        }
+    }
+
+    pub fn reorg_aware_height(&self) -> usize {
+        self.reorg_aware_height
+    }
+
+    /// Reset the recent blocks with the relayed blocks in ascending order, the buffered blocks are dropped.
+    pub fn reset_recent_blocks(&mut self, blocks: Vec<(u64, H256)>) {
+        self.buffer.clear();
+        self.recent_blocks.clear();
+        for (block_number, block_hash) in blocks {
+            self.insert_recent_block(block_number, block_hash);
+        }
+    }
+
+    /// The latest relayed or buffered block.
+    pub fn latest_block(&self) -> Option<(u64, H256)> {
+        self.recent_blocks
+            .last()
+            .map(|(block_number, block_hash)| (*block_number, *block_hash))
+    }
+
+    pub fn is_buffer_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    pub fn pop_block(&mut self) -> Option<BlockWithReceipts> {
+        self.buffer.pop_front()
+    }
+
+    fn insert_recent_block(&mut self, block_number: u64, block_hash: H256) {
+        self.recent_blocks.insert(block_number, block_hash);
+        if self.recent_blocks.len() > self.reorg_aware_height {
+            self.recent_blocks.shift_remove_index(0);
+        }
+    }
+
+    /// Fetch the confirmed blocks after the latest block into the buffer.
+    pub async fn sync(&mut self) -> Result<()> {
+        if self.buffer.len() >= MAX_BUFFERED_BLOCKS {
+            return Ok(());
+        }
+        let latest_block_number = self.rpc_client.get_block_number().await?.as_u64();
+        let Some(confirmed_block_number) = latest_block_number.checked_sub(self.confirmations)
+        else {
+            return Ok(());
+        };
+
+        let mut next_block_number = match self.latest_block() {
+            Some((block_number, _)) => block_number + 1,
+            None => self.start_block_number.unwrap_or(confirmed_block_number),
+        };
+        while next_block_number <= confirmed_block_number && self.buffer.len() < MAX_BUFFERED_BLOCKS
+        {
+            let block = self.get_block(next_block_number).await?;
+            let parent_hash = next_block_number
+                .checked_sub(1)
+                .and_then(|parent_number| self.recent_blocks.get(&parent_number));
+            if let Some(parent_hash) = parent_hash {
+                if *parent_hash != block.parent_hash {
+                    next_block_number = self.rollback(next_block_number - 1).await? + 1;
+                    continue;
+                }
+            }
+
+            let block_hash = block
+                .hash
+                .ok_or_else(|| anyhow::format_err!("The block is a pending block"))?;
+            let receipts = self.get_receipts(&block).await?;
+            let block_header = BlockHeader::try_from(&block)?;
+            info!(
+                "EthereumRelayer buffer block, number: {}, hash: {:?}, receipts: {}",
+                next_block_number,
+                block_hash,
+                receipts.len()
+            );
+            self.insert_recent_block(next_block_number, block_hash);
+            self.buffer
+                .push_back(BlockWithReceipts::new(block_header, receipts));
+            next_block_number += 1;
+        }
+        Ok(())
+    }
+
+    /// Find the common ancestor of the recent blocks and the canonical chain from `block_number` backwards,
+    /// drop the recent blocks and buffered blocks after it, return the common ancestor block number.
+    async fn rollback(&mut self, block_number: u64) -> Result<u64> {
+        let mut common_ancestor = None;
+        for (recent_block_number, recent_block_hash) in self.recent_blocks.iter().rev() {
+            if *recent_block_number > block_number {
+                continue;
+            }
+            let canonical_block = self.get_block(*recent_block_number).await?;
+            if canonical_block.hash == Some(*recent_block_hash) {
+                common_ancestor = Some(*recent_block_number);
+                break;
+            }
+            debug!(
+                "EthereumRelayer block {} {:?} is not in the canonical chain",
+                recent_block_number, recent_block_hash
+            );
+        }
+        let common_ancestor = common_ancestor.ok_or_else(|| {
+            anyhow::anyhow!(
+                "Can not find the common ancestor of the reorg at block {} in the recent {} blocks",
+                block_number,
+                self.reorg_aware_height
+            )
+        })?;
+        warn!(
+            "EthereumRelayer detected reorg at block {}, rollback to the common ancestor {}",
+            block_number, common_ancestor
+        );
+        self.recent_blocks
+            .retain(|recent_block_number, _| *recent_block_number <= common_ancestor);
+        self.buffer
+            .retain(|block| block.header.number <= common_ancestor);
+        Ok(common_ancestor)
+    }
+
+    async fn get_block(&self, block_number: u64) -> Result<Block<H256>> {
+        self.rpc_client
+            .get_block(BlockId::Number(BlockNumber::Number(block_number.into())))
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("The block {} is not found", block_number))
+    }
+
+    async fn get_receipts(&self, block: &Block<H256>) -> Result<Vec<EthereumTransactionReceipt>> {
+        let block_number = block
+            .number
+            .ok_or_else(|| anyhow::format_err!("The block is a pending block"))?;
+        let receipts = self.rpc_client.get_block_receipts(block_number).await?;
+        // the block may be reorganized between the requests
+        if receipts.len() != block.transactions.len()
+            || receipts
+                .iter()
+                .any(|receipt| receipt.block_hash != block.hash)
+        {
+            return Err(anyhow::anyhow!(
+                "The receipts do not match the block {}, the chain may be reorganized",
+                block_number
+            ));
+        }
+        receipts
+            .iter()
+            .map(EthereumTransactionReceipt::try_from)
+            .collect()
+    }
+}
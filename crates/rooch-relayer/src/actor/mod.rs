@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod bitcoin_relayer;
+pub mod bitcoin_reorg;
 pub mod ethereum_relayer;
 pub mod messages;
 pub mod relayer;
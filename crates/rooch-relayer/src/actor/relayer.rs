@@ -71,7 +71,7 @@ impl RelayerActor {
 
     async fn init_relayer(&mut self, ctx: &mut ActorContext) -> Result<()> {
         if let Some(ethereum_config) = &self.ethereum_config {
-            let eth_relayer = EthereumRelayer::new(ethereum_config.clone())?;
+            let eth_relayer = EthereumRelayer::new(ethereum_config.clone(), self.executor.clone())?;
             let eth_relayer_actor_ref = ctx.spawn("eth_relayer".into(), eth_relayer).await?;
             self.relayers
                 .push(RelayerProxy::ethereum(eth_relayer_actor_ref.into()));
@@ -92,6 +92,7 @@ impl RelayerActor {
                 bitcoin_config.clone(),
                 bitcoin_client_proxy,
                 self.executor.clone(),
+                self.event_actor.clone(),
             )?;
             let bitcoin_relayer_actor_ref =
                 ctx.spawn("bitcoin_relayer".into(), bitcoin_relayer).await?;
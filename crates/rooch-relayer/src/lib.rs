@@ -2,3 +2,5 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod actor;
+#[cfg(test)]
+mod tests;
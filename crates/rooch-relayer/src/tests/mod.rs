@@ -0,0 +1,5 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+mod test_bitcoin_reorg;
+mod test_ethereum_relayer;
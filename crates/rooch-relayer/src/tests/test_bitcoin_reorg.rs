@@ -0,0 +1,410 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::actor::bitcoin_relayer::BitcoinRelayer;
+use crate::actor::bitcoin_reorg::{find_fork_point, BitcoinBlockSource, BitcoinHeaderReader};
+use crate::actor::messages::{GetReadyL1BlockMessage, SyncTick};
+use anyhow::Result;
+use async_trait::async_trait;
+use bitcoin::absolute::LockTime;
+use bitcoin::block::{Header, Version as BlockVersion};
+use bitcoin::constants::genesis_block;
+use bitcoin::hashes::Hash;
+use bitcoin::transaction::Version;
+use bitcoin::{
+    Amount, Block, BlockHash, CompactTarget, Network, OutPoint, ScriptBuf, Sequence, Transaction,
+    TxIn, TxMerkleNode, TxOut, Witness,
+};
+use bitcoincore_rpc::bitcoincore_rpc_json::GetBlockHeaderResult;
+use coerce::actor::{system::ActorSystem, IntoActor, LocalActorRef};
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_types::module_binding::MoveFunctionCaller;
+use prometheus::Registry;
+use rooch_config::{BitcoinRelayerConfig, RoochOpt};
+use rooch_db::RoochDB;
+use rooch_executor::actor::executor::ExecutorActor;
+use rooch_executor::actor::reader_executor::ReaderExecutorActor;
+use rooch_executor::proxy::ExecutorProxy;
+use rooch_genesis::RoochGenesis;
+use rooch_types::bitcoin::pending_block::PendingBlockModule;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::into_address::IntoAddress;
+use rooch_types::rooch_network::{BuiltinChainID, RoochNetwork};
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// A regtest-like chain which answers `getblockheader` with the main chain and the stale blocks.
+#[derive(Default)]
+struct MockBitcoinChain {
+    headers: HashMap<BlockHash, GetBlockHeaderResult>,
+}
+
+impl MockBitcoinChain {
+    /// Build the main chain `0..=tip` with the stale branch forked from `fork_height` to `stale_tip`.
+    fn new(tip: u64, fork_height: u64, stale_tip: u64) -> Self {
+        let mut chain = Self::default();
+        for height in 0..=tip {
+            let previous = height.checked_sub(1).map(main_block_hash);
+            let next = (height < tip).then(|| main_block_hash(height + 1));
+            let confirmations = (tip - height + 1) as i64;
+            chain.add_header(
+                height,
+                main_block_hash(height),
+                previous,
+                next,
+                confirmations,
+            );
+        }
+        for height in fork_height + 1..=stale_tip {
+            let previous = if height == fork_height + 1 {
+                main_block_hash(fork_height)
+            } else {
+                stale_block_hash(height - 1)
+            };
+            let next = (height < stale_tip).then(|| stale_block_hash(height + 1));
+            // the stale block has -1 confirmations
+            chain.add_header(height, stale_block_hash(height), Some(previous), next, -1);
+        }
+        chain
+    }
+
+    fn add_header(
+        &mut self,
+        height: u64,
+        hash: BlockHash,
+        previous: Option<BlockHash>,
+        next: Option<BlockHash>,
+        confirmations: i64,
+    ) {
+        self.headers.insert(
+            hash,
+            new_header_info(height, hash, previous, next, confirmations),
+        );
+    }
+}
+
+fn new_header_info(
+    height: u64,
+    hash: BlockHash,
+    previous: Option<BlockHash>,
+    next: Option<BlockHash>,
+    confirmations: i64,
+) -> GetBlockHeaderResult {
+    let mut header = json!({
+        "hash": hash.to_string(),
+        "confirmations": confirmations,
+        "height": height,
+        "version": 536870912,
+        "versionHex": "20000000",
+        "merkleroot": BlockHash::all_zeros().to_string(),
+        "time": 1296688602 + height,
+        "mediantime": 1296688602 + height,
+        "nonce": 0,
+        "bits": "207fffff",
+        "difficulty": 4.656542373906925e-10,
+        "chainwork": "0000000000000000000000000000000000000000000000000000000000000002",
+        "nTx": 1,
+    });
+    if let Some(previous) = previous {
+        header["previousblockhash"] = json!(previous.to_string());
+    }
+    if let Some(next) = next {
+        header["nextblockhash"] = json!(next.to_string());
+    }
+    serde_json::from_value(header).unwrap()
+}
+
+#[async_trait]
+impl BitcoinHeaderReader for MockBitcoinChain {
+    async fn get_block_header_info(&self, hash: BlockHash) -> Result<GetBlockHeaderResult> {
+        self.headers
+            .get(&hash)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Block not found: {}", hash))
+    }
+}
+
+fn main_block_hash(height: u64) -> BlockHash {
+    block_hash(0, height)
+}
+
+fn stale_block_hash(height: u64) -> BlockHash {
+    block_hash(1, height)
+}
+
+fn block_hash(branch: u8, height: u64) -> BlockHash {
+    let mut bytes = [branch; 32];
+    bytes[..8].copy_from_slice(&height.to_le_bytes());
+    BlockHash::from_byte_array(bytes)
+}
+
+#[tokio::test]
+async fn test_find_fork_point_in_main_chain() {
+    let chain = MockBitcoinChain::new(12, 5, 10);
+    let (fork_block, reorg) = find_fork_point(&chain, main_block_hash(8)).await.unwrap();
+    assert!(reorg.is_none());
+    assert_eq!(fork_block.hash, main_block_hash(8));
+    assert_eq!(fork_block.next_block_hash, Some(main_block_hash(9)));
+}
+
+#[tokio::test]
+async fn test_find_fork_point_of_stale_block() {
+    let chain = MockBitcoinChain::new(12, 5, 10);
+    let (fork_block, reorg) = find_fork_point(&chain, stale_block_hash(10)).await.unwrap();
+    let reorg = reorg.unwrap();
+    assert_eq!(fork_block.hash, main_block_hash(5));
+    assert_eq!(fork_block.next_block_hash, Some(main_block_hash(6)));
+    assert_eq!(reorg.orphaned_block_height, 10);
+    assert_eq!(reorg.fork_block_height, 5);
+    assert_eq!(reorg.fork_block_hash, main_block_hash(5));
+    assert_eq!(reorg.depth(), 5);
+
+    // the pending window covers the reorg
+    assert!(!reorg.is_deep(5));
+    assert!(!reorg.is_deep(6));
+    // the orphaned blocks have left the pending window
+    assert!(reorg.is_deep(4));
+    assert!(reorg.is_deep(0));
+}
+
+#[tokio::test]
+async fn test_find_fork_point_of_unknown_block() {
+    let chain = MockBitcoinChain::new(12, 5, 10);
+    assert!(find_fork_point(&chain, stale_block_hash(11)).await.is_err());
+}
+
+/// A regtest node which mines the blocks of the main chain, the blocks orphaned by a reorg are kept as stale blocks.
+struct MockBitcoinNode {
+    blocks: HashMap<BlockHash, (u64, Block)>,
+    main_chain: Vec<BlockHash>,
+}
+
+impl MockBitcoinNode {
+    fn new() -> Self {
+        let genesis = genesis_block(Network::Regtest);
+        let genesis_hash = genesis.block_hash();
+        Self {
+            blocks: HashMap::from([(genesis_hash, (0, genesis))]),
+            main_chain: vec![genesis_hash],
+        }
+    }
+
+    /// Mine the blocks of the branch on the main chain up to the tip height.
+    fn mine(&mut self, branch: u8, tip: u64) {
+        while (self.main_chain.len() as u64) <= tip {
+            let height = self.main_chain.len() as u64;
+            let block = new_block(*self.main_chain.last().unwrap(), height, branch);
+            let block_hash = block.block_hash();
+            self.blocks.insert(block_hash, (height, block));
+            self.main_chain.push(block_hash);
+        }
+    }
+
+    /// Orphan the blocks after the fork height, then mine the blocks of the new branch up to the tip height.
+    fn reorg(&mut self, fork_height: u64, branch: u8, tip: u64) {
+        self.main_chain.truncate(fork_height as usize + 1);
+        self.mine(branch, tip);
+    }
+
+    fn block_hash(&self, height: u64) -> BlockHash {
+        self.main_chain[height as usize]
+    }
+
+    fn header_info(&self, hash: BlockHash) -> Result<GetBlockHeaderResult> {
+        let (height, block) = self
+            .blocks
+            .get(&hash)
+            .ok_or_else(|| anyhow::anyhow!("Block not found: {}", hash))?;
+        let height = *height;
+        let in_main_chain = self.main_chain.get(height as usize) == Some(&hash);
+        let tip = self.main_chain.len() as u64 - 1;
+        let (next, confirmations) = if in_main_chain {
+            (
+                self.main_chain.get(height as usize + 1).cloned(),
+                (tip - height + 1) as i64,
+            )
+        } else {
+            // the stale block has -1 confirmations
+            (None, -1)
+        };
+        let previous = (height > 0).then_some(block.header.prev_blockhash);
+        Ok(new_header_info(height, hash, previous, next, confirmations))
+    }
+}
+
+fn new_block(prev_blockhash: BlockHash, height: u64, branch: u8) -> Block {
+    // the coinbase of each branch is different, so the blocks of different branches have different hashes
+    let coinbase = Transaction {
+        version: Version::TWO,
+        lock_time: LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint::null(),
+            script_sig: ScriptBuf::from_bytes([&height.to_le_bytes()[..], &[branch]].concat()),
+            sequence: Sequence::MAX,
+            witness: Witness::new(),
+        }],
+        output: vec![TxOut {
+            value: Amount::from_sat(50 * 100_000_000),
+            script_pubkey: ScriptBuf::new(),
+        }],
+    };
+    let mut block = Block {
+        header: Header {
+            version: BlockVersion::TWO,
+            prev_blockhash,
+            merkle_root: TxMerkleNode::all_zeros(),
+            time: 1296688602 + height as u32 * 600,
+            bits: CompactTarget::from_consensus(0x207fffff),
+            nonce: branch as u32,
+        },
+        txdata: vec![coinbase],
+    };
+    block.header.merkle_root = block.compute_merkle_root().unwrap();
+    block
+}
+
+/// The node is shared by the relayer and the test, so the test can reorg the chain while the relayer follows it.
+#[derive(Clone)]
+struct SharedBitcoinNode(Arc<RwLock<MockBitcoinNode>>);
+
+#[async_trait]
+impl BitcoinHeaderReader for SharedBitcoinNode {
+    async fn get_block_header_info(&self, hash: BlockHash) -> Result<GetBlockHeaderResult> {
+        self.0.read().unwrap().header_info(hash)
+    }
+}
+
+#[async_trait]
+impl BitcoinBlockSource for SharedBitcoinNode {
+    async fn get_best_block_hash(&self) -> Result<BlockHash> {
+        Ok(*self.0.read().unwrap().main_chain.last().unwrap())
+    }
+
+    async fn get_block(&self, hash: BlockHash) -> Result<Block> {
+        self.0
+            .read()
+            .unwrap()
+            .blocks
+            .get(&hash)
+            .map(|(_, block)| block.clone())
+            .ok_or_else(|| anyhow::anyhow!("Block not found: {}", hash))
+    }
+}
+
+/// Sync the blocks from the node, then execute the blocks buffered by the relayer like the relayer actor does.
+/// Return the hashes of the relayed blocks.
+async fn relay_blocks(
+    relayer: &LocalActorRef<BitcoinRelayer>,
+    executor: &ExecutorProxy,
+) -> Result<Vec<BlockHash>> {
+    relayer.send(SyncTick {}).await?;
+    let mut relayed_blocks = vec![];
+    while let Some(l1_block) = relayer.send(GetReadyL1BlockMessage {}).await?? {
+        relayed_blocks.push(BlockHash::from_slice(&l1_block.block.block_hash)?);
+        let verified_tx = executor.validate_l1_block(l1_block).await?;
+        let (_output, execution_info) = executor.execute_transaction(verified_tx).await?;
+        assert_eq!(execution_info.status, KeptVMStatus::Executed);
+        executor
+            .refresh_state(execution_info.root_metadata(), false)
+            .await?;
+    }
+    Ok(relayed_blocks)
+}
+
+fn best_block(
+    executor: &ExecutorProxy,
+) -> Result<Option<rooch_types::bitcoin::types::BlockHeightHash>> {
+    executor
+        .as_module_binding::<PendingBlockModule>()
+        .get_best_block()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_relay_bitcoin_reorg() -> Result<()> {
+    let mut network: RoochNetwork = BuiltinChainID::Local.into();
+    network.mock_genesis_account(&RoochKeyPair::generate_secp256k1())?;
+    // the reorg within the pending window is handled by the pending blocks
+    network.genesis_config.bitcoin_reorg_block_count = 3;
+    let opt = RoochOpt::new_with_temp_store()?;
+    let registry = Registry::new();
+    let rooch_db = RoochDB::init(opt.store_config(), &registry)?;
+    let root = RoochGenesis::build(network)?.init_genesis(&rooch_db)?;
+
+    let actor_system = ActorSystem::global_system();
+    let executor = ExecutorActor::new(
+        root.clone(),
+        rooch_db.moveos_store.clone(),
+        rooch_db.rooch_store.clone(),
+        &registry,
+        None,
+    )?
+    .into_actor(Some("ExecutorForBitcoinReorg"), &actor_system)
+    .await?;
+    let reader_executor = ReaderExecutorActor::new(
+        root,
+        rooch_db.moveos_store.clone(),
+        rooch_db.rooch_store.clone(),
+        None,
+    )?
+    .into_actor(Some("ReaderExecutorForBitcoinReorg"), &actor_system)
+    .await?;
+    let executor_proxy = ExecutorProxy::new(executor.into(), reader_executor.into());
+
+    let node = SharedBitcoinNode(Arc::new(RwLock::new(MockBitcoinNode::new())));
+    node.0.write().unwrap().mine(0, 2);
+    let block_store_dir = opt.base().data_dir().join("reorg_aware_blocks");
+    std::fs::create_dir_all(&block_store_dir)?;
+    let config = BitcoinRelayerConfig {
+        btc_rpc_url: "mock".to_string(),
+        btc_rpc_user_name: "mock".to_string(),
+        btc_rpc_password: "mock".to_string(),
+        btc_end_block_height: None,
+        btc_sync_block_interval: Some(0),
+        btc_reorg_aware_block_store_dir: block_store_dir,
+        btc_reorg_aware_height: 10,
+    };
+    let relayer = BitcoinRelayer::new_with_source(
+        config,
+        Arc::new(node.clone()),
+        executor_proxy.clone(),
+        None,
+    )?
+    .into_actor(Some("BitcoinRelayerForReorg"), &actor_system)
+    .await?;
+
+    // The relayer follows the main chain from the genesis block
+    let main_chain = node.0.read().unwrap().main_chain.clone();
+    assert_eq!(relay_blocks(&relayer, &executor_proxy).await?, main_chain);
+    let orphaned_block = node.0.read().unwrap().block_hash(2);
+    let best = best_block(&executor_proxy)?.unwrap();
+    assert_eq!(best.block_height, 2);
+    assert_eq!(best.block_hash, orphaned_block.into_address());
+
+    // Block 2 is orphaned by the reorg, the relayer rolls back to the fork point and re-applies the new branch
+    node.0.write().unwrap().reorg(1, 1, 3);
+    let (new_block_2, new_block_3) = {
+        let node = node.0.read().unwrap();
+        (node.block_hash(2), node.block_hash(3))
+    };
+    assert_ne!(new_block_2, orphaned_block);
+    assert_eq!(
+        relay_blocks(&relayer, &executor_proxy).await?,
+        vec![new_block_2, new_block_3]
+    );
+    let best = best_block(&executor_proxy)?.unwrap();
+    assert_eq!(best.block_height, 3);
+    assert_eq!(best.block_hash, new_block_3.into_address());
+
+    // Nothing to relay once the relayer catches up with the main chain
+    assert!(relay_blocks(&relayer, &executor_proxy).await?.is_empty());
+
+    // The relayer keeps following the new branch
+    node.0.write().unwrap().mine(1, 5);
+    let new_branch = node.0.read().unwrap().main_chain[4..].to_vec();
+    assert_eq!(relay_blocks(&relayer, &executor_proxy).await?, new_branch);
+    let best = best_block(&executor_proxy)?.unwrap();
+    assert_eq!(best.block_height, 5);
+    assert_eq!(best.block_hash, new_branch[1].into_address());
+    Ok(())
+}
@@ -0,0 +1,160 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::actor::ethereum_relayer::EthereumBlockSyncer;
+use ethers::prelude::*;
+use serde_json::{json, Value};
+
+fn block_hash(seed: u8) -> H256 {
+    H256::repeat_byte(seed)
+}
+
+fn block(number: u64, hash: H256, parent_hash: H256, transactions: Vec<H256>) -> Value {
+    let block = Block::<H256> {
+        hash: Some(hash),
+        parent_hash,
+        number: Some(number.into()),
+        author: Some(Address::zero()),
+        timestamp: (1700000000 + number).into(),
+        transactions,
+        ..Default::default()
+    };
+    serde_json::to_value(block).unwrap()
+}
+
+fn no_receipts() -> Value {
+    json!([])
+}
+
+/// The MockProvider returns the responses in LIFO order, so push them reversely.
+fn mocked_syncer(
+    responses: Vec<Value>,
+    confirmations: u64,
+    start_block_number: Option<u64>,
+    reorg_aware_height: usize,
+) -> EthereumBlockSyncer<MockProvider> {
+    let (provider, mock) = Provider::mocked();
+    for response in responses.into_iter().rev() {
+        mock.push::<Value, _>(response).unwrap();
+    }
+    EthereumBlockSyncer::new(
+        provider,
+        confirmations,
+        start_block_number,
+        reorg_aware_height,
+    )
+}
+
+#[tokio::test]
+async fn test_sync_confirmed_blocks() {
+    let mut syncer = mocked_syncer(
+        vec![
+            // latest block 13, only the blocks before 11 are confirmed
+            json!(U64::from(13)),
+            block(10, block_hash(10), block_hash(9), vec![]),
+            no_receipts(),
+            block(11, block_hash(11), block_hash(10), vec![]),
+            no_receipts(),
+        ],
+        2,
+        Some(10),
+        8,
+    );
+    syncer.sync().await.unwrap();
+
+    assert_eq!(syncer.latest_block(), Some((11, block_hash(11))));
+    assert_eq!(syncer.pop_block().unwrap().header.number, 10);
+    assert_eq!(syncer.pop_block().unwrap().header.number, 11);
+    assert!(syncer.pop_block().is_none());
+}
+
+#[tokio::test]
+async fn test_sync_receipts() {
+    let tx_hash = H256::repeat_byte(0xaa);
+    let topic = H256::repeat_byte(0xbb);
+    let receipt = TransactionReceipt {
+        transaction_hash: tx_hash,
+        block_hash: Some(block_hash(10)),
+        block_number: Some(10.into()),
+        status: Some(1.into()),
+        gas_used: Some(21000.into()),
+        cumulative_gas_used: 21000.into(),
+        logs: vec![Log {
+            address: Address::repeat_byte(0xcc),
+            topics: vec![topic],
+            data: vec![1u8, 2, 3].into(),
+            log_index: Some(0.into()),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let mut syncer = mocked_syncer(
+        vec![
+            json!(U64::from(10)),
+            block(10, block_hash(10), block_hash(9), vec![tx_hash]),
+            json!([receipt]),
+        ],
+        0,
+        Some(10),
+        8,
+    );
+    syncer.sync().await.unwrap();
+
+    let block = syncer.pop_block().unwrap();
+    assert_eq!(block.receipts.len(), 1);
+    let receipt = &block.receipts[0];
+    assert_eq!(receipt.transaction_hash, tx_hash.as_bytes().to_vec());
+    assert_eq!(receipt.status, 1);
+    assert_eq!(receipt.logs.len(), 1);
+    assert_eq!(receipt.logs[0].topics, vec![topic.as_bytes().to_vec()]);
+    assert_eq!(receipt.logs[0].data, vec![1u8, 2, 3]);
+}
+
+#[tokio::test]
+async fn test_sync_reorg() {
+    let mut syncer = mocked_syncer(
+        vec![
+            json!(U64::from(12)),
+            // the parent of block 12 is not the relayed block 11
+            block(12, block_hash(112), block_hash(111), vec![]),
+            // block 11 is reorganized, block 10 is the common ancestor
+            block(11, block_hash(111), block_hash(10), vec![]),
+            block(10, block_hash(10), block_hash(9), vec![]),
+            // follow the new chain from the common ancestor
+            block(11, block_hash(111), block_hash(10), vec![]),
+            no_receipts(),
+            block(12, block_hash(112), block_hash(111), vec![]),
+            no_receipts(),
+        ],
+        0,
+        None,
+        8,
+    );
+    syncer.reset_recent_blocks(vec![(10, block_hash(10)), (11, block_hash(11))]);
+    syncer.sync().await.unwrap();
+
+    assert_eq!(syncer.latest_block(), Some((12, block_hash(112))));
+    let block = syncer.pop_block().unwrap();
+    assert_eq!(block.header.number, 11);
+    assert_eq!(block.header.hash, block_hash(111).as_bytes().to_vec());
+    assert_eq!(syncer.pop_block().unwrap().header.number, 12);
+    assert!(syncer.pop_block().is_none());
+}
+
+#[tokio::test]
+async fn test_sync_reorg_deeper_than_aware_height() {
+    let mut syncer = mocked_syncer(
+        vec![
+            json!(U64::from(11)),
+            block(11, block_hash(111), block_hash(110), vec![]),
+            block(10, block_hash(110), block_hash(109), vec![]),
+        ],
+        0,
+        None,
+        1,
+    );
+    syncer.reset_recent_blocks(vec![(10, block_hash(10))]);
+    assert!(syncer.sync().await.is_err());
+    assert_eq!(syncer.latest_block(), Some((10, block_hash(10))));
+    assert!(syncer.pop_block().is_none());
+}
@@ -3,20 +3,24 @@
 
 use crate::jsonrpc_types::account_view::BalanceInfoView;
 use crate::jsonrpc_types::address::UnitedAddressView;
-use crate::jsonrpc_types::event_view::{EventFilterView, IndexerEventIDView};
+use crate::jsonrpc_types::event_view::{EventFilterView, IndexerEventIDView, IndexerEventView};
 use crate::jsonrpc_types::field_view::FieldFilterView;
 use crate::jsonrpc_types::repair_view::{RepairIndexerParamsView, RepairIndexerTypeView};
-use crate::jsonrpc_types::transaction_view::{TransactionFilterView, TransactionWithInfoView};
+use crate::jsonrpc_types::transaction_view::{
+    TransactionAccumulatorProofView, TransactionFilterView, TransactionWithInfoView,
+};
 use crate::jsonrpc_types::{
     AccessPathView, AnnotatedFunctionResultView, BalanceInfoPageView, BytesView, EventOptions,
     EventPageView, ExecuteTransactionResponseView, FieldKeyView, FieldPageView, FunctionCallView,
     H256View, IndexerEventPageView, IndexerObjectStatePageView, IndexerStateIDView, ModuleABIView,
     ObjectIDVecView, ObjectIDView, ObjectStateFilterView, ObjectStateView, QueryOptions,
-    RoochAddressView, StateChangeSetPageView, StateOptions, StatePageView, StrView, StructTagView,
-    SyncStateFilterView, TransactionWithInfoPageView, TxOptions,
+    RoochAddressView, StateChangeSetPageView, StateOptions, StateOverrideView, StatePageView,
+    StatesWithProofView, StrView, StructTagView, SyncStateFilterView, TraceOptions,
+    TransactionTraceView, TransactionWithInfoPageView, TxOptions,
 };
-use crate::jsonrpc_types::{DryRunTransactionResponseView, Status};
+use crate::jsonrpc_types::{DryRunTransactionResponseView, GasEstimateView, Status};
 use crate::RpcResult;
+use jsonrpsee::core::SubscriptionResult;
 use jsonrpsee::proc_macros::rpc;
 use moveos_types::{access_path::AccessPath, state::FieldKey};
 use rooch_open_rpc_macros::open_rpc;
@@ -42,15 +46,34 @@ pub trait RoochAPI {
         tx_option: Option<TxOptions>,
     ) -> RpcResult<ExecuteTransactionResponseView>;
 
+    /// Dry run the transaction data in bcs hex format, the transaction does not need to be signed.
+    /// The state overrides are applied on top of the current state only for this dry run, like the eth_call state overrides.
     #[method(name = "dryRunRawTransaction")]
-    async fn dry_run(&self, tx_bcs_hex: BytesView) -> RpcResult<DryRunTransactionResponseView>;
+    async fn dry_run(
+        &self,
+        tx_bcs_hex: BytesView,
+        state_overrides: Option<Vec<StateOverrideView>>,
+    ) -> RpcResult<DryRunTransactionResponseView>;
+
+    /// Estimate the gas of the transaction data in bcs hex format, the transaction does not need to be signed.
+    /// The max gas amount is capped by the RGas balance of the gas payer at the gas price, the gas payer is the sender if it is not set.
+    /// The suggested max_gas_amount includes a safety margin.
+    #[method(name = "estimateGas")]
+    async fn estimate_gas(
+        &self,
+        tx_bcs_hex: BytesView,
+        gas_payer: Option<UnitedAddressView>,
+    ) -> RpcResult<GasEstimateView>;
 
     /// Execute a read-only function call
     /// The function do not change the state of Application
+    /// If the StateOptions.at_tx_order is set, the function is executed at the historical state.
+    /// The StateOptions.state_root is not supported, because the object count of the state root is unknown.
     #[method(name = "executeViewFunction")]
     async fn execute_view_function(
         &self,
         function_call: FunctionCallView,
+        state_option: Option<StateOptions>,
     ) -> RpcResult<AnnotatedFunctionResultView>;
 
     /// Get the states by access_path
@@ -62,6 +85,16 @@ pub trait RoochAPI {
         state_option: Option<StateOptions>,
     ) -> RpcResult<Vec<Option<ObjectStateView>>>;
 
+    /// Get the states by access_path with the proofs against the state root
+    /// If the state_root is None, the latest state root is used.
+    /// The proof of the nested object field is chained through the state roots of its parent objects.
+    #[method(name = "getStatesWithProof")]
+    async fn get_states_with_proof(
+        &self,
+        access_path: AccessPathView,
+        state_root: Option<H256View>,
+    ) -> RpcResult<StatesWithProofView>;
+
     /// List the states by access_path
     /// If the StateOptions.decode is true, the state is decoded and the decoded value is returned in the response.
     #[method(name = "listStates")]
@@ -135,6 +168,23 @@ pub trait RoochAPI {
         descending_order: Option<bool>,
     ) -> RpcResult<TransactionWithInfoPageView>;
 
+    /// Trace the transaction by re-executing it at the state root before it was executed, like the debug_traceTransaction.
+    /// Return the call tree with the gas used by each call, the events, the objects read and the state changes.
+    #[method(name = "traceTransaction")]
+    async fn trace_transaction(
+        &self,
+        tx_hash: H256View,
+        trace_option: Option<TraceOptions>,
+    ) -> RpcResult<TransactionTraceView>;
+
+    /// Get the tx accumulator inclusion proof of the transaction against the block it was proposed in.
+    /// Return None if the transaction does not exist or has not been proposed yet.
+    #[method(name = "getTransactionAccumulatorProof")]
+    async fn get_transaction_accumulator_proof(
+        &self,
+        tx_hash: H256View,
+    ) -> RpcResult<Option<TransactionAccumulatorProofView>>;
+
     /// get account balance by RoochAddress and CoinType
     #[method(name = "getBalance")]
     async fn get_balance(
@@ -234,4 +284,27 @@ pub trait RoochAPI {
         limit: Option<StrView<u64>>,
         query_option: Option<QueryOptions>,
     ) -> RpcResult<Vec<u64>>;
+
+    /// Subscribe to the executed transactions matching the transaction filter
+    /// The subscription is only available over WebSocket.
+    #[subscription(
+        name = "subscribeTransactions",
+        unsubscribe = "unsubscribeTransactions",
+        item = TransactionWithInfoView
+    )]
+    async fn subscribe_transactions(&self, filter: TransactionFilterView) -> SubscriptionResult;
+
+    /// Subscribe to the events emitted by the executed transactions matching the event filter
+    /// If the EventOptions.decode is true, the event is decoded and the decoded value is returned in the notification.
+    /// The subscription is only available over WebSocket.
+    #[subscription(
+        name = "subscribeEvents",
+        unsubscribe = "unsubscribeEvents",
+        item = IndexerEventView
+    )]
+    async fn subscribe_events(
+        &self,
+        filter: EventFilterView,
+        event_options: Option<EventOptions>,
+    ) -> SubscriptionResult;
 }
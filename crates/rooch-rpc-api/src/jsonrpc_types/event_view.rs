@@ -2,8 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::jsonrpc_types::{
-    AnnotatedMoveStructView, H256View, HumanReadableDisplay, RoochAddressView, StrView,
-    StructTagView, UnitedAddressView,
+    AccountAddressView, AnnotatedMoveStructView, H256View, HumanReadableDisplay, IdentifierView,
+    ObjectIDView, RoochAddressView, StrView, StructTagView, UnitedAddressView,
 };
 use moveos_types::moveos_std::{
     event::{AnnotatedEvent, Event, EventID, TransactionEvent},
@@ -217,6 +217,20 @@ pub enum EventFilterView {
         /// right endpoint of transaction order, exclusive
         to_order: StrView<u64>,
     },
+    /// Query by the module which defines the event type, such as all the events of `0x3::coin`.
+    /// Query all the events defined by the address if the module is not set.
+    EventModule {
+        address: AccountAddressView,
+        module: Option<IdentifierView>,
+    },
+    /// Query by any of the event types.
+    EventTypes(Vec<StructTagView>),
+    /// Query by the event handle id.
+    EventHandle(ObjectIDView),
+    /// Return events matching all of the filters.
+    And(Vec<EventFilterView>),
+    /// Return events matching any of the filters.
+    Or(Vec<EventFilterView>),
 }
 
 impl From<EventFilterView> for EventFilter {
@@ -245,6 +259,18 @@ impl From<EventFilterView> for EventFilter {
                 from_order: from_order.0,
                 to_order: to_order.0,
             },
+            EventFilterView::EventModule { address, module } => Self::EventModule {
+                address: address.0,
+                module: module.map(|module| module.0),
+            },
+            EventFilterView::EventTypes(event_types) => {
+                Self::EventTypes(event_types.into_iter().map(Into::into).collect())
+            }
+            EventFilterView::EventHandle(event_handle_id) => Self::EventHandle(event_handle_id.0),
+            EventFilterView::And(filters) => {
+                Self::And(filters.into_iter().map(Into::into).collect())
+            }
+            EventFilterView::Or(filters) => Self::Or(filters.into_iter().map(Into::into).collect()),
         }
     }
 }
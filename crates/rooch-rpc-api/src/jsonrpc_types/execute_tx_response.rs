@@ -1,11 +1,13 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use super::BytesView;
+use super::{BytesView, RoochAddressView};
 use super::{HumanReadableDisplay, ModuleIdView, StateChangeSetView, StrView};
 use crate::jsonrpc_types::event_view::EventView;
 use crate::jsonrpc_types::H256View;
 use ethers::types::H256;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
 use move_core_types::vm_status::{AbortLocation, KeptVMStatus};
 use moveos_types::transaction::TransactionOutput;
 use moveos_types::transaction::{TransactionExecutionInfo, VMErrorInfo};
@@ -138,6 +140,8 @@ pub struct TransactionExecutionInfoView {
     pub event_root: H256View,
     pub gas_used: StrView<u64>,
     pub status: KeptVMStatusView,
+    /// The account which paid the gas, it is the sender unless the transaction is sponsored.
+    pub gas_payer: RoochAddressView,
 }
 
 impl TransactionExecutionInfoView {
@@ -147,6 +151,7 @@ impl TransactionExecutionInfoView {
         event_root: H256,
         gas_used: StrView<u64>,
         status: KeptVMStatusView,
+        gas_payer: RoochAddressView,
     ) -> Self {
         Self {
             tx_hash: tx_hash.into(),
@@ -154,6 +159,7 @@ impl TransactionExecutionInfoView {
             event_root: event_root.into(),
             gas_used,
             status,
+            gas_payer,
         }
     }
 }
@@ -166,6 +172,7 @@ impl From<TransactionExecutionInfo> for TransactionExecutionInfoView {
             event_root: transaction_execution_info.event_root.into(),
             gas_used: transaction_execution_info.gas_used.into(),
             status: KeptVMStatusView::from(transaction_execution_info.status),
+            gas_payer: transaction_execution_info.gas_payer.into(),
         }
     }
 }
@@ -176,11 +183,13 @@ impl HumanReadableDisplay for TransactionExecutionInfoView {
             r#"{indent}Execution info:
 {indent}    status: {:?}
 {indent}    gas used: {}
+{indent}    gas payer: {}
 {indent}    tx hash: {}
 {indent}    state root: {}
 {indent}    event root: {}"#,
             self.status,
             self.gas_used,
+            self.gas_payer,
             self.tx_hash,
             self.state_root,
             self.event_root,
@@ -227,6 +236,77 @@ pub struct DryRunTransactionResponseView {
     pub vm_error_info: VMErrorInfo,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GasEstimateView {
+    pub status: KeptVMStatusView,
+    /// The total gas of the transaction, the execution gas and the storage gas
+    pub gas_used: StrView<u64>,
+    /// The gas used by the Move execution
+    pub execution_gas_used: StrView<u64>,
+    /// The storage gas of the transaction size, the events and the state changes
+    pub storage_gas_used: StrView<u64>,
+    /// The storage gas of the transaction size
+    pub tx_size_storage_gas: StrView<u64>,
+    /// The storage gas of the events
+    pub event_storage_gas: StrView<u64>,
+    /// The storage gas of the state changes
+    pub change_set_storage_gas: StrView<u64>,
+    /// The gas price, the transaction fee is the gas used multiplied by it
+    pub gas_price: StrView<u64>,
+    /// The estimated transaction fee in RGas, the gas used multiplied by the gas price
+    pub estimated_fee: StrView<U256>,
+    /// The suggested max_gas_amount, the gas used with a safety margin
+    pub suggested_max_gas_amount: StrView<u64>,
+    pub vm_error_info: Option<VMErrorInfo>,
+}
+
+impl GasEstimateView {
+    /// The safety margin in percent which is added to the gas used
+    pub const SAFETY_MARGIN_PERCENT: u64 = 20;
+
+    /// The `dry_run_gas_used` is the gas charged in the dry run, it includes the execution gas and the storage gas of the transaction size.
+    /// The storage gas of the events and the state changes are added to the gas used.
+    pub fn new(
+        status: KeptVMStatus,
+        dry_run_gas_used: u64,
+        tx_size_storage_gas: u64,
+        event_storage_gas: u64,
+        change_set_storage_gas: u64,
+        gas_price: u64,
+        vm_error_info: Option<VMErrorInfo>,
+    ) -> Self {
+        let execution_gas_used = dry_run_gas_used.saturating_sub(tx_size_storage_gas);
+        let storage_gas_used = tx_size_storage_gas
+            .saturating_add(event_storage_gas)
+            .saturating_add(change_set_storage_gas);
+        let gas_used = execution_gas_used.saturating_add(storage_gas_used);
+        Self {
+            status: status.into(),
+            gas_used: gas_used.into(),
+            execution_gas_used: execution_gas_used.into(),
+            storage_gas_used: storage_gas_used.into(),
+            tx_size_storage_gas: tx_size_storage_gas.into(),
+            event_storage_gas: event_storage_gas.into(),
+            change_set_storage_gas: change_set_storage_gas.into(),
+            gas_price: gas_price.into(),
+            estimated_fee: (U256::from(gas_used) * U256::from(gas_price)).into(),
+            suggested_max_gas_amount: Self::suggested_max_gas_amount(gas_used).into(),
+            vm_error_info,
+        }
+    }
+
+    pub fn suggested_max_gas_amount(gas_used: u64) -> u64 {
+        let margin = gas_used
+            .saturating_mul(Self::SAFETY_MARGIN_PERCENT)
+            .div_ceil(100);
+        gas_used.saturating_add(margin)
+    }
+
+    pub fn is_executed(&self) -> bool {
+        self.status == KeptVMStatusView::Executed
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ExecuteTransactionResponseView {
     pub sequence_info: TransactionSequenceInfoView,
@@ -261,6 +341,7 @@ impl From<DryRunTransactionResponseView> for ExecuteTransactionResponseView {
                 H256::random(),
                 response.raw_output.gas_used,
                 response.raw_output.status.clone(),
+                AccountAddress::random().into(),
             ),
             output: None,
             error_info: Some(response),
@@ -18,7 +18,7 @@ use moveos_types::transaction::MoveAction;
 use moveos_types::{
     access_path::AccessPath,
     move_types::FunctionId,
-    transaction::{FunctionCall, ScriptCall},
+    transaction::{BatchCall, CallResultArg, FunctionCall, ScriptCall},
 };
 use moveos_types::{
     move_std::{ascii::MoveAsciiString, string::MoveString},
@@ -392,6 +392,57 @@ impl From<FunctionCallView> for FunctionCall {
     }
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct CallResultArgView {
+    pub arg_index: u16,
+    pub call_index: u16,
+    pub result_index: u16,
+}
+
+impl From<CallResultArg> for CallResultArgView {
+    fn from(origin: CallResultArg) -> Self {
+        Self {
+            arg_index: origin.arg_index,
+            call_index: origin.call_index,
+            result_index: origin.result_index,
+        }
+    }
+}
+
+impl From<CallResultArgView> for CallResultArg {
+    fn from(value: CallResultArgView) -> Self {
+        Self {
+            arg_index: value.arg_index,
+            call_index: value.call_index,
+            result_index: value.result_index,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct BatchCallView {
+    pub call: FunctionCallView,
+    pub result_args: Vec<CallResultArgView>,
+}
+
+impl From<BatchCall> for BatchCallView {
+    fn from(origin: BatchCall) -> Self {
+        Self {
+            call: origin.call.into(),
+            result_args: origin.result_args.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<BatchCallView> for BatchCall {
+    fn from(value: BatchCallView) -> Self {
+        Self {
+            call: value.call.into(),
+            result_args: value.result_args.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MoveActionView {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -400,6 +451,8 @@ pub struct MoveActionView {
     pub script_call: Option<ScriptCallView>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub module_bundle: Option<Vec<BytesView>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batch_call: Option<Vec<BatchCallView>>,
 }
 
 impl From<MoveAction> for MoveActionView {
@@ -409,16 +462,25 @@ impl From<MoveAction> for MoveActionView {
                 script_call: Some(script.into()),
                 function_call: None,
                 module_bundle: None,
+                batch_call: None,
             },
             MoveAction::Function(fun) => Self {
                 script_call: None,
                 function_call: Some(fun.into()),
                 module_bundle: None,
+                batch_call: None,
             },
             MoveAction::ModuleBundle(module) => Self {
                 script_call: None,
                 function_call: None,
                 module_bundle: Some(module.into_iter().map(StrView).collect()),
+                batch_call: None,
+            },
+            MoveAction::Batch(calls) => Self {
+                script_call: None,
+                function_call: None,
+                module_bundle: None,
+                batch_call: Some(calls.into_iter().map(Into::into).collect()),
             },
         }
     }
@@ -432,6 +494,8 @@ impl From<MoveActionView> for MoveAction {
             MoveAction::Function(function_call.into())
         } else if let Some(module_bundle) = action.module_bundle {
             MoveAction::ModuleBundle(module_bundle.into_iter().map(StrView::into).collect())
+        } else if let Some(batch_call) = action.batch_call {
+            MoveAction::Batch(batch_call.into_iter().map(Into::into).collect())
         } else {
             panic!("Invalid MoveActionView")
         }
@@ -444,6 +508,7 @@ pub enum MoveActionTypeView {
     ScriptCall,
     FunctionCall,
     ModuleBundle,
+    BatchCall,
 }
 
 impl From<MoveAction> for MoveActionTypeView {
@@ -452,6 +517,7 @@ impl From<MoveAction> for MoveActionTypeView {
             MoveAction::Script(_) => Self::ScriptCall,
             MoveAction::Function(_) => Self::FunctionCall,
             MoveAction::ModuleBundle(_) => Self::ModuleBundle,
+            MoveAction::Batch(_) => Self::BatchCall,
         }
     }
 }
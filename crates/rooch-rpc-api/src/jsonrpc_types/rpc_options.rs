@@ -1,7 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::jsonrpc_types::H256View;
+use crate::jsonrpc_types::{H256View, StrView};
 use moveos_types::h256::H256;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -15,6 +15,9 @@ pub struct StateOptions {
     pub show_display: bool,
     /// The state root of remote stateDB
     pub state_root: Option<H256View>,
+    /// Query the historical state after the transaction of the tx_order is executed.
+    /// It can not be set with the state_root at the same time.
+    pub at_tx_order: Option<StrView<u64>>,
 }
 
 impl StateOptions {
@@ -41,6 +44,11 @@ impl StateOptions {
         }
         self
     }
+
+    pub fn at_tx_order(mut self, tx_order: Option<u64>) -> Self {
+        self.at_tx_order = tx_order.map(StrView);
+        self
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, Eq, PartialEq, Default)]
@@ -82,6 +90,24 @@ impl TxOptions {
     }
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, Eq, PartialEq, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TraceOptions {
+    /// If true, the flamegraph of the gas usage is rendered in SVG format and returned in the response.
+    pub flamegraph: bool,
+}
+
+impl TraceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn flamegraph(mut self, flamegraph: bool) -> Self {
+        self.flamegraph = flamegraph;
+        self
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, Eq, PartialEq)]
 #[serde(rename_all = "camelCase", default)]
 pub struct QueryOptions {
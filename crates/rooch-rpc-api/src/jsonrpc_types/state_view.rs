@@ -8,13 +8,17 @@ use super::{
 };
 use anyhow::Result;
 use move_core_types::effects::Op;
+use move_core_types::u256::U256;
 use moveos_types::moveos_std::object::ObjectMeta;
 use moveos_types::state::{FieldKey, ObjectChange};
+use moveos_types::state_override::ObjectOverride;
+use moveos_types::state_proof::StateProof;
 use moveos_types::state_resolver::StateKV;
 use moveos_types::{
     moveos_std::object::{human_readable_flag, ObjectID},
     state::{AnnotatedState, ObjectState, StateChangeSet},
 };
+use rooch_types::framework::account_coin_store::AccountCoinStoreModule;
 use rooch_types::indexer::state::{IndexerStateID, ObjectStateFilter};
 use rooch_types::state::{StateChangeSetWithTxOrder, SyncStateFilter};
 use schemars::JsonSchema;
@@ -502,6 +506,34 @@ impl HumanReadableDisplay for ObjectStateView {
     }
 }
 
+/// Object state with the proof. Used as return type of `getStatesWithProof`.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct StateWithProofView {
+    pub state: Option<ObjectStateView>,
+    /// The BCS encoded `StateProof`, which chains the field proofs from the root object to the state.
+    pub proof: BytesView,
+}
+
+impl StateWithProofView {
+    pub fn new_from_state_proof(state_proof: StateProof) -> Result<Self> {
+        Ok(StateWithProofView {
+            state: state_proof.state().cloned().map(Into::into),
+            proof: bcs::to_bytes(&state_proof)?.into(),
+        })
+    }
+
+    pub fn state_proof(&self) -> Result<StateProof> {
+        Ok(bcs::from_bytes(&self.proof.0)?)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct StatesWithProofView {
+    /// The state root of the root object which the proofs are against.
+    pub state_root: H256View,
+    pub states: Vec<StateWithProofView>,
+}
+
 impl<T> HumanReadableDisplay for Vec<T>
 where
     T: HumanReadableDisplay,
@@ -575,3 +607,57 @@ impl From<StateChangeSetWithTxOrder> for StateChangeSetWithTxOrderView {
         }
     }
 }
+
+/// Override the state when dry running a transaction, the overrides are never written to the state.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StateOverrideView {
+    /// Override the object value, the fields of the object are kept.
+    Object {
+        object_id: ObjectIDView,
+        /// The owner of the object, keep the current owner if not set.
+        owner: Option<UnitedAddressView>,
+        /// The type of the object, it is required if the object does not exist.
+        object_type: Option<TypeTagView>,
+        /// The BCS bytes of the object value.
+        value: BytesView,
+    },
+    /// Override the coin balance of the account.
+    Balance {
+        owner: UnitedAddressView,
+        coin_type: StructTagView,
+        balance: StrView<U256>,
+    },
+    /// Replace the module byte codes, the package of the module must exist.
+    Module(BytesView),
+}
+
+impl TryFrom<StateOverrideView> for ObjectOverride {
+    type Error = anyhow::Error;
+
+    fn try_from(state_override: StateOverrideView) -> Result<Self> {
+        Ok(match state_override {
+            StateOverrideView::Object {
+                object_id,
+                owner,
+                object_type,
+                value,
+            } => ObjectOverride::new(
+                object_id.into(),
+                owner.map(|owner| owner.0.rooch_address.into()),
+                object_type.map(Into::into),
+                value.0,
+            ),
+            StateOverrideView::Balance {
+                owner,
+                coin_type,
+                balance,
+            } => AccountCoinStoreModule::balance_override(
+                owner.0.rooch_address.into(),
+                coin_type.into(),
+                balance.0,
+            )?,
+            StateOverrideView::Module(byte_codes) => ObjectOverride::module(byte_codes.0)?,
+        })
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::GasEstimateView;
+use move_core_types::u256::U256;
+use move_core_types::vm_status::KeptVMStatus;
+
+#[test]
+fn test_gas_estimate_view() {
+    let estimate = GasEstimateView::new(KeptVMStatus::Executed, 1001, 200, 0, 0, 1, None);
+    assert!(estimate.is_executed());
+    assert_eq!(estimate.gas_used.0, 1001);
+    assert_eq!(estimate.execution_gas_used.0, 801);
+    assert_eq!(estimate.storage_gas_used.0, 200);
+    assert_eq!(estimate.estimated_fee.0, U256::from(1001u64));
+    // 1001 + ceil(1001 * 20 / 100)
+    assert_eq!(estimate.suggested_max_gas_amount.0, 1202);
+
+    let estimate = GasEstimateView::new(KeptVMStatus::OutOfGas, u64::MAX, 0, 0, 0, 1, None);
+    assert!(!estimate.is_executed());
+    assert_eq!(estimate.suggested_max_gas_amount.0, u64::MAX);
+}
+
+#[test]
+fn test_gas_estimate_view_with_storage_and_gas_price() {
+    // The dry run charges the execution gas and the storage gas of the transaction size
+    let estimate = GasEstimateView::new(KeptVMStatus::Executed, 1000, 200, 60, 300, 3, None);
+    assert_eq!(estimate.execution_gas_used.0, 800);
+    assert_eq!(estimate.tx_size_storage_gas.0, 200);
+    assert_eq!(estimate.event_storage_gas.0, 60);
+    assert_eq!(estimate.change_set_storage_gas.0, 300);
+    assert_eq!(estimate.storage_gas_used.0, 560);
+    assert_eq!(estimate.gas_used.0, 1360);
+    assert_eq!(estimate.gas_price.0, 3);
+    assert_eq!(estimate.estimated_fee.0, U256::from(4080u64));
+    // 1360 + ceil(1360 * 20 / 100)
+    assert_eq!(estimate.suggested_max_gas_amount.0, 1632);
+}
@@ -2,4 +2,5 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod changeset_tests;
+mod gas_estimate_tests;
 mod str_view_tests;
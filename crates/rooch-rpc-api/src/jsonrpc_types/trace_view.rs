@@ -0,0 +1,97 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{
+    FunctionIdView, H256View, KeptVMStatusView, ObjectIDView, StateChangeSetView, StrView,
+    TypeTagView,
+};
+use crate::jsonrpc_types::event_view::TransactionEventView;
+use moveos_gas_profiling::log::{CallFrame, ExecutionGasEvent, FrameName};
+use moveos_types::move_types::FunctionId;
+use moveos_types::transaction::VMErrorInfo;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A call in the execution trace, the nested calls are in the execution order.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CallTraceView {
+    /// The called function, it is None for the root frame of a script, module bundle or batch transaction
+    pub function: Option<FunctionIdView>,
+    pub ty_args: Vec<TypeTagView>,
+    pub is_native: bool,
+    /// The gas used by the call, including the nested calls
+    pub gas_used: StrView<u64>,
+    pub calls: Vec<CallTraceView>,
+}
+
+impl From<CallFrame> for CallTraceView {
+    fn from(frame: CallFrame) -> Self {
+        let (function, ty_args) = match frame.name {
+            FrameName::Script => (None, vec![]),
+            FrameName::Function {
+                module_id,
+                name,
+                ty_args,
+            } => (Some(FunctionId::new(module_id, name)), ty_args),
+        };
+        let mut gas_used = 0u64;
+        let mut calls = vec![];
+        for event in frame.events {
+            match event {
+                ExecutionGasEvent::Loc(_) => {}
+                ExecutionGasEvent::Bytecode { cost, .. }
+                | ExecutionGasEvent::CreateTy { cost }
+                | ExecutionGasEvent::LoadResource { cost, .. } => {
+                    gas_used += u64::from(cost);
+                }
+                ExecutionGasEvent::Call(frame) => {
+                    let call = CallTraceView::from(frame);
+                    gas_used += call.gas_used.0;
+                    calls.push(call);
+                }
+                ExecutionGasEvent::CallNative {
+                    module_id,
+                    fn_name,
+                    ty_args,
+                    cost,
+                } => {
+                    gas_used += u64::from(cost);
+                    calls.push(CallTraceView {
+                        function: Some(FunctionId::new(module_id, fn_name).into()),
+                        ty_args: ty_args.into_iter().map(Into::into).collect(),
+                        is_native: true,
+                        gas_used: u64::from(cost).into(),
+                        calls: vec![],
+                    });
+                }
+            }
+        }
+        Self {
+            function: function.map(Into::into),
+            ty_args: ty_args.into_iter().map(Into::into).collect(),
+            is_native: false,
+            gas_used: gas_used.into(),
+            calls,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TransactionTraceView {
+    pub tx_hash: H256View,
+    pub tx_order: StrView<u64>,
+    /// The state root before the transaction is executed, the transaction is re-executed at this state root
+    pub pre_state_root: H256View,
+    pub status: KeptVMStatusView,
+    pub gas_used: StrView<u64>,
+    /// The call tree of the transaction, the gas of the system pre_execute and post_execute functions is not charged
+    pub call_trace: CallTraceView,
+    pub events: Vec<TransactionEventView>,
+    /// The objects read from the state during the execution
+    pub read_set: Vec<ObjectIDView>,
+    /// The state changes written by the transaction
+    pub changeset: StateChangeSetView,
+    pub vm_error_info: Option<VMErrorInfo>,
+    /// The flamegraph of the gas usage in SVG format, only returned if the flamegraph option is set
+    pub flamegraph: Option<String>,
+}
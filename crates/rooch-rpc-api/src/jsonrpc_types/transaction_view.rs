@@ -6,7 +6,9 @@ use crate::jsonrpc_types::{
     H256View, TransactionExecutionInfoView, TransactionSequenceInfoView, TransactionView,
     UnitedAddressView,
 };
+use accumulator::proof::AccumulatorProof;
 use bitcoin::hashes::Hash;
+use rooch_types::block::{Block, TransactionAccumulatorProof};
 use rooch_types::indexer::transaction::TransactionFilter;
 use rooch_types::transaction::{
     L1Block, L1Transaction, LedgerTransaction, LedgerTxData, TransactionWithInfo,
@@ -184,3 +186,76 @@ impl From<TransactionFilterView> for TransactionFilter {
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BlockView {
+    pub block_number: StrView<u128>,
+    pub batch_size: StrView<u64>,
+    pub batch_hash: H256View,
+    pub prev_tx_accumulator_root: H256View,
+    pub tx_accumulator_root: H256View,
+    pub state_root: H256View,
+}
+
+impl From<Block> for BlockView {
+    fn from(block: Block) -> Self {
+        Self {
+            block_number: block.block_number.into(),
+            batch_size: block.batch_size.into(),
+            batch_hash: block.batch_hash.into(),
+            prev_tx_accumulator_root: block.prev_tx_accumulator_root.into(),
+            tx_accumulator_root: block.tx_accumulator_root.into(),
+            state_root: block.state_root.into(),
+        }
+    }
+}
+
+impl From<BlockView> for Block {
+    fn from(block: BlockView) -> Self {
+        Block::new(
+            block.block_number.0,
+            block.batch_size.0,
+            block.batch_hash.0,
+            block.prev_tx_accumulator_root.0,
+            block.tx_accumulator_root.0,
+            block.state_root.0,
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TransactionAccumulatorProofView {
+    pub tx_order: StrView<u64>,
+    pub tx_hash: H256View,
+    /// The block which the transaction was proposed in
+    pub block: BlockView,
+    /// The siblings of the accumulator proof, from the leaf level to the root level
+    pub siblings: Vec<H256View>,
+}
+
+impl From<TransactionAccumulatorProof> for TransactionAccumulatorProofView {
+    fn from(proof: TransactionAccumulatorProof) -> Self {
+        Self {
+            tx_order: proof.tx_order.into(),
+            tx_hash: proof.tx_hash.into(),
+            block: proof.block.into(),
+            siblings: proof
+                .proof
+                .siblings
+                .into_iter()
+                .map(H256View::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<TransactionAccumulatorProofView> for TransactionAccumulatorProof {
+    fn from(proof: TransactionAccumulatorProofView) -> Self {
+        TransactionAccumulatorProof::new(
+            proof.tx_order.0,
+            proof.tx_hash.0,
+            proof.block.into(),
+            AccumulatorProof::new(proof.siblings.into_iter().map(|hash| hash.0).collect()),
+        )
+    }
+}
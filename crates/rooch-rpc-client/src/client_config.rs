@@ -24,6 +24,10 @@ pub struct ClientConfig {
     pub active_address: Option<RoochAddress>,
     pub envs: Vec<Env>,
     pub active_env: Option<String>,
+    /// The endpoint of the remote signer, such as `unix:///path/to/signer.sock`, `tcp://127.0.0.1:6780` or `http://127.0.0.1:6780`.
+    /// If set, the transactions are signed by the remote signer instead of the local keystore.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_signer: Option<String>,
 }
 
 impl ClientConfig {
@@ -33,6 +37,7 @@ impl ClientConfig {
             active_address: None,
             envs: vec![],
             active_env: None,
+            remote_signer: None,
         }
     }
 
@@ -154,6 +159,9 @@ impl Display for ClientConfig {
         let mut writer = String::new();
 
         writeln!(writer, "Keystore path : {:?}", self.keystore_path)?;
+        if let Some(remote_signer) = &self.remote_signer {
+            writeln!(writer, "Remote signer : {}", remote_signer)?;
+        }
         write!(writer, "Active address: ")?;
         match self.active_address {
             Some(r) => writeln!(writer, "{}", r)?,
@@ -27,6 +27,8 @@ use tokio::runtime::Handle;
 
 pub mod client_config;
 pub mod rooch_client;
+pub mod state_proof;
+pub mod tx_proof;
 pub mod wallet_context;
 
 pub struct ClientBuilder {
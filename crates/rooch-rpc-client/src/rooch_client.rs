@@ -1,6 +1,8 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::state_proof::verify_states_with_proof;
+use crate::tx_proof::verify_transaction_accumulator_proof;
 use anyhow::{Ok, Result};
 use bitcoincore_rpc::RawTx;
 use jsonrpsee::http_client::HttpClient;
@@ -15,7 +17,9 @@ use rooch_rpc_api::api::btc_api::BtcAPIClient;
 use rooch_rpc_api::api::rooch_api::RoochAPIClient;
 use rooch_rpc_api::jsonrpc_types::btc::ord::{InscriptionFilterView, InscriptionObjectView};
 use rooch_rpc_api::jsonrpc_types::btc::utxo::{UTXOFilterView, UTXOObjectView};
-use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionFilterView;
+use rooch_rpc_api::jsonrpc_types::transaction_view::{
+    TransactionAccumulatorProofView, TransactionFilterView,
+};
 use rooch_rpc_api::jsonrpc_types::{
     account_view::BalanceInfoView, transaction_view::TransactionWithInfoView, InscriptionPageView,
     Status, UTXOPageView,
@@ -23,18 +27,27 @@ use rooch_rpc_api::jsonrpc_types::{
 use rooch_rpc_api::jsonrpc_types::{
     AccessPathView, AnnotatedFunctionResultView, BalanceInfoPageView, BytesView, EventOptions,
     EventPageView, FieldKeyView, ObjectIDVecView, ObjectIDView, RoochAddressView, StateOptions,
-    StatePageView, StructTagView,
+    StatePageView, StatesWithProofView, StructTagView,
+};
+use rooch_rpc_api::jsonrpc_types::{
+    DryRunTransactionResponseView, ExecuteTransactionResponseView, GasEstimateView,
+    ObjectStateView, StateOverrideView,
 };
-use rooch_rpc_api::jsonrpc_types::{ExecuteTransactionResponseView, ObjectStateView};
 use rooch_rpc_api::jsonrpc_types::{
     IndexerObjectStatePageView, ObjectStateFilterView, QueryOptions,
 };
-use rooch_rpc_api::jsonrpc_types::{TransactionWithInfoPageView, TxOptions};
+use rooch_rpc_api::jsonrpc_types::{
+    TraceOptions, TransactionTraceView, TransactionWithInfoPageView, TxOptions,
+};
 use rooch_types::address::BitcoinAddress;
 use rooch_types::bitcoin::multisign_account::MultisignAccountInfo;
+use rooch_types::block::{Block, TransactionAccumulatorProof};
 use rooch_types::framework::address_mapping::RoochToBitcoinAddressMapping;
 use rooch_types::indexer::state::IndexerStateID;
-use rooch_types::{address::RoochAddress, transaction::rooch::RoochTransaction};
+use rooch_types::{
+    address::RoochAddress,
+    transaction::rooch::{RoochTransaction, RoochTransactionData},
+};
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -67,12 +80,50 @@ impl RoochRpcClient {
             .map_err(|e| anyhow::anyhow!(e))
     }
 
+    /// Dry run the transaction with the state overrides, the overrides are only applied to this dry run
+    pub async fn dry_run(
+        &self,
+        tx_data: &RoochTransactionData,
+        state_overrides: Option<Vec<StateOverrideView>>,
+    ) -> Result<DryRunTransactionResponseView> {
+        let tx_payload = bcs::to_bytes(tx_data)?;
+        self.http
+            .dry_run(tx_payload.into(), state_overrides)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    /// Estimate the gas of the transaction, the `max_gas_amount` of the transaction data is ignored.
+    /// The gas is paid by the `gas_payer` if it is the sponsored transaction, otherwise by the sender.
+    pub async fn estimate_gas(
+        &self,
+        tx_data: &RoochTransactionData,
+        gas_payer: Option<RoochAddress>,
+    ) -> Result<GasEstimateView> {
+        let tx_payload = bcs::to_bytes(tx_data)?;
+        self.http
+            .estimate_gas(tx_payload.into(), gas_payer.map(Into::into))
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
     pub async fn execute_view_function(
         &self,
         function_call: FunctionCall,
+    ) -> Result<AnnotatedFunctionResultView> {
+        self.execute_view_function_with_option(function_call, None)
+            .await
+    }
+
+    /// Execute the view function with the StateOptions,
+    /// the `at_tx_order` of the options could be used to execute it at the historical state.
+    pub async fn execute_view_function_with_option(
+        &self,
+        function_call: FunctionCall,
+        state_option: Option<StateOptions>,
     ) -> Result<AnnotatedFunctionResultView> {
         self.http
-            .execute_view_function(function_call.into())
+            .execute_view_function(function_call.into(), state_option)
             .await
             .map_err(|e| anyhow::anyhow!(e))
     }
@@ -91,6 +142,55 @@ impl RoochRpcClient {
             .await?)
     }
 
+    pub async fn get_states_with_proof(
+        &self,
+        access_path: AccessPath,
+        state_root: Option<H256>,
+    ) -> Result<StatesWithProofView> {
+        Ok(self
+            .http
+            .get_states_with_proof(access_path.into(), state_root.map(Into::into))
+            .await?)
+    }
+
+    /// Get the states and verify them with the proofs against the trusted state root,
+    /// the RPC node does not need to be trusted.
+    pub async fn get_verified_states(
+        &self,
+        access_path: AccessPath,
+        trusted_state_root: H256,
+    ) -> Result<Vec<Option<ObjectState>>> {
+        let states_with_proof = self
+            .get_states_with_proof(access_path.clone(), Some(trusted_state_root))
+            .await?;
+        verify_states_with_proof(trusted_state_root, access_path, &states_with_proof)
+    }
+
+    pub async fn get_transaction_accumulator_proof(
+        &self,
+        tx_hash: H256,
+    ) -> Result<Option<TransactionAccumulatorProofView>> {
+        Ok(self
+            .http
+            .get_transaction_accumulator_proof(tx_hash.into())
+            .await?)
+    }
+
+    /// Get the tx accumulator proof and verify it against the trusted block,
+    /// return None if the transaction has not been proposed yet.
+    pub async fn get_verified_transaction_accumulator_proof(
+        &self,
+        tx_hash: H256,
+        trusted_block: &Block,
+    ) -> Result<Option<TransactionAccumulatorProof>> {
+        self.get_transaction_accumulator_proof(tx_hash)
+            .await?
+            .map(|proof_view| {
+                verify_transaction_accumulator_proof(trusted_block, tx_hash, proof_view)
+            })
+            .transpose()
+    }
+
     pub async fn get_decoded_states(
         &self,
         access_path: AccessPath,
@@ -105,6 +205,17 @@ impl RoochRpcClient {
             .await?)
     }
 
+    pub async fn get_states_with_option(
+        &self,
+        access_path: AccessPath,
+        state_option: StateOptions,
+    ) -> Result<Vec<Option<ObjectStateView>>> {
+        Ok(self
+            .http
+            .get_states(access_path.into(), Some(state_option))
+            .await?)
+    }
+
     pub async fn get_decoded_states_with_display(
         &self,
         access_path: AccessPath,
@@ -162,6 +273,18 @@ impl RoochRpcClient {
             .await?)
     }
 
+    /// Trace the transaction by re-executing it at the state root before it was executed
+    pub async fn trace_transaction(
+        &self,
+        tx_hash: H256,
+        trace_option: Option<TraceOptions>,
+    ) -> Result<TransactionTraceView> {
+        Ok(self
+            .http
+            .trace_transaction(tx_hash.into(), trace_option)
+            .await?)
+    }
+
     pub async fn get_sequence_number(&self, sender: RoochAddress) -> Result<u64> {
         Ok(self
             .get_states(
@@ -0,0 +1,49 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{ensure, Result};
+use moveos_types::access_path::AccessPath;
+use moveos_types::h256::H256;
+use moveos_types::state::ObjectState;
+use rooch_rpc_api::jsonrpc_types::StatesWithProofView;
+
+/// Verify the states returned by `rooch_getStatesWithProof` against a trusted state root,
+/// so the caller does not need to trust the RPC node.
+/// Return the verified states in the order of the fields of the `access_path`.
+pub fn verify_states_with_proof(
+    trusted_state_root: H256,
+    access_path: AccessPath,
+    states_with_proof: &StatesWithProofView,
+) -> Result<Vec<Option<ObjectState>>> {
+    ensure!(
+        states_with_proof.state_root.0 == trusted_state_root,
+        "State root mismatch, trusted: {:?}, returned: {:?}",
+        trusted_state_root,
+        states_with_proof.state_root.0
+    );
+    let fields = access_path.into_state_query().into_fields_query()?;
+    ensure!(
+        fields.len() == states_with_proof.states.len(),
+        "States length mismatch, expected: {}, returned: {}",
+        fields.len(),
+        states_with_proof.states.len()
+    );
+
+    fields
+        .into_iter()
+        .zip(states_with_proof.states.iter())
+        .map(|((object_id, field_key), state_with_proof)| {
+            let state_proof = state_with_proof.state_proof()?;
+            let state = state_proof.verify(trusted_state_root, &object_id, field_key)?;
+            // The returned state view should be the same as the proved state
+            let returned_state = state_with_proof.state.clone().map(ObjectState::from);
+            ensure!(
+                returned_state == state,
+                "The returned state of field {} in object {} does not match the proof",
+                field_key,
+                object_id
+            );
+            Ok(state)
+        })
+        .collect()
+}
@@ -0,0 +1,26 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{ensure, Result};
+use moveos_types::h256::H256;
+use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionAccumulatorProofView;
+use rooch_types::block::{Block, TransactionAccumulatorProof};
+
+/// Verify the tx accumulator proof returned by `rooch_getTransactionAccumulatorProof`
+/// against a trusted block, such as the block committed by the proposer,
+/// so the caller does not need to trust the RPC node.
+pub fn verify_transaction_accumulator_proof(
+    trusted_block: &Block,
+    tx_hash: H256,
+    proof_view: TransactionAccumulatorProofView,
+) -> Result<TransactionAccumulatorProof> {
+    let proof = TransactionAccumulatorProof::from(proof_view);
+    ensure!(
+        proof.tx_hash == tx_hash,
+        "Tx hash mismatch, expected: {:?}, returned: {:?}",
+        tx_hash,
+        proof.tx_hash
+    );
+    proof.verify(trusted_block)?;
+    Ok(proof)
+}
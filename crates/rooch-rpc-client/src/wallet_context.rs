@@ -15,6 +15,7 @@ use rooch_config::config::{Config, PersistedConfig};
 use rooch_config::{rooch_config_dir, ROOCH_CLIENT_CONFIG};
 use rooch_key::keystore::account_keystore::AccountKeystore;
 use rooch_key::keystore::file_keystore::FileBasedKeystore;
+use rooch_key::keystore::remote_keystore::{RemoteKeystore, RemoteSignerEndpoint};
 use rooch_key::keystore::Keystore;
 use rooch_rpc_api::jsonrpc_types::{ExecuteTransactionResponseView, KeptVMStatusView, TxOptions};
 use rooch_types::address::RoochAddress;
@@ -28,6 +29,7 @@ use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
 use rooch_types::{addresses, crypto};
 use std::collections::BTreeMap;
 use std::path::PathBuf;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::RwLock;
@@ -57,10 +59,18 @@ impl WalletContext {
 
         let mut client_config = client_config.persisted(&client_config_path);
 
-        let keystore_result = FileBasedKeystore::load(&client_config.keystore_path);
-        let keystore = match keystore_result {
-            Ok(file_keystore) => Keystore::File(file_keystore),
-            Err(error) => return Err(error),
+        let keystore = match &client_config.remote_signer {
+            Some(remote_signer) => {
+                let endpoint = RemoteSignerEndpoint::from_str(remote_signer)?;
+                Keystore::Remote(RemoteKeystore::new(endpoint))
+            }
+            None => {
+                let keystore_result = FileBasedKeystore::load(&client_config.keystore_path);
+                match keystore_result {
+                    Ok(file_keystore) => Keystore::File(file_keystore),
+                    Err(error) => return Err(error),
+                }
+            }
         };
 
         let mut address_mapping = BTreeMap::new();
@@ -68,11 +78,11 @@ impl WalletContext {
 
         //TODO support account name alias name.
         if let Some(active_address) = &client_config.active_address {
-            let active_address = if !keystore.contains_address(active_address) {
+            let active_address = if !keystore.contains_address(active_address)? {
                 //The active address is not in the keystore, maybe the user reset the keystore.
                 //We auto change the active address to the first address in the keystore.
                 let first_address = keystore
-                    .addresses()
+                    .addresses()?
                     .pop()
                     .ok_or_else(|| anyhow!("No address in the keystore"))?;
                 info!("The active address {} is not in the keystore, auto change the active address to the first address in the keystore: {}", active_address, first_address);
@@ -214,17 +224,47 @@ impl WalletContext {
                 .map_err(RoochError::from)?,
         );
         tracing::debug!("use sequence_number: {}", sequence_number);
-        //TODO max gas amount from cli option or dry run estimate
-        let tx_data = RoochTransactionData::new(
+        let mut tx_data = RoochTransactionData::new(
             sender,
             sequence_number,
             chain_id,
             max_gas_amount.unwrap_or(GasScheduleConfig::CLI_DEFAULT_MAX_GAS_AMOUNT),
             action,
         );
+        if max_gas_amount.is_none() {
+            tx_data.max_gas_amount = Self::estimate_max_gas_amount(&client, &tx_data).await;
+        }
         Ok(tx_data)
     }
 
+    /// Estimate the max gas amount via the `rooch_estimateGas` RPC.
+    /// Fallback to the CLI default if the estimation fails, so the execution reports the real error.
+    async fn estimate_max_gas_amount(client: &Client, tx_data: &RoochTransactionData) -> u64 {
+        match client.rooch.estimate_gas(tx_data, None).await {
+            Ok(estimate) if estimate.is_executed() => {
+                debug!(
+                    "estimated gas_used: {}, use max_gas_amount: {}",
+                    estimate.gas_used, estimate.suggested_max_gas_amount
+                );
+                estimate.suggested_max_gas_amount.0
+            }
+            Ok(estimate) => {
+                debug!(
+                    "estimate gas failed with status {:?}, use the default max_gas_amount",
+                    estimate.status
+                );
+                GasScheduleConfig::CLI_DEFAULT_MAX_GAS_AMOUNT
+            }
+            Err(e) => {
+                debug!(
+                    "estimate gas error: {:?}, use the default max_gas_amount",
+                    e
+                );
+                GasScheduleConfig::CLI_DEFAULT_MAX_GAS_AMOUNT
+            }
+        }
+    }
+
     pub fn generate_session_key(&mut self, address: &RoochAddress) -> Result<AuthenticationKey> {
         self.keystore
             .generate_session_key(address, self.password.clone())
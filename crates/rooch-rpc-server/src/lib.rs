@@ -9,6 +9,8 @@ use crate::service::blocklist::{BlockListLayer, BlocklistConfig};
 use crate::service::error::ErrorHandler;
 use crate::service::metrics::ServiceMetrics;
 use crate::service::rpc_service::RpcService;
+use crate::service::subscription_service::SubscriptionService;
+use crate::service::upstream::UpstreamBlockSource;
 use anyhow::{ensure, Error, Result};
 use axum::http::{HeaderValue, Method};
 use bitcoin_client::actor::client::BitcoinClientConfig;
@@ -20,10 +22,15 @@ use moveos_eventbus::bus::EventBus;
 use raw_store::errors::RawStoreError;
 use rooch_config::da_config::derive_namespace_from_genesis;
 use rooch_config::server_config::ServerConfig;
-use rooch_config::settings::PROPOSER_CHECK_INTERVAL;
+use rooch_config::settings::{
+    DERIVATION_CHECK_INTERVAL, PROPOSER_CHECK_INTERVAL, STATE_PRUNE_INTERVAL,
+};
+use rooch_config::store_config::StatePruneMode;
 use rooch_config::{RoochOpt, ServerOpt};
 use rooch_da::actor::server::DAServerActor;
+use rooch_da::backend::DABackends;
 use rooch_da::proxy::DAServerProxy;
+use rooch_db::pruner::StatePruner;
 use rooch_db::RoochDB;
 use rooch_event::actor::EventActor;
 use rooch_executor::actor::executor::ExecutorActor;
@@ -33,6 +40,8 @@ use rooch_genesis::RoochGenesis;
 use rooch_indexer::actor::indexer::IndexerActor;
 use rooch_indexer::actor::reader_indexer::IndexerReaderActor;
 use rooch_indexer::proxy::IndexerProxy;
+use rooch_pipeline_processor::actor::derivation::DerivationActor;
+use rooch_pipeline_processor::actor::messages::DeriveTick;
 use rooch_pipeline_processor::actor::processor::PipelineProcessorActor;
 use rooch_pipeline_processor::proxy::PipelineProcessorProxy;
 use rooch_proposer::actor::messages::ProposeBlock;
@@ -231,7 +240,9 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         let rooch_dao_address = rooch_dao_bitcoin_address.to_rooch_address();
         println!("Rooch DAO address: {:?}", rooch_dao_address);
         println!("Rooch DAO Bitcoin address: {}", rooch_dao_bitcoin_address);
-    } else {
+    } else if !service_status.is_follower_mode() {
+        // the follower node verifies the batches with the sequencer in genesis config,
+        // so it does not need the sequencer keypair of the network
         ensure!(
             network.genesis_config.sequencer_account == sequencer_bitcoin_address,
             "Sequencer({:?}) in genesis config is not equal to sequencer({:?}) in cli config",
@@ -251,6 +262,41 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         root.size()
     );
 
+    if let StatePruneMode::KeepRecent(keep_recent) = store_config.state_prune_mode() {
+        let pruner = StatePruner::new(rooch_db.clone(), keep_recent, &prometheus_registry);
+        let mut pruner_rx = shutdown_tx.subscribe();
+        info!(
+            "State pruner started, keep the states of the last {} transactions",
+            keep_recent
+        );
+        tokio::spawn(async move {
+            let mut tick = tokio::time::interval(Duration::from_secs(STATE_PRUNE_INTERVAL));
+            loop {
+                tokio::select! {
+                    _ = pruner_rx.recv() => {
+                        info!("State pruner received shutdown signal, stopping.");
+                        break;
+                    }
+                    _ = tick.tick() => {
+                        let pruner = pruner.clone();
+                        match tokio::task::spawn_blocking(move || pruner.prune()).await {
+                            Ok(Ok((pruned_tx_order, deleted_nodes))) => {
+                                if deleted_nodes > 0 {
+                                    info!(
+                                        "State pruner pruned the states before tx_order {}, deleted nodes: {}",
+                                        pruned_tx_order, deleted_nodes
+                                    );
+                                }
+                            }
+                            Ok(Err(e)) => error!("State pruner failed: {:?}", e),
+                            Err(e) => error!("State pruner task failed: {:?}", e),
+                        }
+                    }
+                }
+            }
+        });
+    }
+
     let event_bus = EventBus::new();
     let event_actor = EventActor::new(event_bus.clone());
     let event_actor_ref = event_actor
@@ -301,16 +347,23 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
     // Init DA
     let genesis_hash = genesis.genesis_hash();
     let genesis_namespace = derive_namespace_from_genesis(genesis_hash);
-    let last_tx_order = sequencer_proxy.get_sequencer_order().await?;
-    let (da_issues, da_fixed) = rooch_store.try_repair_da_meta(last_tx_order, false)?;
-    info!("DA meta issues: {:?}, fixed: {:?}", da_issues, da_fixed);
-    let da_config = opt.da_config().clone();
+    let mut da_config = opt.da_config().clone();
+    // In follower mode, the DA backends are used for deriving the ledger rather than submitting,
+    // and the DA meta is written by the DerivationActor.
+    let derivation_da_backend = if service_status.is_follower_mode() {
+        da_config.da_backend.take()
+    } else {
+        let last_tx_order = sequencer_proxy.get_sequencer_order().await?;
+        let (da_issues, da_fixed) = rooch_store.try_repair_da_meta(last_tx_order, false)?;
+        info!("DA meta issues: {:?}, fixed: {:?}", da_issues, da_fixed);
+        None
+    };
     let da_proxy = DAServerProxy::new(
         DAServerActor::new(
             da_config,
             sequencer_keypair.copy(),
             rooch_store.clone(),
-            genesis_namespace,
+            genesis_namespace.clone(),
             shutdown_tx.subscribe(),
         )
         .await?
@@ -326,7 +379,7 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
     let proposer = ProposerActor::new(
         proposer_keypair,
         moveos_store.clone(),
-        rooch_store,
+        rooch_store.clone(),
         &prometheus_registry,
         opt.proposer.clone(),
     )?
@@ -364,7 +417,10 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
             btc_rpc_password: config.btc_rpc_password.clone(),
             local_block_store_dir: Some(config.btc_reorg_aware_block_store_dir.clone()), // this client will be used for startup processing, may need reorg blocks
         });
-    let bitcoin_client_proxy = if service_status.is_active() && bitcoin_client_config.is_some() {
+    // The follower node needs bitcoin client to fetch the L1 block body of derived l1_block_tx
+    let bitcoin_client_proxy = if (service_status.is_active() || service_status.is_follower_mode())
+        && bitcoin_client_config.is_some()
+    {
         let bitcoin_client = bitcoin_client_config.unwrap().build()?;
         let bitcoin_client_actor_ref = bitcoin_client
             .into_actor(Some("bitcoin_client_for_rpc_service"), &actor_system)
@@ -396,6 +452,32 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         .await?;
     let processor_proxy = PipelineProcessorProxy::new(processor_actor.into());
 
+    if service_status.is_follower_mode() {
+        let upstream_rpc_url = opt
+            .upstream_rpc_url
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("--upstream-rpc-url is required in follower mode"))?;
+        let block_source = UpstreamBlockSource::new(upstream_rpc_url).await?;
+        let da_backends = DABackends::initialize(derivation_da_backend, genesis_namespace).await?;
+        let derivation = DerivationActor::new(
+            da_backends,
+            processor_proxy.clone(),
+            sequencer_proxy.clone(),
+            executor_proxy.clone(),
+            rooch_store,
+            network.genesis_config.sequencer_account.clone(),
+            Arc::new(block_source),
+        )?
+        .into_actor(Some("Derivation"), &actor_system)
+        .await?;
+        let derivation_timer = Timer::start(
+            derivation,
+            Duration::from_secs(DERIVATION_CHECK_INTERVAL),
+            DeriveTick {},
+        );
+        timers.push(derivation_timer);
+    }
+
     let ethereum_relayer_config = opt.ethereum_relayer_config();
 
     if service_status.is_active()
@@ -431,6 +513,7 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         da_proxy,
     );
     let aggregate_service = AggregateService::new(rpc_service.clone());
+    let subscription_service = SubscriptionService::new(&event_bus)?;
 
     let acl = match env::var("ACCESS_CONTROL_ALLOW_ORIGIN") {
         Ok(value) => {
@@ -515,6 +598,7 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
     rpc_module_builder.register_module(RoochServer::new(
         rpc_service.clone(),
         aggregate_service.clone(),
+        subscription_service,
     ))?;
     rpc_module_builder.register_module(BtcServer::new(rpc_service.clone()).await?)?;
     rpc_module_builder
@@ -3,8 +3,12 @@
 
 use crate::service::aggregate_service::AggregateService;
 use crate::service::rpc_service::RpcService;
+use crate::service::subscription_service::SubscriptionService;
 use anyhow::Result;
-use jsonrpsee::{core::async_trait, RpcModule};
+use jsonrpsee::core::SubscriptionResult;
+use jsonrpsee::{
+    core::async_trait, PendingSubscriptionSink, RpcModule, SubscriptionMessage, SubscriptionSink,
+};
 use move_core_types::{
     account_address::AccountAddress, identifier::Identifier, language_storage::ModuleId,
 };
@@ -14,20 +18,25 @@ use moveos_types::{
     move_std::string::MoveString,
     moveos_std::{move_module::MoveModule, object::ObjectID},
     state::{AnnotatedState, FieldKey},
+    state_override::ObjectOverride,
 };
+use rooch_event::event::TransactionExecutedEvent;
 use rooch_rpc_api::api::MAX_INTERNAL_LIMIT_USIZE;
 use rooch_rpc_api::jsonrpc_types::field_view::FieldFilterView;
 use rooch_rpc_api::jsonrpc_types::{
     account_view::BalanceInfoView,
     event_view::{EventFilterView, EventView, IndexerEventIDView, IndexerEventView},
-    transaction_view::{TransactionFilterView, TransactionWithInfoView},
+    transaction_view::{
+        TransactionAccumulatorProofView, TransactionFilterView, TransactionWithInfoView,
+    },
     AccessPathView, BalanceInfoPageView, DryRunTransactionResponseView, EventOptions,
-    EventPageView, ExecuteTransactionResponseView, FieldPageView, FunctionCallView, H256View,
-    IndexerEventPageView, IndexerObjectStatePageView, IndexerStateIDView, ModuleABIView,
-    ObjectIDVecView, ObjectStateFilterView, ObjectStateView, QueryOptions,
-    RawTransactionOutputView, RoochAddressView, StateChangeSetPageView,
-    StateChangeSetWithTxOrderView, StateKVView, StateOptions, StatePageView, StrView,
-    StructTagView, SyncStateFilterView, TransactionWithInfoPageView, TxOptions, UnitedAddressView,
+    EventPageView, ExecuteTransactionResponseView, FieldPageView, FunctionCallView,
+    GasEstimateView, H256View, IndexerEventPageView, IndexerObjectStatePageView,
+    IndexerStateIDView, ModuleABIView, ObjectIDVecView, ObjectStateFilterView, ObjectStateView,
+    QueryOptions, RawTransactionOutputView, RoochAddressView, StateChangeSetPageView,
+    StateChangeSetWithTxOrderView, StateKVView, StateOptions, StateOverrideView, StatePageView,
+    StateWithProofView, StatesWithProofView, StrView, StructTagView, SyncStateFilterView,
+    TraceOptions, TransactionTraceView, TransactionWithInfoPageView, TxOptions, UnitedAddressView,
 };
 use rooch_rpc_api::jsonrpc_types::{
     repair_view::{RepairIndexerParamsView, RepairIndexerTypeView},
@@ -42,23 +51,141 @@ use rooch_rpc_api::{
     jsonrpc_types::BytesView,
     RpcError, RpcResult,
 };
+use rooch_types::indexer::event::{EventFilter, IndexerEvent};
 use rooch_types::indexer::state::{IndexerStateID, ObjectStateType};
+use rooch_types::indexer::transaction::{IndexerTransaction, TransactionFilter};
+use rooch_types::indexer::Filter;
 use rooch_types::transaction::{RoochTransaction, RoochTransactionData, TransactionWithInfo};
 use std::cmp::{max, min};
 use std::str::FromStr;
-use tracing::{debug, info};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tracing::{debug, info, warn};
 
+#[derive(Clone)]
 pub struct RoochServer {
     rpc_service: RpcService,
     aggregate_service: AggregateService,
+    subscription_service: SubscriptionService,
 }
 
 impl RoochServer {
-    pub fn new(rpc_service: RpcService, aggregate_service: AggregateService) -> Self {
+    pub fn new(
+        rpc_service: RpcService,
+        aggregate_service: AggregateService,
+        subscription_service: SubscriptionService,
+    ) -> Self {
         Self {
             rpc_service,
             aggregate_service,
+            subscription_service,
+        }
+    }
+
+    /// Resolve the state root of the StateOptions, the `at_tx_order` is resolved to the
+    /// state root after the transaction is executed.
+    async fn resolve_state_root(&self, state_option: &StateOptions) -> Result<Option<H256>> {
+        self.rpc_service
+            .resolve_state_root(
+                state_option.state_root.map(|h256_view| h256_view.0),
+                state_option.at_tx_order.map(|tx_order| tx_order.0),
+            )
+            .await
+    }
+
+    /// Receive the next executed transaction, return None if the subscription is closed.
+    async fn recv_executed_transaction(
+        sink: &SubscriptionSink,
+        rx: &mut broadcast::Receiver<TransactionExecutedEvent>,
+    ) -> Option<TransactionExecutedEvent> {
+        loop {
+            let result = tokio::select! {
+                _ = sink.closed() => return None,
+                result = rx.recv() => result,
+            };
+            match result {
+                Ok(event) => return Some(event),
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!(
+                        "Subscription {:?} lagged behind, skipped {} transactions",
+                        sink.subscription_id(),
+                        skipped
+                    );
+                }
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+
+    async fn pipe_transactions(
+        self,
+        sink: SubscriptionSink,
+        mut rx: broadcast::Receiver<TransactionExecutedEvent>,
+        filter: TransactionFilter,
+    ) -> Result<()> {
+        while let Some(event) = Self::recv_executed_transaction(&sink, &mut rx).await {
+            let indexer_transaction = IndexerTransaction::new(
+                event.ledger_transaction.clone(),
+                event.execution_info.clone(),
+                event.move_action,
+                event.tx_context,
+            )?;
+            if !filter.matches(&indexer_transaction) {
+                continue;
+            }
+            let tx = TransactionWithInfo::new(event.ledger_transaction, event.execution_info)?;
+            for view in self.transactions_to_view(vec![tx]).await? {
+                let message = SubscriptionMessage::from_json(&view)?;
+                if sink.send(message).await.is_err() {
+                    return Ok(());
+                }
+            }
         }
+        Ok(())
+    }
+
+    async fn pipe_events(
+        self,
+        sink: SubscriptionSink,
+        mut rx: broadcast::Receiver<TransactionExecutedEvent>,
+        filter: EventFilter,
+        decode: bool,
+    ) -> Result<()> {
+        while let Some(event) = Self::recv_executed_transaction(&sink, &mut rx).await {
+            let TransactionExecutedEvent {
+                ledger_transaction,
+                tx_context,
+                events,
+                ..
+            } = event;
+            let indexer_events = events
+                .into_iter()
+                .map(|e| IndexerEvent::new(e, ledger_transaction.clone(), tx_context.clone()))
+                .filter(|e| filter.matches(e))
+                .collect::<Vec<_>>();
+            if indexer_events.is_empty() {
+                continue;
+            }
+            let data = if decode {
+                self.rpc_service
+                    .annotate_indexer_events(indexer_events)
+                    .await?
+                    .into_iter()
+                    .map(IndexerEventView::from)
+                    .collect::<Vec<_>>()
+            } else {
+                indexer_events
+                    .into_iter()
+                    .map(IndexerEventView::from)
+                    .collect::<Vec<_>>()
+            };
+            for view in data {
+                let message = SubscriptionMessage::from_json(&view)?;
+                if sink.send(message).await.is_err() {
+                    return Ok(());
+                }
+            }
+        }
+        Ok(())
     }
 
     async fn transactions_to_view(
@@ -168,9 +295,18 @@ impl RoochAPIServer for RoochServer {
         Ok(result)
     }
 
-    async fn dry_run(&self, payload: BytesView) -> RpcResult<DryRunTransactionResponseView> {
+    async fn dry_run(
+        &self,
+        payload: BytesView,
+        state_overrides: Option<Vec<StateOverrideView>>,
+    ) -> RpcResult<DryRunTransactionResponseView> {
         let tx = bcs::from_bytes::<RoochTransactionData>(&payload.0)?;
-        let tx_result = self.rpc_service.dry_run_tx(tx).await?;
+        let state_overrides = state_overrides
+            .unwrap_or_default()
+            .into_iter()
+            .map(ObjectOverride::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        let tx_result = self.rpc_service.dry_run_tx(tx, state_overrides).await?;
         let raw_output = tx_result.raw_output;
         let raw_output_view = RawTransactionOutputView {
             status: raw_output.status.into(),
@@ -184,13 +320,44 @@ impl RoochAPIServer for RoochServer {
         Ok(tx_response)
     }
 
+    async fn estimate_gas(
+        &self,
+        payload: BytesView,
+        gas_payer: Option<UnitedAddressView>,
+    ) -> RpcResult<GasEstimateView> {
+        let tx = bcs::from_bytes::<RoochTransactionData>(&payload.0)?;
+        let result = self
+            .rpc_service
+            .estimate_gas(tx, gas_payer.map(Into::into))
+            .await?;
+        let dry_run_result = result.dry_run_result;
+        Ok(GasEstimateView::new(
+            dry_run_result.raw_output.status,
+            dry_run_result.raw_output.gas_used,
+            dry_run_result.storage_gas.io_write_gas,
+            dry_run_result.storage_gas.event_gas,
+            dry_run_result.storage_gas.change_set_gas,
+            result.gas_price,
+            dry_run_result.vm_error_info,
+        ))
+    }
+
     async fn execute_view_function(
         &self,
         function_call: FunctionCallView,
+        state_option: Option<StateOptions>,
     ) -> RpcResult<AnnotatedFunctionResultView> {
+        let state_option = state_option.unwrap_or_default();
+        let root = self
+            .rpc_service
+            .resolve_view_function_root(
+                state_option.state_root.map(|h256_view| h256_view.0),
+                state_option.at_tx_order.map(|tx_order| tx_order.0),
+            )
+            .await?;
         Ok(self
             .rpc_service
-            .execute_view_function(function_call.into())
+            .execute_view_function(function_call.into(), root)
             .await?
             .into())
     }
@@ -205,7 +372,7 @@ impl RoochAPIServer for RoochServer {
         let show_display =
             state_option.show_display && (access_path.0.is_object() || access_path.0.is_resource());
 
-        let state_root = state_option.state_root.map(|h256_view| h256_view.0);
+        let state_root = self.resolve_state_root(&state_option).await?;
 
         let state_views = if state_option.decode || show_display {
             let states = self
@@ -250,6 +417,26 @@ impl RoochAPIServer for RoochServer {
         Ok(state_views)
     }
 
+    async fn get_states_with_proof(
+        &self,
+        access_path: AccessPathView,
+        state_root: Option<H256View>,
+    ) -> RpcResult<StatesWithProofView> {
+        access_path.0.validate_max_object_ids()?;
+        let (state_root, proofs) = self
+            .rpc_service
+            .get_state_proofs(access_path.into(), state_root.map(|h256_view| h256_view.0))
+            .await?;
+        let states = proofs
+            .into_iter()
+            .map(StateWithProofView::new_from_state_proof)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(StatesWithProofView {
+            state_root: state_root.into(),
+            states,
+        })
+    }
+
     async fn list_states(
         &self,
         access_path: AccessPathView,
@@ -262,7 +449,7 @@ impl RoochAPIServer for RoochServer {
         let show_display =
             state_option.show_display && (access_path.0.is_object() || access_path.0.is_resource());
 
-        let state_root = state_option.state_root.map(|h256_view| h256_view.0);
+        let state_root = self.resolve_state_root(&state_option).await?;
 
         let limit_of = min(
             limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
@@ -335,17 +522,18 @@ impl RoochAPIServer for RoochServer {
         let state_option = state_option.unwrap_or_default();
         let decode = state_option.decode;
         let show_display = state_option.show_display;
+        let state_root = self.resolve_state_root(&state_option).await?;
 
         let mut objects_view = if decode || show_display {
             let states: Vec<Option<AnnotatedState>> = self
                 .rpc_service
-                .get_annotated_states(access_path, None)
+                .get_annotated_states(access_path, state_root)
                 .await?;
 
             let mut valid_display_field_views = if show_display {
                 let valid_states = states.iter().filter_map(|s| s.as_ref()).collect::<Vec<_>>();
                 self.rpc_service
-                    .get_display_fields_and_render(valid_states.as_slice(), None)
+                    .get_display_fields_and_render(valid_states.as_slice(), state_root)
                     .await?
             } else {
                 vec![]
@@ -378,7 +566,7 @@ impl RoochAPIServer for RoochServer {
             }
         } else {
             self.rpc_service
-                .get_states(access_path, None)
+                .get_states(access_path, state_root)
                 .await?
                 .into_iter()
                 .map(|s| s.map(Into::into))
@@ -572,6 +760,55 @@ impl RoochAPIServer for RoochServer {
         })
     }
 
+    async fn trace_transaction(
+        &self,
+        tx_hash: H256View,
+        trace_option: Option<TraceOptions>,
+    ) -> RpcResult<TransactionTraceView> {
+        let trace_option = trace_option.unwrap_or_default();
+        let (ledger_tx, pre_state_root, trace) =
+            self.rpc_service.trace_transaction(tx_hash.into()).await?;
+        let flamegraph = if trace_option.flamegraph {
+            trace
+                .gas_log
+                .exec_io
+                .to_flamegraph(format!("Transaction {:?}", tx_hash.0))?
+                .map(|svg| String::from_utf8_lossy(&svg).into_owned())
+        } else {
+            None
+        };
+        let raw_output = trace.raw_output;
+        Ok(TransactionTraceView {
+            tx_hash,
+            tx_order: ledger_tx.sequence_info.tx_order.into(),
+            pre_state_root: pre_state_root.into(),
+            status: raw_output.status.into(),
+            gas_used: raw_output.gas_used.into(),
+            call_trace: trace.gas_log.exec_io.call_graph.into(),
+            events: raw_output.events.into_iter().map(Into::into).collect(),
+            read_set: trace.read_set.into_iter().map(Into::into).collect(),
+            changeset: raw_output.changeset.into(),
+            vm_error_info: trace.vm_error_info,
+            flamegraph,
+        })
+    }
+
+    async fn get_transaction_accumulator_proof(
+        &self,
+        tx_hash: H256View,
+    ) -> RpcResult<Option<TransactionAccumulatorProofView>> {
+        let tx_order = match self
+            .rpc_service
+            .get_transaction_by_hash(tx_hash.into())
+            .await?
+        {
+            Some(ledger_tx) => ledger_tx.sequence_info.tx_order,
+            None => return Ok(None),
+        };
+        let proof = self.rpc_service.get_tx_accumulator_proof(tx_order).await?;
+        Ok(proof.map(Into::into))
+    }
+
     async fn get_balance(
         &self,
         account_addr: UnitedAddressView,
@@ -953,6 +1190,41 @@ impl RoochAPIServer for RoochServer {
 
         Ok(data)
     }
+
+    async fn subscribe_transactions(
+        &self,
+        pending: PendingSubscriptionSink,
+        filter: TransactionFilterView,
+    ) -> SubscriptionResult {
+        // subscribe before accepting, so no transaction is missed after the subscription is returned
+        let rx = self.subscription_service.subscribe_transactions();
+        let sink = pending.accept().await?;
+        let server = self.clone();
+        tokio::spawn(async move {
+            if let Err(e) = server.pipe_transactions(sink, rx, filter.into()).await {
+                warn!("Transaction subscription terminated, error: {:?}", e);
+            }
+        });
+        Ok(())
+    }
+
+    async fn subscribe_events(
+        &self,
+        pending: PendingSubscriptionSink,
+        filter: EventFilterView,
+        event_options: Option<EventOptions>,
+    ) -> SubscriptionResult {
+        let decode = event_options.unwrap_or_default().decode;
+        let rx = self.subscription_service.subscribe_transactions();
+        let sink = pending.accept().await?;
+        let server = self.clone();
+        tokio::spawn(async move {
+            if let Err(e) = server.pipe_events(sink, rx, filter.into(), decode).await {
+                warn!("Event subscription terminated, error: {:?}", e);
+            }
+        });
+        Ok(())
+    }
 }
 
 impl RoochRpcModule for RoochServer {
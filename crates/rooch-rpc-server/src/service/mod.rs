@@ -5,6 +5,8 @@ pub mod aggregate_service;
 // pub mod rpc_logger;
 pub mod error;
 pub mod rpc_service;
+pub mod subscription_service;
+pub mod upstream;
 
 pub mod routing;
 
@@ -3,9 +3,11 @@
 
 use anyhow::{format_err, Result};
 use bitcoin_client::proxy::BitcoinClientProxy;
-use bitcoincore_rpc::bitcoin::Txid;
+use bitcoincore_rpc::bitcoin::hashes::Hash;
+use bitcoincore_rpc::bitcoin::{BlockHash, Txid};
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::{ModuleId, StructTag};
+use moveos::trace::TransactionTrace;
 use moveos_types::access_path::AccessPath;
 use moveos_types::function_return_value::AnnotatedFunctionResult;
 use moveos_types::h256::H256;
@@ -13,12 +15,14 @@ use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::move_types::type_tag_match;
 use moveos_types::moveos_std::display::{get_object_display_id, RawDisplay};
 use moveos_types::moveos_std::event::{AnnotatedEvent, Event, EventID};
-use moveos_types::moveos_std::object::{ObjectID, MAX_OBJECT_IDS_PER_QUERY};
+use moveos_types::moveos_std::object::{ObjectID, ObjectMeta, MAX_OBJECT_IDS_PER_QUERY};
 use moveos_types::state::{AnnotatedState, FieldKey, ObjectState, StateChangeSet};
+use moveos_types::state_override::ObjectOverride;
+use moveos_types::state_proof::StateProof;
 use moveos_types::state_resolver::{AnnotatedStateKV, StateKV};
 use moveos_types::transaction::{FunctionCall, TransactionExecutionInfo};
 use rooch_da::proxy::DAServerProxy;
-use rooch_executor::actor::messages::DryRunTransactionResult;
+use rooch_executor::actor::messages::{DryRunTransactionResult, GasEstimateResult};
 use rooch_executor::proxy::ExecutorProxy;
 use rooch_indexer::proxy::IndexerProxy;
 use rooch_pipeline_processor::proxy::PipelineProcessorProxy;
@@ -30,6 +34,7 @@ use rooch_sequencer::proxy::SequencerProxy;
 use rooch_types::address::{BitcoinAddress, RoochAddress};
 use rooch_types::bitcoin::pending_block::PendingBlockModule;
 use rooch_types::bitcoin::BitcoinModule;
+use rooch_types::block::TransactionAccumulatorProof;
 use rooch_types::framework::address_mapping::RoochToBitcoinAddressMapping;
 use rooch_types::indexer::event::{
     AnnotatedIndexerEvent, EventFilter, IndexerEvent, IndexerEventID,
@@ -43,7 +48,8 @@ use rooch_types::indexer::transaction::{IndexerTransaction, TransactionFilter};
 use rooch_types::repair::{RepairIndexerParams, RepairIndexerType};
 use rooch_types::state::{StateChangeSetWithTxOrder, SyncStateFilter};
 use rooch_types::transaction::{
-    ExecuteTransactionResponse, LedgerTransaction, RoochTransaction, RoochTransactionData,
+    ExecuteTransactionResponse, L1Block, L1BlockWithBody, LedgerTransaction, LedgerTxData,
+    RoochTransaction, RoochTransactionData,
 };
 use std::collections::{BTreeMap, HashMap};
 
@@ -105,24 +111,165 @@ impl RpcService {
         self.pipeline_processor.execute_l2_tx(tx).await
     }
 
-    pub async fn dry_run_tx(&self, tx: RoochTransactionData) -> Result<DryRunTransactionResult> {
+    pub async fn dry_run_tx(
+        &self,
+        tx: RoochTransactionData,
+        state_overrides: Vec<ObjectOverride>,
+    ) -> Result<DryRunTransactionResult> {
         let verified_tx = self.executor.convert_to_verified_tx(tx).await?;
-        self.executor.dry_run_transaction(verified_tx).await
+        self.executor
+            .dry_run_transaction(verified_tx, state_overrides)
+            .await
+    }
+
+    pub async fn estimate_gas(
+        &self,
+        tx: RoochTransactionData,
+        gas_payer: Option<AccountAddress>,
+    ) -> Result<GasEstimateResult> {
+        self.executor.estimate_gas(tx, gas_payer).await
+    }
+
+    /// Re-execute the transaction at the state root after the previous transaction is executed.
+    /// Return the sequenced transaction, the pre-state root and the trace of the transaction.
+    pub async fn trace_transaction(
+        &self,
+        tx_hash: H256,
+    ) -> Result<(LedgerTransaction, H256, TransactionTrace)> {
+        let ledger_tx = self
+            .get_transaction_by_hash(tx_hash)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Transaction not found: {:?}", tx_hash))?;
+        let tx_order = ledger_tx.sequence_info.tx_order;
+        if tx_order == 0 {
+            return Err(anyhow::anyhow!("The genesis transaction can not be traced"));
+        }
+        let pre_tx_hash = self
+            .get_tx_hashes(vec![tx_order - 1])
+            .await?
+            .pop()
+            .flatten()
+            .ok_or_else(|| {
+                anyhow::anyhow!("Transaction not found for tx_order: {}", tx_order - 1)
+            })?;
+        let pre_execution_info = self
+            .get_transaction_execution_infos_by_hash(vec![pre_tx_hash])
+            .await?
+            .pop()
+            .flatten()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Transaction execution info not found for tx_order: {}",
+                    tx_order - 1
+                )
+            })?;
+        let l1_block = match &ledger_tx.data {
+            LedgerTxData::L1Block(block) => Some(self.get_l1_block_with_body(block).await?),
+            _ => None,
+        };
+        let trace = self
+            .executor
+            .trace_transaction(
+                pre_execution_info.root_metadata(),
+                ledger_tx.clone(),
+                l1_block,
+            )
+            .await?;
+        Ok((ledger_tx, pre_execution_info.state_root, trace))
+    }
+
+    async fn get_l1_block_with_body(&self, block: &L1Block) -> Result<L1BlockWithBody> {
+        let bitcoin_client = self
+            .bitcoin_client
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Bitcoin client is not configured"))?;
+        let block_hash = BlockHash::from_slice(&block.block_hash)?;
+        let btc_block = bitcoin_client.get_block(block_hash).await?;
+        Ok(L1BlockWithBody::new_bitcoin_block(
+            block.block_height,
+            btc_block,
+        ))
     }
 
     pub async fn execute_view_function(
         &self,
         function_call: FunctionCall,
+        root: Option<ObjectMeta>,
     ) -> Result<AnnotatedFunctionResult> {
         let module_id = function_call.function_id.module_id.clone();
-        if !self.exists_module(module_id.clone()).await? {
+        let state_root = root.as_ref().map(|root| root.state_root());
+        if !self.exists_module(module_id.clone(), state_root).await? {
             return Err(anyhow::anyhow!("Module does not exist: {}", module_id));
         }
 
-        let resp = self.executor.execute_view_function(function_call).await?;
+        let resp = self
+            .executor
+            .execute_view_function_at(function_call, root)
+            .await?;
         Ok(resp)
     }
 
+    /// Resolve the root to execute the view function, the `at_tx_order` is resolved to the
+    /// root after the transaction is executed. Return None to execute at the latest state.
+    /// The object count of a bare state root is unknown, so the view function can only be
+    /// executed at a historical state by the `at_tx_order`.
+    pub async fn resolve_view_function_root(
+        &self,
+        state_root: Option<H256>,
+        at_tx_order: Option<u64>,
+    ) -> Result<Option<ObjectMeta>> {
+        match (state_root, at_tx_order) {
+            (Some(_), _) => Err(anyhow::anyhow!(
+                "The view function can not be executed at a state_root, use at_tx_order instead"
+            )),
+            (None, None) => Ok(None),
+            (None, Some(tx_order)) => Ok(Some(
+                self.get_execution_info_by_order(tx_order)
+                    .await?
+                    .root_metadata(),
+            )),
+        }
+    }
+
+    async fn get_execution_info_by_order(&self, tx_order: u64) -> Result<TransactionExecutionInfo> {
+        let tx_hash = self
+            .get_tx_hashes(vec![tx_order])
+            .await?
+            .pop()
+            .flatten()
+            .ok_or_else(|| anyhow::anyhow!("Transaction not found for tx_order: {}", tx_order))?;
+        self.get_transaction_execution_infos_by_hash(vec![tx_hash])
+            .await?
+            .pop()
+            .flatten()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Transaction execution info not found for tx_order: {}",
+                    tx_order
+                )
+            })
+    }
+
+    /// Resolve the state root to query, the `at_tx_order` is resolved to the state root
+    /// after the transaction is executed. Return None to query the latest state.
+    pub async fn resolve_state_root(
+        &self,
+        state_root: Option<H256>,
+        at_tx_order: Option<u64>,
+    ) -> Result<Option<H256>> {
+        let tx_order = match (state_root, at_tx_order) {
+            (Some(_), Some(_)) => {
+                return Err(anyhow::anyhow!(
+                    "The state_root and at_tx_order can not be set at the same time"
+                ))
+            }
+            (state_root, None) => return Ok(state_root),
+            (None, Some(tx_order)) => tx_order,
+        };
+        let execution_info = self.get_execution_info_by_order(tx_order).await?;
+        Ok(Some(execution_info.state_root))
+    }
+
     pub async fn get_states(
         &self,
         access_path: AccessPath,
@@ -131,9 +278,23 @@ impl RpcService {
         self.executor.get_states(access_path, state_root).await
     }
 
-    pub async fn exists_module(&self, module_id: ModuleId) -> Result<bool> {
+    pub async fn get_state_proofs(
+        &self,
+        access_path: AccessPath,
+        state_root: Option<H256>,
+    ) -> Result<(H256, Vec<StateProof>)> {
+        self.executor
+            .get_state_proofs(access_path, state_root)
+            .await
+    }
+
+    pub async fn exists_module(
+        &self,
+        module_id: ModuleId,
+        state_root: Option<H256>,
+    ) -> Result<bool> {
         let mut resp = self
-            .get_states(AccessPath::module(&module_id), None)
+            .get_states(AccessPath::module(&module_id), state_root)
             .await?;
         Ok(resp.pop().flatten().is_some())
     }
@@ -253,6 +414,14 @@ impl RpcService {
         Ok(resp)
     }
 
+    pub async fn get_tx_accumulator_proof(
+        &self,
+        tx_order: u64,
+    ) -> Result<Option<TransactionAccumulatorProof>> {
+        let resp = self.sequencer.get_tx_accumulator_proof(tx_order).await?;
+        Ok(resp)
+    }
+
     pub async fn get_sequencer_order(&self) -> Result<u64> {
         let resp = self.sequencer.get_sequencer_order().await?;
         Ok(resp)
@@ -285,7 +454,15 @@ impl RpcService {
             .indexer
             .query_events(filter, cursor, limit, descending_order)
             .await?;
+        self.annotate_indexer_events(indexer_events).await
+    }
 
+    /// Decode the event data of the indexer events from the event store,
+    /// the events that can not be found in the event store are skipped.
+    pub async fn annotate_indexer_events(
+        &self,
+        indexer_events: Vec<IndexerEvent>,
+    ) -> Result<Vec<AnnotatedIndexerEvent>> {
         let event_ids = indexer_events
             .iter()
             .map(|m| m.event_id.clone())
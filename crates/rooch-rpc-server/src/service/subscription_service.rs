@@ -0,0 +1,98 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use moveos_eventbus::bus::EventBus;
+use rooch_event::event::TransactionExecutedEvent;
+use tokio::sync::broadcast;
+
+/// The capacity of the executed transaction channel,
+/// the slow subscribers will lag behind and skip the oldest transactions if it is exceeded.
+const TRANSACTION_CHANNEL_CAPACITY: usize = 1024;
+
+const SUBSCRIBER_NAME: &str = "RpcSubscriptionService";
+
+/// SubscriptionService bridges the executed transactions from the EventBus to the RPC subscriptions.
+#[derive(Clone)]
+pub struct SubscriptionService {
+    tx_sender: broadcast::Sender<TransactionExecutedEvent>,
+}
+
+impl SubscriptionService {
+    pub fn new(event_bus: &EventBus) -> Result<Self> {
+        let (tx_sender, _) = broadcast::channel(TRANSACTION_CHANNEL_CAPACITY);
+        let sender = tx_sender.clone();
+        event_bus.callback_subscribe::<TransactionExecutedEvent, _>(
+            SUBSCRIBER_NAME,
+            move |data| {
+                if let Ok(event) = data.downcast::<TransactionExecutedEvent>() {
+                    // Send error means there is no active subscription, just ignore it
+                    let _ = sender.send(*event);
+                }
+            },
+        )?;
+        Ok(Self { tx_sender })
+    }
+
+    pub fn subscribe_transactions(&self) -> broadcast::Receiver<TransactionExecutedEvent> {
+        self.tx_sender.subscribe()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use moveos_types::moveos_std::tx_context::TxContext;
+    use moveos_types::transaction::TransactionExecutionInfo;
+    use rooch_types::indexer::transaction::{IndexerTransaction, TransactionFilter};
+    use rooch_types::indexer::Filter;
+    use rooch_types::transaction::{LedgerTransaction, RoochTransaction, TransactionSequenceInfo};
+
+    fn executed_event() -> TransactionExecutedEvent {
+        let tx = RoochTransaction::mock();
+        let mut tx_context = TxContext::random_for_testing_only();
+        tx_context.sender = tx.sender().into();
+        let move_action = tx.action().clone();
+        TransactionExecutedEvent {
+            ledger_transaction: LedgerTransaction::new_l2_tx(tx, TransactionSequenceInfo::random()),
+            execution_info: TransactionExecutionInfo::random(),
+            move_action,
+            tx_context,
+            events: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_transactions_round_trip() -> Result<()> {
+        let event_bus = EventBus::new();
+        let service = SubscriptionService::new(&event_bus)?;
+        let mut receiver = service.subscribe_transactions();
+
+        let matched = executed_event();
+        let unmatched = executed_event();
+        let filter = TransactionFilter::Sender(matched.tx_context.sender);
+        event_bus.notify::<TransactionExecutedEvent>(unmatched)?;
+        event_bus.notify::<TransactionExecutedEvent>(matched.clone())?;
+
+        let mut received = vec![];
+        for _ in 0..2 {
+            let event = receiver.recv().await?;
+            let tx = IndexerTransaction::new(
+                event.ledger_transaction,
+                event.execution_info,
+                event.move_action,
+                event.tx_context,
+            )?;
+            if filter.matches(&tx) {
+                received.push(tx);
+            }
+        }
+        assert_eq!(received.len(), 1);
+        assert_eq!(
+            received[0].tx_hash,
+            matched.ledger_transaction.clone().tx_hash()
+        );
+        assert_eq!(received[0].sender, matched.tx_context.sender);
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use async_trait::async_trait;
+use moveos_types::h256::H256;
+use rooch_pipeline_processor::actor::derivation::ProposedBlockSource;
+use rooch_rpc_client::{Client, ClientBuilder};
+use rooch_types::block::Block;
+
+/// The proposed blocks served by the upstream node through `rooch_getTransactionAccumulatorProof`.
+pub struct UpstreamBlockSource {
+    client: Client,
+}
+
+impl UpstreamBlockSource {
+    pub async fn new(upstream_rpc_url: &str) -> Result<Self> {
+        let client = ClientBuilder::default().build(upstream_rpc_url).await?;
+        Ok(Self { client })
+    }
+}
+
+#[async_trait]
+impl ProposedBlockSource for UpstreamBlockSource {
+    async fn get_proposed_block(&self, tx_hash: H256) -> Result<Option<Block>> {
+        let proof = self
+            .client
+            .rooch
+            .get_transaction_accumulator_proof(tx_hash)
+            .await?;
+        Ok(proof.map(|proof| Block::from(proof.block)))
+    }
+}
@@ -7,8 +7,9 @@ use std::sync::Arc;
 use std::time::SystemTime;
 
 use crate::messages::{
-    GetSequencerInfoMessage, GetSequencerOrderMessage, GetTransactionByHashMessage,
-    GetTransactionsByHashMessage, GetTxHashsMessage, TransactionSequenceMessage,
+    ApplyLedgerTransactionMessage, GetSequencerInfoMessage, GetSequencerOrderMessage,
+    GetTransactionByHashMessage, GetTransactionsByHashMessage, GetTxAccumulatorProofMessage,
+    GetTxHashsMessage, TransactionSequenceMessage,
 };
 use crate::metrics::SequencerMetrics;
 use accumulator::{Accumulator, MerkleAccumulator};
@@ -23,6 +24,7 @@ use rooch_event::actor::{EventActor, EventActorSubscribeMessage};
 use rooch_event::event::ServiceStatusEvent;
 use rooch_store::transaction_store::TransactionStore;
 use rooch_store::RoochStore;
+use rooch_types::block::TransactionAccumulatorProof;
 use rooch_types::crypto::RoochKeyPair;
 use rooch_types::sequencer::SequencerInfo;
 use rooch_types::service_status::ServiceStatus;
@@ -101,6 +103,11 @@ impl SequencerActor {
             ServiceStatus::ReadOnlyMode => {
                 return Err(anyhow::anyhow!("The service is in read-only mode"));
             }
+            ServiceStatus::FollowerMode => {
+                return Err(anyhow::anyhow!(
+                    "The service is in follower mode, transactions are derived from DA"
+                ));
+            }
             ServiceStatus::DateImportMode => {
                 if !tx_data.is_l1_block() && !tx_data.is_l1_tx() {
                     return Err(anyhow::anyhow!(
@@ -198,6 +205,78 @@ impl SequencerActor {
 
         Ok(tx)
     }
+
+    /// Apply a LedgerTransaction which has been sequenced by upstream sequencer (e.g., derived from DA).
+    /// The tx_order must be the next order, and the accumulator root after appending the tx
+    /// must be equal to the one in its sequence info.
+    #[named]
+    pub fn apply(&mut self, mut tx: LedgerTransaction) -> Result<LedgerTransaction> {
+        let fn_name = function_name!();
+        let _timer = self
+            .metrics
+            .sequencer_sequence_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+
+        let tx_order = tx.sequence_info.tx_order;
+        let exp_tx_order = self.get_next_tx_order()?;
+        if tx_order != exp_tx_order {
+            return Err(anyhow::anyhow!(
+                "Tx order is not strictly incremental, expect: {}, actual: {}",
+                exp_tx_order,
+                tx_order
+            ));
+        }
+
+        let tx_hash = tx.tx_hash();
+        let tx_accumulator_root = self.tx_accumulator.append(vec![tx_hash].as_slice())?;
+        if tx_accumulator_root != tx.sequence_info.tx_accumulator_root {
+            self.tx_accumulator = self
+                .tx_accumulator
+                .fork(Some(self.last_sequencer_info.last_accumulator_info.clone()));
+            return Err(anyhow::anyhow!(
+                "Tx accumulator root mismatch, tx_order: {}, expect: {:?}, actual: {:?}",
+                tx_order,
+                tx.sequence_info.tx_accumulator_root,
+                tx_accumulator_root
+            ));
+        }
+
+        let tx_accumulator_unsaved_nodes = self.tx_accumulator.pop_unsaved_nodes();
+        let tx_accumulator_info = self.tx_accumulator.get_info();
+        let sequencer_info = SequencerInfo::new(tx_order, tx_accumulator_info);
+        let save_ret = self.rooch_store.save_sequenced_tx(
+            tx_hash,
+            tx.clone(),
+            sequencer_info.clone(),
+            tx_accumulator_unsaved_nodes,
+            true,
+        );
+        if let Err(e) = save_ret {
+            self.tx_accumulator = self
+                .tx_accumulator
+                .fork(Some(self.last_sequencer_info.last_accumulator_info.clone()));
+            self.service_status = ServiceStatus::Maintenance;
+            tracing::error!(
+                "Failed to save applied tx, tx_order: {}, error: {:?}, set sequencer to Maintenance mode.",
+                tx_order,
+                e
+            );
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!("Save applied tx failed: {:?}", e),
+            )
+            .into());
+        }
+        self.tx_accumulator.clear_after_save();
+        info!(
+            "sequencer applied tx_hash: {:?} tx_order: {:?}",
+            tx_hash, tx_order
+        );
+        self.last_sequencer_info = sequencer_info;
+
+        Ok(tx)
+    }
 }
 
 #[async_trait]
@@ -221,6 +300,17 @@ impl Handler<TransactionSequenceMessage> for SequencerActor {
     }
 }
 
+#[async_trait]
+impl Handler<ApplyLedgerTransactionMessage> for SequencerActor {
+    async fn handle(
+        &mut self,
+        msg: ApplyLedgerTransactionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<LedgerTransaction> {
+        self.apply(msg.tx)
+    }
+}
+
 #[async_trait]
 impl Handler<GetTransactionByHashMessage> for SequencerActor {
     async fn handle(
@@ -255,6 +345,17 @@ impl Handler<GetTxHashsMessage> for SequencerActor {
     }
 }
 
+#[async_trait]
+impl Handler<GetTxAccumulatorProofMessage> for SequencerActor {
+    async fn handle(
+        &mut self,
+        msg: GetTxAccumulatorProofMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Option<TransactionAccumulatorProof>> {
+        self.rooch_store.get_tx_accumulator_proof(msg.tx_order)
+    }
+}
+
 #[async_trait]
 impl Handler<GetSequencerOrderMessage> for SequencerActor {
     async fn handle(
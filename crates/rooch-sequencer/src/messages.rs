@@ -5,6 +5,7 @@ use anyhow::Result;
 use coerce::actor::message::Message;
 use moveos_types::h256::H256;
 use rooch_types::{
+    block::TransactionAccumulatorProof,
     sequencer::SequencerInfo,
     transaction::{LedgerTransaction, LedgerTxData},
 };
@@ -20,6 +21,16 @@ impl Message for TransactionSequenceMessage {
     type Result = Result<LedgerTransaction>;
 }
 
+/// Apply a LedgerTransaction which has been sequenced by upstream sequencer
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApplyLedgerTransactionMessage {
+    pub tx: LedgerTransaction,
+}
+
+impl Message for ApplyLedgerTransactionMessage {
+    type Result = Result<LedgerTransaction>;
+}
+
 /// Get Transaction By Hash Message
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GetTransactionByHashMessage {
@@ -62,3 +73,13 @@ pub struct GetSequencerInfoMessage {}
 impl Message for GetSequencerInfoMessage {
     type Result = Result<SequencerInfo>;
 }
+
+/// Get the tx accumulator proof of the transaction against the block it was proposed in
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetTxAccumulatorProofMessage {
+    pub tx_order: u64,
+}
+
+impl Message for GetTxAccumulatorProofMessage {
+    type Result = Result<Option<TransactionAccumulatorProof>>;
+}
@@ -2,13 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::messages::{
-    GetSequencerInfoMessage, GetSequencerOrderMessage, GetTransactionByHashMessage,
-    GetTransactionsByHashMessage, GetTxHashsMessage,
+    ApplyLedgerTransactionMessage, GetSequencerInfoMessage, GetSequencerOrderMessage,
+    GetTransactionByHashMessage, GetTransactionsByHashMessage, GetTxAccumulatorProofMessage,
+    GetTxHashsMessage,
 };
 use crate::{actor::sequencer::SequencerActor, messages::TransactionSequenceMessage};
 use anyhow::Result;
 use coerce::actor::ActorRef;
 use moveos_types::h256::H256;
+use rooch_types::block::TransactionAccumulatorProof;
 use rooch_types::sequencer::SequencerInfo;
 use rooch_types::transaction::{LedgerTransaction, LedgerTxData};
 
@@ -26,6 +28,15 @@ impl SequencerProxy {
         self.actor.send(TransactionSequenceMessage { tx }).await?
     }
 
+    pub async fn apply_ledger_transaction(
+        &self,
+        tx: LedgerTransaction,
+    ) -> Result<LedgerTransaction> {
+        self.actor
+            .send(ApplyLedgerTransactionMessage { tx })
+            .await?
+    }
+
     pub async fn get_transaction_by_hash(&self, hash: H256) -> Result<Option<LedgerTransaction>> {
         self.actor
             .send(GetTransactionByHashMessage { hash })
@@ -45,6 +56,15 @@ impl SequencerProxy {
         self.actor.send(GetTxHashsMessage { tx_orders }).await?
     }
 
+    pub async fn get_tx_accumulator_proof(
+        &self,
+        tx_order: u64,
+    ) -> Result<Option<TransactionAccumulatorProof>> {
+        self.actor
+            .send(GetTxAccumulatorProofMessage { tx_order })
+            .await?
+    }
+
     pub async fn get_sequencer_order(&self) -> Result<u64> {
         self.actor.send(GetSequencerOrderMessage {}).await?
     }
@@ -4,6 +4,7 @@
 use anyhow::Result;
 use coerce::actor::{system::ActorSystem, IntoActor};
 use metrics::RegistryService;
+use moveos_types::h256::H256;
 use prometheus::Registry;
 use raw_store::metrics::DBMetrics;
 use raw_store::{StoreInstance, CF_METRICS_REPORT_PERIOD_MILLIS};
@@ -137,3 +138,56 @@ async fn test_sequencer_concurrent() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_sequencer_apply_ledger_tx() -> Result<()> {
+    let registry_service = RegistryService::default();
+    let upstream_opt = RoochOpt::new_with_temp_store()?;
+    let upstream_db = init_rooch_db(&upstream_opt, &registry_service.default_registry())?;
+    let sequencer_key = RoochKeyPair::generate_secp256k1();
+    let mut upstream = SequencerActor::new(
+        sequencer_key.copy(),
+        upstream_db.rooch_store,
+        ServiceStatus::Active,
+        &registry_service.default_registry(),
+        None,
+    )?;
+    let mut ledger_txs = vec![];
+    for _ in 0..10 {
+        let tx_data = LedgerTxData::L2Tx(RoochTransaction::mock());
+        ledger_txs.push(upstream.sequence(tx_data)?);
+    }
+
+    let follower_registry = prometheus::Registry::new();
+    let follower_opt = RoochOpt::new_with_temp_store()?;
+    let follower_db = init_rooch_db_with_instance(
+        &follower_opt,
+        RoochDB::generate_store_instance(follower_opt.store_config(), &follower_registry)?,
+        &follower_registry,
+    )?;
+    let mut follower = SequencerActor::new(
+        sequencer_key,
+        follower_db.rooch_store,
+        ServiceStatus::FollowerMode,
+        &follower_registry,
+        None,
+    )?;
+    assert!(follower
+        .sequence(LedgerTxData::L2Tx(RoochTransaction::mock()))
+        .is_err());
+
+    // skip the first tx, tx_order is not continuous
+    assert!(follower.apply(ledger_txs[1].clone()).is_err());
+
+    // tampered accumulator root
+    let mut tampered_tx = ledger_txs[0].clone();
+    tampered_tx.sequence_info.tx_accumulator_root = H256::random();
+    assert!(follower.apply(tampered_tx).is_err());
+
+    for ledger_tx in ledger_txs.iter() {
+        let applied_tx = follower.apply(ledger_tx.clone())?;
+        assert_eq!(applied_tx.sequence_info, ledger_tx.sequence_info);
+    }
+    assert_eq!(follower.last_order(), upstream.last_order());
+    Ok(())
+}
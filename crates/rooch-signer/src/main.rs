@@ -0,0 +1,109 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{bail, Result};
+use clap::Parser;
+use rooch_config::{rooch_config_dir, ROOCH_KEYSTORE_FILENAME};
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::file_keystore::FileBasedKeystore;
+use rooch_key::keystore::remote_keystore::{RemoteSigner, RemoteSignerEndpoint};
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// The reference remote signer, serves the keys of a rooch file keystore to the remote keystore clients.
+/// Set the `remote_signer` of the client config to the listen endpoint to sign the transactions via the signer.
+#[derive(Parser)]
+#[clap(name = "rooch-signer", author = "The Rooch Core Contributors")]
+struct SignerOpts {
+    /// The endpoint to listen, `unix://<path>`, `tcp://<host>:<port>` or `http://<host>:<port>`,
+    /// the signer does not authenticate the clients, so the TCP and HTTP host must be a loopback address
+    #[clap(long)]
+    listen: RemoteSignerEndpoint,
+
+    /// The keystore file path, default is the keystore in the rooch config dir
+    #[clap(long)]
+    keystore_path: Option<PathBuf>,
+
+    /// The keystore password
+    #[clap(long, env = "ROOCH_PASSWORD", hide_env_values = true)]
+    password: Option<String>,
+}
+
+fn main() -> Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let opts = SignerOpts::parse();
+    opts.listen.ensure_local()?;
+
+    let keystore_path = match opts.keystore_path {
+        Some(path) => path,
+        None => rooch_config_dir()?.join(ROOCH_KEYSTORE_FILENAME),
+    };
+    let keystore = FileBasedKeystore::load(&keystore_path)?;
+    if !keystore.get_if_password_is_empty()
+        && !verify_password(opts.password.clone(), keystore.get_password_hash())?
+    {
+        bail!("Password is invalid");
+    }
+    let addresses = keystore.addresses()?;
+    info!(
+        "Loaded {} addresses from keystore {:?}",
+        addresses.len(),
+        keystore_path
+    );
+
+    let signer = Arc::new(RemoteSigner::new(keystore, opts.password));
+    match &opts.listen {
+        #[cfg(unix)]
+        RemoteSignerEndpoint::Unix(path) => {
+            use std::os::unix::fs::PermissionsExt;
+            use std::os::unix::net::UnixListener;
+
+            if path.exists() {
+                std::fs::remove_file(path)?;
+            }
+            let listener = UnixListener::bind(path)?;
+            // Only the owner of the signer can connect to the socket
+            std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+            info!("Remote signer listening on {}", opts.listen);
+            for stream in listener.incoming() {
+                spawn_connection(signer.clone(), stream?);
+            }
+        }
+        RemoteSignerEndpoint::Tcp(addr) => {
+            let listener = TcpListener::bind(addr)?;
+            info!("Remote signer listening on {}", opts.listen);
+            for stream in listener.incoming() {
+                spawn_connection(signer.clone(), stream?);
+            }
+        }
+        RemoteSignerEndpoint::Http(addr) => {
+            let listener = TcpListener::bind(addr)?;
+            info!("Remote signer listening on {}", opts.listen);
+            for stream in listener.incoming() {
+                let signer = signer.clone();
+                let stream = stream?;
+                std::thread::spawn(move || {
+                    if let Err(e) = signer.serve_http(stream) {
+                        warn!("Remote signer HTTP connection error: {:?}", e);
+                    }
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+fn spawn_connection<S>(signer: Arc<RemoteSigner<FileBasedKeystore>>, stream: S)
+where
+    S: Read + Write + Send + 'static,
+{
+    std::thread::spawn(move || {
+        if let Err(e) = signer.serve(stream) {
+            warn!("Remote signer connection error: {:?}", e);
+        }
+    });
+}
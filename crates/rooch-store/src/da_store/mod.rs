@@ -79,6 +79,11 @@ pub trait DAMetaStore {
     fn get_block_state(&self, block_number: u128) -> anyhow::Result<BlockSubmitState>;
     // get block state by block_number, return None if not exist
     fn try_get_block_state(&self, block_number: u128) -> anyhow::Result<Option<BlockSubmitState>>;
+    // get the block state which contains the tx_order, return None if the tx_order is not in any block
+    fn find_block_state_by_tx_order(
+        &self,
+        tx_order: u64,
+    ) -> anyhow::Result<Option<BlockSubmitState>>;
 }
 
 #[derive(Clone)]
@@ -96,6 +101,53 @@ impl DAMetaDBStore {
         Ok(store)
     }
 
+    /// Init the DA meta with a submitted block, it is used when the node is bootstrapped from a snapshot,
+    /// the blocks before the snapshot block are not stored, and the following blocks are appended after it.
+    pub fn init_with_submitted_block(
+        &self,
+        block_range: BlockRange,
+        batch_hash: H256,
+    ) -> anyhow::Result<()> {
+        if let Some(last_block_number) = self.get_last_block_number()? {
+            return Err(anyhow::anyhow!(
+                "DA meta has been initialized, last block number: {}",
+                last_block_number
+            ));
+        }
+        let block_number = block_range.block_number;
+        let block_state = BlockSubmitState::new_done(
+            block_number,
+            block_range.tx_order_start,
+            block_range.tx_order_end,
+            batch_hash,
+        );
+        let inner_store = self.block_submit_state_store.get_store().store();
+        let cf_batches = vec![
+            WriteBatchCF::new_with_rows(
+                vec![(
+                    to_bytes(&block_number)?,
+                    WriteOp::Value(to_bytes(&block_state)?),
+                )],
+                DA_BLOCK_SUBMIT_STATE_COLUMN_FAMILY_NAME.to_string(),
+            ),
+            WriteBatchCF::new_with_rows(
+                vec![
+                    (
+                        to_bytes(LAST_BLOCK_NUMBER_KEY)?,
+                        WriteOp::Value(to_bytes(&block_number)?),
+                    ),
+                    (
+                        to_bytes(BACKGROUND_SUBMIT_BLOCK_CURSOR_KEY)?,
+                        WriteOp::Value(to_bytes(&block_number)?),
+                    ),
+                ],
+                DA_BLOCK_CURSOR_COLUMN_FAMILY_NAME.to_string(),
+            ),
+        ];
+        inner_store.write_cf_batch(cf_batches, true)?;
+        Ok(())
+    }
+
     fn append_block_by_repair(
         &self,
         last_block_number: Option<u128>,
@@ -577,4 +629,40 @@ impl DAMetaStore for DAMetaDBStore {
     fn try_get_block_state(&self, block_number: u128) -> anyhow::Result<Option<BlockSubmitState>> {
         self.get_block_state_opt(block_number)
     }
+
+    fn find_block_state_by_tx_order(
+        &self,
+        tx_order: u64,
+    ) -> anyhow::Result<Option<BlockSubmitState>> {
+        let last_block_number = match self.get_last_block_number()? {
+            Some(last_block_number) => last_block_number,
+            None => return Ok(None),
+        };
+        // blocks are continuous in tx order, binary search the block by tx_order
+        let mut low = 0u128;
+        let mut high = last_block_number;
+        while low <= high {
+            let mid = low + (high - low) / 2;
+            let block_state = match self.get_block_state_opt(mid)? {
+                Some(block_state) => block_state,
+                // the blocks before the snapshot block are missing if the node is bootstrapped from a snapshot
+                None => {
+                    low = mid + 1;
+                    continue;
+                }
+            };
+            let block_range = &block_state.block_range;
+            if tx_order < block_range.tx_order_start {
+                if mid == 0 {
+                    break;
+                }
+                high = mid - 1;
+            } else if tx_order > block_range.tx_order_end {
+                low = mid + 1;
+            } else {
+                return Ok(Some(block_state));
+            }
+        }
+        Ok(None)
+    }
 }
@@ -7,7 +7,7 @@ use crate::meta_store::{MetaDBStore, MetaStore, SEQUENCER_INFO_KEY};
 use crate::proposer_store::{ProposerDBStore, ProposerStore};
 use crate::state_store::{StateDBStore, StateStore};
 use crate::transaction_store::{TransactionDBStore, TransactionStore};
-use accumulator::{AccumulatorNode, AccumulatorTreeStore};
+use accumulator::{Accumulator, AccumulatorNode, AccumulatorTreeStore, MerkleAccumulator};
 use anyhow::Result;
 use moveos_common::utils::to_bytes;
 use moveos_config::store_config::RocksdbConfig;
@@ -21,6 +21,7 @@ use raw_store::rocks::batch::WriteBatch;
 use raw_store::rocks::RocksDB;
 use raw_store::traits::DBStore;
 use raw_store::{ColumnFamilyName, StoreInstance};
+use rooch_types::block::{Block, TransactionAccumulatorProof};
 use rooch_types::da::batch::{BlockRange, BlockSubmitState};
 use rooch_types::sequencer::SequencerInfo;
 use rooch_types::transaction::LedgerTransaction;
@@ -52,6 +53,7 @@ pub const DA_BLOCK_SUBMIT_STATE_COLUMN_FAMILY_NAME: ColumnFamilyName = "da_block
 pub const DA_BLOCK_CURSOR_COLUMN_FAMILY_NAME: ColumnFamilyName = "da_last_block_number";
 
 pub const PROPOSER_LAST_BLOCK_COLUMN_FAMILY_NAME: ColumnFamilyName = "proposer_last_block";
+pub const PROPOSER_BLOCK_COLUMN_FAMILY_NAME: ColumnFamilyName = "proposer_block";
 
 ///db store use cf_name vec to init
 /// Please note that adding a column family needs to be added in vec simultaneously, remember！！
@@ -65,6 +67,7 @@ static VEC_COLUMN_FAMILY_NAME: Lazy<Vec<ColumnFamilyName>> = Lazy::new(|| {
         DA_BLOCK_SUBMIT_STATE_COLUMN_FAMILY_NAME,
         DA_BLOCK_CURSOR_COLUMN_FAMILY_NAME,
         PROPOSER_LAST_BLOCK_COLUMN_FAMILY_NAME,
+        PROPOSER_BLOCK_COLUMN_FAMILY_NAME,
     ]
 });
 
@@ -197,6 +200,61 @@ impl RoochStore {
         Ok(())
     }
 
+    /// Get the inclusion proof of the transaction in the tx accumulator of the block it was proposed in,
+    /// return None if the transaction does not exist or has not been proposed yet.
+    pub fn get_tx_accumulator_proof(
+        &self,
+        tx_order: u64,
+    ) -> Result<Option<TransactionAccumulatorProof>> {
+        let tx_hash = match self
+            .transaction_store
+            .get_tx_hashes(vec![tx_order])?
+            .pop()
+            .flatten()
+        {
+            Some(tx_hash) => tx_hash,
+            None => return Ok(None),
+        };
+        let block_range = match self.find_block_state_by_tx_order(tx_order)? {
+            Some(block_state) => block_state.block_range,
+            None => return Ok(None),
+        };
+        let block = match self.get_proposed_block(block_range.block_number)? {
+            Some(block) => block,
+            None => return Ok(None),
+        };
+
+        // the tx accumulator of the block is the one after the last tx of the block is appended
+        let last_tx = self
+            .transaction_store
+            .get_tx_by_order(block_range.tx_order_end)?
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "LedgerTransaction not found for order: {}",
+                    block_range.tx_order_end
+                )
+            })?;
+        let tx_accumulator_info = last_tx.sequence_info.tx_accumulator_info();
+        if tx_accumulator_info.accumulator_root != block.tx_accumulator_root {
+            return Err(anyhow::anyhow!(
+                "Tx accumulator root mismatch for block: {}, exp: {:?}, act: {:?}",
+                block.block_number,
+                block.tx_accumulator_root,
+                tx_accumulator_info.accumulator_root
+            ));
+        }
+        let tx_accumulator = MerkleAccumulator::new_with_info(
+            tx_accumulator_info,
+            self.get_transaction_accumulator_store(),
+        );
+        let proof = tx_accumulator.get_proof(tx_order)?.ok_or_else(|| {
+            anyhow::anyhow!("Tx accumulator proof not found for order: {}", tx_order)
+        })?;
+        Ok(Some(TransactionAccumulatorProof::new(
+            tx_order, tx_hash, block, proof,
+        )))
+    }
+
     pub fn repair(&self, thorough: bool, _exec: bool) -> Result<(usize, usize)> {
         let sequence_info = self
             .get_sequencer_info()?
@@ -404,6 +462,11 @@ impl DAMetaStore for RoochStore {
     fn try_get_block_state(&self, block_number: u128) -> Result<Option<BlockSubmitState>> {
         self.get_da_meta_store().try_get_block_state(block_number)
     }
+
+    fn find_block_state_by_tx_order(&self, tx_order: u64) -> Result<Option<BlockSubmitState>> {
+        self.get_da_meta_store()
+            .find_block_state_by_tx_order(tx_order)
+    }
 }
 
 impl ProposerStore for RoochStore {
@@ -418,4 +481,12 @@ impl ProposerStore for RoochStore {
     fn clear_last_proposed(&self) -> Result<()> {
         self.get_proposer_store().clear_last_proposed()
     }
+
+    fn save_proposed_block(&self, block: Block) -> Result<()> {
+        self.get_proposer_store().save_proposed_block(block)
+    }
+
+    fn get_proposed_block(&self, block_number: u128) -> Result<Option<Block>> {
+        self.get_proposer_store().get_proposed_block(block_number)
+    }
 }
@@ -1,8 +1,9 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::PROPOSER_LAST_BLOCK_COLUMN_FAMILY_NAME;
+use crate::{PROPOSER_BLOCK_COLUMN_FAMILY_NAME, PROPOSER_LAST_BLOCK_COLUMN_FAMILY_NAME};
 use raw_store::{derive_store, CodecKVStore};
+use rooch_types::block::Block;
 
 pub const PROPOSER_LAST_BLOCK_KEY: &str = "proposer_last_block";
 
@@ -13,21 +14,34 @@ derive_store!(
     PROPOSER_LAST_BLOCK_COLUMN_FAMILY_NAME
 );
 
+derive_store!(
+    ProposerBlockStore,
+    u128,
+    Block,
+    PROPOSER_BLOCK_COLUMN_FAMILY_NAME
+);
+
 pub trait ProposerStore {
     fn get_last_proposed(&self) -> anyhow::Result<Option<u128>>;
     fn set_last_proposed(&self, block_number: u128) -> anyhow::Result<()>;
     fn clear_last_proposed(&self) -> anyhow::Result<()>;
+    // save the proposed block and set it as the last proposed
+    fn save_proposed_block(&self, block: Block) -> anyhow::Result<()>;
+    // get the proposed block, blocks beyond the last proposed are not returned
+    fn get_proposed_block(&self, block_number: u128) -> anyhow::Result<Option<Block>>;
 }
 
 #[derive(Clone)]
 pub struct ProposerDBStore {
     last_block_store: ProposerLastBlockStore,
+    block_store: ProposerBlockStore,
 }
 
 impl ProposerDBStore {
     pub fn new(instance: raw_store::StoreInstance) -> Self {
         ProposerDBStore {
-            last_block_store: ProposerLastBlockStore::new(instance),
+            last_block_store: ProposerLastBlockStore::new(instance.clone()),
+            block_store: ProposerBlockStore::new(instance),
         }
     }
 }
@@ -47,4 +61,21 @@ impl ProposerStore for ProposerDBStore {
         self.last_block_store
             .remove(PROPOSER_LAST_BLOCK_KEY.to_string())
     }
+
+    fn save_proposed_block(&self, block: Block) -> anyhow::Result<()> {
+        let block_number = block.block_number;
+        // the block must be saved before the last proposed is updated,
+        // so the blocks up to the last proposed could always be found
+        self.block_store.put_sync(block_number, block)?;
+        self.set_last_proposed(block_number)
+    }
+
+    fn get_proposed_block(&self, block_number: u128) -> anyhow::Result<Option<Block>> {
+        match self.get_last_proposed()? {
+            Some(last_proposed) if block_number <= last_proposed => {
+                self.block_store.kv_get(block_number)
+            }
+            _ => Ok(None),
+        }
+    }
 }
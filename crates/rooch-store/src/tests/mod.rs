@@ -2,4 +2,5 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod test_accumulator;
+mod test_accumulator_proof;
 mod test_da_store;
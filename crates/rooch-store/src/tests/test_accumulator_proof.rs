@@ -0,0 +1,83 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::da_store::DAMetaStore;
+use crate::proposer_store::ProposerStore;
+use crate::RoochStore;
+use accumulator::{Accumulator, MerkleAccumulator};
+use moveos_types::h256::H256;
+use rooch_types::block::Block;
+use rooch_types::sequencer::SequencerInfo;
+use rooch_types::transaction::{LedgerTransaction, LedgerTxData, RoochTransaction};
+
+#[tokio::test]
+async fn test_tx_accumulator_proof() {
+    let (rooch_store, _) = RoochStore::mock_rooch_store().unwrap();
+    let tx_accumulator =
+        MerkleAccumulator::new_empty(rooch_store.get_transaction_accumulator_store());
+
+    let mut tx_hashes = vec![];
+    let mut tx_accumulator_roots = vec![];
+    for tx_order in 0..10u64 {
+        let mut tx_data = LedgerTxData::L2Tx(RoochTransaction::mock());
+        let tx_hash = tx_data.tx_hash();
+        tx_accumulator.append(vec![tx_hash].as_slice()).unwrap();
+        let unsaved_nodes = tx_accumulator.pop_unsaved_nodes();
+        let tx_accumulator_info = tx_accumulator.get_info();
+        let tx = LedgerTransaction::build_ledger_transaction(
+            tx_data,
+            0,
+            tx_order,
+            vec![],
+            tx_accumulator_info.clone(),
+        );
+        rooch_store
+            .save_sequenced_tx(
+                tx_hash,
+                tx,
+                SequencerInfo::new(tx_order, tx_accumulator_info.clone()),
+                unsaved_nodes,
+                true,
+            )
+            .unwrap();
+        tx_accumulator.clear_after_save();
+        tx_hashes.push(tx_hash);
+        tx_accumulator_roots.push(tx_accumulator_info.accumulator_root);
+    }
+
+    rooch_store.append_submitting_block(0, 4).unwrap();
+    rooch_store.append_submitting_block(5, 9).unwrap();
+    let block = Block::new(
+        0,
+        5,
+        H256::random(),
+        H256::zero(),
+        tx_accumulator_roots[4],
+        H256::random(),
+    );
+    rooch_store.save_proposed_block(block.clone()).unwrap();
+
+    for tx_order in 0..5u64 {
+        let proof = rooch_store
+            .get_tx_accumulator_proof(tx_order)
+            .unwrap()
+            .unwrap();
+        assert_eq!(proof.tx_hash, tx_hashes[tx_order as usize]);
+        assert_eq!(proof.block, block);
+        proof.verify(&block).unwrap();
+    }
+
+    // the proof should not be verified against the other block
+    let proof = rooch_store.get_tx_accumulator_proof(2).unwrap().unwrap();
+    let mut other_block = block.clone();
+    other_block.tx_accumulator_root = tx_accumulator_roots[9];
+    assert!(proof.verify(&other_block).is_err());
+    let mut wrong_proof = proof.clone();
+    wrong_proof.block = other_block.clone();
+    assert!(wrong_proof.verify(&other_block).is_err());
+
+    // the block of the transaction has not been proposed
+    assert!(rooch_store.get_tx_accumulator_proof(7).unwrap().is_none());
+    // the transaction does not exist
+    assert!(rooch_store.get_tx_accumulator_proof(10).unwrap().is_none());
+}
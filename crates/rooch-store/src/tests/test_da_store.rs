@@ -3,6 +3,7 @@
 
 use crate::da_store::{DAMetaDBStore, DAMetaStore, MAX_TXS_PER_BLOCK_IN_FIX};
 use crate::RoochStore;
+use moveos_types::h256::H256;
 use rooch_types::da::batch::BlockRange;
 
 #[tokio::test]
@@ -91,6 +92,61 @@ async fn generate_remove_blocks() {
     check_remove_blocks(13, da_meta_store, Some(2), 1024, 0, None);
 }
 
+#[tokio::test]
+async fn init_with_submitted_block() {
+    let (rooch_store, _) = RoochStore::mock_rooch_store().unwrap();
+    let da_meta_store = rooch_store.get_da_meta_store();
+
+    let block_range = BlockRange {
+        block_number: 5,
+        tx_order_start: 21,
+        tx_order_end: 30,
+    };
+    let batch_hash = H256::random();
+    da_meta_store
+        .init_with_submitted_block(block_range.clone(), batch_hash)
+        .unwrap();
+    assert!(da_meta_store
+        .init_with_submitted_block(block_range.clone(), batch_hash)
+        .is_err());
+    assert_eq!(da_meta_store.get_last_block_number().unwrap(), Some(5));
+    assert_eq!(
+        da_meta_store.get_background_submit_block_cursor().unwrap(),
+        Some(5)
+    );
+    let block_state = da_meta_store.get_block_state(5).unwrap();
+    assert!(block_state.done);
+    assert_eq!(block_state.batch_hash, batch_hash);
+
+    // the following blocks are appended after the snapshot block
+    assert_eq!(da_meta_store.append_submitting_block(31, 40).unwrap(), 6);
+    let submitting_blocks = da_meta_store.get_submitting_blocks(5, None).unwrap();
+    assert_eq!(submitting_blocks.len(), 1);
+    assert_eq!(submitting_blocks[0].block_number, 6);
+    // the blocks before the snapshot block are missing
+    assert!(da_meta_store
+        .find_block_state_by_tx_order(10)
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        da_meta_store
+            .find_block_state_by_tx_order(25)
+            .unwrap()
+            .unwrap()
+            .block_range,
+        block_range
+    );
+    assert_eq!(
+        da_meta_store
+            .find_block_state_by_tx_order(35)
+            .unwrap()
+            .unwrap()
+            .block_range
+            .block_number,
+        6
+    );
+}
+
 #[tokio::test]
 async fn catch_up_last_tx_order() {
     let (rooch_store, _) = RoochStore::mock_rooch_store().unwrap();
@@ -1,6 +1,8 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use accumulator::proof::AccumulatorProof;
+use anyhow::{ensure, Result};
 use moveos_types::h256::H256;
 use serde::{Deserialize, Serialize};
 
@@ -40,3 +42,40 @@ impl Block {
         }
     }
 }
+
+/// The inclusion proof of a transaction in the tx accumulator of the block it was proposed in
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransactionAccumulatorProof {
+    /// The order of the transaction, it is the leaf index in the tx accumulator
+    pub tx_order: u64,
+    /// The hash of the transaction, it is the leaf in the tx accumulator
+    pub tx_hash: H256,
+    /// The block which the transaction was proposed in
+    pub block: Block,
+    /// The proof against the tx accumulator root of the block
+    pub proof: AccumulatorProof,
+}
+
+impl TransactionAccumulatorProof {
+    pub fn new(tx_order: u64, tx_hash: H256, block: Block, proof: AccumulatorProof) -> Self {
+        Self {
+            tx_order,
+            tx_hash,
+            block,
+            proof,
+        }
+    }
+
+    /// Verify the transaction is included in the `block`,
+    /// the `block` should be a trusted one, such as the block committed by the proposer.
+    pub fn verify(&self, block: &Block) -> Result<()> {
+        ensure!(
+            self.block == *block,
+            "Block mismatch, expected block: {}, actual block: {}",
+            block.block_number,
+            self.block.block_number
+        );
+        self.proof
+            .verify(block.tx_accumulator_root, self.tx_hash, self.tx_order)
+    }
+}
@@ -2,7 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
-use anyhow::Result;
+use crate::crypto::{RoochKeyPair, RoochSignature, SignatureScheme};
+use anyhow::{ensure, Result};
 use move_core_types::{
     account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
 };
@@ -11,7 +12,7 @@ use moveos_types::{
     move_std::option::MoveOption,
     moveos_std::tx_context::TxContext,
     state::{MoveState, MoveStructState, MoveStructType},
-    transaction::FunctionCall,
+    transaction::{FunctionCall, MoveAction},
 };
 use serde::{Deserialize, Serialize};
 
@@ -99,3 +100,112 @@ impl<'a> ModuleBinding<'a> for AuthenticationKeyModule<'a> {
         Self { caller }
     }
 }
+
+/// The prefix of the message which the new key signs to accept the rotation, it tags the message with the rotation domain
+pub const ROTATION_MESSAGE_PREFIX: &[u8] = b"Rooch Auth Key Rotation:\n";
+
+/// The message which the new key signs to accept the rotation, it should be the same as the Move `rotation_message`
+pub fn rotation_message(
+    chain_id: u64,
+    account_addr: AccountAddress,
+    auth_validator_id: u64,
+    sequence_number: u64,
+) -> Vec<u8> {
+    let mut message = ROTATION_MESSAGE_PREFIX.to_vec();
+    message.extend(bcs::to_bytes(&chain_id).expect("Serialize u64 should success"));
+    message.extend(bcs::to_bytes(&account_addr).expect("Serialize address should success"));
+    message.extend(bcs::to_bytes(&auth_validator_id).expect("Serialize u64 should success"));
+    message.extend(bcs::to_bytes(&sequence_number).expect("Serialize u64 should success"));
+    message
+}
+
+/// Rust bindings for RoochFramework account_authentication module
+pub struct AccountAuthenticationModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> AccountAuthenticationModule<'a> {
+    const ROTATED_AUTH_KEY_FUNCTION_NAME: &'static IdentStr = ident_str!("rotated_auth_key");
+    const ROTATE_AUTH_KEY_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("rotate_auth_key_entry");
+
+    pub fn rotated_auth_key(
+        &self,
+        address: AccountAddress,
+        auth_validator_id: u64,
+    ) -> Result<Option<Vec<u8>>> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::ROTATED_AUTH_KEY_FUNCTION_NAME),
+            vec![],
+            vec![
+                MoveValue::Address(address)
+                    .simple_serialize()
+                    .expect("address should serialize"),
+                MoveValue::U64(auth_validator_id)
+                    .simple_serialize()
+                    .expect("u64 should serialize"),
+            ],
+        );
+        let ctx = TxContext::new_readonly_ctx(address);
+        let auth_key = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|values| {
+                let value = values.first().expect("Expected return value");
+                let result = MoveOption::<Vec<u8>>::from_bytes(&value.value)
+                    .expect("Expected Option<vector<u8>>");
+                result.into()
+            })?;
+        Ok(auth_key)
+    }
+
+    pub fn rotate_auth_key_action(
+        auth_validator_id: u64,
+        new_public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> MoveAction {
+        Self::create_move_action(
+            Self::ROTATE_AUTH_KEY_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::U64(auth_validator_id),
+                MoveValue::vector_u8(new_public_key),
+                MoveValue::vector_u8(signature),
+            ],
+        )
+    }
+
+    /// Build the rotation action, the new key pair signs the rotation message of the rotation transaction
+    pub fn rotate_auth_key_action_with_key_pair(
+        chain_id: u64,
+        account_addr: AccountAddress,
+        auth_validator_id: u64,
+        sequence_number: u64,
+        new_kp: &RoochKeyPair,
+    ) -> Result<MoveAction> {
+        ensure!(
+            new_kp.public().scheme() == SignatureScheme::Secp256k1,
+            "Only secp256k1 key is supported for the auth key rotation"
+        );
+        let message = rotation_message(chain_id, account_addr, auth_validator_id, sequence_number);
+        let signature = new_kp.sign(&message).signature_bytes().to_vec();
+        Ok(Self::rotate_auth_key_action(
+            auth_validator_id,
+            new_kp.public().as_ref().to_vec(),
+            signature,
+        ))
+    }
+}
+
+impl<'a> ModuleBinding<'a> for AccountAuthenticationModule<'a> {
+    const MODULE_NAME: &'static IdentStr = ident_str!("account_authentication");
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
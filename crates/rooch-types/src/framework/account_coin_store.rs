@@ -2,11 +2,17 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
-use move_core_types::language_storage::StructTag;
+use anyhow::Result;
+use move_core_types::language_storage::{StructTag, TypeTag};
+use move_core_types::u256::U256;
+use move_core_types::value::MoveValue;
 use move_core_types::{account_address::AccountAddress, ident_str, identifier::IdentStr};
 use moveos_types::module_binding::{ModuleBinding, MoveFunctionCaller};
 use moveos_types::moveos_std::object::{self, ObjectID};
+use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::state::PlaceholderStruct;
+use moveos_types::state_override::ObjectOverride;
+use moveos_types::transaction::FunctionCall;
 
 use super::coin_store::CoinStore;
 
@@ -14,17 +20,58 @@ pub const MODULE_NAME: &IdentStr = ident_str!("account_coin_store");
 
 /// Rust bindings for RoochFramework account_coin_store module
 pub struct AccountCoinStoreModule<'a> {
-    _caller: &'a dyn MoveFunctionCaller,
+    caller: &'a dyn MoveFunctionCaller,
 }
 
 impl<'a> AccountCoinStoreModule<'a> {
     pub const COIN_STORE_ID: &'static IdentStr = ident_str!("coin_store_id");
+    pub const BALANCE_FUNCTION_NAME: &'static IdentStr = ident_str!("balance");
+
+    /// The balance of the coin type of the account, it is zero if the coin store does not exist.
+    pub fn balance(&self, addr: AccountAddress, coin_type: StructTag) -> Result<U256> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::BALANCE_FUNCTION_NAME),
+            vec![TypeTag::Struct(Box::new(coin_type))],
+            vec![MoveValue::Address(addr)
+                .simple_serialize()
+                .expect("address should serialize")],
+        );
+        let ctx = TxContext::new_readonly_ctx(addr);
+        let balance = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<U256>(&value.value).expect("should be a valid u256")
+            })?;
+        Ok(balance)
+    }
 
     pub fn account_coin_store_id(addr: AccountAddress, coin_type: StructTag) -> ObjectID {
         let coin_store_struct_tag =
             CoinStore::<PlaceholderStruct>::struct_tag_with_coin_type(coin_type);
         object::account_named_object_id(addr, &coin_store_struct_tag)
     }
+
+    /// Override the balance of the account coin store when simulating a transaction.
+    /// The coin store is created if it does not exist, and it is not frozen after the override.
+    pub fn balance_override(
+        addr: AccountAddress,
+        coin_type: StructTag,
+        balance: U256,
+    ) -> Result<ObjectOverride> {
+        let coin_store_id = Self::account_coin_store_id(addr, coin_type.clone());
+        let coin_store_struct_tag =
+            CoinStore::<PlaceholderStruct>::struct_tag_with_coin_type(coin_type);
+        let coin_store = CoinStore::<PlaceholderStruct>::new(balance, false);
+        Ok(ObjectOverride::new(
+            coin_store_id,
+            Some(addr),
+            Some(coin_store_struct_tag.into()),
+            bcs::to_bytes(&coin_store)?,
+        ))
+    }
 }
 
 impl<'a> ModuleBinding<'a> for AccountCoinStoreModule<'a> {
@@ -35,6 +82,6 @@ impl<'a> ModuleBinding<'a> for AccountCoinStoreModule<'a> {
     where
         Self: Sized,
     {
-        Self { _caller: caller }
+        Self { caller }
     }
 }
@@ -49,6 +49,9 @@ pub enum BuiltinAuthValidator {
     Bitcoin,
     BitcoinMultisign,
     Ethereum,
+    Webauthn,
+    Sponsor,
+    WeightedMultisign,
 }
 
 impl BuiltinAuthValidator {
@@ -56,6 +59,9 @@ impl BuiltinAuthValidator {
     const BITCOIN_FLAG: u8 = 0x01;
     const BITCOIN_MULTISIGN: u8 = 0x02;
     const ETHEREUM_FLAG: u8 = 0x03;
+    const WEBAUTHN_FLAG: u8 = 0x04;
+    const SPONSOR_FLAG: u8 = 0x05;
+    const WEIGHTED_MULTISIGN_FLAG: u8 = 0x06;
 
     pub fn flag(&self) -> u8 {
         match self {
@@ -63,6 +69,9 @@ impl BuiltinAuthValidator {
             BuiltinAuthValidator::Bitcoin => Self::BITCOIN_FLAG,
             BuiltinAuthValidator::BitcoinMultisign => Self::BITCOIN_MULTISIGN,
             BuiltinAuthValidator::Ethereum => Self::ETHEREUM_FLAG,
+            BuiltinAuthValidator::Webauthn => Self::WEBAUTHN_FLAG,
+            BuiltinAuthValidator::Sponsor => Self::SPONSOR_FLAG,
+            BuiltinAuthValidator::WeightedMultisign => Self::WEIGHTED_MULTISIGN_FLAG,
         }
     }
 
@@ -79,6 +88,9 @@ impl BuiltinAuthValidator {
             Self::BITCOIN_FLAG => Ok(BuiltinAuthValidator::Bitcoin),
             Self::BITCOIN_MULTISIGN => Ok(BuiltinAuthValidator::BitcoinMultisign),
             Self::ETHEREUM_FLAG => Ok(BuiltinAuthValidator::Ethereum),
+            Self::WEBAUTHN_FLAG => Ok(BuiltinAuthValidator::Webauthn),
+            Self::SPONSOR_FLAG => Ok(BuiltinAuthValidator::Sponsor),
+            Self::WEIGHTED_MULTISIGN_FLAG => Ok(BuiltinAuthValidator::WeightedMultisign),
             _ => Err(RoochError::KeyConversionError(
                 "Invalid key auth validator".to_owned(),
             )),
@@ -108,6 +120,22 @@ impl BuiltinAuthValidator {
                 module_address: ROOCH_NURSERY_ADDRESS,
                 module_name: MoveString::from_str("ethereum_validator").expect("Should be valid"),
             },
+            BuiltinAuthValidator::Webauthn => AuthValidator {
+                id: self.flag().into(),
+                module_address: ROOCH_FRAMEWORK_ADDRESS,
+                module_name: MoveString::from_str("webauthn_validator").expect("Should be valid"),
+            },
+            BuiltinAuthValidator::Sponsor => AuthValidator {
+                id: self.flag().into(),
+                module_address: ROOCH_FRAMEWORK_ADDRESS,
+                module_name: MoveString::from_str("sponsor_validator").expect("Should be valid"),
+            },
+            BuiltinAuthValidator::WeightedMultisign => AuthValidator {
+                id: self.flag().into(),
+                module_address: ROOCH_FRAMEWORK_ADDRESS,
+                module_name: MoveString::from_str("weighted_multisign_validator")
+                    .expect("Should be valid"),
+            },
         }
     }
 }
@@ -14,6 +14,7 @@ use move_core_types::{
 };
 use moveos_types::{
     module_binding::{ModuleBinding, MoveFunctionCaller},
+    move_std::option::MoveOption,
     moveos_std::tx_context::TxContext,
     transaction::FunctionCall,
 };
@@ -93,6 +94,97 @@ impl<T> TryFrom<&Block<T>> for BlockHeader {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Log {
+    /// Address from which this log originated
+    pub address: ETHAddress,
+    /// Indexed topics of the log
+    pub topics: Vec<Vec<u8>>,
+    /// Data of the log
+    pub data: Vec<u8>,
+    /// Index of the log in the block
+    pub log_index: u64,
+}
+
+impl TryFrom<&ethers::types::Log> for Log {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &ethers::types::Log) -> std::result::Result<Self, Self::Error> {
+        Ok(Log {
+            address: value.address.into(),
+            topics: value
+                .topics
+                .iter()
+                .map(|topic| topic.as_bytes().to_vec())
+                .collect(),
+            data: value.data.to_vec(),
+            log_index: value
+                .log_index
+                .ok_or_else(|| anyhow::format_err!("Unexpected pending log"))?
+                .as_u64(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionReceipt {
+    /// Hash of the transaction
+    pub transaction_hash: Vec<u8>,
+    /// Index of the transaction in the block
+    pub transaction_index: u64,
+    /// Sender of the transaction
+    pub from: ETHAddress,
+    /// Status of the transaction, 1 for success and 0 for failure
+    pub status: u64,
+    /// Gas used by the transaction
+    pub gas_used: U256,
+    /// Cumulative gas used in the block after the transaction
+    pub cumulative_gas_used: U256,
+    /// Logs emitted by the transaction
+    pub logs: Vec<Log>,
+}
+
+impl TryFrom<&ethers::types::TransactionReceipt> for TransactionReceipt {
+    type Error = anyhow::Error;
+
+    fn try_from(
+        value: &ethers::types::TransactionReceipt,
+    ) -> std::result::Result<Self, Self::Error> {
+        Ok(TransactionReceipt {
+            transaction_hash: value.transaction_hash.as_bytes().to_vec(),
+            transaction_index: value.transaction_index.as_u64(),
+            from: value.from.into(),
+            //The status is None before Byzantium, treat it as success
+            status: value.status.map(|status| status.as_u64()).unwrap_or(1),
+            gas_used: eth_u256_to_move_u256(&value.gas_used.unwrap_or_default()),
+            cumulative_gas_used: eth_u256_to_move_u256(&value.cumulative_gas_used),
+            logs: value
+                .logs
+                .iter()
+                .map(Log::try_from)
+                .collect::<Result<Vec<_>>>()?,
+        })
+    }
+}
+
+/// The block header with the receipts of the transactions in the block,
+/// it is the block body relayed to Rooch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockWithReceipts {
+    pub header: BlockHeader,
+    pub receipts: Vec<TransactionReceipt>,
+}
+
+impl BlockWithReceipts {
+    pub fn new(header: BlockHeader, receipts: Vec<TransactionReceipt>) -> Self {
+        Self { header, receipts }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("encode block with receipts should success")
+    }
+}
+
 /// Rust bindings for RoochFramework ethereum_light_client module
 pub struct EthereumModule<'a> {
     caller: &'a dyn MoveFunctionCaller,
@@ -100,7 +192,13 @@ pub struct EthereumModule<'a> {
 
 impl<'a> EthereumModule<'a> {
     pub const GET_BLOCK_FUNCTION_NAME: &'static IdentStr = ident_str!("get_block");
+    pub const EXISTS_BLOCK_FUNCTION_NAME: &'static IdentStr = ident_str!("exists_block");
+    pub const GET_LATEST_BLOCK_NUMBER_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("get_latest_block_number");
+    pub const GET_RECEIPTS_FUNCTION_NAME: &'static IdentStr = ident_str!("get_receipts");
     pub const EXECUTE_L1_BLOCK_FUNCTION_NAME: &'static IdentStr = ident_str!("execute_l1_block");
+    pub const EXECUTE_L1_BLOCK_WITH_RECEIPTS_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("execute_l1_block_with_receipts");
 
     pub fn get_block(&self, block_number: u64) -> Result<BlockHeader> {
         let call = FunctionCall::new(
@@ -121,6 +219,59 @@ impl<'a> EthereumModule<'a> {
         Ok(block_header)
     }
 
+    pub fn exists_block(&self, block_number: u64) -> Result<bool> {
+        let call = Self::create_function_call(
+            Self::EXISTS_BLOCK_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::U64(block_number)],
+        );
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let exists = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<bool>(&value.value).expect("should be a valid bool")
+            })?;
+        Ok(exists)
+    }
+
+    pub fn get_latest_block_number(&self) -> Result<Option<u64>> {
+        let call =
+            Self::create_function_call(Self::GET_LATEST_BLOCK_NUMBER_FUNCTION_NAME, vec![], vec![]);
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let block_number =
+            self.caller
+                .call_function(&ctx, call)?
+                .into_result()
+                .map(|mut values| {
+                    let value = values.pop().expect("should have one return value");
+                    bcs::from_bytes::<MoveOption<u64>>(&value.value)
+                        .expect("should be a valid MoveOption<u64>")
+                })?;
+        Ok(block_number.into())
+    }
+
+    pub fn get_receipts(&self, block_number: u64) -> Result<Vec<TransactionReceipt>> {
+        let call = Self::create_function_call(
+            Self::GET_RECEIPTS_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::U64(block_number)],
+        );
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let receipts = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<Vec<TransactionReceipt>>(&value.value)
+                    .expect("should be a valid Vec<TransactionReceipt>")
+            })?;
+        Ok(receipts)
+    }
+
     pub fn create_execute_l1_block_call(block_header: &BlockHeader) -> FunctionCall {
         Self::create_function_call(
             Self::EXECUTE_L1_BLOCK_FUNCTION_NAME,
@@ -138,6 +289,14 @@ impl<'a> EthereumModule<'a> {
             vec![MoveValue::vector_u8(block_header)],
         )
     }
+
+    pub fn create_execute_l1_block_with_receipts_call_bytes(block: Vec<u8>) -> FunctionCall {
+        Self::create_function_call(
+            Self::EXECUTE_L1_BLOCK_WITH_RECEIPTS_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::vector_u8(block)],
+        )
+    }
 }
 
 impl<'a> ModuleBinding<'a> for EthereumModule<'a> {
@@ -4,9 +4,12 @@
 use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
 use crate::authentication_key::AuthenticationKey;
 use anyhow::Result;
+use move_core_types::language_storage::StructTag;
+use move_core_types::u256::U256;
 use move_core_types::value::MoveValue;
 use move_core_types::{account_address::AccountAddress, ident_str, identifier::IdentStr};
 use moveos_types::moveos_std::object::ObjectID;
+use moveos_types::moveos_std::simple_map::SimpleMap;
 use moveos_types::{
     module_binding::{ModuleBinding, MoveFunctionCaller},
     move_std::option::MoveOption,
@@ -159,6 +162,7 @@ impl SessionKey {
             MoveAction::Script(_) => false,
             MoveAction::ModuleBundle(_) => false,
             MoveAction::Function(function) => self.is_scope_match(function),
+            MoveAction::Batch(_) => false,
         }
     }
 }
@@ -183,6 +187,58 @@ impl MoveStructState for SessionKey {
     }
 }
 
+/// The quota of the session key, a none max value means unlimited
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct SessionKeyQuota {
+    pub max_gas_amount: MoveOption<u64>,
+    pub max_tx_count: MoveOption<u64>,
+    /// The max coin amounts, keyed by the coin type name
+    pub max_coin_amounts: SimpleMap<MoveString, U256>,
+    pub used_gas_amount: u64,
+    pub tx_count: u64,
+    /// The spent coin amounts, keyed by the coin type name
+    pub spent_coin_amounts: SimpleMap<MoveString, U256>,
+}
+
+impl SessionKeyQuota {
+    /// The coin type name used as the key of the coin quotas, it is the same as `type_info::type_name` in Move
+    pub fn coin_type_name(coin_type: &StructTag) -> MoveString {
+        MoveString::from(coin_type.to_canonical_display(false).to_string())
+    }
+
+    pub fn max_coin_amount(&self, coin_type: &StructTag) -> Option<U256> {
+        self.max_coin_amounts
+            .borrow(&Self::coin_type_name(coin_type))
+            .cloned()
+    }
+
+    pub fn spent_coin_amount(&self, coin_type: &StructTag) -> U256 {
+        self.spent_coin_amounts
+            .borrow(&Self::coin_type_name(coin_type))
+            .cloned()
+            .unwrap_or_else(U256::zero)
+    }
+}
+
+impl MoveStructType for SessionKeyQuota {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("SessionKeyQuota");
+}
+
+impl MoveStructState for SessionKeyQuota {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            MoveOption::<u64>::type_layout(),
+            MoveOption::<u64>::type_layout(),
+            SimpleMap::<MoveString, U256>::type_layout(),
+            move_core_types::value::MoveTypeLayout::U64,
+            move_core_types::value::MoveTypeLayout::U64,
+            SimpleMap::<MoveString, U256>::type_layout(),
+        ])
+    }
+}
+
 /// Rust bindings for RoochFramework session_key module
 pub struct SessionKeyModule<'a> {
     caller: &'a dyn MoveFunctionCaller,
@@ -194,6 +250,14 @@ impl<'a> SessionKeyModule<'a> {
         ident_str!("create_session_key_entry");
     pub const GET_SESSION_KEYS_HANDLE_FUNCTION_NAME: &'static IdentStr =
         ident_str!("get_session_keys_handle");
+    pub const GET_SESSION_KEY_QUOTA_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("get_session_key_quota");
+    pub const GET_SESSION_KEY_QUOTAS_HANDLE_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("get_session_key_quotas_handle");
+    pub const CREATE_SESSION_KEY_WITH_QUOTA_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("create_session_key_with_quota_entry");
+    pub const SET_SESSION_KEY_QUOTA_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("set_session_key_quota_entry");
 
     pub fn get_session_key(
         &self,
@@ -251,6 +315,62 @@ impl<'a> SessionKeyModule<'a> {
         Ok(obj_id)
     }
 
+    pub fn get_session_key_quota(
+        &self,
+        account_address: AccountAddress,
+        auth_key: &AuthenticationKey,
+    ) -> Result<Option<SessionKeyQuota>> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::GET_SESSION_KEY_QUOTA_FUNCTION_NAME),
+            vec![],
+            vec![
+                MoveValue::Address(account_address)
+                    .simple_serialize()
+                    .unwrap(),
+                MoveValue::vector_u8(auth_key.as_ref().to_vec())
+                    .simple_serialize()
+                    .unwrap(),
+            ],
+        );
+        let ctx = TxContext::new_readonly_ctx(account_address);
+        let quota = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<MoveOption<SessionKeyQuota>>(&value.value)
+                    .expect("should be a valid MoveOption<SessionKeyQuota>")
+                    .into()
+            })?;
+        Ok(quota)
+    }
+
+    pub fn get_session_key_quotas_handle(
+        &self,
+        account_address: AccountAddress,
+    ) -> Result<Option<ObjectID>> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::GET_SESSION_KEY_QUOTAS_HANDLE_FUNCTION_NAME),
+            vec![],
+            vec![MoveValue::Address(account_address)
+                .simple_serialize()
+                .unwrap()],
+        );
+        let ctx = TxContext::new_readonly_ctx(account_address);
+        let obj_id = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<MoveOption<ObjectID>>(&value.value)
+                    .expect("should be a valid MoveOption<ObjectID>")
+                    .into()
+            })?;
+        Ok(obj_id)
+    }
+
     pub fn create_session_key_action(
         app_name: MoveString,
         app_url: MoveString,
@@ -272,6 +392,83 @@ impl<'a> SessionKeyModule<'a> {
             ],
         )
     }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_session_key_with_quota_action(
+        app_name: MoveString,
+        app_url: MoveString,
+        authentication_key: Vec<u8>,
+        scopes: Vec<SessionScope>,
+        max_inactive_interval: u64,
+        max_gas_amount: Option<u64>,
+        max_tx_count: Option<u64>,
+        max_coin_amounts: Vec<(StructTag, U256)>,
+    ) -> MoveAction {
+        let mut module_addresses = vec![];
+        let mut module_names = vec![];
+        let mut function_names = vec![];
+        for scope in scopes {
+            module_addresses.push(scope.module_address.to_move_value());
+            module_names.push(scope.module_name.to_move_value());
+            function_names.push(scope.function_name.to_move_value());
+        }
+        let (coin_types, max_coin_amounts) = Self::coin_quota_values(max_coin_amounts);
+        Self::create_move_action(
+            Self::CREATE_SESSION_KEY_WITH_QUOTA_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                app_name.to_move_value(),
+                app_url.to_move_value(),
+                MoveValue::vector_u8(authentication_key),
+                MoveValue::Vector(module_addresses),
+                MoveValue::Vector(module_names),
+                MoveValue::Vector(function_names),
+                MoveValue::U64(max_inactive_interval),
+                Self::optional_u64_value(max_gas_amount),
+                Self::optional_u64_value(max_tx_count),
+                coin_types,
+                max_coin_amounts,
+            ],
+        )
+    }
+
+    pub fn set_session_key_quota_action(
+        authentication_key: Vec<u8>,
+        max_gas_amount: Option<u64>,
+        max_tx_count: Option<u64>,
+        max_coin_amounts: Vec<(StructTag, U256)>,
+    ) -> MoveAction {
+        let (coin_types, max_coin_amounts) = Self::coin_quota_values(max_coin_amounts);
+        Self::create_move_action(
+            Self::SET_SESSION_KEY_QUOTA_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::vector_u8(authentication_key),
+                Self::optional_u64_value(max_gas_amount),
+                Self::optional_u64_value(max_tx_count),
+                coin_types,
+                max_coin_amounts,
+            ],
+        )
+    }
+
+    /// The entry functions take the optional value as a vector, the empty vector means none
+    fn optional_u64_value(value: Option<u64>) -> MoveValue {
+        MoveValue::Vector(value.into_iter().map(MoveValue::U64).collect())
+    }
+
+    fn coin_quota_values(max_coin_amounts: Vec<(StructTag, U256)>) -> (MoveValue, MoveValue) {
+        let (coin_types, amounts): (Vec<_>, Vec<_>) = max_coin_amounts
+            .into_iter()
+            .map(|(coin_type, amount)| {
+                (
+                    SessionKeyQuota::coin_type_name(&coin_type).to_move_value(),
+                    MoveValue::U256(amount),
+                )
+            })
+            .unzip();
+        (MoveValue::Vector(coin_types), MoveValue::Vector(amounts))
+    }
 }
 
 impl<'a> ModuleBinding<'a> for SessionKeyModule<'a> {
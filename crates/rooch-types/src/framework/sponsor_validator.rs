@@ -0,0 +1,87 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::auth_validator::BuiltinAuthValidator;
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use crate::transaction::Authenticator;
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    identifier::IdentStr,
+    value::{MoveStructLayout, MoveTypeLayout},
+};
+use moveos_types::state::{MoveStructState, MoveStructType};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("sponsor_validator");
+
+/// Sponsor Auth Validator, the gas of the transaction is paid by the gas payer
+pub struct SponsorValidator {}
+
+impl SponsorValidator {
+    pub fn auth_validator_id() -> u64 {
+        BuiltinAuthValidator::Sponsor.flag().into()
+    }
+}
+
+impl MoveStructType for SponsorValidator {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("SponsorValidator");
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SponsorAuthPayload {
+    // The auth validator id of the sender's authenticator
+    pub sender_auth_validator_id: u64,
+    // The payload of the sender's authenticator
+    pub sender_auth_payload: Vec<u8>,
+    // The account which pays the gas
+    pub gas_payer: AccountAddress,
+    // The auth validator id of the gas payer's authenticator
+    pub gas_payer_auth_validator_id: u64,
+    // The payload of the gas payer's authenticator
+    pub gas_payer_auth_payload: Vec<u8>,
+}
+
+impl MoveStructType for SponsorAuthPayload {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("SponsorAuthPayload");
+}
+
+impl MoveStructState for SponsorAuthPayload {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![
+            MoveTypeLayout::U64,
+            MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+            MoveTypeLayout::Address,
+            MoveTypeLayout::U64,
+            MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+        ])
+    }
+}
+
+impl SponsorAuthPayload {
+    pub fn new(
+        sender_authenticator: Authenticator,
+        gas_payer: AccountAddress,
+        gas_payer_authenticator: Authenticator,
+    ) -> Self {
+        Self {
+            sender_auth_validator_id: sender_authenticator.auth_validator_id,
+            sender_auth_payload: sender_authenticator.payload,
+            gas_payer,
+            gas_payer_auth_validator_id: gas_payer_authenticator.auth_validator_id,
+            gas_payer_auth_payload: gas_payer_authenticator.payload,
+        }
+    }
+
+    /// The authenticator of the sender wrapped in the sponsor payload
+    pub fn sender_authenticator(&self) -> Authenticator {
+        Authenticator::new(
+            self.sender_auth_validator_id,
+            self.sender_auth_payload.clone(),
+        )
+    }
+}
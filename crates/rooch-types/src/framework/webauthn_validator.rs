@@ -0,0 +1,278 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::auth_validator::BuiltinAuthValidator;
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use crate::crypto::{RoochSignature, Signature, SignatureScheme};
+use crate::transaction::RoochTransactionData;
+use anyhow::{ensure, Result};
+use fastcrypto::{
+    encoding::{Base64, Encoding},
+    secp256r1::{Secp256r1PublicKey, Secp256r1Signature},
+    traits::{ToFromBytes, VerifyingKey},
+};
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
+};
+use moveos_types::{
+    h256::{sha2_256_of, H256},
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    moveos_std::tx_context::TxContext,
+    state::{MoveStructState, MoveStructType},
+    transaction::FunctionCall,
+};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("webauthn_validator");
+
+/// The relying party id used when the passkey assertion is simulated by a local key
+pub const DEFAULT_RP_ID: &str = "rooch.network";
+/// The origin used when the passkey assertion is simulated by a local key
+pub const DEFAULT_ORIGIN: &str = "https://rooch.network";
+/// The `type` of the client data for an assertion, see https://www.w3.org/TR/webauthn-2/#dom-collectedclientdata-type
+pub const CLIENT_DATA_TYPE_GET: &str = "webauthn.get";
+
+/// The authenticator data is `rpIdHash(32) || flags(1) || signCount(4) || extensions`
+pub const AUTHENTICATOR_DATA_MIN_LENGTH: usize = 37;
+const AUTHENTICATOR_DATA_FLAGS_INDEX: usize = 32;
+/// User Present (UP) flag
+pub const FLAG_USER_PRESENT: u8 = 0x01;
+/// User Verified (UV) flag
+pub const FLAG_USER_VERIFIED: u8 = 0x04;
+
+pub struct WebauthnValidator {}
+
+impl WebauthnValidator {
+    pub fn auth_validator_id() -> u64 {
+        BuiltinAuthValidator::Webauthn.flag().into()
+    }
+}
+
+impl MoveStructType for WebauthnValidator {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("WebauthnValidator");
+}
+
+/// The client data collected by the browser when the passkey signs the challenge.
+/// The challenge is the base64url encoded tx hash without padding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientData {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub challenge: String,
+    pub origin: String,
+    #[serde(default)]
+    pub cross_origin: bool,
+}
+
+impl ClientData {
+    pub fn new_for_assertion(tx_hash: H256, origin: String) -> Self {
+        Self {
+            type_: CLIENT_DATA_TYPE_GET.to_owned(),
+            challenge: base64url_encode(tx_hash.as_bytes()),
+            origin,
+            cross_origin: false,
+        }
+    }
+
+    pub fn to_json(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect("Serialize ClientData should success")
+    }
+}
+
+/// Encode bytes with the url safe base64 alphabet and without padding, as the WebAuthn challenge
+pub fn base64url_encode(bytes: &[u8]) -> String {
+    Base64::encode(bytes)
+        .trim_end_matches('=')
+        .replace('+', "-")
+        .replace('/', "_")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebauthnAuthPayload {
+    // The signature scheme of the passkey, only secp256r1 is supported
+    pub scheme: u8,
+    // The 64 bytes (r, s) signature with the normalized low s
+    pub signature: Vec<u8>,
+    // The 33 bytes compressed public key of the passkey
+    pub public_key: Vec<u8>,
+    // The authenticator data returned by the authenticator
+    pub authenticator_data: Vec<u8>,
+    // The raw clientDataJSON collected by the client
+    pub client_data_json: Vec<u8>,
+}
+
+impl MoveStructType for WebauthnAuthPayload {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("WebauthnAuthPayload");
+}
+
+impl MoveStructState for WebauthnAuthPayload {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            move_core_types::value::MoveTypeLayout::U8,
+            move_core_types::value::MoveTypeLayout::Vector(Box::new(
+                move_core_types::value::MoveTypeLayout::U8,
+            )),
+            move_core_types::value::MoveTypeLayout::Vector(Box::new(
+                move_core_types::value::MoveTypeLayout::U8,
+            )),
+            move_core_types::value::MoveTypeLayout::Vector(Box::new(
+                move_core_types::value::MoveTypeLayout::U8,
+            )),
+            move_core_types::value::MoveTypeLayout::Vector(Box::new(
+                move_core_types::value::MoveTypeLayout::U8,
+            )),
+        ])
+    }
+}
+
+impl WebauthnAuthPayload {
+    pub fn new(
+        signature: Signature,
+        authenticator_data: Vec<u8>,
+        client_data_json: Vec<u8>,
+    ) -> Self {
+        debug_assert_eq!(signature.scheme(), SignatureScheme::Secp256r1);
+        WebauthnAuthPayload {
+            scheme: SignatureScheme::Secp256r1.flag(),
+            signature: signature.signature_bytes().to_vec(),
+            public_key: signature.public_key_bytes().to_vec(),
+            authenticator_data,
+            client_data_json,
+        }
+    }
+
+    /// The authenticator data of a user present and verified assertion for the given relying party
+    pub fn authenticator_data(rp_id: &str, sign_count: u32) -> Vec<u8> {
+        let mut authenticator_data = sha2_256_of(rp_id.as_bytes()).0.to_vec();
+        authenticator_data.push(FLAG_USER_PRESENT | FLAG_USER_VERIFIED);
+        authenticator_data.extend_from_slice(&sign_count.to_be_bytes());
+        authenticator_data
+    }
+
+    /// The message signed by the passkey: `authenticatorData || sha256(clientDataJSON)`
+    pub fn signing_message(authenticator_data: &[u8], client_data_json: &[u8]) -> Vec<u8> {
+        let mut message = authenticator_data.to_vec();
+        message.extend_from_slice(sha2_256_of(client_data_json).as_bytes());
+        message
+    }
+
+    pub fn client_data(&self) -> Result<ClientData> {
+        Ok(serde_json::from_slice(&self.client_data_json)?)
+    }
+
+    pub fn verify(&self, tx_data: &RoochTransactionData) -> Result<()> {
+        ensure!(
+            self.scheme == SignatureScheme::Secp256r1.flag(),
+            "Invalid webauthn signature scheme: {}",
+            self.scheme
+        );
+        ensure!(
+            self.authenticator_data.len() >= AUTHENTICATOR_DATA_MIN_LENGTH,
+            "Invalid webauthn authenticator data length: {}",
+            self.authenticator_data.len()
+        );
+        ensure!(
+            self.authenticator_data[AUTHENTICATOR_DATA_FLAGS_INDEX] & FLAG_USER_PRESENT != 0,
+            "The webauthn assertion is not user present"
+        );
+        let client_data = self.client_data()?;
+        ensure!(
+            client_data.type_ == CLIENT_DATA_TYPE_GET,
+            "Invalid webauthn client data type: {}",
+            client_data.type_
+        );
+        ensure!(
+            client_data.challenge == base64url_encode(tx_data.tx_hash().as_bytes()),
+            "The webauthn challenge does not match the tx hash"
+        );
+        let pk = Secp256r1PublicKey::from_bytes(&self.public_key)?;
+        let signature = Secp256r1Signature::from_bytes(&self.signature)?;
+        let message = Self::signing_message(&self.authenticator_data, &self.client_data_json);
+        pk.verify(&message, &signature)?;
+        Ok(())
+    }
+}
+
+/// Rust bindings for RoochFramework webauthn_validator module
+pub struct WebauthnValidatorModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> WebauthnValidatorModule<'a> {
+    const VALIDATE_FUNCTION_NAME: &'static IdentStr = ident_str!("validate");
+
+    /// Validate the payload and return the authentication key of the passkey
+    pub fn validate(&self, ctx: &TxContext, payload: Vec<u8>) -> Result<Vec<u8>> {
+        let auth_validator_call = FunctionCall::new(
+            Self::function_id(Self::VALIDATE_FUNCTION_NAME),
+            vec![],
+            vec![MoveValue::vector_u8(payload).simple_serialize().unwrap()],
+        );
+        let auth_key = self
+            .caller
+            .call_function(ctx, auth_validator_call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<Vec<u8>>(&value.value).expect("should be a valid vector<u8>")
+            })?;
+        Ok(auth_key)
+    }
+}
+
+impl<'a> ModuleBinding<'a> for WebauthnValidatorModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        crypto::RoochKeyPair,
+        framework::empty::Empty,
+        transaction::{Authenticator, RoochTransactionData},
+    };
+
+    #[test]
+    pub fn test_sign_and_verify() {
+        let kp = RoochKeyPair::generate_secp256r1();
+        let tx_data = RoochTransactionData::new_for_test(
+            ROOCH_FRAMEWORK_ADDRESS.into(),
+            0,
+            Empty::empty_function_call().into(),
+        );
+        let auth = Authenticator::webauthn(&kp, &tx_data);
+        assert_eq!(
+            auth.auth_validator_id,
+            WebauthnValidator::auth_validator_id()
+        );
+        let payload = bcs::from_bytes::<WebauthnAuthPayload>(&auth.payload).unwrap();
+        payload.verify(&tx_data).unwrap();
+
+        let other_tx_data = RoochTransactionData::new_for_test(
+            ROOCH_FRAMEWORK_ADDRESS.into(),
+            1,
+            Empty::empty_function_call().into(),
+        );
+        assert!(payload.verify(&other_tx_data).is_err());
+    }
+
+    #[test]
+    pub fn test_base64url_encode() {
+        assert_eq!(base64url_encode(&[0xfb, 0xff]), "-_8");
+        assert_eq!(base64url_encode(b"rooch"), "cm9vY2g");
+    }
+}
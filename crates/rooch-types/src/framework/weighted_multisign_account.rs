@@ -0,0 +1,419 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::address::RoochAddress;
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use crate::crypto::{DefaultHash, PublicKey, RoochKeyPair, RoochSignature, SignatureScheme};
+use anyhow::{bail, ensure, Result};
+use fastcrypto::hash::HashFunction;
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    identifier::IdentStr,
+    value::{MoveStructLayout, MoveTypeLayout},
+};
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::tx_context::TxContext;
+use moveos_types::state::{MoveStructState, MoveStructType};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    state::MoveState,
+    transaction::MoveAction,
+};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+use std::fmt;
+use std::str::FromStr;
+
+pub const MODULE_NAME: &IdentStr = ident_str!("weighted_multisign_account");
+
+/// The domain separator of the multisign address, keep it same as the Move module
+pub const MULTISIGN_ADDRESS_DOMAIN: &[u8] = b"rooch::weighted_multisign_account";
+/// The prefix of the Ethereum personal message, the tx hash is always 32 bytes
+pub const ETHEREUM_MESSAGE_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n32";
+
+/// The signature scheme of a weighted multisign participant
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ParticipantScheme {
+    Ed25519,
+    Secp256k1,
+    Ethereum,
+}
+
+impl ParticipantScheme {
+    const ED25519_FLAG: u8 = 0;
+    const SECP256K1_FLAG: u8 = 1;
+    const ETHEREUM_FLAG: u8 = 3;
+
+    pub fn flag(&self) -> u8 {
+        match self {
+            ParticipantScheme::Ed25519 => Self::ED25519_FLAG,
+            ParticipantScheme::Secp256k1 => Self::SECP256K1_FLAG,
+            ParticipantScheme::Ethereum => Self::ETHEREUM_FLAG,
+        }
+    }
+
+    pub fn from_flag_byte(flag: u8) -> Result<Self> {
+        match flag {
+            Self::ED25519_FLAG => Ok(ParticipantScheme::Ed25519),
+            Self::SECP256K1_FLAG => Ok(ParticipantScheme::Secp256k1),
+            Self::ETHEREUM_FLAG => Ok(ParticipantScheme::Ethereum),
+            _ => bail!("Invalid participant signature scheme: {}", flag),
+        }
+    }
+
+    /// Sign the tx hash with the key pair, the returned bytes is the raw signature without flag and public key
+    pub fn sign(&self, kp: &RoochKeyPair, tx_hash: H256) -> Result<Vec<u8>> {
+        match (self, kp.public().scheme()) {
+            (ParticipantScheme::Ed25519, SignatureScheme::Ed25519)
+            | (ParticipantScheme::Secp256k1, SignatureScheme::Secp256k1) => {
+                Ok(kp.sign(tx_hash.as_bytes()).signature_bytes().to_vec())
+            }
+            (ParticipantScheme::Ethereum, SignatureScheme::Secp256k1) => {
+                let secret_key = kp
+                    .secp256k1_secret_key()
+                    .ok_or_else(|| anyhow::anyhow!("Only secp256k1 key can sign as Ethereum"))?;
+                let digest: [u8; 32] = Keccak256::digest(ethereum_message(tx_hash)).into();
+                let secp = bitcoin::secp256k1::Secp256k1::signing_only();
+                let signature = secp.sign_ecdsa(
+                    &bitcoin::secp256k1::Message::from_digest(digest),
+                    &secret_key,
+                );
+                Ok(signature.serialize_compact().to_vec())
+            }
+            (scheme, key_scheme) => bail!(
+                "The key pair scheme {:?} does not match the participant scheme {}",
+                key_scheme,
+                scheme
+            ),
+        }
+    }
+}
+
+impl fmt::Display for ParticipantScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParticipantScheme::Ed25519 => write!(f, "ed25519"),
+            ParticipantScheme::Secp256k1 => write!(f, "secp256k1"),
+            ParticipantScheme::Ethereum => write!(f, "ethereum"),
+        }
+    }
+}
+
+impl FromStr for ParticipantScheme {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "ed25519" => Ok(ParticipantScheme::Ed25519),
+            "secp256k1" => Ok(ParticipantScheme::Secp256k1),
+            "ethereum" | "eth" => Ok(ParticipantScheme::Ethereum),
+            _ => bail!("Invalid participant signature scheme: {}", s),
+        }
+    }
+}
+
+/// The Ethereum personal message of the tx hash
+pub fn ethereum_message(tx_hash: H256) -> Vec<u8> {
+    let mut message = ETHEREUM_MESSAGE_PREFIX.to_vec();
+    message.extend_from_slice(tx_hash.as_bytes());
+    message
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Participant {
+    /// The signature scheme of the participant
+    pub scheme: u8,
+    /// The public key of the participant
+    pub public_key: Vec<u8>,
+    /// The weight of the participant
+    pub weight: u64,
+}
+
+impl Participant {
+    pub fn new(scheme: ParticipantScheme, public_key: Vec<u8>, weight: u64) -> Self {
+        Self {
+            scheme: scheme.flag(),
+            public_key,
+            weight,
+        }
+    }
+
+    /// Create a participant from the Rooch public key, Ed25519 and Secp256k1 are supported
+    pub fn from_public_key(
+        scheme: ParticipantScheme,
+        public_key: &PublicKey,
+        weight: u64,
+    ) -> Result<Self> {
+        match (scheme, public_key.scheme()) {
+            (ParticipantScheme::Ed25519, SignatureScheme::Ed25519)
+            | (ParticipantScheme::Secp256k1, SignatureScheme::Secp256k1)
+            | (ParticipantScheme::Ethereum, SignatureScheme::Secp256k1) => {
+                Ok(Self::new(scheme, public_key.as_ref().to_vec(), weight))
+            }
+            (scheme, key_scheme) => bail!(
+                "The public key scheme {:?} does not match the participant scheme {}",
+                key_scheme,
+                scheme
+            ),
+        }
+    }
+
+    pub fn scheme(&self) -> Result<ParticipantScheme> {
+        ParticipantScheme::from_flag_byte(self.scheme)
+    }
+}
+
+impl MoveStructType for Participant {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Participant");
+}
+
+impl MoveStructState for Participant {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![
+            MoveTypeLayout::U8,
+            MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+            MoveTypeLayout::U64,
+        ])
+    }
+}
+
+/// Generate the multisign address, it should be the same as the Move `generate_multisign_address`
+pub fn generate_multisign_address(
+    threshold: u64,
+    participants: &[Participant],
+) -> Result<RoochAddress> {
+    ensure!(threshold > 0, "Invalid threshold: {}", threshold);
+    let total_weight: u64 = participants.iter().map(|p| p.weight).sum();
+    ensure!(
+        threshold <= total_weight,
+        "The threshold {} is greater than the total weight {}",
+        threshold,
+        total_weight
+    );
+    let mut hasher = DefaultHash::default();
+    hasher.update(MULTISIGN_ADDRESS_DOMAIN);
+    hasher.update(bcs::to_bytes(&threshold)?);
+    hasher.update(bcs::to_bytes(participants)?);
+    let digest = hasher.finalize().digest;
+    Ok(RoochAddress::from(AccountAddress::new(digest)))
+}
+
+/// Rust bindings for weighted_multisign_account module
+pub struct WeightedMultisignAccountModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> WeightedMultisignAccountModule<'a> {
+    const CREATE_MULTISIGN_ACCOUNT_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("create_multisign_account_entry");
+    const ADD_PARTICIPANT_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("add_participant_entry");
+    const REMOVE_PARTICIPANT_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("remove_participant_entry");
+    const UPDATE_THRESHOLD_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("update_threshold_entry");
+    const ROTATE_PARTICIPANTS_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("rotate_participants_entry");
+    const IS_MULTISIGN_ACCOUNT_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("is_multisign_account");
+    const THRESHOLD_FUNCTION_NAME: &'static IdentStr = ident_str!("threshold");
+    const PARTICIPANTS_FUNCTION_NAME: &'static IdentStr = ident_str!("participants");
+
+    fn split_participants(participants: Vec<Participant>) -> (Vec<u8>, Vec<Vec<u8>>, Vec<u64>) {
+        let mut schemes = Vec::with_capacity(participants.len());
+        let mut public_keys = Vec::with_capacity(participants.len());
+        let mut weights = Vec::with_capacity(participants.len());
+        for participant in participants {
+            schemes.push(participant.scheme);
+            public_keys.push(participant.public_key);
+            weights.push(participant.weight);
+        }
+        (schemes, public_keys, weights)
+    }
+
+    pub fn create_multisign_account_action(
+        threshold: u64,
+        participants: Vec<Participant>,
+    ) -> MoveAction {
+        let (schemes, public_keys, weights) = Self::split_participants(participants);
+        Self::create_move_action(
+            Self::CREATE_MULTISIGN_ACCOUNT_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                threshold.to_move_value(),
+                schemes.to_move_value(),
+                public_keys.to_move_value(),
+                weights.to_move_value(),
+            ],
+        )
+    }
+
+    pub fn add_participant_action(participant: Participant) -> MoveAction {
+        Self::create_move_action(
+            Self::ADD_PARTICIPANT_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                participant.scheme.to_move_value(),
+                participant.public_key.to_move_value(),
+                participant.weight.to_move_value(),
+            ],
+        )
+    }
+
+    pub fn remove_participant_action(public_key: Vec<u8>) -> MoveAction {
+        Self::create_move_action(
+            Self::REMOVE_PARTICIPANT_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![public_key.to_move_value()],
+        )
+    }
+
+    pub fn update_threshold_action(threshold: u64) -> MoveAction {
+        Self::create_move_action(
+            Self::UPDATE_THRESHOLD_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![threshold.to_move_value()],
+        )
+    }
+
+    pub fn rotate_participants_action(
+        threshold: u64,
+        participants: Vec<Participant>,
+    ) -> MoveAction {
+        let (schemes, public_keys, weights) = Self::split_participants(participants);
+        Self::create_move_action(
+            Self::ROTATE_PARTICIPANTS_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                threshold.to_move_value(),
+                schemes.to_move_value(),
+                public_keys.to_move_value(),
+                weights.to_move_value(),
+            ],
+        )
+    }
+
+    pub fn is_multisign_account(&self, multisign_address: AccountAddress) -> Result<bool> {
+        let function_call = Self::create_function_call(
+            Self::IS_MULTISIGN_ACCOUNT_FUNCTION_NAME,
+            vec![],
+            vec![multisign_address.to_move_value()],
+        );
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let is_multisign_account = self
+            .caller
+            .call_function(&ctx, function_call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<bool>(&value.value).expect("should be a valid bool")
+            })?;
+        Ok(is_multisign_account)
+    }
+
+    pub fn threshold(&self, multisign_address: AccountAddress) -> Result<u64> {
+        let function_call = Self::create_function_call(
+            Self::THRESHOLD_FUNCTION_NAME,
+            vec![],
+            vec![multisign_address.to_move_value()],
+        );
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let threshold = self
+            .caller
+            .call_function(&ctx, function_call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<u64>(&value.value).expect("should be a valid u64")
+            })?;
+        Ok(threshold)
+    }
+
+    pub fn participants(&self, multisign_address: AccountAddress) -> Result<Vec<Participant>> {
+        let function_call = Self::create_function_call(
+            Self::PARTICIPANTS_FUNCTION_NAME,
+            vec![],
+            vec![multisign_address.to_move_value()],
+        );
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let participants = self
+            .caller
+            .call_function(&ctx, function_call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<Vec<Participant>>(&value.value)
+                    .expect("should be a valid vector of Participant")
+            })?;
+        Ok(participants)
+    }
+}
+
+impl<'a> ModuleBinding<'a> for WeightedMultisignAccountModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_participant_scheme_from_str() {
+        assert_eq!(
+            ParticipantScheme::from_str("ed25519").unwrap(),
+            ParticipantScheme::Ed25519
+        );
+        assert_eq!(
+            ParticipantScheme::from_str("Secp256k1").unwrap(),
+            ParticipantScheme::Secp256k1
+        );
+        assert_eq!(
+            ParticipantScheme::from_str("eth").unwrap(),
+            ParticipantScheme::Ethereum
+        );
+        assert!(ParticipantScheme::from_str("secp256r1").is_err());
+    }
+
+    #[test]
+    fn test_generate_multisign_address() {
+        let kp1 = RoochKeyPair::generate_ed25519();
+        let kp2 = RoochKeyPair::generate_secp256k1();
+        let participants = vec![
+            Participant::from_public_key(ParticipantScheme::Ed25519, &kp1.public(), 1).unwrap(),
+            Participant::from_public_key(ParticipantScheme::Ethereum, &kp2.public(), 2).unwrap(),
+        ];
+        let address = generate_multisign_address(2, &participants).unwrap();
+        assert_eq!(
+            address,
+            generate_multisign_address(2, &participants).unwrap()
+        );
+        assert_ne!(
+            address,
+            generate_multisign_address(3, &participants).unwrap()
+        );
+        assert!(generate_multisign_address(4, &participants).is_err());
+        assert!(generate_multisign_address(0, &participants).is_err());
+    }
+
+    #[test]
+    fn test_participant_scheme_mismatch() {
+        let kp = RoochKeyPair::generate_ed25519();
+        assert!(
+            Participant::from_public_key(ParticipantScheme::Ethereum, &kp.public(), 1).is_err()
+        );
+        assert!(ParticipantScheme::Secp256k1
+            .sign(&kp, H256::random())
+            .is_err());
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::auth_validator::BuiltinAuthValidator;
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    identifier::IdentStr,
+    value::{MoveStructLayout, MoveTypeLayout},
+};
+use moveos_types::state::{MoveStructState, MoveStructType};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("weighted_multisign_validator");
+
+/// Weighted Multisign Auth Validator, the total weight of the signers must reach the threshold
+pub struct WeightedMultisignValidator {}
+
+impl WeightedMultisignValidator {
+    pub fn auth_validator_id() -> u64 {
+        BuiltinAuthValidator::WeightedMultisign.flag().into()
+    }
+}
+
+impl MoveStructType for WeightedMultisignValidator {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("WeightedMultisignValidator");
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WeightedMultisignAuthPayload {
+    // The public keys of the signers
+    pub public_keys: Vec<Vec<u8>>,
+    // The signatures of the tx hash, in the same order as the public keys
+    pub signatures: Vec<Vec<u8>>,
+}
+
+impl MoveStructType for WeightedMultisignAuthPayload {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("WeightedMultisignAuthPayload");
+}
+
+impl MoveStructState for WeightedMultisignAuthPayload {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![
+            MoveTypeLayout::Vector(Box::new(MoveTypeLayout::Vector(Box::new(
+                MoveTypeLayout::U8,
+            )))),
+            MoveTypeLayout::Vector(Box::new(MoveTypeLayout::Vector(Box::new(
+                MoveTypeLayout::U8,
+            )))),
+        ])
+    }
+}
+
+impl WeightedMultisignAuthPayload {
+    pub fn new(public_keys: Vec<Vec<u8>>, signatures: Vec<Vec<u8>>) -> Self {
+        Self {
+            public_keys,
+            signatures,
+        }
+    }
+
+    /// Add the signature of the signer, replace the old one if the signer has signed
+    pub fn add_signature(&mut self, public_key: Vec<u8>, signature: Vec<u8>) {
+        match self.public_keys.iter().position(|pk| pk == &public_key) {
+            Some(idx) => self.signatures[idx] = signature,
+            None => {
+                self.public_keys.push(public_key);
+                self.signatures.push(signature);
+            }
+        }
+    }
+}
@@ -5,11 +5,13 @@ use crate::indexer::Filter;
 use crate::transaction::LedgerTransaction;
 use anyhow::Result;
 use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::StructTag;
 use move_resource_viewer::AnnotatedMoveStruct;
 use moveos_types::h256::H256;
 use moveos_types::move_types::struct_tag_match;
 use moveos_types::moveos_std::event::{Event, EventID};
+use moveos_types::moveos_std::object::ObjectID;
 use moveos_types::moveos_std::tx_context::TxContext;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -108,6 +110,20 @@ pub enum EventFilter {
         /// right endpoint of transaction order, exclusive
         to_order: u64,
     },
+    /// Query by the module which defines the event type, such as all the events of `0x3::coin`.
+    /// Query all the events defined by the address if the module is not set.
+    EventModule {
+        address: AccountAddress,
+        module: Option<Identifier>,
+    },
+    /// Query by any of the event types.
+    EventTypes(Vec<StructTag>),
+    /// Query by the event handle id.
+    EventHandle(ObjectID),
+    /// Return events matching all of the filters.
+    And(Vec<EventFilter>),
+    /// Return events matching any of the filters.
+    Or(Vec<EventFilter>),
 }
 
 impl EventFilter {
@@ -130,6 +146,22 @@ impl EventFilter {
                 *from_order <= item.indexer_event_id.tx_order
                     && item.indexer_event_id.tx_order < *to_order
             }
+            EventFilter::EventModule { address, module } => {
+                address == &item.event_type.address
+                    && module
+                        .as_ref()
+                        .map_or(true, |module| module == &item.event_type.module)
+            }
+            EventFilter::EventTypes(event_types) => event_types
+                .iter()
+                .any(|event_type| struct_tag_match(event_type, &item.event_type)),
+            EventFilter::EventHandle(event_handle_id) => {
+                event_handle_id == &item.event_id.event_handle_id
+            }
+            EventFilter::And(filters) => {
+                !filters.is_empty() && filters.iter().all(|filter| filter.matches(item))
+            }
+            EventFilter::Or(filters) => filters.iter().any(|filter| filter.matches(item)),
         })
     }
 }
@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::indexer::Filter;
 use crate::transaction::{LedgerTransaction, LedgerTxData};
 use anyhow::Result;
 use move_core_types::account_address::AccountAddress;
@@ -26,6 +27,8 @@ pub struct IndexerTransaction {
     // the vm status.
     pub status: String,
     pub created_at: u64,
+    // the account address which paid the gas
+    pub gas_payer: AccountAddress,
 }
 
 impl IndexerTransaction {
@@ -60,6 +63,7 @@ impl IndexerTransaction {
             // the vm status.
             status,
             created_at: transaction.sequence_info.tx_timestamp,
+            gas_payer: execution_info.gas_payer,
         };
         Ok(indexer_transaction)
     }
@@ -87,3 +91,88 @@ pub enum TransactionFilter {
         to_order: u64,
     },
 }
+
+impl TransactionFilter {
+    fn try_matches(&self, item: &IndexerTransaction) -> Result<bool> {
+        Ok(match self {
+            TransactionFilter::Sender(sender) => sender == &item.sender,
+            TransactionFilter::TxHashes(tx_hashes) => tx_hashes.contains(&item.tx_hash),
+            TransactionFilter::TimeRange {
+                start_time,
+                end_time,
+            } => *start_time <= item.created_at && item.created_at < *end_time,
+            TransactionFilter::TxOrderRange {
+                from_order,
+                to_order,
+            } => *from_order <= item.tx_order && item.tx_order < *to_order,
+        })
+    }
+}
+
+impl Filter<IndexerTransaction> for TransactionFilter {
+    fn matches(&self, item: &IndexerTransaction) -> bool {
+        self.try_matches(item).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn indexer_transaction(tx_order: u64, created_at: u64) -> IndexerTransaction {
+        let sender = AccountAddress::random();
+        IndexerTransaction {
+            tx_hash: H256::random(),
+            tx_order,
+            sequence_number: 0,
+            sender,
+            action_type: 0,
+            auth_validator_id: 0,
+            gas_used: 0,
+            status: "\"executed\"".to_string(),
+            created_at,
+            gas_payer: sender,
+        }
+    }
+
+    #[test]
+    fn test_filter_by_sender() {
+        let tx = indexer_transaction(1, 1000);
+        assert!(TransactionFilter::Sender(tx.sender).matches(&tx));
+        assert!(!TransactionFilter::Sender(AccountAddress::random()).matches(&tx));
+    }
+
+    #[test]
+    fn test_filter_by_tx_hashes() {
+        let tx = indexer_transaction(1, 1000);
+        assert!(TransactionFilter::TxHashes(vec![H256::random(), tx.tx_hash]).matches(&tx));
+        assert!(!TransactionFilter::TxHashes(vec![H256::random()]).matches(&tx));
+        assert!(!TransactionFilter::TxHashes(vec![]).matches(&tx));
+    }
+
+    #[test]
+    fn test_filter_by_time_range() {
+        let filter = TransactionFilter::TimeRange {
+            start_time: 1000,
+            end_time: 2000,
+        };
+        assert!(!filter.matches(&indexer_transaction(1, 999)));
+        assert!(filter.matches(&indexer_transaction(1, 1000)));
+        assert!(filter.matches(&indexer_transaction(1, 1999)));
+        // The end time is exclusive
+        assert!(!filter.matches(&indexer_transaction(1, 2000)));
+    }
+
+    #[test]
+    fn test_filter_by_tx_order_range() {
+        let filter = TransactionFilter::TxOrderRange {
+            from_order: 10,
+            to_order: 20,
+        };
+        assert!(!filter.matches(&indexer_transaction(9, 1000)));
+        assert!(filter.matches(&indexer_transaction(10, 1000)));
+        assert!(filter.matches(&indexer_transaction(19, 1000)));
+        // The to order is exclusive
+        assert!(!filter.matches(&indexer_transaction(20, 1000)));
+    }
+}
@@ -12,6 +12,7 @@ use anyhow::Result;
 use fastcrypto::ed25519::Ed25519KeyPair;
 #[cfg(any(test, feature = "fuzzing"))]
 use fastcrypto::traits::KeyPair;
+use move_core_types::account_address::AccountAddress;
 #[cfg(any(test, feature = "fuzzing"))]
 use proptest::{collection::vec, prelude::*};
 #[cfg(any(test, feature = "fuzzing"))]
@@ -24,6 +25,10 @@ use crate::{
     framework::{
         auth_payload::{AuthPayload, MultisignAuthPayload, SignData},
         auth_validator::BuiltinAuthValidator,
+        sponsor_validator::SponsorAuthPayload,
+        webauthn_validator::{ClientData, WebauthnAuthPayload, DEFAULT_ORIGIN, DEFAULT_RP_ID},
+        weighted_multisign_account::ParticipantScheme,
+        weighted_multisign_validator::WeightedMultisignAuthPayload,
     },
     rooch_network::{BuiltinChainID, RoochNetwork},
 };
@@ -176,6 +181,114 @@ impl BitcoinMultisignAuthenticator {
     }
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct WebauthnAuthenticator {
+    pub payload: WebauthnAuthPayload,
+}
+
+impl WebauthnAuthenticator {
+    pub fn new(payload: WebauthnAuthPayload) -> Self {
+        Self { payload }
+    }
+
+    /// Sign the tx with a local secp256r1 key, simulating the passkey assertion of the platform authenticator
+    pub fn sign(kp: &RoochKeyPair, tx_data: &RoochTransactionData) -> Self {
+        assert_eq!(kp.public().scheme(), SignatureScheme::Secp256r1);
+        let authenticator_data = WebauthnAuthPayload::authenticator_data(DEFAULT_RP_ID, 0);
+        let client_data_json =
+            ClientData::new_for_assertion(tx_data.tx_hash(), DEFAULT_ORIGIN.to_owned()).to_json();
+        let message = WebauthnAuthPayload::signing_message(&authenticator_data, &client_data_json);
+        let signature = kp.sign(&message);
+        WebauthnAuthenticator {
+            payload: WebauthnAuthPayload::new(signature, authenticator_data, client_data_json),
+        }
+    }
+}
+
+impl BuiltinAuthenticator for WebauthnAuthenticator {
+    fn auth_validator_id(&self) -> u64 {
+        BuiltinAuthValidator::Webauthn.flag().into()
+    }
+    fn payload(&self) -> Vec<u8> {
+        bcs::to_bytes(&self.payload).expect("Serialize WebauthnAuthenticator should success")
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SponsorAuthenticator {
+    pub payload: SponsorAuthPayload,
+}
+
+impl SponsorAuthenticator {
+    pub fn new(payload: SponsorAuthPayload) -> Self {
+        Self { payload }
+    }
+
+    /// Sponsor the tx signed by the sender, the gas payer signs the same tx with its Bitcoin key
+    pub fn sign(
+        sender_authenticator: Authenticator,
+        gas_payer_kp: &RoochKeyPair,
+        tx_data: &RoochTransactionData,
+    ) -> Self {
+        let gas_payer = gas_payer_kp
+            .public()
+            .rooch_address()
+            .expect("Generate rooch address should success");
+        let gas_payer_authenticator = Authenticator::bitcoin(gas_payer_kp, tx_data);
+        SponsorAuthenticator {
+            payload: SponsorAuthPayload::new(
+                sender_authenticator,
+                gas_payer.into(),
+                gas_payer_authenticator,
+            ),
+        }
+    }
+}
+
+impl BuiltinAuthenticator for SponsorAuthenticator {
+    fn auth_validator_id(&self) -> u64 {
+        BuiltinAuthValidator::Sponsor.flag().into()
+    }
+    fn payload(&self) -> Vec<u8> {
+        bcs::to_bytes(&self.payload).expect("Serialize SponsorAuthenticator should success")
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct WeightedMultisignAuthenticator {
+    pub payload: WeightedMultisignAuthPayload,
+}
+
+impl WeightedMultisignAuthenticator {
+    pub fn new(payload: WeightedMultisignAuthPayload) -> Self {
+        Self { payload }
+    }
+
+    /// Sign the tx with all the given participant key pairs
+    pub fn sign(
+        signers: &[(ParticipantScheme, &RoochKeyPair)],
+        tx_data: &RoochTransactionData,
+    ) -> Result<Self> {
+        let tx_hash = tx_data.tx_hash();
+        let mut payload = WeightedMultisignAuthPayload::default();
+        for (scheme, kp) in signers {
+            let signature = scheme.sign(kp, tx_hash)?;
+            payload.add_signature(kp.public().as_ref().to_vec(), signature);
+        }
+        Ok(Self { payload })
+    }
+}
+
+impl BuiltinAuthenticator for WeightedMultisignAuthenticator {
+    fn auth_validator_id(&self) -> u64 {
+        BuiltinAuthValidator::WeightedMultisign.flag().into()
+    }
+    fn payload(&self) -> Vec<u8> {
+        bcs::to_bytes(&self.payload)
+            .expect("Serialize WeightedMultisignAuthenticator should success")
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct Authenticator {
     pub auth_validator_id: u64,
@@ -199,6 +312,7 @@ impl Authenticator {
         match kp.public().scheme() {
             SignatureScheme::Ed25519 => Self::session(kp, tx_data),
             SignatureScheme::Secp256k1 => Self::bitcoin(kp, tx_data),
+            SignatureScheme::Secp256r1 => Self::webauthn(kp, tx_data),
         }
     }
 
@@ -218,6 +332,49 @@ impl Authenticator {
         BitcoinMultisignAuthenticator::build_multisig_authenticator(authenticators).map(Into::into)
     }
 
+    /// Create a webauthn authenticator for RoochTransaction
+    /// The passkey is registered as a session key of the sender
+    pub fn webauthn(kp: &RoochKeyPair, tx_data: &RoochTransactionData) -> Self {
+        WebauthnAuthenticator::sign(kp, tx_data).into()
+    }
+
+    /// Create a sponsor authenticator for RoochTransaction
+    /// The gas is paid by the gas payer instead of the sender
+    pub fn sponsor(
+        sender_authenticator: Authenticator,
+        gas_payer_kp: &RoochKeyPair,
+        tx_data: &RoochTransactionData,
+    ) -> Self {
+        SponsorAuthenticator::sign(sender_authenticator, gas_payer_kp, tx_data).into()
+    }
+
+    /// Create a weighted multisign authenticator for RoochTransaction
+    /// The sender should be a weighted multisign account, and the signers are its participants
+    pub fn weighted_multisign(
+        signers: &[(ParticipantScheme, &RoochKeyPair)],
+        tx_data: &RoochTransactionData,
+    ) -> Result<Self> {
+        WeightedMultisignAuthenticator::sign(signers, tx_data).map(Into::into)
+    }
+
+    /// Return the gas payer if it is a sponsor authenticator
+    pub fn gas_payer(&self) -> Result<Option<AccountAddress>> {
+        if self.auth_validator_id != u64::from(BuiltinAuthValidator::Sponsor.flag()) {
+            return Ok(None);
+        }
+        let payload = bcs::from_bytes::<SponsorAuthPayload>(&self.payload)?;
+        Ok(Some(payload.gas_payer))
+    }
+
+    /// Return the authenticator of the sender, unwrap the sponsor authenticator
+    pub fn sender_authenticator(&self) -> Result<Authenticator> {
+        if self.auth_validator_id != u64::from(BuiltinAuthValidator::Sponsor.flag()) {
+            return Ok(self.clone());
+        }
+        let payload = bcs::from_bytes::<SponsorAuthPayload>(&self.payload)?;
+        Ok(payload.sender_authenticator())
+    }
+
     /// Create a custom authenticator
     pub fn new(auth_validator_id: u64, payload: Vec<u8>) -> Self {
         Self {
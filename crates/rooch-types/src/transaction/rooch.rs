@@ -1,11 +1,15 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use super::authenticator::{BitcoinAuthenticator, BitcoinMultisignAuthenticator};
+use super::authenticator::{
+    BitcoinAuthenticator, BitcoinMultisignAuthenticator, WeightedMultisignAuthenticator,
+};
 use super::RawTransaction;
 use super::{authenticator::Authenticator, AuthenticatorInfo};
 use crate::address::RoochAddress;
 use crate::crypto::RoochKeyPair;
+use crate::framework::weighted_multisign_account::Participant;
+use crate::framework::weighted_multisign_validator::WeightedMultisignAuthPayload;
 use crate::rooch_network::BuiltinChainID;
 use anyhow::Result;
 use moveos_types::h256::H256;
@@ -147,6 +151,21 @@ impl PartiallySignedRoochTransaction {
         self.authenticators.len() as u64 >= self.threshold
     }
 
+    /// Merge the authenticators of another PSRT of the same transaction
+    pub fn merge(&mut self, other: PartiallySignedRoochTransaction) -> Result<()> {
+        if self.data.tx_hash() != other.data.tx_hash() {
+            return Err(anyhow::anyhow!(
+                "Cannot merge partially signed transactions of different transaction data"
+            ));
+        }
+        for authenticator in other.authenticators {
+            if !self.contains_authenticator(&authenticator) {
+                self.authenticators.push(authenticator);
+            }
+        }
+        Ok(())
+    }
+
     pub fn try_into_rooch_transaction(self) -> Result<RoochTransaction> {
         if !self.is_fully_signed() {
             return Err(anyhow::anyhow!(
@@ -165,6 +184,143 @@ impl PartiallySignedRoochTransaction {
     }
 }
 
+/// PartiallySignedWeightedMultisignTransaction is a transaction of the weighted multisign account
+/// that has been signed by partial participants.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PartiallySignedWeightedMultisignTransaction {
+    pub data: RoochTransactionData,
+    /// The threshold of the total weight of the signers.
+    pub threshold: u64,
+    /// The participants of the multisign account.
+    pub participants: Vec<Participant>,
+    /// The signatures of the partial signers.
+    pub payload: WeightedMultisignAuthPayload,
+}
+
+impl PartiallySignedWeightedMultisignTransaction {
+    pub fn new(data: RoochTransactionData, threshold: u64, participants: Vec<Participant>) -> Self {
+        Self {
+            data,
+            threshold,
+            participants,
+            payload: WeightedMultisignAuthPayload::default(),
+        }
+    }
+
+    pub fn sender(&self) -> RoochAddress {
+        self.data.sender
+    }
+
+    pub fn signatories(&self) -> usize {
+        self.payload.public_keys.len()
+    }
+
+    pub fn participant(&self, public_key: &[u8]) -> Option<&Participant> {
+        self.participants
+            .iter()
+            .find(|p| p.public_key.as_slice() == public_key)
+    }
+
+    pub fn contains_signature(&self, public_key: &[u8]) -> bool {
+        self.payload
+            .public_keys
+            .iter()
+            .any(|pk| pk.as_slice() == public_key)
+    }
+
+    pub fn add_signature(&mut self, public_key: Vec<u8>, signature: Vec<u8>) -> Result<()> {
+        if self.participant(&public_key).is_none() {
+            return Err(anyhow::anyhow!(
+                "Public key {} is not a participant of the multisign account",
+                hex::encode(&public_key)
+            ));
+        }
+        if self.contains_signature(&public_key) {
+            return Err(anyhow::anyhow!(
+                "Signature from public key {} already exists",
+                hex::encode(&public_key)
+            ));
+        }
+        self.payload.add_signature(public_key, signature);
+        Ok(())
+    }
+
+    /// Sign the transaction with the key pair of the participant
+    pub fn sign(&mut self, kp: &RoochKeyPair) -> Result<()> {
+        let public_key = kp.public().as_ref().to_vec();
+        let participant = self.participant(&public_key).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Public key {} is not a participant of the multisign account",
+                hex::encode(&public_key)
+            )
+        })?;
+        let signature = participant.scheme()?.sign(kp, self.data.tx_hash())?;
+        self.add_signature(public_key, signature)
+    }
+
+    /// The total weight of the collected signatures
+    pub fn signed_weight(&self) -> u64 {
+        self.payload
+            .public_keys
+            .iter()
+            .filter_map(|pk| self.participant(pk))
+            .fold(0u64, |total, p| total.saturating_add(p.weight))
+    }
+
+    pub fn threshold(&self) -> u64 {
+        self.threshold
+    }
+
+    pub fn is_fully_signed(&self) -> bool {
+        self.signed_weight() >= self.threshold
+    }
+
+    /// Merge the signatures of another partially signed transaction of the same transaction
+    pub fn merge(&mut self, other: PartiallySignedWeightedMultisignTransaction) -> Result<()> {
+        if self.data.tx_hash() != other.data.tx_hash() {
+            return Err(anyhow::anyhow!(
+                "Cannot merge partially signed transactions of different transaction data"
+            ));
+        }
+        // The signatures are weighted by the participants, so both sides must agree on the multisign account
+        let same_participants = self.participants.len() == other.participants.len()
+            && other
+                .participants
+                .iter()
+                .all(|p| self.participants.contains(p));
+        if self.threshold != other.threshold || !same_participants {
+            return Err(anyhow::anyhow!(
+                "Cannot merge partially signed transactions of different participants or threshold"
+            ));
+        }
+        for (public_key, signature) in other
+            .payload
+            .public_keys
+            .into_iter()
+            .zip(other.payload.signatures)
+        {
+            if !self.contains_signature(&public_key) {
+                self.add_signature(public_key, signature)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn try_into_rooch_transaction(self) -> Result<RoochTransaction> {
+        if !self.is_fully_signed() {
+            return Err(anyhow::anyhow!(
+                "Not enough signature weight to complete transaction"
+            ));
+        }
+        let authenticator = WeightedMultisignAuthenticator::new(self.payload).into();
+        Ok(RoochTransaction::new(self.data, authenticator))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("encode transaction should success")
+    }
+}
+
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub struct RoochTransaction {
     pub data: RoochTransactionData,
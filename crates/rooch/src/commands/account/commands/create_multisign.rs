@@ -110,7 +110,7 @@ impl CommandAction<Option<MultisignAccountOutput>> for CreateMultisignCommand {
         if self.json {
             Ok(Some(output))
         } else {
-            println!("MulitsignAddress: {}", output.multisign_address);
+            println!("MultisignAddress: {}", output.multisign_address);
             println!(
                 "Multisign Bitcoin Address: {}",
                 output.multisign_bitcoin_address
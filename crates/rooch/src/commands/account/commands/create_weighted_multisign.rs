@@ -0,0 +1,137 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, TransactionOptions, WalletContextOptions};
+use anyhow::Result;
+use async_trait::async_trait;
+use clap::Parser;
+use moveos_types::module_binding::MoveFunctionCaller;
+use rooch_rpc_api::jsonrpc_types::BytesView;
+use rooch_types::{
+    address::RoochAddress,
+    error::RoochResult,
+    framework::weighted_multisign_account::{
+        self, Participant, ParticipantScheme, WeightedMultisignAccountModule,
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Create a new weighted multisign account on-chain, the participants can be Ed25519, Secp256k1 or Ethereum signers.
+#[derive(Debug, Parser)]
+pub struct CreateWeightedMultisignCommand {
+    /// Participants of the multisign account, in the format `<scheme>:<public_key_hex>[:<weight>]`
+    /// The scheme is one of `ed25519`, `secp256k1` and `ethereum`, the default weight is 1
+    #[clap(long = "participants", short = 'p', required = true, value_parser = parse_participant)]
+    pub participants: Vec<Participant>,
+
+    /// Threshold of the total weight of the signers
+    #[clap(long = "threshold", short = 't')]
+    pub threshold: u64,
+
+    #[clap(flatten)]
+    pub tx_options: TransactionOptions,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+
+    /// Return command outputs in json format
+    #[clap(long, default_value = "false")]
+    json: bool,
+}
+
+fn parse_participant(s: &str) -> Result<Participant> {
+    let parts: Vec<&str> = s.split(':').collect();
+    if parts.len() != 2 && parts.len() != 3 {
+        anyhow::bail!(
+            "Invalid participant: {}, the format should be `<scheme>:<public_key_hex>[:<weight>]`",
+            s
+        );
+    }
+    let scheme = ParticipantScheme::from_str(parts[0])?;
+    let public_key = hex::decode(parts[1].strip_prefix("0x").unwrap_or(parts[1]))?;
+    let weight = match parts.get(2) {
+        Some(weight) => weight.parse::<u64>()?,
+        None => 1,
+    };
+    Ok(Participant::new(scheme, public_key, weight))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeightedParticipantView {
+    pub scheme: String,
+    pub public_key: BytesView,
+    pub weight: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeightedMultisignAccountOutput {
+    pub multisign_address: RoochAddress,
+    pub threshold: u64,
+    pub participants: Vec<WeightedParticipantView>,
+}
+
+#[async_trait]
+impl CommandAction<Option<WeightedMultisignAccountOutput>> for CreateWeightedMultisignCommand {
+    async fn execute(self) -> RoochResult<Option<WeightedMultisignAccountOutput>> {
+        let context = self.context_options.build_require_password()?;
+
+        let sender: RoochAddress = context.resolve_address(self.tx_options.sender)?.into();
+
+        let client = context.get_client().await?;
+        let multisign_account_module = client.as_module_binding::<WeightedMultisignAccountModule>();
+
+        let multisign_address = weighted_multisign_account::generate_multisign_address(
+            self.threshold,
+            &self.participants,
+        )?;
+
+        let is_onchain = multisign_account_module.is_multisign_account(multisign_address.into())?;
+
+        if !is_onchain {
+            //Build the transaction and create the multisign account on-chain
+            let action = WeightedMultisignAccountModule::create_multisign_account_action(
+                self.threshold,
+                self.participants,
+            );
+            let tx_data = context
+                .build_tx_data(sender, action, self.tx_options.max_gas_amount)
+                .await?;
+            let signed_tx = context.sign_transaction(sender, tx_data)?;
+            let result = context.execute(signed_tx).await?;
+            context.assert_execute_success(result)?;
+        }
+
+        let threshold = multisign_account_module.threshold(multisign_address.into())?;
+        let participants = multisign_account_module.participants(multisign_address.into())?;
+
+        let output = WeightedMultisignAccountOutput {
+            multisign_address,
+            threshold,
+            participants: participants
+                .into_iter()
+                .map(|p| WeightedParticipantView {
+                    scheme: p
+                        .scheme()
+                        .map(|scheme| scheme.to_string())
+                        .unwrap_or_else(|_| p.scheme.to_string()),
+                    public_key: p.public_key.into(),
+                    weight: p.weight,
+                })
+                .collect(),
+        };
+        if self.json {
+            Ok(Some(output))
+        } else {
+            println!("MultisignAddress: {}", output.multisign_address);
+            println!("Threshold: {}", output.threshold);
+            println!("Participants: {}", output.participants.len());
+            for (idx, participant) in output.participants.iter().enumerate() {
+                println!("Participant {} Scheme: {}", idx, participant.scheme);
+                println!("Participant {} Public Key: {}", idx, participant.public_key);
+                println!("Participant {} Weight: {}", idx, participant.weight);
+            }
+            Ok(None)
+        }
+    }
+}
@@ -4,10 +4,12 @@
 pub mod balance;
 pub mod create;
 pub mod create_multisign;
+pub mod create_weighted_multisign;
 pub mod export;
 pub mod import;
 pub mod list;
 pub mod nullify;
+pub mod rotate;
 pub mod sign;
 pub mod switch;
 pub mod transfer;
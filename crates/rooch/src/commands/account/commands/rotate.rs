@@ -0,0 +1,89 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, TransactionOptions, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use fastcrypto::{secp256k1::Secp256k1KeyPair, traits::ToFromBytes};
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_types::{
+    address::RoochAddress,
+    crypto::RoochKeyPair,
+    error::{RoochError, RoochResult},
+    framework::{
+        account_authentication::AccountAuthenticationModule, auth_validator::BuiltinAuthValidator,
+    },
+    rooch_key::ParsedSecretKey,
+};
+
+/// Rotate the auth key of a Bitcoin account, the account address does not change.
+///
+/// The rotation is signed with both keys: the new key signs the rotation message,
+/// and the old key signs the rotation transaction.
+/// If -k or --secretkey is not specified, a new secp256k1 key is generated.
+#[derive(Debug, Parser)]
+pub struct RotateCommand {
+    /// The new secret key, the format shall be the same as the exported private keys
+    #[clap(short = 'k', long = "secretkey", value_parser=ParsedSecretKey::parse)]
+    secretkey: Option<ParsedSecretKey>,
+
+    #[clap(flatten)]
+    tx_options: TransactionOptions,
+
+    #[clap(flatten)]
+    context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<ExecuteTransactionResponseView> for RotateCommand {
+    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+        let mut context = self.context_options.build_require_password()?;
+        let password = context.get_password();
+        let sender: RoochAddress = context.resolve_address(self.tx_options.sender)?.into();
+        let max_gas_amount: Option<u64> = self.tx_options.max_gas_amount;
+
+        let new_kp = match self.secretkey {
+            Some(secretkey) => RoochKeyPair::Secp256k1(
+                Secp256k1KeyPair::from_bytes(&secretkey.into_inner().secret_bytes()).map_err(
+                    |e| {
+                        RoochError::CommandArgumentError(format!("Invalid Rooch secret key: {}", e))
+                    },
+                )?,
+            ),
+            None => RoochKeyPair::generate_secp256k1(),
+        };
+
+        let client = context.get_client().await?;
+        let sequence_number = client
+            .rooch
+            .get_sequence_number(sender)
+            .await
+            .map_err(RoochError::from)?;
+        let chain_id = client
+            .rooch
+            .get_chain_id()
+            .await
+            .map_err(RoochError::from)?;
+        let action = AccountAuthenticationModule::rotate_auth_key_action_with_key_pair(
+            chain_id,
+            sender.into(),
+            BuiltinAuthValidator::Bitcoin.flag().into(),
+            sequence_number,
+            &new_kp,
+        )?;
+        let tx_data = context
+            .build_tx_data_with_sequence_number(
+                sender,
+                action,
+                max_gas_amount,
+                Some(sequence_number),
+            )
+            .await?;
+        let result = context.sign_and_execute(sender, tx_data).await?;
+        let result = context.assert_execute_success(result)?;
+
+        context.keystore.rotate_key(&sender, new_kp, password)?;
+        Ok(result)
+    }
+}
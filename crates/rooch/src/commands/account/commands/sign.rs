@@ -6,11 +6,7 @@ use async_trait::async_trait;
 use clap::Parser;
 use moveos_types::state::MoveState;
 use rooch_key::keystore::account_keystore::AccountKeystore;
-use rooch_types::{
-    address::ParsedAddress,
-    error::RoochResult,
-    framework::auth_payload::{SignData, MESSAGE_INFO_PREFIX},
-};
+use rooch_types::{address::ParsedAddress, error::RoochResult};
 
 /// Sign a message with a parsed address
 #[derive(Debug, Parser)]
@@ -39,14 +35,10 @@ impl CommandAction<Option<String>> for SignCommand {
         let mapping = context.address_mapping();
         let rooch_address = self.address.into_rooch_address(&mapping)?;
 
-        let sign_data =
-            SignData::new_without_tx_hash(MESSAGE_INFO_PREFIX.to_vec(), self.message.to_bytes());
-        let encoded_sign_data = sign_data.encode();
-
         let signature =
             context
                 .keystore
-                .sign_hashed(&rooch_address, &encoded_sign_data, password)?;
+                .sign_message(&rooch_address, &self.message.to_bytes(), password)?;
 
         let signature_bytes = signature.as_ref();
         let signature_hex = hex::encode(signature_bytes);
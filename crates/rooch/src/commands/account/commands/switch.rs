@@ -37,7 +37,7 @@ impl CommandAction<Option<RoochAddressView>> for SwitchCommand {
                 RoochError::CommandArgumentError(format!("Invalid Rooch address String: {}", e))
             })?;
 
-        if !context.keystore.addresses().contains(&rooch_address) {
+        if !context.keystore.contains_address(&rooch_address)? {
             return Err(RoochError::SwitchAccountError(format!(
                 "Address `{}` does not in the Rooch keystore",
                 rooch_address
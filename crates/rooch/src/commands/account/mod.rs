@@ -5,7 +5,8 @@ use crate::cli_types::CommandAction;
 use async_trait::async_trait;
 use commands::{
     balance::BalanceCommand, create::CreateCommand, create_multisign::CreateMultisignCommand,
-    export::ExportCommand, import::ImportCommand, list::ListCommand, nullify::NullifyCommand,
+    create_weighted_multisign::CreateWeightedMultisignCommand, export::ExportCommand,
+    import::ImportCommand, list::ListCommand, nullify::NullifyCommand, rotate::RotateCommand,
     sign::SignCommand, switch::SwitchCommand, transfer::TransferCommand, verify::VerifyCommand,
 };
 use rooch_rpc_api::jsonrpc_types::json_to_table_display::json_to_table;
@@ -32,6 +33,9 @@ impl CommandAction<String> for Account {
             AccountCommand::CreateMultisign(create_multisign) => {
                 create_multisign.execute_serialized().await
             }
+            AccountCommand::CreateWeightedMultisign(create_weighted_multisign) => {
+                create_weighted_multisign.execute_serialized().await
+            }
             AccountCommand::List(list) => list.execute_serialized().await,
             AccountCommand::Switch(switch) => switch.execute_serialized().await,
             AccountCommand::Nullify(nullify) => nullify.execute_serialized().await,
@@ -50,6 +54,7 @@ impl CommandAction<String> for Account {
             }
             AccountCommand::Export(export) => export.execute_serialized().await,
             AccountCommand::Import(import) => import.execute_serialized().await,
+            AccountCommand::Rotate(rotate) => rotate.execute_serialized().await,
             AccountCommand::Sign(sign) => sign.execute_serialized().await,
             AccountCommand::Verify(verify) => verify.execute_serialized().await,
         }
@@ -61,6 +66,7 @@ impl CommandAction<String> for Account {
 pub enum AccountCommand {
     Create(CreateCommand),
     CreateMultisign(CreateMultisignCommand),
+    CreateWeightedMultisign(CreateWeightedMultisignCommand),
     List(ListCommand),
     Switch(SwitchCommand),
     Nullify(NullifyCommand),
@@ -68,6 +74,7 @@ pub enum AccountCommand {
     Transfer(TransferCommand),
     Export(ExportCommand),
     Import(ImportCommand),
+    Rotate(RotateCommand),
     Sign(SignCommand),
     Verify(VerifyCommand),
 }
@@ -142,7 +142,7 @@ pub(crate) async fn sign_psbt(
                         }
                     }
                     let participant_addr: RoochAddress = participant.participant_address.into();
-                    if context.keystore.contains_address(&participant_addr) {
+                    if context.keystore.contains_address(&participant_addr)? {
                         debug!("Signing for participant: {}", participant_addr);
                         let kp = context.get_key_pair(&participant_addr)?;
                         let our_pubkey = kp.public().xonly_public_key()?;
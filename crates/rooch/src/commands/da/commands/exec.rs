@@ -94,6 +94,11 @@ pub struct ExecCommand {
     pub force_align: bool,
     #[clap(long = "max-block-number", help = "Max block number to exec")]
     pub max_block_number: Option<u128>,
+    #[clap(
+        long = "parallel-batch-size",
+        help = "Execute consecutive L1 transactions in parallel in batches of this size. If not set, transactions are executed one by one"
+    )]
+    pub parallel_batch_size: Option<usize>,
 
     #[clap(long = "btc-rpc-url")]
     pub btc_rpc_url: String,
@@ -285,6 +290,7 @@ impl ExecCommand {
             rollback: self.rollback,
             rooch_db,
             tx_anomalies,
+            parallel_batch_size: self.parallel_batch_size,
         })
     }
 }
@@ -309,6 +315,7 @@ struct ExecInner {
     executed_tx_order: Arc<AtomicU64>,
 
     tx_anomalies: Option<TxAnomalies>,
+    parallel_batch_size: Option<usize>,
 }
 
 struct ExecMsg {
@@ -635,59 +642,84 @@ impl ExecInner {
         let mut hist_l1tx = Histogram::<u64>::new_with_bounds(256, 1 << 30, 3)?;
         let mut hist_l2tx = Histogram::<u64>::new_with_bounds(256, 1 << 30, 3)?;
 
+        // The consecutive L1 transactions waiting to be executed in parallel
+        let mut pending = Vec::new();
         loop {
             let exec_msg_opt = rx.recv().await;
             if exec_msg_opt.is_none() {
                 break;
             }
             let exec_msg = exec_msg_opt.unwrap();
-            let tx_order = exec_msg.tx_order;
-
-            let tx_type = match &exec_msg.ledger_tx.data {
-                LedgerTxData::L1Block(_) => "L1Block",
-                LedgerTxData::L1Tx(_) => "L1Tx",
-                LedgerTxData::L2Tx(_) => "L2Tx",
-            };
 
-            let elapsed = std::time::Instant::now();
-            self.execute(exec_msg).await.with_context(|| {
-                format!(
-                    "Error occurs: tx_order: {}, executed_tx_order: {}",
-                    tx_order, executed_tx_order
-                )
-            })?;
-            let tx_cost = elapsed.elapsed().as_micros() as u64;
-            interval_cost += tx_cost;
-
-            match tx_type {
-                "L1Block" => {
-                    hist_l1block.record(tx_cost)?;
-                }
-                "L1Tx" => {
-                    hist_l1tx.record(tx_cost)?;
+            // (tx_order, tx_type, tx_cost) of the executed transactions
+            let mut executed = Vec::new();
+            match self.parallel_batch_size {
+                // The validation of L1 transactions does not depend on the state,
+                // so they can be validated before the previous transactions are executed.
+                Some(batch_size)
+                    if batch_size > 1
+                        && matches!(exec_msg.ledger_tx.data, LedgerTxData::L1Tx(_)) =>
+                {
+                    pending.push(exec_msg);
+                    if pending.len() < batch_size && !rx.is_empty() {
+                        continue;
+                    }
+                    executed = self
+                        .execute_batch(std::mem::take(&mut pending), executed_tx_order)
+                        .await?;
                 }
-                "L2Tx" => {
-                    hist_l2tx.record(tx_cost)?;
+                _ => {
+                    if !pending.is_empty() {
+                        executed = self
+                            .execute_batch(std::mem::take(&mut pending), executed_tx_order)
+                            .await?;
+                    }
+                    let tx_order = exec_msg.tx_order;
+                    let tx_type = Self::tx_type(&exec_msg.ledger_tx.data);
+                    let elapsed = std::time::Instant::now();
+                    self.execute(exec_msg).await.with_context(|| {
+                        format!(
+                            "Error occurs: tx_order: {}, executed_tx_order: {}",
+                            tx_order, executed_tx_order
+                        )
+                    })?;
+                    executed.push((tx_order, tx_type, elapsed.elapsed().as_micros() as u64));
                 }
-                _ => {}
             }
 
-            executed_tx_order = tx_order;
-            self.executed_tx_order
-                .store(executed_tx_order, std::sync::atomic::Ordering::Relaxed);
-            let done = self.done.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1;
+            for (tx_order, tx_type, tx_cost) in executed {
+                interval_cost += tx_cost;
 
-            if done % STATISTICS_INTERVAL == 0 {
-                info!(
-                    "tx range: [{}, {}], avg: {:.3} ms/tx",
-                    tx_order + 1 - STATISTICS_INTERVAL, // add first, avoid overflow
-                    tx_order,
-                    interval_cost as f64 / 1000.0 / STATISTICS_INTERVAL as f64
-                );
-                interval_cost = 0;
-                Self::print_tx_cost_stats(&hist_l1block, "L1Block", false);
-                Self::print_tx_cost_stats(&hist_l1tx, "L1Tx", false);
-                Self::print_tx_cost_stats(&hist_l2tx, "L2Tx", false);
+                match tx_type {
+                    "L1Block" => {
+                        hist_l1block.record(tx_cost)?;
+                    }
+                    "L1Tx" => {
+                        hist_l1tx.record(tx_cost)?;
+                    }
+                    "L2Tx" => {
+                        hist_l2tx.record(tx_cost)?;
+                    }
+                    _ => {}
+                }
+
+                executed_tx_order = tx_order;
+                self.executed_tx_order
+                    .store(executed_tx_order, std::sync::atomic::Ordering::Relaxed);
+                let done = self.done.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1;
+
+                if done % STATISTICS_INTERVAL == 0 {
+                    info!(
+                        "tx range: [{}, {}], avg: {:.3} ms/tx",
+                        tx_order + 1 - STATISTICS_INTERVAL, // add first, avoid overflow
+                        tx_order,
+                        interval_cost as f64 / 1000.0 / STATISTICS_INTERVAL as f64
+                    );
+                    interval_cost = 0;
+                    Self::print_tx_cost_stats(&hist_l1block, "L1Block", false);
+                    Self::print_tx_cost_stats(&hist_l1tx, "L1Tx", false);
+                    Self::print_tx_cost_stats(&hist_l2tx, "L2Tx", false);
+                }
             }
         }
         info!(
@@ -701,14 +733,101 @@ impl ExecInner {
         Ok(())
     }
 
+    fn tx_type(data: &LedgerTxData) -> &'static str {
+        match data {
+            LedgerTxData::L1Block(_) => "L1Block",
+            LedgerTxData::L1Tx(_) => "L1Tx",
+            LedgerTxData::L2Tx(_) => "L2Tx",
+        }
+    }
+
     async fn execute(&self, msg: ExecMsg) -> anyhow::Result<()> {
+        let tx_order = msg.tx_order;
+        let is_l2_tx = msg.ledger_tx.data.is_l2_tx();
+        if let Some((moveos_tx, exp_state_root)) = self.sequence_and_validate(msg).await? {
+            if let Err(err) = self
+                .execute_moveos_tx(tx_order, moveos_tx, exp_state_root)
+                .await
+            {
+                self.handle_execution_error(err, is_l2_tx, tx_order)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Execute the L1 transactions in parallel, returns the (tx_order, tx_type, tx_cost) of the executed transactions.
+    /// The cost of the batch is shared by the transactions equally.
+    async fn execute_batch(
+        &self,
+        msgs: Vec<ExecMsg>,
+        executed_tx_order: u64,
+    ) -> anyhow::Result<Vec<(u64, &'static str, u64)>> {
+        let first_tx_order = msgs.first().map(|msg| msg.tx_order).unwrap_or_default();
+        let last_tx_order = msgs.last().map(|msg| msg.tx_order).unwrap_or_default();
+        let elapsed = std::time::Instant::now();
+
+        let mut tx_orders = Vec::with_capacity(msgs.len());
+        let mut moveos_txs = Vec::with_capacity(msgs.len());
+        let mut exp_state_roots = Vec::with_capacity(msgs.len());
+        let mut executed = Vec::with_capacity(msgs.len());
+        for msg in msgs {
+            let tx_order = msg.tx_order;
+            let tx_type = Self::tx_type(&msg.ledger_tx.data);
+            executed.push((tx_order, tx_type));
+            if let Some((moveos_tx, exp_state_root)) =
+                self.sequence_and_validate(msg).await.with_context(|| {
+                    format!(
+                        "Error occurs: tx_order: {}, executed_tx_order: {}",
+                        tx_order, executed_tx_order
+                    )
+                })?
+            {
+                tx_orders.push(tx_order);
+                moveos_txs.push(moveos_tx);
+                exp_state_roots.push(exp_state_root);
+            }
+        }
+
+        if !moveos_txs.is_empty() {
+            let results = self
+                .executor
+                .execute_transaction_batch(moveos_txs)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Error occurs: tx_order: [{}, {}], executed_tx_order: {}",
+                        first_tx_order, last_tx_order, executed_tx_order
+                    )
+                })?;
+            for ((tx_order, (_output, execution_info)), exp_state_root) in
+                tx_orders.into_iter().zip(results).zip(exp_state_roots)
+            {
+                if let Err(err) = Self::check_state_root(tx_order, execution_info, exp_state_root) {
+                    self.handle_execution_error(err, false, tx_order)?;
+                }
+            }
+        }
+
+        let tx_cost = elapsed.elapsed().as_micros() as u64 / executed.len().max(1) as u64;
+        Ok(executed
+            .into_iter()
+            .map(|(tx_order, tx_type)| (tx_order, tx_type, tx_cost))
+            .collect())
+    }
+
+    /// Sequence the transaction if needed, and validate it for the execution.
+    /// Returns None if the transaction does not need to be executed.
+    async fn sequence_and_validate(
+        &self,
+        msg: ExecMsg,
+    ) -> anyhow::Result<Option<(VerifiedMoveOSTransaction, Option<H256>)>> {
         let ExecMsg {
             tx_order,
             mut ledger_tx,
             l1_block_with_body,
         } = msg;
         let tx_hash = ledger_tx.tx_hash();
-        let is_l2_tx = ledger_tx.data.is_l2_tx();
 
         let exp_root_opt = self.tx_meta_store.get_exp_roots(tx_order).await;
         let exp_state_root = exp_root_opt.map(|v| v.0);
@@ -735,15 +854,10 @@ impl ExecInner {
             let moveos_tx = self
                 .validate_ledger_transaction(ledger_tx, l1_block_with_body)
                 .await?;
-            if let Err(err) = self
-                .execute_moveos_tx(tx_order, moveos_tx, exp_state_root)
-                .await
-            {
-                self.handle_execution_error(err, is_l2_tx, tx_order)?;
-            }
+            return Ok(Some((moveos_tx, exp_state_root)));
         }
 
-        Ok(())
+        Ok(None)
     }
 
     fn handle_execution_error(
@@ -822,6 +936,14 @@ impl ExecInner {
 
         let (_output, execution_info) = executor.execute_transaction(moveos_tx.clone()).await?;
 
+        Self::check_state_root(tx_order, execution_info, exp_state_root_opt)
+    }
+
+    fn check_state_root(
+        tx_order: u64,
+        execution_info: TransactionExecutionInfo,
+        exp_state_root_opt: Option<H256>,
+    ) -> anyhow::Result<()> {
         let root = execution_info.root_metadata();
         match exp_state_root_opt {
             Some(expected_root) => {
@@ -15,6 +15,8 @@ pub mod get_changeset_by_order;
 pub mod get_execution_info_by_hash;
 pub mod get_tx_by_order;
 pub mod list_anomaly;
+pub mod prune;
+pub mod reorg_rollback;
 pub mod repair;
 pub mod revert;
 pub mod rollback;
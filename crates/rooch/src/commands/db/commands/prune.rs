@@ -0,0 +1,61 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::utils::open_rooch_db;
+use clap::Parser;
+use metrics::RegistryService;
+use rooch_config::R_OPT_NET_HELP;
+use rooch_db::pruner::{StatePruner, DEFAULT_PRUNE_BATCH_SIZE};
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::rooch_network::RoochChainID;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PruneOutput {
+    /// The states before this tx order have been pruned
+    pub pruned_tx_order: u64,
+    pub deleted_nodes: u64,
+}
+
+/// Prune the stale state nodes offline, keep the states of the last N transactions.
+/// Only the state written after state pruning was introduced can be pruned,
+/// the state nodes written by an older version are never deleted.
+#[derive(Debug, Parser)]
+pub struct PruneCommand {
+    #[clap(
+        long,
+        help = "the number of the recent transactions whose states are kept"
+    )]
+    pub keep_recent: u64,
+    #[clap(long, default_value_t = DEFAULT_PRUNE_BATCH_SIZE, help = "the number of transactions pruned in a batch")]
+    pub batch_size: u64,
+
+    #[clap(long = "data-dir", short = 'd')]
+    pub base_data_dir: Option<PathBuf>,
+    #[clap(long, short = 'n', help = R_OPT_NET_HELP)]
+    pub chain_id: Option<RoochChainID>,
+}
+
+impl PruneCommand {
+    pub async fn execute(self) -> RoochResult<PruneOutput> {
+        if self.keep_recent == 0 {
+            return Err(RoochError::CommandArgumentError(
+                "keep-recent should be greater than 0".to_string(),
+            ));
+        }
+        let (_root, rooch_db, _start_time) = open_rooch_db(self.base_data_dir, self.chain_id);
+        let registry_service = RegistryService::default();
+        let pruner = StatePruner::new(
+            rooch_db,
+            self.keep_recent,
+            &registry_service.default_registry(),
+        )
+        .with_batch_size(self.batch_size);
+        let (pruned_tx_order, deleted_nodes) = pruner.prune()?;
+        Ok(PruneOutput {
+            pruned_tx_order,
+            deleted_nodes,
+        })
+    }
+}
@@ -0,0 +1,168 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::commands::db::commands::rollback::RollbackCommand;
+use crate::utils::open_rooch_db;
+use bitcoin::hashes::Hash;
+use bitcoin_client::actor::client::BitcoinClientConfig;
+use bitcoin_client::proxy::BitcoinClientProxy;
+use clap::Parser;
+use coerce::actor::system::ActorSystem;
+use coerce::actor::IntoActor;
+use rooch_config::R_OPT_NET_HELP;
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::rooch_network::RoochChainID;
+use rooch_types::transaction::LedgerTxData;
+use std::path::PathBuf;
+
+/// Guided recovery from a Bitcoin reorg deeper than the pending window.
+/// Find the fork point of the relayed Bitcoin blocks and the Bitcoin main chain,
+/// then rollback to the tx before the first orphaned block. Only print the plan without `--execute`.
+#[derive(Debug, Parser)]
+pub struct ReorgRollbackCommand {
+    #[clap(long = "btc-rpc-url")]
+    pub btc_rpc_url: String,
+    #[clap(long = "btc-rpc-user-name")]
+    pub btc_rpc_user_name: String,
+    #[clap(long = "btc-rpc-password")]
+    pub btc_rpc_password: String,
+
+    #[clap(
+        long = "search-depth",
+        help = "max txs to search backward from the last tx_order for the fork point, default is 100000",
+        default_value = "100000"
+    )]
+    pub search_depth: u64,
+
+    #[clap(
+        long,
+        help = "Rollback to the fork point, otherwise only print the plan"
+    )]
+    pub execute: bool,
+
+    #[clap(long = "data-dir", short = 'd')]
+    pub base_data_dir: Option<PathBuf>,
+    #[clap(long, short = 'n', help = R_OPT_NET_HELP)]
+    pub chain_id: Option<RoochChainID>,
+}
+
+struct OrphanedBlock {
+    block_height: u64,
+    block_hash: bitcoin::BlockHash,
+    tx_order: u64,
+}
+
+impl ReorgRollbackCommand {
+    pub async fn execute(self) -> RoochResult<()> {
+        let actor_system = ActorSystem::global_system();
+        let bitcoin_client = BitcoinClientConfig {
+            btc_rpc_url: self.btc_rpc_url.clone(),
+            btc_rpc_user_name: self.btc_rpc_user_name.clone(),
+            btc_rpc_password: self.btc_rpc_password.clone(),
+            local_block_store_dir: None,
+        }
+        .build()?;
+        let bitcoin_client_actor_ref = bitcoin_client
+            .into_actor(Some("bitcoin_client_for_reorg_rollback"), &actor_system)
+            .await?;
+        let bitcoin_client_proxy = BitcoinClientProxy::new(bitcoin_client_actor_ref.into());
+
+        let (_root, rooch_db, _start_time) =
+            open_rooch_db(self.base_data_dir.clone(), self.chain_id.clone());
+        let last_order = rooch_db
+            .rooch_store
+            .get_meta_store()
+            .get_sequencer_info()?
+            .ok_or_else(|| anyhow::anyhow!("Load sequencer info failed"))?
+            .last_order;
+        let best_block_height_in_bitcoin = bitcoin_client_proxy
+            .get_block_header_info(bitcoin_client_proxy.get_best_block_hash().await?)
+            .await?
+            .height as u64;
+
+        // search backward the relayed bitcoin blocks until the first block in the main chain,
+        // the relayed blocks after it are orphaned.
+        let mut orphaned_blocks = vec![];
+        let mut fork_block = None;
+        let end_order = last_order.saturating_sub(self.search_depth).max(1);
+        for tx_order in (end_order..=last_order).rev() {
+            let Some(ledger_tx) = rooch_db
+                .rooch_store
+                .transaction_store
+                .get_tx_by_order(tx_order)?
+            else {
+                continue;
+            };
+            let LedgerTxData::L1Block(l1_block) = ledger_tx.data else {
+                continue;
+            };
+            if !l1_block.chain_id.is_bitcoin() {
+                continue;
+            }
+            let block_hash = bitcoin::BlockHash::from_slice(&l1_block.block_hash)
+                .map_err(|e| anyhow::anyhow!("Invalid bitcoin block hash: {:?}", e))?;
+            let in_main_chain = l1_block.block_height <= best_block_height_in_bitcoin
+                && bitcoin_client_proxy
+                    .get_block_hash(l1_block.block_height)
+                    .await?
+                    == block_hash;
+            if in_main_chain {
+                fork_block = Some((l1_block.block_height, block_hash, tx_order));
+                break;
+            }
+            orphaned_blocks.push(OrphanedBlock {
+                block_height: l1_block.block_height,
+                block_hash,
+                tx_order,
+            });
+        }
+
+        if orphaned_blocks.is_empty() {
+            println!("all relayed bitcoin blocks are in the main chain, no need to rollback");
+            return Ok(());
+        }
+        let Some((fork_block_height, fork_block_hash, fork_tx_order)) = fork_block else {
+            return Err(RoochError::from(anyhow::anyhow!(
+                "no relayed bitcoin block in the main chain found in {} txs, please increase search depth",
+                self.search_depth
+            )));
+        };
+
+        // rollback to the tx before the first orphaned block
+        let first_orphaned_block = orphaned_blocks.last().unwrap();
+        let rollback_tx_order = first_orphaned_block.tx_order - 1;
+        println!("--------------- reorg ------------------");
+        println!(
+            "fork point block height: {}, block hash: {}, tx_order: {}",
+            fork_block_height, fork_block_hash, fork_tx_order
+        );
+        for orphaned_block in orphaned_blocks.iter().rev() {
+            println!(
+                "orphaned block height: {}, block hash: {}, tx_order: {}",
+                orphaned_block.block_height, orphaned_block.block_hash, orphaned_block.tx_order
+            );
+        }
+        println!("rollback tx_order: {}", rollback_tx_order);
+
+        if !self.execute {
+            println!(
+                "please stop the service and run this command again with `--execute`, or run `rooch db rollback --tx-order {}`",
+                rollback_tx_order
+            );
+            return Ok(());
+        }
+        drop(rooch_db);
+
+        RollbackCommand {
+            tx_order: rollback_tx_order,
+            base_data_dir: self.base_data_dir,
+            chain_id: self.chain_id,
+        }
+        .execute()
+        .await?;
+        println!(
+            "rollback to the fork point succeed, restart the service to relay the bitcoin blocks from the fork point"
+        );
+        Ok(())
+    }
+}
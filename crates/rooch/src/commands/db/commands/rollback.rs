@@ -51,6 +51,7 @@ impl RollbackCommand {
         }
 
         let (_root, rooch_db, _start_time) = open_rooch_db(self.base_data_dir, self.chain_id);
+        rooch_db.ensure_state_not_pruned(tx_order)?;
 
         // 1. check
         // 1.1 tx_hash exists via tx_order
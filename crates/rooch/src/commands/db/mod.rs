@@ -10,6 +10,8 @@ use crate::commands::db::commands::get_changeset_by_order::GetChangesetByOrderCo
 use crate::commands::db::commands::get_execution_info_by_hash::GetExecutionInfoByHashCommand;
 use crate::commands::db::commands::get_tx_by_order::GetTxByOrderCommand;
 use crate::commands::db::commands::list_anomaly::ListAnomaly;
+use crate::commands::db::commands::prune::PruneCommand;
+use crate::commands::db::commands::reorg_rollback::ReorgRollbackCommand;
 use crate::commands::db::commands::repair::RepairCommand;
 use crate::commands::db::commands::revert::RevertCommand;
 use async_trait::async_trait;
@@ -57,6 +59,11 @@ impl CommandAction<String> for DB {
             DBCommand::BestRollback(best_rollback) => best_rollback.execute().await.map(|resp| {
                 serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
             }),
+            DBCommand::ReorgRollback(reorg_rollback) => {
+                reorg_rollback.execute().await.map(|resp| {
+                    serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
+                })
+            }
             DBCommand::ListAnomaly(list_anomaly) => list_anomaly.execute().map(|resp| {
                 serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
             }),
@@ -66,6 +73,9 @@ impl CommandAction<String> for DB {
             DBCommand::Changeset(changeset) => changeset.execute().await.map(|resp| {
                 serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
             }),
+            DBCommand::Prune(prune) => prune.execute().await.map(|resp| {
+                serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
+            }),
         }
     }
 }
@@ -81,7 +91,9 @@ pub enum DBCommand {
     GetChangesetByOrder(GetChangesetByOrderCommand),
     GetExecutionInfoByHash(GetExecutionInfoByHashCommand),
     BestRollback(BestRollbackCommand),
+    ReorgRollback(ReorgRollbackCommand),
     ListAnomaly(ListAnomaly),
     CpCf(CpCfCommand),
     Changeset(ChangesetCommand),
+    Prune(PruneCommand),
 }
@@ -18,6 +18,7 @@ pub const BATCH_SIZE: usize = 5000;
 fn init_indexer(
     base_data_dir: Option<PathBuf>,
     chain_id: Option<RoochChainID>,
+    indexer_db_url: Option<String>,
 ) -> Result<(IndexerStore, IndexerReader)> {
     // Reconstruct RoochOpt
     let opt = RoochOpt::new_with_default(base_data_dir, chain_id, None)?;
@@ -25,12 +26,16 @@ fn init_indexer(
     let store_config = opt.store_config();
     let registry_service = RegistryService::default();
 
+    let registry = registry_service.default_registry();
+    if let Some(indexer_db_url) = indexer_db_url {
+        let indexer_store = IndexerStore::new_postgres(&indexer_db_url, &registry)?;
+        let indexer_reader = IndexerReader::new_postgres(&indexer_db_url, &registry)?;
+        return Ok((indexer_store, indexer_reader));
+    }
+
     let indexer_db_path = store_config.get_indexer_dir();
-    let indexer_store = IndexerStore::new(
-        indexer_db_path.clone(),
-        &registry_service.default_registry(),
-    )?;
-    let indexer_reader = IndexerReader::new(indexer_db_path, &registry_service.default_registry())?;
+    let indexer_store = IndexerStore::new(indexer_db_path.clone(), &registry)?;
+    let indexer_reader = IndexerReader::new(indexer_db_path, &registry)?;
 
     Ok((indexer_store, indexer_reader))
 }
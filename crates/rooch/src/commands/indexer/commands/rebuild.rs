@@ -45,6 +45,11 @@ pub struct RebuildCommand {
 
     #[clap(long, short = 'b', default_value = "20971")]
     pub batch_size: Option<usize>,
+
+    #[clap(long = "indexer-db-url")]
+    /// The Postgres db url of the indexer, use the SQLite indexer under the data dir if not set.
+    /// Requires rooch built with the `postgres` feature.
+    pub indexer_db_url: Option<String>,
 }
 
 impl RebuildCommand {
@@ -71,8 +76,12 @@ impl RebuildCommand {
 
     fn init(self) -> (IndexerStore, IndexerReader, Instant) {
         let start_time = Instant::now();
-        let (indexer_store, indexer_reader) =
-            init_indexer(self.base_data_dir.clone(), self.chain_id.clone()).unwrap();
+        let (indexer_store, indexer_reader) = init_indexer(
+            self.base_data_dir.clone(),
+            self.chain_id.clone(),
+            self.indexer_db_url.clone(),
+        )
+        .unwrap();
         tracing::info!("indexer rebuild started");
         (indexer_store, indexer_reader, start_time)
     }
@@ -8,7 +8,7 @@ use clap::Parser;
 use move_command_line_common::types::ParsedStructType;
 use move_core_types::language_storage::TypeTag;
 use moveos_types::transaction::FunctionCall;
-use rooch_rpc_api::jsonrpc_types::AnnotatedFunctionResultView;
+use rooch_rpc_api::jsonrpc_types::{AnnotatedFunctionResultView, StateOptions};
 use rooch_types::{
     error::{RoochError, RoochResult},
     function_arg::{FunctionArg, ParsedFunctionId},
@@ -40,6 +40,10 @@ pub struct RunViewFunction {
     #[clap(long = "args")]
     pub args: Vec<FunctionArg>,
 
+    /// Execute the function at the historical state after the transaction of the tx order is executed.
+    #[clap(long)]
+    pub at_tx_order: Option<u64>,
+
     /// RPC client options.
     #[clap(flatten)]
     context: WalletContextOptions,
@@ -69,9 +73,10 @@ impl CommandAction<AnnotatedFunctionResultView> for RunViewFunction {
         let function_call = FunctionCall::new(function_id, type_args, args);
 
         let client = context.get_client().await?;
+        let state_option = StateOptions::default().at_tx_order(self.at_tx_order);
         client
             .rooch
-            .execute_view_function(function_call)
+            .execute_view_function_with_option(function_call, Some(state_option))
             .await
             .map_err(|e| RoochError::ViewFunctionError(e.to_string()))
     }
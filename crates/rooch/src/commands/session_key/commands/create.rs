@@ -2,7 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::cli_types::{TransactionOptions, WalletContextOptions};
+use anyhow::Result;
 use clap::Parser;
+use move_core_types::language_storage::StructTag;
+use move_core_types::u256::U256;
 use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::move_std::string::MoveString;
 use rooch_key::keystore::account_keystore::AccountKeystore;
@@ -11,6 +14,17 @@ use rooch_types::{
     error::{RoochError, RoochResult},
     framework::session_key::{SessionKey, SessionKeyModule, SessionScope},
 };
+use std::str::FromStr;
+
+fn parse_coin_quota(s: &str) -> Result<(StructTag, U256)> {
+    let (coin_type, amount) = s.split_once('=').ok_or_else(|| {
+        anyhow::anyhow!(
+            "Invalid coin quota: {}, the format should be `<coin_type>=<amount>`",
+            s
+        )
+    })?;
+    Ok((StructTag::from_str(coin_type)?, U256::from_str(amount)?))
+}
 
 /// Create a new session key on-chain
 #[derive(Debug, Parser)]
@@ -31,6 +45,20 @@ pub struct CreateCommand {
     #[clap(long, default_value = "3600")]
     pub max_inactive_interval: u64,
 
+    /// The max total gas amount the session key can use, unlimited if not set.
+    #[clap(long)]
+    pub quota_max_gas_amount: Option<u64>,
+
+    /// The max coin amount, in the smallest unit of the coin, the session key can withdraw or transfer,
+    /// format: coin_type=amount, for example: 0x3::gas_coin::RGas=100000000.
+    /// The option can be repeated for multiple coin types, the coin types not set are unlimited.
+    #[clap(long, value_parser = parse_coin_quota)]
+    pub quota_max_coin_amount: Vec<(StructTag, U256)>,
+
+    /// The max number of transactions the session key can send, unlimited if not set.
+    #[clap(long)]
+    pub quota_max_tx_count: Option<u64>,
+
     #[clap(flatten)]
     pub tx_options: TransactionOptions,
 
@@ -48,14 +76,29 @@ impl CreateCommand {
         let session_auth_key = context.generate_session_key(&sender)?;
         let session_scope = self.scope;
 
-        let action =
-            rooch_types::framework::session_key::SessionKeyModule::create_session_key_action(
+        let has_quota = self.quota_max_gas_amount.is_some()
+            || !self.quota_max_coin_amount.is_empty()
+            || self.quota_max_tx_count.is_some();
+        let action = if has_quota {
+            SessionKeyModule::create_session_key_with_quota_action(
+                self.app_name,
+                self.app_url,
+                session_auth_key.as_ref().to_vec(),
+                vec![session_scope],
+                self.max_inactive_interval,
+                self.quota_max_gas_amount,
+                self.quota_max_tx_count,
+                self.quota_max_coin_amount,
+            )
+        } else {
+            SessionKeyModule::create_session_key_action(
                 self.app_name,
                 self.app_url,
                 session_auth_key.as_ref().to_vec(),
-                session_scope.clone(),
+                session_scope,
                 self.max_inactive_interval,
-            );
+            )
+        };
 
         println!("Generated new session key {session_auth_key} for address [{sender}]",);
 
@@ -45,14 +45,51 @@ impl CommandAction<Vec<BTreeMap<Identifier, AnnotatedMoveValueView>>> for ListCo
         let options = StateOptions::new().decode(true);
         let field_result = client
             .rooch
-            .list_field_states(obj_id.into(), None, None, Some(options))
+            .list_field_states(obj_id.into(), None, None, Some(options.clone()))
             .await
             .map_err(RoochError::from)?;
+        let mut session_keys = extract_session_keys(field_result);
 
-        Ok(extract_session_keys(field_result))
+        // The quota is stored in a separate table, keyed by the same authentication key
+        if let Some(quotas_obj_id) =
+            session_key_module.get_session_key_quotas_handle(address_addr)?
+        {
+            let quota_result = client
+                .rooch
+                .list_field_states(quotas_obj_id.into(), None, None, Some(options))
+                .await
+                .map_err(RoochError::from)?;
+            let mut quotas: BTreeMap<AnnotatedMoveValueView, AnnotatedMoveValueView> =
+                extract_session_keys(quota_result)
+                    .into_iter()
+                    .filter_map(|mut field| {
+                        let name = field.remove(&name_field())?;
+                        let value = field.remove(&value_field())?;
+                        Some((name, value))
+                    })
+                    .collect();
+            for session_key in session_keys.iter_mut() {
+                if let Some(quota) = session_key
+                    .get(&name_field())
+                    .and_then(|name| quotas.remove(name))
+                {
+                    session_key.insert(Identifier::new("quota").unwrap(), quota);
+                }
+            }
+        }
+
+        Ok(session_keys)
     }
 }
 
+fn name_field() -> Identifier {
+    Identifier::new("name").unwrap()
+}
+
+fn value_field() -> Identifier {
+    Identifier::new("value").unwrap()
+}
+
 fn extract_session_keys(
     field_result: StatePageView,
 ) -> Vec<BTreeMap<Identifier, AnnotatedMoveValueView>> {
@@ -5,7 +5,7 @@ use crate::cli_types::{CommandAction, WalletContextOptions};
 use async_trait::async_trait;
 use clap::Parser;
 use moveos_types::access_path::AccessPath;
-use rooch_rpc_api::jsonrpc_types::ObjectStateView;
+use rooch_rpc_api::jsonrpc_types::{ObjectStateView, StateOptions};
 use rooch_types::error::{RoochError, RoochResult};
 
 /// Get states by accessPath
@@ -26,6 +26,10 @@ pub struct StateCommand {
     /// Render and return display fields.
     #[clap(long)]
     pub show_display: bool,
+
+    /// Get the historical states after the transaction of the tx order is executed.
+    #[clap(long)]
+    pub at_tx_order: Option<u64>,
 }
 
 #[async_trait]
@@ -33,19 +37,15 @@ impl CommandAction<Vec<Option<ObjectStateView>>> for StateCommand {
     async fn execute(self) -> RoochResult<Vec<Option<ObjectStateView>>> {
         let client = self.context_options.build()?.get_client().await?;
 
-        let resp = if self.show_display {
-            client
-                .rooch
-                .get_decoded_states_with_display(self.access_path)
-                .await
-                .map_err(RoochError::from)?
-        } else {
-            client
-                .rooch
-                .get_decoded_states(self.access_path, None)
-                .await
-                .map_err(RoochError::from)?
-        };
+        let state_option = StateOptions::default()
+            .decode(true)
+            .show_display(self.show_display)
+            .at_tx_order(self.at_tx_order);
+        let resp = client
+            .rooch
+            .get_states_with_option(self.access_path, state_option)
+            .await
+            .map_err(RoochError::from)?;
         Ok(resp)
     }
 }
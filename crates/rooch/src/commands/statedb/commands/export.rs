@@ -3,7 +3,7 @@
 
 use std::fmt::Display;
 use std::fs::File;
-use std::io::{BufRead, BufReader, Read};
+use std::io::{BufRead, BufReader, BufWriter, Read};
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -30,7 +30,9 @@ use crate::commands::statedb::commands::inscription::{
     gen_inscription_id_update, InscriptionSource,
 };
 use crate::commands::statedb::commands::utxo::UTXORawData;
-use crate::commands::statedb::commands::{init_job, ExportWriter, OutpointInscriptionsMap};
+use crate::commands::statedb::commands::{
+    init_job, init_rooch_db, ExportWriter, OutpointInscriptionsMap,
+};
 
 /// Export statedb
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
@@ -215,6 +217,9 @@ pub struct ExportCommand {
     pub object_id: Option<ObjectID>,
     #[clap(long)]
     pub object_name: Option<ExportObjectName>,
+    /// export the snapshot at the tx order, for snapshot mode, default is the end of the last proposed block
+    #[clap(long)]
+    pub tx_order: Option<u64>,
 
     #[clap(long = "data-dir", short = 'd')]
     /// Path to data dir, this dir is base dir, the final data_dir is base_dir/chain_network_name
@@ -233,6 +238,13 @@ pub struct ExportCommand {
 
 impl ExportCommand {
     pub async fn execute(self) -> RoochResult<()> {
+        let mode = self.mode.unwrap_or_default();
+        // The snapshot is exported from the RoochDB, not the csv ExportWriter
+        if mode == ExportMode::Snapshot {
+            self.export_snapshot()?;
+            return Ok(());
+        }
+
         let (root, moveos_store, start_time) =
             init_job(self.base_data_dir.clone(), self.chain_id.clone());
 
@@ -240,7 +252,6 @@ impl ExportCommand {
         let mut writer = ExportWriter::new(output, None);
         let root_state_root = self.state_root.unwrap_or(root.state_root());
 
-        let mode = self.mode.unwrap_or_default();
         match mode {
             ExportMode::Genesis => {
                 self.export_genesis_from_file()?;
@@ -250,7 +261,7 @@ impl ExportCommand {
                 todo!()
             }
             ExportMode::Snapshot => {
-                todo!()
+                unreachable!("snapshot mode is handled before init job")
             }
             ExportMode::Indexer => {
                 self.export_indexer(&moveos_store, root_state_root, &mut writer)?;
@@ -271,6 +282,26 @@ impl ExportCommand {
         Ok(())
     }
 
+    fn export_snapshot(&self) -> Result<()> {
+        let start_time = Instant::now();
+        let output = self
+            .output
+            .clone()
+            .expect("output must be existed for snapshot mode");
+        let rooch_db = init_rooch_db(self.base_data_dir.clone(), self.chain_id.clone());
+        let mut writer = BufWriter::new(File::create(output)?);
+        let (manifest, node_count) = rooch_db.export_snapshot(self.tx_order, &mut writer)?;
+        tracing::info!(
+            "Export snapshot at tx_order: {}, state_root: {:?}, block: {:?}, nodes: {}, done in {:?}.",
+            manifest.tx_order,
+            manifest.execution_info.state_root,
+            manifest.block.as_ref().map(|block| block.block_number),
+            node_count,
+            start_time.elapsed(),
+        );
+        Ok(())
+    }
+
     fn export_genesis_from_file(&self) -> Result<()> {
         let genesis_output = self
             .genesis_output
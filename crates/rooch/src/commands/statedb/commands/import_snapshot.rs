@@ -0,0 +1,60 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+use std::time::Instant;
+
+use clap::Parser;
+
+use rooch_config::R_OPT_NET_HELP;
+use rooch_rpc_api::jsonrpc_types::transaction_view::BlockView;
+use rooch_types::block::Block;
+use rooch_types::error::RoochResult;
+use rooch_types::rooch_network::RoochChainID;
+
+use crate::commands::statedb::commands::init_rooch_db;
+
+/// Import the state snapshot exported by `rooch statedb export --mode snapshot` into an empty data dir.
+/// The snapshot is verified against the trusted block before the node can start from it.
+#[derive(Debug, Parser)]
+pub struct ImportSnapshotCommand {
+    #[clap(long, short = 'i')]
+    /// snapshot input file, exported by `rooch statedb export --mode snapshot`
+    pub input: PathBuf,
+    #[clap(long)]
+    /// trusted block json file, the `block` of `rooch_getTransactionAccumulatorProof` from a trusted node.
+    /// The state root and tx accumulator root of the snapshot must match the block.
+    pub trusted_block: PathBuf,
+
+    #[clap(long = "data-dir", short = 'd')]
+    /// Path to data dir, this dir is base dir, the final data_dir is base_dir/chain_network_name
+    pub base_data_dir: Option<PathBuf>,
+
+    /// If local chainid, start the service with a temporary data store.
+    /// All data will be deleted when the service is stopped.
+    #[clap(long, short = 'n', help = R_OPT_NET_HELP)]
+    pub chain_id: Option<RoochChainID>,
+}
+
+impl ImportSnapshotCommand {
+    pub async fn execute(self) -> RoochResult<()> {
+        let start_time = Instant::now();
+        let block_view: BlockView = serde_json::from_reader(File::open(&self.trusted_block)?)?;
+        let trusted_block = Block::from(block_view);
+
+        let rooch_db = init_rooch_db(self.base_data_dir.clone(), self.chain_id.clone());
+        let mut reader = BufReader::new(File::open(&self.input)?);
+        let (manifest, node_count) = rooch_db.import_snapshot(&mut reader, &trusted_block)?;
+        tracing::info!(
+            "Import snapshot at tx_order: {}, state_root: {:?}, block: {}, nodes: {}, done in {:?}.",
+            manifest.tx_order,
+            manifest.execution_info.state_root,
+            trusted_block.block_number,
+            node_count,
+            start_time.elapsed(),
+        );
+        Ok(())
+    }
+}
@@ -36,6 +36,7 @@ pub mod genesis;
 pub mod genesis_ord;
 pub mod genesis_utxo;
 pub mod genesis_verify;
+pub mod import_snapshot;
 pub mod re_genesis;
 
 mod inscription;
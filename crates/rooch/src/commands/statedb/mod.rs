@@ -12,6 +12,7 @@ use crate::commands::statedb::commands::genesis::GenesisCommand;
 use crate::commands::statedb::commands::genesis_ord::GenesisOrdCommand;
 use crate::commands::statedb::commands::genesis_utxo::GenesisUTXOCommand;
 use crate::commands::statedb::commands::genesis_verify::GenesisVerifyCommand;
+use crate::commands::statedb::commands::import_snapshot::ImportSnapshotCommand;
 use crate::commands::statedb::commands::re_genesis::ReGenesisCommand;
 
 pub mod commands;
@@ -44,6 +45,11 @@ impl CommandAction<String> for Statedb {
                     serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
                 })
             }
+            StatedbCommand::ImportSnapshot(import_snapshot) => {
+                import_snapshot.execute().await.map(|resp| {
+                    serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
+                })
+            }
             StatedbCommand::ReGenesis(re_genesis) => re_genesis.execute().await.map(|resp| {
                 serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
             }),
@@ -59,5 +65,6 @@ pub enum StatedbCommand {
     GenesisUTXO(GenesisUTXOCommand),
     GenesisOrd(GenesisOrdCommand),
     GenesisVerify(GenesisVerifyCommand),
+    ImportSnapshot(ImportSnapshotCommand),
     ReGenesis(ReGenesisCommand),
 }
@@ -5,12 +5,16 @@ use crate::cli_types::{CommandAction, TransactionOptions, WalletContextOptions};
 use anyhow::Result;
 use async_trait::async_trait;
 use move_command_line_common::types::ParsedStructType;
+use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::TypeTag;
-use moveos_types::transaction::MoveAction;
+use moveos_types::transaction::{BatchCall, CallResultArg, FunctionCall, MoveAction};
 use rooch_types::{
-    error::RoochResult,
+    error::{RoochError, RoochResult},
     function_arg::{parse_function_arg, FunctionArg, ParsedFunctionId},
 };
+use serde::Deserialize;
+use std::path::PathBuf;
+use std::str::FromStr;
 
 use super::{FileOutput, FileOutputData};
 
@@ -19,8 +23,8 @@ use super::{FileOutput, FileOutputData};
 pub struct BuildCommand {
     /// Function name as `<ADDRESS>::<MODULE_ID>::<FUNCTION_NAME>`
     /// Example: `0x42::message::set_message`, `rooch_framework::empty::empty`
-    #[clap(long, required = true)]
-    pub function: ParsedFunctionId,
+    #[clap(long, required_unless_present = "batch", conflicts_with = "batch")]
+    pub function: Option<ParsedFunctionId>,
 
     /// TypeTag arguments separated by spaces.
     ///
@@ -40,6 +44,16 @@ pub struct BuildCommand {
     #[clap(long = "args", value_parser=parse_function_arg)]
     pub args: Vec<FunctionArg>,
 
+    /// Build a batch transaction from a JSON file, the calls are executed atomically in order.
+    ///
+    /// The file contains an array of calls, the `args` use the same format as `--args`,
+    /// and the `result_args` insert the return values of the previous calls as arguments.
+    ///
+    /// Example: `[{"function": "0x42::counter::value", "args": []},
+    ///     {"function": "0x42::counter::set", "args": [], "result_args": [{"arg_index": 0, "call_index": 0, "result_index": 0}]}]`
+    #[clap(long, conflicts_with_all = ["type_args", "args"])]
+    pub batch: Option<PathBuf>,
+
     #[clap(flatten)]
     tx_options: TransactionOptions,
 
@@ -65,22 +79,27 @@ impl CommandAction<Option<FileOutput>> for BuildCommand {
         let sequenc_number = self.tx_options.sequence_number;
         let max_gas_amount = self.tx_options.max_gas_amount;
 
-        let function_id = self.function.into_function_id(&address_mapping)?;
-        let args = self
-            .args
-            .into_iter()
-            .map(|arg| arg.into_bytes(&address_mapping))
-            .collect::<Result<Vec<_>>>()?;
-        let type_args = self
-            .type_args
-            .into_iter()
-            .map(|tag| {
-                Ok(TypeTag::Struct(Box::new(
-                    tag.into_struct_tag(&address_mapping)?,
-                )))
-            })
-            .collect::<Result<Vec<_>>>()?;
-        let action = MoveAction::new_function_call(function_id, type_args, args);
+        let action = match (self.function, self.batch) {
+            (Some(function), None) => {
+                let function_call =
+                    build_function_call(function, self.type_args, self.args, &address_mapping)?;
+                MoveAction::Function(function_call)
+            }
+            (None, Some(batch)) => {
+                let batch_file = std::fs::read_to_string(&batch)?;
+                let batch_calls: Vec<BatchCallArg> = serde_json::from_str(&batch_file)?;
+                let calls = batch_calls
+                    .into_iter()
+                    .map(|batch_call| batch_call.into_batch_call(&address_mapping))
+                    .collect::<Result<Vec<_>>>()?;
+                MoveAction::new_batch_call(calls)
+            }
+            _ => {
+                return Err(RoochError::CommandArgumentError(
+                    "Either --function or --batch should be specified".to_owned(),
+                ))
+            }
+        };
 
         let tx_data = context
             .build_tx_data_with_sequence_number(sender, action, max_gas_amount, sequenc_number)
@@ -96,3 +115,58 @@ impl CommandAction<Option<FileOutput>> for BuildCommand {
         }
     }
 }
+
+/// A call in the batch file of `rooch transaction build --batch`
+#[derive(Debug, Deserialize)]
+struct BatchCallArg {
+    function: String,
+    #[serde(default)]
+    type_args: Vec<String>,
+    #[serde(default)]
+    args: Vec<String>,
+    #[serde(default)]
+    result_args: Vec<CallResultArg>,
+}
+
+impl BatchCallArg {
+    fn into_batch_call(
+        self,
+        address_mapping: &impl Fn(&str) -> Option<AccountAddress>,
+    ) -> Result<BatchCall> {
+        let function = ParsedFunctionId::from_str(&self.function)?;
+        let type_args = self
+            .type_args
+            .iter()
+            .map(|tag| ParsedStructType::parse(tag))
+            .collect::<Result<Vec<_>>>()?;
+        let args = self
+            .args
+            .iter()
+            .map(|arg| parse_function_arg(arg).map_err(Into::into))
+            .collect::<Result<Vec<_>>>()?;
+        let call = build_function_call(function, type_args, args, address_mapping)?;
+        Ok(BatchCall::new(call, self.result_args))
+    }
+}
+
+fn build_function_call(
+    function: ParsedFunctionId,
+    type_args: Vec<ParsedStructType>,
+    args: Vec<FunctionArg>,
+    address_mapping: &impl Fn(&str) -> Option<AccountAddress>,
+) -> Result<FunctionCall> {
+    let function_id = function.into_function_id(address_mapping)?;
+    let args = args
+        .into_iter()
+        .map(|arg| arg.into_bytes(address_mapping))
+        .collect::<Result<Vec<_>>>()?;
+    let type_args = type_args
+        .into_iter()
+        .map(|tag| {
+            Ok(TypeTag::Struct(Box::new(
+                tag.into_struct_tag(address_mapping)?,
+            )))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(FunctionCall::new(function_id, type_args, args))
+}
@@ -0,0 +1,111 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::sign::{SignInput, SignOutput};
+use super::{FileOutput, FileOutputData};
+use crate::cli_types::{CommandAction, FileOrHexInput};
+use async_trait::async_trait;
+use rooch_types::error::RoochResult;
+
+/// Merge the partially signed transactions collected from different signers
+#[derive(Debug, clap::Parser)]
+pub struct MergeCommand {
+    /// The partially signed transaction hex or file paths to be merged
+    /// All the inputs should be partially signed transactions of the same transaction data
+    #[clap(required = true, num_args = 2..)]
+    inputs: Vec<FileOrHexInput>,
+
+    /// The output file path for the merged transaction
+    /// If not specified, the merged output will write to temp directory.
+    #[clap(long, short = 'o')]
+    output: Option<String>,
+
+    /// Return command outputs in json format
+    #[clap(long, default_value = "false")]
+    json: bool,
+}
+
+impl MergeCommand {
+    fn merge(inputs: Vec<FileOrHexInput>) -> anyhow::Result<SignOutput> {
+        let mut inputs = inputs
+            .into_iter()
+            .map(SignInput::try_from)
+            .collect::<anyhow::Result<Vec<_>>>()?
+            .into_iter();
+        let first = inputs.next().expect("at least two inputs");
+        let output = match first {
+            SignInput::PartiallySignedRoochTransaction(mut psrt) => {
+                for input in inputs {
+                    match input {
+                        SignInput::PartiallySignedRoochTransaction(other) => psrt.merge(other)?,
+                        _ => {
+                            return Err(anyhow::anyhow!(
+                                "All the inputs should be Bitcoin multisign psrt"
+                            ))
+                        }
+                    }
+                }
+                if psrt.is_fully_signed() {
+                    SignOutput::SignedRoochTransaction(psrt.try_into_rooch_transaction()?)
+                } else {
+                    SignOutput::PartiallySignedRoochTransaction(psrt)
+                }
+            }
+            SignInput::PartiallySignedWeightedMultisignTransaction(mut pswrt) => {
+                for input in inputs {
+                    match input {
+                        SignInput::PartiallySignedWeightedMultisignTransaction(other) => {
+                            pswrt.merge(other)?
+                        }
+                        _ => {
+                            return Err(anyhow::anyhow!(
+                                "All the inputs should be weighted multisign psrt"
+                            ))
+                        }
+                    }
+                }
+                if pswrt.is_fully_signed() {
+                    SignOutput::SignedRoochTransaction(pswrt.try_into_rooch_transaction()?)
+                } else {
+                    SignOutput::PartiallySignedWeightedMultisignTransaction(pswrt)
+                }
+            }
+            SignInput::RoochTransactionData(_) => {
+                return Err(anyhow::anyhow!(
+                    "Cannot merge transaction data, please sign it with `rooch tx sign` first"
+                ))
+            }
+        };
+        Ok(output)
+    }
+}
+
+#[async_trait]
+impl CommandAction<Option<FileOutput>> for MergeCommand {
+    async fn execute(self) -> RoochResult<Option<FileOutput>> {
+        let merge_output = Self::merge(self.inputs)?;
+        let is_finished = merge_output.is_finished();
+
+        let file_output_data: FileOutputData = merge_output.into();
+        let file_output = FileOutput::write_to_file(file_output_data, self.output)?;
+
+        if !self.json {
+            if is_finished {
+                println!("Signed transaction is written to {:?}", file_output.path);
+                println!(
+                    "You can submit the transaction with `rooch tx submit {}`",
+                    file_output.path
+                );
+            } else {
+                println!(
+                    "Merged partially signed transaction is written to {:?}",
+                    file_output.path
+                );
+                println!("You can send the partially signed transaction to other signers, and sign it later with `rooch tx sign {}`", file_output.path);
+            }
+            Ok(None)
+        } else {
+            Ok(Some(file_output))
+        }
+    }
+}
@@ -4,7 +4,8 @@
 use anyhow::Result;
 use moveos_types::h256::H256;
 use rooch_types::transaction::{
-    rooch::PartiallySignedRoochTransaction, RoochTransaction, RoochTransactionData,
+    rooch::{PartiallySignedRoochTransaction, PartiallySignedWeightedMultisignTransaction},
+    RoochTransaction, RoochTransactionData,
 };
 use serde::{Deserialize, Serialize};
 use std::{env, fs::File, io::Write, path::PathBuf};
@@ -12,6 +13,7 @@ use std::{env, fs::File, io::Write, path::PathBuf};
 pub mod build;
 pub mod get_transactions_by_hash;
 pub mod get_transactions_by_order;
+pub mod merge;
 pub mod query;
 pub mod sign;
 pub mod sign_order;
@@ -21,6 +23,7 @@ pub(crate) enum FileOutputData {
     RoochTransactionData(RoochTransactionData),
     SignedRoochTransaction(RoochTransaction),
     PartiallySignedRoochTransaction(PartiallySignedRoochTransaction),
+    PartiallySignedWeightedMultisignTransaction(PartiallySignedWeightedMultisignTransaction),
 }
 
 impl FileOutputData {
@@ -29,6 +32,9 @@ impl FileOutputData {
             FileOutputData::RoochTransactionData(data) => data.tx_hash(),
             FileOutputData::SignedRoochTransaction(data) => data.data.tx_hash(),
             FileOutputData::PartiallySignedRoochTransaction(data) => data.data.tx_hash(),
+            FileOutputData::PartiallySignedWeightedMultisignTransaction(data) => {
+                data.data.tx_hash()
+            }
         }
     }
 
@@ -37,6 +43,9 @@ impl FileOutputData {
             FileOutputData::RoochTransactionData(data) => data.sender.to_bech32(),
             FileOutputData::SignedRoochTransaction(data) => data.sender().to_bech32(),
             FileOutputData::PartiallySignedRoochTransaction(data) => data.signatories().to_string(),
+            FileOutputData::PartiallySignedWeightedMultisignTransaction(data) => {
+                data.signatories().to_string()
+            }
         }
     }
 
@@ -45,6 +54,7 @@ impl FileOutputData {
             FileOutputData::RoochTransactionData(_) => "rtd",
             FileOutputData::SignedRoochTransaction(_) => "srt",
             FileOutputData::PartiallySignedRoochTransaction(_) => "psrt",
+            FileOutputData::PartiallySignedWeightedMultisignTransaction(_) => "pswrt",
         }
     }
 
@@ -53,6 +63,7 @@ impl FileOutputData {
             FileOutputData::RoochTransactionData(data) => data.encode(),
             FileOutputData::SignedRoochTransaction(data) => data.encode(),
             FileOutputData::PartiallySignedRoochTransaction(data) => data.encode(),
+            FileOutputData::PartiallySignedWeightedMultisignTransaction(data) => data.encode(),
         }
     }
 
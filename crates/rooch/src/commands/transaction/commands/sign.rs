@@ -11,8 +11,10 @@ use rooch_types::{
     address::{ParsedAddress, RoochAddress},
     bitcoin::multisign_account::MultisignAccountModule,
     error::RoochResult,
+    framework::weighted_multisign_account::WeightedMultisignAccountModule,
     transaction::{
-        authenticator::BitcoinAuthenticator, rooch::PartiallySignedRoochTransaction,
+        authenticator::BitcoinAuthenticator,
+        rooch::{PartiallySignedRoochTransaction, PartiallySignedWeightedMultisignTransaction},
         RoochTransaction, RoochTransactionData,
     },
 };
@@ -21,25 +23,25 @@ use rooch_types::{
 pub enum SignInput {
     RoochTransactionData(RoochTransactionData),
     PartiallySignedRoochTransaction(PartiallySignedRoochTransaction),
+    PartiallySignedWeightedMultisignTransaction(PartiallySignedWeightedMultisignTransaction),
 }
 
 impl TryFrom<FileOrHexInput> for SignInput {
     type Error = anyhow::Error;
 
     fn try_from(value: FileOrHexInput) -> Result<Self, Self::Error> {
-        let input = match bcs::from_bytes::<RoochTransactionData>(&value.data) {
-            Ok(tx_data) => SignInput::RoochTransactionData(tx_data),
-            Err(_) => {
-                let psrt: PartiallySignedRoochTransaction = match bcs::from_bytes(&value.data) {
-                    Ok(psrt) => psrt,
-                    Err(_) => {
-                        return Err(anyhow::anyhow!("Invalid tx data or psrt data"));
-                    }
-                };
-                SignInput::PartiallySignedRoochTransaction(psrt)
-            }
-        };
-        Ok(input)
+        if let Ok(tx_data) = bcs::from_bytes::<RoochTransactionData>(&value.data) {
+            return Ok(SignInput::RoochTransactionData(tx_data));
+        }
+        if let Ok(psrt) = bcs::from_bytes::<PartiallySignedRoochTransaction>(&value.data) {
+            return Ok(SignInput::PartiallySignedRoochTransaction(psrt));
+        }
+        match bcs::from_bytes::<PartiallySignedWeightedMultisignTransaction>(&value.data) {
+            Ok(pswrt) => Ok(SignInput::PartiallySignedWeightedMultisignTransaction(
+                pswrt,
+            )),
+            Err(_) => Err(anyhow::anyhow!("Invalid tx data or psrt data")),
+        }
     }
 }
 
@@ -48,12 +50,14 @@ impl SignInput {
         match self {
             SignInput::RoochTransactionData(tx_data) => tx_data.sender,
             SignInput::PartiallySignedRoochTransaction(psrt) => psrt.sender(),
+            SignInput::PartiallySignedWeightedMultisignTransaction(pswrt) => pswrt.sender(),
         }
     }
 }
 pub enum SignOutput {
     SignedRoochTransaction(RoochTransaction),
     PartiallySignedRoochTransaction(PartiallySignedRoochTransaction),
+    PartiallySignedWeightedMultisignTransaction(PartiallySignedWeightedMultisignTransaction),
 }
 
 impl SignOutput {
@@ -69,6 +73,9 @@ impl From<SignOutput> for FileOutputData {
             SignOutput::PartiallySignedRoochTransaction(psrt) => {
                 FileOutputData::PartiallySignedRoochTransaction(psrt)
             }
+            SignOutput::PartiallySignedWeightedMultisignTransaction(pswrt) => {
+                FileOutputData::PartiallySignedWeightedMultisignTransaction(pswrt)
+            }
         }
     }
 }
@@ -108,9 +115,66 @@ impl SignCommand {
         let context = self.context.build_require_password()?;
         let client = context.get_client().await?;
         let multisign_account_module = client.as_module_binding::<MultisignAccountModule>();
+        let weighted_multisign_account_module =
+            client.as_module_binding::<WeightedMultisignAccountModule>();
         let sign_input = SignInput::try_from(self.input)?;
         let sender = sign_input.sender();
-        let output = if multisign_account_module.is_multisign_account(sender.into())? {
+        let output = if weighted_multisign_account_module.is_multisign_account(sender.into())? {
+            let mut pswrt = match sign_input {
+                SignInput::RoochTransactionData(tx_data) => {
+                    let threshold = weighted_multisign_account_module.threshold(sender.into())?;
+                    let participants =
+                        weighted_multisign_account_module.participants(sender.into())?;
+                    PartiallySignedWeightedMultisignTransaction::new(
+                        tx_data,
+                        threshold,
+                        participants,
+                    )
+                }
+                SignInput::PartiallySignedWeightedMultisignTransaction(pswrt) => pswrt,
+                SignInput::PartiallySignedRoochTransaction(_) => {
+                    return Err(anyhow::anyhow!(
+                        "The sender is a weighted multisign account, but the input is a Bitcoin multisign psrt"
+                    ))
+                }
+            };
+            match self.signer {
+                Some(signer) => {
+                    let signer = context.resolve_address(signer)?;
+                    let kp = context.get_key_pair(&signer.into())?;
+                    if pswrt.contains_signature(kp.public().as_ref()) {
+                        return Err(anyhow::anyhow!(
+                            "The signer has already signed the transaction"
+                        ));
+                    }
+                    pswrt.sign(&kp)?;
+                }
+                None => {
+                    let accounts = context.keystore.get_accounts(context.get_password())?;
+                    let mut has_participant = false;
+                    for account in accounts.iter() {
+                        if pswrt.participant(account.public_key.as_ref()).is_none() {
+                            continue;
+                        }
+                        has_participant = true;
+                        if pswrt.contains_signature(account.public_key.as_ref()) {
+                            continue;
+                        }
+                        let kp = context.get_key_pair(&account.address)?;
+                        pswrt.sign(&kp)?;
+                    }
+                    if !has_participant {
+                        return Err(anyhow::anyhow!("No participant found in the weighted multisign account from the keystore, participants: {:?}", pswrt.participants));
+                    }
+                }
+            }
+
+            if pswrt.is_fully_signed() {
+                SignOutput::SignedRoochTransaction(pswrt.try_into_rooch_transaction()?)
+            } else {
+                SignOutput::PartiallySignedWeightedMultisignTransaction(pswrt)
+            }
+        } else if multisign_account_module.is_multisign_account(sender.into())? {
             let threshold = multisign_account_module.threshold(sender.into())?;
 
             let mut psrt = match sign_input {
@@ -118,6 +182,11 @@ impl SignCommand {
                     PartiallySignedRoochTransaction::new(tx_data, threshold)
                 }
                 SignInput::PartiallySignedRoochTransaction(psrt) => psrt,
+                SignInput::PartiallySignedWeightedMultisignTransaction(_) => {
+                    return Err(anyhow::anyhow!(
+                        "The sender is a Bitcoin multisign account, but the input is a weighted multisign psrt"
+                    ))
+                }
             };
             match self.signer {
                 Some(signer) => {
@@ -143,7 +212,7 @@ impl SignCommand {
                     for participant in participants.iter() {
                         if context
                             .keystore
-                            .contains_address(&participant.participant_address.into())
+                            .contains_address(&participant.participant_address.into())?
                         {
                             has_participant = true;
                             let kp =
@@ -169,7 +238,8 @@ impl SignCommand {
         } else {
             let tx_data = match sign_input {
                 SignInput::RoochTransactionData(tx_data) => tx_data,
-                SignInput::PartiallySignedRoochTransaction(_) => {
+                SignInput::PartiallySignedRoochTransaction(_)
+                | SignInput::PartiallySignedWeightedMultisignTransaction(_) => {
                     return Err(anyhow::anyhow!(
                         "Cannot sign a partially signed transaction with a single signer"
                     ))
@@ -208,6 +278,17 @@ impl SignCommand {
                     pstx.threshold
                 );
             }
+            SignInput::PartiallySignedWeightedMultisignTransaction(pswrt) => {
+                println!(
+                    "Partially signed weighted multisign transaction data:\n{}",
+                    tx_data(&pswrt.data)
+                );
+                println!(
+                    " Collected signature weight: {}/{}",
+                    pswrt.signed_weight(),
+                    pswrt.threshold
+                );
+            }
         }
     }
 }
@@ -5,8 +5,8 @@ use crate::cli_types::CommandAction;
 use crate::commands::transaction::commands::sign_order::SignOrderCommand;
 use crate::commands::transaction::commands::{
     build::BuildCommand, get_transactions_by_hash::GetTransactionsByHashCommand,
-    get_transactions_by_order::GetTransactionsByOrderCommand, query::QueryCommand,
-    sign::SignCommand, submit::SubmitCommand,
+    get_transactions_by_order::GetTransactionsByOrderCommand, merge::MergeCommand,
+    query::QueryCommand, sign::SignCommand, submit::SubmitCommand,
 };
 use async_trait::async_trait;
 use clap::{Parser, Subcommand};
@@ -30,6 +30,7 @@ impl CommandAction<String> for Transaction {
             TransactionCommand::Query(cmd) => cmd.execute_serialized().await,
             TransactionCommand::Build(cmd) => cmd.execute_serialized().await,
             TransactionCommand::Sign(cmd) => cmd.execute_serialized().await,
+            TransactionCommand::Merge(cmd) => cmd.execute_serialized().await,
             TransactionCommand::Submit(cmd) => cmd.execute_serialized().await,
             TransactionCommand::SignOrder(cmd) => cmd.execute(),
         }
@@ -43,6 +44,7 @@ pub enum TransactionCommand {
     GetTransactionsByHash(GetTransactionsByHashCommand),
     Query(QueryCommand),
     Sign(SignCommand),
+    Merge(MergeCommand),
     Submit(SubmitCommand),
     SignOrder(SignOrderCommand),
 }
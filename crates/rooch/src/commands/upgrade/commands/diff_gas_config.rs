@@ -0,0 +1,206 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use moveos_types::access_path::AccessPath;
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::moveos_std::gas_schedule::{
+    GasSchedule, GasScheduleConfig, GasScheduleModule, PendingGasSchedule,
+};
+use moveos_types::state::ObjectState;
+use rooch_genesis::{FrameworksGasParameters, LATEST_GAS_SCHEDULE_VERSION};
+use rooch_rpc_client::Client;
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::rooch_network::BuiltinChainID;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ModifiedGasEntry {
+    pub onchain_value: u64,
+    pub local_value: u64,
+}
+
+/// The difference between the onchain gas schedule and the local compiled gas schedule
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GasScheduleDiff {
+    pub onchain_schedule_version: u64,
+    pub local_schedule_version: u64,
+    pub onchain_max_gas_amount: u64,
+    pub local_max_gas_amount: u64,
+    /// The gas entries only in the local gas schedule
+    pub added: BTreeMap<String, u64>,
+    /// The gas entries with different values
+    pub modified: BTreeMap<String, ModifiedGasEntry>,
+    /// The gas entries only in the onchain gas schedule
+    pub removed: BTreeMap<String, u64>,
+    /// The activation time in milliseconds of the pending gas schedule onchain
+    pub pending_activation_time: Option<u64>,
+}
+
+impl GasScheduleDiff {
+    pub fn new(onchain: &GasSchedule, local: &GasScheduleConfig) -> Self {
+        let onchain_entries = onchain
+            .entries
+            .iter()
+            .map(|entry| (entry.key.to_string(), entry.val))
+            .collect::<BTreeMap<_, _>>();
+        let local_entries = local
+            .entries
+            .iter()
+            .map(|entry| (entry.key.to_string(), entry.val))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut added = BTreeMap::new();
+        let mut modified = BTreeMap::new();
+        for (key, local_value) in local_entries.iter() {
+            match onchain_entries.get(key) {
+                None => {
+                    added.insert(key.clone(), *local_value);
+                }
+                Some(onchain_value) if onchain_value != local_value => {
+                    modified.insert(
+                        key.clone(),
+                        ModifiedGasEntry {
+                            onchain_value: *onchain_value,
+                            local_value: *local_value,
+                        },
+                    );
+                }
+                Some(_) => {}
+            }
+        }
+        let removed = onchain_entries
+            .into_iter()
+            .filter(|(key, _)| !local_entries.contains_key(key))
+            .collect();
+
+        Self {
+            onchain_schedule_version: onchain.schedule_version,
+            local_schedule_version: LATEST_GAS_SCHEDULE_VERSION,
+            onchain_max_gas_amount: onchain.max_gas_amount,
+            local_max_gas_amount: local.max_gas_amount,
+            added,
+            modified,
+            removed,
+            pending_activation_time: None,
+        }
+    }
+
+    /// Whether the local gas entries need to be upgraded to the chain
+    pub fn has_upgrade(&self) -> bool {
+        !self.added.is_empty() || !self.modified.is_empty()
+    }
+
+    pub fn print(&self) {
+        println!(
+            "Onchain gas schedule version: {}, local gas schedule version: {}",
+            self.onchain_schedule_version, self.local_schedule_version
+        );
+        if self.onchain_max_gas_amount != self.local_max_gas_amount {
+            println!(
+                "max gas amount, onchain value: {}, local value: {}",
+                self.onchain_max_gas_amount, self.local_max_gas_amount
+            );
+        }
+        if !self.added.is_empty() {
+            println!(
+                "Found {:} new gas entries that need to be upgraded:",
+                self.added.len()
+            );
+            for (gas_key, gas_value) in self.added.iter() {
+                println!("new gas: {:}, value: {:}", gas_key, gas_value);
+            }
+        }
+        if !self.modified.is_empty() {
+            println!(
+                "Found {:} modified gas entries that need to be upgraded:",
+                self.modified.len()
+            );
+            for (gas_key, entry) in self.modified.iter() {
+                println!(
+                    "modified gas: {:}, old value: {}, new value: {:}",
+                    gas_key, entry.onchain_value, entry.local_value
+                );
+            }
+        }
+        if !self.removed.is_empty() {
+            println!(
+                "Found {:} gas entries onchain, but not in local:",
+                self.removed.len()
+            );
+            for (gas_key, gas_value) in self.removed.iter() {
+                println!("removed gas: {:}, value: {:}", gas_key, gas_value);
+            }
+        }
+        if let Some(activation_time) = self.pending_activation_time {
+            println!(
+                "A pending gas schedule takes effect at {} milliseconds.",
+                activation_time
+            );
+        }
+        if !self.has_upgrade() {
+            println!("No local gas entries to be upgraded.");
+        }
+    }
+}
+
+/// The local gas schedule config compiled from the gas parameters
+pub fn local_gas_schedule_config() -> GasScheduleConfig {
+    FrameworksGasParameters::latest().to_gas_schedule_config(BuiltinChainID::Test.chain_id())
+}
+
+pub fn get_onchain_gas_schedule(client: &Client) -> RoochResult<GasSchedule> {
+    let gas_schedule_module = client.as_module_binding::<GasScheduleModule>();
+    gas_schedule_module
+        .gas_schedule()
+        .map_err(|_| RoochError::OnchainGasScheduleIsEmpty)
+}
+
+pub async fn get_pending_gas_schedule(client: &Client) -> RoochResult<Option<PendingGasSchedule>> {
+    let access_path = AccessPath::object(PendingGasSchedule::pending_gas_schedule_object_id());
+    let pending_gas_schedule = client
+        .rooch
+        .get_states(access_path, None)
+        .await?
+        .pop()
+        .flatten()
+        .map(|state_view| ObjectState::from(state_view).into_object::<PendingGasSchedule>())
+        .transpose()?
+        .map(|obj| obj.value);
+    Ok(pending_gas_schedule)
+}
+
+/// Diff the onchain gas config with the local compiled gas config
+#[derive(Debug, clap::Parser)]
+pub struct DiffGasConfigCommand {
+    #[clap(flatten)]
+    pub(crate) context_options: WalletContextOptions,
+
+    /// Diff the pending gas schedule with the local gas config instead of the current onchain gas schedule
+    #[clap(long)]
+    pending: bool,
+}
+
+#[async_trait]
+impl CommandAction<GasScheduleDiff> for DiffGasConfigCommand {
+    async fn execute(self) -> RoochResult<GasScheduleDiff> {
+        let context = self.context_options.build()?;
+        let client = context.get_client().await?;
+
+        let mut onchain_gas_schedule = get_onchain_gas_schedule(&client)?;
+        let pending_gas_schedule = get_pending_gas_schedule(&client).await?;
+        if self.pending {
+            let pending = pending_gas_schedule.as_ref().ok_or_else(|| {
+                RoochError::CommandArgumentError("No pending gas schedule onchain".to_owned())
+            })?;
+            onchain_gas_schedule.max_gas_amount = pending.gas_schedule_config.max_gas_amount;
+            onchain_gas_schedule.entries = pending.gas_schedule_config.entries.clone();
+        }
+
+        let mut diff = GasScheduleDiff::new(&onchain_gas_schedule, &local_gas_schedule_config());
+        diff.pending_activation_time = pending_gas_schedule.map(|pending| pending.activation_time);
+        Ok(diff)
+    }
+}
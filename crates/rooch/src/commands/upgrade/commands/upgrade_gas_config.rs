@@ -3,19 +3,18 @@
 
 use crate::cli_types::{CommandAction, TransactionOptions, WalletContextOptions};
 use crate::commands::transaction::commands::{FileOutput, FileOutputData};
+use crate::commands::upgrade::commands::diff_gas_config::{
+    get_onchain_gas_schedule, local_gas_schedule_config, GasScheduleDiff,
+};
 use async_trait::async_trait;
 use framework_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::ModuleId;
-use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::move_types::FunctionId;
 use moveos_types::state::MoveState;
 use moveos_types::transaction::MoveAction;
-use rooch_framework::natives::gas_parameter::gas_member::ToOnChainGasSchedule;
-use rooch_genesis::{FrameworksGasParameters, LATEST_GAS_SCHEDULE_VERSION};
+use rooch_genesis::LATEST_GAS_SCHEDULE_VERSION;
 use rooch_types::error::{RoochError, RoochResult};
-use rooch_types::rooch_network::BuiltinChainID;
-use std::collections::BTreeMap;
 use std::io;
 use std::io::Write;
 
@@ -36,6 +35,11 @@ pub struct UpgradeGasConfigCommand {
     /// Return command outputs in json format
     #[clap(long, default_value = "false")]
     json: bool,
+
+    /// The onchain time in milliseconds when the gas config takes effect
+    /// If not specified, the gas config takes effect immediately after the transaction is executed
+    #[clap(long)]
+    activation_time: Option<u64>,
 }
 
 #[async_trait]
@@ -44,135 +48,58 @@ impl CommandAction<Option<FileOutput>> for UpgradeGasConfigCommand {
         let context = self.context_options.build()?;
 
         let client = context.get_client().await?;
-        let gas_schedule_module =
-            client.as_module_binding::<moveos_types::moveos_std::gas_schedule::GasScheduleModule>();
-        let gas_schedule_opt = gas_schedule_module.gas_schedule();
-
-        let onchain_gas_schedule = match gas_schedule_opt {
-            Ok(gas_schedule) => {
-                let mut entries_map = BTreeMap::new();
-                let _: Vec<_> = gas_schedule
-                    .entries
-                    .iter()
-                    .map(|gas_entry| entries_map.insert(gas_entry.key.to_string(), gas_entry.val))
-                    .collect();
-                Some((gas_schedule.schedule_version, entries_map))
-            }
-            _ => None,
-        };
-
-        let local_latest_gas_parameters = FrameworksGasParameters::latest();
+        let onchain_gas_schedule = get_onchain_gas_schedule(&client)?;
+        if LATEST_GAS_SCHEDULE_VERSION < onchain_gas_schedule.schedule_version {
+            return Err(RoochError::InvalidLocalGasVersion(
+                LATEST_GAS_SCHEDULE_VERSION,
+                onchain_gas_schedule.schedule_version,
+            ));
+        }
 
-        match onchain_gas_schedule {
-            None => {
-                return Err(RoochError::OnchainGasScheduleIsEmpty);
+        let latest_gas_schedule = local_gas_schedule_config();
+        let diff = GasScheduleDiff::new(&onchain_gas_schedule, &latest_gas_schedule);
+        if !diff.removed.is_empty() {
+            for gas_key in diff.removed.keys() {
+                println!("gas entry {:?} is onchain, but not in local.", gas_key);
             }
-            Some((onchain_gas_schedule_version, onchain_gas_schedule_map)) => {
-                let mut local_gas_entries = local_latest_gas_parameters
-                    .vm_gas_params
-                    .to_on_chain_gas_schedule();
-                local_gas_entries.extend(
-                    local_latest_gas_parameters
-                        .rooch_framework_gas_params
-                        .to_on_chain_gas_schedule(),
+            if latest_gas_schedule.entries.len() < onchain_gas_schedule.entries.len() {
+                println!(
+                    "local gas entries {:?} != onchain gas entries {:?}",
+                    latest_gas_schedule.entries.len(),
+                    onchain_gas_schedule.entries.len()
                 );
-                local_gas_entries.extend(
-                    local_latest_gas_parameters
-                        .bitcoin_move_gas_params
-                        .to_on_chain_gas_schedule(),
-                );
-
-                if LATEST_GAS_SCHEDULE_VERSION < onchain_gas_schedule_version {
-                    return Err(RoochError::InvalidLocalGasVersion(
-                        LATEST_GAS_SCHEDULE_VERSION,
-                        onchain_gas_schedule_version,
-                    ));
-                }
-
-                let local_gas_schedule_map: BTreeMap<String, u64> =
-                    local_gas_entries.into_iter().collect();
-
-                if local_gas_schedule_map.len() < onchain_gas_schedule_map.len() {
-                    println!(
-                        "local gas entries {:?} != onchain gas entries {:?}",
-                        local_gas_schedule_map.len(),
-                        onchain_gas_schedule_map.len()
-                    );
-
-                    for (gas_key, _) in onchain_gas_schedule_map.iter() {
-                        if !local_gas_schedule_map.contains_key(gas_key) {
-                            println!("gas entry {:?} is onchain, but not in local.", gas_key);
-                        }
-                    }
-
-                    return Err(RoochError::LessLocalGasScheduleLength);
-                }
-
-                for (gas_key, _) in onchain_gas_schedule_map.iter() {
-                    if !local_gas_schedule_map.contains_key(gas_key) {
-                        return Err(RoochError::LocalIncorrectGasSchedule);
-                    }
-                }
-
-                let mut modified_gas_entries = Vec::new();
-                let mut added_gas_entries = Vec::new();
-
-                for (gas_key, gas_value) in local_gas_schedule_map.iter() {
-                    match onchain_gas_schedule_map.get(gas_key) {
-                        None => added_gas_entries.push((gas_key.clone(), *gas_value)),
-                        Some(onchain_gas_value) => {
-                            if *onchain_gas_value != *gas_value {
-                                modified_gas_entries.push((gas_key.clone(), *gas_value))
-                            }
-                        }
-                    }
-                }
-
-                if !added_gas_entries.is_empty() {
-                    println!(
-                        "Found {:} new gas entries that need to be upgraded:",
-                        added_gas_entries.len()
-                    );
-                    for (gas_key, gas_value) in added_gas_entries.iter() {
-                        println!("new gas: {:}, value: {:}", gas_key, gas_value);
-                    }
-                }
-
-                if !modified_gas_entries.is_empty() {
-                    println!(
-                        "Found {:} modified gas entries that need to be upgraded:",
-                        modified_gas_entries.len()
-                    );
-                    for (gas_key, gas_value) in modified_gas_entries.iter() {
-                        let old_value = onchain_gas_schedule_map.get(gas_key).unwrap();
-                        println!(
-                            "modified gas: {:}, old value: {}, new value: {:}",
-                            gas_key, old_value, gas_value
-                        );
-                    }
-                }
-
-                if modified_gas_entries.is_empty() && added_gas_entries.is_empty() {
-                    println!("No local gas entries to be upgraded.");
-                    std::process::exit(1);
-                }
-
-                if !get_confirmation() {
-                    std::process::exit(1);
-                }
-
-                (onchain_gas_schedule_version, onchain_gas_schedule_map)
+                return Err(RoochError::LessLocalGasScheduleLength);
             }
-        };
+            return Err(RoochError::LocalIncorrectGasSchedule);
+        }
+
+        diff.print();
+        if !diff.has_upgrade() {
+            std::process::exit(1);
+        }
+        if let Some(activation_time) = self.activation_time {
+            println!(
+                "The gas schedule takes effect at {} milliseconds.",
+                activation_time
+            );
+        }
+        if !get_confirmation() {
+            std::process::exit(1);
+        }
 
-        let latest_gas_schedule =
-            local_latest_gas_parameters.to_gas_schedule_config(BuiltinChainID::Test.chain_id());
         let gas_schedule_bytes = latest_gas_schedule
             .to_move_value()
             .simple_serialize()
             .unwrap();
 
-        let args = vec![bcs::to_bytes(&gas_schedule_bytes).unwrap()];
+        let mut args = vec![bcs::to_bytes(&gas_schedule_bytes).unwrap()];
+        let function_name = match self.activation_time {
+            Some(activation_time) => {
+                args.push(bcs::to_bytes(&activation_time).unwrap());
+                "schedule_gas_schedule_upgrade"
+            }
+            None => "upgrade_gas_schedule",
+        };
 
         let action = MoveAction::new_function_call(
             FunctionId::new(
@@ -180,7 +107,7 @@ impl CommandAction<Option<FileOutput>> for UpgradeGasConfigCommand {
                     ROOCH_FRAMEWORK_ADDRESS,
                     Identifier::new("upgrade".to_owned()).unwrap(),
                 ),
-                Identifier::new("upgrade_gas_schedule".to_owned()).unwrap(),
+                Identifier::new(function_name.to_owned()).unwrap(),
             ),
             vec![],
             args,
@@ -19,7 +19,6 @@ use moveos_gas_profiling::profiler::{new_gas_profiler, ProfileGasMeter};
 use moveos_object_runtime::runtime::ObjectRuntime;
 use moveos_types::h256::H256;
 use moveos_types::move_std::option::MoveOption;
-use moveos_types::moveos_std::gas_schedule::GasScheduleConfig;
 use moveos_types::moveos_std::object::ObjectMeta;
 use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::moveos_std::tx_meta::TxMeta;
@@ -52,11 +51,7 @@ pub fn execute_tx_locally(
     let (move_mv, object_runtime, client_resolver, action, cost_table) =
         prepare_execute_env(root_object_meta, &client_resolver, tx.clone());
 
-    let mut gas_meter = MoveOSGasMeter::new(
-        cost_table,
-        GasScheduleConfig::CLI_DEFAULT_MAX_GAS_AMOUNT,
-        true,
-    );
+    let mut gas_meter = MoveOSGasMeter::new(cost_table, tx.max_gas_amount, true);
 
     // The dry run supports unsigned transactions, but when calculating the transaction size,
     // the length of the signature part needs to be included.
@@ -122,11 +117,7 @@ pub fn execute_tx_locally_with_gas_profile(
     let (move_mv, object_runtime, client_resolver, action, cost_table) =
         prepare_execute_env(root_object_meta, &client_resolver, tx.clone());
 
-    let mut gas_meter = MoveOSGasMeter::new(
-        cost_table,
-        GasScheduleConfig::CLI_DEFAULT_MAX_GAS_AMOUNT,
-        true,
-    );
+    let mut gas_meter = MoveOSGasMeter::new(cost_table, tx.max_gas_amount, true);
     gas_meter.charge_io_write(tx.tx_size()).unwrap();
 
     let mut gas_profiler = new_gas_profiler(tx.clone().action, gas_meter);
@@ -271,6 +262,7 @@ fn convert_to_verified_tx(
             module_bundle,
             init_function_modules: vec![],
         },
+        MoveAction::Batch(calls) => VerifiedMoveAction::Batch { calls },
     };
 
     Ok(VerifiedMoveOSTransaction::new(
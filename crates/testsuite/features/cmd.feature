@@ -327,6 +327,10 @@ Feature: Rooch CLI integration tests
       Then cmd: "move run --function default::counter::increase --json"
       Then cmd: "move view --function default::counter::value"
       Then assert: "{{$.move[-1].return_values[0].decoded_value}} == 1"
+
+      # Query the counter at the historical state after the package was published
+      Then cmd: "move view --function default::counter::value --at-tx-order {{$.move[-4].sequence_info.tx_order}}"
+      Then assert: "{{$.move[-1].return_values[0].decoded_value}} == 0"
       Then cmd: "resource --address default --resource default::counter::Counter"
       Then assert: "{{$.resource[-1].decoded_value.value.value.value.value}} == 1"
 
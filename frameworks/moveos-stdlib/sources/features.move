@@ -144,6 +144,17 @@ module moveos_std::features {
     public fun ensure_value_size_gas_enabled() {
         assert!(is_enabled(VALUE_SIZE_GAS), EAPI_DISABLED);
     }
+
+    /// Whether to relay the Ethereum blocks with the receipts of their transactions.
+    /// The blocks relayed before it is enabled only carry the block header, they are replayed without receipts.
+    const ETHEREUM_RECEIPTS: u64 = 8;
+    public fun get_ethereum_receipts_feature(): u64 { ETHEREUM_RECEIPTS }
+    public fun ethereum_receipts_enabled(): bool {
+        is_enabled(ETHEREUM_RECEIPTS)
+    }
+    public fun ensure_ethereum_receipts_enabled() {
+        assert!(is_enabled(ETHEREUM_RECEIPTS), EAPI_DISABLED);
+    }
     
     /// Helper for getting all features. 
     /// Update this once new feature added.
@@ -156,6 +167,7 @@ module moveos_std::features {
             MODULE_PUBLISHING_ALLOWLIST,
             WASM,
             VALUE_SIZE_GAS,
+            ETHEREUM_RECEIPTS,
         ]
     }
     // --------------------------------------------------------------------------------------------
@@ -5,10 +5,19 @@ module moveos_std::gas_schedule {
     use moveos_std::tx_context;
     use moveos_std::object;
     use moveos_std::core_addresses;
+    use moveos_std::event;
+    use moveos_std::timestamp;
 
     friend moveos_std::genesis;
     
     const ErrorInvalidGasScheduleEntries: u64 = 1;
+    /// The activation time of the pending gas schedule should be in the future
+    const ErrorInvalidActivationTime: u64 = 2;
+    const ErrorPendingGasScheduleNotFound: u64 = 3;
+    /// The on-chain time does not reach the activation time of the pending gas schedule
+    const ErrorPendingGasScheduleNotActivated: u64 = 4;
+    /// The pending gas schedule already takes effect, it can not be cancelled
+    const ErrorPendingGasScheduleActivated: u64 = 5;
 
     /// The initial max gas amount from genesis.
     const InitialMaxGasAmount: u64 = 1_000_000_000u64;
@@ -45,6 +54,30 @@ module moveos_std::gas_schedule {
         entries: vector<GasEntry>,
     }
 
+    /// The gas schedule which takes effect when the on-chain time reaches the `activation_time` in milliseconds.
+    /// The MoveOS VM applies it to the `GasSchedule` before the first transaction after the activation time.
+    struct PendingGasSchedule has key {
+        activation_time: u64,
+        gas_schedule_config: GasScheduleConfig,
+    }
+
+    /// Event emitted when the gas schedule is updated
+    struct GasScheduleUpdatedEvent has copy, drop {
+        schedule_version: u64,
+        max_gas_amount: u64,
+    }
+
+    /// Event emitted when a gas schedule update is scheduled
+    struct GasScheduleUpdateScheduledEvent has copy, drop {
+        activation_time: u64,
+        gas_schedule_config: GasScheduleConfig,
+    }
+
+    /// Event emitted when the pending gas schedule is cancelled
+    struct GasScheduleUpdateCancelledEvent has copy, drop {
+        activation_time: u64,
+    }
+
     public(friend) fun genesis_init(gas_schedule_config: GasScheduleConfig){
 
         let gas_schedule = GasSchedule {
@@ -83,10 +116,85 @@ module moveos_std::gas_schedule {
         gas_schedule.max_gas_amount = gas_schedule_config.max_gas_amount;
         gas_schedule.entries = gas_schedule_config.entries;
 
+        event::emit(GasScheduleUpdatedEvent {
+            schedule_version: gas_schedule.schedule_version,
+            max_gas_amount: gas_schedule.max_gas_amount,
+        });
+        mark_gas_schedule_updated();
+    }
+
+    /// Notify the VM to reload the gas schedule after the transaction
+    fun mark_gas_schedule_updated() {
         let system = moveos_std::signer::module_signer<GasScheduleUpdated>();
         tx_context::add_attribute_via_system(&system, GasScheduleUpdated {last_updated: 1});
     }
 
+    /// Schedule a gas schedule update, it takes effect when the on-chain time reaches the `activation_time` in milliseconds.
+    /// The previous pending gas schedule is replaced, or applied first if it already takes effect.
+    public fun schedule_gas_schedule_update(account: &signer, gas_schedule_config: vector<u8>, activation_time: u64) {
+        core_addresses::assert_system_reserved(account);
+        assert!(vector::length(&gas_schedule_config) > 0, ErrorInvalidGasScheduleEntries);
+        assert!(activation_time > timestamp::now_milliseconds(), ErrorInvalidActivationTime);
+
+        let gas_schedule_config = bcs::from_bytes<GasScheduleConfig>(gas_schedule_config);
+        if (exists_pending_gas_schedule()) {
+            let PendingGasSchedule { activation_time: pending_activation_time, gas_schedule_config: pending_config } = take_pending_gas_schedule();
+            if (timestamp::now_milliseconds() >= pending_activation_time) {
+                update_gas_schedule_interanl(pending_config);
+            };
+        };
+
+        let obj = object::new_named_object(PendingGasSchedule { activation_time, gas_schedule_config });
+        object::transfer_extend(obj, @moveos_std);
+        event::emit(GasScheduleUpdateScheduledEvent { activation_time, gas_schedule_config });
+        // Reload the gas schedule, so the VM knows the activation time of the pending gas schedule
+        mark_gas_schedule_updated();
+    }
+
+    /// Cancel the pending gas schedule before it takes effect
+    public fun cancel_pending_gas_schedule(account: &signer) {
+        core_addresses::assert_system_reserved(account);
+        assert!(exists_pending_gas_schedule(), ErrorPendingGasScheduleNotFound);
+        let PendingGasSchedule { activation_time, gas_schedule_config: _ } = take_pending_gas_schedule();
+        assert!(timestamp::now_milliseconds() < activation_time, ErrorPendingGasScheduleActivated);
+
+        event::emit(GasScheduleUpdateCancelledEvent { activation_time });
+        mark_gas_schedule_updated();
+    }
+
+    /// Apply the pending gas schedule to the `GasSchedule` after the activation time,
+    /// automatically called by the MoveOS VM before the first transaction after the activation time.
+    fun activate_pending_gas_schedule() {
+        assert!(exists_pending_gas_schedule(), ErrorPendingGasScheduleNotFound);
+        let PendingGasSchedule { activation_time, gas_schedule_config } = take_pending_gas_schedule();
+        assert!(timestamp::now_milliseconds() >= activation_time, ErrorPendingGasScheduleNotActivated);
+        update_gas_schedule_interanl(gas_schedule_config);
+    }
+
+    fun take_pending_gas_schedule(): PendingGasSchedule {
+        let object_id = object::named_object_id<PendingGasSchedule>();
+        let obj = object::take_object_extend<PendingGasSchedule>(object_id);
+        object::remove(obj)
+    }
+
+    public fun exists_pending_gas_schedule(): bool {
+        object::exists_object_with_type<PendingGasSchedule>(object::named_object_id<PendingGasSchedule>())
+    }
+
+    public fun pending_gas_schedule(): &PendingGasSchedule {
+        let object_id = object::named_object_id<PendingGasSchedule>();
+        let obj = object::borrow_object<PendingGasSchedule>(object_id);
+        object::borrow(obj)
+    }
+
+    public fun pending_gas_schedule_activation_time(pending: &PendingGasSchedule): u64 {
+        pending.activation_time
+    }
+
+    public fun pending_gas_schedule_config(pending: &PendingGasSchedule): &GasScheduleConfig {
+        &pending.gas_schedule_config
+    }
+
     public fun gas_schedule(): &GasSchedule {
         let object_id = object::named_object_id<GasSchedule>();
         let obj = object::borrow_object<GasSchedule>(object_id);
@@ -125,4 +233,31 @@ module moveos_std::gas_schedule {
         let entries2 = gas_schedule_entries(gas_schedule2);
         assert!(vector::length(entries2) == 1, 1002);
     }
+
+    #[test(account = @moveos_std)]
+    fun test_schedule_gas_schedule_update(account: &signer) {
+        genesis_init(new_gas_schedule_config(initial_max_gas_amount(), vector::empty()));
+        let entries = vector::empty();
+        vector::push_back(&mut entries, new_gas_entry(std::string::utf8(b"test1"), 1));
+        let config = new_gas_schedule_config(initial_max_gas_amount(), entries);
+        schedule_gas_schedule_update(account, bcs::to_bytes(&config), 1000);
+        assert!(exists_pending_gas_schedule(), 1000);
+        assert!(pending_gas_schedule_activation_time(pending_gas_schedule()) == 1000, 1001);
+        assert!(vector::length(gas_schedule_entries(gas_schedule())) == 0, 1002);
+
+        timestamp::update_global_time_for_test(1000);
+        activate_pending_gas_schedule();
+        assert!(!exists_pending_gas_schedule(), 1003);
+        assert!(gas_schedule_version(gas_schedule()) == 1, 1004);
+        assert!(vector::length(gas_schedule_entries(gas_schedule())) == 1, 1005);
+    }
+
+    #[test(account = @moveos_std)]
+    #[expected_failure(abort_code = ErrorPendingGasScheduleNotActivated, location = Self)]
+    fun test_activate_pending_gas_schedule_too_early(account: &signer) {
+        genesis_init(new_gas_schedule_config(initial_max_gas_amount(), vector::empty()));
+        let config = new_gas_schedule_config(initial_max_gas_amount(), vector::empty());
+        schedule_gas_schedule_update(account, bcs::to_bytes(&config), 1000);
+        activate_pending_gas_schedule();
+    }
 }
\ No newline at end of file
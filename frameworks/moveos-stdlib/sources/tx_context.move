@@ -52,6 +52,13 @@ module moveos_std::tx_context {
         is_upgrade: bool,
     }
 
+    /// The account which pays the gas of the current transaction.
+    /// It is written by the VM before the transaction execution, if it is absent, the sender pays the gas.
+    struct GasPaymentAccount has copy, drop, store {
+        account: address,
+        pay_gas_by_module_account: bool,
+    }
+
     /// Return the address of the user that signed the current transaction
     public fun sender(): address {
         borrow().sender
@@ -147,11 +154,16 @@ module moveos_std::tx_context {
     }
 
     /// Get the gas payment account of the transaction
-    /// Currently, the gas payment account is the sender of the transaction.
-    /// In the future, the gas payment account may be different from the sender.
+    /// The gas payment account is the sender of the transaction,
+    /// unless the transaction is sponsored by a gas payer.
     public fun tx_gas_payment_account(): address {
         let ctx = borrow();
-        ctx.sender
+        let gas_payment_account = get<GasPaymentAccount>(ctx);
+        if (option::is_some(&gas_payment_account)) {
+            option::destroy_some(gas_payment_account).account
+        } else {
+            ctx.sender
+        }
     }
 
     /// The result is only available in the `post_execute` function.
@@ -230,6 +242,13 @@ module moveos_std::tx_context {
         ctx.tx_hash = tx_hash;
     }
 
+    #[test_only]
+    /// set the gas payment account of the TxContext for unit test
+    public fun set_gas_payment_account_for_testing(account: address){
+        let ctx = borrow_mut();
+        add(ctx, GasPaymentAccount{account, pay_gas_by_module_account: false});
+    }
+
     #[test_only]
     public fun fresh_address_for_testing(): address {
         fresh_address()
@@ -250,6 +269,14 @@ module moveos_std::tx_context {
         assert!(value == option::extract(&mut value2), 1000);
     }
 
+    #[test(sender=@0x42)]
+    fun test_tx_gas_payment_account() {
+        assert!(tx_gas_payment_account() == @0x42, 1000);
+        set_gas_payment_account_for_testing(@0x43);
+        assert!(tx_gas_payment_account() == @0x43, 1001);
+        assert!(sender() == @0x42, 1002);
+    }
+
     #[test(sender=@0x42)]
     fun test_fresh_address() {
         let addr1 = fresh_address();
@@ -11,6 +11,8 @@ module moveos_std::tx_meta {
     public fun move_action_function_type(): u8 { MoveActionFunctionType }
     const MoveActionModuleBundleType: u8 = 2;
     public fun move_action_module_bundle_type(): u8 { MoveActionModuleBundleType }
+    const MoveActionBatchType: u8 = 3;
+    public fun move_action_batch_type(): u8 { MoveActionBatchType }
 
     /// The transaction Meta data
     /// We can not define MoveAction in Move, so we define a simple meta data struct to represent it
@@ -57,6 +59,10 @@ module moveos_std::tx_meta {
         self.action_type == MoveActionModuleBundleType
     }
 
+    public fun is_batch_call(self: &TxMeta): bool {
+        self.action_type == MoveActionBatchType
+    }
+
     public fun function_meta(self: &TxMeta): Option<FunctionCallMeta> {
         *&self.function_meta
     }
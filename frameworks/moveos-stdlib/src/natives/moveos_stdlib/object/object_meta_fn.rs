@@ -73,6 +73,14 @@ pub(crate) fn native_object_size(
     let object_meta_gas_params = gas_parameters.native_object_meta.clone();
 
     let obj_id = pop_object_id(&mut args)?;
+    // The size is read from the object metadata, notify the resolver so it can track the read.
+    context
+        .extensions()
+        .get::<ObjectRuntimeContext>()
+        .object_runtime()
+        .read()
+        .resolver()
+        .record_size_read(&obj_id);
     object_meta_fn_dispatch(
         &common_gas_params,
         object_meta_gas_params.base,
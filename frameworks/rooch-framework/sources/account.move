@@ -10,6 +10,7 @@ module rooch_framework::account {
    friend rooch_framework::genesis;
    friend rooch_framework::transfer;
    friend rooch_framework::transaction_validator;
+   friend rooch_framework::weighted_multisign_account;
 
    /// Just using to get Account module signer
    struct AccountPlaceholder {}
@@ -7,16 +7,38 @@ module rooch_framework::account_authentication {
    
    use std::signer;
    use std::vector;
+   use std::option::{Self, Option};
    use moveos_std::account;
+   use moveos_std::bcs;
    use moveos_std::features;
+   use moveos_std::tx_context;
+   use moveos_std::simple_map::{Self, SimpleMap};
+   use rooch_framework::ecdsa_k1;
    use rooch_framework::auth_validator_registry;
    use rooch_framework::auth_validator;
+   use rooch_framework::chain_id;
+   use rooch_framework::session_key;
 
    friend rooch_framework::account;
 
 
    /// The authentication validator is already installed
    const ErrorAuthValidatorAlreadyInstalled: u64 = 1;
+   /// The auth validator does not support the auth key rotation
+   const ErrorAuthKeyRotationNotSupported: u64 = 2;
+   /// The new public key is not a compressed secp256k1 public key
+   const ErrorInvalidPublicKey: u64 = 3;
+   /// The rotation message is not signed by the new key
+   const ErrorInvalidRotationSignature: u64 = 4;
+   /// The rotation transaction must be validated by the auth validator whose key is rotated
+   const ErrorRotateAuthKeyPermissionDenied: u64 = 5;
+
+   /// The builtin auth validators which support the auth key rotation
+   const BITCOIN_VALIDATOR_ID: u64 = 1;
+   const ETHEREUM_VALIDATOR_ID: u64 = 3;
+
+   /// The prefix of the message which the new key signs to accept the rotation, it tags the message with the rotation domain
+   const ROTATION_MESSAGE_PREFIX: vector<u8> = b"Rooch Auth Key Rotation:\n";
 
 
    /// A resource that holds the auth validator ids for this account has installed.
@@ -25,6 +47,13 @@ module rooch_framework::account_authentication {
    }
 
 
+   /// The rotated authentication keys of the account.
+   /// The key of the map is the auth validator id, the value is the public key which controls the account after the rotation.
+   /// The account address is still derived from the original key, so the address does not change.
+   struct RotatedAuthKeys has key {
+      keys: SimpleMap<u64, vector<u8>>,
+   }
+
    /// Return if the authentication validator is installed for the account at `account_addr`.
    public fun is_auth_validator_installed(account_addr: address, auth_validator_id: u64): bool {
       if(account::exists_resource<InstalledAuthValidator>(account_addr)){
@@ -61,5 +90,63 @@ module rooch_framework::account_authentication {
       install_auth_validator<ValidatorType>(account_signer);
    }
 
+   /// Return the rotated auth key of the account for the auth validator, return none if the key is not rotated.
+   public fun rotated_auth_key(account_addr: address, auth_validator_id: u64): Option<vector<u8>> {
+      if(account::exists_resource<RotatedAuthKeys>(account_addr)){
+         let rotated_auth_keys = account::borrow_resource<RotatedAuthKeys>(account_addr);
+         if(simple_map::contains_key(&rotated_auth_keys.keys, &auth_validator_id)){
+            return option::some(*simple_map::borrow(&rotated_auth_keys.keys, &auth_validator_id))
+         }
+      };
+      option::none()
+   }
+
+   public fun is_auth_key_rotated(account_addr: address, auth_validator_id: u64): bool {
+      option::is_some(&rotated_auth_key(account_addr, auth_validator_id))
+   }
+
+   /// The message which the new key signs to accept the rotation.
+   /// The chain id and the sequence number of the rotation transaction are included to avoid replay on other chains or transactions.
+   public fun rotation_message(chain_id: u64, account_addr: address, auth_validator_id: u64, sequence_number: u64): vector<u8> {
+      let message = ROTATION_MESSAGE_PREFIX;
+      vector::append(&mut message, bcs::to_bytes(&chain_id));
+      vector::append(&mut message, bcs::to_bytes(&account_addr));
+      vector::append(&mut message, bcs::to_bytes(&auth_validator_id));
+      vector::append(&mut message, bcs::to_bytes(&sequence_number));
+      message
+   }
+
+   /// Rotate the auth key of the account for the Bitcoin or Ethereum auth validator.
+   /// The transaction is signed by the current key, and the `signature` is the new key's signature of the `rotation_message`.
+   /// All the session keys of the account are removed.
+   public fun rotate_auth_key(account_signer: &signer, auth_validator_id: u64, new_public_key: vector<u8>, signature: vector<u8>) {
+      assert!(
+         auth_validator_id == BITCOIN_VALIDATOR_ID || auth_validator_id == ETHEREUM_VALIDATOR_ID,
+         ErrorAuthKeyRotationNotSupported);
+      // The session key or other auth validators can not rotate the key
+      assert!(auth_validator::get_validator_id_from_ctx() == auth_validator_id, ErrorRotateAuthKeyPermissionDenied);
+      assert!(vector::length(&new_public_key) == ecdsa_k1::public_key_length(), ErrorInvalidPublicKey);
+
+      let account_addr = signer::address_of(account_signer);
+      let message = rotation_message(chain_id::chain_id(), account_addr, auth_validator_id, tx_context::sequence_number());
+      assert!(
+         ecdsa_k1::verify(&signature, &new_public_key, &message, ecdsa_k1::sha256()),
+         ErrorInvalidRotationSignature);
+
+      if(!account::exists_resource<RotatedAuthKeys>(account_addr)){
+         account::move_resource_to<RotatedAuthKeys>(account_signer, RotatedAuthKeys {
+            keys: simple_map::new(),
+         });
+      };
+      let rotated_auth_keys = account::borrow_mut_resource<RotatedAuthKeys>(account_addr);
+      simple_map::upsert(&mut rotated_auth_keys.keys, auth_validator_id, new_public_key);
+      // The session keys are authorized by the old key, they are removed with the rotation
+      session_key::remove_all_session_keys(account_addr);
+   }
+
+   public entry fun rotate_auth_key_entry(account_signer: &signer, auth_validator_id: u64, new_public_key: vector<u8>, signature: vector<u8>) {
+      rotate_auth_key(account_signer, auth_validator_id, new_public_key, signature);
+   }
+
 
 }
@@ -12,6 +12,7 @@ module rooch_framework::account_coin_store {
 
     use rooch_framework::coin::{Coin};
     use rooch_framework::coin_store::{Self, CoinStore};
+    use rooch_framework::session_key;
 
     friend rooch_framework::genesis;
     friend rooch_framework::account;
@@ -103,6 +104,7 @@ module rooch_framework::account_coin_store {
         amount: u256,
     ): Coin<CoinType> {
         let addr = signer::address_of(account);
+        session_key::spend_coin_quota<CoinType>(addr, amount);
         withdraw_internal<CoinType>(addr, amount)
     }
 
@@ -122,6 +124,7 @@ module rooch_framework::account_coin_store {
         amount: u256,
     ) {
         let from_addr = signer::address_of(from);
+        session_key::spend_coin_quota<CoinType>(from_addr, amount);
         transfer_internal<CoinType>(from_addr, to, amount);
     }
 
@@ -13,6 +13,8 @@ module rooch_framework::auth_validator {
     friend rooch_framework::auth_validator_registry;
     friend rooch_framework::transaction_validator;
     friend rooch_framework::session_key;
+    friend rooch_framework::weighted_multisign_account;
+    friend rooch_framework::account_authentication;
 
     /// The function must be executed after the transaction is validated
     const ErrorMustExecuteAfterValidate: u64 = 1;
@@ -38,6 +40,8 @@ module rooch_framework::auth_validator {
     const ErrorValidateSessionIsExpired: u64 = 1012;
     /// The function call is beyond the session's scope
     const ErrorValidateFunctionCallBeyondSessionScope: u64 = 1013;
+    /// The transaction exceeds the session key's quota
+    const ErrorValidateSessionKeyQuotaExceeded: u64 = 1014;
 
     public fun error_validate_sequence_number_too_old(): u64 {
         ErrorValidateSequenceNuberTooOld
@@ -91,6 +95,10 @@ module rooch_framework::auth_validator {
         ErrorValidateFunctionCallBeyondSessionScope
     }
 
+    public fun error_validate_session_key_quota_exceeded(): u64 {
+        ErrorValidateSessionKeyQuotaExceeded
+    }
+
     /// The Authentication Validator
     struct AuthValidator has store, copy, drop {
         id: u64,
@@ -187,4 +195,12 @@ module rooch_framework::auth_validator {
         let validate_result = get_validate_result_from_ctx();
         validate_result.bitcoin_address
     }
+
+    #[test_only]
+    /// Set the TxValidateResult of the TxContext for unit test, eg. the tx is validated via the session key
+    public fun set_tx_validate_result_for_testing(auth_validator_id: u64, session_key: Option<vector<u8>>) {
+        let system = moveos_std::account::create_signer_for_testing(@rooch_framework);
+        let validate_result = new_tx_validate_result(auth_validator_id, option::none(), session_key, rooch_framework::bitcoin_address::empty());
+        tx_context::add_attribute_via_system(&system, validate_result);
+    }
 }
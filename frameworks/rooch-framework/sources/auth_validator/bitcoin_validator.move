@@ -4,15 +4,19 @@
 /// This module implements Bitcoin validator with the ECDSA recoverable signature over Secp256k1.
 module rooch_framework::bitcoin_validator {
 
+    use std::option;
     use moveos_std::tx_context;
     use moveos_std::hash;
     use rooch_framework::ecdsa_k1;
+    use rooch_framework::address_mapping;
+    use rooch_framework::account_authentication;
     use rooch_framework::auth_payload;
     use rooch_framework::auth_validator;
     use rooch_framework::auth_payload::AuthPayload;
     use rooch_framework::bitcoin_address::{Self, BitcoinAddress};
 
     friend rooch_framework::transaction_validator;
+    friend rooch_framework::sponsor_validator;
 
     /// there defines auth validator id for each auth validator
     const BITCOIN_AUTH_VALIDATOR_ID: u64 = 1;
@@ -42,8 +46,13 @@ module rooch_framework::bitcoin_validator {
     }
 
     public(friend) fun validate(authenticator_payload: vector<u8>) :BitcoinAddress{
-
         let sender = tx_context::sender();
+        validate_for_address(sender, authenticator_payload)
+    }
+
+    /// Validate the authenticator payload is signed by the given account, eg. the gas payer of a sponsored transaction.
+    public(friend) fun validate_for_address(addr: address, authenticator_payload: vector<u8>) :BitcoinAddress{
+
         let tx_hash = tx_context::tx_hash();
         let payload = auth_payload::from_bytes(authenticator_payload);
 
@@ -58,11 +67,26 @@ module rooch_framework::bitcoin_validator {
             auth_validator::error_validate_invalid_authenticator()
         );
 
+        let rotated_auth_key = account_authentication::rotated_auth_key(addr, BITCOIN_AUTH_VALIDATOR_ID);
+        if (option::is_some(&rotated_auth_key)) {
+            // The auth key of the account has been rotated, only the rotated key can sign the transaction
+            assert!(
+                option::destroy_some(rotated_auth_key) == auth_payload::public_key(&payload),
+                auth_validator::error_validate_invalid_authenticator()
+            );
+            // The account keeps its original Bitcoin address after the rotation
+            let original_bitcoin_addr = address_mapping::resolve_bitcoin(addr);
+            if (option::is_some(&original_bitcoin_addr)) {
+                return option::destroy_some(original_bitcoin_addr)
+            };
+            return bitcoin_addr
+        };
+
         let rooch_addr = bitcoin_address::to_rooch_address(&bitcoin_addr);
 
-        // Check if the sender is related to the Rooch address
+        // Check if the account is related to the Rooch address
         assert!(
-            sender == rooch_addr,
+            addr == rooch_addr,
             auth_validator::error_validate_invalid_authenticator()
         );
         bitcoin_addr
@@ -15,6 +15,13 @@ module rooch_framework::builtin_validators{
     const BITCOIN_VALIDATOR_ID: u64 = 1;
     /// Bitcoin multisign validator is defined in bitcoin_move framework.
     const BITCOIN_MULTISIGN_VALIDATOR_ID: u64 = 2;
+    /// WebAuthn validator is dispatched by the transaction validator directly, it does not need to be registered.
+    /// The id 3 is reserved for the Ethereum validator.
+    const WEBAUTHN_VALIDATOR_ID: u64 = 4;
+    /// Sponsor validator is dispatched by the transaction validator directly, it wraps the sender's authenticator.
+    const SPONSOR_VALIDATOR_ID: u64 = 5;
+    /// Weighted multisign validator is dispatched by the transaction validator directly.
+    const WEIGHTED_MULTISIGN_VALIDATOR_ID: u64 = 6;
 
     public(friend) fun genesis_init(_genesis_account: &signer) {
         // NATIVE_AUTH_VALIDATOR_ID: u64 = 0;
@@ -29,6 +36,9 @@ module rooch_framework::builtin_validators{
     public fun is_builtin_auth_validator(auth_validator_id: u64): bool {
         auth_validator_id == SESSION_VALIDATOR_ID || 
         auth_validator_id == BITCOIN_VALIDATOR_ID || 
-        auth_validator_id == BITCOIN_MULTISIGN_VALIDATOR_ID
+        auth_validator_id == BITCOIN_MULTISIGN_VALIDATOR_ID ||
+        auth_validator_id == WEBAUTHN_VALIDATOR_ID ||
+        auth_validator_id == SPONSOR_VALIDATOR_ID ||
+        auth_validator_id == WEIGHTED_MULTISIGN_VALIDATOR_ID
     }
 }
@@ -88,6 +88,8 @@ module rooch_framework::session_validator {
         assert!(!session_key::is_expired(&session_key), auth_validator::error_validate_session_is_expired());
         
         assert!(session_key::in_session_scope(&session_key), auth_validator::error_validate_function_call_beyond_session_scope());
+
+        assert!(session_key::in_session_quota(sender_addr, auth_key), auth_validator::error_validate_session_key_quota_exceeded());
         auth_key
     }
 }
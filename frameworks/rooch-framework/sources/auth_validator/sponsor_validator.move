@@ -0,0 +1,107 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module implements the sponsor auth validator, the gas of a sponsored transaction is paid by the gas payer instead of the sender.
+/// The payload carries the sender's authenticator and the gas payer's authenticator of the same transaction.
+/// The gas payer must be a Bitcoin account or a weighted multisign account.
+module rooch_framework::sponsor_validator {
+
+    use moveos_std::bcs;
+    use moveos_std::account;
+    use rooch_framework::auth_validator;
+    use rooch_framework::bitcoin_validator;
+    use rooch_framework::weighted_multisign_validator;
+
+    friend rooch_framework::transaction_validator;
+
+    /// there defines auth validator id for each auth validator
+    const SPONSOR_AUTH_VALIDATOR_ID: u64 = 5;
+
+    /// The auth validator of the gas payer is not supported,
+    /// the session key and the passkey are scoped to the sender's apps and can not pay the gas for others.
+    const ErrorGasPayerAuthValidatorNotSupported: u64 = 1;
+
+    struct SponsorValidator has store, drop {}
+
+    #[data_struct]
+    struct SponsorAuthPayload has copy, store, drop {
+        // The auth validator id of the sender's authenticator
+        sender_auth_validator_id: u64,
+        // The payload of the sender's authenticator
+        sender_auth_payload: vector<u8>,
+        // The account which pays the gas
+        gas_payer: address,
+        // The auth validator id of the gas payer's authenticator
+        gas_payer_auth_validator_id: u64,
+        // The payload of the gas payer's authenticator
+        gas_payer_auth_payload: vector<u8>,
+    }
+
+    public fun auth_validator_id(): u64 {
+        SPONSOR_AUTH_VALIDATOR_ID
+    }
+
+    public fun payload_from_bytes(bytes: vector<u8>): SponsorAuthPayload {
+        bcs::from_bytes<SponsorAuthPayload>(bytes)
+    }
+
+    public fun gas_payer(payload: &SponsorAuthPayload): address {
+        payload.gas_payer
+    }
+
+    /// Validate the gas payer's authenticator,
+    /// return the sender's auth validator id and authenticator payload, and the gas payer.
+    public(friend) fun validate(authenticator_payload: vector<u8>): (u64, vector<u8>, address) {
+        let SponsorAuthPayload {
+            sender_auth_validator_id,
+            sender_auth_payload,
+            gas_payer,
+            gas_payer_auth_validator_id,
+            gas_payer_auth_payload,
+        } = payload_from_bytes(authenticator_payload);
+
+        // The sponsored transaction can not be sponsored again
+        assert!(
+            sender_auth_validator_id != SPONSOR_AUTH_VALIDATOR_ID,
+            auth_validator::error_validate_invalid_authenticator()
+        );
+        assert!(account::exists_at(gas_payer), auth_validator::error_validate_account_does_not_exist());
+
+        if (gas_payer_auth_validator_id == bitcoin_validator::auth_validator_id()) {
+            bitcoin_validator::validate_for_address(gas_payer, gas_payer_auth_payload);
+        } else if (gas_payer_auth_validator_id == weighted_multisign_validator::auth_validator_id()) {
+            weighted_multisign_validator::validate_for_address(gas_payer, gas_payer_auth_payload);
+        } else {
+            abort ErrorGasPayerAuthValidatorNotSupported
+        };
+        (sender_auth_validator_id, sender_auth_payload, gas_payer)
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = 1010)]
+    fun test_validate_nested_sponsor() {
+        let payload = SponsorAuthPayload {
+            sender_auth_validator_id: SPONSOR_AUTH_VALIDATOR_ID,
+            sender_auth_payload: vector[],
+            gas_payer: @0x42,
+            gas_payer_auth_validator_id: bitcoin_validator::auth_validator_id(),
+            gas_payer_auth_payload: vector[],
+        };
+        validate(bcs::to_bytes(&payload));
+    }
+
+    #[test(gas_payer = @0x42)]
+    #[expected_failure(location=Self, abort_code = ErrorGasPayerAuthValidatorNotSupported)]
+    fun test_validate_session_key_gas_payer(gas_payer: &signer) {
+        rooch_framework::genesis::init_for_test();
+        account::create_account_for_testing(std::signer::address_of(gas_payer));
+        let payload = SponsorAuthPayload {
+            sender_auth_validator_id: bitcoin_validator::auth_validator_id(),
+            sender_auth_payload: vector[],
+            gas_payer: std::signer::address_of(gas_payer),
+            gas_payer_auth_validator_id: rooch_framework::session_validator::auth_validator_id(),
+            gas_payer_auth_payload: vector[],
+        };
+        validate(bcs::to_bytes(&payload));
+    }
+}
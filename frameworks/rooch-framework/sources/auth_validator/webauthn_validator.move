@@ -0,0 +1,197 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module implements the WebAuthn (passkey) auth validator with the ECDSA signature over Secp256r1.
+/// The passkey is registered as a session key of the account, the authentication key is derived from the passkey public key.
+module rooch_framework::webauthn_validator {
+
+    use std::vector;
+    use std::option;
+    use std::string;
+    use moveos_std::tx_context;
+    use moveos_std::hash;
+    use moveos_std::bcs;
+    use moveos_std::base64;
+    use moveos_std::json;
+    use moveos_std::simple_map;
+    use rooch_framework::ecdsa_r1;
+    use rooch_framework::auth_validator;
+    use rooch_framework::session_key;
+
+    friend rooch_framework::transaction_validator;
+
+    /// there defines auth validator id for each auth validator
+    const WEBAUTHN_AUTH_VALIDATOR_ID: u64 = 4;
+
+    const SIGNATURE_SCHEME_ECDSAR1: u8 = 2;
+
+    /// The authenticator data is `rpIdHash(32) || flags(1) || signCount(4) || extensions`
+    const AUTHENTICATOR_DATA_MIN_LENGTH: u64 = 37;
+    const AUTHENTICATOR_DATA_FLAGS_INDEX: u64 = 32;
+    /// User Present (UP) flag
+    const FLAG_USER_PRESENT: u8 = 1;
+
+    const CLIENT_DATA_TYPE_GET: vector<u8> = b"webauthn.get";
+
+    struct WebauthnValidator has store, drop {}
+
+    #[data_struct]
+    struct WebauthnAuthPayload has copy, store, drop {
+        // The signature scheme of the passkey, only secp256r1 is supported
+        scheme: u8,
+        // The 64 bytes (r, s) signature with the normalized low s
+        signature: vector<u8>,
+        // The 33 bytes compressed public key of the passkey
+        public_key: vector<u8>,
+        // The authenticator data returned by the authenticator
+        authenticator_data: vector<u8>,
+        // The raw clientDataJSON collected by the client
+        client_data_json: vector<u8>,
+    }
+
+    public fun auth_validator_id(): u64 {
+        WEBAUTHN_AUTH_VALIDATOR_ID
+    }
+
+    public fun payload_from_bytes(bytes: vector<u8>): WebauthnAuthPayload {
+        bcs::from_bytes<WebauthnAuthPayload>(bytes)
+    }
+
+    /// Get the authentication key of the given public key.
+    fun public_key_to_authentication_key(signature_scheme: u8, public_key: vector<u8>): vector<u8> {
+        let bytes = vector::singleton(signature_scheme);
+        vector::append(&mut bytes, public_key);
+        hash::blake2b256(&bytes)
+    }
+
+    /// Encode the bytes with the url safe base64 alphabet and without padding, as the WebAuthn challenge.
+    fun base64url_encode(input: &vector<u8>): vector<u8> {
+        let encoded = base64::encode(input);
+        let result = vector::empty<u8>();
+        let i = 0;
+        let len = vector::length(&encoded);
+        while (i < len) {
+            let c = *vector::borrow(&encoded, i);
+            // '+' => '-', '/' => '_', and skip the padding '='
+            if (c == 43) {
+                vector::push_back(&mut result, 45);
+            } else if (c == 47) {
+                vector::push_back(&mut result, 95);
+            } else if (c != 61) {
+                vector::push_back(&mut result, c);
+            };
+            i = i + 1;
+        };
+        result
+    }
+
+    /// Check the client data is collected for an assertion and the challenge is the tx hash.
+    fun validate_client_data(client_data_json: &vector<u8>, tx_hash: &vector<u8>) {
+        let client_data = json::to_map(*client_data_json);
+
+        let type_key = string::utf8(b"type");
+        assert!(simple_map::contains_key(&client_data, &type_key), auth_validator::error_validate_invalid_authenticator());
+        assert!(
+            *string::bytes(simple_map::borrow(&client_data, &type_key)) == CLIENT_DATA_TYPE_GET,
+            auth_validator::error_validate_invalid_authenticator()
+        );
+
+        let challenge_key = string::utf8(b"challenge");
+        assert!(simple_map::contains_key(&client_data, &challenge_key), auth_validator::error_validate_invalid_authenticator());
+        assert!(
+            *string::bytes(simple_map::borrow(&client_data, &challenge_key)) == base64url_encode(tx_hash),
+            auth_validator::error_validate_invalid_authenticator()
+        );
+    }
+
+    /// Validate the signature of the authenticator payload and return the auth key
+    fun validate_signature(payload: &WebauthnAuthPayload, tx_hash: &vector<u8>): vector<u8> {
+        assert!(payload.scheme == SIGNATURE_SCHEME_ECDSAR1, auth_validator::error_validate_invalid_authenticator());
+        assert!(
+            vector::length(&payload.public_key) == ecdsa_r1::public_key_length(),
+            auth_validator::error_validate_invalid_authenticator()
+        );
+
+        let authenticator_data = &payload.authenticator_data;
+        assert!(
+            vector::length(authenticator_data) >= AUTHENTICATOR_DATA_MIN_LENGTH,
+            auth_validator::error_validate_invalid_authenticator()
+        );
+        let flags = *vector::borrow(authenticator_data, AUTHENTICATOR_DATA_FLAGS_INDEX);
+        assert!((flags & FLAG_USER_PRESENT) == FLAG_USER_PRESENT, auth_validator::error_validate_invalid_authenticator());
+
+        validate_client_data(&payload.client_data_json, tx_hash);
+
+        // The passkey signs `authenticatorData || sha256(clientDataJSON)`, the `ecdsa_r1::verify` function does sha2_256 once
+        let message = *authenticator_data;
+        vector::append(&mut message, hash::sha2_256(payload.client_data_json));
+        assert!(
+            ecdsa_r1::verify(
+                &payload.signature,
+                &payload.public_key,
+                &message
+            ),
+            auth_validator::error_validate_invalid_authenticator()
+        );
+        public_key_to_authentication_key(SIGNATURE_SCHEME_ECDSAR1, payload.public_key)
+    }
+
+    public(friend) fun validate(authenticator_payload: vector<u8>): vector<u8> {
+
+        let sender_addr = tx_context::sender();
+        assert!(session_key::has_session_key(sender_addr), auth_validator::error_validate_invalid_account_auth_key());
+
+        let tx_hash = tx_context::tx_hash();
+        let payload = payload_from_bytes(authenticator_payload);
+        let auth_key = validate_signature(&payload, &tx_hash);
+
+        let session_key_option = session_key::get_session_key(sender_addr, auth_key);
+        assert!(option::is_some(&session_key_option), auth_validator::error_validate_invalid_account_auth_key());
+
+        let session_key = option::extract(&mut session_key_option);
+        assert!(!session_key::is_expired(&session_key), auth_validator::error_validate_session_is_expired());
+
+        assert!(session_key::in_session_scope(&session_key), auth_validator::error_validate_function_call_beyond_session_scope());
+        assert!(session_key::in_session_quota(sender_addr, auth_key), auth_validator::error_validate_session_key_quota_exceeded());
+        auth_key
+    }
+
+    #[test]
+    fun test_base64url_encode() {
+        assert!(base64url_encode(&x"fbff") == b"-_8", 1000);
+        assert!(base64url_encode(&b"rooch") == b"cm9vY2g", 1001);
+    }
+
+    #[test]
+    fun test_validate_signature_success() {
+        let tx_hash = x"5415b18de0b880bb2af5dfe1ee27fd19ae8a0c99b5328e8b4b44f4c86cc7176a";
+        let payload_bytes = x"02407c3a975586878b8f6b162abe52573d6a7f7cef790f6888ecdcfd116ae6e0d98c0a8e11df0ed00b52492a655c697bc11bd43fe799ec9496ad8d08b8494a87549c2102471c3e758c4904285bba7e53118ed0f524adeb0757d25bd2f8e7b0d76dfa714c25701e5ca13e155a366732dbcbbbc694edc8c7fc06b03cfebc4c7361057a4f148d050000000086017b2274797065223a22776562617574686e2e676574222c226368616c6c656e6765223a22564257786a654334674c737139645f68376966394761364b444a6d314d6f364c5330543079477a4846326f222c226f726967696e223a2268747470733a2f2f726f6f63682e6e6574776f726b222c2263726f73734f726967696e223a66616c73657d";
+        let payload = payload_from_bytes(payload_bytes);
+
+        let auth_key = validate_signature(&payload, &tx_hash);
+        assert!(auth_key == x"c86cda8f25c774f1ef66a7d02fb816ab0f4790bb1655e14bd6e70106b85fe761", 1000);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = 1010)]
+    fun test_validate_signature_fail_with_other_tx_hash() {
+        let tx_hash = x"5515b18de0b880bb2af5dfe1ee27fd19ae8a0c99b5328e8b4b44f4c86cc7176a";
+        let payload_bytes = x"02407c3a975586878b8f6b162abe52573d6a7f7cef790f6888ecdcfd116ae6e0d98c0a8e11df0ed00b52492a655c697bc11bd43fe799ec9496ad8d08b8494a87549c2102471c3e758c4904285bba7e53118ed0f524adeb0757d25bd2f8e7b0d76dfa714c25701e5ca13e155a366732dbcbbbc694edc8c7fc06b03cfebc4c7361057a4f148d050000000086017b2274797065223a22776562617574686e2e676574222c226368616c6c656e6765223a22564257786a654334674c737139645f68376966394761364b444a6d314d6f364c5330543079477a4846326f222c226f726967696e223a2268747470733a2f2f726f6f63682e6e6574776f726b222c2263726f73734f726967696e223a66616c73657d";
+        let payload = payload_from_bytes(payload_bytes);
+
+        validate_signature(&payload, &tx_hash);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = 1010)]
+    fun test_validate_signature_fail_with_tampered_authenticator_data() {
+        let tx_hash = x"5415b18de0b880bb2af5dfe1ee27fd19ae8a0c99b5328e8b4b44f4c86cc7176a";
+        let payload_bytes = x"02407c3a975586878b8f6b162abe52573d6a7f7cef790f6888ecdcfd116ae6e0d98c0a8e11df0ed00b52492a655c697bc11bd43fe799ec9496ad8d08b8494a87549c2102471c3e758c4904285bba7e53118ed0f524adeb0757d25bd2f8e7b0d76dfa714c25701e5ca13e155a366732dbcbbbc694edc8c7fc06b03cfebc4c7361057a4f148d050000000086017b2274797065223a22776562617574686e2e676574222c226368616c6c656e6765223a22564257786a654334674c737139645f68376966394761364b444a6d314d6f364c5330543079477a4846326f222c226f726967696e223a2268747470733a2f2f726f6f63682e6e6574776f726b222c2263726f73734f726967696e223a66616c73657d";
+        let payload = payload_from_bytes(payload_bytes);
+        // bump the sign count
+        let sign_count = vector::borrow_mut(&mut payload.authenticator_data, 36);
+        *sign_count = 1;
+
+        validate_signature(&payload, &tx_hash);
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module implements the weighted multisign auth validator for the Rooch native multisign account.
+/// Each signature is verified with the participant's signature scheme, and the total weight of the signers must reach the threshold.
+module rooch_framework::weighted_multisign_validator {
+
+    use std::vector;
+    use std::option;
+    use moveos_std::bcs;
+    use moveos_std::tx_context;
+    use rooch_framework::ed25519;
+    use rooch_framework::ecdsa_k1;
+    use rooch_framework::auth_validator;
+    use rooch_framework::weighted_multisign_account::{Self, Participant};
+
+    friend rooch_framework::transaction_validator;
+    friend rooch_framework::sponsor_validator;
+
+    /// there defines auth validator id for each auth validator
+    const WEIGHTED_MULTISIGN_VALIDATOR_ID: u64 = 6;
+
+    const ECDSA_K1_SIG_LEN: u64 = 64;
+
+    /// The prefix of the Ethereum personal message, the tx hash is always 32 bytes
+    const ETHEREUM_MESSAGE_PREFIX: vector<u8> = b"\x19Ethereum Signed Message:\n32";
+
+    struct WeightedMultisignValidator has store, drop {}
+
+    #[data_struct]
+    struct WeightedMultisignAuthPayload has copy, store, drop {
+        /// The public keys of the signers, each public key must be a participant of the multisign account
+        public_keys: vector<vector<u8>>,
+        /// The signatures of the tx hash, in the same order as the public keys
+        signatures: vector<vector<u8>>,
+    }
+
+    public fun auth_validator_id(): u64 {
+        WEIGHTED_MULTISIGN_VALIDATOR_ID
+    }
+
+    public fun payload_from_bytes(bytes: vector<u8>): WeightedMultisignAuthPayload {
+        bcs::from_bytes<WeightedMultisignAuthPayload>(bytes)
+    }
+
+    /// Verify the signature of the tx hash with the participant's signature scheme
+    public fun verify_participant_signature(participant: &Participant, signature: &vector<u8>, tx_hash: &vector<u8>): bool {
+        let scheme = weighted_multisign_account::participant_scheme(participant);
+        let public_key = weighted_multisign_account::participant_public_key(participant);
+        if (scheme == weighted_multisign_account::signature_scheme_ed25519()) {
+            ed25519::verify(signature, public_key, tx_hash)
+        } else if (scheme == weighted_multisign_account::signature_scheme_secp256k1()) {
+            ecdsa_k1::verify(signature, public_key, tx_hash, ecdsa_k1::sha256())
+        } else if (scheme == weighted_multisign_account::signature_scheme_ethereum()) {
+            // The Ethereum wallet returns a 65 bytes signature with the recovery id, the recovery id is not needed
+            let sig_len = vector::length(signature);
+            if (sig_len < ECDSA_K1_SIG_LEN) {
+                return false
+            };
+            let sig = vector::slice(signature, 0, ECDSA_K1_SIG_LEN);
+            ecdsa_k1::verify(&sig, public_key, &ethereum_message(tx_hash), ecdsa_k1::keccak256())
+        } else {
+            false
+        }
+    }
+
+    fun ethereum_message(tx_hash: &vector<u8>): vector<u8> {
+        let message = ETHEREUM_MESSAGE_PREFIX;
+        vector::append(&mut message, *tx_hash);
+        message
+    }
+
+    public(friend) fun validate(authenticator_payload: vector<u8>) {
+        let sender = tx_context::sender();
+        validate_for_address(sender, authenticator_payload)
+    }
+
+    /// Validate the authenticator payload is signed by the participants of the given multisign account,
+    /// eg. the gas payer of a sponsored transaction.
+    public(friend) fun validate_for_address(multisign_address: address, authenticator_payload: vector<u8>) {
+        let tx_hash = tx_context::tx_hash();
+        assert!(
+            weighted_multisign_account::is_multisign_account(multisign_address),
+            auth_validator::error_validate_invalid_account_auth_key()
+        );
+        let WeightedMultisignAuthPayload { public_keys, signatures } = payload_from_bytes(authenticator_payload);
+        let len = vector::length(&public_keys);
+        assert!(len == vector::length(&signatures), auth_validator::error_validate_invalid_authenticator());
+
+        // Sum in u128, so the weights can not overflow
+        let total_weight = 0u128;
+        let idx = 0;
+        while (idx < len) {
+            let public_key = vector::borrow(&public_keys, idx);
+            // Each participant can only sign once
+            let other = idx + 1;
+            while (other < len) {
+                assert!(vector::borrow(&public_keys, other) != public_key, auth_validator::error_validate_invalid_authenticator());
+                other = other + 1;
+            };
+            let participant = weighted_multisign_account::participant_by_public_key(multisign_address, public_key);
+            assert!(option::is_some(&participant), auth_validator::error_validate_invalid_authenticator());
+            let participant = option::destroy_some(participant);
+            assert!(
+                verify_participant_signature(&participant, vector::borrow(&signatures, idx), &tx_hash),
+                auth_validator::error_validate_invalid_authenticator()
+            );
+            total_weight = total_weight + (weighted_multisign_account::participant_weight(&participant) as u128);
+            idx = idx + 1;
+        };
+        assert!(
+            total_weight >= (weighted_multisign_account::threshold(multisign_address) as u128),
+            auth_validator::error_validate_invalid_authenticator()
+        );
+    }
+
+    #[test]
+    fun test_ethereum_message() {
+        let tx_hash = x"0000000000000000000000000000000000000000000000000000000000000001";
+        let message = ethereum_message(&tx_hash);
+        assert!(vector::length(&message) == 28 + 32, 1000);
+        assert!(*vector::borrow(&message, 0) == 0x19, 1001);
+        assert!(vector::slice(&message, 28, 60) == tx_hash, 1002);
+    }
+}
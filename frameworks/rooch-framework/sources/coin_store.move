@@ -10,6 +10,7 @@ module rooch_framework::coin_store {
     use moveos_std::type_info;
     use moveos_std::event;
     use rooch_framework::coin::{Self, Coin};
+    use rooch_framework::session_key;
 
     friend rooch_framework::account_coin_store;
 
@@ -106,12 +107,14 @@ module rooch_framework::coin_store {
     /// Remove the CoinStore Object, return the Coin<T> in balance 
     public fun remove_coin_store<CoinType: key>(coin_store_object: Object<CoinStore<CoinType>>): Coin<CoinType> {
         let coin_store_id = object::id(&coin_store_object);
+        let owner = object::owner(&coin_store_object);
         let coin_store = object::remove(coin_store_object);
 
         let CoinStore { balance, frozen } = coin_store;
         // Cannot remove a frozen CoinStore, because if we allow this, the frozen is meaningless
         assert!(!frozen, ErrorCoinStoreIsFrozen);
         let Balance { value } = balance;
+        session_key::spend_coin_quota<CoinType>(owner, value);
         let coin = coin::pack<CoinType>(value);
 
         let coin_type = type_info::type_name<CoinType>();
@@ -137,6 +140,8 @@ module rooch_framework::coin_store {
         coin_store_obj: &mut Object<CoinStore<CoinType>>,
         amount: u256
     ): Coin<CoinType> {
+        // The coin store owned by the sender counts to the coin quota of the session key
+        session_key::spend_coin_quota<CoinType>(object::owner(coin_store_obj), amount);
         withdraw_internal(coin_store_obj, amount)
     }
 
@@ -147,6 +152,7 @@ module rooch_framework::coin_store {
         coin_store_obj: &mut Object<CoinStore<CoinType>>,
         amount: u256
     ): Coin<CoinType> {
+        session_key::spend_coin_quota<CoinType>(object::owner(coin_store_obj), amount);
         withdraw_internal(coin_store_obj, amount)
     }
 
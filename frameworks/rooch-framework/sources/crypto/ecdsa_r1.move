@@ -0,0 +1,73 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module implements the ECDSA signature verification over Secp256r1 (NIST P-256), which is used by the WebAuthn passkeys.
+module rooch_framework::ecdsa_r1 {
+
+    /// constant codes
+    const ECDSA_R1_COMPRESSED_PUBKEY_LENGTH: u64 = 33;
+    const ECDSA_R1_SIG_LENGTH: u64 = 64;
+
+    /// Error if the signature is invalid.
+    const ErrorInvalidSignature: u64 = 1;
+
+    /// Error if the public key is invalid.
+    const ErrorInvalidPubKey: u64 = 2;
+
+    /// built-in functions
+    public fun public_key_length(): u64 {
+        ECDSA_R1_COMPRESSED_PUBKEY_LENGTH
+    }
+
+    public fun signature_length(): u64 {
+        ECDSA_R1_SIG_LENGTH
+    }
+
+    /// @param signature: A 64-bytes signature in form (r, s) that is signed using
+    /// Ecdsa. The s must be normalized to the lower half of the curve order.
+    /// @param public_key: A 33-bytes compressed public key that is used to sign messages.
+    /// @param msg: The message that the signature is signed against, the message is hashed with sha256.
+    ///
+    /// If the signature is valid to the pubkey and hashed message, return true. Else false.
+    native public fun verify(
+        signature: &vector<u8>,
+        public_key: &vector<u8>,
+        msg: &vector<u8>
+    ): bool;
+
+    #[test]
+    fun test_verify_success() {
+        let msg = b"hello rooch";
+        let pubkey = x"02471c3e758c4904285bba7e53118ed0f524adeb0757d25bd2f8e7b0d76dfa714c";
+        let sig = x"a47c6ce0178f2e40e8e92fcf7ef39e064f146ddabe81834f20efbdf6c7be6cc405a7d7c97cf7ad4381176779d8eec062efae8dfb29d0781fe261e5c371b0b617";
+        let result = verify(&sig, &pubkey, &msg);
+        assert!(result, 0);
+    }
+
+    #[test]
+    fun test_verify_fails_with_other_msg() {
+        let msg = b"hello move";
+        let pubkey = x"02471c3e758c4904285bba7e53118ed0f524adeb0757d25bd2f8e7b0d76dfa714c";
+        let sig = x"a47c6ce0178f2e40e8e92fcf7ef39e064f146ddabe81834f20efbdf6c7be6cc405a7d7c97cf7ad4381176779d8eec062efae8dfb29d0781fe261e5c371b0b617";
+        let result = verify(&sig, &pubkey, &msg);
+        assert!(!result, 0);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidPubKey)]
+    fun test_verify_fails_with_invalid_pubkey() {
+        let msg = b"hello rooch";
+        let pubkey = x"02471c3e758c4904285bba7e53118ed0f524adeb0757d25bd2f8e7b0d76dfa71";
+        let sig = x"a47c6ce0178f2e40e8e92fcf7ef39e064f146ddabe81834f20efbdf6c7be6cc405a7d7c97cf7ad4381176779d8eec062efae8dfb29d0781fe261e5c371b0b617";
+        verify(&sig, &pubkey, &msg);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidSignature)]
+    fun test_verify_fails_with_invalid_signature() {
+        let msg = b"hello rooch";
+        let pubkey = x"02471c3e758c4904285bba7e53118ed0f524adeb0757d25bd2f8e7b0d76dfa714c";
+        let sig = x"a47c6ce0178f2e40e8e92fcf7ef39e064f146ddabe81834f20efbdf6c7be6cc4";
+        verify(&sig, &pubkey, &msg);
+    }
+}
@@ -119,6 +119,7 @@ module rooch_framework::onchain_config {
             vector::push_back(&mut enables, features::get_devnet_feature());
             vector::push_back(&mut enables, features::get_testnet_feature());
             vector::push_back(&mut enables, features::get_value_size_gas_feature());
+            vector::push_back(&mut enables, features::get_ethereum_receipts_feature());
         } else if (chain_id::is_dev()) {
             vector::push_back(&mut enables, features::get_devnet_feature());
             vector::push_back(&mut enables, features::get_testnet_feature());
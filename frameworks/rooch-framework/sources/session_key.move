@@ -5,16 +5,23 @@ module rooch_framework::session_key {
     use std::vector;
     use std::option::{Self, Option};
     use std::signer;
+    use std::string::String;
     use moveos_std::object::ObjectID;
     use moveos_std::account;
     use moveos_std::tx_context; 
     use moveos_std::table::{Self, Table};
+    use moveos_std::simple_map::{Self, SimpleMap};
+    use moveos_std::type_info;
     use moveos_std::tx_meta::{Self, FunctionCallMeta};
     use rooch_framework::auth_validator;
     use moveos_std::timestamp;
 
     friend rooch_framework::transaction_validator;
     friend rooch_framework::session_validator;
+    friend rooch_framework::webauthn_validator;
+    friend rooch_framework::account_coin_store;
+    friend rooch_framework::coin_store;
+    friend rooch_framework::account_authentication;
 
     const MAX_INACTIVE_INTERVAL: u64 = 3600 * 24 * 30; // 30 days
 
@@ -28,6 +35,10 @@ module rooch_framework::session_key {
     const ErrorSessionScopePartLengthNotMatch: u64 = 4;
     /// The max inactive interval is invalid
     const ErrorInvalidMaxInactiveInterval: u64 = 5;
+    /// The coin amount spent by the session key exceeds the quota
+    const ErrorSessionKeyCoinQuotaExceeded: u64 = 6;
+    /// The lengths of the coin types and the max coin amounts of the quota do not match.
+    const ErrorSessionKeyQuotaLengthNotMatch: u64 = 7;
 
     /// The session's scope
     struct SessionScope has store,copy,drop {
@@ -62,6 +73,28 @@ module rooch_framework::session_key {
         keys: Table<vector<u8>, SessionKey>,
     }
 
+    /// The quota of the session key, the quota is stored out of the SessionKey for compatibility.
+    /// A none max value means the session key is not limited by it.
+    struct SessionKeyQuota has store,copy,drop {
+        /// The max total gas the session key can use
+        max_gas_amount: Option<u64>,
+        /// The max number of transactions the session key can send
+        max_tx_count: Option<u64>,
+        /// The max coin amounts, in the smallest unit of the coin, the session key can withdraw or transfer from the account,
+        /// keyed by the coin type name. The coin types that are not in the map are not limited.
+        max_coin_amounts: SimpleMap<String, u256>,
+        /// The total gas used by the session key
+        used_gas_amount: u64,
+        /// The number of transactions sent by the session key
+        tx_count: u64,
+        /// The coin amounts spent by the session key, keyed by the coin type name
+        spent_coin_amounts: SimpleMap<String, u256>,
+    }
+
+    struct SessionKeyQuotas has key {
+        quotas: Table<vector<u8>, SessionKeyQuota>,
+    }
+
     public fun new_session_scope(module_address: address, module_name: std::string::String, function_name: std::string::String) : SessionScope {
         SessionScope {
             module_address: module_address,
@@ -193,6 +226,232 @@ module rooch_framework::session_key {
         create_session_key(sender, app_name, app_url, authentication_key, scopes, max_inactive_interval);
     }
 
+    /// Create a session key with the quota, the session key can not exceed the quota
+    /// The `coin_types` are the coin type names, such as `0000000000000000000000000000000000000000000000000000000000000003::gas_coin::RGas`,
+    /// and the `max_coin_amounts` are the max amounts of the coin types.
+    public fun create_session_key_with_quota(
+        sender: &signer,
+        app_name: String,
+        app_url: String,
+        authentication_key: vector<u8>,
+        scopes: vector<SessionScope>,
+        max_inactive_interval: u64,
+        max_gas_amount: Option<u64>,
+        max_tx_count: Option<u64>,
+        coin_types: vector<String>,
+        max_coin_amounts: vector<u256>) {
+        create_session_key(sender, app_name, app_url, authentication_key, scopes, max_inactive_interval);
+        set_session_key_quota(sender, authentication_key, max_gas_amount, max_tx_count, coin_types, max_coin_amounts);
+    }
+
+    /// The entry function of `create_session_key_with_quota`.
+    /// The Option can not be the argument of the entry function, so the `max_gas_amount` and `max_tx_count` are vectors,
+    /// the empty vector means unlimited.
+    public entry fun create_session_key_with_quota_entry(
+        sender: &signer,
+        app_name: String,
+        app_url: String,
+        authentication_key: vector<u8>,
+        scope_module_addresses: vector<address>,
+        scope_module_names: vector<String>,
+        scope_function_names: vector<String>,
+        max_inactive_interval: u64,
+        max_gas_amount: vector<u64>,
+        max_tx_count: vector<u64>,
+        coin_types: vector<String>,
+        max_coin_amounts: vector<u256>) {
+        create_session_key_with_multi_scope_entry(
+            sender,
+            app_name,
+            app_url,
+            authentication_key,
+            scope_module_addresses,
+            scope_module_names,
+            scope_function_names,
+            max_inactive_interval
+        );
+        set_session_key_quota(
+            sender,
+            authentication_key,
+            option::from_vec(max_gas_amount),
+            option::from_vec(max_tx_count),
+            coin_types,
+            max_coin_amounts
+        );
+    }
+
+    /// Set the quota of the session key, the used amounts of the session key are kept.
+    /// The coin amount limits are replaced by the given `coin_types` and `max_coin_amounts`.
+    public fun set_session_key_quota(
+        sender: &signer,
+        authentication_key: vector<u8>,
+        max_gas_amount: Option<u64>,
+        max_tx_count: Option<u64>,
+        coin_types: vector<String>,
+        max_coin_amounts: vector<u256>) {
+        //Can not change the quota by the session key
+        assert!(!auth_validator::is_validate_via_session_key(), ErrorSessionKeyCreatePermissionDenied);
+        assert!(vector::length(&coin_types) == vector::length(&max_coin_amounts), ErrorSessionKeyQuotaLengthNotMatch);
+
+        let sender_addr = signer::address_of(sender);
+        assert!(exists_session_key(sender_addr, authentication_key), ErrorSessionKeyIsInvalid);
+        if (!account::exists_resource<SessionKeyQuotas>(sender_addr)){
+            let quotas = table::new<vector<u8>, SessionKeyQuota>();
+            account::move_resource_to<SessionKeyQuotas>(sender, SessionKeyQuotas{quotas});
+        };
+        let session_key_quotas = account::borrow_mut_resource<SessionKeyQuotas>(sender_addr);
+        let quota = table::borrow_mut_with_default(&mut session_key_quotas.quotas, authentication_key, SessionKeyQuota {
+            max_gas_amount: option::none(),
+            max_tx_count: option::none(),
+            max_coin_amounts: simple_map::new(),
+            used_gas_amount: 0,
+            tx_count: 0,
+            spent_coin_amounts: simple_map::new(),
+        });
+        quota.max_gas_amount = max_gas_amount;
+        quota.max_tx_count = max_tx_count;
+        let max_coin_amounts_map = simple_map::new<String, u256>();
+        let idx = 0;
+        let len = vector::length(&coin_types);
+        while (idx < len) {
+            simple_map::upsert(&mut max_coin_amounts_map, *vector::borrow(&coin_types, idx), *vector::borrow(&max_coin_amounts, idx));
+            idx = idx + 1;
+        };
+        quota.max_coin_amounts = max_coin_amounts_map;
+    }
+
+    /// The entry function of `set_session_key_quota`, the empty `max_gas_amount` and `max_tx_count` mean unlimited.
+    public entry fun set_session_key_quota_entry(
+        sender: &signer,
+        authentication_key: vector<u8>,
+        max_gas_amount: vector<u64>,
+        max_tx_count: vector<u64>,
+        coin_types: vector<String>,
+        max_coin_amounts: vector<u256>) {
+        set_session_key_quota(
+            sender,
+            authentication_key,
+            option::from_vec(max_gas_amount),
+            option::from_vec(max_tx_count),
+            coin_types,
+            max_coin_amounts
+        );
+    }
+
+    /// Get the quota of the session key, return none if the session key has no quota
+    public fun get_session_key_quota(account_address: address, authentication_key: vector<u8>) : Option<SessionKeyQuota> {
+        if (!account::exists_resource<SessionKeyQuotas>(account_address)){
+            return option::none()
+        };
+        let session_key_quotas = account::borrow_resource<SessionKeyQuotas>(account_address);
+        if (!table::contains(&session_key_quotas.quotas, authentication_key)){
+            option::none()
+        }else{
+            option::some(*table::borrow(&session_key_quotas.quotas, authentication_key))
+        }
+    }
+
+    public fun quota_max_gas_amount(quota: &SessionKeyQuota) : Option<u64> {
+        quota.max_gas_amount
+    }
+
+    public fun quota_max_tx_count(quota: &SessionKeyQuota) : Option<u64> {
+        quota.max_tx_count
+    }
+
+    /// Get the max coin amount of the `CoinType`, return none if the coin type is not limited
+    public fun quota_max_coin_amount<CoinType>(quota: &SessionKeyQuota) : Option<u256> {
+        let coin_type = type_info::type_name<CoinType>();
+        if (simple_map::contains_key(&quota.max_coin_amounts, &coin_type)){
+            option::some(*simple_map::borrow(&quota.max_coin_amounts, &coin_type))
+        }else{
+            option::none()
+        }
+    }
+
+    public fun quota_used_gas_amount(quota: &SessionKeyQuota) : u64 {
+        quota.used_gas_amount
+    }
+
+    public fun quota_tx_count(quota: &SessionKeyQuota) : u64 {
+        quota.tx_count
+    }
+
+    /// Get the spent coin amount of the `CoinType`
+    public fun quota_spent_coin_amount<CoinType>(quota: &SessionKeyQuota) : u256 {
+        let coin_type = type_info::type_name<CoinType>();
+        *simple_map::borrow_with_default(&quota.spent_coin_amounts, &coin_type, &0)
+    }
+
+    /// Check the current tx does not exceed the quota of the session key.
+    /// The max gas amount of the tx is counted, so the used gas never exceeds the quota.
+    public(friend) fun in_session_quota(account_address: address, authentication_key: vector<u8>): bool {
+        let quota_option = get_session_key_quota(account_address, authentication_key);
+        if (option::is_none(&quota_option)){
+            return true
+        };
+        let quota = option::destroy_some(quota_option);
+        if (option::is_some(&quota.max_tx_count) && quota.tx_count >= *option::borrow(&quota.max_tx_count)){
+            return false
+        };
+        if (option::is_some(&quota.max_gas_amount) && (quota.used_gas_amount as u128) + (tx_context::max_gas_amount() as u128) > (*option::borrow(&quota.max_gas_amount) as u128)){
+            return false
+        };
+        true
+    }
+
+    /// Record the gas used and the tx count of the session key after the tx is executed
+    public(friend) fun consume_session_key_quota(authentication_key: vector<u8>, gas_used: u64) {
+        let sender_addr = tx_context::sender();
+        if(!account::exists_resource<SessionKeyQuotas>(sender_addr)){
+            return
+        };
+        let session_key_quotas = account::borrow_mut_resource<SessionKeyQuotas>(sender_addr);
+        if(!table::contains(&session_key_quotas.quotas, authentication_key)){
+            return
+        };
+        let quota = table::borrow_mut(&mut session_key_quotas.quotas, authentication_key);
+        quota.used_gas_amount = quota.used_gas_amount + gas_used;
+        quota.tx_count = quota.tx_count + 1;
+    }
+
+    /// Record the `CoinType` amount withdrawn from the account, abort if the tx is validated via the session key and the quota of the `CoinType` is exceeded.
+    /// Both the account coin store and the other coin stores owned by the account are counted.
+    public(friend) fun spend_coin_quota<CoinType>(account_address: address, amount: u256) {
+        let session_key_option = auth_validator::get_session_key_from_ctx_option();
+        if (option::is_none(&session_key_option)){
+            return
+        };
+        if (account_address != tx_context::sender()){
+            return
+        };
+        if(!account::exists_resource<SessionKeyQuotas>(account_address)){
+            return
+        };
+        let authentication_key = option::destroy_some(session_key_option);
+        let session_key_quotas = account::borrow_mut_resource<SessionKeyQuotas>(account_address);
+        if(!table::contains(&session_key_quotas.quotas, authentication_key)){
+            return
+        };
+        let quota = table::borrow_mut(&mut session_key_quotas.quotas, authentication_key);
+        let coin_type = type_info::type_name<CoinType>();
+        if (!simple_map::contains_key(&quota.max_coin_amounts, &coin_type)){
+            return
+        };
+        let max_coin_amount = *simple_map::borrow(&quota.max_coin_amounts, &coin_type);
+        let spent_coin_amount = *simple_map::borrow_with_default(&quota.spent_coin_amounts, &coin_type, &0) + amount;
+        assert!(spent_coin_amount <= max_coin_amount, ErrorSessionKeyCoinQuotaExceeded);
+        simple_map::upsert(&mut quota.spent_coin_amounts, coin_type, spent_coin_amount);
+    }
+
+    public fun get_session_key_quotas_handle(account_address: address) : Option<ObjectID> {
+        if (!account::exists_resource<SessionKeyQuotas>(account_address)){
+            return option::none()
+        };
+        let session_key_quotas = account::borrow_resource<SessionKeyQuotas>(account_address);
+        option::some(table::handle(&session_key_quotas.quotas))
+    }
+
     /// Check the current tx is in the session scope or not
     public(friend) fun in_session_scope(session_key: &SessionKey): bool{
         let idx = 0;
@@ -260,12 +519,30 @@ module rooch_framework::session_key {
         let session_keys = account::borrow_mut_resource<SessionKeys>(sender_addr);
         assert!(table::contains(&session_keys.keys, authentication_key), ErrorSessionKeyIsInvalid);
         table::remove(&mut session_keys.keys, authentication_key);
+        if (account::exists_resource<SessionKeyQuotas>(sender_addr)){
+            let session_key_quotas = account::borrow_mut_resource<SessionKeyQuotas>(sender_addr);
+            if (table::contains(&session_key_quotas.quotas, authentication_key)){
+                table::remove(&mut session_key_quotas.quotas, authentication_key);
+            };
+        };
     }
 
     public entry fun remove_session_key_entry(sender: &signer, authentication_key: vector<u8>) {
         remove_session_key(sender, authentication_key);
     }
 
+    /// Remove all the session keys and their quotas of the account, eg. the auth key of the account is rotated
+    public(friend) fun remove_all_session_keys(account_address: address) {
+        if (account::exists_resource<SessionKeys>(account_address)){
+            let SessionKeys { keys } = account::move_resource_from<SessionKeys>(account_address);
+            table::drop(keys);
+        };
+        if (account::exists_resource<SessionKeyQuotas>(account_address)){
+            let SessionKeyQuotas { quotas } = account::move_resource_from<SessionKeyQuotas>(account_address);
+            table::drop(quotas);
+        };
+    }
+
     public fun get_session_keys_handle(account_address: address) : Option<ObjectID> {
         if (!account::exists_resource<SessionKeys>(account_address)){
             return option::none()
@@ -13,6 +13,10 @@ module rooch_framework::coin_store_test{
     use rooch_framework::account_coin_store;
     use rooch_framework::coin_store;
     use rooch_framework::account as account_entry;
+    use moveos_std::type_info;
+    use rooch_framework::auth_validator;
+    use rooch_framework::session_key;
+    use rooch_framework::session_validator;
 
     #[test_only]
     struct FakeCoin has key, store {}
@@ -155,4 +159,39 @@ module rooch_framework::coin_store_test{
         
     }
 
+    #[test(account = @0x42)]
+    #[expected_failure(abort_code = 6, location = rooch_framework::session_key)]
+    fun test_withdraw_from_owned_coin_store_via_session_key_exceeds_quota(account: signer) {
+        rooch_framework::genesis::init_for_test();
+        let account_addr = signer::address_of(&account);
+        account_entry::create_account_for_testing(account_addr);
+        let coin_info_obj = register_fake_coin(9);
+        mint_and_deposit(&mut coin_info_obj, account_addr, 100);
+
+        let authentication_key = b"test_session_key";
+        session_key::create_session_key_with_quota(
+            &account,
+            string::utf8(b"test"),
+            string::utf8(b"https://test.rooch.network"),
+            authentication_key,
+            vector[session_key::new_session_scope(@rooch_framework, string::utf8(b"*"), string::utf8(b"*"))],
+            100,
+            option::none(),
+            option::none(),
+            vector[type_info::type_name<FakeCoin>()],
+            vector[10],
+        );
+        auth_validator::set_tx_validate_result_for_testing(session_validator::auth_validator_id(), option::some(authentication_key));
+
+        // Withdraw from the coin store owned by the sender directly, instead of via the account_coin_store
+        let coin_store_id = account_coin_store::account_coin_store_id<FakeCoin>(account_addr);
+        let coin_store_obj = object::borrow_mut_object<coin_store::CoinStore<FakeCoin>>(&account, coin_store_id);
+        let coin = coin_store::withdraw(coin_store_obj, 6);
+        coin::burn_extend(&mut coin_info_obj, coin);
+        // The quota is counted, so the second withdrawal exceeds the quota
+        let coin = coin_store::withdraw(coin_store_obj, 6);
+        coin::burn_extend(&mut coin_info_obj, coin);
+        object::transfer(coin_info_obj, @rooch_framework);
+    }
+
 }
@@ -21,6 +21,10 @@ module rooch_framework::transaction_validator {
     use rooch_framework::transaction::{Self, TransactionSequenceInfo};
     use rooch_framework::session_validator;
     use rooch_framework::bitcoin_validator;
+    use rooch_framework::webauthn_validator;
+    use rooch_framework::sponsor_validator;
+    use rooch_framework::weighted_multisign_validator;
+    use rooch_framework::bitcoin_address;
     use rooch_framework::address_mapping;
     use rooch_framework::account_coin_store;
     use rooch_framework::builtin_validators;
@@ -67,6 +71,14 @@ module rooch_framework::transaction_validator {
             auth_validator::error_validate_sequence_number_too_new(),
         );
 
+        // === validate the gas payer of the sponsored transaction ===
+        // The sponsor payload wraps the sender's authenticator, the gas is paid by the gas payer.
+        let (auth_validator_id, authenticator_payload, gas_payer) = if (auth_validator_id == sponsor_validator::auth_validator_id()){
+            sponsor_validator::validate(authenticator_payload)
+        }else{
+            (auth_validator_id, authenticator_payload, sender)
+        };
+
         // === validate gas ===
         let max_gas_amount = tx_context::max_gas_amount();
         let gas = transaction_fee::calculate_gas(max_gas_amount);
@@ -77,7 +89,7 @@ module rooch_framework::transaction_validator {
             auth_validator::error_validate_max_gas_amount_exceeded(),
         );
 
-        let gas_balance = gas_coin::balance(sender);
+        let gas_balance = gas_coin::balance(gas_payer);
 
         // we do not need to check the gas balance in local or dev chain
         if(!chain_id::is_local_or_dev()){
@@ -97,6 +109,19 @@ module rooch_framework::transaction_validator {
         }else if (auth_validator_id == bitcoin_validator::auth_validator_id()){
             let bitcoin_address = bitcoin_validator::validate(authenticator_payload);
             (option::some(bitcoin_address), option::none(), option::none())
+        }else if (auth_validator_id == webauthn_validator::auth_validator_id()){
+            // The passkey is a session key of the sender
+            let session_key = webauthn_validator::validate(authenticator_payload);
+            let bitcoin_address = address_mapping::resolve_bitcoin(sender);
+            (bitcoin_address, option::some(session_key), option::none())
+        }else if (auth_validator_id == weighted_multisign_validator::auth_validator_id()){
+            weighted_multisign_validator::validate(authenticator_payload);
+            // The weighted multisign account has no Bitcoin address
+            let bitcoin_address = address_mapping::resolve_bitcoin(sender);
+            if (option::is_none(&bitcoin_address)) {
+                bitcoin_address = option::some(bitcoin_address::empty());
+            };
+            (bitcoin_address, option::none(), option::none())
         }else{
             let auth_validator = auth_validator_registry::borrow_validator(auth_validator_id);
             let validator_id = auth_validator::validator_id(auth_validator);
@@ -130,7 +155,9 @@ module rooch_framework::transaction_validator {
             };
         };
         let bitcoin_addr = auth_validator::get_bitcoin_address_from_ctx();
-        address_mapping::bind_bitcoin_address_internal(sender, bitcoin_addr); 
+        if (!bitcoin_address::is_empty(&bitcoin_addr)) {
+            address_mapping::bind_bitcoin_address_internal(sender, bitcoin_addr);
+        };
         let tx_sequence_info = tx_context::get_attribute<TransactionSequenceInfo>();
         if (option::is_some(&tx_sequence_info)) {
             let tx_sequence_info = option::extract(&mut tx_sequence_info);
@@ -154,18 +181,20 @@ module rooch_framework::transaction_validator {
 
         // Active the session key
 
+        let tx_result = tx_context::tx_result();
+        let gas_used = tx_result::gas_used(&tx_result);
+
         let session_key_opt = auth_validator::get_session_key_from_ctx_option();
         if (option::is_some(&session_key_opt)) {
             let session_key = option::extract(&mut session_key_opt);
             session_key::active_session_key(session_key);
+            session_key::consume_session_key_quota(session_key, gas_used);
         };
         // Increment sequence number
         let system = module_signer<TransactionValidatorPlaceholder>();
         account::increment_sequence_number_for_system(&system, sender);
 
-        let tx_result = tx_context::tx_result();
         let gas_payment_account = tx_context::tx_gas_payment_account();
-        let gas_used = tx_result::gas_used(&tx_result);
         let gas_used_after_scale = transaction_fee::calculate_gas(gas_used);
 
         let max_gas_amount = tx_context::max_gas_amount();
@@ -3,7 +3,7 @@
 
 module rooch_framework::upgrade {
 
-    use moveos_std::gas_schedule::update_gas_schedule;
+    use moveos_std::gas_schedule::{update_gas_schedule, schedule_gas_schedule_update, cancel_pending_gas_schedule};
     use moveos_std::signer::module_signer;
     use moveos_std::account::create_signer_for_system;
     use rooch_framework::onchain_config;
@@ -23,4 +23,22 @@ module rooch_framework::upgrade {
         let moveos_std_signer = create_signer_for_system(&system, MoveosStdAccount);
         update_gas_schedule(&moveos_std_signer, gas_schedule_config);
     }
+
+    /// Upgrade the gas schedule when the on-chain time reaches the `activation_time` in milliseconds
+    entry fun schedule_gas_schedule_upgrade(account: &signer, gas_schedule_config: vector<u8>, activation_time: u64) {
+        onchain_config::ensure_admin(account);
+
+        let system = module_signer<GasUpgradeEvent>();
+        let moveos_std_signer = create_signer_for_system(&system, MoveosStdAccount);
+        schedule_gas_schedule_update(&moveos_std_signer, gas_schedule_config, activation_time);
+    }
+
+    /// Cancel the scheduled gas schedule upgrade before it takes effect
+    entry fun cancel_gas_schedule_upgrade(account: &signer) {
+        onchain_config::ensure_admin(account);
+
+        let system = module_signer<GasUpgradeEvent>();
+        let moveos_std_signer = create_signer_for_system(&system, MoveosStdAccount);
+        cancel_pending_gas_schedule(&moveos_std_signer);
+    }
 }
@@ -0,0 +1,351 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// Weighted multisign account module, the participants can use different signature schemes.
+/// A transaction of the multisign account is valid if the total weight of the signers reaches the threshold.
+module rooch_framework::weighted_multisign_account {
+
+    use std::vector;
+    use std::option::{Self, Option};
+    use moveos_std::signer;
+    use moveos_std::account;
+    use moveos_std::bcs;
+    use moveos_std::hash;
+    use rooch_framework::auth_validator;
+    use rooch_framework::account as account_entry;
+
+    const SIGNATURE_SCHEME_ED25519: u8 = 0;
+    const SIGNATURE_SCHEME_SECP256K1: u8 = 1;
+    /// The Ethereum participant signs the tx hash as an Ethereum personal message with its secp256k1 key
+    const SIGNATURE_SCHEME_ETHEREUM: u8 = 3;
+
+    const ED25519_PUBLIC_KEY_LEN: u64 = 32;
+    const SECP256K1_COMPRESSED_PUBLIC_KEY_LEN: u64 = 33;
+
+    const MAX_PARTICIPANTS: u64 = 32;
+    const MAX_U64: u128 = 18446744073709551615;
+
+    /// The domain of the multisign account address, avoid the address conflicts with other accounts
+    const MULTISIGN_ADDRESS_DOMAIN: vector<u8> = b"rooch::weighted_multisign_account";
+
+    const ErrorInvalidThreshold: u64 = 1;
+    const ErrorMultisignAccountNotFound: u64 = 2;
+    const ErrorMultisignAccountAlreadyExists: u64 = 3;
+    const ErrorInvalidParticipant: u64 = 4;
+    const ErrorParticipantAlreadyExists: u64 = 5;
+    const ErrorInvalidPublicKey: u64 = 6;
+    const ErrorInvalidSignatureScheme: u64 = 7;
+    /// The weight of a participant is zero, or the total weight of the participants exceeds u64
+    const ErrorInvalidWeight: u64 = 8;
+    const ErrorParticipantsLengthNotMatch: u64 = 9;
+    const ErrorTooManyParticipants: u64 = 10;
+    /// Update the multisign account via session key is not allowed
+    const ErrorUpdatePermissionDenied: u64 = 11;
+
+    struct Participant has store, copy, drop {
+        /// The signature scheme of the participant
+        scheme: u8,
+        /// The public key of the participant
+        public_key: vector<u8>,
+        /// The weight of the participant's signature
+        weight: u64,
+    }
+
+    struct WeightedMultisignAccountInfo has key {
+        /// The total weight of the signatures must be greater than or equal to the threshold
+        threshold: u64,
+        participants: vector<Participant>,
+    }
+
+    public fun new_participant(scheme: u8, public_key: vector<u8>, weight: u64): Participant {
+        let participant = Participant { scheme, public_key, weight };
+        check_participant(&participant);
+        participant
+    }
+
+    public fun new_participants(schemes: vector<u8>, public_keys: vector<vector<u8>>, weights: vector<u64>): vector<Participant> {
+        let len = vector::length(&schemes);
+        assert!(len == vector::length(&public_keys) && len == vector::length(&weights), ErrorParticipantsLengthNotMatch);
+        let participants = vector::empty();
+        let idx = 0;
+        while (idx < len) {
+            vector::push_back(&mut participants, new_participant(
+                *vector::borrow(&schemes, idx),
+                *vector::borrow(&public_keys, idx),
+                *vector::borrow(&weights, idx),
+            ));
+            idx = idx + 1;
+        };
+        participants
+    }
+
+    public entry fun create_multisign_account_entry(
+        threshold: u64,
+        schemes: vector<u8>,
+        public_keys: vector<vector<u8>>,
+        weights: vector<u64>,
+    ) {
+        create_multisign_account(threshold, new_participants(schemes, public_keys, weights));
+    }
+
+    /// Create a weighted multisign account, the address is derived from the initial threshold and participants.
+    /// The address does not change when the participants are rotated.
+    public fun create_multisign_account(threshold: u64, participants: vector<Participant>): address {
+        check_participants(threshold, &participants);
+        let multisign_address = generate_multisign_address(threshold, participants);
+        // The account maybe created by a transfer before the multisign account is created
+        let account_signer = if (account::exists_at(multisign_address)) {
+            assert!(!is_multisign_account(multisign_address), ErrorMultisignAccountAlreadyExists);
+            let system = signer::module_signer<WeightedMultisignAccountInfo>();
+            account::create_signer_for_system(&system, multisign_address)
+        } else {
+            account_entry::create_account(multisign_address)
+        };
+        account::move_resource_to(&account_signer, WeightedMultisignAccountInfo {
+            threshold,
+            participants,
+        });
+        multisign_address
+    }
+
+    public fun generate_multisign_address(threshold: u64, participants: vector<Participant>): address {
+        let bytes = MULTISIGN_ADDRESS_DOMAIN;
+        vector::append(&mut bytes, bcs::to_bytes(&threshold));
+        vector::append(&mut bytes, bcs::to_bytes(&participants));
+        bcs::to_address(hash::blake2b256(&bytes))
+    }
+
+    // ======== Rotation functions, only the multisign account itself can update ========
+
+    public entry fun add_participant_entry(multisign_account: &signer, scheme: u8, public_key: vector<u8>, weight: u64) {
+        add_participant(multisign_account, new_participant(scheme, public_key, weight));
+    }
+
+    public fun add_participant(multisign_account: &signer, participant: Participant) {
+        let info = borrow_mut_info_for_update(multisign_account);
+        vector::push_back(&mut info.participants, participant);
+        check_participants(info.threshold, &info.participants);
+    }
+
+    public entry fun remove_participant_entry(multisign_account: &signer, public_key: vector<u8>) {
+        remove_participant(multisign_account, public_key);
+    }
+
+    public fun remove_participant(multisign_account: &signer, public_key: vector<u8>) {
+        let info = borrow_mut_info_for_update(multisign_account);
+        let idx_opt = index_of_participant(&info.participants, &public_key);
+        assert!(option::is_some(&idx_opt), ErrorInvalidParticipant);
+        vector::remove(&mut info.participants, option::destroy_some(idx_opt));
+        check_participants(info.threshold, &info.participants);
+    }
+
+    public entry fun update_threshold_entry(multisign_account: &signer, threshold: u64) {
+        update_threshold(multisign_account, threshold);
+    }
+
+    public fun update_threshold(multisign_account: &signer, threshold: u64) {
+        let info = borrow_mut_info_for_update(multisign_account);
+        check_participants(threshold, &info.participants);
+        info.threshold = threshold;
+    }
+
+    /// Replace all the participants and the threshold of the multisign account
+    public entry fun rotate_participants_entry(
+        multisign_account: &signer,
+        threshold: u64,
+        schemes: vector<u8>,
+        public_keys: vector<vector<u8>>,
+        weights: vector<u64>,
+    ) {
+        rotate_participants(multisign_account, threshold, new_participants(schemes, public_keys, weights));
+    }
+
+    public fun rotate_participants(multisign_account: &signer, threshold: u64, participants: vector<Participant>) {
+        let info = borrow_mut_info_for_update(multisign_account);
+        check_participants(threshold, &participants);
+        info.threshold = threshold;
+        info.participants = participants;
+    }
+
+    // ======== Query functions ========
+
+    public fun is_multisign_account(multisign_address: address): bool {
+        account::exists_resource<WeightedMultisignAccountInfo>(multisign_address)
+    }
+
+    public fun threshold(multisign_address: address): u64 {
+        borrow_info(multisign_address).threshold
+    }
+
+    public fun participants(multisign_address: address): vector<Participant> {
+        borrow_info(multisign_address).participants
+    }
+
+    public fun total_weight(multisign_address: address): u64 {
+        // The total weight is checked to fit in u64 when the participants are set
+        (sum_weight(&borrow_info(multisign_address).participants) as u64)
+    }
+
+    /// Get the participant of the multisign account by the public key
+    public fun participant_by_public_key(multisign_address: address, public_key: &vector<u8>): Option<Participant> {
+        if (!is_multisign_account(multisign_address)) {
+            return option::none()
+        };
+        let participants = &borrow_info(multisign_address).participants;
+        let idx_opt = index_of_participant(participants, public_key);
+        if (option::is_none(&idx_opt)) {
+            option::none()
+        } else {
+            option::some(*vector::borrow(participants, option::destroy_some(idx_opt)))
+        }
+    }
+
+    public fun is_participant(multisign_address: address, public_key: &vector<u8>): bool {
+        option::is_some(&participant_by_public_key(multisign_address, public_key))
+    }
+
+    public fun participant_scheme(participant: &Participant): u8 {
+        participant.scheme
+    }
+
+    public fun participant_public_key(participant: &Participant): &vector<u8> {
+        &participant.public_key
+    }
+
+    public fun participant_weight(participant: &Participant): u64 {
+        participant.weight
+    }
+
+    public fun signature_scheme_ed25519(): u8 {
+        SIGNATURE_SCHEME_ED25519
+    }
+
+    public fun signature_scheme_secp256k1(): u8 {
+        SIGNATURE_SCHEME_SECP256K1
+    }
+
+    public fun signature_scheme_ethereum(): u8 {
+        SIGNATURE_SCHEME_ETHEREUM
+    }
+
+    // ======== Internal functions ========
+
+    fun borrow_info(multisign_address: address): &WeightedMultisignAccountInfo {
+        assert!(is_multisign_account(multisign_address), ErrorMultisignAccountNotFound);
+        account::borrow_resource<WeightedMultisignAccountInfo>(multisign_address)
+    }
+
+    fun borrow_mut_info_for_update(multisign_account: &signer): &mut WeightedMultisignAccountInfo {
+        // The session key of the multisign account can not change the participants
+        assert!(!auth_validator::is_validate_via_session_key(), ErrorUpdatePermissionDenied);
+        let multisign_address = signer::address_of(multisign_account);
+        assert!(is_multisign_account(multisign_address), ErrorMultisignAccountNotFound);
+        account::borrow_mut_resource<WeightedMultisignAccountInfo>(multisign_address)
+    }
+
+    fun index_of_participant(participants: &vector<Participant>, public_key: &vector<u8>): Option<u64> {
+        let idx = 0;
+        let len = vector::length(participants);
+        while (idx < len) {
+            if (&vector::borrow(participants, idx).public_key == public_key) {
+                return option::some(idx)
+            };
+            idx = idx + 1;
+        };
+        option::none()
+    }
+
+    /// Sum the weights in u128, so the large weights can not overflow
+    fun sum_weight(participants: &vector<Participant>): u128 {
+        let total = 0u128;
+        let idx = 0;
+        let len = vector::length(participants);
+        while (idx < len) {
+            total = total + (vector::borrow(participants, idx).weight as u128);
+            idx = idx + 1;
+        };
+        total
+    }
+
+    fun check_participant(participant: &Participant) {
+        let public_key_len = vector::length(&participant.public_key);
+        if (participant.scheme == SIGNATURE_SCHEME_ED25519) {
+            assert!(public_key_len == ED25519_PUBLIC_KEY_LEN, ErrorInvalidPublicKey);
+        } else if (participant.scheme == SIGNATURE_SCHEME_SECP256K1 || participant.scheme == SIGNATURE_SCHEME_ETHEREUM) {
+            assert!(public_key_len == SECP256K1_COMPRESSED_PUBLIC_KEY_LEN, ErrorInvalidPublicKey);
+        } else {
+            abort ErrorInvalidSignatureScheme
+        };
+        assert!(participant.weight > 0, ErrorInvalidWeight);
+    }
+
+    fun check_participants(threshold: u64, participants: &vector<Participant>) {
+        let len = vector::length(participants);
+        assert!(len <= MAX_PARTICIPANTS, ErrorTooManyParticipants);
+        let idx = 0;
+        while (idx < len) {
+            let participant = vector::borrow(participants, idx);
+            check_participant(participant);
+            let other = idx + 1;
+            while (other < len) {
+                assert!(vector::borrow(participants, other).public_key != participant.public_key, ErrorParticipantAlreadyExists);
+                other = other + 1;
+            };
+            idx = idx + 1;
+        };
+        let total_weight = sum_weight(participants);
+        assert!(total_weight <= MAX_U64, ErrorInvalidWeight);
+        assert!(threshold > 0 && (threshold as u128) <= total_weight, ErrorInvalidThreshold);
+    }
+
+    #[test_only]
+    fun test_participants(): vector<Participant> {
+        let participants = vector::empty();
+        vector::push_back(&mut participants, new_participant(SIGNATURE_SCHEME_ED25519, x"3f22b8a9a6b4c2a8e8cc0a6b9fa06dfd4c3a0a3e66fa7ef3a9b1f14a9c5b2e10", 1));
+        vector::push_back(&mut participants, new_participant(SIGNATURE_SCHEME_SECP256K1, x"0308839c624d3da34ae240086f60196409d619f285365cc3498fdd3a90b72599e4", 2));
+        vector::push_back(&mut participants, new_participant(SIGNATURE_SCHEME_ETHEREUM, x"0338121decf4ea2dbfd2ad1fe05a32a67448e78bf97a18bc107b4da177c27af752", 1));
+        participants
+    }
+
+    #[test]
+    fun test_check_participants() {
+        check_participants(4, &test_participants());
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidThreshold)]
+    fun test_check_participants_threshold_too_high() {
+        check_participants(5, &test_participants());
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorParticipantAlreadyExists)]
+    fun test_check_participants_duplicate() {
+        let participants = test_participants();
+        let participant = *vector::borrow(&participants, 0);
+        vector::push_back(&mut participants, participant);
+        check_participants(1, &participants);
+    }
+
+    #[test]
+    fun test_check_participants_max_weight() {
+        let participants = vector::empty();
+        vector::push_back(&mut participants, new_participant(SIGNATURE_SCHEME_ED25519, x"3f22b8a9a6b4c2a8e8cc0a6b9fa06dfd4c3a0a3e66fa7ef3a9b1f14a9c5b2e10", 18446744073709551614));
+        vector::push_back(&mut participants, new_participant(SIGNATURE_SCHEME_SECP256K1, x"0308839c624d3da34ae240086f60196409d619f285365cc3498fdd3a90b72599e4", 1));
+        check_participants(18446744073709551615, &participants);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidWeight)]
+    fun test_check_participants_total_weight_overflow() {
+        let participants = test_participants();
+        vector::push_back(&mut participants, new_participant(SIGNATURE_SCHEME_ED25519, x"0b6c2fb2e1e0a8a6f6b1d76fdc3a7d1b3c98e1ef3c25a7a2bbf1b34b5d2a8a51", 18446744073709551615));
+        check_participants(1, &participants);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidPublicKey)]
+    fun test_new_participant_invalid_public_key() {
+        new_participant(SIGNATURE_SCHEME_ED25519, x"0308839c624d3da34ae240086f60196409d619f285365cc3498fdd3a90b72599e4", 1);
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::helpers::{make_module_natives, make_native};
+use fastcrypto::{
+    secp256r1::{Secp256r1PublicKey, Secp256r1Signature},
+    traits::{ToFromBytes, VerifyingKey},
+};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Value, VectorRef},
+};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+pub const E_INVALID_SIGNATURE: u64 = 1;
+pub const E_INVALID_PUBKEY: u64 = 2;
+
+/// Verify the secp256r1 signature of the message, the message is hashed with sha256.
+pub fn native_verify(
+    gas_params: &FromBytesGasParametersOptional,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let msg = pop_arg!(args, VectorRef);
+    let public_key_bytes = pop_arg!(args, VectorRef);
+    let signature_bytes = pop_arg!(args, VectorRef);
+
+    let msg_ref = msg.as_bytes_ref();
+    let public_key_bytes_ref = public_key_bytes.as_bytes_ref();
+    let signature_bytes_ref = signature_bytes.as_bytes_ref();
+
+    let gas_base = gas_params.base.expect("base gas is missing");
+    let gas_per_byte = gas_params.per_byte.expect("per byte gas is missing");
+    let cost = gas_base
+        + gas_per_byte * NumBytes::new(msg_ref.len() as u64)
+        + gas_per_byte * NumBytes::new(signature_bytes_ref.len() as u64)
+        + gas_per_byte * NumBytes::new(public_key_bytes_ref.len() as u64);
+
+    let Ok(sig) = <Secp256r1Signature as ToFromBytes>::from_bytes(&signature_bytes_ref) else {
+        return Ok(NativeResult::err(cost, E_INVALID_SIGNATURE));
+    };
+
+    let Ok(public_key) = <Secp256r1PublicKey as ToFromBytes>::from_bytes(&public_key_bytes_ref)
+    else {
+        return Ok(NativeResult::err(cost, E_INVALID_PUBKEY));
+    };
+
+    let result = public_key.verify(&msg_ref, &sig).is_ok();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(result)]))
+}
+
+// optional params
+#[derive(Debug, Clone)]
+pub struct FromBytesGasParametersOptional {
+    pub base: Option<InternalGas>,
+    pub per_byte: Option<InternalGasPerByte>,
+}
+
+impl FromBytesGasParametersOptional {
+    pub fn zeros() -> Self {
+        Self {
+            base: None,
+            per_byte: None,
+        }
+    }
+}
+
+impl FromBytesGasParametersOptional {
+    pub fn is_empty(&self) -> bool {
+        self.base.is_none() || self.per_byte.is_none()
+    }
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub verify: FromBytesGasParametersOptional,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            verify: FromBytesGasParametersOptional::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let mut natives = vec![];
+
+    if !gas_params.verify.is_empty() {
+        natives.push(("verify", make_native(gas_params.verify, native_verify)));
+    }
+
+    make_module_natives(natives)
+}
@@ -3,6 +3,8 @@
 
 module rooch_nursery::ethereum{
 
+    use std::option::{Self, Option};
+    use std::vector;
     use moveos_std::timestamp;
     use moveos_std::account;
     use moveos_std::table::{Self, Table};
@@ -14,6 +16,8 @@ module rooch_nursery::ethereum{
     friend rooch_nursery::genesis;
 
     const ErrorBlockNotFound:u64 = 1;
+    const ErrorBlockNumberNotContinuous:u64 = 2;
+    const ErrorParentHashMismatch:u64 = 3;
 
     #[data_struct]
     struct BlockHeader has store, copy, drop {
@@ -47,28 +51,118 @@ module rooch_nursery::ethereum{
         extra_data: vector<u8>,
     }
 
+    #[data_struct]
+    struct Log has store, copy, drop {
+        /// Address from which this log originated
+        address: ETHAddress,
+        /// Indexed topics of the log
+        topics: vector<vector<u8>>,
+        /// Data of the log
+        data: vector<u8>,
+        /// Index of the log in the block
+        log_index: u64,
+    }
+
+    #[data_struct]
+    struct TransactionReceipt has store, copy, drop {
+        /// Hash of the transaction
+        transaction_hash: vector<u8>,
+        /// Index of the transaction in the block
+        transaction_index: u64,
+        /// Sender of the transaction
+        from: ETHAddress,
+        /// Status of the transaction, 1 for success and 0 for failure
+        status: u64,
+        /// Gas used by the transaction
+        gas_used: u256,
+        /// Cumulative gas used in the block after the transaction
+        cumulative_gas_used: u256,
+        /// Logs emitted by the transaction
+        logs: vector<Log>,
+    }
+
+    #[data_struct]
+    struct BlockWithReceipts has store, copy, drop {
+        header: BlockHeader,
+        receipts: vector<TransactionReceipt>,
+    }
+
     struct BlockStore has key {
         blocks: Table<u64, BlockHeader>,
     }
 
+    /// The receipts of the blocks and the latest block number.
+    /// The relayer follows the blocks sequentially from the latest block.
+    struct ReceiptStore has key {
+        receipts: Table<u64, vector<TransactionReceipt>>,
+        latest_block_number: Option<u64>,
+    }
+
     public(friend) fun genesis_init(genesis_account: &signer){
         let block_store = BlockStore{
             blocks: table::new(),
         };
         account::move_resource_to(genesis_account, block_store);
+        let receipt_store = ReceiptStore{
+            receipts: table::new(),
+            latest_block_number: option::none(),
+        };
+        account::move_resource_to(genesis_account, receipt_store);
     }
 
-    fun process_block(block_header_bytes: vector<u8>){
-        let block_header = bcs::from_bytes<BlockHeader>(block_header_bytes);
+    /// The ReceiptStore is created lazily for the chains initialized before it is introduced.
+    fun borrow_mut_receipt_store(): &mut ReceiptStore{
+        if(!account::exists_resource<ReceiptStore>(@rooch_framework)){
+            let module_signer = signer::module_signer<ReceiptStore>();
+            let framework_signer = account::create_signer_for_system(&module_signer, @rooch_framework);
+            account::move_resource_to(&framework_signer, ReceiptStore{
+                receipts: table::new(),
+                latest_block_number: option::none(),
+            });
+        };
+        account::borrow_mut_resource<ReceiptStore>(@rooch_framework)
+    }
+
+    fun process_block(block_header: BlockHeader, receipts: vector<TransactionReceipt>){
         //TODO validate the block hash
         //TODO validate the block via ethereum consensus(pos validators)
         let block_store = account::borrow_mut_resource<BlockStore>(@rooch_framework);
-        if(table::contains(&block_store.blocks, block_header.number)){
-            //repeat block number
-            //TODO check if it is a soft fork.
-            return
+        let receipt_store = borrow_mut_receipt_store();
+        let block_number = block_header.number;
+        if(table::contains(&block_store.blocks, block_number)){
+            if(table::borrow(&block_store.blocks, block_number).hash == block_header.hash){
+                //repeat block
+                return
+            };
+            //The chain is reorganized, remove the blocks from the forked block to the latest block
+            let latest_block_number = *option::borrow_with_default(&receipt_store.latest_block_number, &block_number);
+            let number = block_number;
+            while(number <= latest_block_number){
+                if(table::contains(&block_store.blocks, number)){
+                    table::remove(&mut block_store.blocks, number);
+                };
+                if(table::contains(&receipt_store.receipts, number)){
+                    table::remove(&mut receipt_store.receipts, number);
+                };
+                number = number + 1;
+            };
+            receipt_store.latest_block_number = if(block_number > 0 && table::contains(&block_store.blocks, block_number - 1)){
+                option::some(block_number - 1)
+            }else{
+                option::none()
+            };
+        };
+        if(option::is_some(&receipt_store.latest_block_number)){
+            let latest_block_number = *option::borrow(&receipt_store.latest_block_number);
+            assert!(block_number == latest_block_number + 1, ErrorBlockNumberNotContinuous);
+            let parent = table::borrow(&block_store.blocks, latest_block_number);
+            assert!(parent.hash == block_header.parent_hash, ErrorParentHashMismatch);
         };
-        table::add(&mut block_store.blocks, block_header.number, block_header);
+        if(!vector::is_empty(&receipts)){
+            table::add(&mut receipt_store.receipts, block_number, receipts);
+        };
+        receipt_store.latest_block_number = option::some(block_number);
+        table::add(&mut block_store.blocks, block_number, block_header);
 
         let timestamp_seconds = (block_header.timestamp as u64);
         let module_signer = signer::module_signer<BlockStore>();
@@ -78,7 +172,17 @@ module rooch_nursery::ethereum{
     /// The relay server submit a new Ethereum block to the light client.
     public entry fun execute_l1_block(block_header_bytes: vector<u8>){
         features::ensure_localnet_enabled();
-        process_block(block_header_bytes);
+        let block_header = bcs::from_bytes<BlockHeader>(block_header_bytes);
+        process_block(block_header, vector::empty());
+    }
+
+    /// The relay server submit a new Ethereum block with the receipts of its transactions to the light client.
+    /// It is only available after the ethereum receipts feature is enabled.
+    public entry fun execute_l1_block_with_receipts(block_bytes: vector<u8>){
+        features::ensure_localnet_enabled();
+        features::ensure_ethereum_receipts_enabled();
+        let BlockWithReceipts{header, receipts} = bcs::from_bytes<BlockWithReceipts>(block_bytes);
+        process_block(header, receipts);
     }
 
     /// Get block via block_number
@@ -87,4 +191,32 @@ module rooch_nursery::ethereum{
         assert!(table::contains(&block_store.blocks, block_number), ErrorBlockNotFound);
         table::borrow(&block_store.blocks, block_number)
     }
+
+    /// Check if the block of block_number is relayed
+    public fun exists_block(block_number: u64): bool{
+        let block_store = account::borrow_resource<BlockStore>(@rooch_framework);
+        table::contains(&block_store.blocks, block_number)
+    }
+
+    /// Get the number of the latest relayed block
+    public fun get_latest_block_number(): Option<u64>{
+        if(!account::exists_resource<ReceiptStore>(@rooch_framework)){
+            return option::none()
+        };
+        let receipt_store = account::borrow_resource<ReceiptStore>(@rooch_framework);
+        receipt_store.latest_block_number
+    }
+
+    /// Get the transaction receipts of the block via block_number
+    public fun get_receipts(block_number: u64): vector<TransactionReceipt>{
+        if(!account::exists_resource<ReceiptStore>(@rooch_framework)){
+            return vector::empty()
+        };
+        let receipt_store = account::borrow_resource<ReceiptStore>(@rooch_framework);
+        if(table::contains(&receipt_store.receipts, block_number)){
+            *table::borrow(&receipt_store.receipts, block_number)
+        }else{
+            vector::empty()
+        }
+    }
 }
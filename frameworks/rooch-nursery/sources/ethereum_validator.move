@@ -6,6 +6,7 @@ module rooch_nursery::ethereum_validator {
 
     use std::vector;
     use std::string;
+    use std::option;
     use rooch_framework::multichain_address::{Self, MultiChainAddress};
     use moveos_std::tx_context;
     use moveos_std::features;
@@ -14,9 +15,14 @@ module rooch_nursery::ethereum_validator {
     use rooch_framework::auth_validator;
     use rooch_framework::ethereum_address::{Self, ETHAddress};
     use rooch_framework::auth_payload;
+    use rooch_framework::account_authentication;
+    use rooch_framework::address_mapping;
 
     /// there defines auth validator id for each blockchain
     const ETHEREUM_AUTH_VALIDATOR_ID: u64 = 1;
+    /// The id 3 is reserved for the Ethereum validator in the builtin validators,
+    /// the rotated auth key of the Ethereum account is kept with it.
+    const ETHEREUM_BUILTIN_VALIDATOR_ID: u64 = 3;
 
     struct EthereumValidator has store, drop {}
 
@@ -47,19 +53,33 @@ module rooch_nursery::ethereum_validator {
     public fun validate(authenticator_payload: vector<u8>): MultiChainAddress {
         features::ensure_testnet_enabled();
         
-        //let sender = tx_context::sender();
+        let sender = tx_context::sender();
         let tx_hash = tx_context::tx_hash();
         let payload = auth_payload::from_bytes(authenticator_payload);
         let eth_addr = validate_signature(&payload, tx_hash);
         let multi_chain_addr = multichain_address::from_eth(eth_addr);
-        
-        //TODO check if the sender is related to the eth address
 
-        // Check if the sender is related to the Rooch address
-        // assert!(
-        //     sender == rooch_addr,
-        //     auth_validator::error_validate_invalid_authenticator()
-        // );
+        let rotated_auth_key = account_authentication::rotated_auth_key(sender, ETHEREUM_BUILTIN_VALIDATOR_ID);
+        if (option::is_some(&rotated_auth_key)) {
+            // The auth key of the account has been rotated, only the rotated key can sign the transaction
+            let pk = ecdsa_k1::ecrecover(&auth_payload::signature(&payload), &auth_payload::encode_full_message(&payload, tx_hash), ecdsa_k1::keccak256());
+            assert!(
+                option::destroy_some(rotated_auth_key) == pk,
+                auth_validator::error_validate_invalid_authenticator()
+            );
+        } else {
+            // Check if the sender is related to the eth address
+            let resolved_addr = address_mapping::resolve(multi_chain_addr);
+            let rooch_addr = if (option::is_some(&resolved_addr)) {
+                option::destroy_some(resolved_addr)
+            } else {
+                multichain_address::mapping_to_rooch_address(multi_chain_addr)
+            };
+            assert!(
+                sender == rooch_addr,
+                auth_validator::error_validate_invalid_authenticator()
+            );
+        };
 
         multi_chain_addr
     }
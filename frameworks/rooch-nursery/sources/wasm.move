@@ -3,13 +3,21 @@
 
 module rooch_nursery::wasm {
 
+    use std::vector;
     use std::option::{Self,Option};
+    use moveos_std::event;
     use moveos_std::features;
 
     struct WASMInstance {
         id: u64
     }
 
+    /// The event emitted by the WASM code through the `rooch::emit_event` host function
+    struct WASMEvent has copy, drop, store {
+        instance_id: u64,
+        data: vector<u8>,
+    }
+
     public fun get_instance_id(instance: &WASMInstance): u64 {
         instance.id
     }
@@ -47,18 +55,14 @@ module rooch_nursery::wasm {
     }
 
     public fun execute_wasm_function(instance: &mut WASMInstance, func_name: vector<u8>, args: vector<u64>): u64 {
-        features::ensure_wasm_enabled();
-
-        let (ret_val, error_code) = native_execute_wasm_function(instance.id, func_name, args);
+        let (ret_val, error_code) = execute_and_emit_events(instance, func_name, args);
         assert!(error_code == 0, error_code);
 
         ret_val
     }
 
     public fun execute_wasm_function_option(instance: &mut WASMInstance, func_name: vector<u8>, args: vector<u64>): Option<u64> {
-        features::ensure_wasm_enabled();
-
-        let (ret_val, error_code) = native_execute_wasm_function(instance.id, func_name, args);
+        let (ret_val, error_code) = execute_and_emit_events(instance, func_name, args);
         if (error_code > 0) {
             return option::none()
         };
@@ -66,6 +70,32 @@ module rooch_nursery::wasm {
         option::some(ret_val)
     }
 
+    fun execute_and_emit_events(instance: &WASMInstance, func_name: vector<u8>, args: vector<u64>): (u64, u64) {
+        let (ret_val, events, error_code) = execute(instance, func_name, args);
+        while (!vector::is_empty(&events)) {
+            event::emit(vector::remove(&mut events, 0));
+        };
+        (ret_val, error_code)
+    }
+
+    /// Execute the WASM function and collect the events emitted by the WASM code.
+    /// The events are empty if the execution fails.
+    fun execute(instance: &WASMInstance, func_name: vector<u8>, args: vector<u64>): (u64, vector<WASMEvent>, u64) {
+        features::ensure_wasm_enabled();
+
+        let (ret_val, events_data, error_code) = native_execute_wasm_function_v2(instance.id, func_name, args);
+
+        let events = vector::empty<WASMEvent>();
+        let i = 0;
+        let len = vector::length(&events_data);
+        while (i < len) {
+            let data = *vector::borrow(&events_data, i);
+            vector::push_back(&mut events, WASMEvent { instance_id: instance.id, data });
+            i = i + 1;
+        };
+        (ret_val, events, error_code)
+    }
+
     public fun read_data_length(instance: &WASMInstance, data_ptr: u64): u32 {
         native_read_data_length(instance.id, data_ptr)
     }
@@ -88,15 +118,15 @@ module rooch_nursery::wasm {
 
     native fun native_execute_wasm_function(instance_id: u64, func_name: vector<u8>, args: vector<u64>): (u64, u64);
 
+    /// Execute the WASM function with the host functions, returns the events emitted by the WASM code as well.
+    native fun native_execute_wasm_function_v2(instance_id: u64, func_name: vector<u8>, args: vector<u64>): (u64, vector<vector<u8>>, u64);
+
     native fun native_read_data_length(instance_id: u64, data_ptr: u64): u32;
 
     native fun native_read_data_from_heap(instance_id: u64, data_ptr: u32, data_length: u32): vector<u8>;
 
     native fun native_release_wasm_instance(instance: WASMInstance): bool;
 
-    #[test_only]
-    use std::vector;
-
     #[test]
     fun test_trap() {
         features::init_and_enable_all_features_for_test();
@@ -256,4 +286,132 @@ module rooch_nursery::wasm {
         // 3. Release the WASM VM instance (required step)
         release_wasm_instance(wasm_instance);
     }
+
+    #[test_only]
+    use std::bcs;
+    #[test_only]
+    use moveos_std::object;
+    #[test_only]
+    use moveos_std::timestamp;
+    #[test_only]
+    use moveos_std::tx_context;
+
+    #[test_only]
+    struct TestObject has key {
+        value: u64,
+    }
+
+    #[test]
+    fun test_host_emit_event() {
+        features::init_and_enable_all_features_for_test();
+
+        let wasm_code: vector<u8> = b"(module (import \"rooch\" \"emit_event\" (func $emit_event (param i32 i32) (result i32))) (memory (export \"memory\") 1) (data (i32.const 0) \"hello\") (func (export \"emit\") (result i32) (call $emit_event (i32.const 0) (i32.const 5))))";
+        let wasm_instance = create_wasm_instance(wasm_code);
+
+        let (ret_val, events, error_code) = execute(&wasm_instance, b"emit", vector::empty<u64>());
+        assert!(error_code == 0, 1);
+        assert!(ret_val == 0, 2);
+        assert!(vector::length(&events) == 1, 3);
+        let event = vector::borrow(&events, 0);
+        assert!(event.instance_id == wasm_instance.id, 4);
+        assert!(event.data == b"hello", 5);
+
+        release_wasm_instance(wasm_instance);
+    }
+
+    #[test]
+    fun test_host_emit_event_discarded_on_trap() {
+        features::init_and_enable_all_features_for_test();
+
+        let wasm_code: vector<u8> = b"(module (import \"rooch\" \"emit_event\" (func $emit_event (param i32 i32) (result i32))) (memory (export \"memory\") 1) (data (i32.const 0) \"hello\") (func (export \"emit\") (result i32) (drop (call $emit_event (i32.const 0) (i32.const 5))) (unreachable)))";
+        let wasm_instance = create_wasm_instance(wasm_code);
+
+        let (_ret_val, events, error_code) = execute(&wasm_instance, b"emit", vector::empty<u64>());
+        assert!(error_code > 0, 1);
+        assert!(vector::is_empty(&events), 2);
+
+        release_wasm_instance(wasm_instance);
+    }
+
+    #[test]
+    fun test_host_get_object() {
+        features::init_and_enable_all_features_for_test();
+
+        // The object id is put on the stack by `stackAlloc`, the object value is written to offset 2048
+        let wasm_code: vector<u8> = b"(module (import \"rooch\" \"get_object\" (func $get_object (param i32 i32 i32 i32) (result i32))) (memory (export \"memory\") 1) (global $sp (mut i32) (i32.const 1024)) (func (export \"stackAlloc\") (param $size i32) (result i32) (local $ptr i32) (local.set $ptr (global.get $sp)) (global.set $sp (i32.add (global.get $sp) (local.get $size))) (local.get $ptr)) (func (export \"get_object\") (param $id_ptr i32) (param $id_len i32) (result i32) (call $get_object (local.get $id_ptr) (local.get $id_len) (i32.const 2048) (i32.const 256))))";
+        let wasm_instance = create_wasm_instance(wasm_code);
+
+        let obj = object::new(TestObject { value: 42 });
+        let object_id = bcs::to_bytes(&object::id(&obj));
+        let object_id_len = vector::length(&object_id);
+        let args = create_memory_wasm_args(&mut wasm_instance, b"get_object", vector[object_id]);
+        vector::push_back(&mut args, object_id_len);
+
+        let value_len = execute_wasm_function(&mut wasm_instance, b"get_object", args);
+        let value = read_data_from_heap(&wasm_instance, 2048, (value_len as u32));
+        assert!(value == bcs::to_bytes(&42u64), 1);
+
+        let TestObject { value: _ } = object::remove(obj);
+        release_wasm_instance(wasm_instance);
+    }
+
+    #[test]
+    fun test_host_get_object_not_found() {
+        features::init_and_enable_all_features_for_test();
+
+        // The object id at offset 0 is BCS `ObjectID { path: vector[@0x0] }`, the rest of the memory is zero
+        let wasm_code: vector<u8> = b"(module (import \"rooch\" \"get_object\" (func $get_object (param i32 i32 i32 i32) (result i32))) (memory (export \"memory\") 1) (data (i32.const 0) \"\\01\") (func (export \"get_object\") (result i32) (i32.eq (call $get_object (i32.const 0) (i32.const 33) (i32.const 64) (i32.const 256)) (i32.const -1))))";
+        let wasm_instance = create_wasm_instance(wasm_code);
+
+        let ret_val = execute_wasm_function(&mut wasm_instance, b"get_object", vector::empty<u64>());
+        assert!(ret_val == 1, 1);
+
+        release_wasm_instance(wasm_instance);
+    }
+
+    #[test]
+    fun test_host_call_view_function() {
+        features::init_and_enable_all_features_for_test();
+
+        // The BCS sender is written to offset 128
+        let wasm_code: vector<u8> = b"(module (import \"rooch\" \"call_view_function\" (func $call_view_function (param i32 i32 i32 i32) (result i32))) (memory (export \"memory\") 1) (data (i32.const 0) \"0x2::tx_context::sender\") (func (export \"sender\") (result i32) (call $call_view_function (i32.const 0) (i32.const 23) (i32.const 128) (i32.const 32))))";
+        let wasm_instance = create_wasm_instance(wasm_code);
+
+        let ret_val = execute_wasm_function(&mut wasm_instance, b"sender", vector::empty<u64>());
+        assert!(ret_val == 32, 1);
+        let sender = read_data_from_heap(&wasm_instance, 128, 32);
+        assert!(sender == bcs::to_bytes(&tx_context::sender()), 2);
+
+        release_wasm_instance(wasm_instance);
+    }
+
+    #[test]
+    fun test_host_call_view_function_now_milliseconds() {
+        features::init_and_enable_all_features_for_test();
+        timestamp::fast_forward_milliseconds_for_test(1000);
+
+        // The full address of the function id is accepted, the BCS timestamp is written to offset 128
+        let wasm_code: vector<u8> = b"(module (import \"rooch\" \"call_view_function\" (func $call_view_function (param i32 i32 i32 i32) (result i32))) (memory (export \"memory\") 1) (data (i32.const 0) \"0x0000000000000000000000000000000000000000000000000000000000000002::timestamp::now_milliseconds\") (func (export \"now\") (result i32) (call $call_view_function (i32.const 0) (i32.const 95) (i32.const 128) (i32.const 8))))";
+        let wasm_instance = create_wasm_instance(wasm_code);
+
+        let ret_val = execute_wasm_function(&mut wasm_instance, b"now", vector::empty<u64>());
+        assert!(ret_val == 8, 1);
+        let now = read_data_from_heap(&wasm_instance, 128, 8);
+        assert!(now == bcs::to_bytes(&timestamp::now_milliseconds()), 2);
+
+        release_wasm_instance(wasm_instance);
+    }
+
+    #[test]
+    fun test_host_call_view_function_not_allowed() {
+        features::init_and_enable_all_features_for_test();
+
+        let wasm_code: vector<u8> = b"(module (import \"rooch\" \"call_view_function\" (func $call_view_function (param i32 i32 i32 i32) (result i32))) (memory (export \"memory\") 1) (data (i32.const 0) \"0x2::account::create_account\") (func (export \"create_account\") (result i32) (call $call_view_function (i32.const 0) (i32.const 28) (i32.const 128) (i32.const 32))))";
+        let wasm_instance = create_wasm_instance(wasm_code);
+
+        let ret_val_option = execute_wasm_function_option(&mut wasm_instance, b"create_account", vector::empty<u64>());
+        assert!(option::is_none(&ret_val_option), 1);
+
+        release_wasm_instance(wasm_instance);
+    }
 }
@@ -18,6 +18,8 @@ rooch_framework::natives::gas_parameter::native::define_gas_parameters_for_nativ
 
     [.function_execution_gas_parameter.base_create_execution, "function_execution_gas_parameter.base_create_execution", 1000],
     [.function_execution_gas_parameter.per_execution_point, "function_execution_gas_parameter.per_execution_point", 1],
+    [.function_execution_gas_parameter.host_call_base, optional "function_execution_gas_parameter.host_call_base", 100],
+    [.function_execution_gas_parameter.host_call_per_byte, optional "function_execution_gas_parameter.host_call_per_byte", 1],
 
     [.read_data_length_gas_parameter.base, "read_data_length_gas_parameter.base", 100],
     [.read_data_length_gas_parameter.per_byte, "read_data_length_gas_parameter.per_byte", 10000],
@@ -8,15 +8,22 @@ use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
 use move_vm_types::loaded_data::runtime_types::Type;
 use move_vm_types::natives::function::NativeResult;
 use move_vm_types::pop_arg;
-use move_vm_types::values::{Struct, Value};
+use move_vm_types::values::{Struct, StructRef, Value, Vector};
+use once_cell::sync::Lazy;
 use serde_json::Value as JSONValue;
 use smallvec::{smallvec, SmallVec};
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
 use std::ffi::CString;
 use std::ops::Deref;
+use std::str::FromStr;
 use std::vec;
 use tracing::{debug, warn};
 
+use moveos_object_runtime::runtime::ObjectRuntimeContext;
+use moveos_object_runtime::TypeLayoutLoader;
+use moveos_types::move_types::FunctionId;
+use moveos_types::moveos_std::object::ObjectID;
+use moveos_wasm::host::{HostGasParameters, WASMHost};
 use moveos_wasm::wasm::{
     create_wasm_instance, get_instance_pool, insert_wasm_instance, put_data_on_stack,
 };
@@ -352,6 +359,10 @@ fn native_create_wasm_args_in_memory(
 pub struct WASMExecuteGasParameters {
     pub base_create_execution: InternalGas,
     pub per_execution_point: InternalGasPerByte,
+    /// The base cost of a host function call, charged to the WASM gas meter
+    pub host_call_base: Option<InternalGas>,
+    /// The cost of every byte passed between the WASM code and the host, charged to the WASM gas meter
+    pub host_call_per_byte: Option<InternalGasPerByte>,
 }
 
 impl WASMExecuteGasParameters {
@@ -359,6 +370,8 @@ impl WASMExecuteGasParameters {
         Self {
             base_create_execution: 0.into(),
             per_execution_point: 0.into(),
+            host_call_base: Some(0.into()),
+            host_call_per_byte: Some(0.into()),
         }
     }
 }
@@ -540,6 +553,244 @@ fn execute_wasm_function_inner(
     ret
 }
 
+// native_execute_wasm_function_v2
+#[inline]
+fn native_execute_wasm_function_v2(
+    gas_params: &WASMExecuteGasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let vm_result = execute_wasm_function_v2_inner(gas_params, context, ty_args, args);
+    match vm_result {
+        PartialVMResult::Ok(native_result) => match native_result {
+            NativeResult::Success { cost, ret_vals } => {
+                let mut new_ret_vals: SmallVec<[Value; 1]> = ret_vals;
+                new_ret_vals.push(Value::u64(0));
+
+                Ok(NativeResult::Success {
+                    cost,
+                    ret_vals: new_ret_vals,
+                })
+            }
+            NativeResult::Abort { cost, abort_code } => Ok(NativeResult::Success {
+                cost,
+                ret_vals: smallvec![Value::u64(0), empty_events()?, Value::u64(abort_code)],
+            }),
+            NativeResult::OutOfGas { partial_cost } => Ok(NativeResult::OutOfGas { partial_cost }),
+        },
+        PartialVMResult::Err(err) => {
+            warn!("execute_wasm_function_v2_inner vm_error: {:?}", err);
+
+            Ok(NativeResult::Success {
+                cost: gas_params.base_create_execution,
+                ret_vals: smallvec![Value::u64(0), empty_events()?, Value::u64(E_VM_ERROR)],
+            })
+        }
+    }
+}
+
+fn pack_events(events: Vec<Vec<u8>>) -> PartialVMResult<Value> {
+    Vector::pack(
+        &Type::Vector(Box::new(Type::U8)),
+        events.into_iter().map(Value::vector_u8).collect(),
+    )
+}
+
+fn empty_events() -> PartialVMResult<Value> {
+    pack_events(vec![])
+}
+
+/// Resolve the BCS result of a view function from the native context.
+type ViewFunctionResolver = fn(&NativeContext) -> anyhow::Result<Vec<u8>>;
+
+/// The view functions which can be called by the WASM code through the `rooch::call_view_function` host function.
+/// A native function can not call back into the Move VM, so every view function is resolved from the same state
+/// the Move function reads, only when the WASM code calls it.
+static VIEW_FUNCTIONS: Lazy<BTreeMap<FunctionId, ViewFunctionResolver>> = Lazy::new(|| {
+    let view_functions: [(&str, ViewFunctionResolver); 2] = [
+        ("0x2::timestamp::now_milliseconds", |context| {
+            let object_context = context.extensions().get::<ObjectRuntimeContext>();
+            let timestamp = object_context.object_runtime().read().timestamp()?;
+            Ok(bcs::to_bytes(&timestamp.milliseconds)?)
+        }),
+        ("0x2::tx_context::sender", |context| {
+            let object_context = context.extensions().get::<ObjectRuntimeContext>();
+            let sender = object_context.object_runtime().read().tx_context().sender();
+            Ok(bcs::to_bytes(&sender)?)
+        }),
+    ];
+    view_functions
+        .into_iter()
+        .map(|(function_id, resolver)| {
+            let function_id =
+                FunctionId::from_str(function_id).expect("the view function id should be valid");
+            (function_id, resolver)
+        })
+        .collect()
+});
+
+/// The WASM host backed by the native context of the Move VM.
+struct NativeWASMHost<'a, 'b, 'c> {
+    context: &'a NativeContext<'b, 'c>,
+}
+
+impl WASMHost for NativeWASMHost<'_, '_, '_> {
+    fn get_object(&mut self, object_id: &[u8]) -> anyhow::Result<Option<Vec<u8>>> {
+        let object_id: ObjectID = bcs::from_bytes(object_id)?;
+        let object_context = self.context.extensions().get::<ObjectRuntimeContext>();
+        let binding = object_context.object_runtime();
+        let mut object_runtime = binding.write();
+        let (object, _) = object_runtime.load_object(self.context, &object_id)?;
+        if !object.exists()? {
+            return Ok(None);
+        }
+        let layout =
+            TypeLayoutLoader::get_type_layout(self.context, &object.metadata()?.object_type)?;
+        let value = object
+            .borrow_value(None)?
+            .value_as::<StructRef>()?
+            .read_ref()?;
+        let data = value
+            .simple_serialize(&layout)
+            .ok_or_else(|| anyhow::anyhow!("Failed to serialize the object {}", object_id))?;
+        Ok(Some(data))
+    }
+
+    fn call_view_function(&mut self, function_id: &str) -> anyhow::Result<Vec<u8>> {
+        let function_id = FunctionId::from_str(function_id)?;
+        let resolver = VIEW_FUNCTIONS.get(&function_id).ok_or_else(|| {
+            anyhow::anyhow!("The function {} is not allowed to be called", function_id)
+        })?;
+        resolver(self.context)
+    }
+}
+
+fn execute_wasm_function_v2_inner(
+    gas_params: &WASMExecuteGasParameters,
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    if args.len() != 3 {
+        return Ok(NativeResult::err(
+            gas_params.base_create_execution,
+            E_INCORRECT_LENGTH_OF_ARGS,
+        ));
+    }
+
+    let func_args = pop_arg!(args, Vec<u64>);
+    let func_name = pop_arg!(args, Vec<u8>);
+    let instance_id = pop_arg!(args, u64);
+
+    let host_gas_parameters = HostGasParameters {
+        base: u64::from(gas_params.host_call_base.unwrap_or_else(InternalGas::zero)),
+        per_byte: u64::from(
+            gas_params
+                .host_call_per_byte
+                .unwrap_or_else(InternalGasPerByte::zero),
+        ),
+    };
+
+    let instance_pool = get_instance_pool();
+    let mut pool_object = match instance_pool.lock() {
+        Ok(v) => v,
+        Err(e) => {
+            warn!(
+                "execute_wasm_function_v2_inner->instance_pool_lock_error: {:?}",
+                &e
+            );
+
+            return Ok(NativeResult::err(
+                gas_params.base_create_execution,
+                E_GET_INSTANCE_POOL_FAILED,
+            ));
+        }
+    };
+
+    let calling_function = match pool_object.get(&instance_id) {
+        None => {
+            return Ok(NativeResult::err(
+                gas_params.base_create_execution,
+                E_INSTANCE_NO_EXISTS,
+            ))
+        }
+        Some(instance) => match instance
+            .instance
+            .exports
+            .get_function(&String::from_utf8_lossy(func_name.as_slice()))
+        {
+            Ok(calling_function) => calling_function.clone(),
+            Err(err) => {
+                warn!(
+                    "execute_wasm_function_v2_inner->get_function_error:{:?}",
+                    &err
+                );
+
+                return Ok(NativeResult::err(
+                    gas_params.base_create_execution,
+                    E_WASM_FUNCTION_NOT_FOUND,
+                ));
+            }
+        },
+    };
+    // The instance is moved to the WASM executor during the call, and put back after the call
+    let instance = pool_object
+        .remove(&instance_id)
+        .expect("the instance should exist");
+    instance.gas_meter.lock().unwrap().reset();
+
+    let mut wasm_func_args = Vec::with_capacity(func_args.len());
+    for arg in func_args.iter() {
+        wasm_func_args.push(wasmer::Value::I32(*arg as i32));
+    }
+
+    let mut host = NativeWASMHost { context: &*context };
+    let (instance, call_result) = instance.call_with_host(
+        &calling_function,
+        wasm_func_args.as_slice(),
+        host_gas_parameters,
+        &mut host,
+    );
+
+    // The WASM gas is charged whether the call succeeds or not,
+    // including the gas charged by the host functions
+    let gas_used = instance.gas_meter.lock().unwrap().used();
+    debug!("execute_wasm_function_v2_inner->gas_used: {:?}", gas_used);
+    pool_object.insert(instance_id, instance);
+    let mut cost = gas_params.base_create_execution;
+    cost += gas_params.per_execution_point * NumBytes::new(gas_used);
+
+    match call_result {
+        Ok((ret, events)) => {
+            let return_value = match ret.deref().first() {
+                Some(v) => v,
+                None => return build_err(cost, E_EMPTY_RETURN_VALUE),
+            };
+            let offset = match return_value.i32() {
+                Some(v) => v,
+                None => return build_err(cost, E_VALUE_NOT_I32),
+            };
+            let ret_val = Value::u64(offset as u64);
+
+            Ok(NativeResult::Success {
+                cost,
+                ret_vals: smallvec![ret_val, pack_events(events)?],
+            })
+        }
+        Err(err) => {
+            warn!(
+                "execute_wasm_function_v2_inner->calling_function_error:{}",
+                err.message()
+            );
+            if tracing::enabled!(tracing::Level::DEBUG) {
+                debug!("trace:{:?}", err.trace());
+            }
+            Ok(NativeResult::err(cost, E_WASM_EXECUTION_FAILED))
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct WASMReadAddLength {
     pub base: InternalGas,
@@ -844,10 +1095,17 @@ pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, Nati
         (
             "native_execute_wasm_function",
             make_native(
-                gas_params.function_execution_gas_parameter,
+                gas_params.function_execution_gas_parameter.clone(),
                 native_execute_wasm_function,
             ),
         ),
+        (
+            "native_execute_wasm_function_v2",
+            make_native(
+                gas_params.function_execution_gas_parameter,
+                native_execute_wasm_function_v2,
+            ),
+        ),
         (
             "native_read_data_length",
             make_native(
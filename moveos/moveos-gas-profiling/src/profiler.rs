@@ -68,6 +68,14 @@ impl<G> GasProfiler<G> {
             metering: true,
         }
     }
+
+    pub fn new_script(base: G) -> Self {
+        Self {
+            base,
+            frames: Arc::new(RwLock::new(vec![CallFrame::new_script()])),
+            metering: true,
+        }
+    }
 }
 
 impl<G: GasMeter> GasProfiler<G> {
@@ -273,6 +281,7 @@ impl<G: GasMeter> GasMeter for GasProfiler<G> {
         // and we need to convert the current CallFrame into a GasEvent.
         // [call_frame_1, call_frame_2, call_frame_3]
         // [call_frame_1, call_frame_2(events: [Bytecode::Op, Call(call_frame_3)])]
+        // The calls are dropped if the metering is stopped, such as the system pre_execute functions.
         if matches!(instr, SimpleInstruction::Ret) && self.frames.read().unwrap().len() > 1 {
             let cur_frame = self
                 .frames
@@ -280,9 +289,7 @@ impl<G: GasMeter> GasMeter for GasProfiler<G> {
                 .unwrap()
                 .pop()
                 .expect("frame must exist");
-            let mut call_frames = self.frames.write().unwrap();
-            let last_frame = call_frames.last_mut().expect("frame must exist");
-            last_frame.events.push(ExecutionGasEvent::Call(cur_frame));
+            self.record_gas_event(ExecutionGasEvent::Call(cur_frame));
         }
 
         res
@@ -405,12 +412,20 @@ impl<G: GasMeter> GasMeter for GasProfiler<G> {
         //
         // Later when it realizes the function is native, it will transform the original frame
         // into a native-specific event that does not contain recursive structures.
-        let cur = self
-            .frames
-            .write()
-            .unwrap()
-            .pop()
-            .expect("frame must exist");
+        let cur = {
+            let mut frames = self.frames.write().unwrap();
+            // The native function is called directly by the transaction, such as a call of a batch,
+            // there is no frame created for it, keep the root frame and record the cost in it.
+            if frames.len() > 1 {
+                frames.pop()
+            } else {
+                None
+            }
+        };
+        let Some(cur) = cur else {
+            self.record_bytecode(Opcodes::CALL, cost);
+            return res;
+        };
         let (module_id, name, ty_args) = match cur.name {
             FrameName::Function {
                 module_id,
@@ -446,7 +461,7 @@ pub trait ProfileGasMeter {
     fn finish(&mut self) -> TransactionGasLog;
 }
 
-impl<G: GasMeter> ProfileGasMeter for GasProfiler<G> {
+impl<G: SwitchableGasMeter> ProfileGasMeter for GasProfiler<G> {
     fn finish(&mut self) -> TransactionGasLog {
         while self.frames.read().unwrap().len() > 1 {
             let cur = self
@@ -479,33 +494,32 @@ impl<G: GasMeter> ProfileGasMeter for GasProfiler<G> {
     }
 }
 
-impl<G: GasMeter> ClassifiedGasMeter for GasProfiler<G> {
-    fn charge_execution(&mut self, _gas_cost: u64) -> PartialVMResult<()> {
-        Ok(())
+impl<G: GasMeter + ClassifiedGasMeter> ClassifiedGasMeter for GasProfiler<G> {
+    fn charge_execution(&mut self, gas_cost: u64) -> PartialVMResult<()> {
+        self.base.charge_execution(gas_cost)
     }
 
-    fn charge_io_write(&mut self, _data_size: u64) -> PartialVMResult<()> {
-        Ok(())
+    fn charge_io_write(&mut self, data_size: u64) -> PartialVMResult<()> {
+        self.base.charge_io_write(data_size)
     }
 
-    fn check_constrains(&self, _max_gas_amount: u64) -> PartialVMResult<()> {
-        Ok(())
+    fn check_constrains(&self, max_gas_amount: u64) -> PartialVMResult<()> {
+        self.base.check_constrains(max_gas_amount)
     }
 
     fn gas_statement(&self) -> GasStatement {
-        GasStatement {
-            execution_gas_used: InternalGas::zero(),
-            storage_gas_used: InternalGas::zero(),
-        }
+        self.base.gas_statement()
     }
 }
 
-impl<G: GasMeter> SwitchableGasMeter for GasProfiler<G> {
+impl<G: SwitchableGasMeter> SwitchableGasMeter for GasProfiler<G> {
     fn stop_metering(&mut self) {
+        self.base.stop_metering();
         self.metering = false;
     }
 
     fn start_metering(&mut self) {
+        self.base.start_metering();
         self.metering = true;
     }
 
@@ -516,13 +530,15 @@ impl<G: GasMeter> SwitchableGasMeter for GasProfiler<G> {
 
 pub fn new_gas_profiler<G>(action: MoveAction, base_gas_meter: G) -> GasProfiler<G> {
     match action {
-        MoveAction::Script(_) => unreachable!("Script payload is not supported yet"),
         MoveAction::Function(call) => GasProfiler::new_function(
             base_gas_meter,
             call.function_id.module_id,
             call.function_id.function_name,
             call.ty_args,
         ),
-        MoveAction::ModuleBundle(_) => unreachable!("ModuleBundle payload is not supported yet"),
+        // There is no single entry function, the gas events are recorded in the script frame.
+        MoveAction::Script(_) | MoveAction::ModuleBundle(_) | MoveAction::Batch(_) => {
+            GasProfiler::new_script(base_gas_meter)
+        }
     }
 }
@@ -6,12 +6,14 @@
 
 use crate::config_store::{ConfigDBStore, ConfigStore, STARTUP_INFO_KEY};
 use crate::event_store::{EventDBStore, EventStore};
+use crate::prune_store::PruneDBStore;
 use crate::state_store::statedb::StateDBStore;
 use crate::state_store::{nodes_to_write_batch, NodeDBStore};
 use crate::transaction_store::{TransactionDBStore, TransactionStore};
 use accumulator::inmemory::InMemoryAccumulator;
 use anyhow::{Error, Result};
 use bcs::to_bytes;
+use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::StructTag;
 use moveos_config::store_config::{MoveOSStoreConfig, RocksdbConfig};
 use moveos_config::DataDirPath;
@@ -33,13 +35,15 @@ use raw_store::rocks::batch::{WriteBatch, WriteBatchCF};
 use raw_store::rocks::RocksDB;
 use raw_store::traits::DBStore;
 use raw_store::{ColumnFamilyName, SchemaStore, StoreInstance, WriteOp};
-use smt::NodeReader;
+use smt::{NodeReader, SPARSE_MERKLE_PLACEHOLDER_HASH};
+use std::collections::{BTreeSet, HashMap};
 use std::fmt::{Debug, Display, Formatter};
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 pub mod config_store;
 pub mod event_store;
+pub mod prune_store;
 pub mod state_store;
 #[cfg(test)]
 mod tests;
@@ -53,6 +57,9 @@ pub const EVENT_COLUMN_FAMILY_NAME: ColumnFamilyName = "event";
 pub const EVENT_HANDLE_COLUMN_FAMILY_NAME: ColumnFamilyName = "event_handle";
 pub const CONFIG_STARTUP_INFO_COLUMN_FAMILY_NAME: ColumnFamilyName = "config_startup_info";
 pub const CONFIG_GENESIS_COLUMN_FAMILY_NAME: ColumnFamilyName = "config_genesis";
+pub const STATE_NODE_STALE_INDEX_COLUMN_FAMILY_NAME: ColumnFamilyName = "state_node_stale_index";
+pub const STATE_NODE_REF_COUNT_COLUMN_FAMILY_NAME: ColumnFamilyName = "state_node_ref_count";
+pub const CONFIG_STATE_PRUNE_COLUMN_FAMILY_NAME: ColumnFamilyName = "config_state_prune";
 
 /// db store use cf_name vec to init
 /// Please note that adding a column family needs to be added in vec simultaneously, remember！！
@@ -64,6 +71,9 @@ static VEC_COLUMN_FAMILY_NAME: Lazy<Vec<ColumnFamilyName>> = Lazy::new(|| {
         EVENT_HANDLE_COLUMN_FAMILY_NAME,
         CONFIG_STARTUP_INFO_COLUMN_FAMILY_NAME,
         CONFIG_GENESIS_COLUMN_FAMILY_NAME,
+        STATE_NODE_STALE_INDEX_COLUMN_FAMILY_NAME,
+        STATE_NODE_REF_COUNT_COLUMN_FAMILY_NAME,
+        CONFIG_STATE_PRUNE_COLUMN_FAMILY_NAME,
     ]
 });
 
@@ -83,6 +93,9 @@ pub struct MoveOSStore {
     pub transaction_store: TransactionDBStore,
     pub config_store: ConfigDBStore,
     pub state_store: StateDBStore,
+    pub prune_store: PruneDBStore,
+    // The node reference counts are updated by both the tx output and the pruner
+    node_ref_count_lock: Arc<Mutex<()>>,
 }
 
 impl MoveOSStore {
@@ -109,8 +122,10 @@ impl MoveOSStore {
             node_store,
             event_store: EventDBStore::new(instance.clone()),
             transaction_store: TransactionDBStore::new(instance.clone()),
-            config_store: ConfigDBStore::new(instance),
+            config_store: ConfigDBStore::new(instance.clone()),
             state_store,
+            prune_store: PruneDBStore::new(instance),
+            node_ref_count_lock: Arc::new(Mutex::new(())),
         };
         Ok(store)
     }
@@ -143,9 +158,14 @@ impl MoveOSStore {
         &self.state_store
     }
 
+    pub fn get_prune_store(&self) -> &PruneDBStore {
+        &self.prune_store
+    }
+
     pub fn handle_tx_output(
         &self,
         tx_hash: H256,
+        gas_payer: AccountAddress,
         output: RawTransactionOutput,
     ) -> Result<(TransactionOutput, TransactionExecutionInfo)> {
         let RawTransactionOutput {
@@ -158,7 +178,7 @@ impl MoveOSStore {
         } = output;
 
         // node_store updates
-        let changed_nodes = self.state_store.change_set_to_nodes(&mut changeset)?;
+        let tree_change_set = self.state_store.change_set_to_nodes(&mut changeset)?;
         // transaction_store updates
         let new_state_root = changeset.state_root;
         let size = changeset.global_size;
@@ -178,6 +198,7 @@ impl MoveOSStore {
             event_root,
             gas_used,
             status.clone(),
+            gas_payer,
         );
         // config_store updates
         let new_startup_info = StartupInfo::new(new_state_root, size);
@@ -194,9 +215,41 @@ impl MoveOSStore {
         }
 
         // atomic save updates
+        let _guard = self
+            .node_ref_count_lock
+            .lock()
+            .map_err(|e| anyhow::anyhow!("Failed to lock node ref count: {:?}", e))?;
         let inner_store = self.node_store.get_store().store();
         let mut cf_batches: Vec<WriteBatchCF> = Vec::new();
-        let write_batch = nodes_to_write_batch(changed_nodes);
+        // A node may be written again after it became stale, or shared by different trees,
+        // count every reference so that the pruner does not delete a node still in use.
+        let mut ref_count_rows = vec![];
+        for (node_hash, new_refs) in &tree_change_set.node_ref_counts {
+            let ref_count = match self.prune_store.get_ref_count(*node_hash)? {
+                Some(ref_count) => ref_count + new_refs,
+                // The node was written without reference counting, its reference count is unknown,
+                // keep it unknown so that the pruner never deletes it.
+                None if self.node_store.contains(node_hash)? => continue,
+                None => *new_refs,
+            };
+            ref_count_rows.push((to_bytes(node_hash)?, WriteOp::Value(to_bytes(&ref_count)?)));
+        }
+        if !ref_count_rows.is_empty() {
+            cf_batches.push(WriteBatchCF {
+                batch: WriteBatch::new_with_rows(ref_count_rows),
+                cf_name: STATE_NODE_REF_COUNT_COLUMN_FAMILY_NAME.to_string(),
+            });
+        }
+        if !tree_change_set.stale_nodes.is_empty() {
+            cf_batches.push(WriteBatchCF {
+                batch: WriteBatch::new_with_rows(vec![(
+                    to_bytes(&tx_hash)?,
+                    WriteOp::Value(to_bytes(&tree_change_set.stale_nodes)?),
+                )]),
+                cf_name: STATE_NODE_STALE_INDEX_COLUMN_FAMILY_NAME.to_string(),
+            });
+        }
+        let write_batch = nodes_to_write_batch(tree_change_set.nodes);
         cf_batches.push(WriteBatchCF {
             batch: write_batch,
             cf_name: STATE_NODE_COLUMN_FAMILY_NAME.to_string(),
@@ -224,6 +277,106 @@ impl MoveOSStore {
 
         Ok((out, execution_info))
     }
+
+    /// Delete the state nodes which became stale after the given transactions,
+    /// the state roots before these transactions can not be read after pruning.
+    /// Returns the number of deleted nodes.
+    ///
+    /// Only the state written after reference counting was introduced can be pruned:
+    /// the transactions executed before have no stale node index, and the nodes written before
+    /// have an unknown reference count, so they are never deleted, even when they become stale later.
+    /// Reclaiming the space of an existing database requires re-syncing or restoring it from a snapshot.
+    pub fn prune_stale_nodes(&self, tx_hashes: Vec<H256>) -> Result<u64> {
+        let _guard = self
+            .node_ref_count_lock
+            .lock()
+            .map_err(|e| anyhow::anyhow!("Failed to lock node ref count: {:?}", e))?;
+        let stale_indexes = self.prune_store.multi_get_stale_nodes(tx_hashes.clone())?;
+        let mut ref_counts: HashMap<H256, u64> = HashMap::new();
+        let mut deleted_nodes = BTreeSet::new();
+        for node_hash in stale_indexes.into_iter().flatten().flatten() {
+            let ref_count = match ref_counts.get(&node_hash) {
+                Some(ref_count) => *ref_count,
+                None => match self.prune_store.get_ref_count(node_hash)? {
+                    Some(ref_count) => ref_count,
+                    // The reference count is unknown, the node may be still in use
+                    None => continue,
+                },
+            };
+            if ref_count == 0 {
+                continue;
+            }
+            if ref_count == 1 {
+                deleted_nodes.insert(node_hash);
+            }
+            ref_counts.insert(node_hash, ref_count - 1);
+        }
+
+        let ref_count_rows = ref_counts
+            .into_iter()
+            .map(|(node_hash, ref_count)| {
+                let op = if ref_count > 0 {
+                    WriteOp::Value(to_bytes(&ref_count)?)
+                } else {
+                    WriteOp::Deletion
+                };
+                Ok((to_bytes(&node_hash)?, op))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let stale_index_rows = tx_hashes
+            .iter()
+            .map(|tx_hash| Ok((to_bytes(tx_hash)?, WriteOp::Deletion)))
+            .collect::<Result<Vec<_>>>()?;
+        let node_rows = deleted_nodes
+            .iter()
+            .map(|node_hash| (node_hash.0.to_vec(), WriteOp::Deletion))
+            .collect::<Vec<_>>();
+        let deleted_count = node_rows.len() as u64;
+
+        let cf_batches = vec![
+            WriteBatchCF::new_with_rows(
+                ref_count_rows,
+                STATE_NODE_REF_COUNT_COLUMN_FAMILY_NAME.to_string(),
+            ),
+            WriteBatchCF::new_with_rows(
+                stale_index_rows,
+                STATE_NODE_STALE_INDEX_COLUMN_FAMILY_NAME.to_string(),
+            ),
+            WriteBatchCF::new_with_rows(node_rows, STATE_NODE_COLUMN_FAMILY_NAME.to_string()),
+        ];
+        self.node_store
+            .get_store()
+            .store()
+            .write_cf_batch(cf_batches, false)?;
+        Ok(deleted_count)
+    }
+
+    /// Ensure the state after the tx order has not been pruned
+    pub fn ensure_state_not_pruned(&self, tx_order: u64) -> Result<()> {
+        let pruned_tx_order = self.prune_store.get_pruned_tx_order()?.unwrap_or(0);
+        if tx_order < pruned_tx_order {
+            return Err(anyhow::anyhow!(
+                "The state at tx_order {} has been pruned, the states before tx_order {} are not available",
+                tx_order,
+                pruned_tx_order
+            ));
+        }
+        Ok(())
+    }
+
+    /// Ensure the state at the state root has not been pruned,
+    /// the root node of a pruned state is always deleted by the pruner.
+    pub fn ensure_state_root_not_pruned(&self, state_root: H256) -> Result<()> {
+        if state_root != *SPARSE_MERKLE_PLACEHOLDER_HASH
+            && !self.node_store.contains(&state_root)?
+        {
+            return Err(anyhow::anyhow!(
+                "The state at state_root {} does not exist or has been pruned",
+                state_root
+            ));
+        }
+        Ok(())
+    }
 }
 
 impl Display for MoveOSStore {
@@ -0,0 +1,81 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    CONFIG_STATE_PRUNE_COLUMN_FAMILY_NAME, STATE_NODE_REF_COUNT_COLUMN_FAMILY_NAME,
+    STATE_NODE_STALE_INDEX_COLUMN_FAMILY_NAME,
+};
+use anyhow::Result;
+use moveos_types::h256::H256;
+use raw_store::{derive_store, CodecKVStore, StoreInstance};
+use std::string::ToString;
+
+pub const PRUNED_TX_ORDER_KEY: &str = "pruned_tx_order";
+
+// tx_hash -> the state nodes which became stale after the tx
+derive_store!(
+    StaleNodeIndexStore,
+    H256,
+    Vec<H256>,
+    STATE_NODE_STALE_INDEX_COLUMN_FAMILY_NAME
+);
+// node_hash -> the reference count of the node, the count of a node written before the pruner is unknown
+derive_store!(
+    NodeRefCountStore,
+    H256,
+    u64,
+    STATE_NODE_REF_COUNT_COLUMN_FAMILY_NAME
+);
+derive_store!(
+    PrunedTxOrderStore,
+    String,
+    u64,
+    CONFIG_STATE_PRUNE_COLUMN_FAMILY_NAME
+);
+
+#[derive(Clone)]
+pub struct PruneDBStore {
+    stale_index_store: StaleNodeIndexStore,
+    ref_count_store: NodeRefCountStore,
+    pruned_tx_order_store: PrunedTxOrderStore,
+}
+
+impl PruneDBStore {
+    pub fn new(instance: StoreInstance) -> Self {
+        PruneDBStore {
+            stale_index_store: StaleNodeIndexStore::new(instance.clone()),
+            ref_count_store: NodeRefCountStore::new(instance.clone()),
+            pruned_tx_order_store: PrunedTxOrderStore::new(instance),
+        }
+    }
+
+    pub fn get_stale_nodes(&self, tx_hash: H256) -> Result<Option<Vec<H256>>> {
+        self.stale_index_store.kv_get(tx_hash)
+    }
+
+    pub fn multi_get_stale_nodes(&self, tx_hashes: Vec<H256>) -> Result<Vec<Option<Vec<H256>>>> {
+        self.stale_index_store.multiple_get(tx_hashes)
+    }
+
+    pub fn remove_stale_nodes(&self, tx_hash: H256) -> Result<()> {
+        self.stale_index_store.remove(tx_hash)
+    }
+
+    /// Get the reference count of the node, None means the reference count is unknown
+    /// and the node must not be deleted.
+    pub fn get_ref_count(&self, node_hash: H256) -> Result<Option<u64>> {
+        self.ref_count_store.kv_get(node_hash)
+    }
+
+    /// Get the tx order of the last pruned transaction,
+    /// the states before this tx order are incomplete.
+    pub fn get_pruned_tx_order(&self) -> Result<Option<u64>> {
+        self.pruned_tx_order_store
+            .kv_get(PRUNED_TX_ORDER_KEY.to_string())
+    }
+
+    pub fn save_pruned_tx_order(&self, tx_order: u64) -> Result<()> {
+        self.pruned_tx_order_store
+            .put_sync(PRUNED_TX_ORDER_KEY.to_string(), tx_order)
+    }
+}
@@ -2,14 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod metrics;
+pub mod overlay;
 pub mod statedb;
 
 use crate::STATE_NODE_COLUMN_FAMILY_NAME;
 use anyhow::Result;
 use moveos_types::h256::H256;
 use raw_store::rocks::batch::WriteBatch;
-use raw_store::CodecKVStore;
+use raw_store::traits::KVStore;
 use raw_store::{derive_store, WriteOp};
+use raw_store::{CodecKVStore, SchemaStore};
 use smt::{NodeReader, NodeWriter};
 use std::collections::BTreeMap;
 
@@ -29,6 +31,10 @@ impl NodeDBStore {
         );
         self.write_batch_raw(batch)
     }
+
+    pub fn contains(&self, hash: &H256) -> Result<bool> {
+        KVStore::contains_key(self.get_store(), hash.as_bytes())
+    }
 }
 
 pub fn nodes_to_write_batch(nodes: BTreeMap<H256, Vec<u8>>) -> WriteBatch {
@@ -0,0 +1,165 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::state_store::statedb::StateDBStore;
+use crate::state_store::NodeDBStore;
+use anyhow::{format_err, Error, Result};
+use move_core_types::effects::Op;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::object::{ObjectID, ObjectMeta, GENESIS_STATE_ROOT};
+use moveos_types::state::{FieldKey, ObjectChange, ObjectState, StateChangeSet};
+use moveos_types::state_override::ObjectOverride;
+use moveos_types::state_resolver::{RootObjectResolver, StateKV, StateResolver, StatelessResolver};
+use prometheus::Registry;
+use smt::{NodeReader, SMTree};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// OverlayNodeStore reads the in-memory nodes first, then falls back to the node store.
+#[derive(Clone)]
+pub struct OverlayNodeStore {
+    nodes: Arc<BTreeMap<H256, Vec<u8>>>,
+    node_store: NodeDBStore,
+}
+
+impl NodeReader for OverlayNodeStore {
+    fn get(&self, hash: &H256) -> Result<Option<Vec<u8>>> {
+        match self.nodes.get(hash) {
+            Some(node) => Ok(Some(node.clone())),
+            None => NodeReader::get(&self.node_store, hash),
+        }
+    }
+}
+
+/// StateOverlay applies the object overrides on top of a state root.
+/// The SMT nodes of the overrides are kept in memory, so the overrides never reach the state store.
+pub struct StateOverlay {
+    root: ObjectMeta,
+    smt: SMTree<FieldKey, ObjectState, OverlayNodeStore>,
+}
+
+impl StateOverlay {
+    pub fn new(
+        state_store: &StateDBStore,
+        root: ObjectMeta,
+        overrides: Vec<ObjectOverride>,
+    ) -> Result<Self> {
+        let resolver = RootObjectResolver::new(root.clone(), state_store);
+        let mut change_set = StateChangeSet::new(root.state_root(), root.size);
+        for object_override in overrides {
+            let current = resolver.get_object(&object_override.id)?;
+            let exists = current.is_some();
+            let state = object_override.into_object_state(current)?;
+            let path = object_path(&state.metadata.id);
+            apply_override(
+                &mut change_set.changes,
+                &mut change_set.global_size,
+                &resolver,
+                &path,
+                state,
+                exists,
+            )?;
+        }
+        let nodes = state_store.change_set_to_nodes(&mut change_set)?;
+        let node_store = OverlayNodeStore {
+            nodes: Arc::new(nodes),
+            node_store: state_store.node_store.clone(),
+        };
+        Ok(Self {
+            root: change_set.root_metadata(),
+            smt: SMTree::new(node_store, &Registry::new()),
+        })
+    }
+
+    /// The root object with the overrides applied
+    pub fn root(&self) -> &ObjectMeta {
+        &self.root
+    }
+}
+
+impl StatelessResolver for StateOverlay {
+    fn get_field_at(&self, state_root: H256, key: &FieldKey) -> Result<Option<ObjectState>, Error> {
+        if state_root == *GENESIS_STATE_ROOT {
+            return Ok(None);
+        }
+        self.smt.get(state_root, *key)
+    }
+
+    fn list_fields_at(
+        &self,
+        state_root: H256,
+        cursor: Option<FieldKey>,
+        limit: usize,
+    ) -> Result<Vec<StateKV>> {
+        self.smt.list(state_root, cursor, limit)
+    }
+}
+
+/// The object ids from the child of root object to the object
+fn object_path(id: &ObjectID) -> Vec<ObjectID> {
+    let mut path = vec![id.clone()];
+    while let Some(parent) = path.last().and_then(|id| id.parent()) {
+        if parent.is_root() {
+            break;
+        }
+        path.push(parent);
+    }
+    path.reverse();
+    path
+}
+
+fn apply_override(
+    changes: &mut BTreeMap<FieldKey, ObjectChange>,
+    parent_size: &mut u64,
+    resolver: &dyn StateResolver,
+    path: &[ObjectID],
+    state: ObjectState,
+    exists: bool,
+) -> Result<()> {
+    let (id, rest) = path
+        .split_first()
+        .ok_or_else(|| format_err!("The override object path is empty"))?;
+    let key = id.field_key();
+    if rest.is_empty() {
+        let (metadata, value) = state.into_inner();
+        match changes.get_mut(&key) {
+            // The object is already changed as the parent of another override, keep its field changes
+            Some(change) => {
+                let size = change.metadata.size;
+                change.value = match change.value {
+                    Some(Op::New(_)) => Some(Op::New(value)),
+                    _ => Some(Op::Modify(value)),
+                };
+                change.metadata = metadata;
+                change.metadata.size = size;
+            }
+            None => {
+                let op = if exists {
+                    Op::Modify(value)
+                } else {
+                    *parent_size += 1;
+                    Op::New(value)
+                };
+                changes.insert(key, ObjectChange::new(metadata, op));
+            }
+        }
+        return Ok(());
+    }
+    if !changes.contains_key(&key) {
+        let parent = resolver
+            .get_object(id)?
+            .ok_or_else(|| format_err!("The parent object {} of the override is not found", id))?;
+        changes.insert(key, ObjectChange::meta(parent.metadata));
+    }
+    let change = changes
+        .get_mut(&key)
+        .expect("The parent object change should exist");
+    apply_override(
+        &mut change.fields,
+        &mut change.metadata.size,
+        resolver,
+        rest,
+        state,
+        exists,
+    )
+}
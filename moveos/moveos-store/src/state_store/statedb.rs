@@ -3,25 +3,26 @@
 
 use crate::state_store::metrics::StateDBMetrics;
 use crate::state_store::NodeDBStore;
-use anyhow::{Error, Ok, Result};
+use anyhow::{ensure, Error, Ok, Result};
 use function_name::named;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::effects::Op;
 use moveos_types::h256::H256;
-use moveos_types::moveos_std::object::GENESIS_STATE_ROOT;
+use moveos_types::moveos_std::object::{ObjectID, GENESIS_STATE_ROOT};
 use moveos_types::state::FieldKey;
 use moveos_types::state::ObjectChange;
 use moveos_types::state::ObjectState;
 use moveos_types::state::StateChangeSet;
+use moveos_types::state_proof::{FieldProof, StateProof};
 use moveos_types::state_resolver::RootObjectResolver;
 use moveos_types::state_resolver::StateKV;
 use moveos_types::state_resolver::StateResolver;
 use moveos_types::state_resolver::StatelessResolver;
 use prometheus::Registry;
 use quick_cache::sync::Cache;
-use smt::{SMTIterator, TreeChangeSet};
+use smt::{SMTIterator, SparseMerkleProof, TreeChangeSet};
 use smt::{SMTree, UpdateSet};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 use std::sync::Arc;
 
 pub const STATEDB_DUMP_BATCH_SIZE: usize = 5000;
@@ -87,7 +88,7 @@ impl StateDBStore {
     fn apply_object_change(
         &self,
         resolver: &dyn StateResolver,
-        nodes: &mut BTreeMap<H256, Vec<u8>>,
+        tree_change_set: &mut TreeChangeSet,
         update_set: &mut UpdateSet<FieldKey, ObjectState>,
         field_key: FieldKey,
         obj_change: &mut ObjectChange,
@@ -120,15 +121,15 @@ impl StateDBStore {
         for (child_field_key, child_change) in &mut obj_change.fields {
             self.apply_object_change(
                 resolver,
-                nodes,
+                tree_change_set,
                 &mut field_update_set,
                 *child_field_key,
                 child_change,
             )?;
         }
-        let mut tree_change_set = self.update_fields(obj.state_root(), field_update_set)?;
-        nodes.append(&mut tree_change_set.nodes);
-        let new_state_root = tree_change_set.state_root;
+        let field_change_set = self.update_fields(obj.state_root(), field_update_set)?;
+        let new_state_root = field_change_set.state_root;
+        tree_change_set.merge(field_change_set);
         obj.update_state_root(new_state_root);
         obj_change.update_state_root(new_state_root);
         update_set.put(field_key, obj.clone());
@@ -137,11 +138,12 @@ impl StateDBStore {
         Ok(())
     }
 
+    /// Convert the state change set to the tree nodes to write and the stale nodes of the pre state root.
     #[named]
     pub fn change_set_to_nodes(
         &self,
         state_change_set: &mut StateChangeSet,
-    ) -> Result<BTreeMap<H256, Vec<u8>>> {
+    ) -> Result<TreeChangeSet> {
         let fn_name = function_name!();
         let _timer = self
             .metrics
@@ -156,11 +158,11 @@ impl StateDBStore {
         let resolver = RootObjectResolver::new(root, self);
 
         let mut update_set = UpdateSet::new();
-        let mut nodes = BTreeMap::new();
+        let mut changes = TreeChangeSet::new(pre_state_root, BTreeMap::new());
         for (field_key, obj_change) in &mut state_change_set.changes {
             self.apply_object_change(
                 &resolver,
-                &mut nodes,
+                &mut changes,
                 &mut update_set,
                 *field_key,
                 obj_change,
@@ -176,14 +178,16 @@ impl StateDBStore {
                 k_len + v_len
             })
             .sum::<usize>();
-        let mut tree_change_set = self.update_fields(pre_state_root, update_set)?;
+        let tree_change_set = self.update_fields(pre_state_root, update_set)?;
         let new_state_root = tree_change_set.state_root;
-        nodes.append(&mut tree_change_set.nodes);
+        changes.state_root = new_state_root;
+        changes.merge(tree_change_set);
         if tracing::enabled!(tracing::Level::DEBUG) {
             tracing::debug!(
-                "apply_change_set new_state_root: {:?}, smt nodes: {}, new_global_size: {}",
+                "apply_change_set new_state_root: {:?}, smt nodes: {}, stale nodes: {}, new_global_size: {}",
                 new_state_root,
-                nodes.len(),
+                changes.nodes.len(),
+                changes.stale_nodes.len(),
                 global_size
             );
         }
@@ -193,12 +197,82 @@ impl StateDBStore {
             .state_change_set_to_nodes_bytes
             .with_label_values(&[fn_name])
             .observe(size as f64);
-        Ok(nodes)
+        Ok(changes)
     }
 
     pub fn apply_change_set(&self, state_change_set: &mut StateChangeSet) -> Result<()> {
-        let nodes = self.change_set_to_nodes(state_change_set)?;
-        self.node_store.write_nodes(nodes)?;
+        let tree_change_set = self.change_set_to_nodes(state_change_set)?;
+        self.node_store.write_nodes(tree_change_set.nodes)?;
+        Ok(())
+    }
+
+    /// Get the field with the merkle proof against the `state_root`,
+    /// return None and a non-inclusion proof if the field does not exist.
+    pub fn get_with_proof(
+        &self,
+        state_root: H256,
+        key: FieldKey,
+    ) -> Result<(Option<ObjectState>, SparseMerkleProof)> {
+        self.smt.get_with_proof(state_root, key)
+    }
+
+    /// Get the state proof of the object field, the proof is chained from the root object to the field.
+    pub fn get_state_proof(
+        &self,
+        state_root: H256,
+        object_id: &ObjectID,
+        field_key: FieldKey,
+    ) -> Result<StateProof> {
+        let mut field_proofs = vec![];
+        let mut current_root = state_root;
+        for key in StateProof::proof_keys(object_id, field_key) {
+            let (state, proof) = self.get_with_proof(current_root, key)?;
+            let next_root = state.as_ref().map(|state| state.state_root());
+            field_proofs.push(FieldProof::new(key, state, proof));
+            match next_root {
+                Some(root) => current_root = root,
+                None => break,
+            }
+        }
+        Ok(StateProof::new(field_proofs))
+    }
+
+    /// Check the node binary matches the node hash, it is used to check the nodes from an untrusted source.
+    pub fn check_node(hash: H256, node: &[u8]) -> Result<()> {
+        let node_hash = smt::node_hash::<FieldKey, ObjectState>(node)?;
+        ensure!(
+            node_hash == hash,
+            "State node hash mismatch, exp: {:?}, act: {:?}",
+            hash,
+            node_hash
+        );
+        Ok(())
+    }
+
+    /// Walk all the nodes of the state tree, include the field trees of the objects.
+    /// The field trees with the same state root are visited only once, but the nodes shared by
+    /// different trees may be visited more than once.
+    /// Returns an error if any node of the state tree is missing in the store.
+    pub fn walk_nodes<F>(&self, state_root: H256, mut visitor: F) -> Result<()>
+    where
+        F: FnMut(H256, &[u8]) -> Result<()>,
+    {
+        let mut visited = HashSet::new();
+        let mut roots = vec![state_root];
+        while let Some(root) = roots.pop() {
+            if !visited.insert(root) {
+                continue;
+            }
+            self.smt.walk_nodes(root, |hash, node, state| {
+                visitor(hash, node)?;
+                if let Some(state) = state {
+                    if state.metadata.has_fields() {
+                        roots.push(state.state_root());
+                    }
+                }
+                Ok(())
+            })?;
+        }
         Ok(())
     }
 
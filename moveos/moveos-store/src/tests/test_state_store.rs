@@ -1,11 +1,21 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::state_store::statedb::StateDBStore;
 use crate::{MoveOSStore, StoreMeta};
 use anyhow::Result;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::effects::Op;
+use move_core_types::vm_status::KeptVMStatus;
 use moveos_config::store_config::RocksdbConfig;
 use moveos_types::h256::H256;
-use moveos_types::test_utils::random_state_change_set;
+use moveos_types::moveos_std::object::ObjectID;
+use moveos_types::state::{FieldKey, ObjectChange, StateChangeSet};
+use moveos_types::state_resolver::StatelessResolver;
+use moveos_types::test_utils::{
+    random_bytes, random_object_change, random_raw_object, random_state_change_set,
+};
+use moveos_types::transaction::RawTransactionOutput;
 use raw_store::metrics::DBMetrics;
 use raw_store::rocks::RocksDB;
 use raw_store::{StoreInstance, CF_METRICS_REPORT_PERIOD_MILLIS};
@@ -71,6 +81,73 @@ async fn test_statedb_state_root() -> Result<()> {
     Ok(())
 }
 
+fn verify_object_change_proofs(
+    state_store: &StateDBStore,
+    state_root: H256,
+    parent_id: &ObjectID,
+    field_key: FieldKey,
+    change: &ObjectChange,
+) -> Result<()> {
+    let proof = state_store.get_state_proof(state_root, parent_id, field_key)?;
+    let state = proof
+        .verify(state_root, parent_id, field_key)?
+        .expect("The state should exist");
+    assert_eq!(state.metadata.id, change.metadata.id);
+    for (child_key, child_change) in &change.fields {
+        verify_object_change_proofs(
+            state_store,
+            state_root,
+            &change.metadata.id,
+            *child_key,
+            child_change,
+        )?;
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_state_proof() -> Result<()> {
+    let (moveos_store, _) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let state_store = moveos_store.get_state_store();
+    let mut change_set = random_state_change_set();
+    state_store.apply_change_set(&mut change_set)?;
+    let state_root = change_set.state_root;
+
+    for (field_key, change) in &change_set.changes {
+        verify_object_change_proofs(
+            state_store,
+            state_root,
+            &ObjectID::root(),
+            *field_key,
+            change,
+        )?;
+    }
+
+    // non-inclusion proof of the field in root object
+    let missing_key = FieldKey::random();
+    let proof = state_store.get_state_proof(state_root, &ObjectID::root(), missing_key)?;
+    assert!(proof
+        .verify(state_root, &ObjectID::root(), missing_key)?
+        .is_none());
+
+    // non-inclusion proof of the field in a missing object
+    let missing_object_id = ObjectID::random();
+    let proof = state_store.get_state_proof(state_root, &missing_object_id, missing_key)?;
+    assert_eq!(proof.field_proofs.len(), 1);
+    assert!(proof
+        .verify(state_root, &missing_object_id, missing_key)?
+        .is_none());
+
+    // the proof should not be verified against another state root
+    let (field_key, _) = change_set.changes.iter().next().unwrap();
+    let proof = state_store.get_state_proof(state_root, &ObjectID::root(), *field_key)?;
+    assert!(proof
+        .verify(H256::random(), &ObjectID::root(), *field_key)
+        .is_err());
+    Ok(())
+}
+
 // #[tokio::test]
 // async fn test_child_state_db_dump_and_apply() -> Result<()> {
 //     let mut moveos_store = MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
@@ -129,3 +206,164 @@ async fn test_statedb_state_root() -> Result<()> {
 //     }
 //     Ok(())
 // }
+
+fn raw_output(changeset: StateChangeSet) -> RawTransactionOutput {
+    RawTransactionOutput {
+        status: KeptVMStatus::Executed,
+        changeset,
+        events: vec![],
+        gas_used: 0,
+        is_upgrade: false,
+        is_gas_upgrade: false,
+    }
+}
+
+#[tokio::test]
+async fn test_prune_stale_nodes() -> Result<()> {
+    let (moveos_store, _) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let change_set = random_state_change_set();
+    let field_keys = change_set.changes.keys().cloned().collect::<Vec<_>>();
+    let (_, execution_info) = moveos_store.handle_tx_output(
+        H256::random(),
+        AccountAddress::ZERO,
+        raw_output(change_set),
+    )?;
+    let pre_state_root = execution_info.state_root;
+
+    let mut change_set = StateChangeSet::new(pre_state_root, execution_info.size);
+    let (_, object_change) = random_object_change(0);
+    change_set.add_change(object_change)?;
+    let tx_hash = H256::random();
+    let (_, execution_info) =
+        moveos_store.handle_tx_output(tx_hash, AccountAddress::ZERO, raw_output(change_set))?;
+    let state_root = execution_info.state_root;
+
+    let prune_store = moveos_store.get_prune_store();
+    let stale_nodes = prune_store.get_stale_nodes(tx_hash)?.unwrap();
+    assert!(stale_nodes.contains(&pre_state_root));
+
+    let deleted = moveos_store.prune_stale_nodes(vec![tx_hash])?;
+    assert_eq!(deleted, stale_nodes.len() as u64);
+    assert!(prune_store.get_stale_nodes(tx_hash)?.is_none());
+    assert!(moveos_store.get(&pre_state_root)?.is_none());
+    assert!(moveos_store
+        .ensure_state_root_not_pruned(pre_state_root)
+        .is_err());
+    moveos_store.ensure_state_root_not_pruned(state_root)?;
+    // The latest state is still complete
+    for field_key in field_keys {
+        assert!(moveos_store
+            .get_state_store()
+            .get_field_at(state_root, &field_key)?
+            .is_some());
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_prune_node_referenced_again() -> Result<()> {
+    let (moveos_store, _) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let object = random_raw_object();
+    let field_key = object.metadata.id.field_key();
+    let value = object.value.clone();
+
+    let mut change_set = StateChangeSet::default();
+    change_set.add_change(ObjectChange::new_object(object.clone()))?;
+    let (_, execution_info) = moveos_store.handle_tx_output(
+        H256::random(),
+        AccountAddress::ZERO,
+        raw_output(change_set),
+    )?;
+    let state_root = execution_info.state_root;
+
+    // Modify the object, then change it back, the nodes of the first state are written again
+    let mut change_set = StateChangeSet::new(state_root, execution_info.size);
+    change_set.add_change(ObjectChange::new(
+        object.metadata.clone(),
+        Op::Modify(random_bytes()),
+    ))?;
+    let modify_tx_hash = H256::random();
+    let (_, execution_info) = moveos_store.handle_tx_output(
+        modify_tx_hash,
+        AccountAddress::ZERO,
+        raw_output(change_set),
+    )?;
+    let modified_state_root = execution_info.state_root;
+    let mut change_set = StateChangeSet::new(modified_state_root, execution_info.size);
+    change_set.add_change(ObjectChange::new(
+        object.metadata.clone(),
+        Op::Modify(value),
+    ))?;
+    let revert_tx_hash = H256::random();
+    let (_, execution_info) = moveos_store.handle_tx_output(
+        revert_tx_hash,
+        AccountAddress::ZERO,
+        raw_output(change_set),
+    )?;
+    assert_eq!(execution_info.state_root, state_root);
+
+    // The nodes of the first state are still referenced by the latest state
+    let prune_store = moveos_store.get_prune_store();
+    assert_eq!(prune_store.get_ref_count(state_root)?, Some(2));
+    assert_eq!(moveos_store.prune_stale_nodes(vec![modify_tx_hash])?, 0);
+    assert_eq!(prune_store.get_ref_count(state_root)?, Some(1));
+    assert!(moveos_store
+        .get_state_store()
+        .get_field_at(state_root, &field_key)?
+        .is_some());
+
+    let stale_nodes = prune_store.get_stale_nodes(revert_tx_hash)?.unwrap();
+    assert!(stale_nodes.contains(&modified_state_root));
+    assert_eq!(
+        moveos_store.prune_stale_nodes(vec![revert_tx_hash])?,
+        stale_nodes.len() as u64
+    );
+    assert!(moveos_store
+        .ensure_state_root_not_pruned(modified_state_root)
+        .is_err());
+    moveos_store.ensure_state_root_not_pruned(state_root)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_prune_keeps_nodes_with_unknown_ref_count() -> Result<()> {
+    let (moveos_store, _) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    // The nodes written without reference counting, like the genesis state of an old database
+    let mut change_set = random_state_change_set();
+    moveos_store
+        .get_state_store()
+        .apply_change_set(&mut change_set)?;
+    let pre_state_root = change_set.state_root;
+    let prune_store = moveos_store.get_prune_store();
+    assert_eq!(prune_store.get_ref_count(pre_state_root)?, None);
+
+    let mut change_set = StateChangeSet::new(pre_state_root, change_set.global_size);
+    let (_, object_change) = random_object_change(0);
+    change_set.add_change(object_change)?;
+    let tx_hash = H256::random();
+    moveos_store.handle_tx_output(tx_hash, AccountAddress::ZERO, raw_output(change_set))?;
+    assert!(prune_store
+        .get_stale_nodes(tx_hash)?
+        .unwrap()
+        .contains(&pre_state_root));
+
+    assert_eq!(moveos_store.prune_stale_nodes(vec![tx_hash])?, 0);
+    assert!(moveos_store.get(&pre_state_root)?.is_some());
+    moveos_store.ensure_state_root_not_pruned(pre_state_root)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ensure_state_not_pruned() -> Result<()> {
+    let (moveos_store, _) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    moveos_store.ensure_state_not_pruned(0)?;
+    moveos_store.get_prune_store().save_pruned_tx_order(10)?;
+    assert!(moveos_store.ensure_state_not_pruned(9).is_err());
+    moveos_store.ensure_state_not_pruned(10)?;
+    moveos_store.ensure_state_not_pruned(11)?;
+    Ok(())
+}
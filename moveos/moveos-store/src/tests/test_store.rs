@@ -94,6 +94,7 @@ async fn test_store() {
         H256::random(),
         rand::random(),
         KeptVMStatus::Executed,
+        AccountAddress::random(),
     );
     let id = transaction_info1.tx_hash;
     store
@@ -151,6 +152,7 @@ async fn test_iter() {
         H256::random(),
         rand::random(),
         KeptVMStatus::Executed,
+        AccountAddress::random(),
     );
     let id = transaction_info1.tx_hash;
     store
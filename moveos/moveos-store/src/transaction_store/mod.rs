@@ -32,14 +32,24 @@ impl TransactionStore for TransactionDBStore {
     }
 
     fn get_tx_execution_info(&self, tx_hash: H256) -> Result<Option<TransactionExecutionInfo>> {
-        self.kv_get(tx_hash)
+        // Decode the raw value, the execution infos persisted before the gas payer was recorded have a different layout
+        self.get_raw(&bcs::to_bytes(&tx_hash)?)?
+            .map(|bytes| TransactionExecutionInfo::decode(&bytes))
+            .transpose()
     }
 
     fn multi_get_tx_execution_infos(
         &self,
         tx_hashes: Vec<H256>,
     ) -> Result<Vec<Option<TransactionExecutionInfo>>> {
-        self.multiple_get(tx_hashes)
+        self.multiple_get_raw(tx_hashes)?
+            .into_iter()
+            .map(|bytes| {
+                bytes
+                    .map(|bytes| TransactionExecutionInfo::decode(&bytes))
+                    .transpose()
+            })
+            .collect()
     }
 
     fn remove_tx_execution_info(&self, tx_hash: H256) -> Result<()> {
@@ -13,6 +13,8 @@ pub mod moveos_std;
 pub mod serde;
 pub mod startup_info;
 pub mod state;
+pub mod state_override;
+pub mod state_proof;
 pub mod state_resolver;
 pub mod test_utils;
 pub mod transaction;
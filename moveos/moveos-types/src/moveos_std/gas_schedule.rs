@@ -14,6 +14,7 @@ use move_core_types::{
     account_address::AccountAddress,
     ident_str,
     identifier::IdentStr,
+    u256::U256,
     value::{MoveStructLayout, MoveTypeLayout},
 };
 use serde::{Deserialize, Serialize};
@@ -50,6 +51,20 @@ impl GasScheduleConfig {
     pub const READONLY_MAX_GAS_AMOUNT: u64 = 5_000_000_000u64;
     /// The default maximum gas amount for the CLI to use
     pub const CLI_DEFAULT_MAX_GAS_AMOUNT: u64 = 100_000_000u64;
+
+    /// The max gas amount the balance can pay at the gas price, it is capped by the `READONLY_MAX_GAS_AMOUNT`.
+    /// The zero balance gets the `READONLY_MAX_GAS_AMOUNT`, the new account gets the gas via faucet in local or dev chain.
+    pub fn max_gas_amount_for_balance(balance: U256, gas_price: u64) -> u64 {
+        if balance == U256::zero() || gas_price == 0 {
+            return Self::READONLY_MAX_GAS_AMOUNT;
+        }
+        let max_gas_amount = balance / U256::from(gas_price);
+        if max_gas_amount >= U256::from(Self::READONLY_MAX_GAS_AMOUNT) {
+            Self::READONLY_MAX_GAS_AMOUNT
+        } else {
+            max_gas_amount.unchecked_as_u64()
+        }
+    }
 }
 
 impl MoveStructType for GasScheduleConfig {
@@ -115,12 +130,105 @@ impl MoveStructState for GasScheduleUpdated {
     }
 }
 
+/// The gas schedule which takes effect when the on-chain time reaches the `activation_time` in milliseconds.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct PendingGasSchedule {
+    pub activation_time: u64,
+    pub gas_schedule_config: GasScheduleConfig,
+}
+
+impl PendingGasSchedule {
+    pub fn pending_gas_schedule_object_id() -> ObjectID {
+        object::named_object_id(&Self::struct_tag())
+    }
+
+    pub fn is_activated(&self, now_milliseconds: u64) -> bool {
+        now_milliseconds >= self.activation_time
+    }
+}
+
+impl MoveStructType for PendingGasSchedule {
+    const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("PendingGasSchedule");
+}
+
+impl MoveStructState for PendingGasSchedule {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![MoveTypeLayout::U64, GasScheduleConfig::type_layout()])
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct GasScheduleUpdatedEvent {
+    pub schedule_version: u64,
+    pub max_gas_amount: u64,
+}
+
+impl MoveStructType for GasScheduleUpdatedEvent {
+    const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("GasScheduleUpdatedEvent");
+}
+
+impl MoveStructState for GasScheduleUpdatedEvent {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![MoveTypeLayout::U64, MoveTypeLayout::U64])
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct GasScheduleUpdateScheduledEvent {
+    pub activation_time: u64,
+    pub gas_schedule_config: GasScheduleConfig,
+}
+
+impl MoveStructType for GasScheduleUpdateScheduledEvent {
+    const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("GasScheduleUpdateScheduledEvent");
+}
+
+impl MoveStructState for GasScheduleUpdateScheduledEvent {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![MoveTypeLayout::U64, GasScheduleConfig::type_layout()])
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct GasScheduleUpdateCancelledEvent {
+    pub activation_time: u64,
+}
+
+impl MoveStructType for GasScheduleUpdateCancelledEvent {
+    const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("GasScheduleUpdateCancelledEvent");
+}
+
+impl MoveStructState for GasScheduleUpdateCancelledEvent {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![MoveTypeLayout::U64])
+    }
+}
+
 pub struct GasScheduleModule<'a> {
     caller: &'a dyn MoveFunctionCaller,
 }
 
 impl<'a> GasScheduleModule<'a> {
     pub const GAS_SCHEDULE_FUNCTION_NAME: &'static IdentStr = ident_str!("gas_schedule");
+    pub const ACTIVATE_PENDING_GAS_SCHEDULE_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("activate_pending_gas_schedule");
+
+    /// The call to apply the pending gas schedule to the gas schedule, it is executed by the VM after the activation time.
+    pub fn activate_pending_gas_schedule_function_call() -> FunctionCall {
+        FunctionCall::new(
+            Self::function_id(Self::ACTIVATE_PENDING_GAS_SCHEDULE_FUNCTION_NAME),
+            vec![],
+            vec![],
+        )
+    }
 
     pub fn gas_schedule(&self) -> anyhow::Result<GasSchedule> {
         let call = FunctionCall::new(
@@ -155,3 +263,37 @@ impl<'a> ModuleBinding<'a> for GasScheduleModule<'a> {
         Self { caller }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_gas_amount_for_balance() {
+        let readonly_max_gas_amount = GasScheduleConfig::READONLY_MAX_GAS_AMOUNT;
+        assert_eq!(
+            GasScheduleConfig::max_gas_amount_for_balance(U256::zero(), 1),
+            readonly_max_gas_amount
+        );
+        assert_eq!(
+            GasScheduleConfig::max_gas_amount_for_balance(U256::from(1000u64), 1),
+            1000
+        );
+        // The balance pays the gas price for each gas unit
+        assert_eq!(
+            GasScheduleConfig::max_gas_amount_for_balance(U256::from(1001u64), 2),
+            500
+        );
+        assert_eq!(
+            GasScheduleConfig::max_gas_amount_for_balance(U256::from(1u64), 2),
+            0
+        );
+        assert_eq!(
+            GasScheduleConfig::max_gas_amount_for_balance(
+                U256::from(readonly_max_gas_amount) * U256::from(3u64),
+                2
+            ),
+            readonly_max_gas_amount
+        );
+    }
+}
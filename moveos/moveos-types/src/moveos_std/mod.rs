@@ -8,6 +8,7 @@ pub mod any;
 pub mod copyable_any;
 pub mod display;
 pub mod event;
+pub mod gas_payment_account;
 pub mod gas_schedule;
 pub mod genesis;
 pub mod module_store;
@@ -148,6 +148,11 @@ impl ObjectID {
             .into()
     }
 
+    /// Get the field keys of the object path, from the child of root object to the current object.
+    pub fn field_keys(&self) -> Vec<FieldKey> {
+        self.0.iter().cloned().map(Into::into).collect()
+    }
+
     pub fn to_hex(&self) -> String {
         let bytes: Vec<u8> = self.0.iter().flat_map(|addr| addr.to_vec()).collect();
         hex::encode(bytes)
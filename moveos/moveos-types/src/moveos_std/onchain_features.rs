@@ -2,9 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::addresses::MOVEOS_STD_ADDRESS;
+use crate::module_binding::{ModuleBinding, MoveFunctionCaller};
 use crate::moveos_std::object;
 use crate::moveos_std::object::ObjectID;
+use crate::moveos_std::tx_context::TxContext;
 use crate::state::{MoveStructState, MoveStructType};
+use crate::transaction::FunctionCall;
+use anyhow::Result;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::ident_str;
 use move_core_types::identifier::IdentStr;
@@ -13,6 +17,7 @@ use serde::{Deserialize, Serialize};
 
 pub const MODULE_NAME: &IdentStr = ident_str!("features");
 pub const VALUE_SIZE_GAS_FEATURE: u64 = 7;
+pub const ETHEREUM_RECEIPTS_FEATURE: u64 = 8;
 
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 pub struct FeatureStore {
@@ -27,13 +32,19 @@ impl FeatureStore {
     pub fn contains_feature(&self, feature: u64) -> bool {
         let byte_index = feature / 8;
         let bit_mask = 1 << ((feature % 8) as u8);
-        let value = self.entries[byte_index as usize];
-        byte_index < self.entries.len() as u64 && (value & bit_mask) != 0
+        match self.entries.get(byte_index as usize) {
+            Some(value) => (value & bit_mask) != 0,
+            None => false,
+        }
     }
 
     pub fn has_value_size_gas_feature(&self) -> bool {
         self.contains_feature(VALUE_SIZE_GAS_FEATURE)
     }
+
+    pub fn has_ethereum_receipts_feature(&self) -> bool {
+        self.contains_feature(ETHEREUM_RECEIPTS_FEATURE)
+    }
 }
 
 impl MoveStructType for FeatureStore {
@@ -47,3 +58,46 @@ impl MoveStructState for FeatureStore {
         MoveStructLayout::new(vec![MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8))])
     }
 }
+
+/// Rust bindings for MoveosStd features module
+pub struct FeaturesModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> FeaturesModule<'a> {
+    pub const IS_ENABLED_FUNCTION_NAME: &'static IdentStr = ident_str!("is_enabled");
+
+    pub fn is_enabled(&self, feature: u64) -> Result<bool> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::IS_ENABLED_FUNCTION_NAME),
+            vec![],
+            vec![bcs::to_bytes(&feature)?],
+        );
+        let ctx = TxContext::zero();
+        let enabled = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<bool>(&value.value).expect("should be a valid bool")
+            })?;
+        Ok(enabled)
+    }
+
+    pub fn ethereum_receipts_enabled(&self) -> Result<bool> {
+        self.is_enabled(ETHEREUM_RECEIPTS_FEATURE)
+    }
+}
+
+impl<'a> ModuleBinding<'a> for FeaturesModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
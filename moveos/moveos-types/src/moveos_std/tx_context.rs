@@ -7,6 +7,7 @@ use crate::addresses::MOVEOS_STD_ADDRESS;
 use crate::h256::{self, H256};
 use crate::move_std::string::MoveString;
 use crate::moveos_std::copyable_any::{Any, AnyTrait};
+use crate::moveos_std::gas_payment_account::GasPaymentAccount;
 use crate::moveos_std::gas_schedule::GasScheduleConfig;
 use crate::moveos_std::object::ObjectID;
 use crate::moveos_std::simple_map::SimpleMap;
@@ -136,6 +137,14 @@ impl TxContext {
         self.sender
     }
 
+    /// Return the account which pays the gas, it is the sender unless the transaction is sponsored
+    pub fn gas_payment_account(&self) -> Result<AccountAddress> {
+        Ok(self
+            .get::<GasPaymentAccount>()?
+            .map(|gas_payment_account| gas_payment_account.account)
+            .unwrap_or(self.sender))
+    }
+
     pub fn to_bytes(&self) -> Vec<u8> {
         debug_assert!(self.tx_hash.len() == h256::LENGTH);
         bcs::to_bytes(&self).unwrap()
@@ -214,4 +223,13 @@ mod tests {
         let serialized2 = decoded_value.simple_serialize().unwrap();
         assert_eq!(serialized, serialized2);
     }
+
+    #[test]
+    pub fn test_gas_payment_account() {
+        let mut ctx = TxContext::random_for_testing_only();
+        assert_eq!(ctx.gas_payment_account().unwrap(), ctx.sender());
+        let gas_payer = AccountAddress::random();
+        ctx.add(GasPaymentAccount::new(gas_payer)).unwrap();
+        assert_eq!(ctx.gas_payment_account().unwrap(), gas_payer);
+    }
 }
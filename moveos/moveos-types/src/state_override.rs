@@ -0,0 +1,107 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::move_std::string::MoveString;
+use crate::moveos_std::module_store::{ModuleStore, Package};
+use crate::moveos_std::move_module::{MoveModule, MoveModuleDynamicField};
+use crate::moveos_std::object::{DynamicField, ObjectID, ObjectMeta, SYSTEM_OWNER_ADDRESS};
+use crate::state::{FieldKey, MoveStructType, ObjectState};
+use anyhow::{ensure, format_err, Result};
+use move_binary_format::CompiledModule;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::TypeTag;
+use serde::{Deserialize, Serialize};
+
+/// Override the state of an object when simulating a transaction.
+/// The override only affects the simulation, it is never written to the state store.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ObjectOverride {
+    pub id: ObjectID,
+    /// The owner of the object, keep the current owner if None.
+    /// The new object is owned by the system if None.
+    pub owner: Option<AccountAddress>,
+    /// The type of the object, keep the current type if None.
+    /// It is required if the object does not exist.
+    pub object_type: Option<TypeTag>,
+    /// The BCS bytes of the object value
+    pub value: Vec<u8>,
+}
+
+impl ObjectOverride {
+    pub fn new(
+        id: ObjectID,
+        owner: Option<AccountAddress>,
+        object_type: Option<TypeTag>,
+        value: Vec<u8>,
+    ) -> Self {
+        Self {
+            id,
+            owner,
+            object_type,
+            value,
+        }
+    }
+
+    /// Replace the module byte codes, the module's package must exist.
+    pub fn module(byte_codes: Vec<u8>) -> Result<Self> {
+        let module = CompiledModule::deserialize(&byte_codes)?;
+        let module_id = module.self_id();
+        let package_id = Package::package_id(module_id.address());
+        let id = package_id.child_id(FieldKey::derive_module_key(module_id.name()));
+        let value = DynamicField::new(
+            MoveString::from(module_id.name()),
+            MoveModule::new(byte_codes),
+        );
+        Ok(Self::new(
+            id,
+            None,
+            Some(MoveModuleDynamicField::type_tag()),
+            bcs::to_bytes(&value)?,
+        ))
+    }
+
+    /// Whether the override changes a module, the module cache needs to be flushed.
+    pub fn is_module(&self) -> bool {
+        self.id
+            .parent()
+            .and_then(|package_id| package_id.parent())
+            .map(|module_store_id| module_store_id == ModuleStore::object_id())
+            .unwrap_or(false)
+    }
+
+    /// Merge the override with the current object state, the fields of the current object are kept.
+    pub fn into_object_state(self, current: Option<ObjectState>) -> Result<ObjectState> {
+        ensure!(!self.id.is_root(), "The root object can not be overridden");
+        let metadata = match current {
+            Some(current) => {
+                let mut metadata = current.metadata;
+                if let Some(owner) = self.owner {
+                    metadata.owner = owner;
+                }
+                if let Some(object_type) = self.object_type {
+                    metadata.object_type = object_type;
+                }
+                metadata
+            }
+            None => {
+                let object_type = self.object_type.ok_or_else(|| {
+                    format_err!(
+                        "The object type is required to override the non-existent object {}",
+                        self.id
+                    )
+                })?;
+                ObjectMeta::new(
+                    self.id,
+                    self.owner.unwrap_or(SYSTEM_OWNER_ADDRESS),
+                    0u8,
+                    None,
+                    0,
+                    0,
+                    0,
+                    object_type,
+                )
+            }
+        };
+        Ok(ObjectState::new(metadata, self.value))
+    }
+}
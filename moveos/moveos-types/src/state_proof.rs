@@ -0,0 +1,110 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::h256::H256;
+use crate::moveos_std::object::ObjectID;
+use crate::state::{FieldKey, ObjectState};
+use anyhow::{ensure, Result};
+use serde::{Deserialize, Serialize};
+use smt::SparseMerkleProof;
+
+/// The proof of a field in the state tree of its parent object.
+/// If the `state` is None, the `proof` is a non-inclusion proof.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FieldProof {
+    pub field_key: FieldKey,
+    pub state: Option<ObjectState>,
+    pub proof: SparseMerkleProof,
+}
+
+impl FieldProof {
+    pub fn new(field_key: FieldKey, state: Option<ObjectState>, proof: SparseMerkleProof) -> Self {
+        Self {
+            field_key,
+            state,
+            proof,
+        }
+    }
+}
+
+/// The proof of an object field against the state root of the root object.
+/// The nested object field is proved by chaining the field proofs from the root object
+/// down to the object which owns the field, every object's state root is the expected root of the next proof.
+/// If an ancestor object does not exist, the proofs stop at the non-inclusion proof of the ancestor.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StateProof {
+    pub field_proofs: Vec<FieldProof>,
+}
+
+impl StateProof {
+    pub fn new(field_proofs: Vec<FieldProof>) -> Self {
+        Self { field_proofs }
+    }
+
+    /// The field keys to prove the field of the object, from the root object to the field.
+    pub fn proof_keys(object_id: &ObjectID, field_key: FieldKey) -> Vec<FieldKey> {
+        let mut keys = object_id.field_keys();
+        keys.push(field_key);
+        keys
+    }
+
+    /// The proved state, return None if the state does not exist.
+    pub fn state(&self) -> Option<&ObjectState> {
+        self.field_proofs
+            .last()
+            .and_then(|field_proof| field_proof.state.as_ref())
+    }
+
+    /// Verify the field of the object against the `state_root`, return the proved state.
+    pub fn verify(
+        &self,
+        state_root: H256,
+        object_id: &ObjectID,
+        field_key: FieldKey,
+    ) -> Result<Option<ObjectState>> {
+        let keys = Self::proof_keys(object_id, field_key);
+        ensure!(
+            !self.field_proofs.is_empty() && self.field_proofs.len() <= keys.len(),
+            "Invalid state proof length: {}, expected at most: {}",
+            self.field_proofs.len(),
+            keys.len()
+        );
+
+        let mut expected_root = state_root;
+        let last_index = self.field_proofs.len() - 1;
+        for (index, (field_proof, key)) in self.field_proofs.iter().zip(keys.iter()).enumerate() {
+            ensure!(
+                field_proof.field_key == *key,
+                "Field key mismatch at proof {}, expected: {}, actual: {}",
+                index,
+                key,
+                field_proof.field_key
+            );
+            field_proof.proof.verify(
+                expected_root,
+                field_proof.field_key,
+                field_proof.state.clone(),
+            )?;
+            match &field_proof.state {
+                Some(state) => expected_root = state.state_root(),
+                None => ensure!(
+                    index == last_index,
+                    "Proof of missing object {} should be the last one",
+                    field_proof.field_key
+                ),
+            }
+        }
+
+        if self.field_proofs.len() < keys.len() {
+            // The ancestor object does not exist, so the field does not exist
+            ensure!(
+                self.state().is_none(),
+                "Incomplete state proof, expected {} field proofs, actual: {}",
+                keys.len(),
+                self.field_proofs.len()
+            );
+            return Ok(None);
+        }
+        Ok(self.state().cloned())
+    }
+}
@@ -171,6 +171,10 @@ where
     ) -> Result<Vec<StateKV>, anyhow::Error> {
         self.resolver.list_fields_at(state_root, cursor, limit)
     }
+
+    fn record_size_read(&self, object_id: &ObjectID) {
+        self.resolver.record_size_read(object_id)
+    }
 }
 
 impl<R> StateResolver for RootObjectResolver<'_, R>
@@ -197,6 +201,11 @@ pub trait StatelessResolver {
         cursor: Option<FieldKey>,
         limit: usize,
     ) -> Result<Vec<StateKV>>;
+
+    /// Called when the VM reads the field size of the object.
+    /// The size is taken from the object metadata, so it does not go through `get_field_at`.
+    /// The default implementation does nothing.
+    fn record_size_read(&self, _object_id: &ObjectID) {}
 }
 
 impl<R> ResourceResolver for RootObjectResolver<'_, R>
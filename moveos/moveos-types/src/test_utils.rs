@@ -9,7 +9,7 @@ use crate::{
         table::TablePlaceholder,
     },
     state::{FieldKey, ObjectChange, ObjectState, StateChangeSet},
-    transaction::{FunctionCall, MoveAction, ScriptCall, VerifiedMoveAction},
+    transaction::{BatchCall, FunctionCall, MoveAction, ScriptCall, VerifiedMoveAction},
 };
 use move_core_types::{
     account_address::AccountAddress,
@@ -28,6 +28,8 @@ pub enum MoveActionType {
     Function,
     //Publish Move modules
     ModuleBundle,
+    //Execute a batch of Move functions
+    Batch,
 }
 
 impl MoveActionType {
@@ -36,6 +38,7 @@ impl MoveActionType {
             MoveActionType::Script => 0,
             MoveActionType::Function => 1,
             MoveActionType::ModuleBundle => 2,
+            MoveActionType::Batch => 3,
         }
     }
 }
@@ -88,6 +91,8 @@ pub fn random_move_action_with_action_type(action_type: u8) -> MoveAction {
         random_move_action_script()
     } else if MoveActionType::Function.action_type() == action_type {
         random_move_action_function()
+    } else if MoveActionType::Batch.action_type() == action_type {
+        random_move_action_batch()
     } else {
         random_move_action_module_bundle()
     }
@@ -101,6 +106,15 @@ pub fn random_move_action_function() -> MoveAction {
     MoveAction::Function(random_function_call())
 }
 
+pub fn random_move_action_batch() -> MoveAction {
+    MoveAction::Batch(
+        random_function_calls()
+            .into_iter()
+            .map(BatchCall::from)
+            .collect(),
+    )
+}
+
 pub fn random_move_action_module_bundle() -> MoveAction {
     let mut module_bundle = vec![];
     let mut rng = thread_rng();
@@ -111,6 +111,53 @@ impl Arbitrary for FunctionCall {
     }
 }
 
+/// Use a return value of a previous call in the batch as an argument of the call
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "fuzzing"), derive(Arbitrary))]
+pub struct CallResultArg {
+    /// The position of the argument in the call, the signer argument is not counted
+    pub arg_index: u16,
+    /// The index of the previous call in the batch
+    pub call_index: u16,
+    /// The index of the return value of the previous call
+    pub result_index: u16,
+}
+
+impl CallResultArg {
+    pub fn new(arg_index: u16, call_index: u16, result_index: u16) -> Self {
+        Self {
+            arg_index,
+            call_index,
+            result_index,
+        }
+    }
+}
+
+/// A function call in a batch
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "fuzzing"), derive(Arbitrary))]
+pub struct BatchCall {
+    pub call: FunctionCall,
+    /// The arguments taken from the return values of the previous calls,
+    /// they are inserted into `call.args` in ascending order of `arg_index`
+    pub result_args: Vec<CallResultArg>,
+}
+
+impl BatchCall {
+    pub fn new(call: FunctionCall, result_args: Vec<CallResultArg>) -> Self {
+        Self { call, result_args }
+    }
+}
+
+impl From<FunctionCall> for BatchCall {
+    fn from(call: FunctionCall) -> Self {
+        Self {
+            call,
+            result_args: vec![],
+        }
+    }
+}
+
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(any(test, feature = "fuzzing"), derive(Arbitrary))]
 pub enum MoveAction {
@@ -120,6 +167,8 @@ pub enum MoveAction {
     Function(FunctionCall),
     //Publish Move modules
     ModuleBundle(Vec<Vec<u8>>),
+    //Execute a batch of Move functions atomically
+    Batch(Vec<BatchCall>),
 }
 
 impl MoveAction {
@@ -128,6 +177,7 @@ impl MoveAction {
             MoveAction::Script(_) => 0,
             MoveAction::Function(_) => 1,
             MoveAction::ModuleBundle(_) => 2,
+            MoveAction::Batch(_) => 3,
         }
     }
 
@@ -136,6 +186,7 @@ impl MoveAction {
             MoveAction::Script(_) => "Script".to_string(),
             MoveAction::Function(_) => "Function".to_string(),
             MoveAction::ModuleBundle(_) => "ModuleBundle".to_string(),
+            MoveAction::Batch(_) => "Batch".to_string(),
         }
     }
 
@@ -153,6 +204,9 @@ impl MoveAction {
             args,
         })
     }
+    pub fn new_batch_call(calls: Vec<BatchCall>) -> Self {
+        Self::Batch(calls)
+    }
     pub fn new_script_call(code: Vec<u8>, ty_args: Vec<TypeTag>, args: Vec<Vec<u8>>) -> Self {
         Self::Script(ScriptCall {
             code,
@@ -180,6 +234,7 @@ impl From<VerifiedMoveAction> for MoveAction {
                 module_bundle,
                 init_function_modules: _init_function_modules,
             } => MoveAction::ModuleBundle(module_bundle),
+            VerifiedMoveAction::Batch { calls } => MoveAction::Batch(calls),
         }
     }
 }
@@ -229,6 +284,18 @@ impl Display for MoveAction {
                 }
                 write!(f, "MoveAction::ModuleBundle( {:?} )", module_list)
             }
+            MoveAction::Batch(calls) => {
+                let mut call_list = vec![];
+                for batch_call in calls.iter() {
+                    call_list.push(format!(
+                        "{}(args: {}, result_args: {})",
+                        batch_call.call.function_id,
+                        batch_call.call.args.len(),
+                        batch_call.result_args.len()
+                    ));
+                }
+                write!(f, "MoveAction::Batch( {:?} )", call_list)
+            }
         }
     }
 }
@@ -247,6 +314,9 @@ pub enum VerifiedMoveAction {
         module_bundle: Vec<Vec<u8>>,
         init_function_modules: Vec<ModuleId>,
     },
+    Batch {
+        calls: Vec<BatchCall>,
+    },
 }
 
 impl Display for VerifiedMoveAction {
@@ -276,6 +346,9 @@ impl Display for VerifiedMoveAction {
                     init_function_modules.len()
                 )
             }
+            VerifiedMoveAction::Batch { calls } => {
+                write!(f, "Batch(calls: {})", calls.len())
+            }
         }
     }
 }
@@ -489,6 +562,9 @@ pub struct TransactionExecutionInfo {
     /// failures and Move abort's receive more detailed information. But other errors are generally
     /// categorized with no status code or other information
     pub status: KeptVMStatus,
+
+    /// The account which paid the gas, it is the sender unless the transaction is sponsored.
+    pub gas_payer: AccountAddress,
 }
 
 impl TransactionExecutionInfo {
@@ -499,6 +575,7 @@ impl TransactionExecutionInfo {
         event_root: H256,
         gas_used: u64,
         status: KeptVMStatus,
+        gas_payer: AccountAddress,
     ) -> TransactionExecutionInfo {
         TransactionExecutionInfo {
             tx_hash,
@@ -507,6 +584,7 @@ impl TransactionExecutionInfo {
             event_root,
             gas_used,
             status,
+            gas_payer,
         }
     }
 
@@ -518,6 +596,29 @@ impl TransactionExecutionInfo {
         ObjectMeta::root_metadata(self.state_root, self.size)
     }
 
+    /// Decode the persisted execution info.
+    /// The execution info persisted before the gas payer was recorded is decoded with the gas payer `0x0`.
+    pub fn decode(bytes: &[u8]) -> anyhow::Result<Self> {
+        match bcs::from_bytes::<Self>(bytes) {
+            Ok(execution_info) => Ok(execution_info),
+            Err(e) => {
+                let legacy =
+                    bcs::from_bytes::<LegacyTransactionExecutionInfo>(bytes).map_err(|_| {
+                        anyhow::anyhow!("Decode transaction execution info error: {}", e)
+                    })?;
+                Ok(TransactionExecutionInfo::new(
+                    legacy.tx_hash,
+                    legacy.state_root,
+                    legacy.size,
+                    legacy.event_root,
+                    legacy.gas_used,
+                    legacy.status,
+                    AccountAddress::ZERO,
+                ))
+            }
+        }
+    }
+
     pub fn random() -> Self {
         TransactionExecutionInfo::new(
             H256::random(),
@@ -526,15 +627,51 @@ impl TransactionExecutionInfo {
             H256::random(),
             rand::random(),
             KeptVMStatus::Executed,
+            AccountAddress::random(),
         )
     }
 }
 
+/// The layout of `TransactionExecutionInfo` before the gas payer was recorded.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+struct LegacyTransactionExecutionInfo {
+    tx_hash: H256,
+    state_root: H256,
+    size: u64,
+    event_root: H256,
+    gas_used: u64,
+    status: KeptVMStatus,
+}
+
 #[cfg(test)]
 mod tests {
-    use super::MoveAction;
+    use super::{LegacyTransactionExecutionInfo, MoveAction, TransactionExecutionInfo};
+    use move_core_types::account_address::AccountAddress;
     use proptest::prelude::*;
 
+    #[test]
+    fn test_decode_legacy_execution_info() {
+        let execution_info = TransactionExecutionInfo::random();
+        let decoded =
+            TransactionExecutionInfo::decode(&bcs::to_bytes(&execution_info).unwrap()).unwrap();
+        assert_eq!(decoded, execution_info);
+
+        let legacy = LegacyTransactionExecutionInfo {
+            tx_hash: execution_info.tx_hash,
+            state_root: execution_info.state_root,
+            size: execution_info.size,
+            event_root: execution_info.event_root,
+            gas_used: execution_info.gas_used,
+            status: execution_info.status.clone(),
+        };
+        let decoded = TransactionExecutionInfo::decode(&bcs::to_bytes(&legacy).unwrap()).unwrap();
+        assert_eq!(decoded.tx_hash, execution_info.tx_hash);
+        assert_eq!(decoded.root_metadata(), execution_info.root_metadata());
+        assert_eq!(decoded.gas_payer, AccountAddress::ZERO);
+
+        assert!(TransactionExecutionInfo::decode(&[0u8; 8]).is_err());
+    }
+
     proptest! {
         #[test]
         fn test_move_action_bcs_serde(input in any::<MoveAction>()) {
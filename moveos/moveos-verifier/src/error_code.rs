@@ -71,6 +71,8 @@ pub enum ErrorCode {
     INVALID_PARAM_TYPE_ENTRY_FUNCTION = 11001,
     INVALID_PARAM_SINGER_COUNT = 11002,
     INVALID_FIRST_ARGUMENT_IS_NOT_SIGNER = 11003,
+    INVALID_BATCH_FUNCTION_VISIBILITY = 11004,
+    INVALID_BATCH_FUNCTION_RETURN_TYPE = 11005,
 
     INVALID_PUBLIC_INIT_FUNC = 12000,
     INVALID_INIT_FUNC_WITH_ENTRY = 12001,
@@ -13,7 +13,7 @@ use move_binary_format::file_format::{
     StructFieldInformation, StructHandleIndex, Visibility,
 };
 use move_binary_format::{access::ModuleAccess, CompiledModule};
-use move_core_types::identifier::Identifier;
+use move_core_types::identifier::{IdentStr, Identifier};
 use move_core_types::language_storage::ModuleId;
 use move_core_types::resolver::ModuleResolver;
 use move_core_types::vm_status::StatusCode;
@@ -237,6 +237,76 @@ where
     Ok(())
 }
 
+/// Check the function can be called in a batch transaction, return whether it is an entry function.
+/// The function in a batch must be an entry function or a public function.
+pub fn verify_batch_function_visibility(
+    module: &CompiledModule,
+    function_name: &IdentStr,
+) -> PartialVMResult<bool> {
+    let fdef = module
+        .function_defs()
+        .iter()
+        .find(|fdef| {
+            let fhandle = module.function_handle_at(fdef.function);
+            module.identifier_at(fhandle.name) == function_name
+        })
+        .ok_or_else(|| {
+            PartialVMError::new(StatusCode::FUNCTION_RESOLUTION_FAILURE)
+                .with_message(format!("Function {} not found", function_name))
+        })?;
+    if fdef.is_entry {
+        return Ok(true);
+    }
+    if fdef.visibility != Visibility::Public {
+        return Err(PartialVMError::new(StatusCode::ABORTED)
+            .with_sub_status(ErrorCode::INVALID_BATCH_FUNCTION_VISIBILITY.into())
+            .with_message(format!(
+                "Function {} is not an entry or public function",
+                function_name
+            )));
+    }
+    Ok(false)
+}
+
+/// Verify the function called in a batch transaction.
+/// The parameters follow the entry function rules, the return values are passed to the next calls as arguments,
+/// so they must be the transaction input types with the `copy` and `drop` abilities.
+pub fn verify_batch_function<S>(
+    func: &LoadedFunctionInstantiation,
+    session: &Session<S>,
+) -> PartialVMResult<()>
+where
+    S: TransactionCache,
+{
+    for (idx, ty) in func.parameters.iter().enumerate() {
+        if !check_transaction_input_type(ty, session) {
+            return Err(PartialVMError::new(StatusCode::ABORTED)
+                .with_sub_status(ErrorCode::INVALID_ENTRY_FUNC_SIGNATURE.into())
+                .with_message(format!("The type of the {} parameter is not allowed", idx)));
+        }
+    }
+
+    for (idx, ty) in func.return_.iter().enumerate() {
+        let ty = ty.subst(&func.type_arguments)?;
+        let is_allowed = !matches!(ty, Type::Reference(_) | Type::MutableReference(_))
+            && check_transaction_input_type(&ty, session)
+            && session
+                .get_type_abilities(&ty)
+                .map(|abilities| abilities.has_copy() && abilities.has_drop())
+                .unwrap_or(false);
+        if !is_allowed {
+            return Err(PartialVMError::new(StatusCode::ABORTED)
+                .with_sub_status(ErrorCode::INVALID_BATCH_FUNCTION_RETURN_TYPE.into())
+                .with_message(format!(
+                    "The type of the {} return value is not allowed",
+                    idx
+                )));
+        }
+    }
+
+    Ok(())
+}
+
 fn check_transaction_input_type_at_publish(
     ety: &SignatureToken,
     module_bin_view: &BinaryIndexedView,
@@ -0,0 +1,224 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use tracing::debug;
+use wasmer::{AsStoreRef, FunctionEnvMut, RuntimeError};
+
+use crate::wasm::Env;
+
+/// The module name of the host functions imported by the WASM code.
+pub const HOST_MODULE_NAME: &str = "rooch";
+
+/// The return value of the host function when the requested object does not exist.
+pub const HOST_OBJECT_NOT_FOUND: i32 = -1;
+
+/// The host API provided to the WASM code by the Move runtime.
+/// All the data is exchanged in BCS bytes. The host is called by the thread which calls
+/// `WASMInstance::call_with_host`, so it can borrow the context of the caller.
+pub trait WASMHost {
+    /// Get the BCS value of the object with the BCS `ObjectID`, returns None if the object does not exist.
+    fn get_object(&mut self, object_id: &[u8]) -> anyhow::Result<Option<Vec<u8>>>;
+
+    /// Get the BCS result of a whitelisted view function.
+    fn call_view_function(&mut self, function_id: &str) -> anyhow::Result<Vec<u8>>;
+}
+
+/// The gas costs of the host functions, in the gas units of the WASM gas meter.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HostGasParameters {
+    /// The base cost of every host function call.
+    pub base: u64,
+    /// The cost of every byte passed between the WASM code and the host.
+    pub per_byte: u64,
+}
+
+pub(crate) type HostCall = Box<dyn FnOnce(&mut dyn WASMHost) + Send>;
+
+/// The context of the host during a function call, owned by the environment of the instance.
+#[derive(Default)]
+pub(crate) struct HostContext {
+    pub(crate) gas_parameters: HostGasParameters,
+    /// The events emitted by the WASM code, they are only returned if the call succeeds.
+    pub(crate) events: Vec<Vec<u8>>,
+    /// The host calls are sent to the caller thread, it is only available during the call.
+    pub(crate) calls: Option<Sender<HostCall>>,
+}
+
+pub(crate) type HostSlot = Arc<Mutex<HostContext>>;
+
+pub(crate) fn lock_host(slot: &HostSlot) -> MutexGuard<'_, HostContext> {
+    slot.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Close the host when the call finishes, so the caller thread stops serving the host calls.
+pub(crate) struct HostGuard(pub(crate) HostSlot);
+
+impl Drop for HostGuard {
+    fn drop(&mut self) {
+        lock_host(&self.0).calls = None;
+    }
+}
+
+fn host_context<'a>(
+    env: &'a FunctionEnvMut<Env>,
+) -> Result<MutexGuard<'a, HostContext>, RuntimeError> {
+    let host = lock_host(&env.data().host);
+    if host.calls.is_none() {
+        return Err(RuntimeError::new("the WASM host is not available"));
+    }
+    Ok(host)
+}
+
+/// Send the call to the caller thread and wait for the result.
+fn call_host<R: Send + 'static>(
+    env: &FunctionEnvMut<Env>,
+    f: impl FnOnce(&mut dyn WASMHost) -> anyhow::Result<R> + Send + 'static,
+) -> Result<R, RuntimeError> {
+    let calls = host_context(env)?
+        .calls
+        .clone()
+        .ok_or_else(|| RuntimeError::new("the WASM host is not available"))?;
+    let (result_sender, result_receiver) = mpsc::channel();
+    calls
+        .send(Box::new(move |host| {
+            let _ = result_sender.send(f(host));
+        }))
+        .map_err(|_| RuntimeError::new("the WASM host is closed"))?;
+    result_receiver
+        .recv()
+        .map_err(|_| RuntimeError::new("the WASM host is closed"))?
+        .map_err(|e| RuntimeError::new(e.to_string()))
+}
+
+fn charge_gas(env: &FunctionEnvMut<Env>, amount: u64) -> Result<(), RuntimeError> {
+    let mut gas_meter = env
+        .data()
+        .gas_meter
+        .lock()
+        .map_err(|_| RuntimeError::new("getting gas meter mutex failed"))?;
+    gas_meter.charge(amount)
+}
+
+fn charge_call(env: &FunctionEnvMut<Env>) -> Result<(), RuntimeError> {
+    let base = host_context(env)?.gas_parameters.base;
+    charge_gas(env, base)
+}
+
+/// Charge the bytes before reading them from the memory, so the length is limited by the gas.
+fn charge_bytes(env: &FunctionEnvMut<Env>, bytes: usize) -> Result<(), RuntimeError> {
+    let per_byte = host_context(env)?.gas_parameters.per_byte;
+    charge_gas(env, per_byte.saturating_mul(bytes as u64))
+}
+
+fn read_memory(env: &FunctionEnvMut<Env>, ptr: i32, len: i32) -> Result<Vec<u8>, RuntimeError> {
+    if ptr < 0 || len < 0 {
+        return Err(RuntimeError::new("invalid memory range"));
+    }
+    let memory_obj = env
+        .data()
+        .memory
+        .clone()
+        .ok_or_else(|| RuntimeError::new("memory not found"))?;
+    let memory = memory_obj
+        .lock()
+        .map_err(|_| RuntimeError::new("getting memory mutex failed"))?;
+    let store_ref = env.as_store_ref();
+    let memory_view = memory.view(&store_ref);
+    let mut buffer = vec![0u8; len as usize];
+    memory_view
+        .read(ptr as u64, &mut buffer)
+        .map_err(|e| RuntimeError::new(e.to_string()))?;
+    Ok(buffer)
+}
+
+/// Write the data to the output buffer if the capacity is enough, returns the length of the data.
+fn write_output(
+    env: &FunctionEnvMut<Env>,
+    out_ptr: i32,
+    out_cap: i32,
+    data: &[u8],
+) -> Result<i32, RuntimeError> {
+    let data_len =
+        i32::try_from(data.len()).map_err(|_| RuntimeError::new("output data too large"))?;
+    if out_ptr < 0 || data_len > out_cap {
+        return Ok(data_len);
+    }
+    let memory_obj = env
+        .data()
+        .memory
+        .clone()
+        .ok_or_else(|| RuntimeError::new("memory not found"))?;
+    let memory = memory_obj
+        .lock()
+        .map_err(|_| RuntimeError::new("getting memory mutex failed"))?;
+    let store_ref = env.as_store_ref();
+    let memory_view = memory.view(&store_ref);
+    memory_view
+        .write(out_ptr as u64, data)
+        .map_err(|e| RuntimeError::new(e.to_string()))?;
+    Ok(data_len)
+}
+
+/// `get_object(id_ptr, id_len, out_ptr, out_cap) -> i32`
+/// Returns the length of the object value, or `HOST_OBJECT_NOT_FOUND` if the object does not exist.
+/// The value is written to the output buffer only if the capacity is enough,
+/// so the caller can retry with a larger buffer.
+pub(crate) fn host_get_object(
+    env: FunctionEnvMut<Env>,
+    id_ptr: i32,
+    id_len: i32,
+    out_ptr: i32,
+    out_cap: i32,
+) -> Result<i32, RuntimeError> {
+    charge_call(&env)?;
+    charge_bytes(&env, id_len as usize)?;
+    let object_id = read_memory(&env, id_ptr, id_len)?;
+
+    match call_host(&env, move |host| host.get_object(&object_id))? {
+        Some(value) => {
+            charge_bytes(&env, value.len())?;
+            write_output(&env, out_ptr, out_cap, &value)
+        }
+        None => Ok(HOST_OBJECT_NOT_FOUND),
+    }
+}
+
+/// `emit_event(data_ptr, data_len) -> i32`
+/// Returns 0 if the event is emitted. The events are discarded if the function call fails.
+pub(crate) fn host_emit_event(
+    env: FunctionEnvMut<Env>,
+    data_ptr: i32,
+    data_len: i32,
+) -> Result<i32, RuntimeError> {
+    charge_call(&env)?;
+    charge_bytes(&env, data_len as usize)?;
+    let data = read_memory(&env, data_ptr, data_len)?;
+
+    host_context(&env)?.events.push(data);
+    Ok(0)
+}
+
+/// `call_view_function(func_ptr, func_len, out_ptr, out_cap) -> i32`
+/// The function id is an UTF-8 string like `0x2::timestamp::now_milliseconds`.
+/// Returns the length of the BCS result, the result is written to the output buffer only if the capacity is enough.
+pub(crate) fn host_call_view_function(
+    env: FunctionEnvMut<Env>,
+    func_ptr: i32,
+    func_len: i32,
+    out_ptr: i32,
+    out_cap: i32,
+) -> Result<i32, RuntimeError> {
+    charge_call(&env)?;
+    charge_bytes(&env, func_len as usize)?;
+    let function_id = read_memory(&env, func_ptr, func_len)?;
+
+    let function_id = String::from_utf8(function_id)
+        .map_err(|_| RuntimeError::new("the function id is not a valid UTF-8 string"))?;
+    debug!("host_call_view_function: {}", function_id);
+    let result = call_host(&env, move |host| host.call_view_function(&function_id))?;
+    charge_bytes(&env, result.len())?;
+    write_output(&env, out_ptr, out_cap, &result)
+}
@@ -3,5 +3,6 @@
 
 pub mod cost_function;
 pub mod gas_meter;
+pub mod host;
 pub mod middlewares;
 pub mod wasm;
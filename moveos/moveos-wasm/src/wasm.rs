@@ -3,6 +3,8 @@
 
 use std::collections::BTreeMap;
 use std::ops::Deref;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, Sender};
 use std::sync::{Arc, Mutex};
 
 use once_cell::sync::Lazy;
@@ -15,6 +17,10 @@ use wasmer_compiler_singlepass::Singlepass;
 
 use crate::cost_function::cost_function;
 use crate::gas_meter::GasMeter;
+use crate::host::{
+    host_call_view_function, host_emit_event, host_get_object, lock_host, HostCall, HostContext,
+    HostGasParameters, HostGuard, HostSlot, WASMHost, HOST_MODULE_NAME,
+};
 use crate::middlewares::gas_metering::GasMiddleware;
 
 const GAS_LIMIT: u64 = 500000;
@@ -24,6 +30,7 @@ pub struct WASMInstance {
     pub instance: Instance,
     pub store: Store,
     pub gas_meter: Arc<Mutex<GasMeter>>,
+    host: HostSlot,
 }
 
 impl WASMInstance {
@@ -38,10 +45,84 @@ impl WASMInstance {
             instance,
             store,
             gas_meter,
+            host: Arc::new(Mutex::new(HostContext::default())),
         }
     }
+
+    /// Call the function with the host, the host functions imported by the WASM code are only available during the call.
+    /// The function runs on the WASM executor thread while the current thread serves the host calls,
+    /// so the host can borrow the context of the caller. The instance is moved to the executor for the call.
+    /// Returns the instance, and the results of the function and the events emitted by the WASM code.
+    pub fn call_with_host(
+        self,
+        function: &Function,
+        args: &[Value],
+        gas_parameters: HostGasParameters,
+        host: &mut dyn WASMHost,
+    ) -> (Self, Result<(Box<[Value]>, Vec<Vec<u8>>), RuntimeError>) {
+        let (call_sender, call_receiver) = mpsc::channel::<HostCall>();
+        *lock_host(&self.host) = HostContext {
+            gas_parameters,
+            events: vec![],
+            calls: Some(call_sender),
+        };
+
+        let host_slot = self.host.clone();
+        let (result_sender, result_receiver) = mpsc::channel();
+        let function = function.clone();
+        let args = args.to_vec();
+        let mut instance = self;
+        let job: WASMJob = Box::new(move || {
+            let result = {
+                let _guard = HostGuard(host_slot);
+                panic::catch_unwind(AssertUnwindSafe(|| {
+                    function.call(&mut instance.store, args.as_slice())
+                }))
+            };
+            let _ = result_sender.send((instance, result));
+        });
+        // The executor catches the panics of the jobs, so it keeps running
+        WASM_EXECUTOR
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .send(job)
+            .expect("the WASM executor should be running");
+
+        // The receiver is disconnected when the guard closes the host after the call
+        while let Ok(call) = call_receiver.recv() {
+            call(host);
+        }
+        let (instance, result) = result_receiver
+            .recv()
+            .expect("the WASM executor should return the instance");
+
+        let events = std::mem::take(&mut lock_host(&instance.host).events);
+        let result = match result {
+            Ok(Ok(ret)) => Ok((ret, events)),
+            Ok(Err(e)) => Err(e),
+            Err(_) => Err(RuntimeError::new("the WASM function call panicked")),
+        };
+        (instance, result)
+    }
 }
 
+type WASMJob = Box<dyn FnOnce() + Send>;
+
+/// The executor thread which runs the WASM function calls with a host.
+/// The calls are serialized by the global instance pool lock, so a single thread is enough.
+static WASM_EXECUTOR: Lazy<Mutex<Sender<WASMJob>>> = Lazy::new(|| {
+    let (job_sender, job_receiver) = mpsc::channel::<WASMJob>();
+    std::thread::Builder::new()
+        .name("wasm-executor".to_string())
+        .spawn(move || {
+            while let Ok(job) = job_receiver.recv() {
+                job();
+            }
+        })
+        .expect("failed to spawn the WASM executor thread");
+    Mutex::new(job_sender)
+});
+
 /*
 TODO:
 The WASMInstance must be protected by the locker which owned by the signer
@@ -88,9 +169,10 @@ pub fn remove_instance(instance_id: u64) -> anyhow::Result<()> {
 
 #[allow(dead_code)]
 #[derive(Clone)]
-struct Env {
-    memory: Option<Arc<Mutex<Memory>>>,
-    gas_meter: Arc<Mutex<GasMeter>>,
+pub(crate) struct Env {
+    pub(crate) memory: Option<Arc<Mutex<Memory>>>,
+    pub(crate) gas_meter: Arc<Mutex<GasMeter>>,
+    pub(crate) host: HostSlot,
 }
 
 fn js_log(env: FunctionEnvMut<Env>, ptr: i32, len: i32) {
@@ -291,11 +373,13 @@ pub fn create_wasm_instance(code: &[u8]) -> anyhow::Result<WASMInstance> {
         }
     };
 
+    let host: HostSlot = Arc::new(Mutex::new(HostContext::default()));
     let env = FunctionEnv::new(
         &mut store,
         Env {
             memory: None,
             gas_meter: gas_meter.clone(),
+            host: host.clone(),
         },
     );
 
@@ -310,6 +394,11 @@ pub fn create_wasm_instance(code: &[u8]) -> anyhow::Result<WASMInstance> {
             "js_log" => Function::new_typed_with_env(&mut store, &env, js_log),
             "charge" => Function::new_typed_with_env(&mut store, &env, charge),
         },
+        HOST_MODULE_NAME => {
+            "get_object" => Function::new_typed_with_env(&mut store, &env, host_get_object),
+            "emit_event" => Function::new_typed_with_env(&mut store, &env, host_emit_event),
+            "call_view_function" => Function::new_typed_with_env(&mut store, &env, host_call_view_function),
+        },
     };
 
     let instance = match Instance::new(&mut store, &module, &import_object) {
@@ -324,10 +413,7 @@ pub fn create_wasm_instance(code: &[u8]) -> anyhow::Result<WASMInstance> {
         env.as_mut(&mut store).memory = Some(Arc::new(Mutex::new(memory.clone())));
     }
 
-    Ok(WASMInstance::new(
-        bytecode.to_vec(),
-        instance,
-        store,
-        gas_meter,
-    ))
+    let mut wasm_instance = WASMInstance::new(bytecode.to_vec(), instance, store, gas_meter);
+    wasm_instance.host = host;
+    Ok(wasm_instance)
 }
@@ -9,6 +9,7 @@ use anyhow::Result;
 use move_binary_format::errors::{PartialVMError, PartialVMResult};
 use move_binary_format::file_format::CodeOffset;
 use move_core_types::account_address::AccountAddress;
+use move_core_types::effects::Op;
 use move_core_types::gas_algebra::{
     AbstractMemorySize, GasQuantity, InternalGas, InternalGasPerArg, InternalGasPerByte, NumArgs,
     NumBytes,
@@ -18,7 +19,10 @@ use move_core_types::vm_status::StatusCode;
 use move_vm_types::gas::{GasMeter, SimpleInstruction};
 use move_vm_types::views::{TypeView, ValueView};
 use moveos_common::types::{ClassifiedGasMeter, GasStatement, SwitchableGasMeter};
-use moveos_types::moveos_std::gas_schedule::GasSchedule;
+use moveos_types::moveos_std::event::TransactionEvent;
+use moveos_types::moveos_std::gas_schedule::{GasSchedule, GasScheduleConfig, PendingGasSchedule};
+use moveos_types::moveos_std::timestamp::Timestamp;
+use moveos_types::state::{FieldKey, ObjectChange, StateChangeSet};
 use moveos_types::state_resolver::StateResolver;
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
@@ -53,6 +57,25 @@ pub struct StorageGasParameter {
     pub storage_fee_per_op_delete: InternalGas,
 }
 
+/// The storage gas of a transaction, calculated with the storage gas parameters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StorageGasStatement {
+    /// The storage gas of the transaction size, it is charged in the execution
+    pub io_write_gas: u64,
+    /// The storage gas of the events
+    pub event_gas: u64,
+    /// The storage gas of the state changes
+    pub change_set_gas: u64,
+}
+
+impl StorageGasStatement {
+    pub fn total(&self) -> u64 {
+        self.io_write_gas
+            .saturating_add(self.event_gas)
+            .saturating_add(self.change_set_gas)
+    }
+}
+
 #[derive(Clone, Debug, Serialize, PartialEq, Eq, Deserialize)]
 pub struct AbstractValueSizeGasParameter {
     pub u8: AbstractValueSize,
@@ -762,6 +785,45 @@ impl MoveOSGasMeter {
 
         tx_size.checked_mul(factor_gas).unwrap_or(0)
     }
+
+    /// Calculate the storage gas of the events, it is not charged in the execution yet.
+    pub fn calculate_events_gas(&self, events: &[TransactionEvent]) -> u64 {
+        let event_gas_parameter: u64 = self
+            .cost_table
+            .storage_gas_parameter
+            .storage_fee_per_event_byte
+            .into();
+        events.iter().fold(0u64, |total, event| {
+            total
+                .saturating_add((event.event_data.len() as u64).saturating_mul(event_gas_parameter))
+        })
+    }
+
+    /// Calculate the storage gas of the state changes, it is not charged in the execution yet.
+    pub fn calculate_change_set_gas(&self, change_set: &StateChangeSet) -> u64 {
+        change_set.changes.iter().fold(0u64, |total, (_, change)| {
+            total.saturating_add(self.calculate_object_change_gas(change))
+        })
+    }
+
+    fn calculate_object_change_gas(&self, change: &ObjectChange) -> u64 {
+        let storage_gas_parameter = &self.cost_table.storage_gas_parameter;
+        let key_size = FieldKey::LENGTH as u64;
+        let value_gas = match &change.value {
+            Some(Op::New(value)) => (key_size + value.len() as u64)
+                .saturating_mul(storage_gas_parameter.storage_fee_per_op_new_byte.into()),
+            Some(Op::Modify(value)) => (key_size + value.len() as u64)
+                .saturating_mul(storage_gas_parameter.storage_fee_per_op_modify_byte.into()),
+            Some(Op::Delete) => storage_gas_parameter.storage_fee_per_op_delete.into(),
+            None => 0,
+        };
+        change
+            .fields
+            .iter()
+            .fold(value_gas, |total, (_, field_change)| {
+                total.saturating_add(self.calculate_object_change_gas(field_change))
+            })
+    }
 }
 
 impl ClassifiedGasMeter for MoveOSGasMeter {
@@ -1423,23 +1485,95 @@ pub fn misc_parameter_to_on_chain_gas_schedule(
     gas_parameter.to_on_chain_gas_schedule()
 }
 
-pub fn get_gas_schedule_entries<Resolver: StateResolver>(
+/// Get the gas schedule config used by the VM at the state.
+/// The pending gas schedule is used if the on-chain time reaches its activation time,
+/// otherwise the activation time is returned with the current gas schedule config,
+/// so the caller knows when the gas schedule should be reloaded.
+pub fn get_gas_schedule_config<Resolver: StateResolver + ?Sized>(
     state_resolver: &Resolver,
-) -> Result<Option<BTreeMap<String, u64>>> {
-    //TODO try to reuse the GasSchedule with the native function
-    let gas_schedule_state = state_resolver.get_object(&GasSchedule::gas_schedule_object_id())?;
-
-    match gas_schedule_state {
-        None => Ok(None),
-        Some(gas_schedule_state) => {
-            let gas_schedule = gas_schedule_state.into_object::<GasSchedule>()?;
-            let entries = gas_schedule
-                .value
-                .entries
-                .into_iter()
-                .map(|entry| (entry.key.to_string(), entry.val))
-                .collect::<BTreeMap<_, _>>();
-            Ok(Some(entries))
+) -> Result<Option<(GasScheduleConfig, Option<u64>)>> {
+    let (gas_schedule_config, pending_gas_schedule) =
+        match get_gas_schedule_with_pending(state_resolver)? {
+            None => return Ok(None),
+            Some(gas_schedule) => gas_schedule,
+        };
+    let pending_gas_schedule = match pending_gas_schedule {
+        None => return Ok(Some((gas_schedule_config, None))),
+        Some(pending_gas_schedule) => pending_gas_schedule,
+    };
+    if pending_gas_schedule.is_activated(get_now_milliseconds(state_resolver)?) {
+        Ok(Some((pending_gas_schedule.gas_schedule_config, None)))
+    } else {
+        Ok(Some((
+            gas_schedule_config,
+            Some(pending_gas_schedule.activation_time),
+        )))
+    }
+}
+
+/// Get the config of the `GasSchedule` object and the pending gas schedule at the state,
+/// the pending gas schedule is returned even if the on-chain time reaches its activation time.
+pub fn get_gas_schedule_with_pending<Resolver: StateResolver + ?Sized>(
+    state_resolver: &Resolver,
+) -> Result<Option<(GasScheduleConfig, Option<PendingGasSchedule>)>> {
+    let gas_schedule = match state_resolver.get_object(&GasSchedule::gas_schedule_object_id())? {
+        None => return Ok(None),
+        Some(gas_schedule_state) => gas_schedule_state.into_object::<GasSchedule>()?.value,
+    };
+    let gas_schedule_config = GasScheduleConfig {
+        max_gas_amount: gas_schedule.max_gas_amount,
+        entries: gas_schedule.entries,
+    };
+    Ok(Some((
+        gas_schedule_config,
+        get_pending_gas_schedule(state_resolver)?,
+    )))
+}
+
+pub fn get_pending_gas_schedule<Resolver: StateResolver + ?Sized>(
+    state_resolver: &Resolver,
+) -> Result<Option<PendingGasSchedule>> {
+    state_resolver
+        .get_object(&PendingGasSchedule::pending_gas_schedule_object_id())?
+        .map(|pending_state| Ok(pending_state.into_object::<PendingGasSchedule>()?.value))
+        .transpose()
+}
+
+/// Check whether the on-chain time reaches the activation time of the pending gas schedule,
+/// the pending gas schedule is applied to the `GasSchedule` by the first transaction after the activation time.
+pub fn is_pending_gas_schedule_activated<Resolver: StateResolver + ?Sized>(
+    state_resolver: &Resolver,
+) -> Result<bool> {
+    match get_pending_gas_schedule(state_resolver)? {
+        None => Ok(false),
+        Some(pending_gas_schedule) => {
+            Ok(pending_gas_schedule.is_activated(get_now_milliseconds(state_resolver)?))
         }
     }
 }
+
+/// Get the on-chain time in milliseconds at the state.
+pub fn get_now_milliseconds<Resolver: StateResolver + ?Sized>(
+    state_resolver: &Resolver,
+) -> Result<u64> {
+    Ok(state_resolver
+        .get_object(&Timestamp::object_id())?
+        .map(|timestamp_state| timestamp_state.into_object::<Timestamp>())
+        .transpose()?
+        .map(|timestamp| timestamp.value.milliseconds)
+        .unwrap_or_default())
+}
+
+pub fn get_gas_schedule_entries<Resolver: StateResolver + ?Sized>(
+    state_resolver: &Resolver,
+) -> Result<Option<BTreeMap<String, u64>>> {
+    //TODO try to reuse the GasSchedule with the native function
+    let entries = get_gas_schedule_config(state_resolver)?.map(|(gas_schedule_config, _)| {
+        gas_schedule_config
+            .entries
+            .into_iter()
+            .map(|entry| (entry.key.to_string(), entry.val))
+            .collect::<BTreeMap<_, _>>()
+    });
+    Ok(entries)
+}
@@ -5,4 +5,6 @@ pub mod gas;
 pub mod moveos;
 pub mod moveos_test_model_builder;
 pub mod moveos_test_runner;
+pub mod parallel;
+pub mod trace;
 pub mod vm;
@@ -2,8 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::gas::table::{
-    get_gas_schedule_entries, initial_cost_schedule, CostTable, MoveOSGasMeter,
+    get_gas_schedule_with_pending, get_now_milliseconds, initial_cost_schedule,
+    is_pending_gas_schedule_activated, CostTable, MoveOSGasMeter, StorageGasStatement,
 };
+use crate::parallel::{
+    collect_writes, is_upgrade_output, rebase_change_set, AccessRecorder, AccessSet, WriteSet,
+};
+use crate::trace::{ReadSetRecorder, TransactionTrace};
 use crate::vm::data_cache::MoveosDataCache;
 use crate::vm::moveos_vm::{MoveOSSession, MoveOSVM};
 use anyhow::{bail, format_err, Error, Result};
@@ -12,36 +17,42 @@ use move_binary_format::errors::VMError;
 use move_binary_format::errors::{vm_status_of_result, Location, PartialVMError, VMResult};
 use move_binary_format::file_format::FunctionDefinitionIndex;
 use move_binary_format::CompiledModule;
-use move_core_types::identifier::IdentStr;
+use move_core_types::gas_algebra::InternalGas;
 use move_core_types::language_storage::ModuleId;
 use move_core_types::value::MoveTypeLayout;
 use move_core_types::vm_status::{KeptVMStatus, VMStatus};
 use move_core_types::{
-    account_address::AccountAddress, ident_str, identifier::Identifier, vm_status::StatusCode,
+    account_address::AccountAddress, identifier::Identifier, vm_status::StatusCode,
 };
 use move_vm_runtime::config::VMConfig;
 use move_vm_runtime::data_cache::TransactionCache;
-use move_vm_runtime::native_functions::NativeFunction;
-use moveos_common::types::ClassifiedGasMeter;
+use move_vm_runtime::native_functions::{NativeFunction, NativeFunctionTable};
+use moveos_common::types::{ClassifiedGasMeter, SwitchableGasMeter};
+use moveos_gas_profiling::profiler::{new_gas_profiler, ProfileGasMeter};
 use moveos_store::config_store::ConfigDBStore;
 use moveos_store::event_store::EventDBStore;
+use moveos_store::state_store::overlay::StateOverlay;
 use moveos_store::state_store::statedb::StateDBStore;
 use moveos_store::transaction_store::TransactionDBStore;
 use moveos_store::{load_feature_store_object, MoveOSStore};
-use moveos_types::addresses::MOVEOS_STD_ADDRESS;
 use moveos_types::function_return_value::FunctionResult;
-use moveos_types::moveos_std::gas_schedule::{GasScheduleConfig, GasScheduleUpdated};
+use moveos_types::moveos_std::gas_schedule::{
+    GasScheduleConfig, GasScheduleModule, GasScheduleUpdated,
+};
 use moveos_types::moveos_std::object::ObjectMeta;
 use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::moveos_std::tx_result::TxResult;
-use moveos_types::state::{MoveStructState, MoveStructType, ObjectState};
-use moveos_types::state_resolver::{GenesisResolver, RootObjectResolver};
+use moveos_types::state::ObjectState;
+use moveos_types::state_override::ObjectOverride;
+use moveos_types::state_resolver::{GenesisResolver, RootObjectResolver, StatelessResolver};
 use moveos_types::transaction::{FunctionCall, VMErrorInfo};
 use moveos_types::transaction::{
-    MoveOSTransaction, RawTransactionOutput, VerifiedMoveAction, VerifiedMoveOSTransaction,
+    MoveAction, MoveOSTransaction, RawTransactionOutput, VerifiedMoveAction,
+    VerifiedMoveOSTransaction,
 };
 use parking_lot::RwLock;
-use serde::{Deserialize, Serialize};
+use rayon::prelude::*;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 #[derive(thiserror::Error, Debug)]
@@ -52,27 +63,6 @@ pub enum VMPanicError {
     SystemCallPanicError(Error),
 }
 
-#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
-pub struct GasPaymentAccount {
-    pub account: AccountAddress,
-    pub pay_gas_by_module_account: bool,
-}
-
-impl MoveStructType for GasPaymentAccount {
-    const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
-    const MODULE_NAME: &'static IdentStr = ident_str!("tx_context");
-    const STRUCT_NAME: &'static IdentStr = ident_str!("GasPaymentAccount");
-}
-
-impl MoveStructState for GasPaymentAccount {
-    fn struct_layout() -> move_core_types::value::MoveStructLayout {
-        move_core_types::value::MoveStructLayout::new(vec![
-            move_core_types::value::MoveTypeLayout::Address,
-            move_core_types::value::MoveTypeLayout::Bool,
-        ])
-    }
-}
-
 #[derive(Default)]
 pub struct MoveOSConfig {
     pub vm_config: VMConfig,
@@ -111,15 +101,28 @@ impl Clone for MoveOSConfig {
 
 pub struct MoveOS {
     vm: MoveOSVM,
+    /// The natives and the config to create an isolated VM, which does not share the loader cache with the `vm`.
+    natives: NativeFunctionTable,
+    config: MoveOSConfig,
     //MoveOS do not need to hold the db
     //It just need a StateResolver to get the state.
     //TODO remove the db from MoveOS
     db: MoveOSStore,
-    cost_table: Arc<RwLock<Option<CostTable>>>,
+    /// The cached cost table, and the cost table of the pending gas schedule with its activation time,
+    /// the pending cost table is used when the on-chain time of the root reaches the activation time.
+    cost_table: Arc<RwLock<Option<(CostTable, Option<(u64, CostTable)>)>>>,
+    /// The natives with the gas parameters of the pending gas schedule, they take effect with the pending cost table.
+    pending_natives: Option<PendingNatives>,
     system_pre_execute_functions: Vec<FunctionCall>,
     system_post_execute_functions: Vec<FunctionCall>,
 }
 
+struct PendingNatives {
+    activation_time: u64,
+    natives: NativeFunctionTable,
+    vm: MoveOSVM,
+}
+
 impl MoveOS {
     pub fn new(
         db: MoveOSStore,
@@ -130,16 +133,37 @@ impl MoveOS {
     ) -> Result<Self> {
         //TODO load the gas table from argument, and remove the cost_table lock.
 
-        let vm = MoveOSVM::new(natives, config.vm_config)?;
+        let natives: NativeFunctionTable = natives.into_iter().collect();
+        let vm = MoveOSVM::new(natives.clone(), config.clone().vm_config)?;
         Ok(Self {
             vm,
+            natives,
+            config,
             db,
             cost_table: Arc::new(RwLock::new(None)),
+            pending_natives: None,
             system_pre_execute_functions,
             system_post_execute_functions,
         })
     }
 
+    /// Set the natives with the gas parameters of the pending gas schedule, they are used with the pending cost table
+    /// from the activation time, so every node switches the cost table and the natives at the same transaction.
+    pub fn set_pending_natives(
+        &mut self,
+        activation_time: u64,
+        natives: impl IntoIterator<Item = (AccountAddress, Identifier, Identifier, NativeFunction)>,
+    ) -> Result<()> {
+        let natives: NativeFunctionTable = natives.into_iter().collect();
+        let vm = MoveOSVM::new(natives.clone(), self.config.clone().vm_config)?;
+        self.pending_natives = Some(PendingNatives {
+            activation_time,
+            natives,
+            vm,
+        });
+        Ok(())
+    }
+
     pub fn init_genesis(
         &self,
         genesis_tx: MoveOSTransaction,
@@ -186,8 +210,15 @@ impl MoveOS {
         // We use a scoped lock here to avoid holding the lock for a long time.
         {
             let rlock = self.cost_table.read();
-            if let Some(cost_table) = rlock.as_ref() {
-                return Ok(cost_table.clone());
+            if let Some((cost_table, pending_cost_table)) = rlock.as_ref() {
+                return match pending_cost_table {
+                    Some((activation_time, pending_cost_table))
+                        if self.is_activated_at(root, *activation_time)? =>
+                    {
+                        Ok(pending_cost_table.clone())
+                    }
+                    _ => Ok(cost_table.clone()),
+                };
             }
         }
 
@@ -195,21 +226,75 @@ impl MoveOS {
             tracing::trace!("load_cost_table from db");
         }
         let resolver = RootObjectResolver::new(root.clone(), &self.db);
-        let gas_entries = get_gas_schedule_entries(&resolver).map_err(|e| {
+        let gas_schedule = get_gas_schedule_with_pending(&resolver).map_err(|e| {
             PartialVMError::new(StatusCode::STORAGE_ERROR)
                 .with_message(format!("Load gas schedule entries failed: {}", e))
                 .finish(Location::Undefined)
         })?;
-        let cost_table = initial_cost_schedule(gas_entries);
+        let (cost_table, pending_cost_table) = match gas_schedule {
+            Some((gas_schedule_config, pending_gas_schedule)) => (
+                Self::cost_table_of(gas_schedule_config),
+                pending_gas_schedule.map(|pending| {
+                    (
+                        pending.activation_time,
+                        Self::cost_table_of(pending.gas_schedule_config),
+                    )
+                }),
+            ),
+            None => (initial_cost_schedule(None), None),
+        };
+        let activated_cost_table = match &pending_cost_table {
+            Some((activation_time, pending_cost_table))
+                if self.is_activated_at(root, *activation_time)? =>
+            {
+                pending_cost_table.clone()
+            }
+            _ => cost_table.clone(),
+        };
         match self.cost_table.try_write() {
             Some(mut w) => {
-                w.replace(cost_table.clone());
+                w.replace((cost_table, pending_cost_table));
             }
             None => {
                 tracing::warn!("load_cost_table try_write failed");
             }
         }
-        Ok(cost_table)
+        Ok(activated_cost_table)
+    }
+
+    fn cost_table_of(gas_schedule_config: GasScheduleConfig) -> CostTable {
+        let gas_entries = gas_schedule_config
+            .entries
+            .into_iter()
+            .map(|entry| (entry.key.to_string(), entry.val))
+            .collect::<BTreeMap<_, _>>();
+        initial_cost_schedule(Some(gas_entries))
+    }
+
+    /// Check whether the on-chain time at the root reaches the activation time of the pending gas schedule.
+    fn is_activated_at(&self, root: &ObjectMeta, activation_time: u64) -> VMResult<bool> {
+        let resolver = RootObjectResolver::new(root.clone(), &self.db);
+        let now_milliseconds = get_now_milliseconds(&resolver).map_err(|e| {
+            PartialVMError::new(StatusCode::STORAGE_ERROR)
+                .with_message(format!("Load timestamp failed: {}", e))
+                .finish(Location::Undefined)
+        })?;
+        Ok(now_milliseconds >= activation_time)
+    }
+
+    /// The natives in effect at the root, the natives of the pending gas schedule take effect with its cost table.
+    fn natives_at(&self, root: &ObjectMeta) -> VMResult<(&MoveOSVM, &NativeFunctionTable)> {
+        match &self.pending_natives {
+            Some(pending) if self.is_activated_at(root, pending.activation_time)? => {
+                Ok((&pending.vm, &pending.natives))
+            }
+            _ => Ok((&self.vm, &self.natives)),
+        }
+    }
+
+    /// The shared VM with the natives in effect at the root.
+    fn vm_at(&self, root: &ObjectMeta) -> VMResult<&MoveOSVM> {
+        Ok(self.natives_at(root)?.0)
     }
 
     pub fn state(&self) -> &StateDBStore {
@@ -263,6 +348,62 @@ impl MoveOS {
         &self,
         tx: VerifiedMoveOSTransaction,
     ) -> Result<(RawTransactionOutput, Option<VMErrorInfo>)> {
+        let cost_table = self.load_cost_table(&tx.root)?;
+        let vm = self.vm_at(&tx.root)?;
+        self.execute_with_resolver(vm, tx, &self.db, cost_table)
+    }
+
+    fn execute_with_resolver<R: StatelessResolver>(
+        &self,
+        vm: &MoveOSVM,
+        tx: VerifiedMoveOSTransaction,
+        store: &R,
+        cost_table: CostTable,
+    ) -> Result<(RawTransactionOutput, Option<VMErrorInfo>)> {
+        let gas_meter = self.new_gas_meter(&tx, store, cost_table);
+        self.execute_with_gas_meter(vm, tx, store, gas_meter)
+    }
+
+    /// Create a VM with its own loader cache and the natives in effect at the root, the modules loaded by it do not leak into the shared VM.
+    /// It is used to execute the transaction with the modules which are not in the latest state.
+    fn new_isolated_vm(&self, root: &ObjectMeta) -> Result<MoveOSVM> {
+        let (_, natives) = self.natives_at(root)?;
+        Ok(MoveOSVM::new(
+            natives.clone(),
+            self.config.clone().vm_config,
+        )?)
+    }
+
+    fn new_gas_meter<R: StatelessResolver>(
+        &self,
+        tx: &VerifiedMoveOSTransaction,
+        store: &R,
+        cost_table: CostTable,
+    ) -> MoveOSGasMeter {
+        let feature_resolver = RootObjectResolver::new(tx.root.clone(), store);
+        let feature_store_opt = load_feature_store_object(&feature_resolver);
+        let has_io_tired_write_feature = match feature_store_opt {
+            None => false,
+            Some(feature_store) => feature_store.has_value_size_gas_feature(),
+        };
+        MoveOSGasMeter::new(
+            cost_table,
+            tx.ctx.max_gas_amount,
+            has_io_tired_write_feature,
+        )
+    }
+
+    fn execute_with_gas_meter<R, G>(
+        &self,
+        vm: &MoveOSVM,
+        tx: VerifiedMoveOSTransaction,
+        store: &R,
+        gas_meter: G,
+    ) -> Result<(RawTransactionOutput, Option<VMErrorInfo>)>
+    where
+        R: StatelessResolver,
+        G: SwitchableGasMeter + ClassifiedGasMeter + Clone,
+    {
         let VerifiedMoveOSTransaction { root, ctx, action } = tx;
         let tx_hash = ctx.tx_hash();
         if tracing::enabled!(tracing::Level::DEBUG) {
@@ -279,27 +420,26 @@ impl MoveOS {
         // The variables in TxContext kv store before this executions should not be cleaned,
         // So we keep a backup here, and then insert to the TxContext kv store when session respawed.
         let system_env = ctx.map.clone();
-
-        let feature_resolver = RootObjectResolver::new(root.clone(), &self.db);
-        let feature_store_opt = load_feature_store_object(&feature_resolver);
-        let has_io_tired_write_feature = match feature_store_opt {
-            None => false,
-            Some(feature_store) => feature_store.has_value_size_gas_feature(),
-        };
-
-        let cost_table = self.load_cost_table(&root)?;
-        let gas_meter =
-            MoveOSGasMeter::new(cost_table, ctx.max_gas_amount, has_io_tired_write_feature);
         let tx_size = ctx.tx_size;
 
-        let resolver = RootObjectResolver::new(root, &self.db);
-        let mut session = self.vm.new_session(&resolver, ctx, gas_meter);
-
+        let resolver = RootObjectResolver::new(root, store);
+        // The pending gas schedule takes effect from the first transaction after the activation time,
+        // it is applied to the gas schedule before the transaction, even if the transaction is a system call.
+        let mut pre_execute_functions = vec![];
+        if is_pending_gas_schedule_activated(&resolver)? {
+            pre_execute_functions
+                .push(GasScheduleModule::activate_pending_gas_schedule_function_call());
+        }
         //We do not execute pre_execute and post_execute functions for system call
         if !is_system_call {
+            pre_execute_functions.extend(self.system_pre_execute_functions.clone());
+        }
+        let mut session = vm.new_session(&resolver, ctx, gas_meter);
+
+        if !pre_execute_functions.is_empty() {
             // system pre_execute
             // we do not charge gas for system_pre_execute function
-            match session.execute_function_call(self.system_pre_execute_functions.clone(), false) {
+            match session.execute_function_call(pre_execute_functions.clone(), false) {
                 Ok(_) => {}
                 Err(error) => {
                     tracing::warn!("System pre execution failed: {:?}", error);
@@ -342,7 +482,7 @@ impl MoveOS {
                 if !is_system_call {
                     let mut s = session.respawn(system_env);
                     //Because the session is respawned, the pre_execute function should be called again.
-                    s.execute_function_call(self.system_pre_execute_functions.clone(), false)
+                    s.execute_function_call(pre_execute_functions, false)
                         .expect("system_pre_execute should not fail.");
                     self.execution_cleanup(
                         is_system_call,
@@ -369,6 +509,220 @@ impl MoveOS {
         self.execute(tx)
     }
 
+    /// Execute the transaction with the object overrides applied on top of the transaction root,
+    /// the overrides are kept in memory and the execution output is not applied to the state.
+    pub fn execute_only_with_overrides(
+        &self,
+        mut tx: VerifiedMoveOSTransaction,
+        overrides: Vec<ObjectOverride>,
+    ) -> Result<(RawTransactionOutput, Option<VMErrorInfo>)> {
+        let module_overridden = overrides.iter().any(|o| o.is_module());
+        // The gas schedule is loaded from the original state, the overrides can not change it.
+        let cost_table = self.load_cost_table(&tx.root)?;
+        // The loader caches the modules across transactions, the overridden modules are loaded by an isolated VM,
+        // so they do not leak into the shared loader cache.
+        let isolated_vm = if module_overridden {
+            Some(self.new_isolated_vm(&tx.root)?)
+        } else {
+            None
+        };
+        let vm = match &isolated_vm {
+            Some(vm) => vm,
+            None => self.vm_at(&tx.root)?,
+        };
+        let overlay = StateOverlay::new(self.db.get_state_store(), tx.root.clone(), overrides)?;
+        tx.root = overlay.root().clone();
+        self.execute_with_resolver(vm, tx, &overlay, cost_table)
+    }
+
+    /// Re-execute the transaction with the gas profiler to trace the calls, the execution output is not applied to the state.
+    /// The transaction should be verified at its pre-state root.
+    pub fn trace(&self, tx: VerifiedMoveOSTransaction) -> Result<TransactionTrace> {
+        let cost_table = self.load_cost_table(&tx.root)?;
+        let recorder = ReadSetRecorder::new(&self.db);
+        let gas_meter = self.new_gas_meter(&tx, &recorder, cost_table);
+        let mut gas_profiler = new_gas_profiler(MoveAction::from(tx.action.clone()), gas_meter);
+        // The loader caches the modules of the latest state, the modules at the pre-state root are loaded by an isolated VM,
+        // so the historical modules do not leak into the shared loader cache.
+        let vm = self.new_isolated_vm(&tx.root)?;
+        let result = self.execute_with_gas_meter(&vm, tx, &recorder, gas_profiler.clone());
+        let (raw_output, vm_error_info) = result?;
+        // The call frames are shared with the cloned profiler, but the gas balance is not,
+        // so the total gas is taken from the execution output.
+        let mut gas_log = gas_profiler.finish();
+        gas_log.exec_io.total = InternalGas::new(raw_output.gas_used);
+        Ok(TransactionTrace {
+            raw_output,
+            vm_error_info,
+            gas_log,
+            read_set: recorder.into_read_set(),
+        })
+    }
+
+    /// Execute the transaction at its root and record the state accessed by the transaction.
+    fn execute_speculative(
+        &self,
+        vm: &MoveOSVM,
+        tx: VerifiedMoveOSTransaction,
+    ) -> Result<(RawTransactionOutput, AccessSet)> {
+        let cost_table = self.load_cost_table(&tx.root)?;
+        let recorder = AccessRecorder::new(&self.db, &tx.root);
+        let (raw_output, _) = self.execute_with_resolver(vm, tx, &recorder, cost_table)?;
+        Ok((raw_output, recorder.into_access_set()))
+    }
+
+    fn execute_at(
+        &self,
+        tx: &VerifiedMoveOSTransaction,
+        root: &ObjectMeta,
+    ) -> Result<RawTransactionOutput> {
+        let mut tx = tx.clone();
+        tx.root = root.clone();
+        let (raw_output, _) = self.execute(tx)?;
+        Ok(raw_output)
+    }
+
+    /// Execute the sequenced transactions optimistically in parallel, the result is the same as executing them one by one.
+    /// In each round, the remaining transactions are executed at the same state root in parallel, then the outputs are committed in order.
+    /// An output is rebased onto the latest state root if the state it accessed is not changed by the previous transactions,
+    /// otherwise the transaction is re-executed at the latest state root.
+    /// The `commit` function applies the output of the transaction at the index, and returns the new root.
+    /// It stops after the transaction which updates the gas schedule, the natives should be reloaded before the remaining transactions.
+    /// Returns the number of the re-executed transactions.
+    pub fn execute_parallel<F>(
+        &self,
+        mut root: ObjectMeta,
+        txs: &[VerifiedMoveOSTransaction],
+        mut commit: F,
+    ) -> Result<usize>
+    where
+        F: FnMut(usize, RawTransactionOutput) -> Result<ObjectMeta>,
+    {
+        let mut reexecuted = 0;
+        let mut start = 0;
+        // The speculative transactions may publish modules, they are executed with an isolated VM,
+        // so the speculative modules never leak into the loader cache of the shared VM.
+        // The VM is shared by the rounds of the batch, and rebuilt only after a module upgrade.
+        let mut speculative_vm: Option<MoveOSVM> = None;
+        'round: while start < txs.len() {
+            // The transaction activating the pending gas schedule applies it to the gas schedule, so it is executed alone.
+            if is_pending_gas_schedule_activated(&RootObjectResolver::new(root.clone(), &self.db))?
+            {
+                let raw_output = self.execute_at(&txs[start], &root)?;
+                commit(start, raw_output)?;
+                reexecuted += 1;
+                break;
+            }
+            let base = root.clone();
+            if speculative_vm.is_none() {
+                speculative_vm = Some(self.new_isolated_vm(&base)?);
+            }
+            let vm = speculative_vm
+                .as_ref()
+                .expect("speculative vm should be created");
+            let results = txs[start..]
+                .par_iter()
+                .map(|tx| {
+                    let mut tx = tx.clone();
+                    tx.root = base.clone();
+                    self.execute_speculative(vm, tx)
+                })
+                .collect::<Vec<_>>();
+
+            // The transactions after a module or gas schedule upgrade should be executed with the new code and gas schedule,
+            // so the round is discarded, and the transactions are executed one by one until the upgrade.
+            if let Some(upgrade) = results
+                .iter()
+                .position(|result| matches!(result, Ok((output, _)) if is_upgrade_output(output)))
+            {
+                // The speculative upgrade may have loaded the new modules into the speculative VM.
+                speculative_vm = None;
+                for (index, tx) in txs.iter().enumerate().skip(start).take(upgrade + 1) {
+                    let raw_output = self.execute_at(tx, &root)?;
+                    let is_module_upgrade = raw_output.is_upgrade;
+                    let is_gas_upgrade = raw_output.is_gas_upgrade;
+                    root = commit(index, raw_output)?;
+                    self.flush_module_cache(is_module_upgrade)?;
+                    if is_gas_upgrade {
+                        reexecuted += index - start + 1;
+                        break 'round;
+                    }
+                }
+                reexecuted += upgrade + 1;
+                start += upgrade + 1;
+                continue;
+            }
+
+            let base_resolver = RootObjectResolver::new(base.clone(), &self.db);
+            let mut writes = WriteSet::new();
+            for (offset, result) in results.into_iter().enumerate() {
+                let index = start + offset;
+                let current_resolver = RootObjectResolver::new(root.clone(), &self.db);
+                // The speculative output is executed with the gas schedule before the activation time.
+                let gas_schedule_activated = is_pending_gas_schedule_activated(&current_resolver)?;
+                let raw_output = match result {
+                    Ok((mut raw_output, access_set))
+                        if !gas_schedule_activated
+                            && !access_set.conflicts_with(&raw_output.changeset, &writes) =>
+                    {
+                        rebase_change_set(
+                            &mut raw_output.changeset,
+                            &writes,
+                            &base_resolver,
+                            &current_resolver,
+                        )?;
+                        raw_output
+                    }
+                    _ => {
+                        reexecuted += 1;
+                        self.execute_at(&txs[index], &root)?
+                    }
+                };
+                // Only a re-executed transaction can be an upgrade or activate the pending gas schedule here.
+                let is_upgrade = is_upgrade_output(&raw_output);
+                let is_module_upgrade = raw_output.is_upgrade;
+                let is_gas_upgrade = raw_output.is_gas_upgrade;
+                collect_writes(&raw_output.changeset, &current_resolver, &mut writes)?;
+                root = commit(index, raw_output)?;
+                self.flush_module_cache(is_module_upgrade)?;
+                if is_gas_upgrade {
+                    break 'round;
+                }
+                if is_upgrade {
+                    if is_module_upgrade {
+                        speculative_vm = None;
+                    }
+                    start = index + 1;
+                    continue 'round;
+                }
+            }
+            start = txs.len();
+        }
+        Ok(reexecuted)
+    }
+
+    /// Calculate the storage gas of the transaction size, the events and the state changes of the output.
+    /// Only the storage gas of the transaction size is charged in the execution, it is the same as the `charge_io_write`.
+    pub fn storage_gas(
+        &self,
+        root: &ObjectMeta,
+        tx_size: u64,
+        output: &RawTransactionOutput,
+    ) -> Result<StorageGasStatement> {
+        let feature_resolver = RootObjectResolver::new(root.clone(), &self.db);
+        let has_io_tired_write_feature = match load_feature_store_object(&feature_resolver) {
+            None => false,
+            Some(feature_store) => feature_store.has_value_size_gas_feature(),
+        };
+        let cost_table = self.load_cost_table(root)?;
+        let gas_meter = MoveOSGasMeter::new(cost_table, 0, has_io_tired_write_feature);
+        Ok(StorageGasStatement {
+            io_write_gas: gas_meter.calculate_io_writes_gas(tx_size),
+            event_gas: gas_meter.calculate_events_gas(&output.events),
+            change_set_gas: gas_meter.calculate_change_set_gas(&output.changeset),
+        })
+    }
+
     /// Execute readonly view function
     pub fn execute_view_function(
         &self,
@@ -381,11 +735,44 @@ impl MoveOS {
         self.execute_readonly_function(root, &tx_context, function_call)
     }
 
+    /// Execute readonly view function at a historical root.
+    /// The loader caches the modules of the latest state, the modules at the historical root are loaded by an isolated VM,
+    /// so the historical state does not run against the latest modules, and the historical modules do not leak into the shared loader cache.
+    pub fn execute_historical_view_function(
+        &self,
+        root: ObjectMeta,
+        function_call: FunctionCall,
+    ) -> FunctionResult {
+        let tx_context = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let vm = match self.new_isolated_vm(&root) {
+            Ok(vm) => vm,
+            Err(e) => {
+                return FunctionResult::err(
+                    PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                        .with_message(format!("Create isolated VM failed: {}", e))
+                        .finish(Location::Undefined),
+                );
+            }
+        };
+        self.execute_readonly_function_with_vm(Some(&vm), root, &tx_context, function_call)
+    }
+
     pub fn execute_readonly_function(
         &self,
         root: ObjectMeta,
         tx_context: &TxContext,
         function_call: FunctionCall,
+    ) -> FunctionResult {
+        self.execute_readonly_function_with_vm(None, root, tx_context, function_call)
+    }
+
+    /// Execute readonly function with the given VM, the shared VM at the root is used if it is None.
+    fn execute_readonly_function_with_vm(
+        &self,
+        vm: Option<&MoveOSVM>,
+        root: ObjectMeta,
+        tx_context: &TxContext,
+        function_call: FunctionCall,
     ) -> FunctionResult {
         if tx_context.max_gas_amount > GasScheduleConfig::READONLY_MAX_GAS_AMOUNT {
             return FunctionResult::err(
@@ -414,10 +801,17 @@ impl MoveOS {
             has_io_tired_write_feature,
         );
         gas_meter.set_metering(true);
+        let vm = match vm {
+            Some(vm) => vm,
+            None => match self.vm_at(&root) {
+                Ok(vm) => vm,
+                Err(e) => {
+                    return FunctionResult::err(e);
+                }
+            },
+        };
         let resolver = RootObjectResolver::new(root, &self.db);
-        let mut session = self
-            .vm
-            .new_readonly_session(&resolver, tx_context.clone(), gas_meter);
+        let mut session = vm.new_readonly_session(&resolver, tx_context.clone(), gas_meter);
 
         let result = session.execute_function_bypass_visibility(function_call);
         match result {
@@ -440,9 +834,9 @@ impl MoveOS {
     // Execute action with pre_execute and post_execute.
     // Return the action execution status if success,
     // else return VMError and a bool which indicate if we should respawn the session.
-    fn execute_action(
+    fn execute_action<R: StatelessResolver, G: SwitchableGasMeter + ClassifiedGasMeter>(
         &self,
-        session: &mut MoveOSSession<'_, '_, RootObjectResolver<MoveOSStore>, MoveOSGasMeter>,
+        session: &mut MoveOSSession<'_, '_, RootObjectResolver<R>, G>,
         action: VerifiedMoveAction,
         tx_size: u64,
     ) -> Result<(), VMError> {
@@ -455,10 +849,10 @@ impl MoveOS {
         session.execute_move_action(action)
     }
 
-    fn execution_cleanup(
+    fn execution_cleanup<R: StatelessResolver, G: SwitchableGasMeter + ClassifiedGasMeter>(
         &self,
         is_system_call: bool,
-        mut session: MoveOSSession<'_, '_, RootObjectResolver<MoveOSStore>, MoveOSGasMeter>,
+        mut session: MoveOSSession<'_, '_, RootObjectResolver<R>, G>,
         status: VMStatus,
         vm_error_info: Option<VMErrorInfo>,
     ) -> Result<(RawTransactionOutput, Option<VMErrorInfo>)> {
@@ -525,14 +919,17 @@ impl MoveOS {
     pub fn flush_module_cache(&self, is_upgrade: bool) -> Result<()> {
         if is_upgrade {
             self.vm.mark_loader_cache_as_invalid();
+            if let Some(pending) = &self.pending_natives {
+                pending.vm.mark_loader_cache_as_invalid();
+            }
         };
         Ok(())
     }
 }
 
-fn extract_execution_state(
+fn extract_execution_state<R: StatelessResolver>(
     vm_err: VMError,
-    data_cache: &MoveosDataCache<RootObjectResolver<MoveOSStore>>,
+    data_cache: &MoveosDataCache<RootObjectResolver<R>>,
 ) -> Result<Vec<String>> {
     let mut execution_stack_trace = Vec::new();
     if let Some(exec_state) = vm_err.exec_state() {
@@ -557,10 +954,10 @@ fn extract_execution_state(
     Ok(execution_stack_trace)
 }
 
-fn func_name_from_db(
+fn func_name_from_db<R: StatelessResolver>(
     module_id: &ModuleId,
     func_idx: &FunctionDefinitionIndex,
-    data_cache: &MoveosDataCache<RootObjectResolver<MoveOSStore>>,
+    data_cache: &MoveosDataCache<RootObjectResolver<R>>,
 ) -> Result<String> {
     let module_bytes = data_cache.load_module(module_id)?;
     let compiled_module = CompiledModule::deserialize(module_bytes.as_slice())?;
@@ -0,0 +1,314 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{format_err, Error, Result};
+use move_core_types::effects::Op;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::module_store::ModuleStore;
+use moveos_types::moveos_std::object::{ObjectID, ObjectMeta};
+use moveos_types::state::{FieldKey, ObjectChange, ObjectState, StateChangeSet};
+use moveos_types::state_resolver::{StateKV, StateResolver, StatelessResolver};
+use moveos_types::transaction::RawTransactionOutput;
+use parking_lot::RwLock;
+use std::collections::btree_map::Entry as BTreeEntry;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// The state accessed by a transaction executed speculatively at a base state root.
+#[derive(Debug, Clone, Default)]
+pub struct AccessSet {
+    /// The objects read by the transaction, including the fields which are not found
+    pub reads: BTreeSet<ObjectID>,
+    /// The objects whose fields are listed by the transaction
+    pub scans: BTreeSet<ObjectID>,
+    /// The objects whose field size is read by the transaction
+    pub size_reads: BTreeSet<ObjectID>,
+    /// The transaction read a state root which can not be mapped to an object,
+    /// the access set is incomplete and the transaction should be re-executed.
+    pub unknown: bool,
+}
+
+/// The kind of change made to an object by the committed transactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WriteKind {
+    /// Only the fields of the object are changed, so the field size and the state root of the object may be changed.
+    Fields,
+    /// The object is created, removed, or the value or the metadata of the object is changed.
+    Value,
+}
+
+/// The objects changed by the transactions committed after the base state root of a round.
+pub type WriteSet = BTreeMap<ObjectID, WriteKind>;
+
+impl AccessSet {
+    /// Check whether the output of the transaction executed at the base state root is stale,
+    /// because the state it accessed is changed by the writes committed after the base state root.
+    pub fn conflicts_with(&self, changeset: &StateChangeSet, writes: &WriteSet) -> bool {
+        if self.unknown {
+            return true;
+        }
+        if self
+            .reads
+            .iter()
+            .any(|id| writes.get(id) == Some(&WriteKind::Value))
+        {
+            return true;
+        }
+        if self.size_reads.iter().any(|id| writes.contains_key(id)) {
+            return true;
+        }
+        if !self.scans.is_empty()
+            && writes
+                .keys()
+                .filter_map(|id| id.parent())
+                .any(|parent| self.scans.contains(&parent))
+        {
+            return true;
+        }
+        changeset
+            .changes
+            .values()
+            .any(|change| change_conflicts_with(change, writes))
+    }
+}
+
+fn change_conflicts_with(change: &ObjectChange, writes: &WriteSet) -> bool {
+    match writes.get(&change.metadata.id) {
+        None => false,
+        Some(WriteKind::Value) => true,
+        // The fields of a removed object can not be rebased
+        Some(WriteKind::Fields) if matches!(change.value, Some(Op::Delete)) => true,
+        Some(WriteKind::Fields) => change
+            .fields
+            .values()
+            .any(|field| change_conflicts_with(field, writes)),
+    }
+}
+
+struct AccessRecorderState {
+    /// The state root of the loaded objects, it is used to find the objects of the fields read by the state root.
+    /// Different objects may share the same state root, for example, the objects without fields,
+    /// the fields read by the state root are recorded as the reads of all of them.
+    objects: HashMap<H256, BTreeSet<ObjectID>>,
+    access_set: AccessSet,
+}
+
+impl AccessRecorderState {
+    fn index(&mut self, state: &ObjectState) {
+        self.objects
+            .entry(state.metadata.state_root())
+            .or_default()
+            .insert(state.metadata.id.clone());
+    }
+}
+
+/// AccessRecorder records the state accessed by a transaction from the underlying resolver.
+/// The fields are read by the state root of the object, so the recorder maps the state roots
+/// of the loaded objects to the object ids, starting from the root object.
+/// The modules cached by the VM loader are not read again, so the module upgrades should be handled by the caller.
+pub struct AccessRecorder<'a, R> {
+    resolver: &'a R,
+    state: RwLock<AccessRecorderState>,
+}
+
+impl<'a, R> AccessRecorder<'a, R> {
+    pub fn new(resolver: &'a R, root: &ObjectMeta) -> Self {
+        let mut state = AccessRecorderState {
+            objects: HashMap::new(),
+            access_set: AccessSet::default(),
+        };
+        state
+            .objects
+            .entry(root.state_root())
+            .or_default()
+            .insert(ObjectID::root());
+        Self {
+            resolver,
+            state: RwLock::new(state),
+        }
+    }
+
+    pub fn into_access_set(self) -> AccessSet {
+        self.state.into_inner().access_set
+    }
+}
+
+impl<R: StatelessResolver> StatelessResolver for AccessRecorder<'_, R> {
+    fn get_field_at(&self, state_root: H256, key: &FieldKey) -> Result<Option<ObjectState>, Error> {
+        let result = self.resolver.get_field_at(state_root, key)?;
+        let mut state = self.state.write();
+        let state = &mut *state;
+        match state.objects.get(&state_root) {
+            Some(parents) => state
+                .access_set
+                .reads
+                .extend(parents.iter().map(|parent| parent.child_id(*key))),
+            None => state.access_set.unknown = true,
+        }
+        if let Some(obj) = &result {
+            state.index(obj);
+        }
+        Ok(result)
+    }
+
+    fn list_fields_at(
+        &self,
+        state_root: H256,
+        cursor: Option<FieldKey>,
+        limit: usize,
+    ) -> Result<Vec<StateKV>> {
+        let result = self.resolver.list_fields_at(state_root, cursor, limit)?;
+        let mut state = self.state.write();
+        let state = &mut *state;
+        match state.objects.get(&state_root) {
+            Some(parents) => state.access_set.scans.extend(parents.iter().cloned()),
+            None => state.access_set.unknown = true,
+        }
+        for (_, obj) in &result {
+            state.index(obj);
+        }
+        Ok(result)
+    }
+
+    fn record_size_read(&self, object_id: &ObjectID) {
+        self.state
+            .write()
+            .access_set
+            .size_reads
+            .insert(object_id.clone());
+        self.resolver.record_size_read(object_id)
+    }
+}
+
+/// The output which changes the modules or the gas schedule can not be executed in parallel with the other transactions,
+/// because the following transactions should be executed with the new code and gas schedule.
+pub fn is_upgrade_output(output: &RawTransactionOutput) -> bool {
+    output.is_upgrade
+        || output.is_gas_upgrade
+        || output
+            .changeset
+            .changes
+            .contains_key(&ModuleStore::object_id().field_key())
+}
+
+/// Collect the objects changed by the change set into the write set.
+/// The resolver should be at the state root before the change set is applied.
+pub fn collect_writes<R: StateResolver>(
+    changeset: &StateChangeSet,
+    resolver: &R,
+    writes: &mut WriteSet,
+) -> Result<()> {
+    if !changeset.changes.is_empty() {
+        add_write(writes, ObjectID::root(), WriteKind::Fields);
+    }
+    for change in changeset.changes.values() {
+        collect_object_writes(change, resolver, writes)?;
+    }
+    Ok(())
+}
+
+fn collect_object_writes<R: StateResolver>(
+    change: &ObjectChange,
+    resolver: &R,
+    writes: &mut WriteSet,
+) -> Result<()> {
+    let id = change.metadata.id.clone();
+    let kind = if change.value.is_some() {
+        WriteKind::Value
+    } else {
+        match resolver.get_object(&id)? {
+            Some(pre) if only_fields_changed(&pre.metadata, &change.metadata) => WriteKind::Fields,
+            _ => WriteKind::Value,
+        }
+    };
+    add_write(writes, id, kind);
+    for field in change.fields.values() {
+        collect_object_writes(field, resolver, writes)?;
+    }
+    Ok(())
+}
+
+fn only_fields_changed(pre: &ObjectMeta, post: &ObjectMeta) -> bool {
+    let mut pre = pre.clone();
+    pre.state_root = post.state_root;
+    pre.size = post.size;
+    &pre == post
+}
+
+fn add_write(writes: &mut WriteSet, id: ObjectID, kind: WriteKind) {
+    match writes.entry(id) {
+        BTreeEntry::Occupied(mut entry) => {
+            if kind > *entry.get() {
+                entry.insert(kind);
+            }
+        }
+        BTreeEntry::Vacant(entry) => {
+            entry.insert(kind);
+        }
+    }
+}
+
+/// Rebase the change set of a transaction executed at the `base` state root onto the `current` state root.
+/// The transaction should not conflict with the writes between the two state roots, so the objects changed by the
+/// writes only have their fields changed, and the fields changed by the transaction are not touched by the writes.
+pub fn rebase_change_set<B: StateResolver, C: StateResolver>(
+    changeset: &mut StateChangeSet,
+    writes: &WriteSet,
+    base: &B,
+    current: &C,
+) -> Result<()> {
+    changeset.state_root = current.root().state_root();
+    changeset.global_size = rebase_size(
+        &ObjectID::root(),
+        changeset.global_size,
+        base.root().size,
+        current.root().size,
+    )?;
+    for change in changeset.changes.values_mut() {
+        rebase_object_change(change, writes, base, current)?;
+    }
+    Ok(())
+}
+
+fn rebase_object_change<B: StateResolver, C: StateResolver>(
+    change: &mut ObjectChange,
+    writes: &WriteSet,
+    base: &B,
+    current: &C,
+) -> Result<()> {
+    let id = change.metadata.id.clone();
+    // The descendants of an object are not changed if the object is not changed.
+    if !writes.contains_key(&id) {
+        return Ok(());
+    }
+    let base_obj = base
+        .get_object(&id)?
+        .ok_or_else(|| format_err!("Object {} not found at the base state root", id))?;
+    let current_obj = current
+        .get_object(&id)?
+        .ok_or_else(|| format_err!("Object {} not found at the current state root", id))?;
+    change.metadata.state_root = current_obj.metadata.state_root;
+    change.metadata.size = rebase_size(
+        &id,
+        change.metadata.size,
+        base_obj.metadata.size,
+        current_obj.metadata.size,
+    )?;
+    for field in change.fields.values_mut() {
+        rebase_object_change(field, writes, base, current)?;
+    }
+    Ok(())
+}
+
+fn rebase_size(id: &ObjectID, size: u64, base: u64, current: u64) -> Result<u64> {
+    size.checked_add(current)
+        .and_then(|size| size.checked_sub(base))
+        .ok_or_else(|| {
+            format_err!(
+                "Rebase the field size of object {} failed, size: {}, base: {}, current: {}",
+                id,
+                size,
+                base,
+                current
+            )
+        })
+}
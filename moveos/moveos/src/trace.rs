@@ -0,0 +1,66 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{Error, Result};
+use moveos_gas_profiling::log::TransactionGasLog;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::object::ObjectID;
+use moveos_types::state::{FieldKey, ObjectState};
+use moveos_types::state_resolver::{StateKV, StatelessResolver};
+use moveos_types::transaction::{RawTransactionOutput, VMErrorInfo};
+use parking_lot::RwLock;
+use std::collections::BTreeSet;
+
+/// The result of re-executing a transaction with the gas profiler.
+#[derive(Debug, Clone)]
+pub struct TransactionTrace {
+    pub raw_output: RawTransactionOutput,
+    pub vm_error_info: Option<VMErrorInfo>,
+    /// The call tree with the gas used by each call
+    pub gas_log: TransactionGasLog,
+    /// The objects read from the state store during the execution
+    pub read_set: Vec<ObjectID>,
+}
+
+/// ReadSetRecorder records the objects read from the underlying resolver.
+/// The modules cached by the VM loader are not read again, so they may be missing in the read set.
+pub struct ReadSetRecorder<'a, R> {
+    resolver: &'a R,
+    read_set: RwLock<BTreeSet<ObjectID>>,
+}
+
+impl<'a, R> ReadSetRecorder<'a, R> {
+    pub fn new(resolver: &'a R) -> Self {
+        Self {
+            resolver,
+            read_set: RwLock::new(BTreeSet::new()),
+        }
+    }
+
+    pub fn into_read_set(self) -> Vec<ObjectID> {
+        self.read_set.into_inner().into_iter().collect()
+    }
+}
+
+impl<R: StatelessResolver> StatelessResolver for ReadSetRecorder<'_, R> {
+    fn get_field_at(&self, state_root: H256, key: &FieldKey) -> Result<Option<ObjectState>, Error> {
+        let state = self.resolver.get_field_at(state_root, key)?;
+        if let Some(state) = &state {
+            self.read_set.write().insert(state.metadata.id.clone());
+        }
+        Ok(state)
+    }
+
+    fn list_fields_at(
+        &self,
+        state_root: H256,
+        cursor: Option<FieldKey>,
+        limit: usize,
+    ) -> Result<Vec<StateKV>> {
+        let states = self.resolver.list_fields_at(state_root, cursor, limit)?;
+        self.read_set
+            .write()
+            .extend(states.iter().map(|(_, state)| state.metadata.id.clone()));
+        Ok(states)
+    }
+}
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::data_cache::{into_change_set, MoveosDataCache};
+use super::tx_argument_resolver::fill_batch_call_args;
 use move_binary_format::compatibility::Compatibility;
 use move_binary_format::file_format::CompiledScript;
 use move_binary_format::normalized;
@@ -46,7 +47,7 @@ use moveos_types::{
     moveos_std::tx_context::TxContext,
     moveos_std::{event::TransactionEvent, module_upgrade_flag::ModuleUpgradeFlag},
     state_resolver::MoveOSResolver,
-    transaction::{FunctionCall, MoveAction, VerifiedMoveAction},
+    transaction::{BatchCall, FunctionCall, MoveAction, VerifiedMoveAction},
 };
 use moveos_verifier::verifier::INIT_FN_NAME_IDENTIFIER;
 use parking_lot::RwLock;
@@ -54,6 +55,9 @@ use std::collections::BTreeSet;
 use std::rc::Rc;
 use std::{borrow::Borrow, sync::Arc};
 
+/// The max number of function calls in a batch transaction
+pub const MAX_BATCH_CALLS: usize = 64;
+
 /// MoveOSVM is a wrapper of MoveVM with MoveOS specific features.
 pub struct MoveOSVM {
     inner: MoveVM,
@@ -301,7 +305,121 @@ where
                     init_function_modules,
                 })
             }
+            MoveAction::Batch(calls) => {
+                if calls.is_empty() || calls.len() > MAX_BATCH_CALLS {
+                    return Err(
+                        PartialVMError::new(StatusCode::NUMBER_OF_ARGUMENTS_MISMATCH)
+                            .with_message(format!(
+                                "The batch should contain 1 to {} calls, got:{}",
+                                MAX_BATCH_CALLS,
+                                calls.len()
+                            ))
+                            .finish(Location::Undefined),
+                    );
+                }
+                let mut return_types: Vec<Vec<TypeTag>> = Vec::with_capacity(calls.len());
+                for batch_call in calls.iter() {
+                    let call = &batch_call.call;
+                    let (loaded_function, _is_entry) = self.load_batch_function(call)?;
+                    let location = Location::Module(call.function_id.module_id.clone());
+                    self.verify_batch_call_args(
+                        &loaded_function,
+                        batch_call,
+                        &return_types,
+                        location.clone(),
+                    )?;
+                    // The arguments filled by the return values are only known at execution
+                    if batch_call.result_args.is_empty() {
+                        let _resolved_args = self.resolve_argument(
+                            &loaded_function,
+                            call.args.clone(),
+                            location.clone(),
+                            false,
+                        )?;
+                    }
+                    let call_return_types = loaded_function
+                        .return_
+                        .iter()
+                        .map(|ty| {
+                            let ty = ty
+                                .subst(&loaded_function.type_arguments)
+                                .map_err(|e| e.finish(location.clone()))?;
+                            self.get_type_tag(&ty)
+                        })
+                        .collect::<VMResult<Vec<_>>>()?;
+                    return_types.push(call_return_types);
+                }
+                Ok(VerifiedMoveAction::Batch { calls })
+            }
+        }
+    }
+
+    /// Load the function of a call in the batch and check it can be called in a batch.
+    /// Return the loaded function and whether it is an entry function.
+    fn load_batch_function(
+        &self,
+        call: &FunctionCall,
+    ) -> VMResult<(LoadedFunctionInstantiation, bool)> {
+        let location = Location::Module(call.function_id.module_id.clone());
+        let module = self
+            .vm
+            .load_module(&call.function_id.module_id, &self.remote)?;
+        let is_entry = moveos_verifier::verifier::verify_batch_function_visibility(
+            &module,
+            &call.function_id.function_name,
+        )
+        .map_err(|e| e.finish(location.clone()))?;
+        let loaded_function = self.session.load_function(
+            &call.function_id.module_id,
+            &call.function_id.function_name,
+            call.ty_args.as_slice(),
+        )?;
+        moveos_verifier::verifier::verify_batch_function(&loaded_function, &self.session)
+            .map_err(|e| e.finish(location))?;
+        Ok((loaded_function, is_entry))
+    }
+
+    /// Execute the calls of a batch in order, the return values of a call can be used as the arguments of the next calls.
+    /// All the calls are executed in the same session, so the batch is atomic.
+    fn execute_batch_calls(&mut self, calls: Vec<BatchCall>) -> VMResult<()> {
+        let mut results: Vec<Vec<Vec<u8>>> = Vec::with_capacity(calls.len());
+        for BatchCall { call, result_args } in calls {
+            let (loaded_function, is_entry) = self.load_batch_function(&call)?;
+            let location = Location::Module(call.function_id.module_id.clone());
+            let args = fill_batch_call_args(call.args, &result_args, &results, location.clone())?;
+            let serialized_args =
+                self.resolve_argument(&loaded_function, args, location.clone(), true)?;
+            let return_values = if is_entry {
+                self.session.execute_entry_function(
+                    &call.function_id.module_id,
+                    &call.function_id.function_name,
+                    call.ty_args,
+                    serialized_args,
+                    &mut self.gas_meter,
+                )?
+            } else {
+                self.session.execute_function_bypass_visibility(
+                    &call.function_id.module_id,
+                    &call.function_id.function_name,
+                    call.ty_args,
+                    serialized_args,
+                    &mut self.gas_meter,
+                )?
+            };
+            // Release the object arguments, the next call may use the same objects
+            self.object_runtime
+                .write()
+                .release_arguments()
+                .map_err(|e| e.finish(location))?;
+            results.push(
+                return_values
+                    .return_values
+                    .into_iter()
+                    .map(|(value, _layout)| value)
+                    .collect(),
+            );
         }
+        Ok(())
     }
 
     /// Execute a move action.
@@ -470,6 +588,7 @@ where
 
                 self.execute_init_modules(modules_with_init)
             }
+            VerifiedMoveAction::Batch { calls } => self.execute_batch_calls(calls),
         };
 
         if action_result.is_ok() {
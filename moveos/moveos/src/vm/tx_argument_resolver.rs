@@ -14,6 +14,7 @@ use moveos_common::types::{ClassifiedGasMeter, SwitchableGasMeter};
 use moveos_object_runtime::resolved_arg::ResolvedArg;
 use moveos_object_runtime::TypeLayoutLoader;
 use moveos_types::state::ObjectState;
+use moveos_types::transaction::{BatchCall, CallResultArg};
 use moveos_types::{
     move_std::{ascii::MoveAsciiString, string::MoveString},
     moveos_std::object::{is_object_struct, ObjectID},
@@ -85,6 +86,78 @@ where
         Ok(serialized_args)
     }
 
+    /// Verify the arguments of a call in the batch,
+    /// the return values of the previous calls must match the types of the arguments they fill.
+    pub fn verify_batch_call_args(
+        &self,
+        func: &LoadedFunctionInstantiation,
+        batch_call: &BatchCall,
+        previous_return_types: &[Vec<TypeTag>],
+        location: Location,
+    ) -> VMResult<()> {
+        //The signer arguments are filled by the resolver, they are not counted in the arguments
+        let parameters = func
+            .parameters
+            .iter()
+            .map(|ty| ty.subst(&func.type_arguments))
+            .collect::<PartialVMResult<Vec<_>>>()
+            .map_err(|err| err.finish(location.clone()))?
+            .into_iter()
+            .filter(|ty| !is_signer(ty))
+            .collect::<Vec<_>>();
+
+        let args_len = batch_call.call.args.len() + batch_call.result_args.len();
+        if args_len != parameters.len() {
+            return Err(
+                PartialVMError::new(StatusCode::NUMBER_OF_ARGUMENTS_MISMATCH)
+                    .with_message(format!(
+                        "Invalid argument length, expect:{}, got:{}",
+                        parameters.len(),
+                        args_len
+                    ))
+                    .finish(location),
+            );
+        }
+
+        let mut next_arg_index = 0usize;
+        for result_arg in batch_call.result_args.iter() {
+            let arg_index = result_arg.arg_index as usize;
+            if arg_index < next_arg_index || arg_index >= parameters.len() {
+                return Err(
+                    PartialVMError::new(StatusCode::NUMBER_OF_ARGUMENTS_MISMATCH)
+                        .with_message(format!(
+                            "Invalid result argument index:{}, the result arguments must be in ascending order",
+                            arg_index
+                        ))
+                        .finish(location),
+                );
+            }
+            next_arg_index = arg_index + 1;
+
+            let return_type = previous_return_types
+                .get(result_arg.call_index as usize)
+                .and_then(|return_types| return_types.get(result_arg.result_index as usize))
+                .ok_or_else(|| {
+                    PartialVMError::new(StatusCode::NUMBER_OF_ARGUMENTS_MISMATCH)
+                        .with_message(format!(
+                            "Return value {} of call {} not found",
+                            result_arg.result_index, result_arg.call_index
+                        ))
+                        .finish(location.clone())
+                })?;
+            let parameter_type = self.get_type_tag(&parameters[arg_index])?;
+            if &parameter_type != return_type {
+                return Err(PartialVMError::new(StatusCode::TYPE_MISMATCH)
+                    .with_message(format!(
+                        "Invalid result argument type, expect:{}, got:{}",
+                        parameter_type, return_type
+                    ))
+                    .finish(location));
+            }
+        }
+        Ok(())
+    }
+
     pub fn load_arguments(&mut self, resolved_args: Vec<ResolvedArg>) -> VMResult<Vec<Vec<u8>>> {
         let mut object_runtime = self.object_runtime.write();
         object_runtime.load_arguments(self, &resolved_args)?;
@@ -454,6 +527,39 @@ where
     }
 }
 
+/// Insert the return values of the previous calls into the arguments of the call in the batch.
+/// The result arguments are inserted in ascending order of `arg_index`, so the index is the final position of the argument.
+pub fn fill_batch_call_args(
+    mut args: Vec<Vec<u8>>,
+    result_args: &[CallResultArg],
+    previous_results: &[Vec<Vec<u8>>],
+    location: Location,
+) -> VMResult<Vec<Vec<u8>>> {
+    for result_arg in result_args {
+        let value = previous_results
+            .get(result_arg.call_index as usize)
+            .and_then(|results| results.get(result_arg.result_index as usize))
+            .ok_or_else(|| {
+                PartialVMError::new(StatusCode::NUMBER_OF_ARGUMENTS_MISMATCH)
+                    .with_message(format!(
+                        "Return value {} of call {} not found",
+                        result_arg.result_index, result_arg.call_index
+                    ))
+                    .finish(location.clone())
+            })?;
+        let arg_index = result_arg.arg_index as usize;
+        if arg_index > args.len() {
+            return Err(
+                PartialVMError::new(StatusCode::NUMBER_OF_ARGUMENTS_MISMATCH)
+                    .with_message(format!("Invalid result argument index:{}", arg_index))
+                    .finish(location),
+            );
+        }
+        args.insert(arg_index, value.clone());
+    }
+    Ok(args)
+}
+
 fn is_signer(t: &Type) -> bool {
     matches!(t, Type::Signer) || matches!(t, Type::Reference(r) if matches!(**r, Type::Signer))
 }
@@ -3,7 +3,7 @@
 
 use anyhow::Result;
 use function_name::named;
-use jellyfish_merkle::hash::SPARSE_MERKLE_PLACEHOLDER_HASH_VALUE;
+use jellyfish_merkle::hash::{SMTHash, SPARSE_MERKLE_PLACEHOLDER_HASH_VALUE};
 use jellyfish_merkle::{
     iterator::JellyfishMerkleIterator,
     node_type::{Node, NodeKey},
@@ -100,14 +100,42 @@ impl NodeWriter for InMemoryNodeStore {
 pub struct TreeChangeSet {
     pub state_root: H256,
     pub nodes: BTreeMap<H256, Vec<u8>>,
+    /// The number of references to each node in `nodes`, the same node may be written by
+    /// different trees in one change set, but `nodes` only keeps it once
+    pub node_ref_counts: BTreeMap<H256, u64>,
+    /// The nodes of the pre state root that are not reachable from the new state root,
+    /// a node appears once for every reference it loses
+    pub stale_nodes: Vec<H256>,
 }
 
 impl TreeChangeSet {
     pub fn new(state_root: H256, nodes: BTreeMap<H256, Vec<u8>>) -> Self {
-        Self { state_root, nodes }
+        let node_ref_counts = nodes.keys().map(|hash| (*hash, 1)).collect();
+        Self {
+            state_root,
+            nodes,
+            node_ref_counts,
+            stale_nodes: vec![],
+        }
+    }
+
+    /// Merge the nodes, the references and the stale nodes of another change set,
+    /// the state root is not changed.
+    pub fn merge(&mut self, mut other: TreeChangeSet) {
+        self.nodes.append(&mut other.nodes);
+        for (hash, ref_count) in other.node_ref_counts {
+            *self.node_ref_counts.entry(hash).or_insert(0) += ref_count;
+        }
+        self.stale_nodes.append(&mut other.stale_nodes);
     }
 }
 
+/// Decode the node binary and calculate the node hash,
+/// it is used to check the nodes loaded from an untrusted source.
+pub fn node_hash<K: Key, V: Value>(node_bytes: &[u8]) -> Result<H256> {
+    Ok(Node::<K, V>::decode(node_bytes)?.merkle_hash().into())
+}
+
 /// Sparse Merkle Tree
 #[derive(Clone)]
 pub struct SMTree<K, V, NR> {
@@ -290,10 +318,7 @@ where
     ) -> Result<TreeChangeSet> {
         let updates: UpdateSet<K, V> = updates.into();
         if updates.is_empty() {
-            return Ok(TreeChangeSet {
-                state_root,
-                nodes: BTreeMap::default(),
-            });
+            return Ok(TreeChangeSet::new(state_root, BTreeMap::default()));
         }
 
         let tree = JellyfishMerkleTree::new(&self.node_reader);
@@ -306,12 +331,47 @@ where
             node_map.insert(nk.into(), n.encode()?);
         }
 
+        let stale_nodes = change_set
+            .stale_node_index_batch
+            .into_iter()
+            .map(|index| index.node_key.into())
+            .collect();
+
         let new_state_root: H256 = new_state_root.into();
 
-        Ok(TreeChangeSet {
-            state_root: new_state_root,
-            nodes: node_map,
-        })
+        let mut tree_change_set = TreeChangeSet::new(new_state_root, node_map);
+        tree_change_set.stale_nodes = stale_nodes;
+        Ok(tree_change_set)
+    }
+
+    /// Walk all the nodes of the tree in depth-first order, the visitor receives the node hash,
+    /// the node binary and the value if the node is a leaf.
+    /// Returns an error if any node of the tree is missing in the store.
+    pub fn walk_nodes<F>(&self, state_root: H256, mut visitor: F) -> Result<()>
+    where
+        F: FnMut(H256, &[u8], Option<&V>) -> Result<()>,
+    {
+        let mut stack = vec![state_root];
+        while let Some(hash) = stack.pop() {
+            if hash == *SPARSE_MERKLE_PLACEHOLDER_HASH {
+                continue;
+            }
+            let node_bytes = self
+                .node_reader
+                .get(&hash)?
+                .ok_or_else(|| anyhow::format_err!("Missing tree node: {:?}", hash))?;
+            match Node::<K, V>::decode(&node_bytes)? {
+                Node::Null => visitor(hash, &node_bytes, None)?,
+                Node::Internal(internal_node) => {
+                    visitor(hash, &node_bytes, None)?;
+                    stack.extend(internal_node.all_child().into_iter().map(H256::from));
+                }
+                Node::Leaf(leaf_node) => {
+                    visitor(hash, &node_bytes, Some(&leaf_node.value().origin))?
+                }
+            }
+        }
+        Ok(())
     }
 
     /// Dump all (key, value) from the tree
@@ -57,3 +57,100 @@ fn test_smt() {
     let iter = smt.iter(changeset3.state_root, None).unwrap();
     assert_eq!(iter.count(), 2);
 }
+
+#[test]
+fn test_smt_stale_nodes() {
+    let node_store = InMemoryNodeStore::default();
+    let registry = prometheus::Registry::new();
+    let smt = SMTree::new(node_store.clone(), &registry);
+    let genesis_root = *SPARSE_MERKLE_PLACEHOLDER_HASH;
+    let changeset = smt
+        .puts(
+            genesis_root,
+            vec![
+                (H256::random(), Some("value1".to_string())),
+                (H256::random(), Some("value2".to_string())),
+            ],
+        )
+        .unwrap();
+    assert!(changeset.stale_nodes.is_empty());
+    node_store.write_nodes(changeset.nodes.clone()).unwrap();
+
+    let changeset2 = smt
+        .put(changeset.state_root, H256::random(), "value3".to_string())
+        .unwrap();
+    // The old root node is replaced by the new root node
+    assert!(changeset2.stale_nodes.contains(&changeset.state_root));
+    assert!(!changeset2.stale_nodes.contains(&changeset2.state_root));
+    // All the stale nodes are written by the previous change set
+    assert!(changeset2
+        .stale_nodes
+        .iter()
+        .all(|hash| changeset.nodes.contains_key(hash)));
+}
+
+#[test]
+fn test_smt_merge_change_set_counts_every_reference() {
+    let node_store = InMemoryNodeStore::default();
+    let registry = prometheus::Registry::new();
+    let smt = SMTree::new(node_store, &registry);
+    let genesis_root = *SPARSE_MERKLE_PLACEHOLDER_HASH;
+    let updates = vec![
+        (H256::random(), Some("value1".to_string())),
+        (H256::random(), Some("value2".to_string())),
+    ];
+    // Two trees with the same content share all the nodes
+    let mut changeset = smt.puts(genesis_root, updates.clone()).unwrap();
+    let changeset2 = smt.puts(genesis_root, updates).unwrap();
+    assert_eq!(changeset.nodes, changeset2.nodes);
+    assert!(changeset.node_ref_counts.values().all(|count| *count == 1));
+
+    let node_count = changeset.nodes.len();
+    changeset.merge(changeset2);
+    assert_eq!(changeset.nodes.len(), node_count);
+    assert_eq!(changeset.node_ref_counts.len(), node_count);
+    assert!(changeset.node_ref_counts.values().all(|count| *count == 2));
+}
+
+#[test]
+fn test_smt_walk_nodes() {
+    let node_store = InMemoryNodeStore::default();
+    let registry = prometheus::Registry::new();
+    let smt = SMTree::new(node_store.clone(), &registry);
+    let changeset = smt
+        .puts(
+            *SPARSE_MERKLE_PLACEHOLDER_HASH,
+            (0..20)
+                .map(|i| (H256::random(), Some(format!("value{}", i))))
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+    node_store.write_nodes(changeset.nodes.clone()).unwrap();
+
+    let mut nodes = BTreeMap::new();
+    let mut values = 0;
+    smt.walk_nodes(changeset.state_root, |hash, node, value| {
+        assert_eq!(node_hash::<H256, String>(node)?, hash);
+        nodes.insert(hash, node.to_vec());
+        if value.is_some() {
+            values += 1;
+        }
+        Ok(())
+    })
+    .unwrap();
+    assert!(nodes.contains_key(&changeset.state_root));
+    assert!(nodes
+        .iter()
+        .all(|(hash, node)| changeset.nodes.get(hash) == Some(node)));
+    assert_eq!(values, 20);
+
+    // The walk fails if the tree is incomplete
+    let missing = InMemoryNodeStore::default();
+    missing
+        .put(changeset.state_root, nodes[&changeset.state_root].clone())
+        .unwrap();
+    let smt = SMTree::<H256, String, _>::new(missing, &prometheus::Registry::new());
+    assert!(smt
+        .walk_nodes(changeset.state_root, |_, _, _| Ok(()))
+        .is_err());
+}
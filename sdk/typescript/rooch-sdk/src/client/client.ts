@@ -63,6 +63,10 @@ import {
   PaginatedStateChangeSetWithTxOrderViews,
   DryRunRawTransactionParams,
   DryRunTransactionResponseView,
+  EstimateGasParams,
+  GasEstimateView,
+  TraceTransactionParams,
+  TransactionTraceView,
 } from './types/index.js'
 import { fixedBalance } from '../utils/balance.js'
 
@@ -149,7 +153,14 @@ export class RoochClient {
   async dryrun(input: DryRunRawTransactionParams): Promise<DryRunTransactionResponseView> {
     return await this.transport.request({
       method: 'rooch_dryRunRawTransaction',
-      params: [input.txBcsHex],
+      params: [input.txBcsHex, input.stateOverrides],
+    })
+  }
+
+  async estimateGas(input: EstimateGasParams): Promise<GasEstimateView> {
+    return await this.transport.request({
+      method: 'rooch_estimateGas',
+      params: [input.txBcsHex, input.gasPayer],
     })
   }
 
@@ -395,6 +406,13 @@ export class RoochClient {
     })
   }
 
+  async traceTransaction(input: TraceTransactionParams): Promise<TransactionTraceView> {
+    return this.transport.request({
+      method: 'rooch_traceTransaction',
+      params: [input.txHash, input.traceOption],
+    })
+  }
+
   async queryTransactions(
     input: QueryTransactionsParams,
   ): Promise<PaginatedTransactionWithInfoViews> {
@@ -14,6 +14,7 @@ export const ErrorValidateInvalidAuthenticator = 1010
 export const ErrorValidateNotInstalledAuthValidator = 1011
 export const ErrorValidateSessionIsExpired = 1012
 export const ErrorValidateFunctionCallBeyondSessionScope = 1013
+export const ErrorValidateSessionKeyQuotaExceeded = 1014
 
 const CODE_TO_ERROR_TYPE: Record<number, string> = {
   1001: 'SequenceNuberTooOld',
@@ -29,6 +30,7 @@ const CODE_TO_ERROR_TYPE: Record<number, string> = {
   1011: 'NotInstalledAuthValidator',
   1012: 'SessionIsExpired',
   1013: 'CallFunctionBeyondSessionScop',
+  1014: 'SessionKeyQuotaExceeded',
 }
 
 export class RoochHTTPTransportError extends Error {}
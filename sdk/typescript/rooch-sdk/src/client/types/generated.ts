@@ -64,6 +64,10 @@ export interface BalanceInfoView {
   symbol: string
   fixedBalance: number
 }
+export interface BatchCallView {
+  call: FunctionCallView
+  result_args: CallResultArgView[]
+}
 export interface BitcoinStatus {
   confirmed_block?: BlockHeightHashView | null
   pending_block?: BlockHeightHashView | null
@@ -72,6 +76,24 @@ export interface BlockHeightHashView {
   block_hash: string
   block_height: string
 }
+export interface CallResultArgView {
+  arg_index: number
+  call_index: number
+  result_index: number
+}
+/** A call in the execution trace, the nested calls are in the execution order. */
+export interface CallTraceView {
+  calls: CallTraceView[]
+  /**
+   * The called function, it is None for the root frame of a script, module bundle or batch
+   * transaction
+   */
+  function?: string | null
+  /** The gas used by the call, including the nested calls */
+  gas_used: string
+  is_native: boolean
+  ty_args: string[]
+}
 export interface DAInfoView {
   avail_backends: [string, string][]
   last_avail_block_number?: string | null
@@ -122,6 +144,24 @@ export type EventFilterView =
         /** right endpoint of transaction order, exclusive */
         to_order: string
       }
+    } /** Query by the module which defines the event type, such as all the events of `0x3::coin`. Query all the events defined by the address if the module is not set. */
+  | {
+      event_module: {
+        address: string
+        module?: string | null
+      }
+    } /** Query by any of the event types. */
+  | {
+      event_types: string[]
+    } /** Query by the event handle id. */
+  | {
+      event_handle: string
+    } /** Return events matching all of the filters. */
+  | {
+      and: EventFilterView[]
+    } /** Return events matching any of the filters. */
+  | {
+      or: EventFilterView[]
     }
 export interface EventIDView {
   /** each event handle corresponds to a unique event handle id. event handler id equal to guid. */
@@ -155,6 +195,28 @@ export interface FunctionReturnValueView {
   type_tag: string
   value: string
 }
+export interface GasEstimateView {
+  /** The storage gas of the state changes */
+  change_set_storage_gas: string
+  /** The estimated transaction fee in RGas, the gas used multiplied by the gas price */
+  estimated_fee: string
+  /** The storage gas of the events */
+  event_storage_gas: string
+  /** The gas used by the Move execution */
+  execution_gas_used: string
+  /** The gas price, the transaction fee is the gas used multiplied by it */
+  gas_price: string
+  /** The total gas of the transaction, the execution gas and the storage gas */
+  gas_used: string
+  status: KeptVMStatusView
+  /** The storage gas of the transaction size, the events and the state changes */
+  storage_gas_used: string
+  /** The suggested max_gas_amount, the gas used with a safety margin */
+  suggested_max_gas_amount: string
+  /** The storage gas of the transaction size */
+  tx_size_storage_gas: string
+  vm_error_info?: VMErrorInfo | null
+}
 export interface IndexerEventIDView {
   event_index: string
   tx_order: string
@@ -291,8 +353,9 @@ export interface ModuleABIView {
   /** Structs of the module */
   structs: MoveStructView[]
 }
-export type MoveActionTypeView = 'scriptcall' | 'functioncall' | 'modulebundle'
+export type MoveActionTypeView = 'scriptcall' | 'functioncall' | 'modulebundle' | 'batchcall'
 export interface MoveActionView {
+  batch_call?: BatchCallView[] | null
   function_call?: FunctionCallView | null
   module_bundle?: string[] | null
   script_call?: ScriptCallView | null
@@ -539,7 +602,12 @@ export interface SequencerInfoView {
   last_accumulator_info: AccumulatorInfoView
   last_order: string
 }
-export type ServiceStatus = 'active' | 'maintenance' | 'read-only-mode' | 'date-import-mode'
+export type ServiceStatus =
+  | 'active'
+  | 'maintenance'
+  | 'read-only-mode'
+  | 'date-import-mode'
+  | 'follower-mode'
 /** Some specific struct that we want to display in a special way for better readability */
 export type SpecificStructView = MoveString | MoveAsciiString | string
 export interface StateChangeSetView {
@@ -556,6 +624,11 @@ export interface StateKVView {
   state: ObjectStateView
 }
 export interface StateOptions {
+  /**
+   * Query the historical state after the transaction of the tx_order is executed. It can not be set
+   * with the state_root at the same time.
+   */
+  atTxOrder?: string | null
   /** If true, the state is decoded and the decoded value is returned in the response. */
   decode?: boolean
   /** If true, result with display rendered is returned */
@@ -563,6 +636,30 @@ export interface StateOptions {
   /** The state root of remote stateDB */
   stateRoot?: string | null
 }
+/** Override the state when dry running a transaction, the overrides are never written to the state. */
+export type StateOverrideView =
+  /** Override the object value, the fields of the object are kept. */
+  | {
+      object: {
+        object_id: string
+        /** The type of the object, it is required if the object does not exist. */
+        object_type?: string | null
+        /** The owner of the object, keep the current owner if not set. */
+        owner?: string | null
+        /** The BCS bytes of the object value. */
+        value: string
+      }
+    } /** Override the coin balance of the account. */
+  | {
+      balance: {
+        balance: string
+        coin_type: string
+        owner: string
+      }
+    } /** Replace the module byte codes, the package of the module must exist. */
+  | {
+      module: string
+    }
 export interface Status {
   /** The status of the Bitcoin chain */
   bitcoin_status: BitcoinStatus
@@ -577,8 +674,23 @@ export type SyncStateFilterView =
       object_i_d: string
     }
   | 'all'
+export interface TraceOptions {
+  /**
+   * If true, the flamegraph of the gas usage is rendered in SVG format and returned in the
+   * response.
+   */
+  flamegraph?: boolean
+}
+export interface TransactionEventView {
+  decoded_event_data?: AnnotatedMoveStructView | null
+  event_data: string
+  event_index: string
+  event_type: string
+}
 export interface TransactionExecutionInfoView {
   event_root: string
+  /** The account which paid the gas, it is the sender unless the transaction is sponsored. */
+  gas_payer: string
   gas_used: string
   state_root: string
   status: KeptVMStatusView
@@ -621,6 +733,30 @@ export interface TransactionSequenceInfoView {
   tx_order_signature: string
   tx_timestamp: string
 }
+export interface TransactionTraceView {
+  /**
+   * The call tree of the transaction, the gas of the system pre_execute and post_execute functions
+   * is not charged
+   */
+  call_trace: CallTraceView
+  /** The state changes written by the transaction */
+  changeset: StateChangeSetView
+  events: TransactionEventView[]
+  /** The flamegraph of the gas usage in SVG format, only returned if the flamegraph option is set */
+  flamegraph?: string | null
+  gas_used: string
+  /**
+   * The state root before the transaction is executed, the transaction is re-executed at this state
+   * root
+   */
+  pre_state_root: string
+  /** The objects read from the state during the execution */
+  read_set: string[]
+  status: KeptVMStatusView
+  tx_hash: string
+  tx_order: string
+  vm_error_info?: VMErrorInfo | null
+}
 export interface TransactionWithInfoView {
   execution_info?: TransactionExecutionInfoView | null
   transaction: LedgerTransactionView
@@ -32,8 +32,23 @@ export interface QueryUTXOsParams {
   limit?: string | null | undefined
   descendingOrder?: boolean | null | undefined
 }
+/**
+ * Dry run the transaction data in bcs hex format, the transaction does not need to be signed. The
+ * state overrides are applied on top of the current state only for this dry run, like the eth_call
+ * state overrides.
+ */
 export interface DryRunRawTransactionParams {
   txBcsHex: string
+  stateOverrides?: RpcTypes.StateOverrideView[] | null | undefined
+}
+/**
+ * Estimate the gas of the transaction data in bcs hex format, the transaction does not need to be
+ * signed. The max gas amount is capped by the RGas balance of the gas payer at the gas price, the gas
+ * payer is the sender if it is not set. The suggested max_gas_amount includes a safety margin.
+ */
+export interface EstimateGasParams {
+  txBcsHex: string
+  gasPayer?: string | null | undefined
 }
 /**
  * Send the signed transaction in bcs hex format This method blocks waiting for the transaction to be
@@ -43,9 +58,14 @@ export interface ExecuteRawTransactionParams {
   txBcsHex: string
   txOption?: RpcTypes.TxOptions | null | undefined
 }
-/** Execute a read-only function call The function do not change the state of Application */
+/**
+ * Execute a read-only function call The function do not change the state of Application If the
+ * StateOptions.at_tx_order is set, the function is executed at the historical state. The
+ * StateOptions.state_root is not supported, because the object count of the state root is unknown.
+ */
 export interface ExecuteViewFunctionParams {
   functionCall: RpcTypes.FunctionCallView
+  stateOption?: RpcTypes.StateOptions | null | undefined
 }
 /** get account balance by RoochAddress and CoinType */
 export interface GetBalanceParams {
@@ -158,3 +178,12 @@ export interface SyncStatesParams {
   limit?: string | null | undefined
   queryOption?: RpcTypes.QueryOptions | null | undefined
 }
+/**
+ * Trace the transaction by re-executing it at the state root before it was executed, like the
+ * debug_traceTransaction. Return the call tree with the gas used by each call, the events, the objects
+ * read and the state changes.
+ */
+export interface TraceTransactionParams {
+  txHash: string
+  traceOption?: RpcTypes.TraceOptions | null | undefined
+}